@@ -6,6 +6,7 @@ extern crate lexical_core;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use lexical_core::parse as lexical_parse;
+use lexical_core::FloatParser;
 
 // BENCH GENERATORS
 
@@ -22,6 +23,33 @@ macro_rules! lexical_generator {
     );
 }
 
+// Lexical reusable parser generator.
+macro_rules! parser_generator {
+    ($name:ident, $data:ident, $t:ty) => (
+        fn $name(criterion: &mut Criterion) {
+            let parser = FloatParser::new();
+            criterion.bench_function(stringify!($name), |b| b.iter(|| {
+                $data.iter().for_each(|x| {
+                    black_box(parser.parse::<$t>(x.as_bytes()).unwrap());
+                })
+            }));
+        }
+    );
+}
+
+// Lexical specialized f64 generator.
+macro_rules! fast_generator {
+    ($name:ident, $data:ident) => (
+        fn $name(criterion: &mut Criterion) {
+            criterion.bench_function(stringify!($name), |b| b.iter(|| {
+                $data.iter().for_each(|x| {
+                    black_box(lexical_core::parse_f64_fast(x.as_bytes()).unwrap());
+                })
+            }));
+        }
+    );
+}
+
 // Parse atoi generator.
 macro_rules! parse_generator {
     ($name:ident, $data:ident, $t:tt) => (
@@ -41,6 +69,7 @@ macro_rules! parse_generator {
 const F32_DATA: [&'static str; 10000] = ["-1.2129985296099096e-14", "-0.004396982025355101", "-1.66158102901659e-13", "6.238252602135705e-16", "5250382234124288.0", "-1.3224855238691546e-12", "1.0108321108317012e-13", "-4775522816.0", "1.0493279079458192e-27", "-8.379985251079832e-30", "5.251432850222258e-34", "-5.7083813720275e+32", "2.82890044143774e+31", "37486168375296.0", "-7.382808811670504e-28", "1.3281034258461055e-12", "-2.560494488397739e-33", "96.88443756103516", "1.970190126332413e-36", "3.9717424527590896e-17", "-2.066977229423758e-29", "-0.13733242452144623", "-133654183936.0", "1.3510753608692591e-15", "4.493194011301327e-11", "-4.191113873730501e-07", "-1.413631814518423e-15", "2.4647450356983558e+36", "-2.2225850338454975e-30", "-1.681487248656453e+28", "-1.540366280462805e-22", "0.23529592156410217", "1.503399931120632e+21", "8.893277507653207e-17", "3.619114785635702e-09", "-6.8181128938614375e+31", "3.643807954141338e+25", "-5.1648350301580545e-37", "2.7867929770638752e+26", "2.5521388456130263e+38", "-8.900260436807614e+32", "-17067.0625", "-5408583843840.0", "34697523200.0", "-254257148198912.0", "1.9547744783826944e+17", "3.307676672657605e+23", "-4.2988058230339613e+20", "2.1173722200696776e+30", "0.00023252896789927036", "-6.786386811792127e+31", "96150024.0", "-38375248.0", "1.407197391156674e+33", "-8.046807144224122e-12", "9.500131580288599e-25", "6366121.0", "-3.3868110203320247e-35", "-1.2603364549290447e-29", "-7.47034105404201e+25", "-6.017868775380297e+26", "-1.1509972786156581e+25", "-2688138240.0", "-796241472.0", "2.2961796730669448e-07", "-2.031141534195222e+28", "-2.26702611672538e-20", "-5.964611053466797", "3.070906908730221e-38", "-4.633273781848248e+25", "-0.9520187973976135", "3.8951593680079147e-28", "4565773573947392.0", "1.7771047094182833e-26", "-1.877525392102175e-11", "2586314240.0", "-1417041.75", "1.6758923138127102e-08", "2.657499862834811e-06", "-1.2107593920279366e+36", "-250516.125", "6.150666531789446e+28", "-8235911741440.0", "-1.4002297426004716e+23", "2.0938359065756172e-26", "1.7713984561851248e-05", "-0.003150173695757985", "-3.5971746363563494e-37", "-9565483008.0", "-210193874944.0", "-1.264503193824831e-16", "-2.973097995765023e+17", "6.697984511629039e+35", "1.1064481167821505e+19", "-3.1793074727594126e-30", "1.437363418955776e+16", "-2.2692105630994774e-05", "68197265408.0", "2.563225125835242e+25", "8.85091775129516e-23", "-2.474737606689319e-35", "-1.397002415615134e-05", "2.3608126640319824", "-466.61773681640625", "-470602368.0", "1.960214269211443e+16", "-1.6101666368717226e+30", "67862294528.0", "-1.6430671078174094e-18", "-137619872.0", "-2.2732808083956024e-26", "-9.894264553451746e-23", "-82561792.0", "-48359104.0", "1.05134416748594e-19", "1.2609830847929597e+26", "-7.404821200651668e-10", "-0.0007652728236280382", "-3.986366436947719e-07", "4.7291277067704837e-36", "-2.9865796256087627e-30", "-21052542976.0", "7.233411900132547e+34", "-2.1710916983349535e+28", "-8.747275128147211e+21", "7.410166063015719e+28", "7.616545644850703e-06", "-1.847359376583313e-09", "-6.193961118442861e-33", "4.308457434059853e-27", "-1.626246216801519e-06", "4.40553775044558e+26", "3.16803016989114e-35", "3.565304593945375e+22", "-1.3488328606419997e-29", "378712358912.0", "-6.722666323406221e-24", "-8557263872.0", "-4.9561637419750166e+32", "196065264.0", "-2.2873485682156028e-35", "-0.0029207668267190456", "63.50284957885742", "-497762.09375", "2.429514125931478e-22", "-1.3833779455296735e-08", "3.2981588588799924e+25", "-3.1960764988013056e+37", "3.356915608247557e+20", "-747.470703125", "3.4200281264685056e+16", "4395.396484375", "6.744592900331279e-20", "-1.3061733595218531e-20", "-2.4200558986424746e-25", "-235349868544.0", "208654997389312.0", "-1.0037526247046419e-26", "-160839.9375", "-1.4256638384485393e-36", "-2.2897790563709564e+20", "9.47649899246125e+17", "-9.693585811254015e-21", "5.712567919356398e+36", "-118.30876922607422", "1.4835024916801126e+17", "2.161891336501023e+31", "13091.0107421875", "9.058379012140659e+30", "-4.1528001786457725e+20", "2.4027302540479964e-28", "1.5167259878600212e+18", "-523510543810560.0", "314887.65625", "8.480569135826954e-07", "-107.04054260253906", "2.384239160269086e-11", "9.076909179839276e-08", "-3.2295388156002002e+25", "-9.482162006795005e-29", "1.5525995966395488e-34", "-1.2038040016783314e-32", "-1466743679614976.0", "-8204028022358016.0", "1.684227572944924e-13", "-11772.994140625", "-5.893592069564465e+35", "-9.067679057565617e-23", "-2.830972839252432e-16", "7.490363483952373e-39", "7.840639810914607e+34", "1.8279181229241604e+27", "-14281644032.0", "40318565089280.0", "9.323088114279658e-38", "-3.9872885929327074e-33", "-3.490473083377442e+25", "-1.1683143432656888e-18", "369854240.0", "-8.672799285827338e+17", "-48876109824.0", "4.990752871995206e-32", "-3.0899343175319306e-28", "9.984020803693604e-36", "2.184712685614609e+27", "-287516983296.0", "-2.7414011000936154e-25", "-4.810046956504991e+27", "-8188786176.0", "3.3728390034644495e+24", "-3.2834008845128437e-09", "-5.940553740749218e-17", "5.079989607420686e-25", "2.5756693772023273e-21", "3.2237026618621995e+37", "-3.9847545209859223e-32", "2.5128695551793647e-32", "-154519633920.0", "5.183360339708124e-33", "1.2861901135882176e-36", "17091858.0", "-1.0722976326316136e-13", "-4.355078681693527e-37", "3.528444399817089e+32", "-1.2630404163290419e-37", "-1.0214635670480115e-16", "-1.8553257129150183e-33", "2.7780380362315214e+38", "-6.837040517366999e-27", "-6.757199192056832e+21", "-1.1280492171055849e-29", "1.2333236531652198e+17", "1.0474907072405436e-34", "2.809799185216173e+21", "6.068509952363021e-14", "1.2463118669282541e-30", "5.8561828746795734e+29", "-1.8478130221366743e-29", "1.7100149770727264e+22", "-2.0838425426725676e-24", "4.02942630436012e+17", "4.443199525385005e-29", "1416090.625", "-5798512558080.0", "7.896227081971837e-24", "-0.10429863631725311", "1.4007035115115585e-11", "-6.38642120395977e-35", "0.0017653797985985875", "-9.090568024680985e-17", "-1.346285629026647e+37", "-681956.0625", "1.0825010126900626e-15", "1.2515193695442313e-22", "9970204672.0", "1.989327784075102e+30", "2.2613326913991533e-34", "-1.158140771085163e-34", "2.4241552588817524e-39", "-1.317076686564245e-14", "-0.0003972589911427349", "-4.867901848878248e+27", "-3.24827718794032e+28", "4.01847877632e+16", "-9.55992072671929e-19", "3474437.0", "2.2060456752468304e-38", "-3.4875269063698773e-28", "-4.408285872280063e-37", "8.21133728404809e-20", "-3.5140113329111265e-34", "-5.147088381648768e+37", "-9.848000004946266e+25", "-5.880985609783183e-35", "3.8923892465135823e-35", "1.273119023398328e-11", "9.622260930223137e-26", "1.3271467722288093e+33", "5.163298577342082e+32", "-16.196369171142578", "-1.7215622003600392e+19", "-5.75992419982017e-30", "1.0046991807537093e-26", "-3.9800950702714213e+37", "4.402052387520832e+34", "9.981030417783919e-23", "-777.7501831054688", "-3.6634341136449093e-34", "-6.971007104894511e-12", "-1.4834145099657222e-27", "9.84135663032375e+36", "-1.795383062868066e-14", "0.00044192877248860896", "126225.5390625", "-82511170371584.0", "-1.4444441922301516e+24", "-1505.133056640625", "1.256690250940416e+16", "3.034187160606171e-16", "7.088344204930763e-07", "-3.0033549854776143e+25", "-1132.0465087890625", "1.504854271859764e+35", "1.2987813339129725e-25", "3.973006730031793e+28", "-1.1606298086594398e+27", "0.0069464282132685184", "-427502858141696.0", "-278382.09375", "919023714304.0", "2.187127674319158e+28", "220144448.0", "0.00020136717648711056", "-1.0289019586104577e+20", "-9.846885917527283e+28", "17303396679680.0", "0.04771355912089348", "63452.578125", "-0.0034210882149636745", "-1.859149016038438e-15", "5.835309159470342e-34", "1.501200485652615e+21", "2.641304182770012e+20", "147.4907684326172", "-221996859392.0", "537412599808.0", "2.285635568791915e+20", "-2.2498197461106593e-26", "-1.2378175000994505e-33", "-1.0608798524193726e-29", "-0.003062577685341239", "1.8219806850704333e-32", "4.152287783969083e+36", "-4.2526873171540754e+30", "2.4529878617511115e-34", "6.866956985176166e-21", "-1.0670208302825985e+33", "5.036898727103967e-29", "-5.512246324061057e+35", "-1.0259121207083004e-34", "191698550784.0", "-2.974285528978271e-08", "7.8152571541146645e+31", "6.322337049957659e-18", "-9.995380056793393e-30", "5.96997491563821e-27", "-4.306813536913978e-07", "9.870560695681024e+16", "2.1478679590862673e+35", "-1.9871712608754422e-10", "-2.6282501090529428e-36", "-0.00014217154239304364", "1.8947634936374102e-22", "0.04991789907217026", "1891486662656.0", "-1.2178400654427219e-32", "2.1035738594963934e+20", "3.045315883131337e-18", "-1.7471812198709516e-35", "4.8460302611019745e-12", "2162088869888.0", "-9.912706506326474e-13", "-4.622940806784916e-38", "0.011924147605895996", "4.912295619041135e+34", "-2.219679098962807e-29", "-2.239260711458738e+23", "-507974.40625", "-1.570818368357132e+37", "-2.72627339801108e-26", "1.536123116728516e-12", "-2793493.25", "2139176704.0", "1.0213627792260846e-28", "5.372726639067712e+36", "-0.48180148005485535", "-1.2268324334651687e-37", "-123134.375", "-7.390914962887016e+18", "2.7213388295602658e+34", "-1.8007542229409275e+23", "3.735238691021962e-15", "-25029046.0", "-1.7935430786696487e-35", "8.733866407872881e-11", "-5.750212892725252e+20", "2.2757088657954228e-08", "-5.700366320922764e-30", "1145017.25", "1.497824404413203e+20", "335.562744140625", "-5.618091632572668e-37", "5.731822124792031e+26", "-64451.79296875", "4.736787911872897e+37", "2.3346901433320058e-30", "-769.7474365234375", "7.51511761859682e-21", "-1.233617805820036e-21", "500780.15625", "2.639831426076242e-35", "2.5323670271859555e-08", "-1.766845068345105e+35", "-1.2387233079812665e-29", "-180.73146057128906", "-1.3069472768771744e-25", "8.794922468013448e-38", "-1.7108013999896307e+30", "0.00217947899363935", "1.1778827513353463e-07", "-2270817419264.0", "-7.946893713521066e+21", "2.023088054515468e-28", "-2.3107298991947147e+38", "1.1530796424932588e+30", "0.023333478718996048", "1.0138700965337686e+30", "8.516359839561005e-10", "8.601842809080678e-23", "NaN", "-1.8405246410467596e+31", "8.374493947411264e+23", "-3.3972438038499324e-18", "6.870986461639404", "-6.080959222656554e+26", "5.415955468142341e-23", "5.844389688030865e-20", "-5.652627426465813e-31", "5.472468281813304e+26", "-3.2494485777535074e-21", "-6.267126035020736e-22", "-2.8231599989426675e-20", "-3.7958918710501166e-06", "-509145.65625", "1.3719232602822278e+36", "0.17675642669200897", "1.856814645594157e-23", "7.211580163204788e+27", "1.8860544065034797e-38", "-2.2215207451381502e-09", "-4.462396958999531e+30", "1.0447630987339002e-13", "-7.131701085620406e+21", "-226164976.0", "-1.7831241905241768e-07", "-2.2544040069756355e+18", "-4.40208047791657e-09", "219.0077362060547", "-1.6253794683507294e-06", "-5.330072326614754e-06", "-124396424.0", "8.855117669673725e-32", "1.1647619127275032e-18", "6.220515562648174e-19", "6.957229063686565e-12", "-1.4538131881609574e+21", "-3.076772689819336", "437683.34375", "1916070789120.0", "-6.217300979605156e-12", "-7.677597848045422e-30", "-262.29864501953125", "7.716942954330652e+31", "0.07606648653745651", "-6.58972690278154e+33", "-4563480064.0", "-4.311653399595341e-16", "-652.0875244140625", "-1.585507334311842e-06", "-7762.3896484375", "2.260007939777671e+33", "1.0048627777125452e+33", "850837920284672.0", "44.144927978515625", "-53111.1484375", "3.911225221766426e-24", "9.196309060794143e-20", "-115412.140625", "-3.1885658495701392e-34", "-8.349267457963272e-14", "-1.1803533414383653e+31", "2.4492022993038987e-12", "7.320643657243719e-13", "-7.342818712260456e+26", "9.121731636696495e-06", "-0.015710024163126945", "-4.338980058902304e-15", "-10.778801918029785", "NaN", "-6.1892817260211785e-12", "1.4197823089388622e+32", "6.937499623271662e-28", "-2.7195918172275295e-34", "1.4005183110229827e-36", "-1.6347394710290497e+23", "-9.019812620327666e-14", "1.4421488079514632e+19", "0.0002850459422916174", "1.668749665360847e-12", "-1.2943612507092283e+27", "3.0660548443864964e+37", "59020320702464.0", "1.1050850522171965e+18", "NaN", "2.117203079075177e-34", "841149.4375", "1.8808826971491413e-21", "5.931114710477914e-09", "5.074930818464718e-13", "7.5160594645542915e+25", "-2.1321324657704455e-24", "-4.201558897062044e-35", "2382022685229056.0", "7.870036308272821e-26", "269747503104.0", "-480689652760576.0", "12211.9091796875", "2.624048114213684e-12", "-4.4503571834664246e-13", "24344520556544.0", "3.2813231416062956e-24", "8.382703038443291e+18", "1.137165773872073e-12", "-0.0007951920852065086", "-5.167325608983599e+29", "-2077486686928896.0", "1.982340124412528e+36", "-9.188457852360443e-07", "4.1502061135166224e+34", "-3.043274819816624e-21", "-3.638882528633758e-10", "-2.2276367417362053e-08", "1.3496928211196054e-18", "7.534463066845731e+21", "324302962688.0", "-5.580562931511363e-36", "2.6772459802762022e+35", "7.368897560427826e+37", "7.478918790036285e+25", "4.630255670308897e+18", "-3.9008679664220836e-07", "5.899556721569496e-33", "-1.1223840311422707e+34", "-4.63160200877308e+27", "-0.00017806404503062367", "5.4154208479105574e-30", "-698342153650176.0", "-3.0505313979601975e+38", "5.788263194273681e-26", "-9.304377142309547e+33", "2.6803338194769655e-12", "-2.4968731133248705e-20", "1.7745896546567216e-30", "1.2045376077840874e+29", "NaN", "-2.7869325254166567e-19", "-7.555461287235452e-33", "-1.384705392883101e-12", "1057841787961344.0", "-1.8024584215781746e-24", "-2.6570661340733623e+24", "2.872102274482747e-30", "-6.477934103728151e+30", "-2.3478768408241647e+36", "-3.716538360586309e-38", "-6.497999294197143e-08", "3.2877896146171385e-30", "-3694510466924544.0", "-2.1345825840849407e-18", "-1.341750319714497e-35", "-1.8274322164465242e-31", "-6.98533464717002e+25", "-1.4936033089397362e+31", "-2.6667796912637215e+19", "-48994038317056.0", "-811580845457408.0", "1858900096.0", "8.938920937395165e+24", "0.0001282658486161381", "7396.84033203125", "2.365963182518525e-26", "2.9507640287489546e+34", "-7.484339182104859e-16", "2.024060203606999e+17", "-6.638197521055856e-21", "-6.341159164293222e-16", "-1.415260443508736e+16", "2360629453127680.0", "-1.0903400324535312e+36", "-3171646248583168.0", "1.8865554314173263e-25", "13640.8056640625", "53038546944000.0", "-6.776639713935116e+24", "-6.757302717375069e-09", "-9.170420731778169e+27", "-1.6047051448039066e-22", "1.4236763353581528e-35", "-2.9261088677176667e+21", "9531087872.0", "-1.574689109954667e-33", "-5300837376.0", "7.4201787851871e-33", "-5.526314903302877e+28", "7.140968013034845e-15", "1.6563894234712335e-26", "1.401052104194056e-11", "5.660169264883314e+35", "2.2010914675870398e-29", "1.3943195343017578", "-5.855820331410453e+24", "-3259501683671040.0", "-6.525720445768447e-22", "52489076.0", "1.0993390559900467e+17", "-4.334940621462715e-38", "9.38420491408141e-21", "2.0562564475816142e-22", "-2.4983633831465285e+37", "0.0027850104961544275", "2.907374593216375e-36", "-87779048.0", "-3.6439121564310438e+19", "0.0017950795590877533", "-8.931563417581392e-37", "-132817764352.0", "91568.59375", "-4.991427132098395e-21", "24814.71484375", "1.2734968874663936e+17", "9.517891157884151e-05", "-4180994304.0", "1375085002752.0", "3.849210525102685e+32", "-4.7092524368963495e-07", "-6.772944191364067e+29", "2.523952858831347e-29", "-1.1172696056639671e+35", "2.4595065197558484e-32", "-122.39230346679688", "62721731067904.0", "-1.3802020084076848e-33", "-1.7818785070293638e-34", "1.261351761420017e+27", "8.839280732319235e+36", "-9.875924383839774e-11", "-8.213666302248408e+34", "3.3844364282712556e+18", "0.0010710766073316336", "1.8039319969981142e-14", "8.197509338003783e-10", "59981869056.0", "5.282311128376934e+16", "5.108195158199654e+31", "-2.2428382028949784e+36", "2.1232603491178165e-20", "-6.152607000404563e-27", "-2.3342001295781084e-26", "6.158512737338227e-21", "-7.671535957666073e+37", "-3.088720466518686e+29", "-3.3046909137706625e-28", "-2.506458230231542e-21", "0.0064927926287055016", "1.8191121200011883e+24", "552.9046630859375", "-0.780949056148529", "-1.0920763027855215e+25", "34649399296.0", "-1.3180796066196596e-19", "449406894080.0", "-1.3490316711656282e-19", "7.826385183120482e-25", "0.00010371571261202917", "-20035483467776.0", "-1.3280432555412083e+23", "-292.4999084472656", "-4.1711582665736296e+29", "-2.16976059294145e-38", "-1.3834949246064885e+34", "-0.0001082637463696301", "-9.045756882298786e-34", "9.265290641466415e+22", "0.0010351317469030619", "0.026860065758228302", "-431.7982482910156", "4.3893168642987806e+29", "1.3689198378003736e-37", "1.61711286372048e+27", "1.3952398300170898", "-41900.7890625", "-4195189760.0", "1.9217290628517077e-23", "-3.0203158893394403e-24", "8.124958140788673e-21", "-2889490432.0", "-4.270205944972776e+24", "-6.217783543216549e+30", "1.8393119712946451e+22", "620112256.0", "1.5909119019827579e+35", "-2.0356191043902072e-07", "4.289369188322133e-32", "5.474147417203062e-30", "-2.73955521379321e-07", "2.6840257794091826e-10", "543503.4375", "1.201771163313675e-37", "83096.046875", "-1.0599602306982092e-09", "-151.92330932617188", "-0.028936386108398438", "8.149378462047381e-30", "3.2397440571939455e-30", "-1.3324877054762245e-34", "-19727486.0", "2.7592036711243396e+20", "-634216513536.0", "3.1160467265361146e+32", "3.12121185500267e-16", "-5.1470235343881023e+33", "1229301248.0", "-8182161408.0", "152836276224.0", "130589.3203125", "1.8217283053181302e+38", "7.236148324345351e-38", "1.0415935069806668e+21", "-2.209435225655441e-36", "-5.233954223938251e-15", "-2.2502964824866467e-08", "-46973860.0", "3.771279904347683e-26", "4.038827432969883e+30", "1.5506536354456388e-27", "-1.759275567032514e-27", "-585339072.0", "3.1626393592887325e-06", "5.104172495824226e-21", "-7.9890666007995605", "30024558592.0", "2451408.75", "-2.101027364733219e-23", "1.1122561770047144e+19", "-2.9822362193954177e-05", "-1.6903120778030011e-22", "0.11084777116775513", "3.818005055279916e-23", "3.0137771148984544e+31", "8.350123348531696e+23", "3.5882568844417434e+17", "-746599104.0", "-3.7934268631547435e-21", "4.1094231669221265e-28", "8.120117663688275e-38", "33382302.0", "-2.2411238928838196e-21", "1.3904427732775084e-36", "1.3003162099266388e-21", "-1.7394215702894784e+31", "-1.2807920484192437e-06", "2.95564888977535e-31", "1.3904072104038463e+35", "-3.576515084091625e-08", "-1.200655337201332e+24", "2.993387222122168e-12", "1.1227285648003167e-27", "-4.526436604300028e+26", "-4440734727929856.0", "-1.0726852772599245e-15", "5.35940857739256e-24", "1.0439626523067453e-26", "2.883158664070129e-13", "-4.490407980367136e-30", "-7.4354635215845495e+34", "-3.2695906680072437e-20", "-2.2835533178847943e-30", "-3.3508460339537775e-15", "562166.4375", "1.0300158958307901e+19", "-8.29647445678711", "7.222699565384515e+27", "-1.8052478999910262e-27", "5.324415761639552e+35", "1829538365440.0", "0.002300578635185957", "-3.1282953437529363e-30", "-6.387168072536641e+35", "2.356429477247356e-32", "-7.104924335597557e+26", "12.860973358154297", "5.462052649818361e-05", "-7257741852672.0", "6.657045137359319e-09", "-3.4206082048337184e-35", "-2.1262808042566983e+37", "-3.633933319804961e+26", "1.994799038429618e-20", "-2.213649056526128e-20", "5.804553330227151e+18", "-1.1102256003425737e-08", "3.4474488973134508e+28", "7.075994509464074e-31", "-221.03021240234375", "-0.46708783507347107", "-882774835200.0", "-1.314892368795464e-33", "-27075972.0", "8.840282810118716e+36", "12717.6513671875", "2.4673985230526985e-26", "-4.338609111529685e+19", "5.570030892727319e-30", "88869192.0", "4.939710673329554e-23", "1.1226941432528896e+16", "-0.005955928470939398", "9.225915641024723e-26", "-6.120123852149106e-10", "9.565449510218845e-32", "3.305995727515407e+25", "-1.0628923481918913e-14", "-2.4622773019168545e+27", "-7.476595461060464e-31", "-2.244580289778753e-34", "-8.429307039099654e+33", "-0.00011082636046921834", "-945398080.0", "2.0802021903311375e-33", "7.37017413345634e-27", "-3.9567384136166312e+28", "-2.106757007478247e+38", "1.6809641285093132e+28", "45982597120.0", "-4.097621917724609", "4.095813170136498e-32", "-3.961966332912052e-24", "-5.108898076028211e+33", "6.6873221586257215e-34", "-4.9778996664095745e-24", "3402938187776.0", "5.513662131306774e-07", "18845804.0", "2.338447960663449e-26", "-5.426506106530179e-37", "2.1550868694578031e-16", "-3.455127243456512e+16", "70653277372416.0", "-1090585.125", "-1.0288173095687358e-22", "-4.975556899718464e+26", "0.00011524993897182867", "1.4817524131644165e-26", "1.6036842891150333e+38", "-6.091251622326497e-16", "1.835089461615679e-37", "4.0503461919448534e+23", "2.8296372975595954e+38", "-972.6044921875", "-2.0847733139817137e-07", "-1.4695179437490401e+21", "-3.4957061253928845e+19", "-6105318022971392.0", "3.316457195978728e-06", "-214422432.0", "1.3083727677665191e-11", "-1.5870914770434624e+22", "-2.2437688690868224e+16", "-7115891200.0", "2.7336820588925967e+32", "-240393408.0", "-9.30836507533737e-34", "-8.92832578812248e+17", "210661490688.0", "-2.605156806041518e-28", "-7.827731681379215e+36", "2.4502348731028434e+36", "-1585410.375", "-44.22627639770508", "-2.638699368578196e-20", "-9.900197581667831e+30", "-4.4683362213513506e-29", "1.225024096919436e-12", "-7120416145408.0", "2.758432267827148e-22", "1.8853219105921222e-15", "-8.897933381618902e-20", "-2.54825171380757e-11", "-3300278528.0", "3.1339920939380233e+29", "-33228410880.0", "-6.648816306254311e+35", "1.758771121217254e-31", "-1.693533024757994e-17", "3.927637913723137e+36", "1.9234017269039025e+20", "-1.2528377413662791e-30", "2.4848869560562475e-18", "-4.410714415231665e-12", "-1.9421820279663315e-18", "1.0275417413408151e+23", "1.0844840526033715e+18", "-3.003082036972046", "5.5879488536358934e-11", "2.566062121583068e+21", "-2.053689723400707e-32", "4.1681008687913246e-13", "4.714521408081055", "3.0499633418343547e+18", "-8.182433913234868e-19", "0.0005289965192787349", "-1.502715866904755e-28", "-6.031865131074772e-34", "-1.1816982532334239e-23", "-3.02534397400371e+34", "1.021100220807885e-32", "1.7022688597254088e-12", "7.305114882251162e+16", "2.7224414293486382e-21", "-2.121991193870798e-10", "-2.769828704157696e+17", "458986520576.0", "-7.707280788996128e+35", "1.0756577069505614e-18", "393130835968.0", "2.93762875136494e-10", "-2.3168414680068024e+20", "-3.407242433363497e+22", "-2.8876562840826896e-16", "-39.5981330871582", "-7.114652839523123e+16", "-3.044103192472464e+36", "-4.909479073278433e-12", "-205.2560272216797", "-0.010055993683636189", "2.5524936745475852e+29", "5.83581257471112e-18", "0.008448667824268341", "-2581.453857421875", "-4.155969159839064e-23", "1.8330170238882374e+35", "3.2229032888403466e-18", "2.769244400262858e-27", "2.9687395095825195", "4.305176724353784e+32", "2.249586543712212e-07", "2.9701310268137604e-05", "-20431.603515625", "4.999751930128152e-17", "4529.515625", "4.310627991799265e-05", "1.4269049586346227e-07", "155948534464512.0", "-6760790698754048.0", "-97081586876416.0", "4.904866912625039e-08", "-9.952959335676182e+30", "-1.2047543375856404e-18", "-6.151696343689659e-26", "-317.6865234375", "-1.4111867720252928e-18", "4.1076557410121623e-26", "-1.9947760624273254e-31", "-1.4921728939440357e-37", "-5.412749537423523e-23", "-420436480.0", "-1.556067980650358e+22", "-1.6096199319756997e+19", "1.0851636474280023e-36", "-20.272502899169922", "382.1629638671875", "-4.53458402415072e-22", "-2.731421488988417e-08", "-3032.517578125", "-3.102804183959961", "-2.362981632586525e-07", "-5825565184.0", "-1.0345330590336009e+21", "-1.3239828149380628e-05", "2.833047496075799e+25", "0.0056924535892903805", "1.7373342348081522e-13", "-3.9259709188404675e+18", "-2.4818639748768357e-16", "5881384.0", "3.035953355379206e-08", "3.691031182720658e+37", "-1015642.3125", "5737808920576.0", "NaN", "-36151595368448.0", "42064460906496.0", "5.648423352308529e-13", "-1.5149051717927575e-12", "1.2163269592760012e-23", "1.3623078448680635e+25", "9.477607818528017e-17", "-1.786438225538471e-14", "-12680.734375", "-1784593.75", "-2.395103029800426e-29", "2.5686357783135558e-36", "2.4845298251861248e-29", "-265867.78125", "2.7344722241393787e+20", "3.1285091499223967e+38", "1.1255227266415268e-14", "1.5683421763968958e+19", "-42179358720.0", "NaN", "-2.6331249480755813e-15", "-1.0408122881851975e+28", "1977258213376.0", "1.54788958336503e+18", "-8.613713933620494e+27", "-5600450.0", "6.114618268854293e-36", "-31190637608960.0", "-1.3752392190035093e-17", "4.644967330855901e-25", "-9.010900528714189e-31", "-2.3705336453881492e-26", "7.994377504654193e+35", "-0.018960127606987953", "-1.2740918023003396e+26", "4.363386042728016e-15", "1.154107209799804e-07", "-4.522943848777948e+23", "-4.634968170631651e+27", "-0.00015681108925491571", "-3.9557062486705874e-11", "-9.485622047476113e+23", "-0.008000786416232586", "-6.188335491327593e+35", "9.627326703682245e+30", "-4.645493390853517e-05", "13.345266342163086", "2.552186914923787e+38", "7.885588712574833e-28", "7.404288846082764e-22", "-46601213050880.0", "-9.019498472748189e-13", "-0.013458721339702606", "-968.9133911132812", "1.6413338244092301e-06", "-5.886790752670843e+17", "-1.252638867299538e-06", "7.448139740517945e-07", "1.111701436233854e+30", "-1.654045781759361e+18", "-3.198223700909583e+32", "6.650226203053222e-11", "1.7890280899860665e-18", "914973952.0", "-6.6639184760209934e-21", "-60169785344.0", "-3.370589077148209e+29", "-6.243951952197985e-22", "-0.015449498780071735", "1.7224958050358803e+38", "-1.2784562508379305e-28", "-1.5925594176735558e-09", "1.8836839166127248e+33", "3.757082689254772e+19", "-1.1013432417444516e+22", "-8.444129109252825e+24", "-1.9833362204857386e+35", "4.355550863224066e-37", "0.0029201614670455456", "1.7896439383616966e+21", "-109.63069152832031", "3.7082573135915107e-26", "-4.677436996568831e+31", "-2.7731933139360923e-18", "-2.206964211382001e-08", "5.260925887621796e-20", "1.2823835557551088e+22", "-2.2797849888329053e+36", "0.007543766405433416", "5455287808.0", "-1.3586582525704216e-08", "-3.065338985515115e+24", "7.150525107135763e-06", "1.1905986750041931e-24", "0.0004703689191956073", "-1.1730675458787587e-11", "-1.0711885126410027e-34", "-1419550654464.0", "4.251590081725859e+23", "1.5170047707924625e+22", "-2.575794724180014e-06", "1.1730798248149153e+36", "2.1217269213051274e+24", "-7.520548686016579e-36", "1.5983108464140028e-18", "-1926.71337890625", "1.4049733598753278e-09", "8.940393907354128e-32", "-2.104481744424902e-08", "-9804.419921875", "1.1990052371402271e-05", "-1.417163361970628e-34", "2.0453848894047204e+20", "9.637490415842064e-18", "-1.2216639921902583e-09", "-1.4656401061500661e-34", "-4.294475794247745e+35", "8.7533740021975e-16", "-2.57040012385214e+23", "1.0595288611532108e-20", "-1.9411342325192926e-11", "-1999168640.0", "6.506755194355712e+17", "-5.317564509360589e-35", "-1.6775229083373673e+38", "-3.620749679110031e-21", "1.0168570085364258e+34", "5.7398730859858915e-05", "-56865832.0", "-4.9631503556350726e-29", "-4.950657939151171e-11", "-1.2123816474067932e+29", "-2.126478811280454e+36", "2.4285681491774858e-21", "-2.5584897994995117", "5.386907770593935e-35", "-2.8240124432403245e-29", "9.111284587346495e-21", "1.1628564522700735e+30", "-1.105886918904906e+26", "-5355612405760.0", "1.36503474126712e+28", "-0.0005626980564557016", "6.908407765120195e-13", "2.1861402884442279e-16", "0.013176819309592247", "-2.1024043398822935e-33", "-3.3488061361842673e+38", "6.342537492991668e-29", "16124.43359375", "4.7580900087255e+33", "2.982571112716742e+27", "-2.9665974246363785e+20", "4.4558335503097624e-05", "83804312.0", "-1.856085769125272e+37", "34866074746880.0", "-1.385218328930904e-10", "3.775264545424761e-09", "-4.10362867942681e-31", "1981776920576.0", "1.4022403521322875e-35", "-2.6829186346676908e-20", "1.263124216075627e+36", "-9.983688122804726e+35", "-2.873286294055226e+23", "4.5480233783596954e-29", "1.2649966479022615e-21", "-6.332885771342944e-09", "-4.5531139007269694e-33", "3.446676277718552e-11", "2.9140204828549173e+33", "257728991723520.0", "NaN", "-2.5886715213356966e-36", "-5.1192761272753854e-15", "-4.0237572613279854e-29", "-0.0007570541929453611", "1.0303629868939465e-27", "6.96925853844732e-05", "-4.138532797544261e+23", "-1.3564926702078372e+26", "-8.466501587078518e+22", "1.4270701775882097e-27", "-1.9980043979587584e+16", "-6.810940051034373e+26", "-4.798405499071766e+31", "0.00035213789669796824", "-4.575746508755799e+31", "-3.387380260703463e+19", "3.159431255249908e+37", "-6.531763051034141e-18", "6.321516513824463", "0.007571401074528694", "-2.010101731693691e+17", "-1.0177824644296402e-17", "-347304.0", "6.096507304561198e-36", "-2.6853072477416223e+35", "-4.2382858223207055e+18", "7.68718957901001", "854590.125", "-1.0391651325395436e-21", "-6.401989605109293e-09", "1.5440539472000303e-11", "5.2049253566274546e-30", "505411305472.0", "1231813632.0", "-1.9473108501550363e+37", "2.377212908632554e+26", "-4.067182701739114e-11", "-9.977559336740011e-28", "-2.3104214617668928e+32", "-4.1154712591757476e+27", "7.124311448940268e-14", "1.145409450880458e-14", "-187341616.0", "299.4916076660156", "-0.07370814681053162", "3.8890048700413306e-17", "15918.337890625", "-7.08373117108696e-12", "-1.0056211364381733e+37", "1.2790681318523577e-37", "-2.6953559182492513e+23", "-0.028266379609704018", "-5.514888760361658e+24", "-2.702547953693021e-29", "-319163.25", "1.386962167401218e-19", "-2.512264935184267e+21", "6.368673108706609e-39", "4.286485996275069e-36", "273970592.0", "-3.3155242707124224e+16", "-1.9907841428431813e-20", "5.077026189110212e-21", "-5.497791878574372e-28", "2.489263890803007e+20", "-1.5496434981140007e-35", "-3.132086617652679e-35", "5.660796276174551e-10", "-1.4135746866505492e-23", "0.9941490888595581", "-7.276512305018672e+22", "2.0963336194079536e+20", "NaN", "-2.1588771415436996e-27", "-59587368.0", "-3.611204459001413e-24", "-2.5133722555436237e+18", "6.442630689266018e+31", "-1.5844618744791617e+20", "0.059448227286338806", "6.755718299427799e+17", "-3.7990137250224576e-20", "0.00016942739603109658", "-3.19831992720582e-33", "-5.616440072865191e+35", "0.0030963176395744085", "7.544539972826987e-25", "-4.38603275355785e+27", "-3.4532149584265426e-07", "-1.1705466931799654e+17", "5.176750050850387e-08", "6.204867431436923e-30", "1.0620595976190969e-20", "-5.340152722349769e+31", "1.907553765983024e-17", "-5.120046221257991e+34", "-7.2533213565075e+21", "-82568400.0", "-1.4453484007455438e-17", "-1.621009866629115e-36", "4.991442735438934e-18", "-4.723657862601826e-20", "-1.849288535404708e+17", "-175.5975799560547", "1.7275501323187362e-30", "-5.2431888682967926e-17", "2.5243167212057637e-35", "2.3691702274687542e+32", "-2.963071953597518e-13", "1.2732524577174617e-31", "7257.111328125", "0.00010901079804170877", "-6.480276363568423e-36", "-14399356928.0", "1.092253049894929e+29", "-1672.3563232421875", "2.7724428402697504e-28", "-1.6476944784687233e+33", "-2.1852951053735183e+36", "2.386083970111753e+31", "8.263353480930834e-25", "1.1610623573687917e-09", "1595386757120.0", "1.6686201777401342e-14", "-1.7209725172111628e-34", "-9.223313741101033e-23", "-2.005311028341339e-09", "26.12026023864746", "1.5604652953265225e+22", "-8.05075339618648e-15", "4.676526660315089e+29", "9.425918918523415e-32", "2.611307263318919e-28", "-3199.875", "5.937153974955691e-23", "4.581858092933544e-08", "3.602011321419127e+25", "-2246.1005859375", "6856.490234375", "481446494208.0", "84460584960.0", "2.9130911827087402", "5.455699715390699e-19", "-6.1768590806362646e-27", "55220240384.0", "-8.393363000867503e+26", "-533334778183680.0", "-1.7297476249883401e+31", "1.2371879909434114e-10", "-3.757623404051847e+37", "5.0624412170677355e+23", "1.125864184828846e+38", "-1.4034231756444694e-31", "3.8990706496822014e-11", "3.896247872546406e+16", "-1.6498386230523465e-06", "-9.41173495770359e+28", "1.2877502009051422e+31", "2.7814618555437043e-13", "3.905252084565494e+20", "-8.279673990991385e-37", "1.2592860604546417e+18", "-2.2517179382556518e-36", "8.689490789427437e-37", "-4.927327096974071e-21", "2.948569906578232e+23", "1.1249307213910886e+34", "-0.004812569357454777", "-1.5214690765130841e+21", "-1.7658932768593351e+22", "55.66487503051758", "-1.309335420298634e+30", "9.397957935600795e-18", "1.2138566149400533e+21", "4.2401853798244567e-38", "1.3369448264878824e-33", "-6.300484619157947e+36", "549886642618368.0", "-8.909666271693598e-17", "-1.5561162907546042e-24", "2.464125211547672e-34", "37499.3671875", "-8.469925701977275e-23", "-1.8496704066573082e+31", "7.079167517076712e-06", "5.697952057998293e+25", "-0.0007727334159426391", "9.55179270325476e+26", "1.0166270239926246e-33", "3.998099299205244e-39", "78.41581726074219", "3.7082551905232908e+31", "-1.3098283379458048e+16", "3.3632382414809744e+28", "-2.2892710235928662e+23", "-150071803904.0", "-1.1524995898806546e-27", "7.919430627771618e-13", "-1.228291316741743e+37", "1.0166590659610453e-11", "2.0128449423524627e+23", "-8.359468658897115e-19", "2.131437916884229e-26", "-6.1367671017545444e-24", "-1.6169812061323264e+16", "7.980246543884277", "-101106.6953125", "-4.326538398002053e+18", "1.1414095833833476e+23", "1235048.125", "-3572175732736.0", "-2.7317531787239995e-33", "-1.725745273071408e-25", "-1.3411633261209636e-28", "-1.298061355158704e-21", "-1.810356031750615e-11", "-7.49034370528534e-05", "1.1553450230858557e+28", "-3.6377645182116864e-25", "8.665882250999103e-36", "-1.1576930525336593e-09", "-3.37600564649204e+23", "-4.858081771955242e-39", "4.619895697780914e-35", "-5.312245739313017e-24", "-6.472124384861811e-35", "1.7078923461258537e+21", "-8.004288056467899e+34", "-1.298989992041524e-14", "-4.707868237825417e-37", "1.991178045042022e+30", "-3.43665436966855e-29", "2.8559007522569762e+26", "-2.1528343178385747e-20", "1.587778709509144e-13", "8.140757714695972e+36", "-1.411659384600897e+37", "-4.979515845189146e-25", "1.5319068178884376e+26", "-356272536813568.0", "-2.190012767228379e+26", "2.9873877754135416e+30", "0.0002511315979063511", "-4.388963946365749e-31", "1.6107601472774731e-19", "-4.4615064435853755e-35", "-0.000440185860497877", "-4047.090576171875", "1119.1427001953125", "107806712.0", "-40.7833366394043", "-1.1446826559541323e-12", "-2.0619364697222915e+25", "-3.2379117200698428e+31", "-26945.091796875", "1.7908218582097374e-13", "-2.6248386899334042e+36", "7.429469525535095e+25", "-6.958607409908446e-14", "-3.114218683988509e-19", "2.619290997221317e-20", "-5.42834846614245e+26", "3.0223947056541167e+34", "2.1046004023729525e-13", "1.0956920490914094e+26", "2.1287428440566812e-20", "-4.640900752889487e-25", "NaN", "1.8608856175234154e-33", "4269644974129152.0", "6.852166945826854e-13", "-1.7106650835684403e+26", "-2.211979487228013e+34", "-6.782370860491937e-07", "20.284042358398438", "105126535168.0", "-6.258121814932058e-25", "1.9225669233707832e-13", "-1.5539550860998902e+32", "-1.0358013863904136e-16", "32.04709243774414", "0.04289019852876663", "-1.5809769084453308e-35", "-8.947378050129942e+28", "-2.3371382556769813e+36", "-2.4101478629745543e-05", "-24026076.0", "4.37906275565568e+17", "-1239429120.0", "2.9246187784966268e-24", "-1.3622913561106564e-12", "-316155193458688.0", "2.8870269323664633e-36", "-4.895086927193047e+17", "-9.957481728421396e+17", "5.22790851140891e+23", "2.8852470412843445e-10", "1.6101826248861722e+25", "1216689878335488.0", "5.025899387301944e-25", "-9.480191780461897e-28", "-6065847810392064.0", "6.118739959148911e-10", "-4184235264.0", "-1.788808680328587e-18", "1.958691164816173e-08", "7.627635289681701e+28", "-8.11925127329798e-25", "-8.644402486006075e-24", "-31.407821655273438", "-0.002348260022699833", "3.4174924656010096e+37", "-4.611475199142756e+35", "-1.040328903918591e+31", "49134288.0", "1.374035917611812e+33", "-938814016.0", "-5.5092978556818566e+29", "NaN", "-470569856.0", "-4.198508262634277", "40152620.0", "-4.932008823814723e+33", "-2.508263042573417e+33", "-5.4362912539567516e-27", "2.840534629006882e+34", "-1.800921472496983e-13", "-1.3652964087789484e-15", "9.850313017256837e+17", "-3172637312286720.0", "174257980047360.0", "-2.866299523575887e-14", "-7.906171643108014e-32", "-6.216666179233166e-14", "458.6827697753906", "-1.6565056645908081e-23", "-4.946766084178598e-37", "-1.1432711548298169e-11", "1.1617866397766436e-25", "-1.803047775071963e+37", "1.9287382414293753e+32", "-19524.693359375", "-1.4247618058114718e-36", "-2.926009821167616e+16", "-2.4238308896190067e+32", "-1.5853619969284906e+37", "9.787508058273952e+24", "5.0892302038864164e-27", "1.6508305474146256e+38", "-3.977757710173575e-17", "6.7223583016217246e-21", "-6.250440492294729e-05", "-5.664336983144214e+37", "-1057885650944.0", "-4.5719241111916426e-26", "2.8843637079866767e+22", "0.005600867327302694", "-4.2730930088506094e-35", "5.15347346663475e-06", "1.376319325597987e-20", "-9.500056696040775e-39", "-1.5025362138955962e+23", "2.317889741902876e+37", "7.14195220541843e-27", "-1.3646102477171684e-12", "-578365.125", "8727648.0", "-1.927186985994922e-06", "-8.887945935553148e-25", "1.9127845141131417e-11", "-0.09814183413982391", "-4.751728695377307e+36", "-9.59029730629446e-13", "-1.3788751001787792e+30", "2.945652647677847e+25", "1.2026291244763539e-17", "1.0144485679007078e-21", "9.191291729993868e-15", "564366272.0", "-396936128.0", "-2.1108027712985366e-23", "3.284280128672962e-14", "2.693676353199086e-37", "-13015051.0", "2.0278616440693352e+25", "-5.113592114734602e+27", "-30235700.0", "6.146268710777651e+16", "6.091377495565598e-30", "2.4461197987826923e+26", "83116.8203125", "0.0013124024262651801", "1.592227307291725e+19", "5.012415693075063e-19", "17149494034432.0", "7.324548365176951e+24", "1.1465522693118218e-30", "37359.0390625", "1.2786724314319493e-20", "4.285918481607405e-36", "-0.00010321131412638351", "-2.2849855261050875e-36", "1.0243889752018082e+31", "-1.0471571061859575e+20", "45139.35546875", "-7.787735833663671e-38", "1.2937609188449443e-14", "-442385.09375", "-1432786.0", "61124004.0", "-249444.703125", "-2548.111083984375", "-4.090428781097879e-37", "-1.6316315330232768e-31", "-4.0521972671995824e-38", "6.447492806108774e+16", "-4.6724260016617336e-08", "2.3875233311834483e-17", "-2.675482619443304e-27", "-222877529931776.0", "5.522951887493299e-13", "-5.644643780167158e-11", "1.6355784282495733e-07", "0.9558175802230835", "-1.4646287471215129e-37", "-5.025981298929197e-34", "-2.3003474647467973e+24", "-0.05290696397423744", "-6.475370334742494e+32", "54390688.0", "-42275172352.0", "133394931384320.0", "-7.454294569983839e-33", "-4.504525535843764e+33", "-2.9277859946756747e+28", "-3.8030434082475953e+34", "-5.1159822162017334e+20", "-8.306565574346705e+29", "-888432373006336.0", "5.808707224375351e+28", "-1.6841444281908216e-14", "3.9442959224075663e+28", "1.5379447920623773e+21", "-4143473330487296.0", "1.8257872605118097e+20", "1.6308442596368877e-08", "1.7976957117282262e-21", "2.0084951308007427e+20", "-1.0382741758770884e+35", "2.284925213831752e-31", "2.9483483739640878e-28", "3.8300818072656284e-10", "-2.2842459329713313e-21", "-2.3908594477980254e-26", "1.9585037591696164e-07", "3.3475958671873306e+31", "34984156.0", "2.2774630792365969e-10", "929851359887360.0", "-3.7665604245106196e+18", "-9.507132545556499e+27", "2.3156613640631717e-19", "-3.7273332645046774e-26", "-4.121582577700415e-34", "14868619.0", "-2.340628188310015e-33", "3.0393854649550457e-30", "3.189349832257766e-16", "-4.526388973762127e-18", "964.6719360351562", "7.636073751078967e-34", "7.030079557180842e-27", "6373249357185024.0", "2.802877558955724e-13", "-1.138572285633516e-14", "-1.3236332961241715e-05", "-3.3536651117977844e+25", "-1.1763438888693176e-18", "-1.2779109863669004e-26", "0.00043535654549486935", "3.1802301851939154e-26", "8.417239521000879e+24", "1.2132614295679562e-36", "127355609088.0", "4.381172244149995e-25", "-6.649374718303622e-20", "-54166214934528.0", "-1.2388676774954571e-29", "-2.2851689607960986e+17", "2.4210050462228752e-24", "2.4298225573822464e+16", "7045771689984.0", "-2.94049662563153e+31", "2.6695171272023034e+29", "-27623.46484375", "-3.58123941168742e-07", "-4.1895353361007086e-16", "2.4342485811014973e+27", "-1.8693991899490356", "-7.098813031747122e+33", "2.4524032227241723e+31", "7.45976638101835e+18", "4.047572379224938e+31", "2.818355725441014e+19", "109841.921875", "-3.9409308566102564e+31", "2.6798160923505634e+34", "-2.227171502777736e-10", "-4.65166545019452e-24", "2.1870828444733176e-13", "98596535926784.0", "-8.260978885021848e+34", "-1.8756122380715743e+33", "-2.2587166562720472e-32", "1.2482429240207943e-17", "1.3027168130577004e-24", "8.59978717926424e-06", "-1.8261733885083231e-06", "1.9345091919175032e-27", "1.106002172532001e+35", "-9.363996755075434e+23", "4.1912038327830613e-39", "-1.1099772057836287e+30", "2.2682343114865944e-05", "2.930182135272571e-33", "-1.492254153836396e-15", "1.2637550307055655e+36", "-8.534566717190113e+29", "-6.922220018585035e+34", "1.0027791749462998e+27", "1.3597410711566287e+19", "-8.113631396720231e-30", "3.4876285141664277e-16", "-1.1220776095115712e-20", "0.004094853065907955", "9.157015694750276e-15", "-1.187924457277096e+18", "-43270459392.0", "4117318.5", "1.506539851017255e+35", "2.2141769397364682e+36", "-4.3485169278733165e-35", "-7.137340642356416e+36", "1.5041013789721057e+31", "4.547551608395672e-33", "-0.18962858617305756", "9.756255959163762e+22", "-0.10562566667795181", "-3.839099346583319e-34", "-0.0005544163868762553", "430067552.0", "2.9830450785084395e-06", "-11.890093803405762", "4.523555755615234", "8.424856901001476e-08", "-2.1083029366764094e-07", "707900.9375", "540.571044921875", "2.575233644720715e-32", "9.561948469566107e-29", "0.00014765161904506385", "-3.2780846481728076e-08", "0.012915167026221752", "-3.265627955885232e-20", "3.939958332921378e-05", "-3.889016740940328e+30", "2.002347609620068e-30", "6.414529420151095e+29", "-4.3498927088370635e+24", "-1.5836194066594211e-31", "2.3065666995463997e-38", "-56975.109375", "2.3109006708616887e-34", "-7.456427439082701e+17", "2441616698638336.0", "-4.466821854172912e+29", "1016839744.0", "1.496173242093076e+35", "1.009369301465457e-32", "2.9795419417448033e-15", "6.211983117134038e+32", "-0.0009676786721684039", "4.468644595837368e-31", "1.4175353259777276e+27", "6.119236954479264e+25", "2.2233661072462576e+37", "-1.8223044364490197e+31", "20843.2421875", "4.0935316247825426e-10", "9.681278050972467e+16", "-126727690911744.0", "-79490654208.0", "1.611831080979679e-34", "4.714065998192171e+30", "-4.6312143808791006e-07", "0.02058219164609909", "-3.499538279038976e+16", "-1.0949747775157448e-08", "4.236984163042204e-14", "-9.714418851353637e-31", "1.5375991509614592e+16", "79032328192.0", "1.1864541378588203e-23", "-2.865038339905368e-08", "-3.736535299940861e-18", "592676.75", "3.44189998911146e-33", "7.491814273636311e+32", "-1.0549027706377257e+32", "-7.33493471965063e-23", "7.061912369985005e+30", "-5.798165925707258e-33", "8.897300146529057e-15", "-2.279323962284252e-05", "6.063939689473902e-29", "-49.60866928100586", "-7.358142768438937e+24", "2.0898349507598808e+24", "-7.698100876936194e-28", "-1.4368482853259662e-37", "-2.975328533896815e-12", "1.0496157219981964e-24", "-1.139626804383398e-12", "-2.8797075466084145e-32", "-1.4270134461599384e-17", "-2.8172264599977984e+16", "-8.995881229062301e-15", "2.4054852603810546e+38", "6.695916761868563e+24", "5.927762152005537e-25", "1.3568551667765897e+33", "-67681.515625", "-2.638271937288401e+22", "-2176976158720.0", "4.190346350969188e+22", "9.171697189372559e+18", "0.0002845428534783423", "4.843446706761771e-25", "-3.483269210846629e-06", "14838363.0", "7.303813071916597e+24", "-1.0976853217823949e+17", "-9.532228905696229e-36", "57.06584167480469", "1.8435832921273375e-38", "4.1670534117594235e+18", "3.384802710169102e-20", "5.7421112550282546e-36", "4.61059954659504e-08", "-1.1440408063714552e+30", "-1.3638451299264203e+30", "-1.9687290974745892e+34", "-63040671744.0", "-642672951296.0", "-4.065724628985675e-31", "-2.0256537078849028e-35", "4.432209552378708e+29", "-191.03724670410156", "1.589696125847695e-06", "1.3887907701975494e+33", "-1.175240766016979e+18", "-5.599827268554236e-09", "6.832150134267884e-14", "1.1236384155293182e-36", "-1.129407394273585e-07", "4.743111742807081e+18", "-0.00019505363889038563", "126239.421875", "-3.433810793554968e-15", "-3.772234049392864e-05", "4.3688112860365334e+24", "2.7227445238798746e+17", "-1.7069881369932072e-37", "-1.7177284741220995e-25", "7058638090797056.0", "5.101264613149395e+28", "-6.217534428320371e-25", "-9.87015447423268e-38", "-3.169668649379279e+38", "1.2696618037936128e+16", "9.969965321943164e-05", "-63878652.0", "5.833849893436392e-35", "-6.072540372770163e-07", "1.8433244533826509e+21", "5.606804950875066e-12", "2.4401158263085577e+27", "-4.846495151519775", "24320.05859375", "72504097898496.0", "2.4569589022710593e-20", "17237.98046875", "-7301769461760.0", "-7.570245382266581e+35", "6.233273897038316e-10", "-9.860405095260134e-26", "6.66270112991333", "-2.0971451469501825e+20", "2.2951712958570875e-14", "3.4236244993777924e+27", "-4.431101596757969e-10", "1.8226588369177338e+37", "7.73482156546379e-07", "1.5894945387095767e-27", "-4.094558801092747e-26", "-1.9381387848352196e-34", "1750287085731840.0", "-7.61389444560748e-37", "1879058153472.0", "-0.0005060975672677159", "-2.041219808643749e-26", "-2.13287803574376e+26", "-2990962074714112.0", "-7.242970772137049e+30", "0.002401947043836117", "-2.053095508075788e+32", "-6.287727641351082e+22", "3.1210513774110327e-12", "122471.375", "-34547.61328125", "-6231227392.0", "1911.138671875", "387492256.0", "-3.0494409962802848e-33", "2226262.25", "-2.9433513191179372e-06", "1.5210704178740693e+23", "-4.141437338501376e-32", "-5.461666674477783e-09", "-1.6112704994818264e+28", "2.513904271259372e+30", "7.010061564598398e-25", "-9.615185760023726e-24", "-3382266822656.0", "5.16052289129633e-30", "-5.525832097376708e-25", "-3.791441786764736e+37", "5.2030139447041e-25", "4.1676986484162626e-07", "-1.4521787938814867e-10", "5.811446832395028e-19", "4.542652831673877e-08", "-2.1600006035377764e-18", "-7.468109474064328e+37", "201.5665740966797", "4.469562629465812e-26", "-0.018534386530518532", "-4.7418241705701684e-20", "-2.5096095925083365e+31", "-5.201358975763209e+36", "9.47975639173479e-21", "-1.6725058454327123e-18", "5.86279406144933e-30", "2.318710300102254e-19", "1.7293042332008977e-29", "4.604035880404113e+20", "-9.146437668165435e-23", "2.1192054021165888e-24", "6.389609681115651e+29", "1.1762368705789657e-24", "1.6322504027661136e+33", "1.2328245247772429e-05", "-216815058944.0", "-4261088854016.0", "1.7789374099773554e-14", "1.692323225999104e-10", "7899486923784192.0", "575.3380126953125", "-4.48359508446311e-22", "NaN", "0.014625973999500275", "-2884726161408.0", "-1.5770203087495287e+29", "-6.151279953595155e+32", "35.06393051147461", "7433648640.0", "-4.437455572769977e-06", "-7.869386594542672e-22", "6.086149241550878e+37", "2.773248725498799e+35", "-1.295094449460521e-07", "508615262208.0", "0.08531248569488525", "6.529975616788995e+24", "3.9257826877358544e-33", "2.93439092585975e+18", "-1.0117433934627494e-22", "1.8925233880007128e+24", "300643348643840.0", "3.83943915362338e+21", "2.2037528706995022e-38", "-5.642358666297344e+17", "-3.729679419209736e-35", "0.007299773395061493", "-2.1073283278383315e-05", "3.040492387497052e-24", "4.6687167923664674e-05", "-1.0635745482425759e-37", "-9.448160164052426e+33", "-1.6198693560757285e+25", "3.344895281964491e+38", "4.5989514178547537e-38", "-1.695316953487236e-08", "112759.203125", "5.110063301058469e-29", "4.107121543903136e-13", "-1665.97216796875", "-0.0013646342558786273", "5.422439298789772e-13", "-2.950262086617213e+31", "-5.5603601124580476e-34", "3.9136916895296976e+21", "-2.200740878833431e-21", "1.9039845938095823e-05", "-8.956680675458358e-33", "-2.823252800664558e+38", "-1.7267617745819725e-23", "-2.5741650285203213e-34", "14783889.0", "-1.7597441578250456e-29", "0.00011067955347243696", "2.482724539731862e-06", "-1.878791921843502e-15", "-1.8995623986304948e-28", "2.4246293609443458e+32", "-2.0141135317958656e+16", "-9.560787717077801e-10", "NaN", "-32148010.0", "765407330304.0", "0.0005487093003466725", "1.6704083997426134e-19", "2.5511531930609254e+17", "530449248.0", "-1.4824757821815943e+35", "-2.5345010593975947e+37", "-1.1502547973370037e-32", "-8.205850556601915e-35", "436.973388671875", "-265603797483520.0", "-77130.5", "-6.583162191639518e+24", "-2.6712114742366566e-09", "2.725789409944677e+26", "-2.3887334642548736e+16", "-641377.625", "276695456.0", "-1.9154220910435978e-36", "960135296.0", "2924423.0", "2.0757560268905305e-38", "1.932579926207494e+36", "1.8902062844071636e+28", "-4.5806716981185243e+21", "6.78263383390236e+21", "-8.276792736193232e-22", "16932596736.0", "9.153017788143814e-10", "-1.0542374126836734e+34", "-3.1032616182349824e+16", "1.830157970861776e+28", "-4.4182707622728024e-13", "-43326804.0", "-0.00022973777959123254", "-2.353521656871727e-31", "-6.458002128600008e+20", "-3.40555739164472e-09", "-2.970025199717198e+33", "3.168281238343516e+18", "246575.140625", "1.7801425039917056e+16", "1.0450267471033003e-32", "-279639648.0", "5.7616088203176127e+26", "-3.0013285639487187e+32", "1.3726017027456495e-33", "-7.4141790620205025e-31", "5994.4814453125", "1.7929557619731258e-35", "2.3839380381195063e+26", "8.784881155854407e-10", "3.221069093380193e-20", "7.28441814898454e+21", "34847072256.0", "4.1464011044101134e-13", "-2.604496354128331e+34", "-3.695606757326848e+16", "-2.2772720324401672e+21", "2.1283614959235802e+21", "1.639717503359904e-34", "1.1177145581699072e+16", "2.058932740416674e-27", "8.257115694312867e-13", "1.306180370027471e-14", "4.206373557597067e+33", "674397509648384.0", "-7.13594417902641e-05", "-1.4187165325178777e-20", "-0.0432731993496418", "-3.5866604299858483e-17", "-1.435682298979494e-17", "-1.1189609329401948e+20", "-5.114500101042145e-32", "8.397651606213696e-13", "157327.171875", "127630665842688.0", "4.4734571247317945e-07", "6.872891036984446e+21", "2.000754668187904e+33", "-1.4365657221888535e-23", "1.5573693706674204e+30", "1.495143535339126e-23", "-1.7964631575845854e-23", "9.339773205569172e+26", "-1.4561709896916533e+19", "2.0405519496335955e-09", "3.0544668223772254e-31", "-30705600.0", "8.49158751535622e+28", "1.0366230609702912e+35", "-2.0407131160027348e-05", "-3.692090522850558e-37", "-5.5001070364824045e-08", "-6.2564579044320985e-18", "-3.449015153561232e-31", "4.052148180242837e-16", "-2.7739064075794164e-36", "-2.5045654399071898e-30", "-1.1556273564152866e-21", "0.5305919051170349", "3.501274477820799e-37", "-333403456.0", "1.5601712442007552e+37", "-4.728068560750948e-16", "16633152143360.0", "6.164757661608823e-25", "-3.884007640350326e-16", "-3.348985699490595e+18", "-4.235168095956141e-30", "6.989544655336242e+25", "4.3075273434328724e-32", "-3.2781504669008477e-32", "4.3759953993749434e-10", "-5.506223480463243e-24", "6.7030539105394915e-12", "1.0448359711387636e+36", "-5.236703028351393e+27", "-4258.5380859375", "1.0463882075991586e-29", "6.519111299006234e-36", "368400992.0", "-5.8992671828453615e+35", "-4.9655131348389295e-34", "99.71022033691406", "-5.618855662844014e-20", "-238027456643072.0", "-1.2237943116716214e-33", "-2.3058809355967095e-18", "3.861643893543487e-09", "-476443049984.0", "963800128.0", "-8.542555021115706e-25", "6.602400617339299e-07", "464556352.0", "-1.3605323381355315e+17", "-1.435938308591604e-29", "-4.3143975436761403e-29", "-2.026550847666527e-26", "-4.77048928627668e+28", "1.0175463554473019e+34", "-6.432911821635584e+16", "3.4814522732970687e+33", "-7.438900840803231e-15", "2758.400390625", "-1.068191325556275e-24", "-1440629473148928.0", "6.618566557334843e-17", "-24.065826416015625", "1.2793284440704156e-06", "-5.194650250067711e+20", "-33583712.0", "-7.034848556026763e+36", "3.6513283716010925e-12", "-2.4982229502369063e+25", "8.383644924084109e+21", "7391650816.0", "-4.165686443928191e+18", "-4255344128.0", "5.3840090391010946e+23", "4.9804865755108754e-26", "-4.353598013684183e-36", "-1.0828827043951605e-06", "0.00016663353017065674", "-0.00012748545850627124", "-6.462138799206957e+18", "4.5324670665952693e-13", "-9.239605472865717e-14", "5782802486263808.0", "1.7060385480828692e-29", "-7.9785364666524e-35", "-9.739062855319756e-31", "2.145212013772091e-26", "3.4137425687776734e+23", "2.965855128659914e-34", "0.0032399939373135567", "6.730846990876671e-08", "-4.326054039116684e+23", "1.234115145688254e-14", "-6.26916630286532e-28", "-5.986357814636016e-29", "6.201158822317261e+16", "7.78583246694558e-23", "-1.7292860276526075e+20", "4.1786805788963475e-21", "585808512.0", "-5.789287180772615e-19", "-1.9240311678581237e+32", "1.580345912563561e+30", "-2.108300488680436e-35", "6.795138865208739e+27", "-1.2157410663727104e+16", "-476.56805419921875", "1.5943017734035967e-23", "0.00036297764745540917", "-9.672097095345197e+31", "94128.7265625", "3.334992192651412e+38", "1.5945003290623522e-20", "-86777.5390625", "3816370.0", "-1.1238811958257825e+35", "0.016961831599473953", "-7.293671830246887e+23", "-5.559278403154946e-15", "-1.187668019486665e-10", "-3.6215826657388466e-22", "7.215405054974421e-28", "-4.4019316274371406e+29", "-3.068762168823014e-07", "197821632.0", "5.404205736211251e+16", "-4554.7119140625", "3.5991366651700565e-27", "-3.597583897452239e-10", "1.0489489795872942e-05", "4.40531646478222e-37", "-6.571402171556014e+34", "-7.194596617016924e+37", "-4043860.5", "4.670098509296228e-12", "7.101003135110903e-14", "348992.71875", "6.889098728585938e+28", "-703965429760.0", "-4.006831454343589e-17", "-1.1390080803418263e+34", "24432647077888.0", "5563.484375", "1.2391687675972207e-09", "-9.59220242158e-30", "-29.421146392822266", "-0.06079457327723503", "-2.0175189141546913e-25", "65915097088.0", "40469052522496.0", "-1.8695076536804885e+37", "8.843475598877942e-36", "3.1287054257091484e-38", "9.300135145513676e-30", "9.633705388978342e-08", "-3.1349211150177164e-14", "6.077939859586569e+26", "1.1753985019726919e+24", "1.036994058331575e-12", "-9.997754268533273e+30", "-132894130176.0", "2.8374644822497407e+37", "9.83033905222498e-23", "7.3366994081713635e-31", "2.381305819199042e-09", "4.069916991220708e-20", "-3.312062152356562e+32", "4.337803374628613e-18", "-3.277096357544003e-17", "3.7181876417951907e-35", "1.848350704506551e-22", "3.96068528797143e+22", "3117376768.0", "136272437248.0", "7.978373176858443e+23", "1.140206758975834e-20", "1.8759681430931063e-31", "-1.307689062761473e-15", "-2.1134842573881344e+16", "0.0035300678573548794", "5.636658456180788e+22", "4.340238156938861e-14", "-5.064543762754696e-38", "6.332586602958127e+35", "2391836685500416.0", "7.904047029616216e+21", "-91494.59375", "5.0009041352439e-09", "-2.307258107742078e-33", "-5.8928862252329075e+20", "5.339177626039387e-32", "3.4760528023664215e+26", "-3.593973657711411e+19", "0.6891463994979858", "-1.213192637987991e+23", "-1.1127385091905945e-13", "-2.972558421632243e-09", "-73630.5703125", "3.4866495836504507e-25", "5.307204088809652e+18", "0.00011008369619958103", "-682782949376.0", "1.796879895155573e-18", "-8.483271540407008e-18", "-2.1882627750556074e+19", "-7.674686278896417e-17", "-6.996581903880434e-25", "-3.8000619042577455e-06", "2.0428578442780367e-19", "-4.855396085190478e-09", "-2.835470404207851e-14", "-5.060125063711539e-16", "3.9337909266718236e-34", "-6.308331623009859e-30", "4.112508908133921e+35", "-2.417963019477748e-13", "1.2821638208058338e+26", "-3.969004139190468e-39", "-2.1079674117352454e-10", "-7.506103249456852e-36", "7309180.5", "3.939822706846965e+32", "2.802053937999132e-14", "NaN", "-2.5786168109088146e-14", "-8.102924258585666e-23", "-1.3463011186719547e-33", "2340069444681728.0", "0.0007767584756948054", "1.2689710783791982e+26", "1.4985183957034983e+35", "3.148656001452203e+31", "1.6289289906954997e-33", "-7577746.5", "-2.1873421583997864e-22", "2.3275748059520967e-16", "-3.6341102817847356e-22", "-4.382197664719169e-24", "-6.472528405170735e-14", "3.6791736578629035e+32", "-4.677061557769775", "-5618278912.0", "-25226868.0", "1.1851843291499353e-12", "-5.562385853627763e-30", "-3.0116058106237367e+34", "-3.3393922585908284e+38", "1.2952668955738112e+16", "2.2617661744565316e+26", "3.645865915416556e-16", "2.9802750149656576e+16", "8.110242242262185e-19", "4.3509828331300264e-23", "8.404099882920002e-32", "2.5175273418426514", "-30867195953152.0", "-1.126190847927255e+17", "-3.9287297612633547e-22", "1.4955363017284959e-36", "NaN", "-1.3366425250735012e-20", "118871205871616.0", "-1.2015244447638228e-26", "3.1830960628402484e-29", "-6.476495286733552e-07", "-1.2151086096773642e-20", "5.4224240233466246e+23", "0.15386337041854858", "-1.2374919375879327e-27", "-3706839170547712.0", "-9.376469547734794e+17", "-1.6627749283662163e-35", "-1.0799221745647616e+16", "0.2794926166534424", "-3452.179443359375", "-1.8100494479724402e-29", "6.208198716268835e+20", "16282703364096.0", "559.2742919921875", "6.566820374615316e+30", "3.336945249201445e-13", "-3.106408789949584e+21", "-6.039529495673969e+25", "-1.3882680464815434e-20", "2811882897408.0", "3.8114788560549007e-13", "-897785280.0", "3.2014446560424403e-07", "0.0071715060621500015", "-1.768603272539257e-24", "-3.3769917351273854e-23", "4.687170977126172e+32", "-6.369309491547857e-24", "-405794.25", "1.0651345563647076e+26", "9.871718277691805e-23", "1.7950379608356855e+31", "8.119552148855291e-06", "-6.901453459451812e-16", "-2.0243837630832787e-17", "-0.09431512653827667", "-2.0239842288390433e-30", "1.5624615116394125e-05", "9421460.0", "-14418008080384.0", "2.574388346943124e-08", "-3.4491280763874295e+29", "-0.01787308230996132", "285056000.0", "-25306.001953125", "-1.596410041505955e-33", "2998996.75", "-4.1363320901974985e-36", "0.061563462018966675", "1.4418213026136177e-18", "-1.0286672394029012e-36", "122264741740544.0", "515172435230720.0", "-4.0297317017766935e+29", "0.00033614665153436363", "-8.593157760550939e+23", "186612576.0", "118792.7265625", "-3.9799639951993577e+37", "1473533952.0", "-4.899020725300539e-30", "-6.757540538739721e-21", "-2.9945191272418015e-05", "2.3854003576390894e+25", "13381139456.0", "6.917769968445878e+31", "-3.879969446299092e-37", "-11827572375552.0", "-1921381760.0", "-194.1099395751953", "-5.690897380199629e+16", "1.775190057570116e+21", "61.68851852416992", "-4.175207389675905e-20", "2.6285969729829946e-19", "-6.736698702155379e-18", "-609462234644480.0", "-2.5794340621776435e-13", "-1.7165385268815095e+26", "5.166125773697905e-12", "0.015045545995235443", "-6.834093291274712e-09", "-2.5301435293813507e-16", "1.7548781754499297e-29", "2.2420310709088944e-18", "-3.5930431241947706e-34", "-2.4802221562011786e+25", "2.1894480173670037e-29", "2.3272763945009177e-31", "-503951648.0", "-1.922497531269121e+25", "-7.74139784022387e+20", "1.4430643482791317e-18", "-2.034465663824691e+16", "84181827584.0", "-24067647488.0", "2.5515306881218613e-11", "4.708787538354816e+37", "2.4315412964354133e-12", "12321147904.0", "-8.800279820623183e+24", "3.108382666273006e-19", "-4.4208640220810835e-11", "-8.780704769642289e+29", "-3.1210818553536967e-35", "6.065807648926963e-27", "-2.0105679307049574e+29", "-2.1907062651964322e-21", "8.753337754618512e-29", "746956800.0", "5.7357583380554005e-27", "-4.0929204510076556e-27", "1.8678502101239109e-22", "-789.0914306640625", "-1048089.25", "4.1391233239389206e+25", "4.5536250132633903e+36", "-109247029248.0", "-2.741643611397554e-18", "2.978752006711954e+21", "-1608292368384.0", "-78512.0078125", "5.506281161428955e+33", "4.845366019435159e+33", "-1.0455963020138906e+17", "-1.6708440912808815e-19", "2.779411722997338e-08", "2.226563684580954e+28", "-1.3376942147916519e+32", "-2.2256655819341824e+16", "2.03897745388886e+38", "1.8472553980102262e-26", "1.4902936610944696e+25", "-8156.04541015625", "-5.347841963559172e+24", "-1.6151510552049743e-29", "4.679650685237688e-24", "1.6367718522699876e-32", "1.259509013738171e+30", "-7.493143081665039", "8.098648467260283e+30", "6.39685420632373e-27", "-8.05720162588841e-07", "1.0212422740774748e+18", "3.397358901037621e-35", "-1.9105390039973777e-34", "-9.643093615727664e-30", "-3.3993822923808698e-15", "-1.7843514753535071e-25", "12328258560.0", "1.821339726448059", "1.936335771464106e+18", "-15160271.0", "33169602.0", "1.880152153604385e-33", "-5.469373815428106e-25", "3.905543804168701", "1.2204638411006385e-09", "-7.323207613998167e+32", "1.7125549589547262e-10", "-2.073850373562891e-05", "3.4367780843425066e-10", "-2.0362450669582114e-35", "0.19696374237537384", "6.40184986905738e-17", "-4.2612662259477324e-14", "-4.530443277975035e-26", "2.6552646599891094e-22", "-3.5961687187141544e-12", "5.155604594619945e-05", "-0.007666559889912605", "-1.457249326454851e-32", "61147344896.0", "1.2625631381478361e+30", "2.406337890956083e+16", "-1.5153328436512052e-17", "-4.1208536213273755e-30", "-2.922520352511169e-19", "-8.871446612164274e-29", "117062248.0", "-4.577052843181465e-33", "-1.547723453217796e-31", "0.012831547297537327", "-3.9259981689963913e+28", "6.104606155531145e-17", "-0.44830724596977234", "-3481791232.0", "-6.953411804677678e-12", "-5.206648008360012e+22", "6.866414022150036e+25", "9.879337140687856e-26", "-19045.158203125", "-3.918111580851675e+28", "-1.1771102224314404e-21", "1651032.875", "-9.397639004579862e+24", "-5.395388143369928e-06", "-5.0756364560089275e-23", "-1.151474186765028e-19", "-5.048166047369725e-22", "9.53716224156983e-21", "2017219.0", "-1149.5556640625", "-1.4092545779513534e+24", "2.5363106559824325e+38", "-0.24899739027023315", "1.0451154117278408e-37", "-104018812928.0", "-0.12239456176757812", "-2.703038140952e-17", "337825.625", "2.775883913040161", "4.2048569071084785e-08", "1.1766153573989868", "-1.5825365781784058", "1.8361607815156967e+31", "8.9389608385434e-36", "1.0022251523523191e+24", "-4.283231269663901e-10", "-2.1635765715377796e+29", "-2.5519935489370753e-14", "6.541956099681556e-05", "31.30220603942871", "1.1498855201352853e-06", "2.3762433035235982e-24", "-1.0432758930275375e-21", "15112591.0", "1.298802970554902e-12", "1.5251430320404672e-21", "-6.452921661548316e-05", "-7.191071586700993e+30", "10735144927232.0", "7.572696631541476e-06", "4.622226166988886e-36", "1.552509130520841e-29", "-6.64433682286828e-38", "-1.8571773767471313", "2.1023619596126718e-23", "-6.3978630084286e-27", "-2.952257034394279e-07", "4.121331187434407e-07", "-4.4212784876477625e+36", "-0.006304518785327673", "-1.881638258778969e+29", "-2.9839183891169095e+26", "8.154170646121677e+16", "-2.1782405960014234e+17", "-1.093192384973037e-18", "3.25208432030307e-26", "-1.779397376488325e-38", "-4.520567994497777e+20", "-98154.8046875", "6.788705543088887e+37", "9.0889388097465e-17", "2.7076264153331095e+34", "9.086229546901996e+21", "NaN", "8.074724907574664e+37", "-19212014.0", "139291023900672.0", "4.4804525776507e-30", "-0.7266185879707336", "-1.2103130363862503e+23", "-61218086912.0", "-0.4840620160102844", "33361.98046875", "-1.4307967310944747e-10", "5.562310011710259e-26", "1.2944370151764878e+29", "-6.091834502119919e-12", "7.0410987942168735e+22", "-4.891632303004933e-25", "3.803076368401141e+32", "-11.328241348266602", "2.0523635568814314e-10", "-2.7043137151849805e-07", "8.748686048035076e-22", "-3.8247817642246144e+18", "-1025155.5625", "-153.14846801757812", "-801764147200.0", "3.673358809723777e-24", "8.396133417655347e-36", "0.9517853260040283", "722418.0625", "-8893138944.0", "-1.3119665817233665e-33", "1.0244759032844037e+26", "3.30820890437603e-34", "8876899328.0", "2.3522679437599776e+21", "-7.868179518752643e-33", "-8.266591312015262e+18", "-2.028789441437209e+22", "1021722.3125", "-0.30285778641700745", "-7.118466643824389e+25", "-8.42870336516728e-22", "3.318861538022337e+32", "1.1722443638412023e+36", "-4.862724498845941e+36", "2.476588178979e-08", "-1.5911291604596525e+34", "-1.88906066781321e+27", "-7.104616618445296e+34", "-7.150324386205541e-24", "-0.019068174064159393", "-7.70472435851488e-11", "537.0653686523438", "-3.437098119629513e+33", "-1.4235671872792008e+37", "-487171697934336.0", "2.7269312150503424e+16", "51137.23828125", "-2.4205207282967933e-11", "383265064091648.0", "1.1728694559288954e+30", "2.682509304610685e+19", "-6783748.5", "7.849448905928659e-36", "991.7517700195312", "229832015872.0", "-5.0172319222803694e+23", "1.140167474746704", "-7.218239003538156e-35", "-7.378597876578669e-33", "1.708898499163652e-37", "-1.6216782214617423e+22", "-1.3108739910672704e-20", "-9.463755971353694e-26", "1476507520.0", "-3.77754029726634e-17", "3.855448437928662e-31", "1.0174978755248308e-19", "0.00010627077426761389", "9.49547856514405e+33", "118558.25", "-1.5499536243884032e+16", "1.732168361627001e-32", "1095716554407936.0", "7285206205595648.0", "3.174280322032724e-15", "1.155851746180499e-30", "-2.9543102317854915e+35", "-0.019284551963210106", "530672401776640.0", "-5.971590582039138e-36", "7.166730769630543e-35", "3.659284653718606e-14", "4312.4091796875", "-8.181780815124512", "-2.1463788387964266e+26", "0.09986330568790436", "-2.057997557203227e+32", "-626650382336.0", "0.7142737507820129", "-5.161192463078208e+19", "8.136359974741936e-05", "-2.114890003005082e-29", "1.0151224379018801e+32", "2.438900875315995e+32", "1.7955775153132502e+22", "3.358558527193991e+38", "-0.0002244168717879802", "0.000230058329179883", "1.7066870063154567e+27", "-7.631437367882192e-22", "4039782912.0", "-2.938559787839121e-16", "1.2589481135287208e-16", "-0.0033676058519631624", "1.390319391732231e-12", "-3.800359751689579e-27", "-0.0006573892896994948", "6569095856128.0", "-4.8443336265800534e-18", "-1.2123830452272721e+30", "-310.12652587890625", "1.4106197896056286e-37", "-601044.125", "-219.19725036621094", "176417.90625", "96.79041290283203", "-7.803174559549994e-17", "14933498527744.0", "8.884244651726801e+20", "-3.225712803253326e+21", "-1.8959419080034085e-32", "-7.589829940732705e-22", "-1.9924944353988394e-05", "-210573.03125", "-6.992713679210283e-07", "1.0417171543659187e-21", "2651215.25", "1.1659379767162042e-29", "-4.049087290251674e+16", "-3792448512.0", "6.4140047765685275e+31", "-2.2848362200424417e-19", "2.4266719499081026e+26", "-2.2596206110046516e-27", "1.8277979130232325e-10", "-4.103346127498117e+17", "1.9528625813886383e-14", "-3.7250868696259093e+37", "-3.563053955938453e-35", "-2.647465748067942e+34", "1.6646299139458365e-12", "1.0044036960515156e+21", "-4.137013782658771e+35", "7.058145463516558e+33", "-54.36515426635742", "5.4174248707415475e+35", "-1.8010612489598078e-36", "4.33742493750657e+18", "-0.03293471410870552", "170651680.0", "56107917639680.0", "2.5307916653641323e+31", "-1.1029321406542335e-34", "1.804158245022336e-07", "-7.884835008353602e-19", "-7.954193710747061e+22", "2.4757135902962746e-13", "-1.5572896966992172e-26", "7.624928177964739e-30", "-1344653824.0", "1.8047861658487818e-06", "-77397164556288.0", "8.081428769670857e+31", "-0.0014053566846996546", "6.926247417402892e-21", "-1.4448338359063229e-13", "3.51545539427242e-27", "-1.8821825875647846e-08", "-2.1335323565134498e+28", "11.430468559265137", "-9.381392478942871", "2.819572223790602e-26", "-7.676796369884938e+17", "-2.0679529513321986e-23", "-4.743276563534104e-27", "-3.5075105277268427e-34", "-7.537003885991798e-26", "1760232064.0", "28621498368.0", "6.906808076088359e-34", "8.730821512807629e-13", "-1.380100230610944e+16", "4.0372493012495565e-24", "-2.4623050931659754e-27", "-5.216767501921987e-15", "-68.97901153564453", "-6.4843451330517114e-24", "1469.77783203125", "6.318292880280672e+29", "-66243.9609375", "297738400.0", "0.07603175938129425", "-7.639722575817604e+31", "-2.7598647362477032e-27", "4.3375663206281534e+23", "-1.743951221560829e+23", "1.8343827179765132e+28", "4.953305775955786e-37", "0.00047512887977063656", "-4.431904176982471e-09", "5908566016.0", "-0.5057445764541626", "0.0021695333998650312", "-7.609070801654659e+23", "0.027485065162181854", "3.634338805517011e+37", "1.8061456596088007e+37", "-1.1788624790060438e+30", "2.223438213287697e-28", "-1.6548529657711697e-08", "-11.032842636108398", "3.268056235183465e+37", "3.7248793712539755e+30", "-1.1786523707178656e+22", "384524.875", "2.833011995316829e+27", "5.0890034511418653e+33", "1.8232928723292293e-21", "-1.3117638485854455e-12", "-2.5410331911806467e-24", "-6.36760223833503e+32", "-1.2885297451248334e-07", "3.972805273877634e+29", "-2.897339926624338e+17", "-2.179739344332037e-28", "-4.679603514013339e-30", "5.658605324987269e+17", "4.095329480682593e+27", "1.0546801934416195e+21", "4.0353712282244933e+37", "6.498503353213891e-05", "1.6278137707828347e+18", "-5.5422170036749985e-31", "8.802741372102814e-21", "-485121.0", "4.1823757239395926e-18", "-3.7922195560013214e-29", "-25.481319427490234", "-1.4223687331943785e+32", "-0.03987615928053856", "-2.7974305474993042e-30", "-0.006229464430361986", "2.3180419963284478e-21", "-1.416691245411527e-18", "2.4040547244258978e-20", "1.7747370714887468e-19", "5.372945817156589e-30", "3.782329231928676e+22", "-16571385.0", "4.849714084313472e-34", "-1.4090545728282007e-24", "-1.6297952806616653e+28", "-2.8398870255698647e-18", "-55880.1796875", "-32986150912.0", "-6.029158839125727e-28", "-3.753103669122784e+33", "3.110259666148393e+17", "8857292011208704.0", "5.837356316572605e+26", "0.0002069198089884594", "7.101403794667215e+22", "6.144909453923952e-37", "2.4446257193562635e+31", "-4.481323945688389e-15", "6.0708046667606704e-27", "-9.363069239788903e-28", "1.2136393777971433e-13", "-1.0982475345810983e-10", "7.503285746560118e+29", "-1.0532322005795162e+27", "3.333735076562323e+24", "-583.9066772460938", "-24.646209716796875", "655555.6875", "1.6344186242068419e-24", "-24895954944.0", "-15887265792.0", "2.6627936291524755e-34", "-476394.5", "1.798106313198305e-20", "-1.0287795870192115e-13", "2.576372831454956e+20", "-2.5798247955801767e+28", "-1.8210814095430253e-37", "2.1669382476829924e-05", "8.60129404245074e+20", "-6.91966491034395e+35", "1.4574009554735312e-08", "-4.474823983580123e-11", "-9.34240436116581e-35", "4.435628360143291e-26", "6233771.0", "-5.065423230525003e+22", "60604.9375", "1.8809097156898243e+28", "-7.067676286507764e+31", "1.5505825366502217e-15", "-4.709196574359531e-11", "1.0253748228902093e+17", "-5.070707869442857e+37", "4831696474079232.0", "-1.274714874806163e-14", "-2.108175389630134e-14", "-1.3385250116101734e+17", "9.83804042335343e+26", "-2.1938204949956602e-16", "-4.778892349652154e-30", "-7.489288594668408e-13", "-1.5947628619716097e-18", "1.1096177666112452e-29", "-345485927776256.0", "4.023919419893218e-09", "478517.53125", "0.1133405789732933", "8.271169678730592e+37", "-5.7531056091693955e+26", "-0.0003581419005058706", "-2.2786056872681013e+19", "-2.2253386032714777e+36", "0.0006594688748009503", "2.411070128827078e-21", "1.76158344676766e+26", "-16623721.0", "-9.674051297768532e+19", "3.718904557031689e+26", "18638384.0", "-80285597696.0", "-1.7536911651267768e+37", "7.641889847357573e+29", "-165947424768.0", "-4.208373138681054e-05", "2.912831501663241e+23", "-1.5132411407313666e+21", "-4.853057653008211e-14", "-1.466365265257994e+24", "-708495590555648.0", "1.2217823416335332e-19", "1.7491436665295623e-05", "-0.15237341821193695", "-1.931507576102831e-08", "6.213719332950397e+27", "1.586716008670791e+32", "-7.736005905623912e-16", "-2.9242767096949246e+31", "-1.59584841689641e+20", "-2.150665338231625e+28", "35484.12109375", "4.2644824800373995e-30", "-6.214830576342612e+22", "-8.431444441679159e+21", "2.4671247355536565e+30", "-7.205304728444865e+27", "8.357651046493305e-31", "2.573529641681278e-35", "1.922372575791087e-05", "1.9226670099186415e+30", "4.931718138640317e+27", "392.9024963378906", "31.18391990661621", "1.1154541271036519e-25", "-7.482815216032085e-23", "668937984.0", "-7.069533489428154e-14", "-2043090575556608.0", "1.1795306344909208e-20", "-3.524557902538003e+35", "-0.0018295611953362823", "-2.0949841806652237e+26", "3.053154146568393e+20", "9.302740850332366e+27", "3.974188549347094e-24", "1.7889221542860298e+36", "6.079079137182037e-34", "-4.237277053340194e-23", "-241860368.0", "4.897608197738452e-40", "1.6760613897628908e+26", "-4.093764497128217e-16", "-1.952092565123777e+25", "8.649304688379162e-37", "-73.74519348144531", "8.904083396520836e+23", "1.5375492557378414e-32", "-2.0339718136749146e-13", "1.8045080407843352e+22", "1.724951019695109e-12", "2.3690563516201173e+19", "-6.279913333047641e+21", "5.3077585274590754e-20", "5.3572674920502e+19", "3118161152.0", "2.4475804851751291e+30", "-74.38340759277344", "-1.1191026699028885e+33", "-4.899772665526873e-31", "5.8709450968308374e-05", "-1.8333154536330845e-35", "1.3885587768636322e-28", "1.6778506752371247e-12", "-2.748957630128351e+19", "8.100038109866425e-13", "1.0219341555131482e+19", "-513677.28125", "-5.356842042622902e+21", "NaN", "8.798001961518648e-36", "-0.8324100375175476", "6.409109909455424e-10", "2.023994374194393e+29", "-8764019703808.0", "3377001.5", "-3.2255295578007373e-22", "-9.161295545491008e+21", "-4.609169712566086e-10", "5.307326136971824e-05", "-357594038272.0", "NaN", "5.318414843724292e-14", "28424521383936.0", "-2.622075889746611e-10", "7.195599677354117e-25", "-5.4031288011031184e+22", "-5378.205078125", "-5.681076165053867e-10", "6.540569206141858e-21", "-1.3982646130415391e-21", "1.1386494336381948e+33", "4.644182260982177e-10", "-3.921323669747229e-37", "1.0672042110695488e+38", "4.999392191378835e+24", "2057493376.0", "1.023925278786919e-06", "2.7263366464045334e-14", "-730.8633422851562", "8.817453647122086e+26", "-1.5239581443893258e-05", "12648617869312.0", "-12532496.0", "5.291820760383225e-19", "4.1706525166773645e-07", "508.5964660644531", "-6.942501018514236e-10", "-6.850057035925461e+27", "-5.2877400119505976e-23", "-0.00012655006139539182", "-2.9459010734390523e+23", "-2.951492927844086e+33", "-1.5166682904609927e-26", "-1.1395284848541472e-32", "2.0690519748977833e+33", "-219981381632.0", "3.51999568939209", "-2.444367643983112e-37", "6.122639432802632e+17", "-4.3975584285149694e-10", "-3.288884029966359e+19", "-2.7545326769209444e+24", "-0.014094558544456959", "51620.5859375", "-3.172704920996633e+29", "-8.852754616155519e-38", "-1.2431208981753764e-29", "1.3122826795011423e-33", "-7.863729760207605e+31", "0.09833884239196777", "-0.0005900247488170862", "-1.6389992154380766e+30", "5.713834707126463e-27", "-1.2146609271955986e-34", "18694916800512.0", "-5.7328874921821995e+19", "3.2855962274209763e+30", "-1.0723939042212702e-28", "-8.612587011694855e+30", "2.0258947527767166e+23", "4.549468857195649e-10", "5.8162848394076335e-28", "7.574332644156194e-31", "-4.7959701152518925e-34", "2.7306379256503677e+33", "1.3806955653674193e-37", "2.3802125667185205e+38", "-94963.1640625", "5.853369296284866e+31", "2.2561217204124596e-17", "1.7662864411068046e+21", "-1.6270494721721107e-22", "8.056341692100274e+36", "-126.31535339355469", "6.69973287369807e-31", "-2.996234804331946e+34", "3.8208672776818275e-05", "-6.626411462238342e-35", "-25718.326171875", "1.6520261968266012e-22", "-2.0411699988819086e+29", "-9.495969854028041e+35", "-8.338169826846735e+21", "6.291437330219196e-06", "-2.95785172256693e-35", "-1.393272016425697e+31", "-11797389.0", "-2.255433831556443e+20", "-2.307245716856126e-29", "0.0001019686387735419", "2.7052002962641825e-27", "-1.9920781069683943e-21", "6.901652812957764", "-6.878281443191187e-23", "4.641837226031279e+32", "5.042180724898971e+28", "-2.8312591918956544e+16", "-2.02811122424905e+35", "1.7350613375358743e+31", "-1082159071232.0", "85265.1875", "5.0339872813204964e-17", "2.0375151319574804e-22", "-1.2975814025204215e-25", "3.1814570487908087e-25", "7.466234245435471e-38", "-5.420083361350279e-15", "79459193454592.0", "230079250432.0", "1.6530501412148624e-08", "-58.1977424621582", "-1.6457143807435681e-12", "1.8735414414550178e-05", "2.4280722831643287e-12", "-1.970683557208613e+33", "1.326343307424045e-31", "-62.2055778503418", "9.63652101186294e-20", "0.9942777156829834", "5.486255798948735e-29", "-7.541208833087183e+24", "3.046599386009174e+18", "3.9012652040788026e-20", "-1.9015747546811364e-29", "1.9001061160909322e-19", "2.3769748096144535e+36", "5283347169280.0", "-1.0424002334025896e-36", "-7.320458891934938e+34", "-5437.79833984375", "3.1096467118150967e+38", "-17973.259765625", "4.4955433302114786e-33", "9.565871811734137e+25", "4.984139669347174e-35", "-29653958.0", "-14031.5009765625", "-2.3400247728556548e+29", "6.874174406784617e-12", "2.1737681606567526e+17", "-8.555425869806436e+18", "6.900741882389643e-35", "5981147234304.0", "3.104485172296469e-34", "-2.1994855676303965e-21", "0.04719056189060211", "1.0706447994834889e-07", "0.004637068137526512", "14488.2568359375", "1.6263565750422833e+38", "-5.009274920589328e+18", "-1317048320.0", "11962.330078125", "6.296796865105558e+33", "3.1532689285835792e-24", "0.3842945992946625", "2.2328938840346836e+26", "5.3963124041593106e+29", "4.000354332879266e-20", "-1.0642199754404444e+24", "-2.1244387034840433e-29", "4.418320272085445e-30", "3.698653765659757e+29", "1.9594300672270423e-17", "-6.823676104296907e-27", "-2.4506577716118012e+27", "-2.7332882794172016e-21", "-3.8414639867876526e+26", "2.4587326348005987e-37", "1.2577183200909537e+36", "-3.669858899912135e+28", "-3.043875015364423e+36", "-194662.609375", "2.8343923785884757e-22", "-545921856.0", "109020413952.0", "7.343019335999124e-35", "-1.894726335577065e-26", "57859852.0", "-0.005767563823610544", "NaN", "-2.1203450303211296e+37", "1.293650581770732e-36", "-16874728.0", "-2.072249837239892e-24", "3.8509231082137693e+27", "-2.9651845423472878e-08", "-8.182179952345194e+28", "-5.48604973543019e-22", "-0.36123424768447876", "1.5647562488117759e-31", "-107077.4296875", "1851600000.0", "-2.815762359433547e+35", "-1.8849422076527844e-06", "2.635106501338928e+35", "3.567906007094433e+35", "1.4820374983333594e-38", "-2.6585698052237923e+31", "6.890124154052166e-28", "-0.0009718905203044415", "5.3162592383859675e-11", "1.0266899153066333e-06", "-18.451486587524414", "0.2780732810497284", "-33886916575232.0", "-0.0016463687643408775", "0.01988239400088787", "-1.515973303431332e+17", "1.0158042348119807e+22", "-1.0113517050971936e-30", "-1.9245656637628805e-28", "4.0844986773704026e-23", "3.979873720477942e+17", "2.0541450751259443e+17", "-3.5559269466067635e+36", "-0.000931707036215812", "4.904116037400792e-18", "3.130065469025992e+33", "-2.1217977809416644e+22", "-329.10986328125", "8.521140361377734e-37", "8.390581593410166e+21", "-7.367594201694752e+34", "-2.0246300470633784e-20", "9.399958885502561e-18", "-13247409291264.0", "-1.4864404874970205e-05", "1.1510323286056519", "-7605267968.0", "1.0011464535192181e-19", "0.0035437154583632946", "-3583279135457280.0", "2.3889706788376957e+27", "-3.176152425617871e+27", "-4.1187378471779697e+18", "-4.0302555549298657e-19", "-8.27153130345411e-36", "-3.3503101474375294e+31", "0.006447694264352322", "1.6223168430630648e+32", "262881558528.0", "-18371060.0", "-178925.0", "2.0299787713875568e-21", "1.162679670303359e+18", "-8.801976075084594e-13", "1.1785175127239538e-18", "-6.494882590899205e+32", "-2.527789281544208e-23", "-1.315684100871488e-15", "-2.2127715629961288e-23", "-0.02693314664065838", "6.552375151128044e-18", "2.428018723577008e-12", "7.225966244632509e-13", "-1.2377316375267028e+37", "7586934272.0", "-4.9242915968940643e-32", "-8.163968679230203e+22", "-999716317822976.0", "-1.6194811305116392e+27", "1.2469633971618249e-33", "-2.127516984939575", "-1.1008924742845645e-34", "-6.080436441929505e+26", "-2.9221321958437683e+18", "-1.2432366637883139e+38", "461755936.0", "3436.63671875", "-1.7560778155741112e-19", "2.2034212113789658e-37", "3.1957331279432794e+26", "975988703887360.0", "0.05852054804563522", "9416774852280320.0", "1.0566004120088199e-27", "50605871104.0", "-4.6232585102511256e+20", "-7.306684818390305e+36", "109481409118208.0", "-2630.48046875", "0.001437121070921421", "-9.963521411077146e+33", "-3.736028879496351e-33", "1.689921674177163e-28", "-8270316568576.0", "6.453053646993112e-10", "-5.670774170978788e-20", "6.825773682791547e+18", "4.79313633012779e-36", "-13183565.0", "8.027225775657961e+24", "3870745.25", "2.160339586552701e+22", "-1.7734357295933948e+20", "4.2986220921046305e-32", "1.2643259668605092e+27", "2.8074759696427203e-38", "-186.97373962402344", "9.304464857688781e-39", "-45278.4609375", "-4958639.5", "1.5945312668113246e-29", "3.7036495087815297e-35", "-8.246966503952052e+25", "-0.0006232240120880306", "-8.542206586525936e-08", "-798.9142456054688", "1.007987313408898e-12", "289229766656.0", "-9.356919917225264e-16", "-2.368073876821462e+32", "-1.0750797691540683e-37", "-3.0890664423499094e+33", "-2.323727577390168e+20", "3.6245414296479283e+33", "3816392704.0", "-1.6330744401086075e-15", "-4.1088154725460214e-23", "-1.6810310483608674e+21", "1855.765625", "1.088010975561422e-32", "464447045632.0", "5.617492582210559e-11", "31002.1171875", "3.920997157343761e-23", "-0.00020108318130951375", "3.219108087871557e+31", "1.0001509950200946e-38", "-242971836416.0", "-1.0983979631676323e+31", "1591.932373046875", "1.6829986861921498e-06", "-13796.9580078125", "-2.010415008672326e+27", "698317457588224.0", "1.966485875170327e-24", "-61.73651885986328", "-1.5450950940540914e-36", "1.5512105343471682e+32", "-2.06340143732201e-33", "-62063.38671875", "1.8120815856624414e+38", "-2.1038522012300895e-38", "-8.52111654097568e+31", "-2.5474446374573745e-06", "-3.278777534726292e+34", "-6.863026766550385e-25", "2.4509004101292836e-21", "8.565346059044956e-38", "4.768355656997284e+24", "9318422282240.0", "3.983082026688315e+19", "-3.30174117380011e-07", "9.567743787362511e-19", "-4.519767806548947e-16", "3.0247913358095084e+29", "7.969956962683632e-28", "-1.3823445974002952e+23", "1.1609331004671484e-17", "-1.4126715609177653e+19", "NaN", "-123146929700864.0", "6.085836373439283e+16", "-2.043494521802041e+26", "2.3140129812304622e-08", "-0.19102346897125244", "-2.505750478323276e+21", "4127468671729664.0", "-2.822123451020227e-35", "1239234.625", "4.551799879461695e-17", "-2.1818609066290563e-29", "-3.128634567984233e-20", "8875312128.0", "2.254839610315337e-15", "2.2006479412084445e-05", "1.4996723316579949e+26", "-4886403083141120.0", "-6.042234984511236e+23", "2.3837121819113947e+19", "8.93178112331081e-39", "-7.972784120761804e-35", "1.710705937341956e+24", "1.0705615505941987e-18", "616325578752.0", "2.4546177437929537e-26", "-1667932618752.0", "-4.441049611392245e-11", "5.989448305065591e+18", "-2.0588687005158248e-21", "-9.798789519588694e-14", "-4.979447965069994e-10", "-0.7936201095581055", "1.8109254545745374e-32", "4.8444913858247673e-08", "-7.056397308658928e-35", "-5.592993020887234e-22", "-2.2756031673032497e+21", "-6921826336768.0", "7.860116949937147e-31", "-2.2925222831204906e-10", "-8.061833661329213e-31", "-4.9690786233144105e-39", "-9.586587437510757e-14", "0.0008758037001825869", "1.423630153861359e+35", "-2.8828951742201837e+23", "-10086617448448.0", "3.1575656945844152e-21", "-8.556300922389182e-26", "6.926128239777529e+30", "4084168422064128.0", "4.061204550790429e+23", "-122.47003936767578", "-1.1143546174707808e-07", "-75100760.0", "1.0935057357422644e-31", "-7.223074831177095e-33", "-3.4798312187194824", "3.56328496876623e+20", "-1510.1771240234375", "1.7993942792770452e+37", "-2.4499905815457667e-26", "4.994937189556348e-12", "1.0221641513554464e+20", "-4.800906026503071e-05", "3.527420133764281e-08", "915212098076672.0", "-1.2118406361497126e-30", "-11706446848.0", "-3927.078125", "-1.6811020552508736e-16", "-1.5562868842364742e-27", "-1.3853053102386997e+20", "-3.1365882865075903e+35", "-9.55047117850932e+26", "-0.0002187951613450423", "5724040891203584.0", "0.33380621671676636", "4.397366655288446e-22", "1.1352032487985218e-35", "77701025103872.0", "-1.7888562928446454e-16", "0.011765686795115471", "-3374597948833792.0", "-2249.251708984375", "-1.7086850052765138e+37", "-6.409404886653647e-05", "2.2127969446899127e-16", "8.645153578018203e-19", "1.569937815158915e+18", "-1.1510891159081179e+31", "-0.0013760405126959085", "-4.4201269163920975e-13", "7.540765023040033e-14", "-7.541788629269565e-18", "2.2956000253351172e-24", "4.004778304533829e-07", "-1.3624681827195673e-08", "227965856.0", "-6175865.0", "2.3030046155485437e+22", "-194780807168.0", "2.6814870638155908e+35", "362280.8125", "2.5479422938063093e+23", "-7.195101459078757e-11", "8.212221063460081e-16", "-7.120907263357491e+24", "7.719561706899495e-27", "-0.00505024753510952", "-2.9994306452327563e-34", "3.657485113930648e-23", "8.372035488669678e+20", "8.948005153500268e+33", "-5.357052097722319e+18", "-1514702208.0", "6171929346048.0", "-2.2105125349465038e-33", "-247830736273408.0", "-6990.78662109375", "-2.048148440534444e-23", "-1058982720.0", "1.6831394366739186e+19", "14428.6103515625", "-1.4241829701172794e-30", "30.487573623657227", "-2.2247124550469704e-35", "-5.005103542128914e-31", "-4.853533296708321e-20", "8.304800851264096e+24", "657109.75", "-6.033444678614549e+23", "-3.277006170778239e+18", "-2.031836225664317e+24", "5.01061441763201e-28", "4.4654506329601384e-13", "1.439286116502746e-29", "-1.0884383535310224e+34", "-62711078912.0", "7.083175475175433e+22", "-5.743556386050841e-08", "-1.6090342998208095e-33", "2.727757128562238e+29", "-3.9519764449273662e-25", "-6.3446884281392865e-37", "310.30242919921875", "-5.9951846181962005e+22", "-302552252416.0", "3.092255184550252e-13", "2.572643738202859e-27", "-7.086210965965941e-31", "-1727929.75", "-4.565864219330251e-05", "-6.592138343558927e-19", "5.682610343881212e+18", "-2.174573764775888e+27", "3.289046855824452e+38", "1.313249946047976e-32", "-0.07445720583200455", "3.213713411496556e-16", "-9347879281885184.0", "-1.7965243737094738e-09", "2.4873145995150872e-29", "4.7576238171734154e-26", "2.868634663144576e-09", "0.001580084441229701", "9.423585694945642e-26", "1.5831126904174766e+30", "2.330848795770721e-25", "-1.0589370787312149e+35", "-5.549414009876504e+32", "5.9112924333517765e+34", "1.0845999827727904e+29", "-49030.765625", "-3.1397865698759803e+18", "1.313967185963662e+35", "-2.545361434076195e+36", "5.792760130152136e+37", "1.950595061095555e-24", "2.3984083220221484e+38", "-8537229492224.0", "-2.9694421404878986e-31", "-15.035490989685059", "-1.4060083497489403e+33", "-4.597184693440596e-21", "1.9937523882802613e-36", "1993704223014912.0", "-6.494448352481925e+34", "-7.89333680119926e+19", "-7.643652204236684e-12", "-1.3160431385040283", "1.2296736962014165e-09", "1.9536779726539894e-26", "2.6786812351575762e-11", "-20828.421875", "-1.9647934529842658e-23", "-1.7208501583478784e+16", "-4035040.25", "-1.3916046151915953e-18", "1.5759854316711426", "-8.159296327333264e-17", "-2.9352543837759633e+23", "2.875945547922879e+31", "1.084266916498244e+35", "-982313728.0", "3.7011671854645938e-28", "2.2821469134607686e+38", "-5.039406761870623e+17", "-547229728768.0", "-9.696354481240033e+22", "1.2612665652062994e+30", "-9.431928328640371e-12", "-6.433626201229892e-12", "0.19875460863113403", "5.417341887798445e-30", "-1.0733918945505151e+28", "-3310496635682816.0", "5.439234437911051e-23", "-80384784072704.0", "-6.613172429761544e-23", "-9.210153465476208e+35", "-3.2070993828520616e-18", "-1.8275843023162468e+20", "-8.390454265616923e-15", "-1.055492320245465e-32", "8.693508879282399e+30", "-1.5899670926759877e+38", "5.883977906575442e-25", "-1.0572949850698454e-12", "-6.461748809774093e-15", "-2.4633859610535317e+21", "-4.808316631745846e-19", "-6.404851437824803e-28", "0.034028053283691406", "-1.3734570973200384e+16", "1.6467811272610766e-12", "9.568678988093187e-36", "-2.243820407255015e-10", "-4.799739154216812e-32", "407717728.0", "-4.6503610064974055e-05", "-1.4114312757376526e+23", "-4.255383015675768e-17", "1.4451089200277094e+17", "-1.5266209220529172e+35", "1.5672801445366211e+29", "-7.720993834820606e-16", "6.53726127080057e+34", "-8.78197825015481e-25", "7.829643016246119e+28", "255291568.0", "-21275.45703125", "-8.891904546655047e-14", "-2.7443526995093645e+20", "-1.6417498045484535e-05", "3.835618448018701e-15", "3.929806445958455e-24", "-5.502734373331353e-26", "2.7908968538992163e-11", "-25852211200.0", "7.095572304481828e-38", "-5.33100071011942e-25", "-0.2753548324108124", "2.5933127294692625e-35", "4.8912837610744613e+27", "3.3130442836924166e+31", "-1.5271208688654527e+22", "2.0759112459129586e+33", "-3.417979669304469e+19", "-1.2030477393953837e+23", "8.963305844797827e+34", "1.5710410764936676e+33", "1.0498907504641926e-22", "3.567254316294566e-05", "4.1142984286542334e-16", "3777608.25", "3.178657549477164e+19", "-1.274727022019516e+36", "-4.621823841419936e-34", "-4.77995536238472e+22", "-680818560.0", "-9.83997061609898e-09", "4.1741721299067003e-13", "4.448692461289535e+23", "-4.567328357817145e+28", "-2.747868790624317e+24", "22945088.0", "-1606582009856.0", "0.0005842348909936845", "-2.515306723754111e-26", "-6.810691232175554e-16", "1.411262433494171e-27", "1.8666022706995654e-16", "5.1484159570073805e-27", "2.0206121931903853e-12", "2.0976323109045403e-11", "-251509090549760.0", "2.3120198551285545e+31", "1.2349762193618014e+26", "2.162470580947434e+36", "2.850784416624404e+31", "-1.1881056064797013e+23", "-3.1439669560344576e+16", "4.3818536363092534e+29", "-3.3791123699454623e-21", "-0.009782162494957447", "883.9993896484375", "1.2938157851749565e-06", "-9.375959329546387e-37", "-3.9247553375824833e-38", "6.22319773838087e-12", "-1.339659770740238e-35", "212839488.0", "-3.828574790532002e-06", "-1.0471406803743963e-21", "-2.43488671045814e-23", "1.2681273160820777e+36", "-2.8586601073457967e+37", "-960804450992128.0", "-4.910495890535748e+23", "1.485116890576066e-10", "-41.87205505371094", "-8.880151178749573e+26", "4.1663049281266673e-16", "-6.008434388476662e-28", "67455.6796875", "-6.2203089901125e-12", "-2370089655468032.0", "-2.996813645532365e+16", "-1.1400298857131973e-36", "-4.733296293883916e-21", "-2.226756251352446e+31", "8.532052978993371e-22", "2.1054824671725135e-28", "-6.029205376903445e+28", "9.417667995236709e-18", "-8.678137267482499e-36", "-3.0714609987045775e+24", "3.9988756829108423e-19", "-1.4482630463547793e-22", "-2035131613184.0", "-1.7303764720177411e-34", "-6.247521667668086e+31", "-2.3614628514697896e+33", "-8.62580453102088e-20", "-687154.3125", "200.12229919433594", "3.3941313289483146e+22", "-1.8017715391254047e-35", "-111.49097442626953", "-71338475520.0", "5.575085098435961e-12", "2.5322306758582944e+25", "-814358659072.0", "-3.7333145996854e-07", "1700068.125", "-3.236411946040842e+33", "-5.732811114711467e-09", "9.620939703762688e-29", "-4.805436765385713e-23", "-1.0763845799392205e+26", "2.6224601200184637e-18", "-682.7614135742188", "1.3697854406302667e-08", "-1.3856742953066714e-06", "-7.256716573434197e-21", "34592966836224.0", "3925069312.0", "112081030348800.0", "3.0078098408048736e+35", "-8.986645424175999e+19", "1462377.25", "4.2922267798241513e-29", "2.1783155546017952e-07", "8.208417689327317e-32", "-970655203328.0", "0.8224507570266724", "-5.3920218508676346e+29", "1.4160109861987257e+24", "1.0576105517027182e+33", "-5.926187424316292e+27", "6.1311697463766155e+19", "386.382080078125", "743.0003051757812", "1.6924257976135619e-34", "2.2062840295147825e+30", "139.06146240234375", "1.3819815649940995e-38", "-1.5370714603418285e-18", "-6.7507513493539e-24", "-3.450104920399439e+25", "-7.482056780248252e+18", "2.5149459554754405e-24", "-4.149183768356616e-37", "3.4029016856728243e-31", "0.0025782985612750053", "-0.8826302886009216", "1.052489089533714e-14", "-3.1484383324694347e+27", "1.7609325167860433e-29", "3.5111520471008745e-24", "5.981107609187575e-09", "-911924396032.0", "7.937882972056568e+34", "-3.9015789511002046e-20", "1.7134423657694242e-08", "-4.760694152296816e-16", "3.45820493112148e+17", "-2.2698589027372627e-31", "1.6760868248866245e-09", "1.8445727635465012e-34", "-5.0973834756406e+21", "-3.261085052560733e-16", "-3.719436800902419e+21", "-2.2115189149306045e+21", "5.809410463294605e+34", "2.435463510026245e-16", "-4.162612924099487e+19", "-1.3996033665364394e-08", "300.02008056640625", "-1.903693575789355e-21", "-1.7919611479107007e+32", "-4.014323063715359e-17", "-2.788675841732572e-29", "1.6095825661725413e+21", "2.818248673861121e+37", "5.873814018838027e+27", "-1.227637704208041e+35", "5.1591825733061505e-36", "0.0007006866508163512", "2273.853515625", "1.8853964615859778e+31", "-212853.53125", "1.6877242599910475e+35", "-0.00044694443931803107", "0.00044688477646559477", "1.8608866681214915e-31", "6.297959384301385e-39", "-2467009.75", "3204.219482421875", "7.623644655830025e-15", "-2.433365349059078e+32", "1.881775827217573e+27", "5.4977716806592115e+26", "-1.5473332840732666e-28", "1.0706394396341422e+30", "-2.714564533303149e-22", "-6.397539348167305e-18", "2.2980809532925795e-17", "-1.4272032593473669e-27", "1.318605741754909e-22", "1.5245482466951487e-24", "-1.1637282401177318e-32", "51617956.0", "-7.9367343637029035e-22", "-4.904704635776669e+20", "-5.72254965105838e-24", "-1.2016065875146252e+23", "1.2130862397912627e-24", "-1.996204632052718e-24", "-1.6821042336800929e-09", "0.0016190209425985813", "1.4843298979107296e+26", "-1271964416.0", "7.142523271405122e-17", "0.6730265021324158", "2.539005505979502e-29", "1.5217146121012762e-27", "5.458205760003308e+33", "2.144752031443447e-23", "-0.0008837008499540389", "-1275231.375", "1.793976183003825e+25", "5.429681011196179e+21", "-1.7713162884518286e-26", "-4.124479528426719e-34", "1.5898550600468297e-06", "8.180642274702166e-11", "-22609644.0", "4.8360377924439035e-09", "9.872486508855083e-12", "1.7629577513372443e+28", "3.07870825244203e-29", "-1009351.75", "-17693.564453125", "-4.546304647959722e+37", "3.8447470034064235e-12", "2.887844956523725e+16", "NaN", "-3.3037102278023604e-36", "1.90944429309717e+31", "-0.21335840225219727", "-1.0088276216054377e+31", "-2.569012376736627e+26", "-8.920156112389453e-25", "5.328481833544174e-08", "117.65469360351562", "4.921183599515544e-11", "1.5335318027883304e+33", "-229.9153594970703", "-252910.890625", "5.2890839557784105e-20", "-7.199373948039529e+24", "-8.965961166776716e-27", "556989.1875", "-2.0117578094312125e+24", "2.406459493718443e-37", "6.411764081877695e-28", "-4.580571837552187e+24", "-1.5875006198484698e-08", "-3.4293254181149596e+30", "-0.2775808274745941", "-1.467746604128414e+20", "-1.6503828761033974e-36", "-1.1137245544993355e-23", "1.0191913413620366e-27", "3.8438459119424415e+34", "-6.708044815011962e-15", "0.019898956641554832", "-1.930075196887593e-11", "3.4939838946572266e-26", "-0.0005855170311406255", "5.463256957938256e-09", "6.201621624747866e-25", "1.7995074784605922e-34", "-5.022788283356634e+23", "1.2896462856941879e+21", "1.2519481861009552e-37", "6889671929888768.0", "1.7106434812511756e-13", "18616076288.0", "-2.3180187370192653e-29", "2.0689349392709696e-20", "-6.464452127691333e+20", "48777.69921875", "-1.6059538971261746e-28", "2.773630609208115e+16", "-1.9278097936759835e-37", "1.3439822256174247e-24", "-6.425999859270388e+22", "-1.57473365295104e+16", "1.6403049585278495e-07", "4.332337849695078e-38", "-771183.0", "NaN", "-2.286066093564887e+18", "11075531776.0", "1.8526995574594123e+36", "-9.004260878575643e-12", "-1.9210381983075786e-13", "-1.2040888867367645e-22", "-6.4390990784476946e+32", "-3.030642101064348e+25", "-69693022208.0", "2.1639558188070488e-27", "-3.2473607457860964e+35", "-83010144.0", "-615586624.0", "-1.8752391849558208e-38", "-5.156364248210955e-19", "8.554184205422644e-08", "-2.521561611176223e-19", "-1.7746705223830733e+17", "-174.9328155517578", "1.2943590782742947e-36", "1.1286661624908447", "234721.5", "5.343829543080409e+26", "-0.00012634020822588354", "11779.431640625", "-7.944883233744964e-24", "-0.044082511216402054", "2935330168635392.0", "-4.804733589912608e-29", "8.717069634963439e-30", "1.156240946868139e+38", "3.1740505779254136e-09", "-1.276638272429409e-06", "-3.6216240435463803e-37", "2.7697572022589156e-06", "-499986792448.0", "2.0053614124528687e+26", "2.0631682748893778e-37", "-5.4123930901402665e+35", "108188393472.0", "-5.029087158049178e+16", "2.0064585548595915e-09", "-1.322725074714981e-05", "-3.3403956933852896e-08", "-5.785276791280236e+32", "1.2192539487787332e-23", "655483.3125", "2.86665283549001e+28", "-1.8652909724151543e+36", "-253.76809692382812", "-1.1830717883219016e-13", "-1.398845084882245e+27", "-3.836274559739644e-13", "1.9026016394194593e-38", "0.1914019137620926", "2.565933453741714e+24", "-5.325797534122786e+20", "-7.73921148334227e+20", "-5.929205894470215", "-3.9205605162351276e-07", "9.194785787405463e-38", "1.952250284785607e+26", "9.492212484474294e-06", "7.872495174407959", "3.75377848833122e-35", "-11595436032.0", "1.117498346463186e-12", "4.1744514167740344e+33", "-2.3246928254540532e+21", "-60022.0078125", "4.5887391024734825e-06", "4.4942831262649065e-15", "-204340208.0", "-1.0945127050389122e-25", "-6.590451929085085e-19", "-2.3582716234563584e+16", "-0.43412256240844727", "-1.4220506231466839e+25", "5.355148302519478e-21", "-1959266048.0", "-6.891902160376693e+18", "-8.64290384893624e+29", "4.061637546599209e-18", "-2.744617639179085e+30", "2.879907923189248e+32", "-1.992594214356252e-19", "-5.020522104437113e-17", "-1.3226670899011705e-30", "3.77887728932369e-21", "9.662298413077991e+33", "-1.2002502025267905e+19", "7.893462932870892e-34", "1.309493283041085e+33", "5.311600151325402e-22", "12.567071914672852", "3.1497940498340905e+18", "-33656232.0", "7.604857976278497e-37", "1.1421210148693326e-14", "-2.365368604660034", "2.1276340238462715e+21", "-9.75192612047383e-25", "1.3123184067443271e-21", "-4.535079436922095e+20", "2.1067098014078347e+33", "-1.037527166613451e-31", "-99.10343170166016", "-2.0045047899302715e+29", "-7.2553389077884e-13", "-1.5287091758640356e+22", "-3.3471916949626547e-34", "-7.391908258563479e-19", "-3.0849103653529664e-12", "-2.4977833291074807e+21", "9624407040.0", "-1.63082424718239e-36", "3.690636771885858e-12", "1.1218600918030952e-12", "-184259.859375", "-2.835139457602054e-05", "-8.756930037634447e-05", "2.769255603348095e-17", "7.523255519527993e-25", "-2.6403261540974156e+38", "4.962012462783605e-05", "9.309243688825651e+16", "1.2553616932522474e+34", "150088122368.0", "-1.350261587697119e-27", "-4.133410058670961e-09", "-0.00200483575463295", "-23846686720.0", "-1.5889655819916123e+28", "-2.4251716616128328e-26", "3.0193398900064256e+16", "-1.2173680290837883e+33", "449304179245056.0", "1.9327006408742606e+29", "-7.331612495379662e+21", "-178831.3125", "-4.50856654028406e-19", "-4.067620209442294e-23", "-9.989788487058218e+32", "-1.9600362670444767e+30", "-1.072210469064908e+22", "-1.7224475641341996e-06", "5.20854141721534e+32", "-2.579192972741347e-22", "1231626.0", "5.539833067784829e-15", "1.7620828657527454e-05", "-279765311291392.0", "2.00014328016751e-11", "1.1575775060723714e-12", "5.941429818620151e-38", "247.06744384765625", "-6.473699883212283e+35", "-6.304658361200228e+21", "1.512055554759293e+32", "-1.7490946868201552e-31", "-229494715580416.0", "-2.0078139454857147e-13", "1.5397163321347225e-14", "7.298064023919865e-33", "1.846286396214477e+16", "2.227994957447168e+16", "3.4329784683701435e-21", "-8.919736540725248e+16", "-3.0324954769918267e-15", "117804.7890625", "5.740122054976107e-15", "-2.8481503965144846e+36", "4.2961409315095645e-29", "12776515829760.0", "4.62933287106571e+31", "-1.6067710599357439e-35", "-4.979667521634221e-16", "3184803584.0", "-1.771812319774413e+32", "2.518421645808965e-26", "-6.466238677317604e-13", "-2.6575125980246272e-21", "0.00043900913442485034", "3.989386111827269e-20", "3.882237083526001e-37", "6.053069259385294e-28", "-2.2346706572694262e-23", "1.5042081782288396e-23", "6.511957534755539e-17", "-1777360384.0", "-1.9457987376520096e-33", "1.406875667972886e-15", "1.419463139493869e-27", "3.042862796360141e+36", "2.096085854973848e-28", "-63.933719635009766", "-1.7236956300155595e-23", "-442010787905536.0", "-6.455109735281555e-27", "-8.819428186607736e+26", "-6.479783749971106e-22", "-190.88363647460938", "5.62967555785099e-23", "2.30697259950307e+33", "2.5092848073333422e+33", "-7.357533604590571e-07", "-8.574168621892438e-25", "2.074141356993528e-16", "-1.4601440673895284e-35", "-1.1329494486841063e-30", "1.0697188690400594e-09", "-1.1321687338306043e-25", "1.1170362660488566e+38", "7.315359802897933e-38", "-0.03587007895112038", "9.456345287617296e-05", "-4.666935903254772e+34", "-1.4558696849650272e-22", "11601403969536.0", "2.209829113838181e-36", "-3.2374963760375977", "-7.687407560297288e-06", "NaN", "-265633593819136.0", "9.890259104441405e+35", "-1.6638209696438655e-37", "1.8311215448840969e-38", "1.4938811552355613e-18", "2.995031486999679e+36", "-4.475890280001816e-25", "-4.869732705969909e-08", "-2.7260386378337518e-31", "-1157362823987200.0", "1.7868684454837824e-17", "1.4278378168882495e+32", "6.34131184942659e-19", "-1.3942788193594132e+25", "-1.5469327208084138e-10", "-0.012425721623003483", "-9.793737058055572e-17", "-469296775168.0", "1.5302119380639079e-12", "7.53809078680816e-34", "-3.068538151648151e-18", "-1.2517443795537312e-17", "-100603032.0", "-438537519104.0", "NaN", "-1.1973920657036797e-34", "-4306562711552.0", "1507.7620849609375", "9.336288542272086e-08", "-1.5264074972287363e+38", "-2.572373548106796e-17", "1.2997531021298211e+20", "3.0890287072801023e-32", "-3.67615745402242e-26", "-3.48221283429542e+28", "-7.547214376925662e-28", "-1420362240.0", "-3.4294130509170606e+18", "35219088343040.0", "2353826627584.0", "3460528896.0", "-0.0023506712168455124", "5.0110076063990667e-23", "-2.8521384401067573e+20", "-3752626688.0", "-503.09942626953125", "3.469497300646684e+34", "-1.80837979242086e+20", "-4480596992.0", "-7.458564148243862e-12", "-7.160684428159619e+37", "1606449.875", "266439.5625", "-2.5004113459772475e+34", "-1.2244210362413654e-28", "2.4732027890299917e-24", "0.00010958802158711478", "8.322768358085422e-27", "-1066309648384.0", "1.0580666892551696e+24", "9706720460800.0", "-0.0007376854191534221", "4.3315793786001886e-27", "585137955799040.0", "-8.287673193506845e+23", "-7.053967515909525e+37", "1.2170338911984251e-28", "-4.826696421477347e+22", "5.265094757808201e-34", "NaN", "1.2938585433625823e-18", "1.0143578272664858e+28", "1062740131053568.0", "-2.0392650665579078e-15", "-6.5635652468643e-09", "4.595973448007727e-24", "-2.8480716773709328e+25", "82746760.0", "-1.0206527178979741e-07", "-6.391226817148482e+25", "1.465098008190946e-12", "1.1775860264124953e+24", "1.4504557546387527e+22", "-1.702119269426972e-38", "-3.7166844343123565e-15", "-1.979290062680806e+23", "1.8533601515129317e-38", "1069557317894144.0", "-21944.681640625", "-8.667838113929349e-10", "-4118815386370048.0", "4.6351156419988104e-12", "7.649509548028263e-16", "-1.1506839772034818e-26", "-2.6730303113470667e-16", "-3.862736519533172e-10", "5.307223063799604e-11", "3.9934646101592724e-28", "2.3255930297112085e-23", "-7.553707890474406e-29", "6.554287284082642e+36", "7482753763246080.0", "-4.233332376570349e-26", "-2.310355620017343e-36", "6.500162726297276e-06", "2.7928806957788765e-05", "2.117658328184817e+27", "9.635118889025874e-18", "7.765535498975635e-32", "1.1245803169674324e+20", "368953.59375", "2.841954144957551e-15", "4.4424930811965735e-38", "3062.92919921875", "8.45154512455649e-26", "-1.2169326595057233e-28", "5672080376856576.0", "-3.3276754544942117e-31", "109177088.0", "195913054158848.0", "1.3257076629864353e-35", "-1.581701852826356e-10", "1.1285527173065308e+33", "-0.0067045181058347225", "3.494005779147713e-11", "4.7076329928673404e-27", "4.654900283431664e+25", "1.80503082428843e+36", "0.001406894065439701", "1.902461299607694e-09", "0.08862408995628357", "1.0015890363069235e+17", "-2.3922539572615338e+29", "4.188809574226916e-38", "8.227351877935513e+35", "7.485938244530692e+22", "-0.21174390614032745", "-1.3453766960808802e-18", "-2.1392793288379066e-15", "8.099960151774565e+29", "-1.474849164250433e+23", "-1.8819424686664333e-24", "2.0613233460661416e+24", "-5.321509834598646e+18", "1.4689438813999105e+38", "430224000.0", "8.288805560281429e-34", "-4.2574494787620506e+21", "-1.4712956390855706e-22", "5.49891046830453e-06", "-3.280650334717089e-35", "-0.0036430074833333492", "-3.7198788602542485e-17", "-8.228941769731865e+28", "-6.272600417696474e+25", "-1.4768070855097903e-07", "-1.933889389038086", "1.9074408464043213e+17", "-5.304132612958323e-32", "1.0167741147193259e-16", "3.778793352461001e+31", "6.409636603364628e-26", "-3.840223005138524e+24", "-1.5890312769568768e+16", "-2.5790610966303085e-13", "-2.0912592411636345e+24", "-3528572416.0", "-1.66620439234072e-12", "-7.576044852071361e+37", "-4.741509666404481e+20", "0.00013531785225495696", "-2.830694375906199e+28", "-1.9643262710866694e-11", "106058789945344.0", "-6.0820255082250085e-12", "-242476.96875", "8.760009319493112e-14", "-663541241610240.0", "-7.365523397826634e-27", "-1.547391199183776e+30", "-5.340286447819999e-34", "2.311008347980934e-34", "-0.178666889667511", "6.628998241697726e-23", "5478985089679360.0", "-9.428997860272403e-11", "3.8516135051585394e-31", "2.0159655275928e+31", "4.026967992491889e-32", "9.051985799314145e+17", "9.381835847834403e-26", "8.796120278427781e+32", "-2.0816998409218829e+21", "1.782036439399347e+19", "1668313742573568.0", "1.3976549308468864e-20", "-6.382126388415046e+37", "-8.585764044166666e-11", "4.234395372224512e+16", "1.2993288445731275e+19", "-276824992.0", "1.6353473600113199e-25", "7.93192502221871e-29", "-3.165094648428366e-27", "-0.9535375237464905", "-6.103553763252097e+24", "-4.756942062478231e-10", "4.144602251053475e-39", "-424.1318359375", "-9.350229864235165e-39", "8.894814662596673e-09", "2.960724379934641e-36", "4.116049900883253e-18", "1.8098619200213533e+22", "3623227431583744.0", "1.2462721753290578e+26", "-7.0275909434813945e-31", "-4.322731579398476e-26", "3.6052782981244036e-09", "1.1543831099387448e+33", "-9.333889951907672e-23", "3.018136259618821e+31", "-2.981910467147827", "-2.795956232515143e-16", "2.7082945325327717e-19", "-1.778980896013378e+38", "2.0640356103638116e+36", "-4.463300911083934e-07", "33870921596928.0", "0.0008551648934371769", "-5.612554723775491e+36", "-92609376681984.0", "6.679017474708154e-34", "-6.263297116454748e-17", "4.6218170657949166e-20", "-1.8305867685954914e-14", "-1.3788981366428114e-36", "6.2050406520321004e-15", "-5.863583498182328e+17", "-7.54572152715346e+16", "-0.0009027776541188359", "6.390937690986302e-24", "-1.1677988645008887e+20", "-7.997766228526841e+27", "3.1758234952716338e-22", "-83.9123306274414", "1.634223843965697e-17", "-7.336181225609878e+32", "3.934347449778541e-34", "3.5410360466333638e+22", "8.344096550023825e-24", "2693822010097664.0", "1363.7958984375", "-2.002248228625672e-29", "3800308992.0", "2.1839255168742966e-06", "1.3816076283035272e-08", "-8913.203125", "6.721871940474663e-24", "8.689703991002532e-22", "8.92582649176474e+29", "-1.1661981458470888e+35", "-384.075439453125", "2.269886998422077e-20", "25276.27734375", "125263.0078125", "21.86623191833496", "-0.005141907837241888", "-3.801149141171915e+29", "-6167013376.0", "-12740873216.0", "-6.60338735834411e+31", "-1.6556924624040293e-32", "30751869370368.0", "-4.341874859647987e-09", "6.84057641802717e-14", "-1.256072611541776e-28", "-2.393661169346806e+27", "-1.0130018853002105e+32", "-5.523594642647765e+31", "210416517120.0", "3.363807574154872e-28", "1268539195392.0", "-43.05659866333008", "2.100801143794934e+23", "71845139185664.0", "-4.507276855105196e+36", "-1.112174030453828e+25", "5.0681594430226255e-21", "1.1270360655775772e-32", "-4.862113471913818e+31", "-2511.037841796875", "-2.6915806609297455e+27", "-1.2787896594090853e-05", "8.907619330549774e-18", "8.416705774135625e-34", "3.4177901867898308e-28", "5.4520170544522854e+17", "-4.7095760476981224e+30", "2771517696.0", "80190587797504.0", "-2.820973489525702e-11", "9.06730923957325e-24", "-1.5657011798175802e-10", "-2.7504775940869226e-38", "-1.1438784470570466e-23", "-1.9216032963383393e-16", "3.4803842690117144e+22", "5.218118298566665e-11", "-2.350216621977916e+30", "-16013684.0", "1.833411852484367e+34", "-8.775733891300406e-33", "-6.55520097299475e+28", "4.3675860125835845e-19", "-5.4748406943838184e-33", "5.120159518594179e-31", "-3.09937215866099e-13", "46414075789312.0", "-9.376948977786075e-24", "-1.5779173014845373e+31", "1.0927700058727521e-18", "-6.071514120755933e+27", "3.5422822983950217e-28", "7.999781909269289e+30", "-9.739445658269344e-36", "7.766860425283887e-22", "-0.019723717123270035", "0.8422330021858215", "-3.5761291931196704e-13", "4.899001878794901e+28", "3.31794149310109e+24", "1.067517385253125e-12", "94724773904384.0", "-4.8091567536067966e+36", "-920199808.0", "-1.8119440392538877e-09", "NaN", "-1.4679773255470934e+35", "-13188375.0", "0.0009082062751986086", "8.169826204372962e-38", "-2.0305576157600175e-36", "4.795715672486943e-18", "-8.010769148984513e-22", "-9.885331559121162e-27", "-238092173312.0", "0.27386945486068726", "-3.3720852844833645e+36", "3.7330874166275785e+34", "185244073984.0", "-2312.67529296875", "1.8319019292813255e+32", "2.803778476750951e-12", "-2.0902902869827075e+31", "-2.4488826017524805e-25", "3.1070545244019754e-19", "5.206628265873652e-20", "-3.86829488346169e-19", "0.0005058720125816762", "1.578338733025255e+31", "1.0867793219679891e-29", "5.296637436049927e-25", "321.9998779296875", "19809.81640625", "1.193278953189198e-25", "-6.38805101677528e-31", "-7.10204180918225e-29", "-2.0197446112430625e-26", "0.023801133036613464", "6.905989113903388e-09", "-1.25400620908207e-28", "-5.821989809824323e+25", "4.105131713523546e-28", "2590229135360.0", "5.215872970615533e-32", "-1.8437130175598164e+38", "-6.731561252160079e+32", "-1.2871551524578092e-20", "-58493.1328125", "-3.934400211185996e-17", "0.2340054214000702", "-8.25163241684519e-36", "114410.9453125", "-6.905990517225291e-07", "0.342555969953537", "3.0130727657229273e-24", "-1.580717094377231e+34", "9.232243998417026e+25", "-3.7124984930701998e+34", "-63794.65234375", "1.6063854750730357e+37", "17424444293120.0", "-6.374477778813344e+23", "1.0747652284258629e+33", "-2.0480082153492026e-38", "-9.048692888432793e+20", "-1.262973392535116e-09", "-55.45439147949219", "-7.727574849617833e+27", "-1.0387615872399809e+21", "-0.001922739204019308", "-1.0740724823308443e+21", "-238666944.0", "-1.8079866108905437e-25", "2.058158746905707e-19", "75002401521664.0", "7778994.5", "113.29246520996094", "-5.1762601316340806e-08", "1.2352769662968522e-38", "-3.815887605895796e-09", "1.074954200957001e+27", "2.9676842753877606e+17", "-1.3457412754731801e+20", "-2.168555348541676e+19", "8.413316142070316e+30", "2.250357272430232e+36", "-0.10495039075613022", "-8.135816072547862e-33", "2.6075706503076445e-24", "-1.8035600149877652e-27", "-1.6394961763172352e+16", "3.557291255565259e+37", "-3.6455000930122593e+30", "1.1199793629158936e-35", "-5.01156696252859e+19", "3.783079303924599e+26", "150839989633024.0", "1.9051476744624381e-31", "5.875581681483383e-15", "1.2022793782762164e-07", "4832911412953088.0", "-21977227264.0", "1.5362244148297472e-30", "-369557608857600.0", "1.7056597800824136e-10", "-3.842933869472306e+25", "5.008457439973541e-21", "-222234207584256.0", "0.24164368212223053", "1.4669264067346255e-12", "9.850421319152173e+18", "2.3813044163733094e+17", "4.289640082998588e+21", "-1.8627573763937413e-15", "9.899224587836888e-14", "-100283426275328.0", "0.0007831286638975143", "3.80973249568757e+20", "-3.9575957664012354e-14", "1.713535154557905e-26", "-2.194238132309043e+25", "-68.28721618652344", "62694280.0", "-7.448489378342925e-26", "1.6394313277416965e+35", "-7.670404110088782e+26", "2.075889736704776e+33", "1.3053883132980734e-33", "-9.271970943383684e+21", "0.004483312368392944", "2.666542872057843e-31", "2.660799847819093e+34", "3.0497005546021466e-37", "-1053508352.0", "-4.3809292752440056e-38", "-9.686990984573465e-21", "-0.9671691656112671", "0.4139774441719055", "2.3995472942099639e-35", "-4.780067945306891e-21", "8.777103681857111e+30", "0.1238287165760994", "5.488364762662457e-10", "-3.6295046514959566e-26", "-8.665912497145267e-13", "0.0010397214209660888", "-35.541080474853516", "1.2069795438310962e-30", "2.0062764526338777e-32", "-1.7180408484751553e-10", "1.3544667164113639e+38", "NaN", "-8.127515841403824e-19", "-2.6912477889299923e-25", "4.666313881664576e+29", "-1.3878761855046218e-11", "1.4060353359032773e-12", "-2.7540817188116164e-14", "7.742013485139207e+27", "-1.0642522264595692e+28", "1689679.75", "0.0050609479658305645", "-194206236672.0", "1.2210988260288701e+26", "-4.3193691112719534e-07", "-3.5082746525771737e+25", "-7.671385865579187e+19", "-0.059881892055273056", "-2.2780170685560226e+22", "-0.39502912759780884", "4.820571848540567e-05", "9.96232324017448e+24", "-1.5766017036872567e-21", "-1.2673919531046296e-13", "4.351110199369271e+30", "7.759274468716521e+34", "5.073316514935816e+29", "5.806381558494956e+25", "1.0408605159440778e-31", "-2.126830998632111e-21", "631437904576512.0", "5.058473615824856e-33", "7.057767517157697e+23", "-2.6660522293353744e+35", "8.346451178002842e+16", "9.724478261810234e-31", "5.9462803604637404e-12", "1.5768989583952136e-38", "1.5449974665160233e+37", "-1.0535690529995424e-38", "-7.873682175002505e-14", "1.4338161127938258e-21", "-9.906164196815983e-33", "4.253142745879178e-16", "-32.680049896240234", "-59.313228607177734", "-4.850795459754309e-11", "-4.526503086090088", "-2.0507408407866023e-05", "-41454911488.0", "-1.5005921749427806e-18", "2.112999648716927e+29", "-3.619292649862644e-24", "17208108384256.0", "5.809248210518016e+16", "-8.018292473139515e-17", "-9.895072820335235e-25", "-7843862806528.0", "-0.8977601528167725", "-442.5616149902344", "-1.0229985261678287e-12", "4.138835475799461e+36", "-1.6027838195850721e+22", "-2.8423440555697663e+26", "-7.141352019846899e-20", "3.7185594772663755e-13", "3.743631774067145e-28", "6.282832327314525e-12", "-3.430213734933841e-26", "1.6568113283811246e+18", "-15157.2412109375", "-2.0025112235089182e-08", "-1.0841097974889635e-07", "-8.11137938170419e-35", "15.141786575317383", "-1.904520679551034e-35", "-5536669.0", "-6.076850704612994e-20", "1.5743124877899842e+34", "-7647053824.0", "982.8301391601562", "3.299582563045845e-38", "1.7329862163665058e+31", "-10697123.0", "6797503496192.0", "-224429588480.0", "0.0008385883993469179", "-14.174373626708984", "7.038896144771973e+17", "-1.5624440656679284e+38", "4.364711164175828e+34", "499347936.0", "-7.303182059104074e-08", "1.1758885383605957", "-1.4645932101924576e-36", "-3.5116778309468965e-17", "-8.592573947672244e+20", "1.0541474046578527e+24", "-2.0850821069728909e-10", "-6.935206095983162e+19", "78655.0", "-1.691811939835957e-15", "-1.085939737457748e-22", "5644884107067392.0", "-3.809100429553617e+22", "9172117408972800.0", "1.3284530407554193e+31", "-1.7354425887992364e+25", "4.435115729708879e+23", "1106830.375", "-2.407688502095213e+30", "3.99052298637962e-14", "-6.931713976190268e-18", "2.8213719349820498e-31", "3681596735488.0", "8.903367535161495e-23", "0.02720348723232746", "8.459907050586652e+20", "1.4417275114073788e+26", "5.11865527182985e+34", "5.652162664414815e+28", "-870.41455078125", "3.148000746369188e+19", "-2.2833952672310285e+22", "19424404.0", "-8.692789333908581e-35", "4.619582268408375e-28", "-1.513529733393861e-24", "1.1968380064786288e-08", "1.5268095720067444e-33", "5.822522009354257e-22", "3.7245032378932203e+22", "-3455649.25", "5614.546875", "2.2978583072183512e+24", "-114.58758544921875", "-0.004191640764474869", "4.9749016418541755e+28", "-6.0432486559028734e+29", "-9876204282707968.0", "-4.788778822770175e+30", "-9.185566429045326e+20", "-1.2496224580357742e+22", "1.5193398590517564e+21", "-1.8944703214226978e+20", "-4.856464080708889e-14", "7.13375964945253e-39", "-1.90048974821333e-12", "2.621644616774647e+31", "-8.583312233465905e+18", "5.70518886888749e-06", "430964867072.0", "2.596688211337854e+25", "3.644481072950284e+23", "-1.4035079584575917e+34", "3953252892672.0", "-25502.79296875", "-9.56107750294938e+25", "-295347620413440.0", "2.864282765937869e+16", "6.632953045482282e-06", "-4.0412472113393616e-26", "1708592.375", "4723.84228515625", "1.3390266384283693e+29", "-2.3341715404967545e-06", "2.5614488152237397e+33", "-2.2322551148922274e+19", "142421180416.0", "-2.52092452603691e+17", "3.059900617334355e-36", "-9.696328108160615e+25", "4.028428554534912", "3.095867770496352e+24", "1.7457760815440117e+37", "2561833472.0", "2463912.75", "-9.289731310591076e-12", "-3.141865438617925e-30", "-1.3894836324970512e-27", "-0.7626071572303772", "9.980369491184863e-21", "400621396033536.0", "4.4333693983929205e-27", "5686491611136.0", "-1.6425340165698067e-21", "2.0843602456447287e+19", "0.00040428052307106555", "-8.052035263972357e-05", "-4210873600.0", "-4.3746559795908174e-29", "1.6917824639336448e+16", "-3.2761815619609686e-16", "-30.284130096435547", "-3.725712303100369e+19", "-7.986411934419799e-35", "-3.3833390054833555e+25", "1.3190168097312473e+19", "-5.577311480492106e-14", "529108631552.0", "-1.9353134318019027e+29", "3.7323394941383457e-22", "-5.772095454840737e-37", "-5102897152.0", "-2.717012725651609e-25", "378466.125", "-0.003419241402298212", "4.815517824318742e+35", "-7.461699169653002e-06", "5.587725630962283e-25", "4.942381541784466e-20", "1.4919696383670795e+29", "-1.6071222866010614e-26", "1.3215284128041896e+37", "NaN", "0.012062947265803814", "1.2536263570163588e-30", "-8.327157470226652e+19", "6.733563140128113e+19", "5.5840221881107826e-36", "1111398528.0", "6.32860386318157e-09", "2.0329685882726536e+27", "1.4043460326598882e+23", "-2.1449322146697705e-26", "-69752.4609375", "8495755264.0", "51.495887756347656", "2.4622107486183795e+37", "1.2004639630501645e-33", "9.755484922823767e-33", "6.383380152588658e-13", "-1.3356057371183283e-09", "7.818688792669189e+24", "-9.47073162623803e-14", "-5.999491632398796e+37", "2.1704095506518286e+25", "-2538089180299264.0", "2.645325087869685e-17", "-1.94439564637764e-38", "-1.0700859478376448e+17", "-22529.13671875", "7.540184474554036e-25", "-1.2972638992839037e+22", "9.769178396541118e-18", "-5.77541156514109e-16", "-1.4595757420465816e-05", "-1.8662508419702296e-23", "-1.1825319228662327e+35", "-2.09533654785486e-30", "1.140207676629326e-18", "0.2000761330127716", "-2.0277053986712914e+29", "-1.8625132943339478e+27", "-5.542119627166843e+17", "-1.8070202610964729e-28", "4.1377390234476577e+30", "1993068.625", "2.874728126401741e+16", "1.3259793268723831e-20", "-2.0203045741706869e-29", "4094563328.0", "-1.0175251788336562e-14", "8.385070900519654e-22", "-7.508868325614531e-22", "-1576534272.0", "3.0380727230813753e-14", "4.572836819518205e-18", "-9.562721070435764e+37", "2.233493560934891e-26", "3.4453027026395685e+31", "-6.812505337389645e-22", "-8.381836318190461e+30", "-3.8385388617667715e-12", "2.685801701303975e-31", "-4.368249769355374e+32", "-7.504392940393956e-12", "-3.039125062720416e-10", "7.560251253160843e-13", "5.148387022895548e+20", "5.375391205410369e-20", "-2.7026606570794613e-19", "524305213423616.0", "-4.248845480026549e+25", "-2.4994716504522268e+35", "-215783904903168.0", "5.3030499877408555e-24", "3.4258276330410686e+31", "-2.076481610894541e+16", "-1.4130085612316787e+19", "5.2474111210745744e-14", "-8.488356344812755e-36", "-1796711.375", "4.187764272834856e-36", "-3.2363689115300076e-06", "1.8559470963200113e+33", "-2.491846717855678e+24", "-2.0962004847748365e-33", "-6.63162532364686e+21", "2.7748320391807504e-25", "-1.4582443018616961e+38", "6.024490735355179e-17", "-2.291809795204563e-25", "-5.6071663083189325e-19", "5.571157216580163e+35", "0.011579005047678947", "-1.9264090322934635e-09", "-5.600141474239873e+18", "-2.0188994739833405e+18", "1.640500436853515e+29", "1430402.375", "-8.900026551455703e-24", "-1.2458712473837802e+38", "9.26798959945151e-34", "-3.641403600340709e-05", "-4.134503048655284e+24", "-1.0871454443772321e-32", "7.099675859065242e+16", "1.903273444282449e+22", "-6.572131948741199e-36", "2.426216162755152e-25", "-101444569006080.0", "1.001452892715588e+19", "7.765303753910862e+26", "1.970566085799431e+24", "2.6175079964862618e-17", "-2.5698273636390736e-22", "9.165507552174601e+26", "1.2910380642689296e-18", "4.4038167481265684e+18", "-893.713623046875", "-1.3860343642591033e-05", "6.390290324109642e-29", "-1.058659757031099e+20", "-7.29331959409274e-12", "27556726.0", "-5.430637883829803e-11", "1.6553578564725768e+19", "-1.8916778697514025e+18", "0.0013719587586820126", "-2.2328945766999107e-20", "-5262490468352.0", "-4.962062011282242e-11", "0.043920647352933884", "-3.278922766689338e-32", "-2.7920504481745745e+29", "-3.3484361608291626e+27", "9.064980913540276e+25", "2.3906839807219205e+23", "1.9061624401115375e+20", "-1.5712109343950108e+38", "2.1189194878015088e-27", "414.7806396484375", "-5.027189471128478e+22", "-8.209194367650494e+35", "0.8817046880722046", "4.22667041665543e-37", "9.088617632422194e-30", "-1.8766294860297976e-36", "1.4339954327206554e-31", "0.5606955289840698", "1.308666890131871e-11", "1.9342359880653373e-14", "273698.3125", "-3.8031007403456647e+31", "2.78330019278559e-22", "1.9075940118271462e-36", "2.9072159152817723e-23", "-1.3722691840007458e-15", "-2.724594683146025e+22", "-3.136928781652549e+24", "-10516012.0", "4.909786252684501e+31", "-6.372515362297315e+24", "2.3419252949135255e+32", "5.316811749765305e+31", "-1.4235014555996628e-10", "1.0520000027871295e+22", "0.0007196585647761822", "-5.967162865906235e+36", "-1.8355703807242257e+21", "5.54600462657789e-26", "1.8905419695052586e-20", "1.4087410855700899e-36", "NaN", "6.419097421252814e-30", "1.4523106634943724e-14", "-2.192172224996236e-12", "3.53263777822313e-13", "8.090729352261405e-06", "0.8571780323982239", "-2.2379659542480408e-30", "-1.1979533609941893e-14", "2.3301509655685233e+36", "1.9981874488372853e-10", "-1.1066709828618589e-18", "-23376.029296875", "5.3327968501176314e-39", "1.2962046676828578e+36", "2292354505506816.0", "-0.0022858427837491035", "-197345.78125", "8.657500529807294e-07", "-1.0169600142562153e-19", "-1.9448172986105984e-29", "0.384321391582489", "-1.3391350687648973e+18", "2.2133110178401694e-05", "-1.2729916048362648e+20", "9.504136851476337e+36", "3.1612767022980518e-34", "1.3463904906529933e-06", "247.9249725341797", "-6.702383516602301e+22", "7.755659640463966e-20", "-1.1350574313271145e+20", "-128114832.0", "5.118224247202541e-11", "NaN", "-128889.078125", "-5.573354347330273e+17", "-713302.5625", "0.00037615513429045677", "0.001567199476994574", "-4.3971679075660575e-10", "207451664.0", "-1097004800.0", "4.473692024475895e-05", "-1.8940253270767043e+18", "-18.40886878967285", "-495.1199035644531", "1.4082549150679659e+21", "6.812600249842949e+36", "0.013113816268742085", "-2898699776.0", "144953840.0", "-4.893791614378026e+25", "-6.488402636427159e+22", "1.722543848993629e+37", "-4.947082032913386e-08", "2786183610368.0", "-8.020053182428383e-38", "110554.796875", "2.512592726259746e-32", "-2.5947281445397463e+18", "228087312.0", "-818746432.0", "1.6964192696734283e-15", "-4.085509931053123e+35", "2.434307791729459e+16", "-1.903612374497058e+28", "889836160.0", "3.4697505831787217e+34", "-22.1912899017334", "1.4675097888185856e-13", "71843453075456.0", "1947771.75", "2.2700825495950835e+35", "3.28361171586522e-10", "-1.262706939009206e-09", "-7.12287951456586e+32", "-6.573193633978944e-30", "-4.175482620826759e+34", "-1.4725345195956634e+17", "3.6365374687236107e-12", "1.4307684811226684e-12", "869879382016.0", "-61932092.0", "22894.255859375", "-1.5111133164283853e-30", "1.2224855276987218e+38", "1.4318748554132378e+32", "2.305766470251153e-25", "2.3818763342184448e+17", "-2.2686653558024773e-24", "1.4932014905380987e+26", "4.277730819970302e+31", "-9.002121820401814e-19", "1.4855317040128865e-25", "-0.12860196828842163", "9.194786845594925e+20", "-4.387137931560391e-28", "-24659.75390625", "-2.889617607311967e+21", "-3.078517691939239e-22", "-4.802605306938603e-24", "-1.7052815432626345e-27", "-73787351040.0", "22615.97265625", "-190987780096.0", "-5.409903526306152", "172364620890112.0", "-242441011200.0", "1.1489768121734567e-20", "-7259607663640576.0", "5.089889011548968e+34", "-1.793925348679326e+20", "2.0102592657167406e-07", "207488477560832.0", "-44941673758720.0", "53570412544.0", "-4.268614118926601e-25", "-4.396746424503501e+16", "1.0748376553237904e-06", "-8.627751789096152e-32", "-1483023082061824.0", "-3661.09228515625", "14878.3115234375", "1.2371219094722174e+35", "-1.7693287945874658e-09", "-5.527944753708169e-33", "2.0037814639793275e+18", "1.790808260702372e+17", "-16329903964160.0", "-6.1853330727961255e+31", "-375925276672.0", "-1.0549599124053547e-30", "-7.388594822514412e+37", "2.3613589570036637e-20", "1.6893610954284668", "0.0009387393365614116", "-3.353382325758665e-33", "2.4238190116794117e-25", "3.729802382744507e-13", "-1.4968743755616742e-07", "1204192384.0", "229988064.0", "1.237257994877588e-26", "-15.85442066192627", "0.09994488209486008", "-6.077557065178098e+33", "-1.1358448399822765e+21", "-1.0537636933562371e-38", "-1.2735898171632123e-36", "1.084318787176242e+37", "9.91158106933166e+16", "-1073429151744.0", "-1.7440946464830631e-09", "1.2772512205458517e-28", "-4.324113934875359e-26", "-0.8850976228713989", "1.761072732954505e-12", "2.835298218428761e-09", "5.361515834264903e+33", "-4.2622598641350723e-35", "-1327.092041015625", "-2.812394384501445e+21", "5.779380245371221e-18", "20.428417205810547", "-4.215715826846039e+31", "-59546873856.0", "1.4033728172602178e-11", "1.4769376011583313e-18", "2.3254455767431913e-34", "3.2200936664922337e-19", "128017280.0", "-318791.125", "-2.5670461581016485e+22", "3.1108960432663514e-38", "-1.0979554314427603e+23", "-2.1571677535365475e+29", "-8.749146845355643e-25", "5.343126233200722e+17", "-237.67483520507812", "1.1973833076889064e+38", "2.0391918558565093e+22", "-5.490980631624225e+21", "75986.65625", "-4.619056701660156", "-8.861719833695118e-33", "-157269.4375", "128665484525568.0", "-6.873673555674031e-06", "7975501939343360.0", "-2.624976230023529e+36", "0.0002339562779525295", "3276.2958984375", "1.3996268553861935e-11", "8.314787991901962e+17", "3.6913754275502697e-09", "-1.3856758264219834e-32", "2.801421679958764e-32", "-3.4715214022450427e+31", "3.193919018151803e-10", "-5.148430502114565e-35", "-6.679019837196876e-11", "-4223806398791680.0", "3.253677639026465e+29", "8.285675133281178e-21", "0.0015612266724929214", "7.328853763907874e-29", "-7.931285810884993e-26", "-3.100172679335045e-12", "-8.068575471130426e+35", "-9.456412226427346e-05", "1.909367640717455e-16", "-9.097580199089295e-24", "-1879529.25", "2.270603094104836e-29", "0.003279720200225711", "-5.691552427410634e+37", "-3.8634363130641326e+33", "5.208045550375573e-08", "2.184389448259769e-38", "-7.325095720146085e+34", "-1.927438209037291e-10", "-5.9578634876836254e-18", "442054173786112.0", "-2.155036006470683e+27", "56605458432.0", "-0.01496232021600008", "-1.8445815985757008e-09", "2.0948762603211702e-18", "-0.00012376871018204838", "-1.3833060847900924e-06", "1170.01611328125", "5590.9482421875", "-22072.81640625", "-6.630247592926025", "-7.407887061552531e-20", "2.006678022985688e+28", "1.9148482084274292", "6.216394922375912e-06", "3.7871052010411796e+29", "-3983055360.0", "1.5272788814200832e+16", "2.994901418685913", "4125207.75", "-8.492529838219423e-27", "-517916.75", "-7669188.5", "1.5359819275243839e+35", "-442395288141824.0", "-4.165122366001214e+37", "3.829273356736884e-11", "2.5041910434248827e+29", "-0.00019716356473509222", "-7.506280356974427e-23", "9.315228676259666e+28", "3.5822074827098826e+35", "8.729363588096792e+32", "3.5322437473553496e+36", "-9.096560980014542e-28", "-3.5962076437197704e+34", "6419168256.0", "1.736694066956644e-38", "-0.0017413960304111242", "-9.662628766961475e-34", "7.195567248595089e-14", "5.71764458174245e-27", "-4.5670888590341785e+32", "-2.335740997657016e-16", "8.748996761013981e+30", "6.544263783325764e+29", "-3.771669669910206e+36", "7.254534306611049e-09", "-59678.42578125", "-2.7348178761675856e-32", "2.50777108585009e+23", "3.5527164807577946e-38", "-8.13460529614736e-37", "-7933117054582784.0", "6.322418143668396e+36", "5.745856421355948e-37", "-1.4240397955120702e+27", "-0.000620390463154763", "-16203.78515625", "5.0835722068957955e-26", "60670509056.0", "-17621994.0", "251211882168320.0", "1.02572263979199e-22", "20635656.0", "6.981768083484637e+31", "1.9934515193067992e-13", "5.8905606901994905e+31", "1.0260495222225871e-31", "-2.6488949696574366e-33", "-2.9181096303545266e+22", "-4.373566322188481e-32", "-3.3527855193894666e+28", "2.0602652551282146e-33", "5.63229212627006e+23", "1.0401406566980482e+37", "-5.186481212149374e-05", "1.1991135929192965e+24", "-6.41360865525564e+23", "3.761910775169324e-28", "-9954924.0", "-0.0033652521669864655", "-8.727352303024901e-15", "1.6622902594639093e-30", "-1438780489728000.0", "3.380715955952399e+28", "-4.740546749432694e+36", "-5.548984835005366e+37", "-1.3491764773783988e+30", "-2.324827049835951e-29", "-0.0004075205943081528", "-1.4607503934153705e+25", "-2.369220331399348e+29", "-7.130070054128845e+16", "6.65725237922743e-05", "-1.0386510540527638e-09", "370127648.0", "1.636659328888888e+27", "-7.164140356508142e-07", "-6.221722287591547e-05", "-1747921280.0", "4.92598358951029e+23", "-2.8371244923317094e-35", "5.135172740309215e+19", "22228344832.0", "2954101.0", "6.3081842668319936e-37", "-28245764096.0", "1.4488010347801667e-34", "-5.076205433828232e-31", "-2.8479475415863263e+19", "-5.71484733629259e-18", "2.7067985558503424e+16", "-3.652662661271341e+34", "3.0488268967236316e+30", "-8.100541191746618e+29", "-74.62808227539062", "-3.456553527878479e+20", "-117183375998976.0", "-9.414750032907867e-15", "-74471.8359375", "5.795255410818527e-24", "-19.523818969726562", "5.077288686279217e+28", "2.92048716545105", "2.159610444280669e+36", "2.3283939592412862e-23", "-83388660711424.0", "3.3671726518136325e-10", "-4.0763920864846147e-38", "5.331814057916908e-16", "-1.1740948300202256e-21", "-2.6507435569450566e-12", "-1.5663904378016014e-06", "4.053576585505425e-09", "9.003948798819002e+37", "-6102986.5", "-1.1995963404842769e-06", "-2.056331928227292e-38", "7.824781120385948e-38", "-5.973079962756904e-23", "2.290402766877887e+37", "7.785233606887167e-27", "0.07073847949504852", "9276.029296875", "-73236136.0", "-8286791168.0", "1.8942499136336751e-22", "-4.330480176644619e-20", "1420727.75", "2.3786569807208394e-16", "1.641162136716094e-19", "-8.129344878580554e-29", "-2.8618390912156967e+27", "4.678842288109199e-14", "15836.142578125", "11787.63671875", "-3203.003173828125", "101.27881622314453", "-3.9585221493949885e-13", "-6.828678196369595e-38", "2.5353072238349077e+27", "-0.9079404473304749", "2.8216657198743315e+22", "-16297109.0", "-1.1805075403549569e-32", "-1099014283984896.0", "-1.9667400057643108e-20", "-4.0586719425327777e+24", "-9.37225360825844e-20", "9.73391820480174e-07", "-4.5239818293428546e-13", "-1.4312543328287575e-07", "1.8927785351559106e-31", "3160.704345703125", "1.2410124177191731e+17", "61066.796875", "8.798069757176563e-06", "1.7641354299941591e-34", "1.4454437751746458e+24", "6.005455445395899e-22", "0.5729559659957886", "-5.919673799572971e-18", "2.601227788545657e-05", "839247.3125", "4.178782342097561e+19", "8.015929010828679e-35", "2644383715295232.0", "-3.048189898805666e-39", "1.2600088221148736e+37", "-1.4501381461271888e+32", "2.2277139507970053e-30", "-1.2753903608494084e+25", "3.3132603327601373e-09", "4.601706794768528e-30", "1.2992660066418369e-30", "-3.4298660751389685e+25", "1.3383524507219157e+32", "9.36474205473492e-14", "198321111040.0", "4.3155523599125445e-05", "1.0658242296853078e+23", "-112659.515625", "1.1482566074826752e+16", "-14986067836928.0", "-2.268962334199902e-27", "-23152224632832.0", "-18.474824905395508", "5.82813936610767e-15", "-635666165661696.0", "2.762084653427649e+37", "9.985656398980858e-12", "-2.13290363149099e-09", "4.4729123456274633e-32", "8.309597878917962e-38", "3960711675904.0", "1272600985600.0", "1.1735413164531462e+29", "-1.9823092169857994e-25", "2.236165075368277e-24", "-2.1525760794905646e+31", "0.001983943860977888", "-2.442277559214661e-17", "-1.185374612178237e-28", "-5.8288296729898915e-27", "-494087434141696.0", "-0.381163626909256", "-9.448807482570291e+26", "11485330432.0", "4.7922227104339805e-35", "-8.201046880053762e-24", "6645896.5", "8.130643956017503e+19", "1.6700564542393437e-10", "-1.293300614305575e-23", "1.960217581124995e+27", "11963.501953125", "-5.97982840740589e+29", "17938154.0", "-1.0854321772058029e-05", "-1.44748416170011e-20", "-9.062431310708871e-17", "569094272.0", "-2.5883794516006664e-18", "1.9068922775356655e+25", "-2717286490177536.0", "-4.25482336659658e-27", "-2.6608041806092303e+33", "4.75360580820009e+16", "-8.252361039714765e+35", "-48625180.0", "-2.418838045316233e-24", "0.02114875242114067", "3.151912750896179e+32", "-2.418500491734889e-23", "-2.0487514640546805e-38", "-2.0676932308560936e-06", "-3.3978555080693695e-08", "1.651935564826386e-30", "21025.40625", "-41166741438464.0", "6008.7822265625", "2.473534980890158e+26", "-4.060974889696922e-10", "5.792583855096423e+26", "6.721417464106818e-34", "2.3048389771022843e-33", "-3.5542950627132086e-06", "9.300616578430086e-15", "1.6931736369809162e-29", "-2.5921600310637173e+22", "45376180.0", "9.096738182496544e-32", "6.629663689349792e+18", "-3.5832847027401217e-32", "5.946538824465822e+36", "-9.265160341530381e-37", "-431549.53125", "1403656077312.0", "-2.5012472178786993e-05", "-3.739296901202937e+20", "2.960999708932582e-18", "-5.009079162576842e-27", "0.00021230251877568662", "2137487744.0", "-1.2224923521057792e+28", "3.1769869583266226e-19", "-2.4325777937123094e-13", "-1.8743535800209103e-35", "1.0918894199983108e-14", "-3.2218300408810864e-27", "2.2935000806588416e+16", "1.0733091203205076e+27", "1.68051656301469e+32", "3.105418310292865e+18", "1.5997523453986524e+30", "-15899.2939453125", "9.22049189482108e+23", "7.112473085000357e-37", "-2876449536.0", "1.6383525647256647e-09", "-1.0962718927087778e-10", "-3.3393501623259287e+21", "-3.6510034511681116e-28", "-1.707186839292878e+27", "-8.043307550296015e+27", "-6.524261789457239e-14", "-7.559758912949157e-34", "116.9412841796875", "-4.6303856906179115e-23", "-1.8289100160544247e-13", "-8.992870419207815e+18", "2.051285367255535e-31", "1.7444835844813687e+30", "8.34723804733598e-31", "1.0857952421785189e-32", "-29281629503488.0", "2.2459208122420834e+36", "242893.09375", "-188945.671875", "3.7854111568429565e+25", "2.7781100841456227e-33", "-1.820235648596363e-25", "-4.5516193112645495e+29", "1.7080551521349958e-32", "2.58194406566899e+22", "1.1583978817537295e+24", "6.321156807104731e-32", "-1.8918181697587615e+29", "3.7038667293517987e+30", "-5.446563736964407e-32", "2.2274848930419963e-15", "-0.0035981356631964445", "-5.607422300039002e-17", "-2.6698796612190208e+17", "9.680433267055671e-14", "-0.0017033275216817856", "22371800.0", "-6.101561382786546e-27", "174.25204467773438", "-1.6874927391430652e-35", "1.1852904692216137e+36", "-2.652562084086029e+22", "9.969141337309152e-23", "1.7439455513658132e-30", "-1.6202445568153643e+20", "-8.776308146095008e+21", "3.492291518816744e-21", "3.11859732482489e-10", "-3.592910285515577e-21", "-3.0452298968231156e-36", "-3.5161794537195284e-15", "3.188120175907703e+32", "7.31091553203523e-29", "9.0427066193656e+20", "6.8189735611516965e-28", "1.978651467169592e-22", "183059267584.0", "-1.4836968320608435e-09", "-1.2461416394627482e+17", "-1.0016593150285867e+21", "2.01307761031672e-18", "-5.952499279211954e+32", "-5.575796410399458e-32", "2.5156129803190426e-29", "-7754071146496.0", "-25119848136704.0", "2.9437506977043493e-28", "1.5024208531245407e+34", "-1.4029140515958716e-08", "-499827802112.0", "-2.407139097932418e+21", "6146.31982421875", "-3538.231689453125", "-1.3198120047423005e-22", "4.20038048787319e-07", "1.2478347323493719e-33", "448765760.0", "0.4627686142921448", "-5.725042696620343e+18", "-2.5436759504923394e+19", "-5.013564191194133e-26", "4.013388932826711e+24", "-3.498725060939422e+28", "-186936262656.0", "-4.0360336419427076e-26", "-1.9380840063095093", "3.991885227117042e-16", "2.463775932767037e-22", "15.731876373291016", "9.529760092163997e-09", "2.943374981679431e-13", "-2.6664163888656945e-31", "400769472.0", "-28569793200128.0", "-9.807688899961882e+34", "3.2028119045790504e-10", "1.6061772265059024e-15", "-101.08087921142578", "-1.1822204214695375e-05", "-15463.20703125", "-1335.4508056640625", "7.146360077227516e+22", "-3.156558711420361e+24", "-2.075847938850501e-32", "-1.6166029626063505e-10", "2.033733158720709e+32", "-7.20829120837152e-05", "505.1888732910156", "3.8667669066683175e-10", "1.3114612868882687e-25", "-7.05312609893473e-19", "94951442481152.0", "0.0003919368318747729", "-116315918434304.0", "1.9476200167108317e-20", "1.6303497787327572e-37", "-4.143888044388801e-30", "2.0447067234564494e+32", "2.2109215343938138e-29", "-6.437373988172137e-24", "1.5166916017333742e+21", "-1.4296402925803956e-26", "7.048658642242481e-29", "1.3269293758210345e+28", "-2.7178805280036434e+32", "2.8379791932470487e+20", "64928079872.0", "7.07418968474867e-23", "0.028804872184991837", "-7.990358546930568e-38", "2.3308294021588105e+21", "1.710643555115991e+18", "9.759336535353214e-05", "-1.8132921739787633e+18", "-1.2316191174447891e+35", "-5.623713354271949e+16", "1.2937413318697835e-17", "-7.383607960452538e+20", "8.505545356581274e+16", "241668046848.0", "8.133208098115574e-07", "-57.73953628540039", "-2.936879514336381e-33", "-1005378112.0", "2.258021754279494e-16", "-288969392128.0", "1.1277042151622757e+27", "-2311876771840.0", "-3.106514420847454e-31", "1.0485798226374609e+31", "9.869076534148946e+22", "2376.27734375", "-1.977959185328615e-10", "-4.266144831254243e-21", "2.3783189946196705e+36", "8469646848.0", "1.9290149388885883e+23", "-8.77183049854768e+29", "2.5562998190641627e-23", "2.1472851861511864e+18", "-1.310833714929802e-14", "-2.2045075361609473e-16", "301313376.0", "-2.076169534727708e+27", "16512.65625", "-1.4437075849162508e+26", "2.310127959463635e-37", "-1.6735855348670344e+37", "-0.0004729905631393194", "5.936851942071921e-17", "1.6464039956126304e-36", "6.56865914599767e+37", "1.0329072828111978e+28", "1.6365781071916302e-14", "7.101336074940339e+26", "3.2227560775079665e+23", "-6.314303703685298e-22", "-61.65171432495117", "-14707.4052734375", "1.9426906180272552e-21", "4.0408375214098917e-19", "2.1136860579071194e-23", "-1.7458563522895977e+24", "-4.9612371368283183e-26", "4.470056580766348e+28", "-5.438971015792049e-07", "4.463777813840213e-25", "3.2211585558172134e-14", "9.667624941599494e-22", "-4.4499317077917996e+24", "3.3928394324160802e+31", "7.17984275855131e-28", "3.210150916007133e+18", "-1.3524853733090918e+17", "4.481984505755321e-10", "-260305408.0", "2.177198551990136e+26", "-115965583360.0", "8.256663940454559e-25", "-5.1196716122524144e-17", "-16781754.0", "8.043801668925522e+17", "-3.4078156188905636e+18", "1.0089284822865682e+33", "-1.0037540297147296e+36", "1.912538573378697e-05", "-3.330124641001209e-24", "-21331374505984.0", "1.4302429683296647e-12", "-4.156732146106594e-24", "1.1564220400072114e+22", "4258610934710272.0", "-9.52945866499575e-33", "-4.544854290639494e-38", "9.691908005082806e-24", "-5.623839422561697e-37", "-4.985457537638662e-22", "8.526249717434935e+21", "1.0623334182771148e+26", "-1.8416992938329375e+18", "3.0294066164060496e-05", "21853796.0", "-2.624110100091169e+31", "-6.214824426994122e-36", "-4.0283527707636956e+29", "5.296496431107423e-22", "-9.577445360510224e+32", "-2.5621774923934026e-08", "-1.1978598590400516e-37", "1.3677841086649729e+20", "7.727535827241236e-28", "-9.155024845959848e+21", "-2.335380321400074e+34", "-9.730613902968758e-27", "-4.752362482532185e+28", "-7.872938806976594e-18", "-1.8281613733961422e-07", "449704329216.0", "9.150500357026882e-31", "-8.181287144252982e-36", "-44321571078144.0", "1.208492996986295e-35", "2.817453939056215e-19", "-7.886456066028883e+26", "-1.6041177463101353e+31", "-1.230725763486494e-20", "2.4811750688831663e+26", "2195821821952.0", "-1.78976409299238e+20", "2.9847345840543216e-13", "-59310130331648.0", "-3.225682410235346e+33", "-6.9744459942858595e-16", "3.7838609962339374e+23", "-4.8334847235764755e-08", "2.441071715529106e-27", "0.07346822321414948", "1.312427068606882e-14", "2054697984.0", "-6.182396955489722e-11", "-7.452888803845215e+20", "-1.8581034200395016e-17", "-1.424537035997184e+16", "3.01031194976988e-25", "-8176531.0", "1.176047474098212e+36", "-2.3966501885297475e-06", "2.2222790285481714e-13", "3.0580882259574784e+16", "-5.349529033082945e-07", "-2.3252737924593175e+31", "-1.6930549747877477e+38", "4.65317275863397e-31", "-3.074837650274276e-06", "1587511033856.0", "3.616212880119959e-16", "-2.2677502563000006e+38", "2.0578408421734196e-10", "4.3905270755907896e-35", "0.00011772509606089443", "0.004153452813625336", "2878.663330078125", "2.8195556261909917e+30", "-1.3996260462393497e+26", "1.6178918239747876e+30", "NaN", "-1479065468928.0", "-3.0665184169720305e-08", "-1.1350697613130814e-32", "-9.794919039808376e-24", "-6.742333471046469e-14", "1365.051025390625", "1.8140853822411024e+24", "131997808.0", "4573534.0", "-2.4452225722093772e+23", "7.735770855941078e+37", "-1.3300976040966724e+38", "2408915622952960.0", "-2.3395640250291592e-29", "5.152170750026958e-28", "1.3273069709301977e-12", "6.76974109282015e+26", "-2.1544462211163204e+23", "8.691968554161669e-36", "0.0026800360064953566", "-200845120.0", "-223.5078582763672", "3.7353195536660166e-10", "-4.926979720496735e-23", "4.780919137322926e+17", "-1.7289420714798116e+25", "-0.9246433973312378", "1.2654050332489611e+38", "952831872.0", "-0.002389955334365368", "-57974432.0", "-2.084836016686196e-28", "-17043950.0", "-46233112.0", "3.5170744080208696e-26", "3.6325776012784373e-39", "5.998820599355358e+21", "-1.667957162700671e-15", "3.8419551336627657e-38", "-74.239501953125", "2.975892764989354e-16", "1.0334170322487017e-33", "-1.1396660525020419e-11", "-14596406272.0", "3.475965685904755e+35", "-3.66679005902843e+22", "-3.4183379517654497e+22", "-1.5952580329657993e-18", "-6.040261303943096e+36", "-34344460615680.0", "-6.035389603149625e+22", "4.4668229553254124e+36", "1.4705691376222856e-17", "75112243200.0", "6.107626201162749e-33", "1.7008634824859244e+23", "-9.893257431324146e+26", "-2.1219289177045863e-15", "-1.9918786005079158e-27", "-3.240183427878703e-20", "9.67390855502317e-28", "4.487688088032915e-17", "-2.4948690093462846e+26", "-55.706668853759766", "-9.935674289673994e+17", "-2.7676570740993324e-32", "-9.516325538818105e-26", "709833.8125", "-5220526305837056.0", "1.0251692353164202e-27", "-7.47674080025962e-20", "-2.7774375985211966e-31", "1.503950749502557e+22", "2.5934486460035763e-34", "-9.664419004365287e+33", "NaN", "-120719312.0", "-12.55850887298584", "2.953630007240804e-09", "-29795282.0", "-3.715152758353953e+18", "3.803961312609516e-10", "-0.0022755651734769344", "1.0714058037137263e+26", "90.14505004882812", "2.666322965736955e+28", "7950537216.0", "-3.345868021322764e-15", "354.4328918457031", "-1.8365478224888458e-31", "6040.71142578125", "-2.3868463302969435e-34", "-7.762745524511616e-13", "104693569159168.0", "-0.007618138566613197", "8.92389704370977e+36", "9.151137021799902e-38", "-8.920690297564704e-10", "-5.478978720802117e-27", "-6.100614452779499e+29", "-0.0001992773322854191", "-6.438925751367944e+34", "0.0001194912038045004", "36106292.0", "7.844998539674464e-10", "-5.847282601792388e+25", "9.554103638744708e-32", "-129206001664.0", "-8.89298092943136e-08", "2.4607729936603546e+17", "-996821979627520.0", "1.4184168390263833e+19", "59070627840.0", "-1051465416704.0", "2.4464821921553906e-36", "-1.3468604703748e-11", "-311.4248352050781", "6.7315245360077824e-06", "2.349091400694506e+26", "1.5951909006372363e+27", "-1.1309442185502246e+38", "2.3407662716948345e+36", "-2.5945419468565307e-27", "4.054821271441551e-35", "1.2238038430030352e+30", "6870647372251136.0", "-199.0450897216797", "2.3387900774501413e+21", "4.7458250352866865e-30", "1.7693383650568828e-12", "-5.611667559435741e-25", "-1956.2640380859375", "-10174691.0", "-0.0071151359006762505", "3.95988280133925e+28", "1.9041705153959583e-32", "1.1756038294240484e-23", "10777640.0", "5.469471734922309e-17", "0.6765620708465576", "4.604163729958477e-13", "-1274169262080.0", "3.346419054522296e+20", "2.7915820263171686e+17", "2.9081941817186845e+20", "-4.027456502032849e-39", "-1.7689514790292508e+33", "4.829729069289436e+30", "-2.4413928986299597e+17", "-6.132179883718956e+28", "5.916603370205629e+31", "6.51034290837513e+36", "0.08351580053567886", "5548124.0", "7.975960742716333e-32", "-2.1640026888158818e+38", "3.2579078664325125e-32", "7.448476416372176e-23", "-825690685440.0", "-339655753728.0", "-2.917528305927954e-33", "-2.5050992462638533e-06", "-2.344067440152031e-10", "1310214.375", "-8.844654937038903e+36", "-3.8613413565687836e+24", "-9.723240378096742e-33", "2.5818235524921078e-21", "19.58299446105957", "0.0009393932996317744", "2.963537211854786e-14", "-5.905497685545483e+35", "5.4533681827571265e-17", "-1909.4222412109375", "-6.153812093289365e-26", "9.739295224786078e-27", "1.0830801961570271e+19", "-1560274272256.0", "-1045461952.0", "2688.939208984375", "-2.9349044780246913e-05", "-774068830208.0", "0.0014256953727453947", "-2.43180289540745e-23", "-5.901874429087333e-32", "9.292065047656862e-30", "-3.587030636773102e-38", "3.5489690458159535e-19", "-9668750.0", "-100250299662336.0", "8.40918684915875e+35", "1.4881370362704036e+18", "445.36468505859375", "3.061753198839682e+37", "9.967260262570813e+19", "6.80948630815692e-09", "430715043840.0", "7.056090807690501e-34", "-1272105533440.0", "9.519069309131074e+24", "2.529253022298672e-11", "1.4479798846423315e-29", "9.433987811103331e+27", "-1.5507183519053906e+18", "NaN", "4.9245098357994515e+29", "5.545953778683665e-13", "-417286016.0", "-1.7028216740843684e-14", "3.0094907948713516e-26", "70874.03125", "-4445739975442432.0", "-11083681103872.0", "0.07481993734836578", "1.5793931518199098e-31", "0.001403028960339725", "-3.402286980368248e-27", "2.2100356066193956e-29", "7.314342316568881e-16", "6.946645195056218e+18", "153.68368530273438", "7.427273352771793e-34", "5.612594822651822e+24", "4.523122911899132e+23", "-3.1406134184309845e+29", "67422064.0", "-7.386485958704725e-05", "-9.464665041645815e+26", "220214515990528.0", "2.7922367759402547e-25", "3.578974926142403e+37", "-10270617370624.0", "-0.11904633045196533", "-7.819457676020611e+25", "2.4452804350203396e+19", "1.9668206679198524e-13", "-2.3269510985408972e+36", "1.0458668246068692e+37", "NaN", "0.1659335047006607", "40894.4140625", "-9.952889254888735e-17", "-97193040.0", "-1.8073127617067767e-28", "4.476428125366996e-23", "-1.5879231796134263e-07", "-19158292.0", "2.033407888259397e+25", "-1.046456116204908e-10", "4.706624622485833e-06", "2.781375850864024e+19", "2.108769403429239e-23", "-3.7856796848714584e+21", "1.7614213881948163e-22", "-206.1334686279297", "-1.893112377436932e+35", "2.561412524781792e+28", "9.516735489933925e-12", "-10468.28125", "-1.7554728981792555e+19", "-2.0755890091095353e-06", "-1.6584349870681763", "-2.5939995634296207e+35", "-0.031122418120503426", "1.5047139327323949e-21", "-1.7838875851835302e-37", "-1.6162566816860612e+33", "-4498942.5", "-6.207865168098015e-13", "-3.2255787340445132e+38", "1.0926494475296468e-21", "2.649310706432316e-20", "-2.785324431768167e+26", "-3.8895160443210286e-38", "9.812494639895646e-20", "1098340.625", "-2.4461423038104623e+27", "-1.2878083361040453e-33", "-0.0003329424362163991", "-3.150228330727371e+26", "-3.694986134552576e+17", "3.4958092326787654e-30", "4.202013703378515e+19", "2.6620953705314143e-12", "46943156.0", "2.414263211560637e-30", "-6.188355423615756e-38", "-1.7590985273059643e-21", "1.8684170465633323e-33", "61461137195008.0", "-6.373977938830519e-22", "5.508198283930026e-20", "1.0187507378516781e+29", "6.561544418334961", "1.4066120252725552e-26", "-2.7766652419368205e+35", "-1.7034695853421363e+17", "2.1190467684960446e-32", "5.702599850665457e-12", "2.9197380275368607e-15", "-1.3187276626421863e-27", "7.012595299051268e-22", "-0.0017867397982627153", "5.238611827377387e-30", "-9.114438969067938e+22", "1.1888950212496623e-14", "42948.1640625", "45076180.0", "-1.066617951289395e+25", "1.7372012797965333e+29", "-0.07332682609558105", "-2.0415264195339502e-17", "1.0112311443473835e-16", "4.517530793890501e-09", "-9.61027537098272e-33", "-1.8220479313406619e-28", "1.3499557870062148e-19", "-5.226697088475769e-36", "6.199385770083267e+28", "-5.628098014009082e+23", "8.280421748792739e-22", "-5846957293568.0", "NaN", "7.1629946418738655e-09", "-9.939246644846799e+33", "8.844749126093648e+29", "1.6264833917779968e+16", "9.576709570605371e-32", "520098016.0", "-183388323840.0", "4.766384665627564e+21", "-9.26326919096307e-25", "-577628004155392.0", "1.7346040012033314e-29", "7.53194692211261e+28", "-5466094819082240.0", "-1.7983847380881988e+27", "-1.3456025260526808e-33", "2.4963891989409956e+18", "-6.239306848954129e-09", "192013402112.0", "-2.400074969798328e-17", "1.7866485204516864e+16", "0.24164767563343048", "1.869493402659342e-31", "-5.491018106279035e+17", "0.00032648674095980823", "-7.256933459365437e-36", "-2.985937044286402e-06", "21851637760.0", "9.228040423817085e-14", "1.6259819879405057e-10", "-2.3720428832946228e-12", "-5.1059935474801996e+23", "-6.385447250067955e-06", "-68722035261440.0", "-2.3534726681446564e-33", "-2.741144095223607e-15", "-0.12888720631599426", "-821.5543823242188", "-5.871844291687012", "-5.671206836257297e-25", "-6.820295537904845e+16", "-7.375919088657718e+24", "5.409317545824139e-28", "-0.00046586920507252216", "-1.3568360514848817e+19", "7.524396449812636e-36", "-4348742144.0", "1.7129439309981006e+37", "NaN", "1.545426673613634e-29", "-98041.1171875", "1.2182053024721242e-19", "1.2308462409016396e-11", "-1.2221970249888563e+17", "-6.478680421498932e-26", "-2.2109164317633955e-36", "-1079.46875", "-8.943216016632505e-06", "-1.4255969451801298e-12", "1.0373081502149788e+25", "-2.5700494114605163e-14", "2390635168399360.0", "-3.9719392702480373e-23", "9.454377085743845e-35", "1.1738741035081407e+31", "-3.3595774495879575e-29", "4.320374898655578e+20", "1.0688939048484551e-16", "-3.0041374491924632e-15", "2.627536443014507e-30", "-5.097569432397364e-29", "-8.76271273458955e+36", "-6.878254486370672e+22", "-9.616974234354452e+36", "8.520501351591244e-16", "5.719615176368694e+26", "NaN", "-447328326516736.0", "33438716.0", "-0.00018712836026679724", "-1.9326453651390805e+20", "-0.22596116364002228", "-3.151578037860525e-19", "-51910.51953125", "-3.298775482107885e-06", "-6.864006536488887e-06", "-6.060440798870122e-16", "2.9837619885954914e-27", "0.01153660099953413", "-375759843622912.0", "3.022608019647906e-29", "-8.751902550376055e+18", "6.668068043295607e-35", "-3.141614835611623e+21", "-2.1153628153522847e+28", "-7.992107409104659e-18", "25874430689280.0", "-2.6526031805113037e-12", "-1.4473917337077446e-24", "-475953948000256.0", "-9.92732778577407e-15", "1.834205248255545e+35", "1.8653618272844817e-22", "-2.6861300987724426e-20", "-2.3816222970717986e-20", "11624785.0", "2.9635655899065804e+30", "-1.974074472791681e-17", "NaN", "-4.887528923247508e-11", "-1.3319262616181582e-24", "3.2763808172940116e+26", "-12648.2353515625", "-0.43811362981796265", "8677.92578125", "1.7186151675231026e-18", "-10426.20703125", "95.32935333251953", "1.3008112505429653e+35", "-6.782095125132495e+31", "-3.36514405012862e-25", "-5.249659397881895e-27", "4.856730807655465e+17", "-2.983122635937035e-23", "-1.689253537051627e-08", "-1.997111410652287e+33", "0.002647912362590432", "-1.9467946406424158e-13", "-3.705376351759536e+20", "0.6123515367507935", "-1.8288892762353255e+36", "-8.84873297139939e-15", "-44479.4375", "4033656389632.0", "-1.4132073983263993e-11", "106552262656.0", "-2.3699190616607666", "1083892.75", "1769676178718720.0", "2.655764334575511e-13", "3.966065512433282e+30", "-2.1736239544378755e-11", "-8.929267445477636e+36", "-7.2933826231746934e-06", "-1.1966848373413086", "-2.4867054019187622e-33", "-4.565225303140476e-28", "4.1815688281779395e-13", "5.510423579835333e-05", "-1.5604532233428128e-14", "8.624453900176946e-18", "4.295823374831088e-38", "8.050273275745455e-25", "5.363197175256573e-08", "1.6501476139161742e+22", "-2.558702908572741e-05", "-7.427984514657138e-37", "-0.003583310404792428", "8.522984984438153e-08", "-9.456806045919655e-18", "4.407126502576607e+32", "-4.4251516852417995e-15", "-1.5161301762914596e-21", "197.46205139160156", "0.00019286925089545548", "-4.631460145954058e-11", "2020.3671875", "3.0874632604814333e+35", "3.511527604595588e-28", "-4.0895150833097483e-25", "-6.28890464298592e-16", "-3.531569839700837e-30", "-2.821034697953172e-26", "-24986655916032.0", "0.007306420244276524", "-269087195136.0", "4.2665957153076306e-05", "0.289754718542099", "40.61266326904297", "1.4946838377398272e+17", "-2.6924905712260313e+31", "-8.45255497275304e+31", "-2.868115190318137e+32", "-4.7683987772199305e-17", "6.735103699119021e+23", "3.2050476487175605e-31", "11.614056587219238", "-2.8044946554957114e-17", "5.790686313411119e-37", "-2.6950842877220916e+33", "9.383956012243289e-07", "-1483696720838656.0", "-1.1207678439610028e+21", "-1.6158200516564495e+22", "3.0862526046406137e+34", "-3.906700669631391e-07", "-1.5143456088407814e-22", "3.90908488231792e-34", "-571354644480.0", "-1.0819051383947897e-11", "8632079.0", "3.9569528209604243e-10", "-1.2604113562022142e-38", "-6.448596130746134e-35", "4.3544848162651703e-32", "-3.2152092888806735e+28", "6.067819857680234e-11", "-2.450707185170593e-29", "15826811904.0", "1.2500770500514013e+31", "23241478144.0", "7.994064040778223e+26", "-1.2763661807983175e-20", "-1064052416.0", "182760673116160.0", "-1.6243840423214944e-25", "1.8791685194119093e-26", "5.435311420710768e+34", "-1.6202115205032896e-09", "-1.1004971372713162e-38", "0.5471541285514832", "207273.34375", "8.581076031866783e-19", "2.3288628244574634e+19", "1.1725246204652785e-34", "-1.6411066313591885e+25", "1.7674025297164917", "0.0023181538563221693", "-2.1548473105328414e+37", "3.957392180983999e+26", "2.3822523760540168e-20", "-1.4116504182444436e-24", "-2.3660508805344293e-17", "3.991841145828401e-12", "4.8064295057013984e-14", "-1.9319953318099655e-16", "0.002161611570045352", "-1.1348931516774741e-10", "-5.665861113612891e-10", "-20349678780416.0", "-0.04216494783759117", "-70516.6015625", "-509.0779113769531", "-7.5839110345304365e-25", "-2.6029781284539656e-36", "-1.88749854015488e+16", "2.727062393731397e-28", "2.5153181099121275e-31", "-0.00034077849704772234", "-2.525624255492912e+23", "-8.415178598397688e-08", "-7.407844322963921e-32", "2.636296970867461e-25", "4.292152473861804e+29", "-162047.71875", "-0.5144253373146057", "1.3805589560433873e-07", "-4.735375736863809e-29", "-6.38985075467396e-27", "-65914015842304.0", "2.1791925631630543e-13", "-6.345814720989915e-22", "-3.837390123307002e-18", "-3.2803961067831445e-26", "8751736832.0", "2.284629226778634e-05", "-1.4769800169054536e+18", "-25631630819328.0", "1.228638943190195e-28", "-9.754340823704712e-24", "4.416028195774267e+36", "8.774503939597926e+16", "1.7554796933586388e+37", "2.0930896163725047e-16", "692692.9375", "-1.846565367519086e-14", "1.077540048299852e-07", "-6.992611522985106e-32", "-0.002524498151615262", "2.445320803010358e-15", "-0.01066362950950861", "-3.173249197591017e-24", "-2.724141589191738e+38", "-4.314753443424024e-09", "-5092.03857421875", "-1.2607091850233131e-14", "-1.0772458125529738e-07", "-2.555980049106862e+17", "4601031950336.0", "-1.4422703316085972e-05", "-3.7339322913615776e+31", "5.6445503653079765e-12", "4.002133266987635e-16", "7.289196944534856e+35", "-6.95236750114514e-12", "-7154780602368.0", "9.189586336333377e-08", "-7.637659610084483e-27", "-1.2100137561382168e+32", "108095600.0", "-3.756437152234209e+17", "4.903471250113307e-18", "-39.157470703125", "-28360816197632.0", "-12.113846778869629", "-1.634725341204124e-13", "-0.009680680930614471", "2.3907661107952277e-16", "3.333598950984755e-35", "1.8208184362680162e-36", "-8.798844465529587e-12", "0.0008554505766369402", "20.180986404418945", "-4.902902628160788e-13", "2.4035935681127234e+31", "-1.24671692571712e-19", "-3944848809787392.0", "6.797904780002956e+32", "20817956864.0", "3720087.0", "-3.213807244904396e+23", "5.191049129584419e+20", "10.107741355895996", "1.383810277452096e+35", "2.3738782838758655e-18", "2.7961852785466787e+35", "6.15386662955483e+37", "-5.659156233790366e+33", "5.228510978460958e-16", "-2.1834879693820673e+26", "-1.6201553660359573e-36", "47.14645767211914", "3.771562870347137e+36", "-7.391062337140173e-21", "1673179.875", "1.3459849046144976e-14", "2.1626481855330226e-11", "-1.5487127017389365e+22", "6.346323149038604e-29", "-1.240505143051962e-09", "-5680200704.0", "2.911227428455516e-31", "2.2370757009508284e+35", "1.3972887903364696e+37", "9737261285376.0", "-3826.97265625", "-67501328.0", "-232.82713317871094", "-1.1240607056262064e-10", "44266659840.0", "255886770176.0", "2.8488345713118765e+35", "3.7519043312289385e-38", "1.4726971356806735e-21", "2.4530304323921326e-24", "-8.580001989540171e-21", "6.73757582782032e-33", "-16122.45703125", "0.00017419880896341056", "-3.601977596190409e-06", "1.6281462911023718e+34", "-8.971518175396624e-23", "-7.060045330250841e-36", "3.2515079693059176e+38", "3.35084243940245e+37", "-3.9176793072258656e-36", "3354.5615234375", "-69145966149632.0", "-206.69137573242188", "-0.06601829081773758", "-4106550528.0", "2.470279423829737e-36", "-2.732610890170127e+30", "3.2065938969352313e+21", "1.4193897968658022e+18", "-5.2078885905414654e-34", "-2.183998974264318e-18", "3.961119333618519e-34", "1854046918934528.0", "3.444046432262543e+28", "0.009494525380432606", "-1.7883935439857346e+34", "-1.3711239262192976e-05", "-4.063668138479869e+29", "1.1470090524986982e-27", "6.806352789803902e-19", "1.996225108800344e+20", "1.7422877720024634e+24", "-9.037381634782805e-17", "2.882024207165722e-38", "-1.3625876517853594e+18", "-6.143741653578966e+28", "-4.059082728553247e-28", "2.9591242125512384e-15", "1.7834598786042977e-29", "2.377937631032066e+29", "1733141760.0", "0.0003890032530762255", "3525.889404296875", "-6.026868622541624e-31", "-8.801080420006713e-10", "-5.561500283203628e-31", "6.05048299477719e-23", "3.2998151546673824e+19", "2.312150283717381e+33", "-92047016.0", "1205271808.0", "9.702095585666934e+34", "3.486296483699477e+37", "-2.3755000744252186e-10", "4.4352454153795406e-08", "-9.848571608017664e-06", "2958280097792.0", "-4.121868002422474e-32", "1.9713570306755492e-20", "-0.06534546613693237", "-3.9141688382268136e-29", "15822.076171875", "-1.8392628750218324e+20", "-9.224177521749981e-31", "-1.1019937444893787e+21", "-0.0007452722638845444", "-34686291968.0", "-1.7201517966655488e+16", "0.0037520453333854675", "7.966501191129498e+17", "9.948618357242471e-21", "6.712542968886067e+16", "5.283636739878041e+28", "2.3264670989573427e+32", "7919.33544921875", "-3782.4052734375", "NaN", "5.334997309376643e-13", "2.859006064658109e-19", "4.9335167635283365e+36", "2.5189969664198197e-33", "0.0003012656234204769", "1.3037127195693732e-32", "-1.437894781020077e-07", "-0.00014495749201159924", "126696071168.0", "1.2660221418354999e-21", "-5.368638453206871e-32", "-6.3492239096095726e-24", "1.3331240883372463e+32", "-1.3955207980443313e+37", "-4.142513887472887e-09", "781.6947631835938", "79.82874298095703", "5.305859565734863", "1.6901834802789781e-28", "-1.3082579512646727e-15", "5.8414225350134075e-05", "-635254912.0", "6.038767228982972e+19", "-5631167168512.0", "1.1317026309132228e-11", "-3207643.5", "-1.190648686313472e+17", "-16470815744.0", "1.3086901520637507e-38", "-2.071013499582719e-10", "-260188.46875", "-2.3074279927555567e-22", "0.10655001550912857", "2.511985810026701e+37", "-4101483582717952.0", "7.172090241200232e+37", "7.660637590714363e-22", "-2271449448448.0", "9.039121345360546e+25", "5.613641140566753e+17", "317576642560.0", "-29378473885696.0", "1.2516029644843562e-29", "6.601661399176039e-39", "8.564002610258903e+17", "0.00036057515535503626", "-1.540673953982815e+30", "3.525783523626552e-23", "121186369536.0", "341495.0625", "-1.5096905608462336e+16", "3.726863953895829e-26", "2.9486510419161907e+18", "-7.938545247597809e-15", "-243325894656.0", "2.4525040693326506e+27", "1.2967293165750273e+36", "-4.3100659240735695e-05", "3.154210390459462e+22", "1.2205099127540753e-12", "-61546528768.0", "447899691384832.0", "2.4489455611245863e-30", "1.861420572475392e+16", "-4.6273449134479354e+30", "6.522890993328088e-37", "8.601078028220717e+33", "-3.4636296138303553e+28", "-2.9354057485796133e-30", "-6.55787938761598e-31", "-4.731408383920382e-34", "-1.805840854259916e+33", "-807896320.0", "8.845993040536766e-29", "2.4261097853824563e+25", "1.937359474235567e-11", "-1.885571094817351e-07", "-2.5403897735420955e-12", "-1.093147298408587e+23", "1.5161443462089277e+18", "-2.9342770483079085e-29", "-4.0111312516092e-36", "-5.876616848253599e-25", "2.256165842417751e+20", "-9.802405036094607e-29", "-0.01475480291992426", "5.979611249269838e+33", "-0.00013963194214738905", "-1.8996886863076278e+34", "4.7635618093811286e+23", "1587100032.0", "-48225767653376.0", "124294533218304.0", "-3.4442256302437962e-25", "-7.033260114928201e-26", "-4.0879760514200836e-12", "-8.831238806000816e-35", "-1.1794568605335552e+16", "-1.4529666588419377e+35", "-7.948657717200112e+20", "-61749657600.0", "-6.675845052698204e+31", "5.064677402007451e+37", "-0.0006341925472952425", "1.9283204793683027e-14", "-3725543936.0", "-0.00033763243118301034", "-55714626142208.0", "-1.675731037160929e-27", "478.9385070800781", "-70879.453125", "-212.29010009765625", "9709491200.0", "1.0408134466407253e+26", "-2.1796719984479296e-09", "-8.811890280531358e+31", "-172.09188842773438", "3.8744710629863146e+34", "-3.596626640794509e-22", "2.1522546048747805e+20", "6985138315460608.0", "-0.0017661555903032422", "2375852032.0", "-7.561350345611572", "-5.370887686854884e-24", "1.5165767803531897e+27", "-0.16750144958496094", "1.498810370051464e+33", "-4.235270978369954e-08", "2.595084254153335e-07", "-0.00021998472220730036", "-1.381402927340328e-24", "9.619033634970852e-29", "-2.066952705383301", "-3.074392243983965e+25", "3.0316000318290385e-22", "-0.04373583570122719", "-3.2401339606201474e-22", "1.5418441349419315e+38", "5.126375886288503e-12", "-2.0569315695162516e-10", "415.2367248535156", "-1.5992310039461233e+18", "7.631391424623072e-22", "1.7313950702226976e-22", "53633224704.0", "-0.0048150126822292805", "-6.289173046551081e-15", "0.0009148692479357123", "-110214741229568.0", "8.661868196213618e-05", "4.840136084815872e+16", "-2.3477548365248384e-16", "1623960.0", "-6.526220414352288e+30", "-1.8117089425895294e-11", "9.19681134683407e-21", "40613948.0", "4.923789020949698e-08", "7307172773888.0", "-1.1358132444161408e+21", "-3.6027777282279486e+36", "330.068115234375", "1484089589760.0", "-7.418908442548098e-29", "2.0315936733735636e+34", "-1.8037445190764375e-27", "1.502947844837418e-32", "9.870780448700828e+37", "0.15928737819194794", "-5.324922584623157e-17", "-5.827875884208719e-32", "2.359852337672942e+24", "4.220989994432729e-24", "-1.2579970842807597e-08", "-79776030720.0", "-2.3186653919521434e+38", "3.641036853529317e-14", "-264178287771648.0", "6.767472809502213e+33", "-6.015932589089506e+21", "9.546258228354532e-11", "2.016435729501609e-07", "-4.408026503271416e+18", "-2.145972094389715e+18", "-4.967037579397582e+29", "4.880809189666345e-19", "-350100062208.0", "1928912.125", "-7.64278738206229e-18", "7.51832070152777e-25", "-1.6948641379244123e-09", "3.9123608197697686e-32", "-1.2071905577162998e+30", "-7.092145186881928e+30", "-5.119806090192469e-08", "-1.3738968758798677e+29", "-7.026241211977513e-15", "-0.009596964344382286", "1.5462529818192112e-27", "-5.756309873206078e-10", "0.002655857475474477", "1.3892581330635827e-25", "3.5847992307935783e-07", "5567837372416.0", "1.0403140759848274e+24", "1.571102015474736e-33", "-6.294903187985678e+28", "600.00537109375", "0.1938372552394867", "-5.721419128507659e+21", "1035870797824.0", "30111452.0", "379.23980712890625", "388506.9375", "805063568130048.0", "19.56532859802246", "-1.2700025684857434e-31", "2.83824114089725e+20", "-2.6241371742230513e-08", "-9.823157104771667e-24", "6216909863256064.0", "1.5549039039977776e-20", "1.909629270546687e+36", "-5.0010125132207856e-33", "-2.598194092843567e+27", "1134423403659264.0", "-1.1908404812339004e+35", "4.142656513300687e-26", "-1700.3592529296875", "4983381504.0", "5.429283323141043e-36", "0.20776313543319702", "-1.7345278385716783e+31", "-7.476323847456191e+21", "-1.4681904767192283e-26", "-1.5626884687036524e+38", "-8.808105444518618e-35", "-462869233664.0", "9.72415909927804e-06", "-6.353322830873101e-24", "-138089.6875", "-9.474663717291818e-17", "-4.14776650359272e-07", "NaN", "-6.449851272457897e-28", "-14885809152.0", "-5.3794929044670425e-06", "-2.9088033959004726e+38", "-5.3393081819177405e-15", "5.579271311444145e-13", "1.6883149170706974e+21", "-620571584.0", "-818081792.0", "5827.1611328125", "1642248536064.0", "6450.05224609375", "1.104799509048462", "-1.7868728177876326e-23", "3.0454179394631266e-27", "7.684345347536148e-12", "1.2615221006924947e+33", "1.892664049792615e-26", "-1.0703798701566615e-38", "-2.1337413721894472e+23", "670659584.0", "1.6241694133275336e-14", "-1.1027048908491313e-35", "-3.6548379199907716e+36", "-12476145.0", "1.0445837250738776e-10", "-740.1925048828125", "2.783146467727949e+31", "-1.3919960677586428e+32", "7.655019305146253e+18", "1.2364381564745116e-20", "33054748672.0", "0.11569031327962875", "98154364010496.0", "-2.5425591862077096e+22", "0.022611934691667557", "2.5782186473371716e+32", "-6.200560694774072e-16", "-3.265417141959098e-24", "1.9052188857955984e+30", "-2.4089203219475366e+29", "2.2682710256349138e+20", "-8.30631700295023e+35", "494.7254333496094", "-7.006689065752747e-13", "-1.6889941161895002e+20", "4.756189260506403e-17", "0.0001754240511218086", "1800679680.0", "-24168850522112.0", "-101.89521026611328", "-3380.8828125", "-1.7220945510357707e+34", "-5.6359391682660595e-31", "3.88288826297183e+35", "5.003450750955244e-17", "-1.3223760273780322e+36", "750426.6875", "-1.1701292134252623e+20", "1.9288413340898e+29", "-1.1595746675632997e-35", "0.017780190333724022", "-1.4052368977367098e-37", "1.0637347020827481e+38", "9.191889762878418", "253578480.0", "-8.3674204758097e-36", "843.6901245117188", "1.8112478220628145e-20", "1.0817585486071248e-16", "425621152.0", "3.7122875021483244e+30", "2.4833567294990644e-05", "1.78372187870326e+28", "1.8489693403244019", "-9.485915915090514e-30", "3.3122313035185338e-22", "1.7798383353355158e+21", "-2.8154595804330777e-33", "-7.998676257144095e-19", "-1.2090265849541318e+25", "4.245625337314017e+17", "6.526315963302903e-19", "5.3678258339621476e-31", "-1.4837376763606398e+32", "11680740.0", "3.048821295291395e+23", "1401536512.0", "-2.1864212612854317e-05", "-7.385176471878959e+24", "-3664084.5", "-41755172864.0", "1.1407221430847378e-26", "0.0016447589732706547", "-2.837701429915372e+35", "1.1436448820079982e+24", "4.640640085748493e-13", "-0.3126351237297058", "1.6082999682665546e+19", "432546928132096.0", "2.9826179418927573e-15", "11752349630464.0", "-36077019136.0", "-8.510075417689222e-07", "-3.578075147746361e+37", "-8.152748803338872e-27", "1.0798587574368017e-37", "-2.0774024044211303e+26", "-1.552687844811638e-35", "-6.193269329284989e-17", "796393664.0", "-2.9474690891380487e-22", "3.248766542777847e-34", "1.3109970092773438", "3.697143437158759e-16", "NaN", "-1.2724491034085074e+34", "-6.0342873921768225e+23", "-1.2710150217701066e-32", "4.431532629076072e-36", "8.266359259323508e-07", "4.904964780692855e-13", "3.96079683303833", "1.089972331382176e+38", "-1.5064257628152342e-17", "1.0922947083291853e+17", "1.0346127708722506e-24", "1.324131602975509e-11", "-4.43298795984257e+17", "473913664.0", "1.2528116564991532e-12", "3.037139038042369e-07", "6.524104113452483e+17", "-884491616256.0", "-1.3380468099514368e+17", "-2.0860245970639006e+33", "2.4313637391391114e-30", "2.853890949781738e+33", "3.5242087059968916e-31", "-1.0257645089235509e-32", "-2.1260664198052837e-06", "-1.1341359641380336e-19", "1.7307047389408478e+22", "-5.086294226249329e+24", "2.5787780032260343e-05", "3.435463973869716e-34", "9.476527340623875e-10", "-2.2132180161095202e+24", "-5.130050226010189e+26", "-1.817292556470403e-24", "-1.817983087746937e-14", "-3.1597637655035615e-16", "-3.0897827283215684e+37", "-4.071385429786175e+28", "1.4136542417050836e+26", "4.0474265223248017e+30", "6393565.5", "-1.5324472339234973e+20", "22823837892608.0", "-0.0005289356922730803", "4.568392105580577e-13", "-2.066098472406399e+31", "2855591477248.0", "3.6082859917740784e+21", "-305459331072.0", "-1.2729420201836498e+32", "-0.01321160513907671", "-6703178240.0", "-3.8257464357701185e-26", "-2.94431351929942e-23", "-1.2299080466260092e+36", "2.0560913882782357e+22", "7.513452812703077e+30", "-3.744351488421671e-06", "-5764.0869140625", "5478640779264.0", "3.2214745411273144e-19", "1.4612906936653932e-30", "-8.032480641287711e-27", "7.226238285192896e+35", "3433195529830400.0", "7.75771800704e-16", "6.455255913364334e-28", "2.5880508763949603e+20", "6.221489238991438e+18", "9.952418702857527e-20", "-1.210290103814324e+35", "-0.0007338792784139514", "-1.056418735185306e-25", "-2.2467447706731036e-06", "2.3528264992360827e-15", "1.765968673127339e-32", "9.991048961474781e+30", "-2.979838055992834e-13", "-3.0233539641734684e-35", "4.177375830727016e-18", "321065347710976.0", "24244156416.0", "1.401770618588499e+25", "1.9150671827562308e+26", "125736607744.0", "-446750988959744.0", "2.292426256475405e-21", "1.0182561902658281e+35", "1.4096521320339207e+28", "-2.5300086628149568e-24", "1.0965669581306758e-30", "-0.0011982766445726156", "3.988690524494418e-25", "137420390400.0", "1139.496337890625", "-8.777029018523577e-18", "2.3148459178991642e-29", "746.5621337890625", "-1.9658599954524795e-20", "-0.02862456627190113", "1.0387425133527509e+35", "-7.161712792062227e+20", "6.079178552686511e+23", "-1.0805545996547121e-35", "1.7522567510604858", "-8.92970035692997e-07", "-1.382713463726759e-07", "1.0773867050460774e+17", "2.532246796335209e+36", "1.064448421868549e+19", "0.0017845459515228868", "-2.9940147684370357e-30", "-90508.859375", "7.253370768012246e-06", "-4.967873204035673e+31", "-218922569695232.0", "1.189766982824781e-32", "1.7077791690826416", "1.4674558323970378e-30", "1.3042110189214131e-19", "1.0697413632793244e-25", "-1.8081480832175792e+28", "-2.5067635045126576e+24", "-0.02528843656182289", "-13232143360.0", "4.140301400502436e+17", "-3.7616212886393833e-26", "-0.0001005495578283444", "3.3540724797684804e-28", "-5.1888690858630505e-20", "2073.40673828125", "57443979624448.0", "6.3743439630203995e-18", "-8.754977623997436e+27", "1017510592.0", "6.072121128980131e-33", "1.2744536329045708e-13", "1.623600749288065e-13", "1511696512.0", "9.345245851065854e-18", "3.2038205327857943e+19", "0.25225356221199036", "-3259950.75", "5.52447115831356e-11", "7.671861820375026e+32", "8.081932733937313e-32", "6.648847426391897e+22", "2407.09130859375", "1.0242555248396457e-08", "-9724197888.0", "-2.0115726372484967e-14", "0.07864657789468765", "1.0425904799599234e-34", "7.929869769159633e+23", "-3.997273724531393e+34", "-1.468667824963584e+16", "-3.054073906020926e-17", "-0.0012743580155074596", "NaN", "6.771183693139254e+29", "3.1833889679138095e+19", "6.081914213184554e-19", "-3.4326261668192425e-29", "1.820069033320797e+26", "5.491177673979654e+34", "9.421068548265457e-23", "-2.2137119719819497e+30", "-1.5132649611269208e+29", "-3.7403412302553917e+30", "-1.028145786146099e-14", "6572137775104.0", "1.8154371739430505e+28", "-2.693172352104058e-33", "-1.3244858564576134e-05", "33754621935616.0", "-1.926614913827507e-06", "3.5251947106583613e-31", "9.05155534368864e+28", "2.2387149862278825e-34", "-5.208333462517278e-17", "-8.514509626869615e-13", "6633.29736328125", "7.910579987850622e+31", "-1831.8602294921875", "2.5652140076644093e+26", "4.5697404194022795e-12", "-7.811694984204334e+19", "-1.0508163442150658e+23", "-5.603156393724218e-12", "2963949.0", "-1.0353625339882297e-18", "-8.779867159908541e-14", "-6.959890583857486e-07", "-1.6617703046950538e-10", "-136021598208.0", "1.8302221915296932e-38", "-6.040967032161504e-11", "-1300148256768.0", "-1.5550654297244448e-12", "9.55888953944219e-37", "-1.9487519931082886e-10", "-5.3576148875900504e-33", "-2.846364447230472e-23", "-819.8429565429688", "-7.06255872658361e-32", "-8.423002746671404e-12", "-20420.177734375", "-4.45840678530601e+35", "-2.6498449748044124e-31", "-2.5071313796865043e+34", "3.092728156905977e-11", "-5.456471940945562e-10", "-7.031328711398024e-37", "6.214402801318984e-17", "6.763850137758709e-07", "2.0329582461897644e-10", "1.0042837298627815e-19", "6.34921297524012e-18", "8.518630602634291e+32", "45.12253189086914", "433400288.0", "1.472679825316756e+31", "1.2765947026531207e+25", "-1.0036218166351318", "1.0213203100271602e-36", "189145872.0", "-6.018808364868164", "-1.92794013855746e-05", "-1.7383749805165402e-37", "80288289914880.0", "1.5354249573325417e-18", "-5.698242478925303e+24", "-6.772716159007968e+36", "1416116349960192.0", "-7.30721172801093e-29", "1.8296325058540384e-34", "1.790567987840806e+29", "-7.97264462477311e-21", "7.990714519250297e-33", "1814.596435546875", "-1.5438106603328246e+36", "-2.429033536672064e+24", "1.3966971059797915e-14", "-9.323682681680036e+19", "-1.1581981316548125e+34", "3.85534688749195e+31", "-9.08294714463409e-06", "3.451735734939575", "-1.0881664423159256e-26", "-5.386950240827051e+31", "-6675.6748046875", "-34956.33203125", "242.78945922851562", "-3812061.25", "-1.6216264558900382e-35", "-1.9152477470938604e-36", "2.7280993461608887", "-2.5712732096903007e+26", "-1.30785245662374e+20", "-4.0799373715993565e-38", "-6.666260673468354e-21", "13701342035968.0", "-1.3200873354435316e-06", "2.4511728537933206e-38", "3.900924668232526e+25", "0.02378937043249607", "5.355677158431785e+17", "155338391552.0", "-3.2792770438667095e-15", "-1.1144069866753884e+18", "4.494492053985596", "85705576.0", "1.1683651600212736e-28", "3.6559021593059544e-30", "-1.345010436359928e-09", "429336704.0", "-1.8699058296664844e+34", "-2647.568359375", "2.7913503799292457e+35", "-4.731977674218181e-29", "-6.350233739355517e+28", "3.1271399315510595e-13", "-87781992824832.0", "-31212507136.0", "390115584.0", "-83446512.0", "136895422464.0", "3.258423475926975e-06", "2.714805871861814e-27", "-3.721956506903827e-23", "-592163918315520.0", "2.1068481810577999e+24", "4.004165473398675e+37", "8.162993747123194e-20", "-4.6368728461519086e-29", "-1.0259082130906552e-13", "1.1376876708931832e-19", "-8.892887947767793e+30", "1.8921214991638724e+27", "-69.08406066894531", "3.54943948431486e+26", "2.5243170014954252e-17", "1421343195136.0", "4.682672170019941e-06", "-1.1270235759500997e-32", "-1.2863090694757897e-21", "16228.1171875", "-6.292028729149024e-07", "1.23993362122365e-38", "-1106967296.0", "-1.3791427175873534e-26", "-3.2160053687484416e+16", "1938855.875", "2.1674088657164248e-07", "-2003531.25", "2.777575325296766e-08", "5.666641476944782e+25", "7.588354293579094e-25", "-4.2923418974261647e+30", "-4.031812839478577e-30", "4.341165821927189e+37", "-2.6877256603532217e+25", "-8.94267727359519e+19", "4338124800.0", "-2.132854645733272e-36", "1.057851251460515e+25", "2.1003905524183464e+25", "5.751209314142808e+22", "-0.00025406351778656244", "-1.631810443263472e-31", "-1.1477832806118195e+17", "-4.0489348080740105e-31", "5.661972946460981e-36", "8.144605390745754e-32", "5.244589484920681e-13", "2.1735883137807832e-07", "6.516083082669368e+20", "-15080072.0", "835761856.0", "-3.144727225375392e+18", "-1.619891916297987e-22", "109474000.0", "-1.9827817843398417e-19", "-1.2336061611146438e+31", "1.0978406319233988e+34", "34113888.0", "238190542848.0", "-0.5386185646057129", "-2.643760512081654e-23", "1.9349000179857967e-10", "7.327144669491522e-19", "-4.874310615947731e+20", "-2.9662101663774365e-09", "-2.7949356004823115e+25", "-1.3123314415965376e+26", "-9.115151677236041e-38", "-7.78007103611544e-26", "1.4224404367269017e-05", "6.242507036871526e-15", "6.402141367505244e-18", "1.0514219904111275e+35", "6.016866872204333e+29", "-3.829405142660798e+24", "-4.001716136932373", "1.1140857644415053e-21", "1.35768928706124e+37", "-2.9986158347930946e-34", "3.5236277975665807e+31", "2.284335091608561e-25", "3.944302880412968e-31", "-5.449713464227615e-21", "-6.032477929257695e+36", "4.731980978238927e-34", "-2228293457674240.0", "-4.118222236633301", "2.1271986736289248e-17", "1.749405934765379e-34", "-5.812449530393807e-20", "5.80390739440918", "4.4756007788748775e-09", "1.5133550993109678e-24", "NaN", "-3.9627097066444596e-16", "-1.2414033394577021e+20", "-3.0807889572770364e-09", "-3.1574888907925924e-06", "2.9016512904563433e-24", "239016.3125", "-1.0576759479389112e-32", "-4.318114733405585e+23", "6.672603490007048e+26", "-2.7651294234043374e+36", "4.87104378133923e+24", "-4.0333535082024486e-28", "8.445866114925593e-05", "-2.6681863099162206e-13", "-2.2367433019686647e-14", "-245.4928741455078", "259816731705344.0", "1.1137359142303467", "-4.641238743919286e+34", "-2.834903113648666e-36", "-3.6978867952104747e+21", "-4.019869892105861e-29", "2.0165245421019848e-35", "-40554062675968.0", "2.9786112168295032e-33", "-8.180932275356628e+21", "2.4121608098280818e+29", "3.0476382477995134e-28", "7850325.0", "5.1494441675915264e+17", "-5.755874761881787e+19", "-1945611403264.0", "2.568105369029828e-25", "-1.1773852629700474e-38", "-4444830720.0", "-7.608753025489934e-38", "0.266475111246109", "-3.8169397784114187e+26", "1.340151990905023e+26", "115119895871488.0", "6.005822342177594e+19", "1.4825935686100011e+28", "0.0008081407868303359", "-4.843476538580009e+17", "1.4711487975584444e-25", "-4.107083573235908e-20", "311.9978942871094", "-2.7807268022950144e-25", "-8.693441799767498e-11", "-2.6371123837989217e-18", "1973310208.0", "6.39777805220547e+22", "5.770494245022764e-31", "2.23993037087438e+36", "101.37590026855469", "8.291919463187731e+34", "5.02855130495133e-22", "-209359024.0", "-2.598153498782293e-15", "0.00012647933908738196", "4.9705231314350684e-30", "7.895777854451876e+17", "5.412282026896653e-22", "NaN", "5.8722995789678195e-34", "4.0055286832599984e-14", "3.7319861938540778e+22", "1.7839480988564136e-36", "3.1343278730844213e+30", "-3.055881512794265e-19", "-6.497448993992975e-27", "4.014952750453356e-33", "2.0976596459831853e-08", "-2.9537711550993463e-31", "1.6134937542608812e+33", "-2.792926549911499", "-1.5477039259005226e+38", "3.2585535336693283e-06", "3.788234170798432e-09", "1.7651147210528961e-09", "-2.2918938968885527e-10", "-6.373302794452124e-31", "-2.766407979429286e-12", "-1.0000507197252106e-15", "1.1936101580028753e-36", "-1.4081783913691433e-37", "1.104238990221738e-25", "7.266865045574457e-20", "1.6979782628391226e-26", "-1.5958351439531663e-31", "1.1708912354564506e+18", "2.2608704557963413e-32", "7565980160.0", "8.693673589732498e-05", "446011328.0", "-1.841616944393716e-29", "2.4217169699824634e+26", "-8.976823411265553e-16", "-3.0152976974190052e-21", "9.898020004677141e-24", "-5211825152.0", "4.0648466632463886e-25", "-167249214177280.0", "-1.7252528098880295e-22", "-1.5878263195870167e+18", "-1.128766087413762e+36", "-3.665151598311005e-17", "-1.1920913642075183e+24", "16280726.0", "2.9066276926280965e-20", "-2.986281057718685e+19", "-0.0010330857476219535", "-9.43073003424868e+18", "500405792.0", "1.827942239935459e-30", "-1.1314251691936055e-17", "-13.788213729858398", "-1.2108094474966302e-12", "1.3254428634097558e-17", "7.817410608841441e+34", "6.041103363037109", "-6651550208.0", "2.6451439137692058e+17", "2.0448286020874443e-20", "6.324962708173654e-27", "630557704716288.0", "5491951927296.0", "1.7995787735865596e+20", "-3.1964787262396543e-13", "2.4891093803228682e-23", "-1.9737136159991882e-24", "1.2192479035988239e-33", "2.1325136828028935e-24", "5.545760376797979e+20", "-6.241046510754206e+37", "4.1172644993606775e+36", "1.67987609527166e+29", "6.491201200287155e-36", "4.315315952688476e-20", "-2.552351133999764e-06", "0.3157500922679901", "0.1429319530725479", "2.2898990239801978e-14", "-1536366.125", "-2.169541965045539e-34", "-4.161513633320964e-16", "79.012939453125", "8623.2216796875", "51255545856.0", "1592171648.0", "-2.4929992027200578e-36", "-0.00011906259169336408", "1.5014804125169536e+21", "2.2032225469781163e+33", "1.289883216736229e-19", "5.66631979381782e-06", "-131216506880.0", "-3.2893552790423664e-13", "-2.374796482293761e-38", "4.7276953388941804e-30", "4.735139008466282e+25", "1.6796071406558434e-23", "4.6443975757556465e-32", "2.1136001138717674e-18", "1.3960013700575757e-11", "1.4311110074771705e-09", "5.188584460739186e-38", "0.004293943755328655", "1.5660924162395565e+27", "-72808856.0", "5.198246987563929e-19", "0.003803797299042344", "4.0072010647463713e-28", "0.0006450873333960772", "-18909626368.0", "-4.7609190250621936e+24", "186075.171875", "-1.196339470309528e+36", "-2.904870868024559e+35", "-0.006124162580817938", "1.6383240465184857e-26", "-2.5976098306850978e-14", "-1.0217198342192253e-15", "-1.556440830230713", "-125.12198638916016", "-1.3849503667492682e+38", "1.0221324281168385e+36", "1038.34228515625", "2.76434394327154e-32", "1.7196783471902956e+25", "1.1130649293795948e+21", "-4.692275036929919e-37", "-226723488.0", "50879012.0", "-6699550769152.0", "-1.7325055235925535e-12", "12368196141056.0", "-2.2686743792765402e+21", "5104003072.0", "1.1102150892838836e-05", "-1.6041581141547285e-31", "-56242421760.0", "-1.0889238720444542e-25", "-7.057202548958269e-29", "116411951218688.0", "-2.5017150279651468e-20", "-8.043670827041817e+18", "81101784.0", "-2.2176868479477674e+22", "1.1152537417966582e-27", "-92161456.0", "-2.2414864512111938e+27", "-1.3348641025890857e+27", "22501.513671875", "-3.973488687380587e+34", "-4.4498449369712034e-10", "1.2917031581072905e-29", "-6.253529863897711e-05", "1.1058037644413863e+23", "-1.140050845156294e-31", "1.1264973227506376e-14", "-1436125797285888.0", "-1.936903117893962e-06", "326.6138916015625", "-8.816071240756651e-24", "-0.0026656363625079393", "-2.7722748203387316e-21", "5.429525074059081e+21", "-3.881177763694492e+29", "-5.166602933053183e-33", "2.366892116912468e+20", "-2.818712979236303e-23", "1.416261413700326e-10", "14866538496.0", "1.216943012970496e+16", "-4560304469966848.0", "1.241564361113653e+29", "-0.002359366975724697", "1.0863422851715397e-37", "1.1531411495697784e-20", "-0.014970102347433567", "1.347568431581688e+23", "-1.7731482785960512e-15", "-2.790867167366071e+37", "-3.724990439393132e-16", "2.870509457424672e-36", "133501032.0", "-2.564490440089964e+35", "9.244093927688139e-21", "-57311368.0", "6.013991629525882e+32", "-2.3892506653289974e-22", "-1.0724350112840548e-19", "-5.027442596447783e-25", "-5.555217391782644e+29", "-1.946871260618142e+35", "-5.578081216537559e+17", "-502306930688.0", "-3.3321203928271025e-21", "2.1914786002762476e+21", "6.322284541775021e+31", "1776295739392.0", "7.79481730897591e-13", "-1.8388647357211715e+36", "4.0783065540542234e+37", "1.9882487271116594e+31", "-7.037896043726732e-09", "1.4026027453906944e+17", "-3.380173526702504e-24", "6.533658185090622e-35", "1.0716290717027732e-06", "-7257848807424.0", "-1.89796381278029e-07", "-5.90796035661493e-27", "2.1507337345699112e+33", "NaN", "-3.4204839169878917e+19", "0.0009382425341755152", "9.092608779175665e-27", "1.3723565615204872e-36", "-3.712273542833001e+28", "1.0609690074192199e-13", "-9071383.0", "-42362536.0", "2.52701197390068e+29", "-34.91431427001953", "0.0005294861039146781", "2.3262828288096035e-38", "8.810567812398766e+36", "1.3870466407384097e-11", "-2.5495621640014057e+20", "-4.846510765952293e-18", "-3792482429042688.0", "9.47517296229855e+29", "-1.0076194825320791e+28", "-5.024610119637753e-37", "4.774448987686615e-25", "-6.606978220174118e-16", "0.11697939783334732", "-2.6323890021887573e+24", "1918.3115234375", "-1.3935795870580933e-22", "341880960.0", "9.762083405667051e-28", "8.310338888415575e-22", "-2.2010211033509746e+36", "7537.17041015625", "9.596050176696735e-08", "-5.422766661984376e-34", "-7.079086611963615e+32", "7145202.0", "95923556319232.0", "2.8079039093853453e-09", "-7593309765632.0", "-5.205483262216148e-07", "-0.006537862122058868", "-566199.375", "8.988187579130025e+23", "-1.3211279071490954e-33", "16771.1953125", "0.8373754024505615", "1.9423088530046077e+38", "5.853586365622832e-11", "-7.538212085725433e+27", "6.314563344127161e+29", "3.905580323794835e+23", "5.833376564650407e+30", "5.622002301841444e+23", "3.864605525626847e+18", "-3.0055622607022255e-30", "15.861275672912598", "4.9361191474002635e-23", "192780713984.0", "-0.07192015647888184", "2.0284270367751944e+26", "-4.737948393602039e-25", "-273560.5625", "-38092.91796875", "1.5507599048300552e+33", "4.768138141264533e-12", "-6.970411434335177e+31", "6.455064937218032e+20", "250204944.0", "3.600303060684181e+26", "1.591707321502156e-11", "1.3278178533344057e+35", "2.934532330322037e-27", "2.3706994651830218e-23", "5.969929884600474e+19", "1.1918274645310308e-27", "2.517781083516174e-15", "NaN", "-0.1843438297510147", "-5.615584537264452e+21", "-8.721627114631818e-36", "1.650683138602082e-28", "10387119104.0", "-9.943862325040657e-37", "-10265397559296.0", "9.295362761445437e+24", "5.123940845148484e-35", "-2.1949497774897964e-09", "-4.498614047815886e+36", "-3727528564883456.0", "-7.579104169452486e-16", "3.296038387615277e-30", "1.1550592093250155e-24", "0.04081789776682854", "-7.364511899419842e-27", "4.7332752912511755e-21", "1.5092071689593034e-11", "-6.199781000946125e-15", "1.491648895235563e+29", "-1.419778835003474e+17", "3.016337087673244e+19", "1.4658241242559841e+26", "1.7234478636812844e+22", "-6.668779721845663e-27", "1.282392636525742e-09", "2.63787794618004e+26", "-6.208839594800536e-14", "5.881440105453729e-17", "12507.0068359375", "-3.844414564468934e-08", "-2.065183639526367", "-5.2924020677020485e-37", "-2230746555088896.0", "1.9846901978377053e-35", "8.295834450050074e-22", "4.2478050803540694e-39", "5.86292022244238e-37", "1.0478519243406481e-06", "1.0139639819018145e+28", "5.5566268717042146e-30", "2.7659238081492427e-28", "-6.708092667229453e-21", "-2.1917872318285257e-23", "6.9641091613448225e-06", "5.834385408411172e+33", "3.935382896378928e-28", "-1.7468823443771984e+35", "-2623.4052734375", "-0.00011460414680186659", "8.096259871625889e+31", "0.00016152714670170099", "1.344189610398092e-37", "1.1953827735180338e+26", "-2.535949902186283e+19", "-0.3595903813838959", "-3.181161961567747e+30", "1.0946672123229532e-32", "-3.1614760955459952e-16", "-4.562122145309571e+20", "-1.641625799052319e-33", "5.0649949496143626e+29", "23820.025390625", "-182.36062622070312", "1.3862277992160443e-09", "-188641008.0", "8.560112317973999e-16", "47208484.0", "-1.2543948373227647e+35", "3.676352555714857e-10", "129998.984375", "-2.0323877303329851e-10", "-2.56004744313143e-12", "-1.606120916392768e+38", "-6.224282200761877e-11", "-378.56256103515625", "5.457416502902659e-20", "1.8642943712624077e+32", "-5.974901796435006e-06", "-8.971396375272858e-24", "-1.1739089700313343e-09", "5.805224460716679e-31", "-258920064.0", "-4574606.5", "-0.000509080127812922", "35646455808.0", "-8.863146945259359e-07", "-4840011.5", "22242181120.0", "-1.2520107133599058e+34", "-2.047705574022253e-23", "84609.7109375", "6.940633535878362e-37", "-1.1086575796070974e-05", "-0.001117104315198958", "2.5270151628715837e-21", "3.3728738518922484e+38", "-1.9038807153701782", "2.0845139248340612e-21", "1.8700280970131815e+22", "118.71986389160156", "-5.750297414317173e-26", "-4.6046501600276e-20", "658658266447872.0", "-154139547926528.0", "-31394237513728.0", "4.459600584910993e+21", "-60377976832.0", "2.0534766647562793e-16", "-23.71330451965332", "2.802655532494799e-21", "1.3388811310738369e-19", "1.8424688685326423e+25", "-7029104.5", "1.3708591012471775e-34", "248.8749542236328", "1.5268889768612258e-21", "2511.621337890625", "1.7872012853622437", "-2.9124432330718264e-05", "1.2117603223817672e-23", "-5.402404221359301e-37", "9.070866733159564e-19", "-1.8350372556596994e-05", "-7.064948919529182e+37", "1.36868710675617e+36", "5.383404081011501e+26", "1.0788999833344273e-31", "-7.329473399736441e-13", "-410021.96875", "-3.783272906193001e+30", "78296192.0", "-1.5607384273863545e-09", "-3.6458417354488824e+33", "-2.6421244779255337e-18", "3.7981944702531915e-24", "-515655.8125", "9.009222571222568e-23", "-1.1304971157045069e-38", "1.373761857100905e-28", "-2.158710099564587e-09", "19084654592.0", "-7624.5419921875", "-7.163495063070445e+37", "2.50248133326636e-09", "-8.189560996698601e-32", "-7.874969121246522e-09", "-6.807722418227014e+31", "2.5751661218101197e-22", "-7310449339006976.0", "14909767155712.0", "5.3447880906662505e+23", "1.2140736544097308e-05", "-3.06693881743945e-32", "-32400.349609375", "9.476647677661215e+25", "-3.997889915688988e-28", "1.7489076130947352e+21", "2.44570440670401e+20", "-116719779840.0", "3.0590264346141054e+32", "5.201705995351369e-36", "-1.8795408197056025e+28", "8.906082251888825e-13", "-9.106121170866574e-19", "-3.139101024376062e+18", "361500770304.0", "-7.493757498543129e+27", "-3.6133955567151183e+30", "1.511680268723093e-22", "-2.2442080762778748e-15", "-7.237863544119505e+31", "0.8611747622489929", "1.239045590360838e-36", "-2.550812455708794e-32", "-19.004854202270508", "28545949696.0", "-2.8664541345415455e+26", "2.5303386500159654e-39", "-2.022407633599642e+24", "-1.7348191825244172e+23", "185931197841408.0", "6.554456647741957e-19", "0.006487790960818529", "5.973760454336844e+23", "-1.5428089976559137e-15", "0.0003955668362323195", "-5.041621658786902e-37", "4.434202514996599e+25", "3606971.75", "-3.54121787415442e-31", "1.7679643349310936e-07", "6.9237354022831654e-15", "-5.605679216092774e+16", "-1.8759514604772795e-29", "2.3726914530342036e-12", "5.86708419391034e-23", "-16517810176.0", "-1.7065849304199219", "-5.272161691159757e+17", "9.144789149169253e+20", "2912964575232.0", "-5.114774688551645e+20", "1.5119388967516148e+21", "-4.2111212313652964e-32", "-810828.25", "-6.3821965490262755e-09", "-16896958136320.0", "-4.5386700138912406e-33", "-2.750103140085586e+32", "6.92633186811824e-25", "-5.885755408541664e+20", "-3599428222976.0", "2026.277099609375", "-4.7036761336854103e-29", "-59028.34765625", "-1.968689050082639e-21", "1.6046800730292836e+36", "0.0013168066507205367", "-4.9124454087424444e+23", "-9.306510798697986e-24", "-1392349056.0", "-1.2479585394705121e+35", "111.7280502319336", "2.2553764174456595e-15", "-1.1961038556577308e+33", "855361.375", "-5.922811047482201e-17", "-0.05101240053772926", "867720364032.0", "-5.582486444165224e-17", "3.881541389334031e-21", "-3.788975391216809e-06", "-2.9425820026593374e+26", "-9.596232792387632e-20", "1.8270332446805915e+22", "3.7407768348362275e-24", "9.581666381563991e-05", "14915756.0", "-0.0008261295151896775", "-2.496340245627948e-09", "-5.729751723670721e-29", "9.456528475734309e-36", "-449884553216.0", "1.0265806134837248e+16", "-9.274597208367465e+30", "16072512110592.0", "4.3282353612630886e-14", "-7.016452282306135e+21", "-2.137902934684118e+21", "-2.0779601183820185e+37", "-1.2918441575919993e+34", "-1.4512338439545383e+29", "-1.5921224947379529e-34", "-0.00016858705203048885", "8.95047161386664e-11", "-2.1236994774046078e-29", "-3.0079935068176416e-20", "2.755094554442846e-26", "7.119097701177754e+16", "1.7085948229578207e-06", "-9.398739324040821e+23", "226121334784.0", "-4.960359785972024e-34", "-126842656.0", "-4.7866069698439194e-35", "6.422364015040835e+29", "-121732208.0", "3.4345514272342186e-38", "-1101.906982421875", "-15523958.0", "6.123942531542723e+22", "-8.647905586086765e+32", "4.317990839916384e-22", "-2.214521455478345e+37", "3.7985871865194613e+27", "-7.425985586451134e-06", "0.002637778874486685", "-0.01329469308257103", "0.006041431799530983", "-1.5461930781386743e+29", "1.2674741562941122e-24", "-354097984.0", "3.2111120360035564e-19", "1673782578118656.0", "1.3136689036108515e-15", "-584910121205760.0", "-7567140864.0", "-2070687121408.0", "-1.6054885914492783e+24", "5.321118972464642e+23", "-1.32286802683663e-27", "-3469559808.0", "-600579899392.0", "2.3536565228079647e-33", "9.214174490259624e+25", "4.304260166813822e-20", "4.0974384241660673e+34", "-6.294324242471427e-20", "9.598760698281694e-06", "-4.5400307320010713e-13", "-3938520662016.0", "-4.178616311293705e+32", "-8.027488826781855e+29", "-369562746880.0", "3.3674241712558433e+38", "-2315.9130859375", "-6.817134747330589e-13", "2.9513936042785645", "-1.1402481071204497e-37", "-1.1111065845170742e-07", "5.050157510947737e-23", "-2.01766019706663e-33", "-1.5054411852417488e-19", "-5.482614676346896e+18", "-2.889248085571894e-13", "-1.8801152144225696e+25", "-1.1868702619863244e-23", "1.5364675532618636e+29", "-261678952349696.0", "-5.707882517330676e-12", "1.163942966409162e-23", "-3.0000126108126714e-30", "8.508073938299013e-14", "39.95729064941406", "2752713.0", "0.9768626689910889", "-2.80013622855494e+37", "1.7772105137759827e+25", "-0.07196492701768875", "4.122392127064066e+25", "817712704.0", "-7.393530236418462e-34", "88368.8515625", "3.1808800038390928e-09", "-4.88055521368189e-31", "-1.7523790307293757e-14", "-133885.375", "-8.139329983593384e+34", "-2.05478718271479e-05", "8.72221872572334e+16", "-3649120239616.0", "-3.5756453150758437e+37", "0.0018586678197607398", "0.014940520748496056", "7.296536195851763e+32", "-1.94144779598485e-15", "-5.481781175053833e-17", "-1.6599883210657028e+28", "-1719016636809216.0", "0.11527889966964722", "-7.662935734719789e+29", "3.315283762563038e+36", "-12471122264064.0", "6.3663376913718575e+19", "-5.556575342779979e-05", "15565063168.0", "8.011164814876743e-34", "-3.427631912149576e-32", "1.302384108641766e+27", "3.279108471443288e-38", "1.2566067075781007e-09", "8116607.5", "7.423401223870661e-35", "-5.293474475373477e-20", "2.5519723904127103e+36", "-72.22744750976562", "-1.494287669956444e+17", "2.2048105000563997e+30", "-6.4036307423252505e+31", "-9.964760912551985e+22", "4.070107330104275e-24", "-3.1334916537757203e+34", "-5.998038559214307e-35", "-2678.180908203125", "-5.258253281681391e-07", "1.5661808601321464e-14", "2.2690777257139416e-08", "1.5378698718220282e-34", "-2.2779751418511296e-09", "8.211780713175878e-26", "1.1573547238535056e-29", "-1.6694019490637013e-37", "2.8144640430333536e-18", "-8.00384225856343e-20", "-3.0401648397420426e+34", "1.2330333116150194e+30", "-3.727075421003723e-27", "2.310808725144384e+27", "5.6463758730967655e+22", "3.2100813718966764e+18", "-0.00019608369620982558", "6.461795839898808e-25", "3749781962752.0", "-1.2418270111083984", "4.326162024176749e+20", "25.555688858032227", "2.1342791181675875e-32", "2.0321493450122854e+17", "5.005311165540149e-10", "-3.661750958161721e-23", "54935.8125", "-4304154112.0", "-4.939498526500271e-33", "7.15389787332648e+28", "3.728052327727067e+20", "1.3206971771596817e+31", "-1.4805368290458727e-23", "-2.0008192267609527e+34", "5.88828854434467e+29", "7.632521830745818e-22", "3.7792639745085834e+37", "-1.7871720207685894e-17", "3.6415128138740063e+31", "-2.1800392410499444e+24", "-5.337631709908734e-30", "-4.376937303795255e-29", "-1.8478931451682e-05", "-4.328264929816621e+27", "-3.3000935325178766e-37", "1.3917039609623316e-07", "6.0879082740958434e-18", "2.0383059118594254e-13", "629009.8125", "-765617447632896.0", "-5.828925608852842e-08", "-5.044672378031623e+19", "5.553092324760501e-32", "2202.938720703125", "-1.78525643590322e+23", "-5.731683747377823e-30", "-446971840.0", "-3.240044946117479e+21", "9.113745136346552e-07", "3731414.25", "-2.108211755535565e+16", "6741877063680.0", "-4.750598048961136e-16", "2.7925539303797766e-27", "-4.5181813173921616e+26", "-1.2385338526592495e+24", "-189169.34375", "-2.2232642334509097e-22", "-1.3959568116862385e-30", "5.406932281588015e-28", "5.153500409989803e-21", "3.109451850733316e-14", "-0.0009081613388843834", "2.1998581624131505e-22", "-4.444396658008379e+29", "-465208544.0", "-7.796436022819431e-13", "-1.1650884285422225e+33", "-3.231223187801571e+26", "3.075345659162943e-32", "1.709604899703018e+35", "-32606538170368.0", "5.00666106170579e+26", "-8.284301139397475e-31", "8.036592427943116e-39", "-102584.890625", "3.77794921828084e+25", "-1.4005379507437775e+32", "-1.304364517650796e+24", "16353.279296875", "-3151828.25", "-1.2590769353236706e-34", "1.5091556853760393e+19", "-3.2187101624177787e-35", "3.786225951485269e-23", "-506630.4375", "1.0707478522634508e+38", "7569.3857421875", "-8.918689432723751e-33", "-5.675677304592794e+16", "3.482789414938764e-13", "-3093.5986328125", "631.9985961914062", "1.1427350104118395e+18", "-10942.3818359375", "4.46649491529657e+31", "7.15673459887274e+26", "3.066980601697027e+34", "1.591391572334327e+26", "1840505.125", "1.8010864067524723e-12", "326638403584.0", "0.4713168740272522", "0.0009686144767329097", "-2.5292967620771378e-05", "-0.19089233875274658", "1.8774732768330843e-16", "2288282.25", "-1.375124782590429e+32", "1.4166737712210868e-18", "-3.3257246885582435e-34", "9345848836096.0", "-8.636677484297195e-10", "15089117.0", "17314310.0", "-1.8508367061187414e-10", "-2.678296941851954e+36", "2.4403747967138708e-30", "1.6106956589679436e+21", "-9.951615396745403e-17", "-6.742957585798636e+35", "-6.059550142795612e+17", "-2.4118634953267668e-28", "2.069834459154536e-16", "-3.26119748484587e+37", "-4.2935594137816224e-06", "2.240001848292239e-25", "-3.705885066425394e+27", "-9.78279296960808e+22", "-22.997709274291992", "-3737657540608.0", "-6.212161628084429e-35", "-5.7140465248114206e+20", "-3.1147547670384263e-24", "1.1338300313884809e-17", "1.6744526150043558e-17", "8.48460891550463e+31", "1.608257764788156e+24", "2.4818340382323626e+28", "1.5211086163077343e+38", "-18641658.0", "-0.9225483536720276", "-1.0032872079642067e+20", "-1367581392896.0", "4.317568426775778e+35", "6.426341533660889", "-1.1625947368701492e-27", "-567768.4375", "1.8093479885285848e-19", "0.01785246655344963", "17101677568.0", "1.4267781021158505e+23", "1.4257192850261757e-37", "14.494443893432617", "2.1862664822600244e+33", "-1.2747987994279936e+16", "8.490136312310586e-19", "-8.381099831722144e+36", "-1.842931127535765e-24", "-1.5833935637234442e+38", "1.8915404780273093e-06", "-31073784.0", "5.573105819967157e+23", "-1.4009612215754048e+26", "-1.8713916679844385e+29", "-6.171440459255539e+19", "-2.640672115471735e-36", "8.881663108957146e+37", "46.23552322387695", "-9387254.0", "9.888762795910082e-26", "-6.328999734250829e-05", "-2.6362123489379883", "-7.353090210403252e-26", "-4406.35986328125", "-1.1622711685055295e-18", "-42179674112.0", "-6.888846201036358e-06", "-4.169654361097907e-15", "2.889473699150142e-16", "1.7334341340463842e-19", "2.159883116803485e-08", "-2.7436713147148495e+29", "-5.933001297751853e-27", "2.12679180123793e+18", "-3.3493964672088623", "-9.508405938134965e+23", "1.1574657321489212e-07", "-481.60601806640625", "26598053888.0", "-6.89463829356957e-20", "904082620416.0", "814990.875", "-1.9786577309086242e-30", "5.327532600647713e+32", "1.480001930623138e-29", "4.649539077419818e-16", "3.8406282779993715e+36", "5.474906560639051e+29", "-2.0755995330169432e-34", "-7.230175117466098e-13", "-1.1599457165281417e-28", "-24.671266555786133", "-75.75926971435547", "-5.598014239434801e+27", "-5.68003025394729e+16", "2.6400750075677992e-12", "1.1068503131065663e+33", "-4.0687727031655146e-19", "-9.342249821534281e-26", "1.289900519674433e-20", "-1.5197800859153319e+21", "-1.9116166723487265e+30", "-0.04080621898174286", "1.56652123564843e-19", "-1.8695769934732223e-28", "-0.0082998126745224", "-7.26947504173539e-23", "5.2492405216582306e-17", "-0.4462141692638397", "4.2253628862454207e-07", "94.93634033203125", "1.6407004762977293e+30", "-7.141530034004973e-08", "-3.0116289035969952e+26", "-1.8300884930448967e+27", "-271.9952087402344", "-1.3994861482053242e-36", "620550976.0", "-1.271670269087366e-10", "369486876114944.0", "-1.9085498903458988e-26", "-2.7041086383960966e-11", "-7.205167018575764e-11", "-4.010251297703604e-10", "3.4516660123208173e+34", "-2.769534044704008e-31", "-1.6724527622906705e+37", "8.971038995155712e+28", "1.2182330372683873e+29", "-1.6199125313930152e+32", "-0.01764822192490101", "-1.8222398798102523e+30", "-2.711598343598097e-19", "-3.0947127160869617e+25", "6528.03759765625", "-7.660936601849037e+17", "-2.843527229734654e+27", "781.6427612304688", "-2.9032469382810245e+34", "-2.9040020817049886e+34", "-1.5718097727158763e+23", "7.266841109159405e+32", "7.706280318442007e+20", "-0.00408123480156064", "-4.982808683748721e+26", "1.520074473556599e+20", "8074712055808.0", "-6.137206742806711e-09", "8.54627383539442e-35", "1527684165271552.0", "1.0767693091073109e-36", "-0.001868648687377572", "17417304539136.0", "0.0024751608725637197", "405.60211181640625", "-2.1387057402844079e-10", "-7.311519960436982e-14", "390721142784.0", "-3.900100381537541e-11", "1.8299907530753253e-37", "0.033091951161623", "8.938701301244535e+37", "43.81321334838867", "-4.084831545241229e-27", "-1.3943753060384768e+16", "-25153748.0", "4.387167962249805e-08", "1253.76611328125", "6.541880866886371e+35", "946341618384896.0", "-1.6748685863975966e+27", "-7.1161912273964845e-06", "-2.3785711712217388e+24", "1.688469946882358e-32", "5.51205570076263e-09", "-7.175292734636653e-40", "49707.24609375", "1.3304738586133646e-17", "2.2148941974860335e-31", "-617269035008.0", "-8.893061982513432e-22", "0.0018544303020462394", "-713931264.0", "2.1772782856429185e-15", "-1.1893282266196006e-30", "154631606894592.0", "0.3147377371788025", "0.56428462266922", "-9.197467164947251e+16", "3.033120693186019e-16", "-6.794098249989991e-29", "-2.186144987327069e-28", "1.1614258018993552e+29", "11710.888671875", "-2.461200173926697e-19", "1.7304186957201831e-16", "4.96096568269877e-08", "214.26364135742188", "1.2512114405743012e+22", "12.951374053955078", "9.123105118129474e+32", "-3.0418707714067624e-37", "-5.945835454396492e-18", "-1.4846901438465379e-21", "-5516.31103515625", "-6.367641987815979e+31", "4.896838634593755e+22", "1.0104959124930601e-24", "2.297602786088224e+32", "-891830.25", "-2.6600671603036093e-15", "6.444004630629224e-28", "1.238873842215814e+35", "-3.830461543682286e-25", "5.877520137254066e-10", "1.2582198657207571e-21", "NaN", "-3.0010934584657576e+19", "-7.480380694504126e+25", "1.475125114892085e-27", "5.5404696130446907e-14", "-7.281038478939175e+20", "-1.2175679843691445e-13", "-14915277.0", "5615338.5", "-11775449.0", "221410731491328.0", "-2.001463203169389e+34", "-36608652869632.0", "-1.0401669827337307e-23", "1.2686564615758122e-25", "7.804184871609909e-33", "-2.5444276232538936e+31", "1.4105641639616806e-05", "-2285762267578368.0", "-1.0700650702971559e+36", "2.232645329058869e-06", "4.5465239758509195e+23", "6.073810815861861e-20", "2.786371355587446e-31", "-7.348217678782021e-08", "-8.393570978327189e+36", "-1.8884443989871954e+27", "25513368.0", "937103.0625", "0.00029810474370606244", "-0.00040049999370239675", "1.575791474622706e-17", "-1.9853528001288756e-13", "-1401134.875", "-53241792.0", "-3.811665377380537e+37", "-1.8690358167884992e-26", "6.029385986039415e-06", "7.176181202592105e+23", "-2702728704.0", "1.7193780562306265e-25", "-4.5722874271458824e-12", "-2.2763854947504162e-17", "5.275393018394837e+19", "2.6120965681379183e-36", "3.4495411682655686e-07", "8.387106791409374e-15", "1.7317434756639695e-37", "-1536485.0", "4.0569720441492945e-26", "1.9656523599246785e+37", "9.710151398116557e+16", "-1.2831061807694824e-35", "-161062768.0", "-4.6226767563465195e-28", "3.1151751340858944e-29", "11.759450912475586", "-2.5593354149974508e+19", "-4.717424544993003e+34", "-2.517505827728984e-36", "457171.15625", "3.3225040169111912e+19", "-1.6515727990213254e-24", "1.2970740663507512e-26", "2.5792633232723738e+35", "1492346.0", "-7.745760977062588e-11", "3.301672824029822e-10", "7.827620043219814e+22", "-4.735707081749823e+18", "-0.0007718804408796132", "-267.28070068359375", "-2.4292471153807924e+25", "1.507675858299993e+32", "2.7371811737848893e+31", "3.868762733046525e+27", "3.3206101675418106e-38", "-3.8721684424185465e+24", "-5.8872096133243385e-22", "3.3878967373743383e-16", "2.873753168825549e+16", "1.149756645425525e+29", "317542.0", "-55.53878402709961", "2.0668666660985394e-34", "1.422535024175886e-05", "-63.4649772644043", "-9.892733847751255e-24", "0.02598702162504196", "2.948607716175925e-18", "4.273105203012051e-10", "NaN", "-7.298007497864324e+28", "-1.0095332335396457e+24", "175077228281856.0", "1.0208801581867988e-09", "1.6624600754331674e+38", "1.1885210214618638e-19", "5.330395764883783e-34", "-151417.234375", "3.7540563090079882e+22", "2.0509589967914677e+26", "3.2740587635996416e+38", "-6.417898563638314e-20", "-4.801882758466439e+23", "-2.416092673780596e-26", "9.445699769767592e-15", "2.416007791236022e+28", "7.784925458096065e-27", "4.476770558490343e+21", "1.1320260482289426e+32", "-1579.2076416015625", "1.2001452616692638e+26", "-3.341155527433388e+18", "461547520.0", "-3.237698885102611e-31", "2.1294044532349126e+32", "-1.370423934156858e-32", "-1.2263094776496128e+16", "-39592460288.0", "1.6399720874784504e-17", "5.562588816967744e+29", "3.4791198469404444e-19", "7.901890825730237e+26", "-4.423699806466175e-08", "-5.7840479413297275e+23", "4.164744526210702e-33", "4.296684484872543e-19", "2.0110358481595974e-23", "-3.0891692802292178e+29", "6.01357301030953e-29", "4.069150805966481e+28", "-7865728.5", "9.022941386427665e-35", "8.249876515264987e-08", "-1.0299151893786372e-21", "3.243501893763068e+38", "-1.2267549476252526e-10", "126066319360.0", "-6.0314700282837475e-12", "-2.9431398322001264e+26", "-782563147776.0", "-1.0546987173098368e+23", "3.0176858733148576e-38", "245276592.0", "2.133273758173683e-20", "-1.3077310860181325e-18", "1.2550870733164646e-33", "3.017960904928686e+30", "4.044626096438386e-36", "64380.42578125", "7751.4267578125", "-1.5688116858398924e-20", "-5.738692913576857e+19", "-315580.28125", "-2.9986805805293363e+26", "1.2508307760155075e-20", "9.899851419070364e-36", "2.701180412030949e+35", "-2.0022346848122276e-24", "2.248799875901464e+23", "-7.458938529031917e+35", "-2.5625479363971833e-26", "-6.721920214652068e+25", "-2.170546519595612e-29", "1.101370274873344e+16", "-2.4088626309570056e-21", "1.5741800252742955e-27", "1.1558119887348747e+20", "2.551605491859487e+29", "-90.05257415771484", "-7.374647767455222e-12", "-0.4444390535354614", "6.629235051430586e-21", "7.68589516896025e-32", "-0.32703807950019836", "-607.4019775390625", "-23454558453760.0", "-8.665361089665434e+17", "5.005767699967056e-37", "-5602.1796875", "8.838402539267295e+22", "-2.0703660385778565e-30", "4.672286280104564e+34", "2.173865836922536e-38", "-0.04190119355916977", "-2.4416016083939063e-14", "1.4905313311733281e-13", "-1.152980398649956e-20", "0.241085946559906", "-1.0223352363672425e+37", "-0.0029094985220581293", "-6833837178880.0", "-3.614719974707903e-34", "-1.2195434939688e-10", "-1.5110999517874356e+19", "-2.0646692641716746e-21", "4240915.0", "15269020827648.0", "-1.1019757707727995e+18", "-7.268492039715553e+18", "-928645614927872.0", "-4.521211818120961e-25", "-1674764746752.0", "-2.7354424328398362e+34", "-2.9018542997266167e+28", "2.6581905784168054e+26", "-4.613629844084155e+25", "-285557678669824.0", "-0.14392384886741638", "-2.3485345303844089e+27", "-0.0050667584873735905", "-1.9476145696195601e-10", "-2.9345428416865616e-30", "0.00023630984651390463", "2.111532624248832e+16", "212646336.0", "-0.09175971895456314", "-6.803172151101665e-20", "-0.7275035977363586", "-2789895596670976.0", "-1.9033847138416604e-06", "-3.218621766679086e-12", "3.7641409944735776e+22", "-4.878258787466071e-22", "7.319750092541508e-16", "1066285989888.0", "5.631841117788977e+24", "-3.618772229441443e+22", "1.6459507835098612e-17", "-3.333683271430752e-23", "8.832784514760817e+27", "2.0146151156850233e-32", "3.5669290423309677e+22", "-5.078672570627346e-17", "-2.0717155909105976e-19", "2.180769867948064e-17", "-1.4711043750213121e-28", "1.8451402400176014e+36", "1.9294718417952338e+31", "2.915822923552117e+33", "2.4829846889066355e-26", "3.993976237433925e-34", "-1.9670244649343655e+37", "1.6204052624374066e-15", "2.0075219708814984e-08", "-593.6985473632812", "-1.3077497842279383e+19", "-3.884882099305089e-25", "-1.8440589225289141e-23", "-5.024493970152816e+31", "1.1293386294584934e+17", "-4.7568002514708496e-07", "3.306964237026554e-22", "-3.224728584289551", "-6.163039916987704e-25", "-462045266837504.0", "-2.7501159682863374e-10", "-6.791992065039871e-30", "3814.991455078125", "-5.880963297063165e+20", "0.23518714308738708", "1.3698903591315704e-21", "6.269005098147318e-05", "-9.118574784139355e-10", "1.4598777915708627e-12", "-9.470266836807088e-22", "-825426240.0", "8.450248008812054e-18", "2.2446867973238063e+31", "-1.6595866582269438e-35", "-4.8565838452149006e+32", "4.110060708394629e+34", "-1.99401419912467e-18", "-2.0626226353531556e-14", "19.864532470703125", "5.911356737442475e-09", "-5.723070067066259e+24", "3.9982242680462407e+24", "2.894910402468469e+33", "1572331.0", "-4.591585072087491e-38", "-5.377378178958287e+33", "7.704686069048967e-22", "-1.295775333837733e+28", "22.54998207092285", "2.4278851454509905e-35", "251946086694912.0", "-88723685376.0", "1.969909597590243e-36", "2.5833058536874543e-21", "-9.154244855686909e+27", "-1.3284495265186023e-35", "-3.49353445477929e-12", "-0.00010963414388243109", "-8.693497679793606e+33", "-2.752279075975745e-29", "1.1422150276811295e-27", "5.1997234155730055e+32", "-1.3531746828117825e-19", "2.6186962741064927e-29", "-2.1211980633194103e+19", "-1.260742201133547e-21", "2.8425444396439617e-33", "-6.457455623280935e-15", "-1563924758528.0", "6.527406613865251e-21", "8.759744298269968e-35", "9.622048135151036e-39", "15160094720.0", "-27174774784.0", "2.48237191340378e-18", "15.802410125732422", "102708.9140625", "1.9353132247924805", "3.1998806223140644e-15", "3570406313164800.0", "2.445405004833346e-34", "2.8258012818582756e-08", "-1.3345022356281986e-21", "-1.564103529712606e-29", "-2.0049247631030327e-19", "837086.375", "-7.356239972056251e-20", "-0.1088685393333435", "2.595651338310745e-08", "-1.4883597607059193e+35", "-1.8347788566241974e-12", "-1.7772938719147957e+20", "-2.6526108609064645e-25", "3.2843133038346988e+22", "-2.557253592809472e+16", "3.5821488075025944e-28", "3.316813103783492e-18", "-6.445132839071769e-21", "-1.8609323365472187e-28", "-3655040522780672.0", "7.120526128654176e+34", "-1.8611354746447984e-36", "2.2326041145602855e-33", "2.999592241259563e-10", "-0.0038860528729856014", "427959812096.0", "-9.350507282498318e-33", "1.421046464060647e+38", "2.2220264561442816e+16", "-81956824.0", "2.679602003038416e-29", "-1.350193989173236e-20", "3.3207147136932805e-23", "1.6360355888818553e+19", "3.3895963070938544e+27", "-2.0246091025910573e-07", "1.494903095820288e+16", "-4.322096484740015e-26", "-1.5796317774145556e+29", "5.257657264642685e-38", "2.365606558645688e+35", "2106634112.0", "7.931562844043764e-23", "10512238.0", "2116276096.0", "1.5831647915660803e-33", "3.347548762851154e-35", "0.00047000814811326563", "1983626.375", "1.4909335988022576e-08", "-5.164666501085493e+32", "2.1546313181981108e-13", "4.310342765401013e-26", "-1.4656794178335986e-07", "-6.264418237617031e-13", "2.690795719920147e-13", "-6.160767627802118e-26", "1.1700482291894324e+28", "1.6372832451795605e-13", "1.922638915298267e-26", "-1.0224900536387936e+22", "-3.205794857096315e+17", "-7.492682585485966e-10", "7.5273155164671215e+22", "-273598432.0", "-2.5761235504601817e+25", "3218725264162816.0", "5.0353381186862833e-11", "2.8609763600056996e-23", "-3.392636822251557e+34", "1.548183387045654e-10", "-2.5131112622251227e-24", "3.122406121211919e+25", "3.839267633466009e-22", "8.979515464524468e-10", "-2.730268091063631e+34", "-1.0158053218023433e-11", "1.4579322178773918e-13", "5.898012841406195e+28", "1.7941793231612508e+27", "2260365.0", "-1.4363166809882593e+33", "-2.7239605495808956e-14", "1.776741401993574e-26", "-203584576.0", "5208886214656.0", "851633.0", "1286182928384.0", "-4.630643906880296e-21", "-1.3703489420188041e+26", "1.7715838627044232e-34", "-4.153925723960115e+17", "-2.0150523525637552e-27", "-1.7675111387304726e+21", "9.769060596878818e-35", "-8137611721834496.0", "4.67153838605747e+37", "-34107.578125", "1.2386859101532358e-25", "-6.431975178161631e-12", "1.3399958629721577e-22", "-3.4303123331430387e-32", "-3.1544139601875075e-24", "11101138.0", "2.5216811315550817e-23", "1.746389491352751e-11", "3.1624919081829223e-26", "-3.3512992381190543e-10", "1.5074643446385933e+35", "1.3824377768765855e+22", "7.591713251349389e+26", "0.00015813294157851487", "-1.8833396476405788e+24", "-3.4594665976328542e-06", "-3.121853637821914e-07", "1.0111041055793404e+36", "5.642964597046533e+33", "-1.1542974575096627e+17", "-0.8286832571029663", "3.89133412992404e-11", "2.3370041425189456e-21", "1.462739666329318e-18", "3.755094187574272e+36", "-2.2401321039434386e+24", "4.044944958313986e-28", "-2073319.25", "3.7102077437057352e-19", "-0.020515238866209984", "5.098848894407893e-37", "-4.8199969567436636e+23", "-9.922469126307934e-31", "65508143104.0", "2.7951550682515067e-25", "-8.061580216315214e-24", "-76404.3203125", "3.852015314845324e+20", "3.6254956285383666e-13", "-195179456.0", "1.66751266242216e-38", "-973.1332397460938", "-2.1045086871521705e+22", "7.812355762304435e-10", "726.3212280273438", "-5.0563098754105237e+30", "1.2119099692954877e-22", "3.803065889238709e+35", "NaN", "8.417508356071363e-22", "4.164645209846866e-24", "2.811340672334614e-36", "-5.116655810565393e-38", "-5.554245272045071e+34", "-10850170880.0", "-3.3888727981211674e+25", "-1.828600536933335e+18", "-4.218091066683917e-17", "1.414414024724252e+29", "-0.03665638715028763", "-6.684281420637803e+31", "55994621952.0", "-2.6606961244016215e+30", "-3.439005922700744e-06", "1.7392505965492169e+37", "-5.903524315328353e+28", "-1.9943279744270797e-39", "-3.0425109798670746e-05", "-2.0350858903839253e-05", "3.551926808231798e-11", "0.0021262899972498417", "4.296486957037398e-15", "-9.696821253943333e-13", "132.69976806640625", "-7.730734935770638e-38", "-3838.348876953125", "4.751036914260443e+28", "-79676.9765625", "1.2764993471137132e-06", "1.1176987466070112e-32", "1.1888464606889847e-36", "9.674886739399904e-35", "3.4072835048746016e+36", "-1.1027704260868632e-07", "-4.254224864607062e-26", "-1.0445842654555957e-26", "2.2614737980227584e+16", "-1913508736.0", "11208127.0", "1295201075200000.0", "3.234515411301714e-31", "-3.536314574503763e-10", "-0.31750786304473877", "-6.352208255927527e-12", "-1.3054273649836493e-29", "-1.973523779952302e-07", "-3.441407120524733e+30", "8.659524912581307e-27", "0.00010858228051802143", "-2.2641491586940904e+33", "3.665754743719792e-39", "1.074246019837663e-18", "8.209600663864195e+24", "-7.866156509540866e+32", "-56558.421875", "2.2978380446229077e+25", "-13042891882496.0", "2.1895764244400637e+33", "40450154496.0", "2.5795700366870733e-06", "-1.279973041039757e-30", "2.8681730324109367e-09", "-1.1420266637895401e+19", "-2.442262143631524e-07", "3.954457835156653e+35", "-9.163146352797469e-10", "40987336.0", "1.0681505457910134e-08", "808808.375", "-2.3571998563917057e+35", "1.443524939091491e+25", "-2.664352244467499e+18", "-370.0335388183594", "-7.941315037021483e+34", "-1.9237615246519534e-33", "-1.8152288831436564e-35", "429239132225536.0", "NaN", "2.4522839885908086e-32", "28297996288.0", "-78.68279266357422", "-5.474622492586232e+26", "2.5072753154221745e+28", "2.24520206451416", "-3.887874465655341e-23", "-7.020384222338349e-19", "-8.664670452928706e-36", "-6.032410356827944e-13", "-6.55638387668309e-30", "-4.1573196897088026e-23", "140133584076800.0", "1.7804163639914004e+18", "-1715446528.0", "8.591717720031738", "-1.8817756985330865e+30", "5.239255919480537e+18", "-0.00013324255996849388", "-1029411072.0", "-1.9294623297038228e+37", "1.2273684316176662e+27", "2.30410693064847e-31", "5.440851102989086e-10", "-6.614503433866048e-08", "43543.7265625", "9.448362726301463e+20", "8.481721051659253e+22", "1.9289938704126541e-32", "9.723693000818994e-36", "5.026615241997648e+36", "-2.910330465510924e-07", "477.4564514160156", "-7.90651862620234e+19", "5.567122877934188e+22", "2.6881925005784097e-36", "2.731961180099707e+17", "3.469827438999594e-35", "9.854542440734804e-06", "272826720.0", "-1.3673384993356601e-15", "-7.74197109840867e-35", "-3.63327131304066e-18", "8.570430622981976e-27", "1.0067805486923787e-17", "1.6806043820490752e+16", "-3.1590812043645017e-37", "2.2976453649349846e-34", "52516972.0", "-1.6296027860462914e-09", "1.0878382162436798e+38", "1.5608724512116873e-28", "4.65689842737103e+28", "5.397788800705862e-19", "-2.3931472595475547e-22", "2.3586942075850212e-20", "-1.3028106344138814e-36", "-5.3166124280856764e-11", "-1.0174340786761604e-05", "-1.1538848851068658e-20", "-4.592746326917155e+32", "6645407232.0", "-2.474905835825325e-09", "-7.274849115631441e-21", "-6.852553256180947e+31", "4.415602533243408e+23", "-1024.1729736328125", "-1210.936279296875", "-1.7362276315689087", "-1.9771870772566924e-12", "-4.379149314113257e+26", "-2.2387594038070903e+30", "1.182044755410061e-07", "0.13672815263271332", "2.0271291911746856e+35", "-3.709559244641329e-32", "-1.8243178805213653e-29", "2.880492887037359e-33", "5.060688055804793e+23", "-1023597936640.0", "4.440870321226342e+24", "6771671.5", "4.594170675051614e+21", "-2.53150500778912e-13", "-8.926621664073764e-10", "-59.9233283996582", "3.628693142540124e+17", "1.1816747580439824e-08", "37591696.0", "-4513919360040960.0", "1.649800133057425e+21", "-1.1793038929144324e-30", "-414290400.0", "-7.55461058293827e-12", "-9.515024540640604e-30", "1.3719522242718832e-14", "-4.826992231848696e-23", "-259957194752.0", "3.1619790050713704e-32", "9.608537469173545e+20", "3.2656967895762757e-36", "2.4598639074480957e+35", "4.1863976084267234e-16", "5.489667913999007e+37", "-11.565746307373047", "1.2160068507682809e-08", "4.410226594134264e-17", "-28473070452736.0", "6.572900231821506e-17", "-8.795368059615669e-23", "2.0789547860999846e-08", "1.33402858448198e+22", "1.4540365630625238e-19", "-5.16730313051914e+27", "-5.696383577509512e-13", "-8.879755981635143e-23", "2.11988829268428e-10", "3.767498157779805e+23", "2.9436935111704088e-09", "-2.0364323104496036e-17", "5.169400909920237e+34", "-1.7688081563569923e-37", "-2.5448566974363353e-21", "8.618236980507929e-26", "3.5209536531813565e+26", "-8.275927765987875e-23", "6.038863187285415e-09", "2.1715678260356724e+23", "-3.801309294614314e-37", "1.562618377821643e+27", "4.8667779610730735e+29", "1.101087310217963e-08", "-0.2225768268108368", "-2.9058787297954326e-28", "1.093437470277632e+16", "2.4376238095627978e+36", "-10249912188928.0", "-1.8490436339969637e+25", "7.34985205188028e+18", "5.849853282418367e-38", "1.3873845476841737e+32", "-19699499008.0", "3.373521947902525e-11", "7286939224899584.0", "3.6494343064046606e-16", "-0.47106465697288513", "1.1970102137349308e+23", "-156750.625", "-2.728682415809836e+19", "-8.024241826554146e-10", "-1.3374064971624083e+37", "7.968144200276583e-05", "-55.9613151550293", "-2.795074389869008e-21", "8.340034265305836e-32", "-9.905923798214644e-05", "-6.219153664277499e-12", "-7.685990870961304e+27", "1.3706179857254028", "-3.3708530846202197e-20", "4.520889579658669e+20", "4195236096.0", "453522944.0", "1.7815652450215068e-28", "4.6550907907256876e-35", "-1.119167836768214e+26", "-4.8711005007362473e-08", "1.2906403012364937e-15", "-239310816.0", "1.3889438800880337e+19", "1.0610145719989138e+28", "5884362620928.0", "-1.3787352552094036e-30", "-7.089935265626126e+21", "3.672943036423596e-33", "-3.739352279828502e-35", "-1.0770459136160715e+21", "-13723944960.0", "-3.6492222692792384e-36", "1.2805036577572082e+31", "-127645155328.0", "-8.173991904629746e-34", "5.820826560645889e+32", "1.6402703159348047e+27", "-4.978562262290661e+18", "-1.5501325046223208e+19", "-0.02672155387699604", "-2.5510601898715334e+26", "75771864.0", "7.867644171710708e-07", "-9.767954273023702e-32", "-1.0540784960372598e-13", "2.174475758149541e-24", "-113133101056.0", "-7450.6845703125", "-1.7572228117046507e-09", "-1.5314330425736274e+35", "-2.2355830520126574e-09", "-624342.8125", "-20.835695266723633", "-3.005094127919909e-26", "-2.4688909761761135e+30", "1.9418931570969646e+35", "-1.257432271761956e+34", "5.960066416613786e+16", "1.8833557645793228e+30", "0.00010871941049117595", "4.2603338373534605e-27", "-1.665445930633003e+18", "0.4675561189651489", "-1.1790350613940416e+25", "519033644384256.0", "-2.2883620712503746e+36", "3.0954656485175215e-29", "-2.1817846322575712e+37", "6.5588682664127945e-37", "-3.33968085270325e-25", "-6.125349075048815e+31", "-7.461910155568972e+37", "3.272166361093187e-16", "2.831576693058044e+18", "-0.02369070053100586", "-3.220414213078726e-11", "4.1850557891242644e-17", "-1.881613647588026e+34", "-7004444672.0", "3.158054911054738e-31", "1.2489750782142229e-28", "3.6513009441077537e+28", "1.4744309599890354e+24", "7.898933773731187e-08", "3.938844129526732e-27", "-154354.828125", "-21882900.0", "0.027744758874177933", "0.01360721793025732", "-837985297760256.0", "-237644656.0", "-1.2216784395865144e+32", "7.408058445435017e-05", "1973973948563456.0", "0.14655369520187378", "-1.1476601786963858e-11", "-2.526615935494192e-05", "-7.057158011828305e-29", "340871.28125", "4.497126939374007e-21", "-4.662799059448276e+21", "1.0528156415196102e+37", "6.300486125790167e+17", "-3.757645671065531e+26", "-6.920922086952029e-37", "0.003433346515521407", "5.600341437741238e-31", "5.351053596928627e-21", "1.235793113708496", "-7.157399704316372e-27", "249926160.0", "-3.575124555809425e-27", "-1.9152086093882712e-35", "-5.723331808482612e-10", "-9589.955078125", "3.917667805050712e+22", "2.8608830689336173e-05", "-9582667.0", "-3.5503954612490976e-26", "1.5511850137706062e-10", "15724.2744140625", "-3.2387413792053367e+22", "383343230976.0", "-1.894618522189824e+16", "9.424962405656257e+33", "3.760434361125817e-25", "0.09724167734384537", "-1.6338831942889868e-35", "-1.823505661741729e+27", "4.048554752345957e+31", "-1.292875539071987e-26", "-7.879372280793362e+35", "0.10418344289064407", "2.438095050941399e+17", "9.299016300586608e-29", "6.1232144414364374e+26", "-9.189178213358644e-34", "1.3572974341680144e-11", "-2.770581884697027e+23", "-6.523780969544742e+24", "-8.831010396428191e-31", "1.5231211538843126e+28", "-5.111837738042482e-14", "-3.692396715949687e+18", "1.2717413281740882e+19", "539563917312.0", "-18768482795520.0", "2.692379331161161e+28", "889599557632.0", "9.164973103881925e+24", "1623290674151424.0", "-3.2177430451022667e+25", "-2.2631261717412417e-24", "1.4423382591944626e-22", "1295634688.0", "4.8960618670434997e-08", "1146494275026944.0", "-1.152477239351263e+34", "2.9062340090035832e-08", "9.291099163697097e-28", "3.3698697090148926", "-4.493376750773443e-37", "-3.318015529799489e+34", "-5.6118781666445585e+25", "8.118644114810374e+21", "-1197.84423828125", "-1.0266179941426254e-17", "6.2130972829437295e-24", "-125812.96875", "-330618.5625", "4.187703552815947e-07", "9.482796598554639e-23", "-1.1937711737220342e+26", "-2.7266431735840322e+20", "-3.046020307719392e-32", "5557.07861328125", "-3209625.0", "7.82836295787348e-12", "8.333599821642859e+23", "-1.1262742685616492e+21", "-1.2934537240274247e+29", "1.9350016383687145e-33", "-2.046503923630226e+25", "-4.428472639849886e-24", "-1.977539666885964e-27", "-3.259713801510335e-14", "-2.9321213139576947e+19", "9.80153069818357e-10", "-1436.0762939453125", "225702.796875", "-3.1291055659295796e-28", "2.1755552308289084e+35", "3.9367316714412647e-19", "3.843002218673064e-07", "1.677295861366784e+16", "2.4801560225016554e+36", "3.206289581110308e-21", "-9.453926079216149e+20", "5.239549168483635e+36", "925834.5", "1.148890560015346e-21", "-523590816.0", "1.5826288154969927e+27", "1.5718187873514747e+28", "3.9967428221844614e-26", "-8.932036036478496e+32", "1.0038989180987147e-32", "3.1323695462384858e+32", "3.779414875942493e-21", "-2.2413310944102705e-05", "2.366111671566774e-31", "9.904484232594932e-20", "-8.986521994269566e-25", "7.92586469996734e-31", "-2353.894287109375", "-4.985263722843411e+30", "-4.936896800994873", "-5.150348022363603e+33", "-1.1619338566594395e-16", "-7.86775393645879e-24", "-2.2587854233733693e+18", "2.975649256820811e+29", "3.210662985475565e-11", "-2.716375759742702e+25", "-3.1527297458802524e+18", "2.649470530064563e+33", "-1.4051628955603007e+23", "1.760982580459862e+35", "3.259502262891751e-23", "7.753443055197245e+22", "-1.711426200756705e-08", "14.972114562988281", "-9.342475169348938e+24", "-4.999470505491299e-28", "5.391990048144743e+22", "-157023904.0", "-0.04443228244781494", "6.163205996772799e+19", "-2.8487707392319514e-25", "3.6076946230423346e-07", "-6.022649158177915e+17", "-1.1211251542234273e-35", "7.236124588416715e-07", "-0.05818691849708557", "-2.327877942831179e-18", "-1.2661132054855323e+29", "-3.030312836926896e-05", "-6.753557303075683e+25", "2.0273627461112542e+24", "4556.90625", "-2.86025222356003e-21", "-5.728760888123713e+36", "-1.1638005616987357e-06", "-6.288159843544315e-23", "-153893699584.0", "-8.825121978470685e-29", "-2.038552460382019e-24", "30714300989440.0", "-4.550555958525312e+23", "-1.7828740429593402e+21", "4.045924076267294e-16", "6210824.5", "-2.391923455702038e-34", "-634607435776.0", "1.5044867919024058e-15", "1.3075857932281714e-35", "-1.6918197870254517", "4941.28515625", "-1.0743932106819071e+24", "-1.8123206602379162e+22", "4.702080598411787e+25", "1.4605587096844016e-26", "2053420.375", "-15596578144256.0", "53542808.0", "36293853577216.0", "-9888.9990234375", "-8.400176104782211e-26", "5.677519656208283e-23", "-3702647014031360.0", "-3138179563520.0", "-1.343892051163296e+37", "2.432732397089219e+24", "-6.441010486882571e+30", "1.0666051375451957e-10", "-25937940.0", "0.010555887594819069", "2.9322099831546907e+30", "-7.257230758666992", "3.143200121235288e+26", "8.919642366724928e-24", "-87273112.0", "-3085232384.0", "1.3579567690293164e-17", "1.3582874758382151e-17", "-7.135024127080669e-09", "-219992.21875", "0.08684074878692627", "49296.90625", "-5.231927811946752e+25", "-2112799102730240.0", "1036878.1875", "1.3592513492328163e-36", "12.300487518310547", "-3.025730886039024e+26", "-1.3816088437024179e-22", "-1737313.5", "-239755912544256.0", "-3.3700298108412997e-25", "1.0882146577659235e+38", "-3.794487611230579e-06", "-1.2227405341117819e+33", "-6.405551967237782e+31", "9.259944173885303e-16", "2.0577160295828904e-22", "1278635904.0", "5.0660325452578956e+33", "6707.2763671875", "-4.9243410661376e+16", "-3.439515624610001e+24", "-1308771876864.0", "5.362354561156794e+22", "-5911906304.0", "3.506474968162365e-07", "-19094.751953125", "3.21030230177044e-10", "-1.552853279638406e-21", "-1.9618115183576978e-13", "1.700071888821597e-10", "-1.1371633020952832e+34", "-6.559750097579266e+37", "1.474042939254152e-35", "3.8744618322298226e-27", "-4.92282361095468e+30", "1.9085920008041328e-28", "-183571480576.0", "-3.3349300155994123e+28", "411187015581696.0", "-1.5081211403740893e-34", "-2.5653880331177243e-22", "-0.0038891497533768415", "6.674996999578298e-09", "4.545369587901179e+31", "3.893908074424601e+23", "-2.3227095590731894e+25", "2.872626005418514e-22", "-1.1833715306378005e+24", "1.3867608855211936e+29", "2.159749568934044e+36", "-6.033836129476547e+28", "-777036544.0", "239645600.0", "49926948.0", "-3.835592798066629e+21", "4.313473287732919e-32", "-7.758395592638578e-10", "-3.053067841180756e-36", "-2.8848104434524336e+25", "8.392161884038588e-15", "-2.6522455820197455e-20", "-4.156222310758295e-10", "-1.2093357749703593e+20", "1.1008111130810778e+18", "-1.368709728888362e-10", "4.995901262128122e-11", "2.083221772863908e-07", "3.986468707223301e-12", "-5.7451750024029025e-09", "3.80502278574113e-08", "-8.867268547891169e-29", "-1.601032769707548e+38", "-9.191358169994106e+28", "-210.9897918701172", "-4.201853708724057e-13", "834783360.0", "5.024336338043213", "-2.4644308257035182e+31", "1.806743070406869e+18", "5.716016174923608e-14", "5.612851811477094e+16", "-2.235012924359679e-30", "-4090353942528.0", "1.314348393441139e-14", "1.1714599559495276e-12", "-2.2873823321286372e+21", "-5.939365564699983e-06", "1.4824848674087744e-21", "-1.041274135627222e+28", "-7717322227712.0", "6.439732291549161e+20", "-1.07064107718476e+22", "8.672426665690125e-10", "-4.459582049888382e-14", "-2.0525199595500254e-11", "-2.1934485470104227e-26", "-1.5336758013746732e-14", "1.3769437047267581e-14", "-2.248716782939801e-37", "-6.464587211608887", "-2.8392034686582517e+21", "-1.0207396039518812e-09", "-896907476992.0", "1.5964524498198815e-25", "286615168.0", "-2637469184.0", "-2.3160922175552776e-35", "2.1921266142194307e+24", "-0.0002609978837426752", "7.132457734913731e-31", "2.793480291529704e-08", "323.0852966308594", "-5.104722913362589e-38", "2.543835775614686e-19", "5.675844863550772e-11", "-9.267653828066542e-18", "1.2183007271261876e-21", "-1.1687714049912756e-06", "2.144733758159933e+23", "9.228188238648607e+31", "6.419036127569369e-24", "-8.775422907293253e+20", "3.532560593359484e-31", "1.006492029272476e+22", "-2.594564559199766e+21", "1.568263105344514e-16", "2.3998459823647656e-23", "1.4612471734566475e+28", "-11217071.0", "9.228402122767431e-17", "2.5240098843618458e+26", "3.0385280576663345e+30", "1.265609512302035e-06", "-161841783242752.0", "2.5213098485988763e+30", "2.3900683786796466e-34", "1.2186357724327756e-18", "1.9165802586191684e-36", "-2.084093804267548e+36", "2.28143923742087e-17", "1.2920036102259777e-21", "1.4364923439940905e+22", "2.0846350552923727e-35", "-1318.5364990234375", "936.6815795898438", "1389.980712890625", "1.7023312135308198e+27", "87955176.0", "117599.078125", "3.203513744227129e-38", "-2.5248373276554048e-05", "-451646980096.0", "-2.7275357062446666e-14", "1.4316204625071918e+23", "NaN", "3.643318208581446e+29", "-9.006853066565438e-31", "-0.0008841940434649587", "-1651398528.0", "-0.23830972611904144", "6.457669223891571e-05", "14028431.0", "-1539767664640.0", "-226410784.0", "6.026653410056103e+28", "9.057756998409388e+18", "-7.52650501161477e-16", "-2.2918450997308267e+37", "-14683674.0", "1908722499584.0", "3.1479178570333597e-27", "77.98735809326172", "-0.4370916783809662", "-6.569138526916504", "1.4657219440656226e+22", "-1.1111840603963392e+17", "1.0845124671991496e-22", "-2.7814464620412593e-10", "1.7815575120039284e-05", "9.319212479718359e+17", "-8.870425481459918e+35", "-1.4915550309524406e-05", "4.603663898364613e+27", "1.5272622108002079e-31", "9541.2744140625", "23675.541015625", "-2.603349216990927e+33", "-3.9329801736848137e-16", "-0.04204122722148895", "4.37363780124631e+35", "-5.800350971980809e+24", "5.888423452762779e+25", "3.0467785976084085e+21", "-1.603462380030834e-15", "7.491329053284655e+22", "4.279684670768391e+29", "-1.9231887268832933e+37", "-1.4819225953052872e+38", "114222512.0", "-4.754354016249912e-38", "2.6521071145145833e-37", "6.60656443277393e-29", "-7.491424290108202e-19", "-1.2517701710452306e-16", "-1.7733864406448126e-19", "-2.2501672495233403e-14", "3.347090399979935e-13", "-2.9075963229008815e+25", "-1.1317051773526788e-35", "-2.8146717812385647e-13", "-5.515102908485468e+17", "9.967323677528839e-08", "9.431079853108954e+22", "6.881131987141822e-26", "2.89070616332765e-08", "-1.926514023071744e+16", "127609464.0", "-1.3929135452091348e-28", "1.0129247903823853", "-1.412332499430572e-26", "5.64180356323883e+27", "-1.165887250591851e-17", "-1.163283153674636e-16", "0.1606006920337677", "6.554958417837042e-06", "-7.13953995727934e-05", "2.241016446724018e-29", "-2.4425223147534375e+27", "5.572949885390699e-05", "-2.882878572063157e+37", "-5.651479211457013e+19", "1.353065337723891e-13", "1.7355227742647018e+21", "-1.051227331161499", "-5.975393326943749e-26", "-5.155018880032003e-05", "4412292917624832.0", "-2.808057767252246e+33", "2.3612544407471e-32", "-798063168.0", "30112.12890625", "1.8366567685812126e+19", "-1.3269442127651518e-16", "-6.992316503584561e-09", "-4.8744191463116066e-14", "-3961639715995648.0", "-5.849912900313471e-29", "-6441558.5", "-2723641556992.0", "1.1613714824659716e-27", "8.304502821055156e+25", "2.1696277587024415e-12", "-4.905178901344045e-28", "-1.3076085505343834e+24", "8.07720215037763e+36", "-1.5339802099157222e-28", "-1.2046225079243158e+33", "235.0823211669922", "3.231043082109992e-20", "7.032922772976173e+19", "4.5429590803412325e+35", "6.638842253714472e-12", "-1.6483056132532168e-23", "-94484.1875", "-4.101559466830926e-22", "-3.5339941989046026e+18", "2.6144474282198156e-18", "2584170496.0", "10.941245079040527", "-1.9163042619587337e-32", "-1.930055458542972e-37", "0.021501943469047546", "3.580263346000608e-17", "-1.0845326720021475e-10", "-3.175686284750084e-25", "-0.001828806009143591", "-1.6760108578422832e-16", "-8.788042928379721e-18", "5.45786445569875e+25", "-9.435396267110579e-36", "1.339256179913595e-12", "-9053946880.0", "-2.303866419932898e-17", "7.755724881763904e-12", "7.259386674520041e-31", "-3.504383164340872e+27", "-2.8189047526189693e+32", "220.1327362060547", "-4.550058414917118e+37", "-2.4314585310035617e-30", "17513.994140625", "8.146421508071437e+19", "-7.395163059234619", "3.231146123527198e-35", "-2234.702880859375", "-3.790671713952166e+20", "-3.11666973504559e+22", "-2.7874188987116762e+20", "3.1944433060674023e-15", "-3.6935927406889224e-12", "-9.651929192595505e+37", "-1.3046548019701731e-07", "-1.5664420488648825e-38", "-1.492397742840377e+25", "-2.3376681463404703e+23", "5.972962056194904e+21", "-2.0430047334472905e-36", "-1.1394164231852e+20", "3.3249674088807113e+37", "-6.555592470120569e-20", "0.0015790697652846575", "5456493805568.0", "1.8069523959264488e-19", "-5.108247999238426e-31", "487751488.0", "-7.162375981120563e+37", "-3.717563325975431e+23", "-1.433198030767435e+30", "3348326514688.0", "1.7986194507102482e-05", "-343906624.0", "-9.04124154211182e+20", "0.000836636929307133", "3.0902711707790783e+31", "5.629840938672864e-32", "33180688056320.0", "-7.951293581511502e-33", "-3.299113501322117e+18", "2.374828518691942e+35", "-1.2698183677033641e-20", "-1.2469354145239688e+31", "5.0412841510503215e-21", "-99.91157531738281", "-1.1798162677718381e-23", "-7398175119769600.0", "1.8213966113148684e-32", "4.470541693589914e+16", "1.566485262597566e-16", "9.8535102783596e-32", "1.8053604007679833e+38", "9.897597503358018e-38", "-1.8139782014259254e-06", "-8.924386028316471e+27", "3.377202731546495e-35", "-2.106621301012357e-11", "1.083866662735097e-14", "-0.0004693118971772492", "-1.0684144100849263e-14", "-2.0196627500128402e-38", "4.136973772436701e+34", "8.140709142217834e-15", "-3.419447167398893e-37", "-2.732259076765331e-07", "2.229387471743877e+17", "5.409962617702337e+33", "2.5631129135436326e-36", "-3.2960580772396926e-27", "-3720.139892578125", "-1.563458315087117e-18", "1.7755920745279982e-15", "1082833.875", "-1.4431986130921133e-30", "7.42108846223288e+30", "-5327.40673828125", "289047037607936.0", "-1.8912572875716714e-38", "-395552832.0", "-0.05779929459095001", "1.1830049015993355e+24", "-0.4744192063808441", "-2.7132938766542587e-33", "50998718496768.0", "-1.0467545116823434e+24", "-2.543101898785903e-26", "-0.022451680153608322", "-43970387968.0", "1017251260006400.0", "3.0431135687985656e-30", "-1.6769504183115453e+36", "2.4420867622692278e-24", "5.537807409027424e+34", "-6.15780010564e-24", "61081.29296875", "-3.444612415667975e+33", "-2.8022459003770006e-20", "-1.4331314526015978e+36", "14726834176.0", "-3.778322829700167e+22", "6.300451164756378e+16", "-7.743694064867085e+31", "5.479551540757892e+31", "68209258496.0", "-1.352202832630736e+21", "-4.652394954880604e-15", "-6.204877116762965e+24", "8.847976013558723e+28", "8.965976405761467e-09", "-1153025846542336.0", "244.92144775390625", "-1331038.875", "3.533806401080395e-29", "-9.704745829600038e+31", "-1.6439540160106348e+23", "-4861348352.0", "2.3319661757170707e-09", "-3.670579108024437e+29", "0.012106875889003277", "-1.4577616891386924e-11", "4.305823324557423e-07", "8845365423898624.0", "-9.476978879808752e+20", "6.109287336053705e-17", "-71966908416.0", "4.1654794463393136e+32", "8942661632.0", "-6.085734999542505e-25", "-5.563563197203171e+35", "-8216469.5", "8.382728509213317e+37", "-8.65165858012206e-16", "-34318789378048.0", "1.3603650586446747e-05", "1.3649104632987297e-12", "-7.614350039375495e-08", "-1.413450867460142e-08", "1.4402506335131918e-35", "-4.1848192519215514e+17", "-8.233516646249939e-10", "6.64825460990935e+16", "-7.982512252820367e+29", "-3.887334151311318e+35", "-6.825007076896382e+21", "8.659550674527421e-32", "5.9476680527369215e+28", "1.4824073009980947e-38", "-4.504831799351387e-27", "-9.016132494911316e+29", "6.369641971767505e-27", "5.6480078409725775e+34", "3.8254897852301853e-34", "1.1309777264176005e+18", "1.1759738552896038e+37", "1.0767576007990156e-32", "-7109318168018944.0", "-0.6070619225502014", "-1.3521560537910204e-09", "-3.628639055897054e-11", "-2.4028423293063857e+36", "6.3361422292182925e-24", "4.9099454624006064e-26", "-0.029220854863524437", "4.036142513541563e-07", "-9.307824978219108e+23", "2.0827901430402646e+19", "-0.21974128484725952", "3.1215969897857576e-07", "-3.6830527960773907e-07", "1.1935462139513512e-37", "4.92740974440429e+21", "-16036133.0", "-1.8450346548737107e+33", "-1.9317603625502807e+32", "-0.06023181229829788", "2.8914934748868656e+29", "-2.1537318271042747e-27", "-1.8950876340800455e+30", "1.9462896431641333e-26", "1.1334353297391403e+22", "-0.37654146552085876", "-5.656563749663615e+36", "-8.939710662681606e-16", "-1.4649821519851685", "6.623279230585178e-38", "-81306.3515625", "-4773473360543744.0", "3872.2099609375", "-1.1866790536330107e-11", "-4.427782738982561e-13", "-27026079612928.0", "2.1946496060241416e-33", "4.282627259681938e+33", "-21481168.0", "2.5477666929819027e-19", "-7.975338977202015e+35", "2.098968222958046e-34", "-7.581728469969379e+31", "-0.0005778466002084315", "139708038381568.0", "-2.0728077591151097e-22", "-0.11306449770927429", "-725959616.0", "-7.65459703966771e-17", "6352071947190272.0", "7.891068322308573e-35", "956380903112704.0", "20647637942272.0", "1.4629415734379592e-11", "6.671550181636121e-06", "2.0148140933429204e-08", "6.88156755322295e+33", "-13929736568832.0", "4.457143972606771e+16", "119358.796875", "-1.173526667318559e-29", "-1.8626286330495117e-26", "-2.455206738700378e+27", "9.925790662161655e+31", "1.4957006670823246e-28", "5.2921343874882454e+26", "8.488712915422141e-20", "-6.141791688407908e+18", "-3.2111694486035235e+19", "3547364327424.0", "-27053413892096.0", "-6.499862168966544e-17", "-13.282618522644043", "-1.0438370307008782e-10", "13689.494140625", "-6.111925744158031e-13", "-1.7008857655653742e-09", "1.698645760667045e-32", "-4.68711181240538e+20", "539831.0625", "-7.590541031277098e-07", "7.919258906929543e+34", "2.3922842519170585e-17", "-1.1025103751178684e-31", "-9.978763580322266", "-4.3746066346889585e-33", "-4.466733794139539e+20", "8.463325320706211e-19", "36444936601600.0", "1.8455449731900375e+18", "1.1003876570748032e-28", "9.89664686247982e-38", "-8.689802237161597e+28", "-90905024.0", "-1.848222382250242e-05", "7.394328396735447e+18", "59709.51171875", "4.876539830209285e+23", "8.063493234984223e+17", "-2079348097024.0", "1.577400740954513e-36", "64272994304.0", "1.0696594221050828e+33", "2.0166135938346655e-34", "2.97509673057314e+33", "42077643603968.0", "-7.820099968590827e+24", "-1.6568739874452413e-26", "-13.09793758392334", "6.857746038249704e-19", "-4.130672771019571e+17", "-7702976000.0", "1.6183353687030273e+34", "4.3771439643502165e+36", "-2.6988629467449786e-26", "1.0732575951701088e+30", "322795104.0", "5.796111610433079e+26", "-3399950335475712.0", "-2.157339746797786e-36", "NaN", "-2.671017646789551", "-7.61475523922924e-20", "9.290582908160668e-24", "3.59629434707864e+28", "3.944243184281069e-22", "4.333779626963763e+16", "-1.5142545981404128e+24", "-1.6907962389649092e-27", "-3170.523193359375", "0.0009114074055105448", "-1.0936076522504244e-35", "2.4678172394487774e-06", "-286741.21875", "-3.797363614807803e-35", "-6.178898452643597e-37", "-2.1480197195455583e-18", "4.371999520446638e+19", "-1.3396491791659253e-38", "-1.3171049140225932e-08", "178029871104.0", "7.434886570504588e-32", "-128454918144.0", "1.9473702990067789e+34", "3.4520921051281513e+27", "6.070592429280799e-27", "-2.2308322987422515e+17", "5.612712810487032e-31", "-6.881687068838403e+29", "2.0278530143968965e-07", "-3.9396223153031056e+32", "1.1876590976479964e-21", "6178332672.0", "3.6217938911348286e-23", "8.195951295419945e-07", "2.631654297147179e-06", "-2.897277911849788e-27", "436963.65625", "-2.6218625419351467e-25", "-1.1148534657056768e+16", "-3.424998325254368e+24", "562635112185856.0", "7.193718829512363e-06", "6.905680768278171e+33", "-4.103389570735884e-17", "-1.3219595012431449e-36", "-51.90133285522461", "-0.08894438296556473", "-6.837595703395912e-33"];
 
 lexical_generator!(atof_f32_lexical, F32_DATA, f32);
+parser_generator!(atof_f32_parser, F32_DATA, f32);
 parse_generator!(atof_f32_parse, F32_DATA, f32);
 
 // F64
@@ -49,10 +78,43 @@ parse_generator!(atof_f32_parse, F32_DATA, f32);
 const F64_DATA: [&'static str; 10000] = ["4.605903649704751e+215", "4.3777614830568243e-190", "-5.296159056412222e+165", "1.7188170795546048e+169", "-8.169327931971852e+64", "2.12338050800719e-291", "3.3817923868437805e+254", "-8.694048944451457e+130", "2.1508285202308386e-184", "-3.68888824783738e+151", "5.777811765978983e+139", "-1.004836796807778e+50", "2.0369844524725027e-193", "-1.394721303931989e-206", "-6.620324553788364e-287", "-3.863651082792937e+188", "6.668018501027924e+100", "-2.431142929918985e+58", "2.4519003383813767e+225", "-2.1572862263177616e-94", "1.0407063280620988e+94", "1.2394685065066398e+242", "2.2925972104952533e+238", "0.9579312694384565", "5.650714616582864e-52", "-2.6099915918499864e-285", "-7.710801797390307e-259", "-7.706937315191701e+147", "6023210374.235577", "1.493872980423587e+156", "6.101720742160517e+186", "4.76006994599459e+157", "9.411766647778948e-87", "3.0790204730154097e-125", "-3.340732850324595e+29", "-1.1590210434428368e+95", "-7.699510428125844e+189", "-1.4399928775246042e+307", "6.429858559388991e+114", "1.5316270277228486e+63", "7.427228061440168e+21", "2.3143257914486143e-173", "-5.766522859182995e-92", "-6.55858430370723e+182", "1.983166993967462e+247", "-1.4605536866420174e+291", "-1.6324397813429352e+92", "-1.0059887875981593e+85", "-3.7317170120438595e-116", "1.7392252677140169e-127", "-3.3389079172329986e-243", "6.195027500104433e-192", "-3.245630231557922e-10", "1.3370228045585017e+77", "-7.459454097283361e-61", "-1.6233585232843771e-282", "1.0914171662970213e+240", "-8.909048090987935e+145", "-6.210215798170802e+207", "9.346837727375784e+120", "3.4213760797782104e-247", "-1.2023504166557088e+268", "4.072414955144482e+84", "1.8005710287718673e+206", "-6.220784449800688e-82", "4.3781339258699265e-92", "-1.584093686548497e+106", "3.7857972616804545e-53", "-7.51556115929554e+220", "-3.92558959519242e+246", "6.701583835568633e-251", "3.6274931893781554e-233", "-4.8396105974535137e+36", "-1.6951078104498838e-134", "-2.926007862838777e+183", "-5.819872017461808e+58", "3.073356114183163e+266", "2.0954669533213084e-18", "-5.023422916633748e+271", "-2.036759149166589e+247", "-3.5357721653809485e+95", "1.7712372568255865e+195", "5.709401103491006e-269", "1.9963236744150374e+100", "-1.4666439613018903e-261", "3.304080707750769e+147", "7.281653834543107e+218", "1.2438458460714423e+305", "-5.7876766192792386e+206", "1.5177067809541384e+221", "-3.3913523102598134e-26", "3.6885004060933e+260", "1.0325991687411937e-158", "-1016213497746.3241", "1.0555642773668308e-77", "-2.3781926005904633e+263", "-1.4885703236711692e+73", "1.1186867166021473e-145", "-4.1688248385242167e-113", "8.540352537902222e+79", "-1.5440042690694577e-238", "5.109659341347355e+228", "3.619255940060516e-237", "4.7843576406207644e-11", "-1.2931571027794542e+239", "-2.987290033632463e-57", "697738872.3165306", "-3.980936321040661e+18", "-1.5677205505297562e+301", "3.508175641576687e-22", "-1.9023753554242674e+83", "8.603740023933161e-45", "1.814281812386611e+275", "-3.325313745697068e-30", "1.0843572812900846e+288", "5.831990581812188e-132", "1.124802309686453e-305", "-1.2254125300322015e+60", "-1.4326791945189636e-166", "1.9501389833342532e+238", "5.030494330229965e+68", "1.4818872467261912e+125", "-9.627670631745247e+210", "1.8183153232008567e+211", "9.498303142220424e-78", "2.5358297643623095e+264", "5.888080776617609", "2.5293451887088014e+32", "1.8337688794943287e+226", "-1.3089832343690099e+299", "-3.757112537268374e-66", "-1.6021809555546155e+28", "3.30050731422945e+277", "1.2737774342351182e+264", "-83.44837060528569", "1.7177391587450495e+87", "1.8034437470775157e-27", "3.4501087450005756e-112", "-8.646540042688057e+210", "-8.224981598245566e-261", "6.607527891505606e+146", "1.2251362424627131e-107", "3.702137863548339e+294", "-4.692449614533182e-46", "6.911809071269411e+299", "-4.883376818939651e-26", "5.83062609592203e-158", "-2.0974636235369308e-153", "9.833850777209971e+188", "5.071064646605283e+26", "9.816240715841856e+139", "1.1402140987476077e+30", "8.592778155583538e+157", "-9.637535752806082e-113", "-8.070943842818446e-13", "-3.5013321940040483e+264", "3.0316527891496245e+79", "-1.6814712742436358e+56", "3.664556803373548e+207", "1.876041205798888e-212", "-1.2993412580178822e-234", "4.479959203289602e-304", "-4.1230957210551983e-128", "-1.0472433939504376e+190", "7.778877912177527e-276", "2.7837368871791476e-75", "1.654283055467195e+247", "-9.289623354539027e+294", "-5.7704538129351064e-55", "-6.997088563181378e+114", "1.2538153604878197e+101", "2.0998578319214048e-23", "-5.2360812360711937e-11", "-1.7067694847306695e+265", "-1.7952680038548113e+87", "5.876973326855836e+252", "2.3308400708305598e+181", "2.6799316631502128e-152", "9.67597662089537e-285", "1.570836289481208e-220", "5.5939885266234955e+94", "4.715974885988868e-150", "-6.247429694596136e+178", "5.214613089721641e-110", "-1.4636588645682607e-39", "-3.3814122245139456e+16", "-6.369416550441738e+242", "-2.4750835204994606e+29", "198.73604002582306", "-1.675906411210153e-36", "2.9651035405858896e-09", "-3.8759941733914552e+146", "1.6506416486274946e-05", "5.72179603950775e-51", "3.8954660667014614e-21", "-2.19873137490894e-34", "3.467358593288476e-260", "-2.9662300108411305e-214", "1.0576695681168224e+270", "-1.2454765070233005e+253", "7.032571892923852e-290", "-6.338098098600931e-43", "1.0817739018982682e-155", "-2.361120019458122e-288", "4.565533866153875e-149", "-1.296591368967194e-83", "-4.271297338906073e-102", "-1.0939464554981953e+25", "1.1440033055373221e+198", "2.7940052854353057e+134", "1.316757730271084e-209", "4.4923826015227576e+86", "4.39671260155374e-189", "-2.3560049774324555e+284", "5.538312461549171e+108", "1.3275297589392918e-257", "8.271523360092129e-88", "-5.522942932097574e+284", "2.0092942781875022e+282", "4.815642662481583e+291", "5.458736952884696e+179", "8.814642909486091e+293", "1.0649746437063272e-283", "-2.0820585859646487e+126", "-9.557455240897849e+31", "1.2155283621515514e+176", "-2.7700886921240747e-154", "9.752876160989522e-94", "1.8337793933295239e+177", "1.6230727813993345e+86", "-7.032670856751041e-47", "-9.141026506481747e+86", "1.2289144899247394e-211", "-1.6289881799921488e+75", "-1.8898769392239145e-26", "-3.242429001762079e+70", "9.22491162041972e-88", "9.250380887353046e+209", "-6.425533236416891e-194", "4.6148186404141005e-174", "-3.1906205454598094e+289", "-1.0892080291136505e+302", "4.638314974776802e-217", "2.3236139972541943e-60", "-3.711868762092259e-11", "2.4110268649028985e-110", "-6.843288129143734e+75", "-2.9540922469246335e-15", "2.4180908158656614e-308", "-1.7392924363102984e-237", "-2.0495802633881997e-246", "3.0055474226916084e+77", "-1.6546681110646857e+263", "-8.469594879497706e+173", "6.65567917749252e+264", "-2.8618994487196748e+212", "-1.3120396261259334e+107", "-1.0699280411515273e+84", "-5.566825689898752e-26", "3.212204598719864e-263", "-3.4935104994583783e-138", "4.60840375176133e+55", "3.515241799147854e-67", "-6.374118549469474e+50", "-1.1332727891668924e-191", "-6.7888602959561035e+305", "4.616577185549428e+31", "2.4039052850770167e+58", "4.057460309894702e-169", "3.3065441885542063e+218", "2.08802642715253e-218", "1.9883137634081433e+61", "-1.7769687552338914e+304", "-1.0389707289893817e-114", "7.881494124775697e+104", "1.9149620308814208e-32", "-4.3146733926982646e+46", "7.967068921934255e+116", "1.541137397599014e-170", "-2.791662145679014e-80", "-1.7520435271435597e+211", "2.6736925478988478e-158", "2.8424316125928175e-48", "3.2195966016140444e+146", "-1.2314336643250336e-168", "-7.592632182666457e+124", "-6.509792217231882e+25", "-1.9968788822699438e+161", "-2.8276782391453255e+59", "2.9387425977682742e+243", "2.3074218988073086e+238", "2.1952060840149514e+285", "-2.0514585069034244e+109", "-6.285989725771415e-162", "-1.8738507381486873e+69", "-9.652178846001998e+172", "-4.595899994269238e-239", "2.708108310809366e+70", "-7.995118077317709e+110", "-2.0502930630760993e+72", "-2.060491848360071e-137", "-7.590367992753044e-231", "8.726747466854537e+186", "9.393907919107946e-298", "-2.3589563949028985e-18", "1.960750976332268e-20", "-7.47222110928968e+50", "-2.333019140407344e-31", "2.260381292999776e-52", "7.912621227348502e-13", "5.358721525659554e-15", "1.4164957226042438e-12", "-8.700559414119404e-233", "-3.5345970959162445e+31", "-6.789463888840968e-112", "-4.369840264236733e-142", "-8.102249315653357e+41", "5.862577360020017e+198", "-8.337108621925682e-137", "5.224609470022669e+20", "2.5936837917972476e+305", "-3.981990260390005e+25", "-4.389611693082252e+289", "1.8785821791223656e-109", "-6.530549689324885e-226", "6.691048919670921e-171", "1.8253790139430668e+161", "-4.44797785661067e+250", "3.5577626190515143e+177", "-2.559792126399622e+62", "-9.678566900237203e-54", "-3.37622830988822e-274", "-3.630952806307936e-121", "-2.522446929599587e+186", "3.785284044297128e+96", "1.6661909533301814e+307", "-1.716975267811364e-100", "-5.492176901004369e+137", "1.1463552083103943e+164", "-7.189595394621147e+144", "1.0487505970878743e-252", "5.449161081773079e+253", "-3.105647857862928e+43", "-1.4678531906305488e+49", "-2.5002876299462024e+183", "4.867601329852251e+89", "4.424412275658233e-28", "3.609620854224149e+153", "-8.5845640238719e-130", "-2.1185990040525925e-169", "2.0653504949686332e-116", "8.428811651034524e+206", "1.1087815769316036e-19", "2.32526124494166e+183", "-4.942204292407202e+189", "5.7309275911280705e+100", "2.4575448191387945e-165", "-1.532406791720727e+61", "-1.7234754509411661e-186", "-1.190059448818284e-308", "-2.1196322713928356e+64", "3.551432179826976e-130", "8.74215558272617e-260", "-4.890808432472357e+195", "-2.2991925606430193e+275", "-2.1630557921936615e-240", "-2.0157366757730431e+96", "1.6052944717984477e-285", "-1.0002377240995895e+217", "6.445528497303547e-83", "-1.6919198198191205e-173", "-4.332747186347178e+275", "6.256764985507178e-11", "-2.4972299187175385e+200", "5.811067182171597e+44", "5.837694733778997e+105", "3.7061264584010736e-129", "-1.1485885761781536e-99", "-9.767349455357279e-22", "-8.849762275107811e-68", "5.0310045288180614e-170", "9.711458007280784e-80", "-1.414527306301643e-273", "-1.9951148321254482e-198", "2.0722566351999577e+172", "-9.340980240207548e+201", "-5.357727209212438e+151", "1.2064356878870047e-116", "-7.459337095202623e-80", "-1.6556480001178674e+226", "-1.195347496494454e+154", "24442588.170096435", "1.4833457953025158e+227", "-2.1904618301372345e-149", "2.783802545978512e-47", "-8.4033077137776e-26", "-7.031696423864762e-140", "8.587206566293658e-282", "-3.826525752946729e-189", "8.194083527356342e-201", "3.2548374615141844e+281", "0.1234597508862378", "-3.20960059472453e+186", "5.0615096358253835e+292", "7.346906993781052e-239", "-2.6650970870002458e+73", "-2.8739203136162664e-270", "1.2290250145515001e+138", "-3.5722922187567064e-253", "1.6869217552568485e-308", "-2.694790158048292e+160", "-265723829562.14264", "5.3798471250410124e-266", "2.6022824791204217e-255", "5.701632123385228e-141", "9.169368200362428e+49", "-1.8115674203151597e+168", "1474837219.3421535", "-4.6024613528035984e-156", "-1.3956620613975515e+196", "8.272354815599682e+260", "5.274888694944477e-94", "7.282712922530908e+22", "1.2976925863711168e+244", "-3.947429488903084e-138", "-1.1628352056430525e+233", "-1.786838855319786", "7.994358248656206e+56", "3.399783934545275e+252", "-6.645200217179875e+247", "3.2869260872956533e+184", "-7.871371181652648e-276", "3.2524632564574177e-205", "3.163663679573824e-62", "-1.2298997159979351e+86", "4.7794999716934225e+228", "2.971218383459608e+296", "-2.9108754002854664e+178", "5.0226651697951006e-161", "3.189581510771388e-199", "-2.4209885675416077e+63", "-3.0761822423174004e-232", "-5.327004753696262e-150", "-4.0291367104460285e+153", "-2.7257235896933868e+82", "-3.091430248748309e+149", "1.9906060786425425e-136", "-4.247031986696217e+293", "-1.234678611656621e+201", "-7.14848409466121e-48", "9.319613476071653e+145", "-5.908648048687402e-19", "-5.3135673516001745e+188", "-1.4096063808304398e+120", "-7.36424299056314e-84", "3.164824092992898e-150", "2.4044197193995732e+242", "-1.635535591809169e-146", "3.999251620755977e+184", "-4.59653385267374e+263", "-1.53967935615429e-125", "-1.4416633476433777e+262", "3.6056735813040617e-147", "-1.4379462284596968e-154", "-3.250786525488452e-80", "-2.3514304727356495e+143", "-2.2276696087897847e-107", "-1.2736912289284274e-211", "1.9732891962764851e-25", "5.254776706294032e+159", "1.5066060659352722e+283", "1.436675286705032e-303", "2.2690002894483872e-54", "3.7177015805128903e-255", "3.668095566251028e+82", "3.910646395645485e+50", "9.550583718850037e-233", "-1.2245654036175746e+38", "-450.5510138736456", "-2.0908976371159448e+117", "-2.789675162277652e-234", "1.7789332762236196e+137", "-1.1234253242490857e-266", "-4.9247935361905066e-104", "6.4147671736957775e+56", "3.673492322863566e+168", "1.000164998376383e+50", "-0.35924865268305944", "2.96883885846182e+151", "1.1194236799114753e-90", "-3.4887563020377355e-290", "-5.274174414090316e-256", "-4.9978925859485965e+297", "-3.594070161114215e+148", "-1.744501390327328e-182", "-2.3084820147462033e+85", "-7.140649884779493e-57", "3.493768726379499e+289", "6.104930741318118e-94", "-6.465049568917377e+182", "2.9204473223603825e+144", "1.1881501006535494e-165", "2.9859261992370307e-10", "-1.419784879085097e+23", "3.861407528263771e+196", "-3.7202583068571815e-149", "-1.4562414466427162e-89", "2.7446611187144698e-14", "-1.2459690977054181e-285", "-5.2969021114647985e+191", "3.524432695067153e+154", "4.911125206996357e-125", "-2.790621936045029e-85", "-3.259256830742235e-165", "4.86182438665005e-90", "1.0094830456191501e-15", "-1.6679794675143038e-246", "8.581025751167447e-122", "2.012889102074514e-290", "-8.199338856157298e+24", "-2.3228860245338367e+49", "-1.1988720814930991e-170", "2.872883207850944e+279", "-1.267116193018612e+224", "-3.3056990485468306e-143", "1.224960043752412e+264", "-3.2284700471923085e-15", "4.818698556293927e-260", "4.6788208860339225e+228", "-1.0096772054436581e+278", "3.1335078745186603e-130", "-2.5461268963010294e+221", "-5.763927510462826e+57", "3.8861155704015763e+285", "-2.287164551995503e+17", "1.522698951909613e+207", "-4.6630903722670524e+206", "-2.0446655549995568e+137", "-1.009733870240923e-220", "2.6003289842626513e-154", "-7.341037054809118e+235", "-1.3802863158534546e+204", "-7.00015390733721e-248", "1.7456560342954725e-136", "-3.067791768393238e+71", "1.568982002961438e-34", "-5.80745756106394e+37", "-5.460916823866777e-198", "-1445131054328362.5", "4.769314971347562e+263", "-1.7794322065407946e+53", "9.1282626505918e+78", "-7.462385510016487e-224", "6.688386685719249e-207", "-2.0214251013770855e-144", "5.134905323333068e+202", "9.957501807672275e+37", "-1584596.12854015", "5.25186995515793e-253", "-2.895224242266038e+162", "4.832875412080398e+202", "-4.637317312313842e-136", "-2.7331532760808207e-158", "1.2578884784137878e-69", "2.0114196647565155e-200", "-5.1194329990391e-217", "1.0293869121696606e-233", "3.793170247247071e-242", "5.669207966729783e+257", "1.8945818153097877e-127", "-1.5013523591823748e-30", "-5.931914108302793e+196", "1.577923746471452e-60", "-1.4254558693561186e-258", "4.1949927421664626e+128", "-6.976452326509688e-221", "5.280950872765968e-208", "-4.878933125884887e-168", "-7.2925013244031395e-292", "-3.9568218116454183e-240", "7.652251232411718e-128", "-9.817803446401897e-70", "6.16965942370705e+45", "-6.846605931811872e-50", "-8.674044448738325e-169", "1.735008368038344e-06", "-7.293476325190894e+85", "-3.8951822999407584e-186", "2.1066581449000344e+96", "-9.553033131798745e+68", "5.0516774310221565e+95", "-2.92065934703345e+104", "9.684501155301909e-169", "1.3930980947401842e-71", "3.4464332704414e-267", "5.1456496051404365e-275", "9.499471268004841e-61", "-1.2288333997449763e-84", "6.231291570479672e-47", "3.4192389400048614e-147", "-1.3517907328414227e+100", "-8.110560551537345e-308", "-5.021262918142004e+116", "-5.390319569519392e+241", "-0.0002151496685110137", "2.4793236339755564e+205", "-9.420857194271926e+42", "-1.1690015034384648e-117", "4.105544142062045e+239", "3.8289565533352335e+303", "-5.04446351744357e-17", "-4.505845939938744e+134", "4.67470065358253e+73", "-1.3172461225605483e+39", "4.530640262463814e+40", "-9.621750529703403e+297", "-8.53328457135562e-11", "-2.71211731170682e-263", "5864986327849.044", "-3.2853388799664e-187", "3.554095098647695e+291", "-1.3297545225561461e-143", "-4.397021834766906e-198", "-2.854321885649954e-199", "-5.517508074984615e+180", "1.0238416023513819e+88", "4.540446984006204e+170", "-1.7665485640385873e-200", "-3.2477181019711047e+193", "-4.80435142405367e-59", "-9.80012875760014e-241", "1.0799331905969294e+294", "8.573962922654113e-10", "-4.367121265986557e-287", "-2.81635856766858e-244", "-2.387042306358744e+260", "-1.1295959186680868e+64", "1.99656192984936e-211", "4.642685625723951e-207", "4.576448742175186e+92", "-1.1115497237777586e-126", "-1.9208704541197173e+34", "-2.7459870551474223e+293", "-2.606806063873258e+195", "1.1597878859567342e+83", "1.1401009019053071e-80", "-2.021147315433768e+136", "-2.5367929765428383e-155", "1.3676862855988906e+22", "8.512896347774293e-106", "1.1162933409400607e-227", "1.2262167584448833e-268", "4.0605011789356736e+132", "3.9688837583818264e+262", "1.9308359782759917e+219", "2.585430934883764e-05", "-1.254967658679785e-44", "-1.4589163615614306e+308", "4.0629162438184686e-20", "4.841781804376047e-73", "2.6738294842997515e+105", "-7.013305202919508e-302", "-1.083401312953969e+268", "2.0093139930187738e-262", "1.9120761007353725e-209", "5.6949714806434846e-129", "-8.694523742883585e-102", "-9.041914345231832e+269", "-1.24601008823825e+162", "1.1873983698575892e+66", "-6.869314114917811e-302", "6.1275434857379066e+290", "-1.5759049117135897e+242", "-2.9003399293252737e+46", "-3.8505151210189333e-149", "-3.526755183143762e-72", "-2.3580206444862197e-161", "-8.08274422909991e-249", "1.9865877662086105e+220", "-8.36750707148487e+143", "1.3215439625712427e+171", "8.293723003648457e-272", "1.7356269844789366e-193", "-1.1762206818593315e-197", "3.4640760150755696e+59", "-1.063577270596533e-168", "-9.521308006330126e+110", "-6.810022979827181e+306", "1.0316242413544014e+260", "-6.1701069254981185e-276", "-1.3347795955942259e-136", "1.3754516332813081e+130", "-8.71365354990119e+212", "-4.824862069364646e-292", "-4.747024364666442e+65", "-1.4876001671676918e-76", "1.8582488686042874e+264", "-2.3815915046957215e-237", "-4.803097219861818e+118", "-1.8094040706516977e+276", "7.347497576181526e-289", "1.7462120889380107e+246", "-3.0779169184339274e-86", "-6.080603066155132e-56", "-1.4811408679936807e-83", "-7.146604143140916e-37", "9.956851843726383e+68", "-1.2942577859419354e-162", "5.859787519418252e+245", "-5.59243383098814e+66", "2.303947907555634e+209", "6.476636288239268e-81", "-7.872714057966797e-59", "7.702585935796898e+202", "4.254277120852779e+152", "-1.5614069817990552e-190", "-3.4750254297825514e+187", "-6.549704361090392e-21", "-3.5076173892301774e+160", "-6.09034766605437e+147", "-2.375802100000872e+235", "-6.46722172461294e+95", "1.1665749659508285e+73", "-2.4643881436311722e-290", "2.0229732305470518e+290", "-6.439929183848769e+216", "1.431533057110869e-201", "2.263510166777402e-08", "3.075496932132975e+41", "2.979188867189492e+208", "-1.0033263192886409e-115", "-3.993834720101194e+214", "5.587050570360416e+22", "-4.2202266907266525e-138", "1.508761208758566e+254", "2.3670990429489164e-70", "1.9072818015827154e-99", "-4.5882119914096574e+145", "2.1033628278207053e-209", "6.530379785560293e+199", "-1.2464474213408073e-293", "6.241291917540211e-13", "3.722373347282027e-48", "-1.0352902272905198e-122", "1.1569249473748235e-273", "-2.0628799205132172e+98", "-1.4842055773041285e-257", "-1.1116506575910407e-285", "1.1765541251747911e+147", "2.184914331204547e-85", "4.420718829171373e-206", "1.2338963393009825e+121", "-1.1215657913327063e-19", "1.847019589948838e-149", "1.509601739503291e-11", "5.029180502624e-100", "-3587498738565.1807", "1.333246434627229e-119", "88496157911.8884", "2.871970124217877e+257", "1.256456180172118e+242", "1.1784862835024637e+239", "-1.4947547311848428e+203", "1.1227035238196889e+141", "4.552666512565147e+199", "-1.6135786123068439e-10", "1.5607085004753855e-150", "7.433096811500515e+202", "2.4940594026687675e+291", "8.679247109891648e-91", "-8.171693286253107e-186", "6.793204259366318e+224", "1.9558756937593767e-186", "-2.7211559475375385e-05", "-9.715611324343111e-104", "5.361620240113552e+25", "3.528289656236233e+54", "-3.984877574841379e-305", "3.554733585180731e-126", "7.353612831239335e+279", "2.0619279716872587e+143", "265725750.47390652", "-3.446332646935865e+62", "-3.8392381877054724e+182", "2.0755525841946027e-280", "-2.7295587351579454e+95", "5.277889275846134e-130", "-2.6284039502818684e+283", "6.950020594191479e+71", "-1.0241101970819055e-85", "-3.221555243249828e-254", "5.656799262798555e+178", "-1.4144483299342305e+141", "-3.463434855298857e+64", "-1.039953009818461e-145", "-1.7484406841723174e+195", "2.1200902951814643e-86", "1.2256050476990918e+294", "-2.803906781197967e+57", "4.699698853447094e-258", "7.005116865500454e+185", "-1.6139576076495506e-301", "1.3772287248813355e+266", "2.3579796524917704e-08", "1.7500072041892944e-150", "2.946159797015068e-138", "1.5149667607232302e+171", "1.7094570154230463e+157", "4.0633211782377466e+124", "-2.0253580085207894e-107", "5.835918680005152e-105", "2.509235400848491e+246", "-2.0870842947466256e+257", "1.7714097225830368e-123", "-3.735048030825554e-189", "-1.9855985930555892e+214", "3.451160952449369e-271", "-2.1886344660895983e+59", "-3.3617948341087082e-21", "-1.8540190733477972e+160", "5.381129626909699e+125", "4.029566131373693e+216", "2.9210382785514952e-179", "-4.440372129698543e-66", "-4.855186006560102e+263", "1.142523680966107e+268", "8.698737600647602e+287", "1.358499945681163e-89", "-0.15784697009045065", "-1.0339298319948193e-190", "-2.345816144872921e+278", "2.9829274251492616e-110", "4.064305920918722e-181", "-8.425926998375272e-125", "-2.329748817084161e+290", "-3.947563379257917e-70", "2.3179484616005093e+255", "8.779641490882127e-44", "4.1657514422848496e-69", "1.0055178255691923e+267", "1.580690866443379e-256", "-4.2419512674561965e-285", "-7.039629914810402e+261", "-0.05628806810816631", "-2.0642342084983892e+247", "-1.1192887237312834e+185", "-4.356983454943975e-36", "-1.1610543010429778e+16", "-1.6542312847114685e+134", "3.457548578534609e+83", "9.934872707804613e-290", "2.002559491700491e-74", "1.82579855791613e+274", "-1.3377641274326887e-40", "-1.2579595471913663e+241", "9.098186052731754e-84", "-1.562844910724398e+24", "-1.2557780407355727e+86", "2.3060422295832116e-13", "-6.406241986049138e+247", "-1.1183675066392768e+306", "5.878013839897993e-97", "-1.0822098650035073e+231", "-3.5836170031283917e-217", "3.438296864268486e+203", "-5.415233587513074e+95", "5.883540439238828e+297", "-1.457873345998543e+152", "8.572246822108865e+24", "-1.3092275575387955e-165", "-1.0773580748638626e+271", "-4.362785707976749e-15", "4.443226546467664e-260", "3.3770344481520685e-90", "-8.239900600318365e+37", "1.681397099355149e-160", "-6.057371886806232e-93", "-5.315083714547619e-259", "-1.6543469866495314e+169", "4.082645623158476e-117", "5.207186242759098e+140", "7.261526952135286e-34", "2.419658452310897e+18", "1.2123682064960545e+158", "3.228436070071906e-231", "4.349638155629211e-233", "0.44779423078043995", "3.19187521774056e+228", "4.6074259738440886e+167", "2.368487180003621e-107", "8.180296826697319e-69", "1.2506905787783972e+63", "1.4793328893765859e+171", "-1.6512566612823063e-106", "-8.315117962510267e+27", "9.16447409436046e+91", "-1.0789441247855778e-80", "-2.214532815822384e+125", "1.367182049556896e+252", "1.6011787626540376e-118", "-2.735735869653074e+137", "3.90319536621367e+136", "1.76088773194138e+128", "2.1406839009894737e-237", "-2.7329389869333365e-86", "-1.0775616877617972e+92", "1.908596397793522e-128", "1.288895239093781e+205", "1.4867734416744045e+219", "-2.334909004600448e-170", "-6.052462425169109e+140", "-1.5052430094583747e+94", "-1.4888297880731118e+259", "-2.0173179159858837e-93", "-2.3117381176481516e+272", "1.9318546470959497e-94", "1.961431803955658e-154", "-2.1039870579903627e+294", "-9.481457648745855e+169", "7.1621334955082e+214", "-1.453211553224063e+129", "-1.9117958756805404e+261", "-1.9974347080014314e+303", "9.332445025237492e+83", "3.536095178185316e+29", "2.982862731122744e-297", "2.4203596177338665e+175", "-5.252567917338093e+130", "2.5566272452198273e-223", "1.496178371514157e-70", "4.804944298847318e+108", "-3.17432356574144e+20", "-1.0098922224782302e-209", "1.2103140472891477e-52", "-1.5743940425127445e-104", "-6.603316087242615e+233", "9.64317751570315e+175", "-1.6983387525702462e-165", "-2.198592857456154e-50", "1656739853.5370429", "2.2680669376169846e+48", "1.7431279507674895e-137", "-4.373404901751139e+40", "-7.106802351658499e-72", "3.298808668843867e-266", "-4.633841325504954e-267", "2.9911311295814137e-214", "-1.8322820273589686e-193", "8.189127135264892e+21", "3.028510327047214e+47", "-1.2262111420475145e-182", "-4.416364942658789e-93", "5.826891342573573e-91", "-1.2104781266471073e-12", "-4.76992412262134e+230", "5.695580181582622e-63", "9.481682748937705e+306", "-1.5520447565289276e+120", "-1.7206660545183548e+202", "-5.982518071918246e-108", "1.40531127125051e-211", "-2.9541264952592207e+177", "2.5357862316829822e-110", "1.4872564689135702e+179", "8.597919737372263e-137", "4.1657727811454024e-296", "9.095030897632538e-215", "-2.157260950116011e-45", "7.324239664806773e-28", "-4.007898435256018e-06", "-1.1972846757108762e+145", "-3.4543679237972593e+96", "-1.8614407041272484e+41", "-2.4742145726978604e+255", "3.3514700494029432e-270", "6.676023514558436e+242", "2.2471872611752687e-41", "4.2931552138090045e-127", "-4.8313782448347376e-27", "9.057928887714119e-174", "-7.606450548314506e-161", "-7.871175337412938e+193", "1.4007973784450947e-67", "2.9267438657190376e+94", "-1.2191763396589477e-296", "1.1322466898144816e+131", "-2.6629726384902255e+210", "-1.5832806716818199e+130", "-2.0705839197048728e-95", "6.714311610016441e-171", "-5.1220895880173553e-160", "-4.092294756215809e+282", "-3.060174727260816e-201", "-3.750996799795711e-51", "1.3045456319408935e+304", "-1.6238921285304103e-205", "6.850775391897294e-41", "-7.032653929912631e-304", "1.3260029516528977e+286", "4.480259575051633e+197", "-1.1687355814301801e+281", "9.96135197046809e-231", "5.295710297265164e+180", "4.045562311279015e+17", "3.838175258246695e+177", "-3.901912084403871e-277", "1.793189252719569e+46", "-1.7375297871208768e+248", "-3.739630557208157e-100", "3.7799815080844406e+111", "-2.444127454386431e-53", "-3.169202575716065e-64", "5.804604669772881e+120", "2.920940372729504e+250", "-3.39942277730372e-100", "1.3742477654834898e+248", "2.2843426829541647e+142", "5.7035779901554744e-226", "1.0711188552588404e-262", "2.3747130304107105e+73", "-5.112187650150894e-143", "-8.386598599276585e+98", "8.234762938117566e-168", "2.805103489329466", "2.62926301882291e-135", "5.934568908280424e+143", "-9.874125558602241e+202", "-3.166917364293201e+138", "4.295366490853947e+296", "-2.274887556310549e-15", "-3.2520712164242393e-264", "-2.038458937800083e+40", "-7.582536128832807e-45", "-1.3905172245432217e+185", "4.470407621834876e-230", "-6.56477403916773e+126", "-78497.97621412155", "-1.0632828175592531e+245", "4.713162236290418e+262", "-3.3119841880513625e-138", "1.694150494110423e+271", "2.8231048795428362e-139", "-7.865805337042764e+62", "7.935150953053963e-193", "-6.3420195049207045e+277", "-2.1114434738569442e+135", "-1.6773451652278685e-204", "1.392888525402528e-245", "1.2068504760002664e+197", "-6.713365205843957e-203", "-9.630868189022274e-288", "1.4013033780058987e+290", "-2.7680561366625314e+208", "-8.61614481492742e-16", "-3634923845.1499076", "-8.168691714713527e-44", "-8.220335568335525e-41", "-4.836007048197369e+184", "-5.540480951656591e+191", "7608481724842.611", "1.2065584077822518e-142", "-3.4214070832480016e-240", "3.849717593825652e-280", "7.022746707729456e+300", "-1.975232983479813e+224", "2.076906052631837e+284", "0.008903569277310562", "-1.320403209705347e-233", "-1.907727337476748e+131", "2.8950396967086973e+240", "8.49233968400331e+48", "-4.9554266267636324e+207", "5.283345050401155e-242", "-5.34495662553804e+195", "-0.0030782710316344672", "2.8066561744328763e-105", "-6.329452874957659e+290", "-1.1730016734567327e-272", "-2.8376753704995096e-75", "9150140235857.307", "-4.971121783161782e+222", "-6.754419696241331e+173", "-2.9997561883670136e+292", "-6.993080782329501e-131", "-2.9823666332594224e-203", "2.6583545570651816e-97", "-1.6700986882107411e+255", "-9.154442572365978e-98", "1.428951451835005e+181", "-6.211035598050058e+96", "1.8105535637668034e+184", "-5.755506149041012e-258", "4.06009361534727e+283", "-1.6270674381562373e+77", "2.1266822352551716e-30", "2.121747832935288e+26", "7.132612446711361e+56", "3.6958872723864714e-263", "4.5229514524828925e+246", "4.597527960250231e+70", "8.925439126661913e+162", "-2.2558786071061124e-207", "3.1561459142386327e-239", "88302884812.76355", "5.809361598931248e-92", "1.832522387422361e-283", "-7.556386356267007e+294", "-1.0746204389846649e+148", "-2.9890600784272614e-111", "6.715540078581616e-226", "-3.0659253975027946e+127", "2.820776817293362e-52", "4.8193988398819743e-17", "-6.458693027675753e-273", "2.1270107339850723e-19", "5.930569881036439e+150", "5.129516348227451e-198", "-2.3815755218973488e-247", "-1.0494433550054595e-119", "6.105290386117869e-14", "-1.6531223477872006e+214", "-4.20917523160459e+218", "-0.0006207847404493262", "-3.7813402238709195e+277", "2.5831702270205848e+88", "-1.2465405988343528e-212", "-2175310640971817.5", "2.232703376644606e-215", "7.370155201725572e+224", "-2.725916696823354e+158", "1.7087754502034635e+259", "4.533422961652715e-19", "-5.3733024127318735e-31", "3.178392311998787e-242", "6.062956425222274e+175", "-4.835092657630335e+122", "-2.184299732025626e-60", "-1.053913531559465e-215", "-6.106634712855644e+172", "1.4170901635343304e-238", "3.604806402446899e+213", "7.26608306400364e+259", "2.121096664418034e-179", "-1.6813478880610417e-202", "-1.524934888460864e+282", "-1.046087273418774e-276", "4.0068215296185565e+117", "-7.930526026795332e+195", "9.059884032873589e-147", "-1.2435248198941522e-172", "-3.592087361612819e-140", "-3.49350697881088e+172", "2.545209178701219e-74", "-3.6368873914855313e+36", "9.799027031268586e-138", "4.017749986960537e+112", "-1.226700804161927e+181", "1.2334005694081759e+95", "5.375590098024608e-86", "-9.015835030698807e+89", "3.5328493002720838e-130", "-1.303179733885076e+250", "-2.4824001272489926e+115", "4.995235058702133e-66", "-3.52976430507524e-239", "8.050592124153812e-301", "-8.823180940436549e+173", "1.4544725288165148e-291", "3.8763687897824107e+216", "-2.147341876397935e-113", "2.6974600042428186e-261", "3.818176321569166e-263", "-1.0420393306898406e-209", "6.323717926112483e-280", "-2.8993624900780545e-241", "-2.40301731698508e+197", "5.4854949632783576e-281", "1.961792378471254e-23", "-2.4775401081002875e-19", "4.989919800521616e+76", "-2.4271587410676077e+39", "-8.078037475903744e+34", "-4.9801013999902834e+244", "1.572937762065168e+65", "4.1564224998546403e-269", "-1.5621850111177946e-258", "-2.7091116079619415e-13", "-2.59998951264666e+262", "1.6916266419858615e-259", "3.851934964857995e+71", "-2.3236821653241298e+204", "-1.3118823334455973e+192", "1.6433449398581883e-85", "-8.706688108280205e+130", "5.582952515316699e+192", "-5.877350623744282e-171", "733017598.9326044", "1.19845682415582e-60", "-5.79339943015845e-148", "-1.8914418645294784e-47", "-1.1351719945200502e+188", "6.263399233313362e+140", "4.5094690587343834e-228", "1.9239535626211482e+301", "-1.0968844695332421e-112", "-1.957821540187161e-232", "-7.792898370547477e-149", "-7.085224474760887e+221", "2.63743752288566e-69", "1.6741787756444682e-220", "-1.943238100308634e+38", "-1.5547731778243323e+226", "9.491672580742917e+257", "-2.114231529036902e-212", "1.3579519160915043e-122", "3.5247512990780587e-100", "-5.477178359191683e+220", "3.3166133020181654e+171", "2.9741316932912943e-173", "5.5130379195360435e+225", "-1.0245166676174827e+169", "-1.0012739026500101e+170", "-1.9068673094973132e-189", "9.999336254907934e-165", "-8.102524494216959e-50", "8.090418766821471e+182", "4.448925751391645e-100", "-3.9952050979474425e+183", "-3.170933876989524e+250", "2.9311728380236256e+124", "6.733115707788484e+121", "3.822475916069533e+295", "-1.5282300929463222e-56", "9.553954491589001e-14", "-5.069148671236563e-304", "3.905236096883734e-45", "-1.999620691254009e-221", "-1.3882877074942057e-112", "1.82620647697472e-61", "-1.0409357658300904e+80", "8.66988956372957e+18", "-1.3727523348117404e-301", "-2.4586510632524322e+64", "-3.39312962478102e-116", "5.5377125379403256e+293", "0.9736169107442938", "-2.67948067330217e+176", "1.2262426200372962e-301", "2.542190626991094e-180", "-1.0889560865828435e+161", "-1.75185923389238e-54", "-1.9079108580291037e+231", "3.8133106376681e-52", "-2.734228885644896e-238", "7.662407833072683e+239", "-6.826454483319808e+45", "-1.5879142687117945e-184", "-5.8107746115243964e-95", "-3.8446469157282658e+115", "-1.0139312730052005e-190", "4.127446255598988e+292", "1.6573168772513027e-271", "6.506442212276879e-108", "-1.871618529499614e-38", "3.7932770801780524e-237", "-3.066170842327288e+107", "1.5512553106776652e-98", "2.407705187780827e-67", "-2.250915950910974e-183", "1.309637881095574e+252", "-3.046461065342075e-23", "4.30369833793009e-36", "2.9615606040059404e-96", "-3.488348841689488e-266", "-5.1513680215777165e-36", "-3.293109194958639e-99", "-3.4127701535044998e+199", "-1.1287500790980052e+130", "-2.2423918449432984e-97", "5.478242383071609e-283", "-4.835127727552428e+260", "-1.0076899505859932e+198", "-3.96106225298364e+297", "8.620705926226892e+58", "-3.550159263545093e-258", "-9.980132260679982e+140", "-1.9378252536412372e+306", "-1.9592901140166913e-212", "2.81911111629482e-270", "7.477516079385154e+95", "-7.897116377910892e-156", "2.0679782981556517e+154", "-3.3763030412380127e-81", "-2.9060104530921344e-302", "2.1773348145026124e+59", "-2.868367745005404e-15", "1.9488994704198808e-255", "3.377537127811903e-189", "9.095097609047444e-121", "-2.4246183840961427e+17", "-6.859850536369496e-248", "-9.051417187991405e-81", "-1.499855620618972e+227", "1.1178656474987287e-301", "2.7509656657632218e+271", "-6.428393482872238e-173", "-7.427219859637393e-94", "-4.006703684358148e+106", "-3.3336478221001215e+49", "1.1026497683901826e-293", "6.437517142965474e-124", "-2.3252486880137642e-123", "4.88916927877988e+233", "-4.659894173933432e+66", "1.4926006964451454e+276", "6.557456045808075e-62", "-5.894928793312027e-196", "2.624607348602595e-103", "4.443224061555137e+121", "5.622429833363532e-230", "-2.1099867396122058e+268", "17.262284268449985", "-2.9268445033767444e+190", "1.0661840484419413e+220", "-2.1151052557669205e+254", "9.529521304833139e+158", "-3.625940419099798e-133", "-5.639629379657611e-204", "-1.7884547581308113e-252", "6.841567578495498e-37", "2.293685753009228e+135", "-2.9571736619321285e-38", "1.7786891108400194e-07", "1.5821646794391427e-208", "-3.2158642233970455e+189", "3.705788082500326e-75", "-1.5798764442238294e-124", "-3.5192489010111296e-109", "-2.521845583825699e-194", "3.606638618802575e+64", "1.2279944503724318e-10", "6.378020581796174e+286", "1.701742349956143e-145", "4.301424289649326e-43", "-1.9262722963998793e+278", "5.177135007143779e-150", "7.579447607827422e+145", "6.202419030676214e-257", "-1.8653418283651954e-215", "-1.2893180949465456e+85", "-5.86569890904594e+18", "2.3157086182835924e+69", "-1.583785430897299e-256", "1.9214075720375307e-290", "7.5064676664704535e+230", "3.0202442905838733", "14.344810587928988", "1.2528001540756102e+152", "2.6358448149561752e+272", "8.567706531307576e+142", "6.109898270129616e+220", "-6.162380341920591e+287", "7.934603782158335e+295", "-2.9917336267887506e+256", "-6.812215160377373e-116", "-5.959825077963317e+43", "8.164156786326969e+268", "-4.848698757069032e-42", "-7.358980529344515e-196", "1.6069433981482822e+218", "-5.587466031239839e+59", "-6.314787427436751e+233", "-0.0007349106043735398", "-4.8231385121056856e-200", "9.520763055205241e+112", "1.03117571081491e-300", "-5.224764484470288e-191", "-6.437310682231898e+238", "1.1476281870707229e+293", "3.9034914927854017e-166", "-3.8766424713340633e-196", "-3.9912889772503214e+162", "4.084903468418322e+286", "-2.985177824377594e+155", "3.3539757330605178e-220", "-4.307451546424122e-26", "3.664851620775782e+28", "-1.7963661127120216e-249", "4.866836081738875e-21", "2.6801272726873505e-49", "2.7347767583851805e-40", "1.442316717118354e+150", "1.6113184458704713e-304", "-1.278937321553054e-91", "-2.1446986970129705e-13", "1.7607349691643886e-65", "-5.273877501181547e+135", "-1.3102734815273331e-48", "3.610821384617748e+89", "4.3957923436107365e+89", "2.888739348459735e-29", "3.6752796657571966e-268", "-1.3597501051143148e-282", "2.6840422120331724e+197", "1.163841034474778e-157", "2.2178070888901777e-154", "-1.7599570430432473e-113", "3.470944991581378e-307", "-7.07150588502171e+66", "6.82919805106149e+246", "5.293642934756036e-87", "6.718970506909888e+210", "-4.209853419242382e-282", "-2.208003033488682e-238", "-1.088149286797898e-304", "-2.189112160798325e+168", "-6.363137042808255e+123", "1.9893262999837095e+274", "-4.4837416400383e-163", "2.85449631453801e+138", "-2.372454642514315e-174", "-1.5281220824094728e-158", "3.258881934226397e+100", "2.5579874326799785e+138", "1.8631133297770969e-298", "-3.0245364388687683e-127", "5.1256392860421056e-40", "4.4921577595002834e+36", "-6.153910292564269e-263", "-2.2731482589898657e-292", "-3.5563383011166404e-293", "2.0634048480363e-204", "-1.5731511343215456e-200", "1.77425193799324e-221", "-3.2916153536141696e-247", "-8.805997662299681e+259", "-2.191029186522919e-137", "1.883524760724916e+227", "-1.0990751704064401e-229", "2.1575375071660735e+52", "1.310639508638879e-50", "3.4943182409034208e+283", "4.773799919391518e+200", "2.617597075965281e-58", "5.008868017020299e+168", "-9.934389383016405e-185", "4.2435051490059766e-95", "-8127081969494675.0", "55.750035971607865", "-2.346760752576443e-253", "8.21819165778026e-11", "-5.21276717714275e+126", "1.27594192538426e+84", "7.04312800345478e+221", "-4.231418214387049e-69", "1.1268908003574623e-05", "-2.3265852275473625e+231", "-2.8292577162574707e-303", "-4.509640485377251e+220", "-1.0443626730072176e-286", "-1.5439718079210377e-71", "2.1408105050822218e-207", "-1.1075929345342945e+266", "1.0334217041473303e+287", "-1.8298725055241954e-69", "4.7208303586420156e-245", "-7979125433.5688305", "6.265508452021915e+225", "-9.753682138970836e+299", "-1.006117387241808e+86", "8161350.62832953", "-9.145166226946696e+187", "3.5263351960002885e+201", "-1.6666620859161331e+233", "5.585492955178965e-24", "-1.4608766481428792e+107", "1.6408051635509062e+246", "1.9091247002697313e-33", "2.0777773058317943e-269", "9.694857088790801e-85", "-2.5506163802175486e+254", "-1.2038854580127831e-154", "-1.6977120260553724e+162", "2.0654175101165386e-304", "-8.965464228891964e+123", "9.165117519502268e+192", "1.4911202684415987e-177", "-2.9936968043541317e+25", "5.6860986705791904e+290", "8.707654326745924e+210", "-2.5234824907117173e+223", "1.2785907449172645e-24", "3.1721025231971805e-283", "6.624765954454162e+20", "0.021080060238223463", "-1.2553354733068026e+171", "-6.234940949618447e+251", "5.6716148242122277e-39", "1.0095773244031146e-07", "2.883431316564238e-162", "-4.244252222099548e-67", "4.926687241288411e-42", "1.00471377805842e-275", "-7.249457171385036e+201", "2.0776048892903577e-263", "-2.4188138641983124e+284", "41353301.75799129", "2.8793023101711353e+72", "-9.776465899833214e+190", "1.1799968524814506e-114", "8.936124740487108e-87", "2.0844208513643773e+153", "1.4088307242691586e+165", "-1.592442206360305e-168", "-3.7257792253918674e+54", "7.211670799251663e+281", "2.8508876918520766e+223", "-1.9369930461376e+116", "-4.6180253325659256e-49", "-6.114428148181167e-270", "2.6815595355511877e+178", "-2.5467951049019267e+253", "-2.936438913189088e+296", "-8.960484334840665e+90", "1.9526673028166873e-24", "-8.120606390497925e-85", "-1.1437952812746567e+27", "3.7899673158122974e-94", "-3.7774718272915823e+183", "-2.4262628841611864e+30", "-3.6149325627391295e-71", "1.3682630347096224e+301", "3.4861430661189844e+307", "1.3764198929262287e+62", "3.117227793882517e-156", "-1.3060024489246844e+149", "-9.700576408058063e+216", "-9.021685999230911e+122", "2.6515445420259403e-31", "2.5300171967594457e-182", "-1.1895723292304348e-98", "-4.6164987876577556e-214", "2.1851128199367216e+161", "1.0222209716350457e+189", "3.963013737003773e-87", "-8.471823664701509e+110", "4.497361868678453e+111", "-5.536180367898187e+113", "7.2235351081845865e+168", "-6.445103052926448e-247", "-5.5961068050602456e+271", "-95167683.99280317", "1.007500650415603e-234", "2.4311943709849437e+121", "2.7391806307927706e-35", "3.697154268001468e+292", "3.815784883144078e-80", "6.728574961865483e+48", "-6.79874248793255e+99", "-1.3034253418510192e+124", "3.162755643035131e+282", "1.2348160456013853e-247", "-4.9827361064483074e-59", "-7.16229856012024e+134", "-3.1662150006024943e-130", "2.8864387569326524e-146", "-1.5242992943297207e-62", "-3.680770095190221e-247", "9.361980850022146e-267", "-1.224899755849186e+126", "2.6724794255036445e-185", "1.6276913516255966e-77", "4.4631968745330435e-176", "4.5132761727183645e-306", "1.0116220846671944e-28", "2.870937664423907e+192", "-1.7537489286057873e-15", "1.381952192963976e+44", "3.920191635427942e-270", "1.3626889418443428e+219", "-4.4559521012439893e+55", "-4.552878417729425e+17", "-1.4875476940211951e+165", "-6.953466631871263e+116", "-3.426807640442877e+254", "8.130444865750328e-83", "-1.7695986464156677e+115", "3.485146694782894e+141", "-2.7420320849740447e-28", "-1.589995291040331e-284", "3.4161462094635855e+275", "6.408193740992737e-282", "9.719902258166981e+23", "3.8977407585640366e-175", "-7.686871187853835e-280", "-3.893148179053565e-104", "-3.331358250407627e-119", "7.599285030309346e+68", "5.0300775751873167e-200", "-11416502.641774578", "-7.211787208348792e-291", "1.5510382161568394e+229", "-1.831023501217724e+290", "7.218078811635626e-234", "7.287931265077969e+163", "-1.5093451280205935e-120", "-5.213363478903967e+243", "4.6326578860614865e-262", "1.253374297332946e-78", "-4.942600162482487e+262", "-7475.9364424270225", "2.3132795588091083e+207", "-1.8818497828433782e-36", "-9.795011876511307e-55", "-2.087109988361704e+219", "-9.409904100087539e+32", "-6.3857676696504116e+271", "-1.006477855198077e-211", "2.850662840829256e+115", "2.4247766192297965e-19", "9.762521304222341e+26", "3.2368087771361674e-206", "2.731941092778058e+263", "6.662363828333591e+183", "-8.95253732459002e-288", "4.31661029441272e-20", "2.0026048924140294e-91", "-1.1493066540070488e-300", "7.034061007555413e+270", "5.3698594825961725e+78", "NaN", "-26242227806.228104", "1.230111020323026e+70", "2.7014485033056407e+27", "1.687711107063071e+197", "2.2074598848415317e-198", "1.784494200326126e-158", "-1.3840452528671282e+154", "2.1011703114356426e-277", "2.2008488793529627e-100", "-2.5685071268594873e+216", "-1.9286380736300053e+160", "7.884407458930743e-88", "1.3421572236635828e+44", "1.4738331402859876e-190", "-3.682724632749041e+196", "-1.3303074644555766e+154", "6.296044606674525e-115", "1.0581448572510705e-75", "-2.7985950481047207e+169", "3.2581129072797213e+184", "-1.2266641376646325e+141", "3.0838562232745097e+127", "-3.060207726185612e-141", "6.956568876754685e-103", "-6.355440551792749e+273", "7.135155783539268e-271", "-6.5313787263329745e-68", "-8.23584126159403e+63", "-2.14143081968288e-273", "5.099251771247087e-97", "1.4044264740609147e-131", "-1.2794229406596033e+94", "5.657921584882836e-279", "-4.764308769300645e+238", "6.510354057364992e+22", "1.2123238546320223e-85", "2.4180236007130812e-278", "1.1910822631540515e+247", "-4.519763248633312e-172", "2.0899233618881808e+211", "2.772991479010335e+156", "1.3490143466498094e-88", "-1.9923992434052334e+299", "6.821386584980669e+34", "1.2127937956417202e-13", "4.234819741801053e-182", "-1.4047178047535284e-128", "9.241204092093653e+93", "-3.885940559172896e+204", "4.339651762092293e+131", "1.3433409353782658e-119", "-5.91047667786871e-136", "2.347669537806208e+121", "-2.4397575082206468e-107", "4.5347872032773356e-246", "-9.200836813219247e+98", "7.845141014749262e-23", "-3.436836112916182e+227", "-3.8484306076304175e-100", "9.541971535031571e-24", "-1.017633480601677e-254", "-8.205687883135082e-257", "-3.2639943734658084e+185", "7.055107136168144e+153", "-4.4322611349521235e-181", "-2.6693029821889562e+172", "2.1595245392632198e+136", "1.4059596208764229e-98", "-2.1281501021740476e-130", "1.0813155669232081e-299", "4.179046854779117e+142", "7.849168951223491e-125", "-2.562142514953296e+102", "-4.929121901326056e+107", "5.216363266545932e-196", "3.5069543632498737e-279", "-2.7711504713835985e+278", "-1.6182244295539216e+143", "3.6467645146074935e-301", "1.0829357052308687e+239", "-4.407042962464111e+292", "1.041527274278639e+170", "-5.775882673545793e+88", "5.982006225731769e+33", "-3.898696305506993e+180", "-1.2045916806909196e-171", "1.0681586891925124e+167", "-7.36578033825355e-272", "-5.565915984268589e-146", "7.19038519190376e+72", "5.500525477296683e+211", "-1.0126027511973823e-161", "1.6929107572753285e+267", "-9.564225294384443e-95", "1.8266510652324622e+195", "1.66192595250971e+147", "1.3410138105982526e-277", "6.222829092298009e+140", "-0.001481104267216433", "-1.018813123327687e-101", "-1.4485750093524507e-146", "8.72916764007501e-26", "7.915717407778332e+201", "1.99403652648432e-289", "-1.64261474925263e+298", "-3.322167247504972e+68", "2.024661325239736e+269", "1.281146052130918e-105", "-1.2386116934160734e+55", "4.0657636726889776e+175", "2.4558189333009256e-244", "-1.3954435224357067e+247", "-8.969815563348215e-194", "24506974326.559967", "-6.331892004942516e-107", "-7.4023789365138405e+261", "-4.770341144868325e-264", "-1.0455049353773226e-138", "-8.775816705828165e+57", "-1.359171716123854e+211", "1.0412228707391172e+295", "5.849123206442334e-238", "-28403.597653464123", "-1.80325475899995e+154", "-6.980858153595708e+239", "6.005620624672414e-151", "2.018081130092618e-180", "7.908071274515656e-32", "-1.4861206034038774e+173", "-3.252941960767627e+27", "7.241774340212412e+121", "-2.0864679623046107e-254", "1.2658367842721403e-71", "4.638909964341703e+195", "-2.9165151651123848e-307", "2.2226745290358807e+281", "-5.397292345902376e-280", "-8.621860456089073e-156", "-2.907112418433479e+122", "-5.690805978392352e-185", "-6.837168335574367e-271", "-4.464012408145682e-207", "1.8245426773916432e+213", "8.919961920904448e-06", "34816211.989793815", "-2.0111882267265287e+121", "4.7852459427812866e+199", "-2.8000237980368746e+163", "-1.1508573436246412e+165", "1.1703812237658341e-219", "3.0407270240431236e-28", "385213571747.7636", "1.0505783226777343e+265", "5.9237817557156594e+54", "6.251254122701321e+235", "-1.067763436801014e+102", "-9.2484210061571e-102", "-4.7058136383227545e+97", "4.891536193497627e-92", "-4.348973612719758e+262", "-1.3185986406830185e-10", "-1.170181359372075e-214", "-8.252860785203341e+203", "-1.6597733307589327e+89", "2.1881560667175373e+60", "1.3913081029303414e-57", "-8.681519742914957e-250", "-0.21645789656732384", "-1.005691011438458e-122", "-9.924709795657238e+175", "-1.8970037573833292e-305", "3.447626684119273e+271", "3.1391917437786545", "-2.471858434294877e-272", "-6.228336511290729e-290", "1.1505371208030393e+265", "-3.4544920381428215e+208", "1.3112632117769353e+48", "6.493601411736551e+201", "-2.2177482840215006e+251", "-6.452703889856365e-27", "3.731439853845291e+69", "2.738301082060081e+257", "-5.5267879391531916e-219", "-9.787265208491126e+230", "-2.2457281434629958e-57", "1.6942184163250946e+243", "-1.7319374297678497e+290", "1.1891883534852673e-273", "-2.985107931079897e-149", "-1.001038364609815e+226", "-3.61921559890914e+127", "6.01072423551282e-86", "4.6188547811360966e+47", "4.547569167298674e+237", "3.0130341032449643e+99", "9.630592478061213e-111", "-6.443714581135616e+77", "-2.152249229287451e-243", "-2.3184025074348134e+219", "8.120356741125883e+164", "-4.707523387114442e-144", "5.895029505223563e-35", "-1.8514921775831792e+133", "6.722605165483212e+47", "1.359483140103028e+300", "8.69433661719108e+103", "2.0393854375439137e-46", "-8.250897904785249e-289", "5.9743782912393675e+277", "-3.284116979294699e-37", "-6.784334222385295e-68", "3.0678032819504556e-152", "7.067467645195798e-102", "-1.5204767572257075e+158", "8.529600016033429e-11", "6.121605676685013e-277", "-2.10960490604713e-229", "1.754427080049643e-153", "-4.881284649827704e-279", "-6.193514908178279e-49", "-4.9030526852695785e+114", "-7.619657362214887e-198", "-5.251193184419586e-10", "-1.768768319187814e-69", "5.91876513630182e+116", "-8.159999530099793e+259", "9.864882914673991e-16", "4.838437745781373e-242", "-4.288868497694853e-32", "-1.199209650552085e-193", "-3.657308313661726e-70", "-6.612011137847284e-139", "-8.336727225012631e-250", "2.083468625395623e+190", "-1.5422010262578615e-176", "1.2839682534740482e-161", "-6.775640522518921e+65", "5.1787817920366315e+42", "-2.4108336313100207e-133", "2.7352562224725295e+200", "2.1242489115021867e-300", "1.0850228760777873e+150", "-3.7736049880470104e-191", "-8.430147702212919e-100", "-9.986872695792553e-38", "-3.87602765769804e+175", "8.095861159538883e-35", "-1.5710583298523225e-176", "2.7618065094407426e+104", "-1.1707746052504598e+58", "112595724715.93387", "-1.3813460578527754e-281", "-5.1647478116001625e-161", "7.333713167541921e-07", "-3.4481126279505317e-243", "-5.953763491439556e+255", "9.937573624396663e+233", "4.71734614532319e-183", "-2.284662507027196e-276", "-1.0206822439591757e-196", "1.4027211387020674e+308", "1.879063616662881e-182", "-6.6687601065584125e+199", "1.228458261178487e-106", "-6.644071835070428e-180", "-8.464451157851699e+71", "3.8451291605319563e+179", "1.7347655645278206e+131", "-1.1518953577231675e+243", "-1.3966999032499504e-22", "-1.3648322881313865e-301", "-1.5200293839062018e+161", "1.7488518898101734e-258", "1.7796628680917032e+57", "4.808956032544242e+100", "-1.0652130398594637e+88", "-1.5439596345704254e+53", "1.0947013939061862e-67", "4.722566873917024e-225", "-8.69278027090437e-163", "1.3552590773691292e+221", "-4.0650274789676255e-244", "-1.701246832219209e-12", "5.098976569142355e+147", "-6.24855264322028e+268", "1.9956761061651518e-269", "-9.115200378346315e+117", "2.7887050535033403e+206", "1.8061926187789812e-255", "-4.1947224167113313e+18", "-1.462688706730681e-112", "8.039531879098235e-218", "-2.8354489770719757e+31", "-2.728471006241226e-183", "-9.472256294619753e-210", "-2.0506759203229898e-167", "0.17025705557409837", "-1.405808809083034e-139", "4.167339428455095e+128", "2.971074534271181e-56", "4.3994867438190536e-40", "2.6057500255101397e-172", "3.849577569849961e+93", "-9.970294255709154e+219", "4.1885097200199476e+117", "1.5657213886995673e-48", "-4.280341690608984e-48", "-1.7772554811123234e+212", "7.834465026577601e-270", "-1.5038042571710146e+302", "-8.43522957633837e+45", "-6.513170786301642e+129", "2.751000558608427e-299", "2.4923409527425867e+157", "-9.404177358730024e+123", "-6.695466543659206e+97", "-5.929901950660404e-63", "-9.344199277082254e-179", "1.346128402213448e+76", "-6.0631389323900615e+143", "-8.597324570509805e-269", "1.9758989595618178e-101", "1.41753332835432e+62", "2.0192434295704714e-45", "-8.558604450650371e-259", "-3.2803813293013785e+82", "4.290543930859522e-225", "-1.7090297731594454e+143", "8.00897171201206e+192", "1.7307864901610997e+193", "-1.0246990031280455e+203", "-9.918906130606416e-53", "-1.241505003046581e+90", "1.0033160758465937e-44", "-1.9718103377158763e-71", "-6.144278809320578e-135", "7.819051977929452e-84", "2.280342612718428e-141", "-8.859622411579776e-101", "1.3527546831930378e-278", "3.550706938493487e+158", "-1.8574236047695842e-213", "1.4535414813364024e-185", "-1.7661013955728688e-216", "-9.487246806346352e+40", "7.172355576469145e+127", "-1.9043778915362705e-191", "5.142272976629817e+278", "-1.7707423601617853e-243", "-1.0818915816453481e+188", "1.9109339188089143e+44", "6.279121540185035e+237", "3.2672565269439823e+302", "1.4547534986547157e+115", "1.4903367969499493e+82", "5.294462397437271e+152", "-0.0013122272418226708", "2.3133685094565678e+159", "2.7604776353721326e-238", "3.315256419980541e+278", "2.242776190635345e+221", "2.1607007118727605e+23", "-1.700333583222135e-211", "-1.5243941337880332e-90", "-4.229524357350381e+40", "-283555533831896.7", "2.1407132543431463e+141", "-6.677413093066116e+236", "106361260.37850322", "8.52709434024374e+216", "-9.218981394104934e-48", "6.476582185530621e-146", "4.692060078907161e-46", "-2.988453553735349e+269", "5.427449140988922e-308", "-22874.44300869484", "1.092903073350988e+88", "-0.0001422734860416645", "-2.62205609655405e-63", "2.0814705563839928e-199", "4.435791292135785e+79", "4.59293901750071e+255", "1.4630793686157486e-94", "-4.391332882745717e-270", "-2.6792835892449146e-27", "1.0957815720778272e-104", "-1.3380241110199643e+200", "-3.356909483968248e-267", "-5.724184269560422e-126", "3.5850486215123344e+253", "1.5428977364520116e-151", "-1.7145759252167633e-31", "4.821780641244867e-64", "4.026781613929093e-298", "1.6284369044891614e+243", "-5.433652532871833e-241", "-2.4729555256065103e+181", "-5.151744644091643e+79", "-5.598219962885882e+155", "2.8051836654518674e-163", "-1.1857917607684557e-188", "1.0844119649058474e+298", "4.744761625424828e+193", "9.950067355505656e-144", "2.2273721899110402e-225", "2.2089763405646048e+78", "-4.162334463778673e-151", "-3.885281041256186e+196", "5.304456257395838e-286", "3.830373915999419e+296", "-2.2246106442085115e-181", "0.0014287276977845715", "4.098267313475971e+76", "5.0027951440275573e-138", "5.105958042879894e-192", "-2.570602843593239e+300", "-5.6615447129461795e+57", "-2.2725317881072476e-164", "-2.0206772289188766e+165", "6.605496862131317e+50", "-1.2651979966428988e-260", "-1.3719732497549353e-188", "-3.112899358933263", "-3.9444573649971197e-168", "2.9567948831574196e-276", "5.133363171152801e-220", "2.3719646268122482e-86", "8.941942791066766e+135", "2.7232773563903954e-49", "-7.349731192760858e+216", "7.625741343475779e+204", "7.74727973690807e+56", "1.1171788316879715e+154", "-1.3035239341635196e-77", "-4.5723391458297635e+162", "1.9253531171103232e-110", "-5.1481664470505814e-95", "-4.877507160283195e+301", "1.0705249251806472e-88", "1.7399081866360424e-291", "2.044927925229612e+127", "4.247271188733825e+191", "-5.6238472911515286e-114", "-2.0874875452634505e+110", "1.9332115948707294e-185", "1.5230068857090924e-34", "4.587751644671401e-88", "-3.709596331134998e-289", "2.3643422796737076e-161", "2.99492809240498e+109", "1.3014184300702848e+301", "1.8581007756807498e-54", "7.417780491006366e-72", "-5.02338730680695e-232", "-3.09616824432297e+185", "-5.6755925979781515e-285", "3.818034418128478e-36", "1.2672262933847712e+126", "-7.983392265161015e-159", "1.1881705206146773e-256", "1.4995535002362452e-235", "2.6333260147768675e-27", "-2.6853469414647314e-90", "1.573986747206122e+172", "4.79093687619134e-192", "-5.272923606682772e+260", "-1.2638449583511353e-20", "-1.793342927045801e-193", "-2.7930400203490943e-205", "1.7570919270530423e+104", "3.6819431316681537e-242", "-2.072162768047023e-116", "1.1375623660567617e-46", "-9.70226276586099e-268", "3.543962744119087e-108", "-4.144263687754614e+210", "-6.955053819232035e+75", "-5.110009812592344e-178", "5.985074699143221e-297", "1.2421100432529181e+167", "-7.984388415428896e+94", "-4.057201325217853e-14", "-8.99390629603142e-116", "-2.227084793116667e+281", "1.557266295544694e+56", "7.871468761697657e+264", "5.184288580731361e-32", "6.769876792724745e-236", "-1.677420586199812e+49", "1.1281351344967329e+269", "-7.587474779917946e-308", "118452895609.1182", "5.2724481865709483e-216", "1.7288571046454088e-200", "-3.3695579403856317e-133", "1.278801155671687e+277", "-7.97889758760811e-287", "-2.6868689979490945e+234", "-7.848671629614436e-11", "2.334873389497247e-38", "-6.224059566726179e+141", "-9.405403538874253e-139", "-4.3290260164949695e-103", "4.342728386224646e-79", "-4.2256661139914885e+192", "-1.5231200013880611e-90", "8861708671.721653", "-2.0987002861983168e-269", "9.584691204575865e-223", "-3.7579724370239226e-281", "7.213854011995135e-125", "5.334688225764336e-305", "4.246796432615778e-175", "-1.201946749622411e+200", "-7.062267470971138e+183", "1.0631788328754052e+80", "-3.3121356015165246e+34", "1.3351505514396693e-192", "-3.687986963854921e+166", "-5.668531343172577e-233", "-30365099234117.297", "2.819345732065512e+105", "9.137496903296076e-226", "6.58337104000692e-106", "-1.9263284796133213e+192", "3.2392912139553817e-239", "-1.5457727486169202e-199", "-2.5206569889024654e-21", "5.16903391594062e+92", "-1.0495948672243065e-231", "-2.328067000667139e+238", "2.846127008955458e-285", "-7.919555477021205e+62", "-9.342703271108336e+162", "4.465572641481713e-212", "4.8436456242375656e+232", "-1.3922271545877084e-151", "-3.400774895203426e-227", "5.742695675702269e-307", "-4.291850016489093e+37", "-1.2004343077205986e+281", "8.925271124423398e+122", "7.623744341682362e-84", "9.443460593023362e+173", "-3.2450990282014986e-37", "8.761222792460252e-144", "3.808737874641853e+205", "-3.718943916724759e-126", "8.30643276184323e+107", "3.4356674807055343e+100", "0.08483232228955989", "-4.84528770545756e-278", "-1.0289742797689752e-82", "-1.5578392190213371e+212", "5.285277316933953e+223", "-5.0838634441679835e+277", "-5.3623218764984875e-192", "-1.4480054805420396e+120", "1.0720437676700374e+295", "-4.828165629616419e-302", "-3.4104711378565163e+171", "8.492048913669997e+128", "6.404849856068831e-51", "1.2315081136676714e-170", "7.565585902893265e+160", "-1.7676990889557082e+224", "-1.6111200403363858e-202", "4.409701618233033e+43", "-5.932789377832649e+99", "-1.0036053017844059e-307", "4.4088728470367577e+142", "1.6136317257865874e+304", "6.387079674237194e+68", "7.498014273458551e+58", "-2.163207662310486e+178", "1.027932536436709e-209", "1.9811516719912492e+188", "-7.089542766725311e+202", "1.755469755283475e-231", "-2.7700767765441227e+200", "6.045428517099149e+60", "-6.06200323182579e-186", "8.070327903697642e-300", "1.55094224238088e-199", "1.8308746883410987e-54", "2.588988611495516e+222", "-2.3654219455841713e+139", "-5.9439411357887086e+153", "1.1048577307247262e-231", "-1.6622110760247618e-142", "2.8183760067009902e+132", "2.8316996097723773e-145", "5.9725122744013645e+197", "-3.3810348048270262e-292", "-1.995840135647859e+149", "-3.55699524860309e+300", "3.966355625651321e+278", "1.8681769901203166e-300", "-1.806791395806577e+151", "1.0846062734002027e-255", "-5.358455876681735e-261", "2.0753723888419341e-128", "4.161475136636598e+36", "2.4678691565567177e-161", "1.1515114431209286e-83", "1.480106194358123e+138", "4.23494648260426e+295", "1.8344285635603475e+26", "7.403296882699174e-182", "6.943546226848788e+225", "8.882220775849561e-08", "4.7763482457322663e+82", "-1.496935738434543e+224", "-1.3416464147244977e+38", "-2.6068233557923355e-72", "4.4072345230530745e-46", "51524092.856943935", "380976504.7154", "-2.04946878514621e-263", "1.0162962651249128e-63", "-4.2489114868874705e+131", "-3.491751886953453e+114", "4.0774522536318155e-143", "-7.06086952360092e-258", "8.3790255988799e-240", "-9.26921305214337e+147", "8.741378224603305e+85", "2.448119630073575e+144", "8.113668708311437e+29", "-1.660361200714148e-73", "2.843224027541183e-22", "2.367603707623697e+89", "8.971526442683271e-222", "1.0984996531854075e+155", "2.162176262297995e-206", "-5.482453448922995e+137", "4.091772830454843e+123", "-6.1841857517940175e-139", "-9.728943236260307e-260", "-0.0002433323285088076", "-3.281878772230373e-142", "-9.469823806065208e+107", "-3.2640662425493357e-231", "6.112333796660509e-166", "-1.2555152145403451e-185", "-6.040938524614445e+21", "5.5872266080288034e-173", "2.61598789465602e+213", "-5.90537897919693e-251", "-6.62771558768378e+256", "1.758694440728682e+205", "2.3610428126293452e-102", "2.073459181299388e+147", "-2.424231794339718e+132", "2.1392845975536986e+31", "-7.636772413193945e-171", "-3.893507044662802e+21", "8.878755422523743e-135", "-1.017799503870085e+59", "-1.35408501601629e-79", "4.137688592885328e+184", "-1.3395285457002617e+197", "6.422118429739471e-115", "3.3576423331556416e-39", "-3.070613074986427e-180", "2.5938226152667935e+286", "1.219158052441269e+197", "2.640090716484407e+272", "2.2631914720514552e+278", "-1.8846872181811655e+19", "0.43656271534601687", "-6.087605130918697e-183", "4.557883106475135e-157", "-1.4713613514424318e-09", "2698048720.0546923", "-3.344910714660431e-192", "-3.0154377748552217e+35", "2.6677427266456317e-280", "-1.4617891461577552e-104", "6.438816268273009e+113", "7.124134820958737e+39", "-4.347883252245404e+25", "3.5010208047573984e+251", "-7.296915508729037e-115", "-1.1573587649337332e+253", "-1.763922201526108e-222", "-9.438516881481078e+158", "1.3999655454905111e+53", "3.285363897707633e+105", "-5.465082309688829e+220", "-1.4760115944922518e+197", "-5.865092768349676e-291", "2.700378714699278e-217", "-1.479049400106111e-250", "3.355362890917042e+92", "-1.4390865894547433e+225", "2.2706655572057745e-104", "1.789590693311539e-18", "-4.8613929156707804e+241", "2.0730885357282638e-256", "-1.2743172674602399e-227", "3.526888858956843e-37", "2.095926012858749e-17", "1.6775573591829457e+190", "-4916503417.190642", "-4.428426370529553e+16", "1.3062253959997986e+228", "8.337472158725309e+216", "2.444358387346511e+220", "5.8850426184455034e-204", "7.76310839531179e+107", "4.613800570670256e-196", "-4.5643758010307264e-256", "1.5416433566772595e-285", "2.621729570433304e-168", "4.196046054481107e+297", "1.28139517202943e-20", "-2.375579398658035e-121", "-2.1557076305145317e-104", "-8.914710075702865e+166", "-2.0921738017654963e-97", "1.071801648164171e-54", "4.864208528629721e+254", "1.0429000031482164e-195", "-8.06408834447547e-308", "-3.567924764259037e-220", "-6.322289504286812e-71", "6.08083384925237e+112", "-1.1817127402723663e-16", "-2.6109876054475274e-182", "-1.1801769603451038e-35", "1.2167086883015116e-88", "-4605.655900514052", "-8.901308049938807e+220", "0.0025771215112032632", "-1.4882660230996435e+115", "-1.2438260225766649e+203", "-5.602720996929597e+177", "-5.974362469736422e-80", "-1.7324075792466826e+223", "-2.7151902675108426e-42", "-3.376759981158558e-153", "-4.7940789990725276e+151", "-1.455956368220568e-194", "6.27201857593823e-257", "8.085677344995292e-232", "-6.045207188182856e+149", "-1.9213436259443665e-139", "-2.852524104874438e+139", "4.945863583720997e+139", "-4.008217356059204e+171", "-1.6957688692178052e-84", "-1.7058462638156663e+206", "-1.7161183656079582e-217", "2.402684050302788e+247", "7.639023772258681e+112", "9.158233055858418e+45", "1.1251153716382173e+143", "1.4006659262850727e+302", "-5.226326105987672e-63", "1.531105991373903e-94", "-2.2381385268985508e-79", "1.906919816488853e+223", "-2.94051271907072e+142", "8.064958421608778e-237", "1.8126463994692778e-109", "-8.3127457080985e-222", "-1.6838152096093965e+156", "7.715940408737923e+154", "1.4517863092247683e+62", "3.058205203393773e+16", "3.950903834217479e+221", "-6.095630650533916e+39", "-1.3097298003517058e+166", "3.001938052608138e+225", "-2.074840882101984e-258", "1.843546310923152e+252", "-9.377487097309687e-154", "1.2702465090028378e-270", "5.728610764405555e+208", "4.390325356365715e-162", "-8.532421383857662e+102", "-3.184077431723649e-208", "-6.1637222129456514e+209", "5.409310053292511e+71", "9.595149171581343e-278", "1.8094520985838034e-88", "2.166947971796866e-251", "4.146216013363161e-194", "-4.105012921344343e-292", "-2.0503773072157118e-216", "-1.088063750560045e-271", "1.0513422564675362e+257", "6.660017946780044e+86", "-2.20472042285336e-65", "933.3921000838111", "-7.116492558110052e+77", "2.14968162475187e-106", "-1.942401308604637e-129", "-9.306587531124414e+108", "-2.8209910857355835e-190", "-1.4869064918768919e-30", "7.123297519620366e+70", "3.625988268747817e-229", "3.860056465795552e-291", "5.637319406237813e-159", "-8.548097533807475e-196", "9.862936281303315e-284", "-9.256173337889845e+117", "2.7644379797719926e+305", "3.676244082334802e+263", "1.8120152291388336e-189", "2.2317181438994427e+18", "-1.4516032838874978e+295", "5.496155106023657e+45", "-7.616135431290658e-131", "1.4787842406192584e-136", "4.755031451824245e-41", "-9.078752106444254e-192", "-5.107775692626987e-21", "2.9653653384271803e-08", "5.955577161943985e+84", "-3.73123658517124e-262", "-9.467957829932548e-278", "-5.825165365897693e+110", "2.4496608324445718e+57", "-6.273749862469814e-139", "9.999949912535322e+268", "3.2751731017755553e-295", "5.6175949054035205e-154", "-1.3851422475001413e+34", "5.551473737117148e+109", "-1.5233520691211999e-212", "-2.442872037046224e+107", "-547805807814.9163", "1.5339491994506078e-187", "-7.127886363308345e-73", "-2.2026667845433825e+285", "4.847471706540185e-249", "-7.49258390223565e+299", "1.7894227453347811e+301", "-6.965282504474272e-288", "-7.550165795717626e+248", "7.52890881733493e+281", "472977892.72002846", "-1.571257093956473e+244", "-2.0128678650530275e+228", "-4.328663505039718e-307", "-1.4593539518018413e-72", "3.600727499737581e-144", "7.424171831369254e+101", "-6.5257614642959154e+187", "-2.757206908146692e-57", "1.6229540039475817e-230", "-4.0656873592607006e-194", "3.7576767288439533e+294", "4.49398677017446e-205", "-3.2784849026104177e-96", "1.3678216586660692e-261", "-2.158639545796021e-250", "5.15179836137132e-169", "-7.494204291084742e-287", "-5.7634381101122475e+141", "9.130875093761167e+88", "-31770190432492.105", "7304407842.713312", "-2.735249843608792e+100", "1.9959083091571937e-96", "-1.4311792046614308e+240", "9.586939840472639e+173", "-3.816491254211634e-116", "-8.360342330354883e+186", "5.1820226979357527e+39", "1.2791626575378784e-246", "1.6408009275198776e-39", "5.136435631453298e-131", "-3.1049436517836467e-21", "-2.3725120059057822e+83", "-1.7062354065017671e-102", "4.7630720988036985e-177", "1.316604747840434e+275", "3.567835033721532e-82", "-1.273889786328102e-159", "-1.389709828852492e-78", "-5.917137867241857e+21", "5.187003013194531e+302", "4.2153851389655015e+281", "3.0722477629878146e+92", "-7.04379260469757e+31", "-1.3830935329752784e+23", "-1.6143749061577528e-178", "2.059647187572125e+57", "7.041067701061165e+20", "-1.1596666103100148e-294", "1.4868850232255895e-280", "-8.900464665758756e-60", "1.1061084221041697e-244", "2.850753627750704e-224", "8.782551021793214e-102", "3.3083270850281063e+273", "-3.226711976051809e-49", "-3.2196536390597477e-158", "-4.511670999022323e+299", "2.3191276563565776e+237", "-6.454109206372707e+20", "-1.0451391623551291e-113", "-1.414971844792042e-104", "3.3660754912206704e-232", "-1.9107754928364747e-173", "9.97843692662241e-218", "-7.743340334944642e+224", "-3.4264913988542485e-196", "-2.3111879956508508e-156", "2.5675588091086922e-14", "1.8032061955134887e+59", "4.794124307140459e+268", "2.1001841012349634e-126", "-3.1342286398859916e-25", "-8.154100720771023e+225", "1.6824911244470813e-152", "-9.058541703125055e+209", "1.0073028780388955e-197", "1.2876740774650546e-165", "-1.930105461747966e+25", "-1.4953934242444244e-65", "2.489506507161396e+126", "3.3401941184464385e-180", "-1.5660230063526585e+217", "-1.1623684298227063e-244", "4.82063121425977e+42", "8.82528448151127e-212", "8.902236520062502e-83", "-2.122115857215012e+212", "-4.902224153480248e+156", "2.566791830149522e+94", "2.3662463202867433e-244", "1.1569019540960005e+203", "5.1738290169522226e+39", "-4.584874940757015e-273", "1.448191380516726e-119", "6.874079401831807e+195", "-2.8607533459571363e-291", "1.7942389408626748e+267", "-2.2859658828790214e-184", "-1.082485904723714e+213", "-1.9616144322856675e+234", "2.0806983100047235e+29", "2.5004481102055385e-246", "-1.6315646669895786e-252", "1.900928202249465e-226", "-8127620863497.965", "-7.69794599160418e+175", "4.036210724356323e+185", "1.644087615883736e+187", "1.312244577354604e-72", "-1.8168274862577588e-266", "-0.000492065587271095", "2.7585731452838633e-219", "-3.49967099153945e+217", "-9.88793717291206e-45", "8.100976609094631e-143", "2.4082874100346525e+222", "4.470483139096631e-158", "-3.759201891148757e-259", "2.3653874737066074e-76", "2.8830529807951074e-10", "-5.146431622819535e+94", "2.0974091900677366e+32", "794962.3315340307", "-1.4557530563778017e+86", "6.573083777058391e-79", "-2.110554818262233e+108", "1.8013231682512395e-104", "-1.0367309122059989e-116", "2.5860584425147992e+215", "-6.042151110654685e+71", "-1.752177713332363e-208", "5.760393952033056e+220", "2.5222681237107386e+212", "-1.3309237073309063e-72", "3.703736490280477e+106", "-1.2795158616720956e-38", "1.7093525213192542e+272", "2.491613609211535e+295", "-9.98136873816955e-125", "-1.0528653274194148e+268", "3.655996269040189e+247", "1.9018159081163685e-143", "1.9075318897336473e+45", "-2.8224374709385903e-162", "7.792935194134609e-168", "-4.1920718861814075e-87", "-6.064973916783694e+217", "2.680820916903086e+218", "-7.536858137696155e+204", "1.0394317272797321e+18", "1.0731713040735646e-100", "-5.79938820458842e-74", "-1.8853804497832012e-210", "-3.9135776612226407e+21", "1.899740311270747e-187", "-1.8674212559529887e+105", "-1.009349061610086e+233", "-1.353657789088205e-235", "3.1340398939867857e+301", "-4.74730263694463e-188", "-2.296105822832519e-61", "-1.0887915503944808e-44", "-1.6006969485763529e+159", "-2.3685010004158453e-59", "-1.021160598843233e-269", "-5.2215997094501106e-188", "74441009712.34715", "-2.94931527655658e+179", "-4.972835039479975e+158", "6.245194505271165e+196", "1.9815536240443628e+203", "-1.6255757533596758e-243", "1.3192896496567991e-61", "-4.703093658736285e+216", "3.337481552465097e-214", "1.4080572533789875e+48", "-3.490060484204821e-59", "-3.1876508756612875e-116", "-6.219145131418651e-130", "-36277775334.2003", "5.06672575986606e+42", "-1.2656821046909876e+67", "3.6001958262514756e+200", "3.7271960313072454e-261", "9.833722930246746e+160", "2.3607841569428694e+259", "-4.2319242331666455e+88", "-4.8439726026087866e+306", "-2.02891263844441e+227", "3.1154521604627165e-231", "5.489302857038976e+20", "-7.243175628111712e+53", "3.842430149782024e-269", "1.7999318598000284e-37", "-7.208445137026188e+173", "-3.1095664334811046e+103", "-3.813810034558351e+177", "1.4719910533084941e-68", "-1.4274133321480125e-170", "-2.544421908540499e-112", "5.688438404617359e+148", "-1.0830201350089802e-135", "1.5523548083315753e-109", "-1.5768284859594003e-42", "-3.3825312615964285e-184", "1.4424518066118661e-31", "1.408127276841787e+131", "-1.060073639684486e-110", "3.276253740389505e+168", "6.2891350901020216e+16", "-1.374198841563453e-48", "-5.948447534317687e-206", "-2.5974626237005894e+52", "4.3571746383911845e+154", "-6.457655660168773e-245", "-7.372813335816268e-114", "6.436502439799955e-87", "-1.1269872982882422e-37", "-3.5862257092054435e-237", "2.3628969592084e+91", "0.06740987435928587", "1.7109019539622252e+154", "1.683358574513183e+198", "-2.996515146841159e-240", "-5.152746272354629e+16", "4.709952824806217e-216", "5.324498834193018e+73", "-1.5345616535840838e-238", "2.7823557929877e+48", "1.5498919683000062e-135", "4.211346543319525e+298", "-1.6090857444048806e-51", "-6.223750610107143e-63", "3.5080896523358635e+306", "7.163348882165767e-129", "-4.174893886612018e-146", "-3.82307653534757e-226", "-4.284150386166341e+16", "-6.2014457128209305e-121", "248234803686.36533", "-2.5335089789676998e+294", "3.0532464545906432e-99", "1.0501061080598498e+161", "-2.1320452367764545e+117", "-1.034021309301777e-177", "5.094713777817597e-238", "-1.1697363689244834e-105", "-4.937293068302281e+198", "-1.400688913096611e-213", "4.4595477961545875e-125", "-1.0811896694553942e+147", "-2.39784799711107e+186", "-6.48185464842344e-128", "3.945702875196459e+69", "-2059138.871938811", "-1.0912024930222318e-200", "2.384190202468258e-115", "-3.745147104797943e-85", "3.663192423231551e-52", "1.4532940778403815e+188", "-2.3826780944782807e-254", "-3.4038126376652554e-302", "4.263101116617122e-184", "2.028776157755189e+294", "4.370367598946015e-264", "-5.3624572164329375e-238", "1.737903997696848e-195", "-1.7974387796947906e-294", "9.04478735720319e+290", "-4.078204122212573e-303", "-4.7145921591238024e-247", "-2.4091894423249807e-50", "1.0216032914608779e+37", "8.373191829470328e+65", "4.9173306707650047e-20", "1.360219021203034e+283", "1.4692651100960704e+64", "4.560264007691625e-66", "-1.1706908092883764e-84", "-1.8612423642819517e-53", "-2.763628430305861e-132", "-2.553268098415621e+42", "-1.0938453506416266e+185", "-2.5603458483115004e+68", "3.308279725209245e-134", "5.966464280070261e-183", "7.447120294446227e-177", "-6.505164761181447e-88", "2.910139361324232e-40", "-6.156852196958799e+212", "9.198814129801432e-282", "-2.722441300795866", "1.652638103182763e-246", "1.5084438955687612e-09", "-1.812538235055985e-182", "-3.3844265806808723e+136", "5.681821866207107e+303", "2.487600928350508e+187", "-2.2352457010923944e-283", "1.0363894332877902e+89", "-1.1350661526147813e+268", "-1.7403801944753825e+60", "-2.609744126897962e-244", "3.170160162217945e-280", "-4.971393110352089e+80", "2.7043356380405486e-287", "1192966.892997378", "3.6565879899130454e-138", "-1.2735834939907653e+290", "8.443818359624044e+73", "1.1347176554230404e+297", "5.2845416930532225e-235", "9.044400408257468e-296", "-4.567567435370209e+132", "3.1039370867894093e-106", "-2.4717230854468333e-16", "1.0990369817014954e-83", "5.2918697638183454e+42", "4.011150400002351e+151", "8.462899668201427e-13", "3.2122253551228707e-38", "2.8611672634584487e-202", "-1.6398302548805926e-35", "4.4911357074174944e-299", "1.0115535587969084e-157", "8.388856123135502e+90", "1.171827454229051e-88", "-5.73903430275235e-241", "-3.2915364214174953e-147", "3.6983647046581694e-187", "4.55827932365439e+81", "0.0827063034582742", "-6.185190446423377e-177", "-2.0943593772736167e+47", "-5.676041887157015e-24", "-6.758771396998125e-117", "1.5258269215579913e-99", "-1.899357328784277e+231", "7.340973388965417e+79", "9.220794195193838e-169", "7.0276650918032525e-12", "-7.171516617161082e-158", "-5.482069657082559e-96", "-0.5575181685538698", "4.5207851349408466e+278", "1.1570112901591818e-156", "-2.4556908456766193e+216", "-8.463363488171912e+135", "-5.834408687434387e+72", "1.805359266508733e-210", "4.4606190881734936e+287", "-1.0334760500518224e+140", "-1.331181306035288e+37", "-1.659436995053725e-149", "1.9261135399388465e-296", "-2.7638347493083913e-20", "-9.81773888532155e-285", "1.81037843972595e-54", "6.911382575217936e+159", "2.9620118208820084e+144", "1.5635378738821855e-244", "2.7435868551750995e+40", "7.78349048289711e-248", "-6.271149708461975e+90", "7.163911720466515e-121", "1.0555257396950992e+162", "-7.7408872778429e+42", "-1.9651444390229658e-249", "2.6613729159755726e+99", "7.124581339498853e-130", "-8.142536965335971e+218", "-4.7070823686873766e-214", "9.114194011383638e-231", "3.3763445574845255e-99", "1.085699441125814e-264", "1.4144529453631955e-75", "-3.417216440504929e-121", "-7.202761110198377e-155", "-2.145951336911212e+262", "-1.5240595379927886e+51", "3.640472558678247e+21", "-1.0278974979314805e-223", "1.0736106891112118e-126", "2.0077426167640305e-38", "-9.885883983828487e+73", "-1.1496991974821809e-182", "5.7801772999786785e+78", "1.5505809553722799e-137", "4.463925550570322e+84", "4.2647691408245276e-160", "-6.321612784872235e+278", "2.4972069719692624e-144", "-1.679607050323501e-236", "6.708415125042035e+251", "1.7187617419077284e+30", "-1.6037523155900158e+90", "-1.0752239772840466e-150", "1.2836786322433985e+131", "-5.958019195915173e+23", "-2.1901634708222643e-197", "-9.695135735659666e-269", "7.753922062491193e-197", "3.2231251240755515e-268", "883533818892.8124", "1.9256326600896182e-31", "2.001000094458155e-13", "1.7827731704535647e-127", "7.718929661431346e+136", "-4.083445147926143e-180", "-9.606722914609344e-213", "-4.691810176977463e+296", "9.204088863744833e+121", "-3.1149524111664496e-195", "3.176279408222725e-38", "-3.801889430612857e-202", "2.5508165251895177e+129", "6.641762491298755e+26", "-2.9906495231597905e+94", "-1.439229173749963e+167", "1.3750836989211073e-128", "-3.0925825774650876e+144", "-1.627344799129205e+246", "-1.2543154217364596e-183", "6.880399420043648e+198", "1.565308868686033e+42", "-5.261567914481258e+120", "-1.229481007300606e-147", "-1.9934979888496743e-177", "-1.5075861137993373e-56", "-4.664396401735941e-149", "-2.1725611724408345e-261", "3.506827971310892e-98", "1.6835133827897387e-167", "5.099734890960512e+39", "-9.17764211727854e+124", "9.317523225968377e+235", "2.9059747571558245e-33", "2.9977759168610276e-293", "1.7608117378895495e-17", "-1.5448168879774343e-160", "-3.4057081676170323e-245", "-4.001752582178786e-78", "-1762.8400532083392", "3.0769856086681463e-68", "9.886902599309426e-79", "2.8482150490302394e+301", "-4.387875716291119e+271", "-2.0524323577279125e-255", "-1.273004766154114e-27", "-3.115482653917655e+157", "-7.294257155112877e+76", "9.134417275701795e-238", "3.0204086065308862e+289", "1.1056258365423146e+261", "9.967059700188867e-226", "-6.434697373169295e+171", "-2.263488012995031e-98", "-1.2509729534752116e-282", "1.390328198186257e+224", "1.931980677450995e-252", "2.4990472740837562e-135", "1.4038593246919598e-157", "-3.040025230977717e-41", "5.525901404457264e-81", "-1.992344734071221e+103", "-2.728207596615014e-125", "5.3516006644359e-253", "-1.2324793933441368e+234", "7.409018533007525e-79", "8.991665772017654e+234", "6.4549674291666315e-201", "1.5041307207690947e-287", "-1.1494046906931495e+138", "-6.185804228602901e-276", "-4.028451765858015e+96", "9.146242267553677e+270", "1.5530050081719952e-234", "8.967997708239152e-133", "-2.894886552183887e+237", "9.953910088846401e+227", "-8.013513979348707e-85", "-1966814969508948.8", "-5.586721168211877e+280", "-3.5280202336912194e+203", "2.0449190186069992e-165", "6.883402056557514e+77", "5.222401381970974e-214", "-1.2184168423007162e-68", "7.77707046106781e-18", "2.6713465796951514e+42", "2.3310160945085274e+261", "-2.610292835730345e-217", "-1.2532102569058598e+304", "-2.5708344375048622e-52", "4.934076783936144e-223", "2.703228541313039e-279", "-7.807535872273378e-190", "-1.1206022600586294e-187", "122740577954474.88", "1.171961627364771e-306", "-3.471927611181253e-50", "-8.70520848573453e+80", "-2.7290726710001867e+77", "-8.162396602221133e-56", "-6.719058209690684e+120", "2.5196595821416438e-14", "-2.229299961783362e+95", "-1.1145880774456069e-117", "5.056264012105286e-37", "-8.464327693119741e+43", "-1.5508936578417692e-119", "-1.2682214266812384e+62", "-1.150182528525877e-64", "9.909035075438638e-89", "4.946369764450926e-82", "-7.122394151291642e-91", "3.182061023748387e-128", "-6.282325753732256e+190", "-1.7096054318148687e-306", "-5.3443821261736045e+171", "-1.787174134391393e+179", "-1.0362303258778153e-104", "4.640927598915944e+20", "2.0598406386384576e-13", "-1.2367755812673258e+155", "2.0083395107186282e+278", "-7.952263638543449e+39", "-1.1253896294081819e-146", "-4.052760634112688e-170", "-1.0849706111898586e-235", "-8.313052301132359e+75", "7.636360119896124e+85", "2.728084726224459e-106", "-8.045676487267365e+150", "1.1765953623645394e-214", "-1.9744197430802426e-137", "-4.654902362704997e+175", "-2.981390625001545e+175", "7.192209176393827e-137", "-1.675325907659281e+131", "3.1519884056947367e-307", "-4.724850928426683e+242", "-6.299543719406633e+80", "4.560327285411481e-135", "8.629840630924342e+239", "3.0342734665477313e+46", "-5.8084418483462164e-210", "1.4386091390747649e-44", "-3.163450881485098e-258", "9.613098852890047e-286", "-1.0230300932015128e-120", "-1.6116041773152301e+131", "-6.2861980768154365e+268", "-1.6035286025354563e-262", "-7.849310476648658e+87", "-4.035632016988273e+227", "4.132532149124146e-304", "3.886350712225981e+218", "9.486801270495438e+238", "5.264575195870783e+158", "7.507247857462842e-207", "3.637716696353407e-157", "1.5133249109980086e+160", "-1.0616162588819034e-44", "4.04656095407424e+81", "1.7646675298205385e+292", "-1.6127587099755326e+94", "1.0954421596532728e-303", "9.542026202769258e-202", "-1.4163838312532802e-222", "-1.6698308285317812e+131", "3.6205357329536933e+43", "8.189271555177636e-86", "-1.1398166400746512e+178", "-4.8750750508355546e-132", "-7.06007491181825e+42", "-3.778230293401079e-25", "-5.498034669324505e+257", "1.8286486294549157e-305", "6.441556920427089e-112", "2.8742425315139026e+192", "-1.4324939969095225e-43", "1.9145222778018557e+41", "-7.029091310578847e+109", "6.022400767145555e-58", "2.0838471032844507e-104", "-1.849399478889576e-39", "1.668206477857728e-243", "1.9696158304412296e-69", "1.2489546133719639e-176", "3.112251762125691e-18", "-5.702978331410094e-304", "-7.19571237126631e+29", "-1.2851320442484549e+69", "-2.1541986319253566e+173", "-5.862688064625562e+89", "-6.174703187035427e+170", "1.628336297628792e-49", "-1.7545280717396323e+292", "-0.00010184148502717293", "-7.681053032987261e-165", "3.511231917071676e-216", "-6.756231174093372e-17", "-6.23094192185685e-50", "6.135026336987114e-86", "2.2145387071352595e-91", "3.2531445803612754e-60", "-2.324017444097027e-136", "-6.42683935560308e-72", "-7.525034122446866e-109", "1.0665182818276173e+257", "7.788653075610813e+154", "3.756344654243508e+158", "-2.830505433211422e+169", "1.3987549153325782e+134", "-1.1339187903875084e-209", "-1.194833263428507e-124", "1.6997448259007563e+288", "1.1860743369252952e+91", "-2.0748731969871218e+86", "-1.3635694342527612e-64", "8.418823163374838e+104", "-1.587447001148404e-28", "1.833541092608486e+233", "2.239175872872042e-229", "-2.6398250194729954e-102", "1.0961627808721158e+138", "1.337525148616985e+186", "3.1487769641702143e-114", "3.4956404686822682e+131", "7.915873362265082e-154", "-8.882315425286234e-199", "-2.205747506348879e+63", "1.9799505827397724e-47", "-1.0403570324359556e+63", "-2.0925795289228713e-281", "-4.263759209027225e+110", "-3.4315164647385276e-94", "2.9615604792307423e-174", "5.420287540761063e+118", "9.465584792836205e-242", "1.6226620621829333e+141", "-5.529813326909942e+222", "3.4989576035775787e-203", "-1.9765372998839385e+242", "2.837950782068904e+198", "3.998966701288893e+201", "1.678057527436698e-183", "-2.4868076873945893e+203", "5.722389285018067e+127", "-3.7486151688655603e+276", "6.434910735458532e+43", "5.842263298815135e-98", "-1.7187904193945127e-291", "-1.3059250190935251e-105", "-1.8929160191271392e-284", "-9.775289512391505e-209", "1.4359439038003123e-108", "-2.155586768440891e-153", "4.7169844007209914e-164", "1.6922448558901923e-09", "9.464619392302086e-248", "1.2415380602181664e+57", "-8.64102555394244e-85", "4.450801456818112e+208", "9.005467312953538e+69", "8.695501030304939e+209", "1.2528065354174474e+293", "5.314287173651594e-119", "-9.993128715304087e+76", "-6.3125788114684514e-27", "-1.252020724652594e-67", "-1.0154547133142019e+246", "6.14304667593225e+90", "-1.2212586032381058e-62", "4.4160573640246175e+204", "1.7176733194634373e+193", "-5.777642848419713e-48", "-2.8150295267299974e-39", "-1.7842574650760326e+188", "2.2513801170262527e+107", "2.4273651702283487e-38", "4.885224668466515e+157", "1.7134506900590747e+191", "-4.43612253925439e-13", "3.620364545562114e-147", "-7.57883366974975e+274", "3.590520408055103e-289", "8.832898721060584e+213", "3.6411582923119026", "2.7019311094018975e+30", "-3321866181.9615026", "-5.060417478341632e-126", "3.6587554579841e+47", "-4.985479799601098e+256", "-7.701364889991546e+16", "-3.242389772754842e+276", "-4.79966493694534e-306", "-1.5850710560501139e+292", "-1.8166395395084102e+77", "-1.9993777911503507e+62", "9.266629886010341e-228", "1.1396566637470664e-06", "1.7193900379568086e-300", "-4.755302992141262e-201", "1.1773089825028737e-195", "-2.1871723034614974e-178", "-8.36973224256645e+96", "-3.148419715778945e+171", "1.7940632889202765e-261", "1.957534144255629e-223", "-8.735999802070046e-179", "7.668825574892051e+288", "-2.0026895348543268e-230", "3.0785014464723996e+256", "5.7040264485955985e-154", "-2.8715589316225894e+198", "1.7596475348290984e+16", "-1.1461616978535236e+293", "-2.7735697207971954e-49", "1.3015806533343915e-08", "1.1614723414505715e+162", "-1.0572547304192772e+189", "5.742681569840161e-74", "4.008198037842215e-261", "-6.057721998657489e+43", "-6.014909922254359e-46", "5.871473416394017e-285", "2.5390995106238744e-209", "-7955959289.425995", "2.561015846168519e-146", "2.2271124012083807e+23", "-3.3170349575039486e+273", "5.85725504579382e+237", "-1.129339474800955e-86", "7.513934422787617e+22", "2.1210504909877787e-275", "5.211102566153707e+245", "-1.610689259945085e+168", "-4.264244420334021e-251", "6.70377891505233e-16", "2.707992941440552e+275", "7.403079237279941e-10", "-9.310679750511798e-27", "6.575120939415851e+277", "4.867821664547827e-188", "2.7005486664827797e+160", "-2.890996421192733e-157", "1.7062120888446267e-168", "2.6072568578726115e+151", "-6.905582629988586e-182", "1.0710619509381747e+99", "3.597010478971181e-73", "2.3094543941657074e+149", "-8.133981618583981e-229", "-1.1388415506041533e+306", "-5.860090280229635e-75", "-1.2023219591526885e+286", "-3.9665992234282234e+293", "-7.272597193776235e+262", "-1.1400742402555101e+257", "2.992947548877704e-106", "1.9442622084138004e+73", "4.72226567636601e+57", "2.8197559838493083e-249", "1.3761326678090372e+130", "6.941989723925283e-184", "-5.611031947067689e+155", "-1.816799611038644e+268", "3.1251700677337783e-194", "-1.7855928647090226e+40", "1.5217652277735015e-102", "1.0619570782001912e-107", "-2.2859626571555706e+210", "-1.1498785973924479e+117", "-0.11706998653618392", "1.0624890004542627e+226", "2.2161706184435852e-259", "6.4170591339564395e+296", "-5.64755570810427e+267", "8.751108916601151e+304", "-1.8196796501905155e+280", "3.3360021551874866e-306", "4.2915208550698377e+229", "30.349460734859644", "-1.1728073117626773e+208", "-5.292717854674494e+287", "-3.63301688131607e-310", "9.403338476884707e+100", "-1.1617578670216196e+54", "1.395439477658714e+278", "-4.311549269166724e+82", "2.1191353380904723e-70", "8.361834099367606e-53", "-5.811859244976659e-82", "2.317906324173191e-47", "4.4609894331440204e-93", "4.2876186184732624e-161", "-2.0157281745432652e+108", "-1.1533899586623323e-181", "2.7962930538135523e+118", "-1.493030404716952e-170", "2.0416155534189632e-193", "1.6678993070267582e+271", "1.9803094717632757e-258", "-8.63273155311637e-15", "-2.067834625682822e+304", "-2.341451192995854e+21", "-7.127367274012081e+143", "-5.8371820747201466e+302", "3.4504370994065025e+98", "-1.2756645531198114e-277", "3.6562013230165945e+304", "5.22570996359692e+190", "-3.3374069910911514e-46", "-9.92562945008577e-145", "1.8960237773483849e-208", "-1.5243252372939637e-186", "-4.182986640144629e-275", "-1.3135911258474984e-222", "-4.653875349583523e-180", "-2.321573719870015e-121", "1.2087878085173211e-171", "1.6263204824617785e+108", "-3.1483749383797e-23", "-4.9572921091407046e+213", "-1.7859288357131493e-121", "1.0388600696255445e+181", "-2.7562540855726907e-230", "3.969651554447528e-49", "6.800692961735928e+274", "-3.449262244092951e+53", "6.319278504703119e-225", "-5.39311715204985e-217", "3.508923965544971e+194", "3.841306638807927e+146", "-7.768635828699118e-56", "-2.982580326217174e+299", "-2.9374236936597817e-286", "-9.720066790160069e-176", "6.594398682364092e-42", "1.233930164711562e-22", "4.974873000504845e-174", "-3.725202373234561e+282", "7.869754309944778e+277", "1.515841652512079e-83", "3.93253663281726e-108", "2.7983816108031994e+69", "5.167162770926456e+254", "9.139319289368726e+288", "-4.8077328391598904e-244", "-2.6409223518959465e-302", "-4.854595464842601e+97", "1.4818154802918214e-144", "9.042442567026211e+74", "2.4061272689970614e-279", "1.6865995487230853e-136", "6.922439386718137e-212", "5.154229363437701e-302", "8.635701744644157e+35", "3.124717658219398e-236", "4.767509873225157e+118", "158785360146922.12", "8.793197175528269e+38", "1.960388739594937e-99", "7.803326497401077e+293", "-1.0844685996492315e+117", "-1.0031777119147633e-296", "-1.5672861481675766e-294", "2.195514983421014e+111", "8.724069403799893e+65", "2.0966279546332325e-52", "1.2645292456434176", "-6.244130772189837e+248", "-2.9824515703744655e+74", "-1.09565914824183e+107", "-1.0665378169239252e-67", "-5.660906571772661e+62", "4.8211261131336193e-88", "-5.144647640706661e-216", "1.4418542809246224e+169", "7.373738108957217e+19", "356843010.67542803", "-3.9820476989648176e+95", "-1.3775368041257266e-307", "2.0915914833884214e-224", "1.7156974043462884e-112", "-1.4075254657480695e-257", "3.1158094063784675e-279", "4.1633360252053234e-94", "1.2062572242161147e+291", "-9.326970031471752e+88", "-4.554271147701083e-243", "-8.701739279658123e+67", "3.2981707392459403e+302", "8.821634942719508e-47", "9.305300039227266e-217", "-6.76666857746857e+43", "-1.3487037393325037e-148", "8.64580792538933e-78", "1.6239774575549967e+112", "-1.622278029581239e+48", "-1.1079466967763934e-189", "-1.4765285465531172e+206", "-6.333564589479264e-185", "9.563007133393623e+58", "-1.0498057458232854e+297", "-1.336159489191146e-76", "5.25698225978819e-241", "-2.0393863889023474e+68", "0.03425330834194862", "-1.2969169193909616e-295", "-1.446329270389468e+291", "7.29135919882642e-291", "1.4385825101957157e+295", "1.8546642054909787e+38", "5.391284574431978e+180", "1.777669889422382e-06", "1.5579372189721432e-205", "8.212040657388435e+44", "-8.547158356050879e+172", "8.089900910095186e-208", "-1.7128707345734522e-161", "-9.295906164951845e+76", "-2.4065993347943733e-218", "-5.3098030030058746e-33", "1.2231667249919268e-223", "1.0273254660265244e-209", "2.9878784715298737e+183", "2.2119645507710618e-45", "-1.7078113849838424e-179", "4.4761119087199545e-41", "-1.026822582265791e-51", "2.0182392580782962e-305", "-5.7355443233289765e-92", "1.3843347506537513e-306", "9.120652967351089e-274", "-3710102.0420926153", "-1.2387607694805566e-161", "4.451931290598094e-294", "2.478055204559617e+138", "-4.218870962892643e+127", "-1.6101637859394576e-228", "1.1404113781888689e+218", "2.997093732313644e-95", "-5.88864695276582e-228", "-1.1444779070468424e-60", "2.337543358410082e+36", "3.4724592331472763e-112", "-4.455953170845219e+259", "-5.915747802691874e-50", "-1.7870060357492692e-127", "3.7495347087016674e-216", "2.398964489284561e+244", "4.687485742530694e+151", "-3.818056424274169e+255", "6.1917995505766204e-201", "-1.0668480152054157e-92", "2.0601099067776895e+303", "-1.7319595549695635e+106", "-1.4225865591721453e-195", "-2.2046922275585597e-139", "-8.277662797420857e+85", "-3.742530460286171e+28", "1.1326893648340628e+239", "6.6150646483859605e-217", "1.3343662777790626e+288", "8.798243579274677e+167", "-5.732179945902958e-43", "2.2513286112728506e-125", "-29937988.251868866", "5.3767618542284946e+181", "5.39988768308073e+75", "4.0607520162861637e+43", "4.696658938074314e-250", "-2.9566819825033726e+189", "2.8596853127463356e+127", "-8.764809493764604e-158", "-2.1143986516926664e-278", "8.096960286780975e+124", "9.07000585404315e-33", "-9.374475669206045e-253", "-2.3580018931019466e+262", "-1.791984516220191e+133", "-7.326713501027994e+295", "6.123569616788165e+134", "-6.947359239475682e+74", "-2.2216099772257624e-132", "4.76725649654054e+166", "-3.7704102998492926e+196", "-3.188663748387647e+96", "6.428231031879631e+289", "-6.646534886949438e+300", "2561913.846523808", "-5.65247719158007e-256", "2.7709249582272917", "-1.304389411122834e+228", "1.7465426599957883e-84", "9.725656274019105e-133", "-1.0472677408246483e+268", "3.95585000945057e+221", "-4.046546844700453e-66", "2.124757251779431e+122", "-1.7000924540707647e+34", "8.05850484437268e-133", "5.4347722636033634e-27", "-3.8787039639765466e+290", "-2.1526944402037857e-235", "-2.6756240806570455e-282", "-4.8482994497040425e+81", "-2.544137937432168e-245", "4.7255160121457266e-116", "-7.704279576015957e-166", "-1.4663445620930113e-15", "2.2391452634076504e+260", "1.9366180326180361e+261", "-1.1256370412824193e-106", "-7.010016046747595e-160", "-2.661043126179464e+129", "-4.9300927332983985e+153", "-1.4484749699483919e-239", "-3.530960095244201e+113", "1.9121352765112713e-231", "-4.456647329029835e-48", "-2.069481517769665e-80", "-7.111567473506277e-210", "-5.427461162357795e-67", "-4.765717097439749e-234", "3.3576591942462735e+27", "-4.2591565971645085e+161", "-1.2898869954083086e-112", "-5.156815406023484e-155", "1.4354828038876841e+63", "1.429436957016636e-141", "-9.83957711382904e-239", "1.9152827812156476e-303", "2.4973723802733406e+290", "-4.166020340259839e+67", "-2.0938905381611317e-95", "-1.326550379995876e-155", "2.66305860757129e-246", "1.1290490820422836e+70", "-8.04900778651757e-41", "-1.3756536567710955e+258", "-2.7792926796108347e-223", "-2.38216756488058e-30", "-3.157841737448028e-147", "5.287750583464772e+234", "6.806219255557246e-186", "-7.88659031455007e-218", "-3.1944683571031843e+77", "2.976374843510833e+29", "4.825617515469041e-189", "-1.894067473969865e-300", "1.3744250583824087e+171", "4.978457088898896e+198", "-1.6107886047090968e-140", "-3.9372669728684094e+146", "-1.5863662045789732e+114", "1.0548336201258901e+75", "5.09707113609417e+212", "4.050436685854614e-97", "1.4072689011099548e+178", "1.11241573245384e-228", "4.875128850633924e-145", "5.289002224530479e-296", "3.6029378782364264e+278", "9.783754966866418e+25", "4.183898016383526e-98", "-3.0747086803498406e-189", "1.7195121763100057e-238", "-5.032061384678867e+289", "-2.762637028460769e+218", "-4.189332956948961e+115", "1.4850620282067752e-302", "-3.344306319148246e+132", "5.794648718251642e-239", "7.288795900757225e+109", "-1.6234657531767166e+261", "1.3301997363072444e+34", "-2.280579446780759e-200", "-5.205565586465544e-118", "-4.393593196456699e+173", "-5.6099730636787497e-132", "2.2106554701714084e-30", "1.2104414332251496e-173", "2.5072477315066532e+277", "2.049283094809551e+220", "1.0912080192200739e+60", "2.430059364633782e-90", "7.61788157634335e+298", "-8.35568848786678e+300", "4.8274065713052306e-287", "-6.0200362076515105e-83", "5.32050141818275e-215", "-4.218285787796453e+127", "4.853596294855291e+73", "6.62681226628946e+174", "-8.19334507811349e-247", "-1.393425169290406e-29", "-4.38523350384821e-61", "3.1231946758647574e+193", "-3.396241304762576e+190", "-7.876068746902791e-09", "5.8601406494749916e-214", "-4.087877717892097e+172", "1.3875999288921682e-58", "-9.833475962301193e-68", "1.1672767615510698e+267", "1.6752702038643264e-25", "-1.1917345627219453e-14", "2.764394872962802e-255", "-2.991440267559939e+20", "1.001645966825233e-210", "3.6455155861073292e-90", "-4.927207442328057e-18", "35218.42477755018", "-4.0132058524929574e-230", "-2.4928040914028665e+86", "1.869301433811679e-97", "8.619741851049844e-273", "-1.3707881922511348e+244", "-3.8522153844073673e-190", "8.916398377106232e+278", "4.935395151080572e+220", "-3.1570435862063492e+96", "-2.768981780002836e-49", "-8.660636944347099e-166", "-1.8081530018527217e+55", "-1.942566640515398e+148", "2.925246299329219e-223", "-4.228090996060668e+39", "3.610203876283364e-205", "-2.6506495101993646e+22", "1.4918545487657034e+227", "-2.1769516444493233e+191", "-2.3829470710005206e+52", "-4.1842279556532123e-187", "1.5024799581693718e-109", "2.1805605678556284e-275", "-7.3102727704742e-136", "7.0013852904318436e-276", "3.421631829287972e-128", "1.2296114132875204e+36", "3.521966075665348e-116", "-1.6133450480251493e+26", "8.361943778539856e+151", "-1.5241323450791188e-92", "-4.675540509171774e-09", "-6.244455049051882e+29", "7.262147307963172e-106", "-1.6130626901159703e+82", "1.4767541911549883e+37", "-8907610124.84597", "9.546949199260965e-224", "5.99332903907705e-13", "2.2916755003523567e+296", "1.449516523708685e+154", "-1.4309016588582917e-05", "2.950850469879376e+107", "7.962098651281268e+251", "-2.703984441078085e+255", "3.8065326582043977e+177", "-7.865625260784424e+141", "-2.1769753683884703e-168", "1.3653184632621502e+36", "-4.110416273996952e+253", "-1.3838067367933185e-157", "3.814260945146868e+302", "2.2466566682415136e+173", "-2.9791364477628434e+200", "1.0033878889240339e+239", "-1.1901695126901688e-279", "-4.765716341018172e-273", "-472.9812675668828", "4.496931704783036e+153", "-1.3731096766337644e+58", "-2.6495794455640773e-251", "1.8826771593954858e-238", "1.6368618553811148e+165", "1.00783005998958e+96", "4.387103886622394e-291", "-4.6020597542398495e+82", "-1.3385741234161143e-169", "-1.491988762029419e-268", "-3.285270554552587e-59", "2.244692590277446e-20", "1.12483828755206e-22", "8.57701667070511e+76", "6.496198477200015e+274", "-3.44982996425033e-137", "4.0186486185827186e-94", "2.5728391677414755e-259", "5.891200167739276e+193", "-4.2288804014007844e+291", "1.8669270950966222e-16", "5.281732963160316e-235", "1.0025805758793415e+251", "-1.7294682503871413e-176", "-1.8266368283867576e+51", "-3.535828078643232e-276", "-2.1673061062520776e+150", "-5.2585981536266367e+241", "-8.362000205126928e-231", "1.1878161959975583e-281", "-6.379245403135894e-281", "-1.2301227946720228e-101", "2.9451455315293966e+131", "9.963956168409258e+107", "-1.9075374647493893e-86", "2.573775791301639e-85", "-6.238413829639849e-301", "1.2343173925035148e+272", "-5.692769178095137e-182", "1.4336526229344755e+125", "-3.921343444614098e-55", "6.981553512899266e+171", "7.034092830149843e+176", "7.677376115288455e+136", "4.5112603085941864e+283", "2.0571096131661244e-306", "-3.1157164876297e+222", "-3.520897059411674e-302", "1.5805115821453594e+109", "-2.2538916240080108e+18", "-6.940458951908824e+140", "4.292717173607855e-279", "3.70557245743488e+162", "8.905857550745994e+40", "-7.87257861681206e-230", "7.786313935683341e+27", "1.519798195765009e+305", "1.0075935508936516e+93", "1.0258386592872801e-248", "2.0402021107339655e-83", "-3.133504346093607e+122", "-1.3693644552762574e-164", "-1.4192839941248855e+168", "6544455101.540977", "-7.210628539098176e-102", "-8.372547386736117e+121", "4.686800465663382e-198", "-4.8733250411791664e+70", "1.1676844252188921e-198", "1.8125622364294713e-65", "1.8646730284562018e-199", "-5.406470825006807e+273", "1.5158829265055035e-51", "-1.077781866694607e+182", "2.1926907296816578e-207", "-2.1575447746831711e-228", "-5.6852231264689646e-239", "-3.1003468454241637e+72", "9.122239387317256e-132", "5.252531524657897e-266", "-1.1747846319484904e+246", "1.2612095786483907e-154", "6.234583866436164e+130", "-4.3432512520532384e+75", "-1.256107901688657e+72", "4.784321922254893e+301", "-2.6907311867373726e+104", "-6.2298057166987e-44", "-1.3960389906542956e+163", "3.824764110815054e+291", "-2.248260731734916e+241", "-5.7812332197185655e+225", "7.894881698390186e+205", "4.6217943512253786e+35", "-1.680905053732605e+25", "9.482995343022977e-65", "-3.1077866052188647e-175", "-1.4043040140303797e-219", "-1.7929727356307783e-150", "-7.057076715672176e+22", "1.0771555098235523e+275", "-1.0927680162641356e-28", "-1.7071212083584402e+123", "-4.63874053674987e-239", "-1.633672564354221e+90", "1.2741709680351108e-169", "5.875319788584456e-239", "-8.24596231802249e+195", "-1.392045216961711e-28", "-1.1565156163810593e-196", "-2.6588361928613498e-127", "2.6381647005092098e+182", "-2.904298560534946e-63", "9.8175646514443e+267", "-7.530808583365164e+202", "-7.060756451479842e-23", "-4.332384929304447e+218", "2.8079921624800935e-167", "2.602676881787562e-228", "-1.47930325267929e+113", "24271471093516.496", "-8.626421596406151e-171", "-0.9772393181627352", "-2.2013472190364135e-129", "1.2667357324216038e+62", "5.672646857974136e-119", "-7.010363827738183e-107", "-3.4062121044101654e+119", "2.7779587288074258e-204", "2.3090019223367137e-118", "3.192222997887595e+51", "1.765497861552055e-110", "8.839310257086004e+247", "2.2138824518382353e+51", "-6.7876040292989204e-279", "-4.381127603823416e-24", "-1.1014024095669205e-76", "-2.013199512390383e-13", "-2.9819319707853144e+246", "-5.17055760547398e+61", "-8.273213624223476e-296", "1.0169958832823147e+117", "1.301995785398047e-136", "3.6789964683950364e+255", "8.812415244362903e-265", "-2.77971339240393e+18", "2.2284994772922788e+268", "5.212297192801647e+256", "9.364946460507562e+182", "-1.3774165024310216e-45", "3.93025051450539e+125", "-1.3731198397727091e-83", "-1.4839291779283624e-297", "-3.9047199247798997e+154", "1.5200376122847208e+177", "3.675387534813385e+42", "1.3805309279651738e+225", "-6.27777767095404e-88", "-9.753178924148599e+228", "-5.2167331976696536e+221", "-1.0816314881188943e-118", "-5.155060657561226e-150", "-5.506559862070127e+181", "3.109469433917279e-183", "6.365983503637546e+259", "-5.089749905485504e+275", "1.6969027685477936e-117", "-1.7864158313797413e-175", "3.821283299347321e+250", "7.970990246372536e-10", "-4.348831884040959e-219", "5.690325571311363e+189", "3.4657073613832813e-245", "1.3298194330509297e-149", "-2.8750182964794925e-55", "2.3872550971064216e+285", "3.353052371366306e+307", "-1.2561125826212927e+116", "-6.139058059593604e-115", "6.018777017310352e-295", "1.028184180204029e-303", "3.6882214524421356e-201", "-2.292667502104658e+97", "1.865925701406143e+183", "-1.6143621395570985e-47", "-5.736120938460089e-82", "9.871085523140255e+215", "-1.48141521501794e-123", "-6.001490907930579e-117", "-1.0920340192181694e-51", "-23015498060.890675", "8.026008294827451e+303", "-2.4031082758623193e+25", "1.6469075161804483", "-1.8186207112925806e+48", "-1.114228457554027e+59", "1.9044968468126052e-153", "-4.1475172910194085e+254", "4.4880468257141624e+218", "3.118378055366856e-104", "4.039420583812888e-77", "-2.760844232071967e+210", "-3.253956921588759e-30", "2.338794999436141e-156", "3.224403622074583e-281", "-4.581081362279708e-261", "-1.0855612703700434e-259", "-7.401082921309717e-66", "-1.2382752394927808e+45", "5.164766298459056e+183", "8.162885718863995e-254", "-2.031912655320431e+122", "7.824310768247333e+115", "3.246175218796928e-149", "-921.5612338899252", "1.2677324447807565e+70", "-2.18877415111127e-155", "-1.4015327260976415e-166", "4.041802458764787e-188", "0.0005122269514185008", "7.724531912727063e+25", "8.992420597487104e-113", "1.881812751856734e+209", "5.7222308495165775e-25", "-8.401978941432391e+56", "3.925414855522232e-260", "-1.9125446125289918e-174", "-2.914011460653611e-66", "-1.8642383061017842e-45", "-4.7221181094647e-166", "6.493806415558641e-244", "7.368140667142463e+45", "1.140034090768332e-230", "-6.189779708153754e+63", "6.254519718469697e-229", "-4.170185570490988e+82", "-8.656932917417305e-173", "1.122005089103415e-220", "5.2193265296725395e-121", "2.29398490529423e-271", "-1.0365766379143913e+282", "-2.1783611639993387e+243", "-1.1644208081679203e-172", "-8.844043854841527e+179", "-1.6101670951741683e+122", "2.3094252203121964e+34", "-1.7127926186992498e-262", "6.573936429663084e+18", "2.170226143519706e-172", "3.92298129293699e+58", "13108626638450.705", "1.716160739828759e-277", "2.0499708266843936e+197", "-3.027406191846548e+306", "1.1098833034584994e+36", "-6.517535246055019e+262", "-1.6501998255951036e-70", "-1.233797887841808e-65", "-1.4300218960227536e+87", "1.9046975280398326e+290", "2.1701062206558098e+158", "9.063418269310703e+205", "-1.0824594107167243e+231", "6.571430244552023e+59", "-335513666217.4889", "8.646915015634457e-280", "-8.563222379001789e+178", "-1.794154812483372e-91", "7.707180878192233e+270", "1.5597527307541046e-123", "4.655454766430505e-248", "-9.436125449893318e-201", "-2.14940676409856e-244", "4.9558911116962355e-36", "4.401146782502042e+117", "-3.0142086216683574e-307", "-6.091562021869881e+299", "1.4402173419528456e-21", "-1.3894932753511367e-161", "9.251035622827998e+282", "1.0036260929773408e+83", "-1.5939376240603515e-67", "3.4957859910697365e+77", "1.294595660657164e-23", "-1.1130712928576172e+22", "8.148275540731368e+104", "-1.1575270891167317e-145", "1.0790890358937816e+206", "-1.3728566713380662e-279", "8.693430294220364e+156", "-1.1812302267200623e-124", "-1.5594638794490445e+229", "-1.6273871218091557e+170", "-1.1912798809236301e+24", "-1.2894314475075608e-187", "-1.36571981267958e+265", "3.995861299290695e-192", "2.5913224726290463e+74", "-1.4567037040166852e+48", "1.358205195524298e+163", "1.3897978992698077e+262", "1.7219552395253831e-258", "-1.7916813893496736e+91", "-6.155820839380357e-222", "5.282456519078965e-07", "-1.4917337880011064e+100", "1.128880741758187e-55", "2.6945430974638775e+183", "-5.973534837042927e-57", "-1.403306130354691e+110", "-4.95252666431427e-133", "-5.7679615950100377e-216", "-1.1419153147137109e-151", "2.9453965025431745e-295", "-4.9892534037427505e+97", "9.39586437836745e-188", "3.097780594965549e-101", "1.7141804219985304e-201", "-3.221094923340855e-293", "5.189213134062159e+33", "-3.560191921748027e+212", "1761629.3705971073", "-1.4392375645947158e-214", "2.6535215041096448e-235", "-0.00030727687861109744", "-2.0782086892164406e-123", "-2.1278156465118912e+27", "-3.158501396983685e+39", "-6.169313114425369e-182", "1.0260486903208365e+63", "5.22907099648301e-156", "7.833850485501133e+99", "-1.5637383380480364e-63", "-1.9730389538596696e+241", "-8.83041721694554e-240", "3.4976437281635597e+62", "3.813491736653581e+294", "-0.000155615198426195", "-8.360351186482461e-266", "-2.3378240011585412e-110", "-2.3294108828757536e+163", "1.0998368147701563e+105", "1.6560634123390188e-121", "-1.55083346788559e+248", "-7.63033248182819e+173", "1.0538860425493217e+295", "2.4080817303423184e-229", "-7.702174620372832e-137", "3.4172799626243988e-134", "1.4034025496654954e+79", "-1.9550960843228218e+61", "-9.79886400446055e-189", "4.0154822865057817e-144", "-8.866723796773164e-237", "4.6594561575523055e+292", "-4.317705367648539e+183", "-7.131758570780957e+71", "9.627260525488469e-144", "2.4866243619619216e-298", "2.550698390721555e+199", "9.925678311188333e+197", "2.6806097262954543e+54", "7.667908882968618e+276", "-2.0707948947470535e-90", "4.49122062786164e+64", "3.1184118234218743e+66", "1.5223982274095396e-258", "6.714700033075527e+212", "9.349315853723524e-76", "-7.192083897811638e-220", "-3.858497892905158e+284", "2.8054196376517718e+35", "1.4230242766221639e-156", "7.935216117207862e-220", "7.945696471021259e-153", "-1.6454045489094276e+213", "3.771235574585286e-118", "-2.4678758840821116e+191", "-4.1297227244022363e+288", "-1.2496306271490306e+152", "1.1409808528965591e+191", "4.884778016112979e+52", "2.19280490846226e-56", "7.422417455116837e-303", "5.758275842966451e-09", "5.821072027972382e+39", "-1.0999229824837658e+260", "4.863368486409568e+49", "4.301647629298574e+105", "1.3161671986847847e-211", "7.36058240737574e-31", "5.510123118605942e-169", "4.756671521296276e+295", "-4.0653690148332306e-134", "1081114809656.8828", "4.610313575750953e-158", "-2.7383879073184485e+58", "-1.3770038911825756e-49", "1.1632549856467694e+113", "-1.1687120949845869e-231", "3.5916085822500357e+285", "-6.545380621427778e-24", "1.0611842803117436e+104", "-6.128313466240958e+215", "1.559479286808187e+27", "-3.598181898043444e+159", "-8.361127402253042e-145", "1.219742145313271e-269", "8.087790621944003e+220", "1.0625648611797414e-167", "-6.510412576284573e-263", "3.411451649708572e+142", "8.208744362308981e+229", "6.915963713705395e-129", "-4.523894843942371e+97", "1.4284473312891481e+52", "1.109849481147424e+117", "-3.72879909270959e+160", "-3.600058600942635e-308", "-1.3180199815178101e+200", "-3.445532684860246e-255", "-4.127495228848239e-181", "7.986798318343194e+139", "2.1055454189348184e+219", "9.321341020107025e-65", "3.010861698659798e-249", "2.6087624399754525e+119", "-1.1299009067072913e+272", "-3.5658712990193185e+169", "1.3056939749504936e-270", "1.459526866927054e-291", "-1.2558641857554039e-170", "-2.4712085444380396e+21", "6.838043379498797e-25", "-1.3600314132927212e-199", "2.772569345387612e-267", "1.1739367133138608e+135", "1.2409892881761834e-268", "9.566800456729913e-145", "-1.0356068427827855e-194", "-3.4504981338045335e+233", "-2.258590346625285e-69", "-2.344946824865525e+287", "6.302062516989513e-186", "-9.014083326814475e-182", "-1.3009888447484077e-199", "-7.466246270817203e-140", "7.291993075473886e-178", "-2.5254867203412105e-179", "7.093411874652563e+19", "-4.7716838242449686e-213", "1.059619114593705e+155", "-3.850814130145039e+134", "-3.9148626372427156e-250", "1.0504233354136924e-153", "8.385296219140415e+57", "-4.0118957394335415e-227", "-2.1146198979973165e-11", "8.279717209291411e-151", "-2.1327631275213705e+116", "-2.1305659730752695e+70", "2.4330848384054283e-75", "-3.3610018874814826e-117", "-2.2190694127845278e-199", "-3.8653446424557446e-207", "-3.041052690874018e-94", "6.960597510783456e-52", "2.18564310563982e+133", "-6.624010338211937e-221", "-8.412798369641588e-131", "-1.5871992737468318e+249", "-2.484374770553487e+247", "1.9666143969898185e+38", "-1.924250617543729e-122", "-8.619828385300058e+162", "6.358883279240738e+40", "6.1175849131928705e+283", "-4.831263127296313e+284", "-1.752470080935402e-207", "-4.753272129598789e+49", "2.8517618390900547e-216", "-6.226269713864273e-237", "-6.06797818302644e+148", "-1.2559526521017356e+100", "4.6255348397321025e-86", "2.582393717193509e-224", "-1.635115514725567e+291", "-1.610612621369635e+201", "3.1963564039755354e-127", "-2.7667808398336174e+37", "-3.0870832085670903e+47", "3.101355190957169e-194", "2.6755408683770503e+271", "1.778281154835463e-216", "4.131647762520588e-69", "4.0371296146768117e+117", "-3.699574181170396e-231", "2.197106692378921e-37", "6.633884061813654e-188", "-2.960021909394316e-144", "1.5811651649243907e-75", "2.1839282568981853e-201", "-1.1427495792984148e-272", "5.294536015332547e+113", "1.432198332067215e+253", "-1.8668262404800438e-261", "-4.663003826849973e+140", "-7.851651181177258e+182", "-2.2831444712946306e-37", "-2.5354655201452016e+101", "1.1913097352710654e-153", "7.940876222043976e-188", "4.2220657639593474e+303", "-1.158154021414106e+128", "4.466803000771922e+185", "-1.5188099172886747e-140", "-3.888682995755642e-63", "-3.122418008786911e+16", "2.7527889924652724e-63", "-3.9437069543715274e-71", "1.1753076015584673e+219", "-8.77799468990247e-123", "1.2484548250235299e+134", "2.3657504473690588e-235", "-2.28595590360909e-175", "-1.5540202893862732e+273", "2.482083695254154e+36", "1.5813272414861788e+60", "-4.0973836561887393e-196", "-3.3821276263521426e-296", "-8.604109602426314e-274", "-4.850850880062269e+61", "2.3558446414853065e-45", "-1.0528070567903317e-117", "-9.218781784202751e+266", "2.9876514865788255e+136", "6.336449176578472e-24", "-4.527911237508013e+218", "-9.017895723130231e-57", "4.689022606535362e+280", "3.940345355717625e+263", "1.5852885707102612e+81", "-1.2160996605527952e+81", "1.5995343363827348e-188", "-3.101769216665373e+306", "2.5125564018162458e+242", "5.481245648845819e+133", "-3.251249202118899e+84", "-3.528242452963257e-234", "-1.806491940092563e-220", "3.955747356189063e-167", "7.78194814028866e+242", "-2.6254753883203255e+161", "1.4412730460860048e+206", "2.144944840411454e-68", "1.9792656223348464e+211", "-4.524906166193401e+16", "8.888518362860724e-234", "-2.6902029397976625e-143", "1.285145292238471e-30", "-2.2644237203865816e+180", "-5.867991342987026e+305", "-7.905144890727277e-202", "9.395632286955699e+41", "2.2500365441443152e-46", "4.3808663199351504e-69", "-5.392972029663445e-07", "-3.758115123193819e-240", "1.3914965645435541e+174", "1.8312734823548584e+127", "-9.414100127781602e-169", "2.0471006818336336e+285", "-3.299768631815356e+247", "5.2792252848406574e+302", "4.858328464054863e-81", "3.1019601760184502e+25", "4.378164070860397e-151", "-2.2877393139893034e-291", "-2.811032431406388e-91", "-5.302471690435691e+139", "1690250.1446558249", "-1.7170365852412457e+93", "1.0499610865077381e+104", "1389913636.4714112", "7.066902452436125e-50", "-1.6996880263557168e+41", "-1.069195776509838e+216", "-5.643519811286168e+24", "9.511682183151149e+153", "1.4784525448928878e-218", "-4.633020527199121e+265", "3.993992703107172e+289", "-7.906417210588785e-271", "1.793605776639466e-218", "-3.89379426917465e-175", "9.575768102680335e-244", "-4.3832180935309503e+49", "6.292089040365924e+217", "3.3765996678347934e-198", "6.71682891512135e-309", "-1.9192636663680463e-84", "-1.9234464396394892e-36", "-1.0896304669987746e-117", "4.0411946531308523e+207", "1.7453544968023864e-96", "-5.166577671443866e+78", "-7.324494571167849e+232", "-3.2127568379755997e+62", "9.190079007368563e+287", "-4.8243761531093207e+201", "1.529799226689781e+145", "-3.0679039753416545e+26", "-5.064204735888294e+218", "4.384558535892395e+189", "1.0801263694312185e-27", "1.1645781675656287e-25", "-8.085951530662866e+126", "1.836980839627844e-161", "-1.9351514989725558e+289", "-4.765863015230916e+48", "9.95805301065268e-134", "7.33431070949532e-290", "1.0882228160691176e+214", "-4.401023534668905e+269", "5.4194358849223204e-80", "-0.06937333208752336", "4.711274152463523e-137", "-2.1223762917014558e-24", "-4.75535982229444e-240", "4.07576956480562e+178", "4.720444954094434e+175", "4.843434679683636e+207", "-1.1857375843717811e-54", "9.522218813486469e+52", "-4.159500638804938e+176", "1.213203309587278e+71", "8.695843498161736e+155", "1.351457528052683e-186", "1.0568663607437845e+234", "-1.396329758968867e+161", "-1.0653517790985568e+131", "-1.7791526347199451e+227", "-3.185962185356321e-133", "-2.2879002372970003e+157", "-2.187444470369797e+100", "-5.577079490712798e+74", "6.761988101394974e-220", "2.42117334525473e+137", "-1.8349546901094155e-293", "7.49519131182096e-236", "-1.2005142373069072e+46", "7.0990624784579005e+230", "-1.5684089989583936e-52", "3.8102677429730404e+254", "-2.92624803729436e-135", "-2.0224085056843482e+259", "1.9026887533206346e-54", "-4.3493028057072913e-106", "4.737843240795835e-269", "1.4597721866298278e+53", "2.524514020119468e+182", "-2.8017585107906757e-142", "-0.4311361021990821", "3.1286557072073536e+148", "-4.1114395692820096e-272", "6.755929959096936e-175", "-0.028468188485976258", "6.12378091869014e-255", "1.0661665222936711e+215", "1.7088127037770477e+200", "-4.247918995559946e-36", "-2.769921610685567e-127", "-3.783443870250464e+243", "-2.1451213385102196e+145", "2.2752446479242591e+223", "-9.758213775805499e-180", "-3248214616.130969", "-3.946992790875101e+264", "7.287444637727465e+282", "-1.2614819202387795e-125", "-2.027738168116612e+279", "-3.6957165476156463e+211", "-6.832270320673276e+147", "-8.641296664067847e-262", "-1.0787842369372582e-70", "-8.486399231896723e+214", "-1.789958470999426e-136", "-2.9030483542464444e-47", "-1.4774662427792284e-240", "182.28445804730077", "1.2639626187525475e-240", "5.5234595066372135e+45", "6.565746196403118e+145", "7.13792164602427e-68", "2.0218080078468783e-160", "6.0312321632209e+272", "-4.059413087983951", "-9.252499328279482e-28", "3.433798714114921e+248", "1.2154526507486172e+113", "-3.084733229811486e-10", "-1.7231091306451647e+24", "2.7138616090844432e+132", "3.182229867912538e-12", "-1.0754600117955894e+176", "5.990865014088291e-269", "2.6292986291035e+234", "1.9735433509140342e+48", "6.768565418715898e+48", "3.129118104713341e-283", "3.276320391204703e-59", "-2.9755213530564922e-266", "-1.65523525824235e+123", "-1.4617611608547032e-89", "-1.2487528281725662e+151", "-1.6245608770591705e+132", "-1.8259036981427364e-217", "-2.5103133421511683e-208", "1.1681390298063318e-116", "8.798921241944981e-285", "1.255454130454189e-294", "-2.9374360383565525e+160", "-1.035194890017283e+89", "-7.05507641241555e+37", "-8.109483005296091e+110", "-1.4148314528604396e-12", "-7.358872867393878e-302", "1.553597458181297e+79", "-6.3529276654448425e+118", "-5.299672084526504e-96", "-4.7549695937317166e+79", "6.127474132124754e-159", "2.1124295481711236e+276", "1.030170853982113e-64", "1.0657496724274932e+140", "1.4846910211963235e+303", "6.887197391620916e-302", "3.393919253801643e-231", "-5.020066001756579e-17", "6.59743961528427e-126", "-6.9489556966572475e-31", "-2.0147452788527566e-95", "5.29624957136062e-257", "-1.3111952581793733e-247", "-6.116105248206626e-286", "1.3581148749890835e-287", "-3.9696133380895975e+55", "6.30475254461833e+72", "-1.4733906561704048e-205", "3.351004178757024e-56", "4.210685295188975e-49", "-1.5910131510721214e-123", "-1.7850333300941836e+259", "9.711273604974466e-200", "-1.144480728962885e+70", "2.250718025786798e+290", "-1.4643498258152335e-81", "6.494967469771312e+178", "8.542044306141702e+203", "-4.4815876439226305e-66", "-1.911417015950595e+152", "-5.956761621613081e-211", "8.967818632862052e+226", "3.2334346223162737e-22", "-3.7369797095738484e-52", "7.205399141741402e-158", "0.0006026261238235871", "3.6951694226254263e-246", "-2.762647511672244e-267", "5.030513861598267e-121", "3.559392876499032e+66", "3.710206132861682e-235", "-1.1442245911431077e+232", "1.1484023587927254e-234", "2.546525512148585e+202", "-6.132995024186839e+102", "3.5220060309428717e+86", "5.552875152006907e+150", "1.7501762164059222e-190", "-4.5799365173481324e+138", "-2.1186610299343178e+55", "8.000950222657872e+183", "-6.421343613682539e-208", "1.2218323559876816e+49", "-4.714879914671566e+104", "4.732627868253532e+252", "-4.337710926401518e+150", "-1.2219625968400472e-119", "6.273668320261315e-48", "-5.2429361638100654e-42", "-1.1797442317987553e-198", "-5.805291654848563e-269", "-1.465323661929058e-112", "-2.413493662500904e-266", "-1.387985594497733e-50", "-4.9146016090471444e-59", "-1.7523196234281933e+180", "1.5347847347680232e-159", "-6.6824057565922744e-248", "1.4106112087325451e-157", "-8.912177782517112e+234", "-1.1348597605187648e-171", "-1.5090705868056013e-10", "-5.981081191389884e+103", "-5.808415745078754e-145", "2.458379942059306e+258", "6.311364734011977e-144", "5.457600485786006e-293", "7.245842417608456e-200", "-1.4923708910260727e-114", "-5.4149086604129736e-297", "-3.2999567110108315e-58", "1.3407244149077808e-08", "2.322356136612183e-294", "2.3083744436190039e+139", "1.236300304432713e+284", "-1.4335440251257974e-273", "2.216449319132383e-140", "2.734970193176589e+248", "5.51251265156848e+255", "1.426562844629543e+218", "2.0357452049902956e+18", "-5.2833699419759145e+284", "1.3461925881103486e+242", "-1.3378601171627647e-116", "1.9138182731066538e+161", "1.4882432111556844e-259", "-2.0029519044317405e+234", "1.781529530604118e-169", "9.566988564944718e-51", "-4.985649352144418e-294", "8.457461048251802e+38", "-9.943073991111156e-146", "-0.0073997882948084424", "1.6588633811692795e+149", "225453.68933947495", "1.8871258861342623e-304", "-1.1669082647674503e+203", "-4.543895947508143e-13", "-1.1614614412892724e-123", "-1.7140113739795418e+145", "3.0972143942255506e-135", "1.6321112371646503e-183", "2.2851330860183493e-223", "-9.999059940066676e+258", "7.090572775573481e-252", "-1.0872278867398508e+24", "4.0367459229759414e-140", "-4.403516246548083e-254", "4.387515066464009e+296", "2.411794374683052e-106", "-5.858649206654543e+20", "1.5548527090993918e-228", "-3.342929595149875e-98", "-8.513863224415012e-242", "-2.2425138927468044e+248", "-1.1314811983014534e+282", "2.9733771398558184e+57", "4.3591782300571544e+139", "-1.8015295080954784e+229", "-5.738665510922816e-135", "-2.9200800609411204e+134", "-1.4535198994881134e-156", "7.265854820478171e+296", "1.1255106006420581e-39", "-4.224023777186045e+189", "-3.820858483126325e-167", "9.589903809367574e+76", "3.901503427590522e-19", "8.590490480564478e-244", "-1.6915590558604958e+134", "-1.2925441424810646e+218", "-9.575669838336894e+278", "-7.634470303240589e-51", "-5.7385222203527346e+234", "3.107893730417994e+53", "1.8573801263003943e-215", "1.0526796371292182e+142", "-7.476098438790078e+297", "-1.9115931393165438e-271", "-6.952514274848787e+202", "3.062451447020931e-209", "-2.5994732304471823e-33", "-5.546704709108588e+45", "1.846599886546844e-182", "5.909461725352533e+233", "-2.6786853847489073e-188", "-7.945980987142387e-134", "3.1248308286354448e+106", "2.2723539429931845e+200", "4.43940971286682e-178", "-1.2264205161650413e+47", "1.8315021603479343e-205", "5.940299242649738e-284", "-1.9902667520655012e-269", "5.313020874914008e+153", "-1.3465845121550387e-107", "-3.1747589508251354e+140", "-2.924256391977436e+104", "-5.383669993528275e+21", "-1.219717065239765e-79", "-1.2154846631782518e-57", "8.341565517412734e+45", "1.464731800773206e-27", "-8.647164886045572e+54", "-1.582412307545233e-271", "-5.1284924086390074e-241", "2.1950740561473734e+110", "-1.6008926270250784e-288", "-2.1968684979459422e-45", "9.333702498985961e-86", "2.6276279519150525", "6.113951890545189e-257", "-1.5277973531343351e-238", "1.7556571655625655e-252", "-2.339924151393212e+282", "6.251846639603219e+59", "-1.4916159148113422e-19", "-1.1634534579637931e+30", "1.9121563691756532e+207", "-1.0222251918465969e-35", "8.986956849909324e+55", "3.928149719293639e-105", "8.760458728988399e+287", "-4.575010970934182e+27", "-1.3393304408118548e+44", "-7.168072430566717e+287", "-1.7675886191707048e+202", "8.12720870018355e+236", "6.903765883294681e+145", "1.0558276585150929e+257", "-1.2201079109574194e-123", "-5.52443607648223e+221", "NaN", "-7.157671390681888e+114", "1.4245125770635599e-127", "1.4766309654912626e+84", "-2.9271545100634555e-41", "6.782106346796131e+199", "3.9939091056503055e+18", "9.619353509673657e-79", "4.665944527499934e-145", "3.2020642705171715e-204", "5.826020918112688e-46", "4.2971200221592925e+29", "-1.0470102760273124e+185", "1.1273800265723033e+225", "-2.723165282986277e+272", "-3.469517964571126e+283", "-1.0134586176620853e+84", "-1.3121642768817036e-149", "-1.066342313786994e-262", "7.108544888979699e+232", "-5.824396031240866e-211", "-4.569094949041588e-157", "1.0403621206899712e-91", "5.3743089826805115e-49", "3.9063981884348176e-205", "-1.8284234548005844e+68", "4.889790505269604e+133", "-2.4278670912010315e+138", "9.66078400368974e-302", "2.1448317717163736e-50", "8.862008041344842e-168", "-3.159168100288591e-174", "-2.7791193578282696e-195", "-1.3721386105894131e-149", "2.3840498542697263e-283", "2.8432466340141853e+211", "-6.420997574423503e+139", "1.4864413462053078e-77", "2.4144378082614114e-272", "-1.8284510927665974e+108", "4.978865707725916e-197", "3.253494584024525e-26", "-2.158352160261701e+224", "-1.0437787287078859e-18", "-2.1917402292835913e-196", "-4.5467535803527075e+21", "-2.5031266903058703e-301", "-1.3444996698448261e+90", "-187948.97682544563", "-4.928641007035633e+82", "-1.0103634193651046e-21", "6.459044158973517e-155", "-6.199155344486767e-293", "6.354062921978174e-97", "1.010082789643587e+121", "1.7988264787798285e+224", "1.964229058425459e-263", "3.442985051635627e-29", "1.404085413552339e+262", "-1.569189667363654e-25", "-4.4628663547194235e-12", "-1.9172056051699138e-222", "1.6207622786376133e+250", "5545027507.029164", "-1.7310676573331415e-182", "2.607981173293837e-161", "-1.859025439668151e+278", "-2.44640564864303e-89", "5.881437088756615e+269", "2.2514683130699426e-54", "4.981320102667752e+257", "-0.0017090293983851722", "-3.4457532164758633e-280", "173903523.44047576", "-1.1274745449228959e-157", "-6.731971558111324e-16", "-5.070108574038354e-05", "-2.967759083732801e-273", "1.5448016286068025e+68", "-1.077049822171934e+124", "-1.0559384709597425e+250", "-4.413954185775137e-267", "2.182148993596425e+226", "1.7571768768936848e-122", "-1.493757610765069e+106", "4.6771124205336436e+209", "2.287038973049278e+183", "-8.647485092497428e-231", "1.8239028459645372e+174", "1.7068954637992472e-52", "2.3038123337738872e-05", "1.5578411724591912e-118", "759807180.8493085", "-1.0661618673358322e-284", "-5.890565972881839e-130", "-3.065250341432156e+39", "2.8656113957546733e+79", "-7.926819687166198e+180", "-4.703761008927917e-300", "1.9554113239450202e-187", "-1.2646496526761e-37", "8.677473328165056e+176", "2446206346.392338", "5.681020175637e+62", "-2.8584447357038995e-13", "2.1486313616521495e-134", "-5.841628687261363e-298", "2.1732795710958217e+238", "-1.0172806041003735e+283", "-1.3380224080378715e-115", "5.080587501003347e-288", "-1.2627215836616634e-221", "-3.7669632600759875e+297", "8.937931553314673e-279", "2.3696233859354435e+266", "2.2860088134220214e+277", "8.073012993038326e-144", "-5.651151228012961e+50", "-0.00019809100394273905", "-3.8088455756471805e+44", "2.602283167234226e-31", "-2.4922751279449803e-61", "2.991863516746237e+111", "8.937106578094073e+230", "8.260894560126259e-162", "128400.68928083281", "5.173295706506613e+244", "-1.525326410549501e+77", "-4.67185628186527e-75", "5.0844061106695706e+33", "-2.1630042074913746e-288", "-8.119060063315238e-187", "2.0868365560662917e-78", "2.365000645054225e-247", "-7.210244406261552e+71", "-9.475418109604982e-204", "4.291862500402806e-133", "-6.341425815931518e+269", "-1.9897656732618025e-263", "3.99731871048812e+85", "1.3151496480107633e-299", "3.779614421871206", "-3.6805724065710894e+277", "-1.1351875439710913e-82", "4.185177129189157e-227", "1.8143150289711436e-166", "-1.0735658796408116e+98", "9.84946650549323e+184", "-7.323382060643252e+25", "-5.742575116704573e+145", "1.914964775336739e+68", "-1.2561157798743295e+180", "-3.444048849219935e-70", "1.6518807307307345e-227", "4.570196016050048e+268", "-3.736753755268666e+164", "-7.561192002047328e+255", "3.272841144124803e+194", "1.0976778939818368e+210", "-8.166768568041045e+117", "-3.3886306923901216e-13", "762278.899501159", "-2.8546376320098287e-41", "8.998757031844181e+158", "-2.0839075207078294e-66", "-4.453083959885863e-237", "3.882208907431199e+85", "3.276614058492013e+259", "-1.2331694482156621e+73", "-3.335755549576039e+50", "-5.514184576775188e-206", "1.9020320551023006e+189", "-4.269871895817622e+219", "1.9204886227778874e-13", "2.954908915275449e-218", "-7.23205631264043e-307", "-3.497311105779332e-130", "-6.665342698859739e-40", "2.2613645434587158e+163", "-9.927399391298752e-116", "-6.8567116120385475e-189", "-5.609883710632515e-63", "1.2008301878196888e+201", "3.6517819195461895e+193", "-1.1868746774565153e+91", "5.789426198372969e-80", "9.646920282331026", "2.2750198456382606e+144", "-2.1003340896003067e-186", "1.2345197410429448e-54", "8.65436124002693e-70", "-4.9798952487121514e-98", "-2.854925355623922e-218", "-1.4180783805672368e+131", "62787770436667.69", "-6.392864536020004e-104", "656.8597956579558", "1.063915462191442e+91", "-2.8842029546615e-79", "2.1094010120562603e-21", "4.537003803549104e+212", "7.257360844522245e-153", "-3.267589400490216e+267", "6.779393809263043e-253", "2.157575785275142e+125", "2.463473683249917e+53", "4.5830282243854677e-128", "4.915007876528326e+226", "4.668628910245407e-205", "-1.350708793046184e+39", "-4.81147698789713e-243", "2.235256104593146e+146", "-1.3855155585939475e+252", "2.2897720732985404e+46", "9.1078992581998e+155", "-2.280672021385669e+148", "-2.0946960840550002e+98", "-4.705086797112712e+150", "-4.4480104613003395e+224", "2.2781590968237257e+195", "5.678200435835951e+74", "1.8650404182984077e+163", "2.003195257377548e-161", "-1.738881236783635e+72", "-3.910351640229034e+158", "1.965433745730658e+94", "1.488841185031926e-298", "3.5773095257919888e+87", "1.427567606961274e-150", "2.353662379006295e-22", "-1.614182881155896e+67", "2.5644603852295636e+23", "1.5913379061529954e+132", "9.149503191984436e-287", "-0.00016786284934849525", "1.86338790547473e-164", "3.552925838884485e-136", "4.2476978405271225e+65", "-1.1077496405183459e-72", "-5.989147222723115e+80", "5.246286445570708e-50", "1.5483352919233732e-213", "1.2036481544819763e+78", "3.2490477926785094e+255", "-1.1744963798103983e-51", "-2.0601399267895378e+275", "-10142399.317241509", "-2.310041834499325e-268", "4.718018659214855e+40", "5.8505901193214205e-12", "-2.2690481954737233e-211", "2.6220752986261603e+156", "-3.622013451128303e+64", "-8.210147468095451e+198", "-1.2882102014599247e-165", "5.5587196675127975e+132", "-3.097469116289627e-23", "3.311342606586956e+207", "1.7471139626849537e-204", "4.183784917622824e+27", "2.2431502113010297e+250", "-3.0966646659251907e-15", "5.079370960812464e+45", "2.6545826633220295e+267", "-109.79583240822936", "1.894108993174664e-165", "-2.1460659379378363e+297", "1.506692089541291e+17", "-5.180108995888479e-92", "-3.6401393887049987e+304", "8.58517747290861e+60", "-4.8307261486566367e-250", "4.665451526415454e+240", "5.274853529257994e+155", "-6.551604261615718e-206", "4.6522262815223466e+300", "-3.288990709910696e-224", "-5.888958492410532e-245", "1.3747530610191444e-127", "-7.196982020970507e+91", "1.4682836355705526e+242", "3.604296239611745e-277", "2.5505174112660665e+247", "-2.333372307052177e-123", "3.349764305711238e-273", "-4.5168188280706434e+287", "1.9537675928474676e-254", "8.556966624372188e-78", "1.4329814546657813e-32", "-2.613870402014634e+32", "-3.4726644880110793e-50", "-2.213704751668609e+28", "122284.16932463388", "2.6555152088953152e+121", "-2.365330701345115e-265", "-5.893158122836581e-10", "1.4750155962247214e-298", "-7.216517860341929e-169", "2.1454290050215788e-09", "-2.1821481897477918e-33", "8.319036169770195e-170", "6.248127282583587e-205", "1.2120521576847177e-199", "1.130516890519527e+260", "2.2660565565993014e+188", "3.377950957381934e+151", "-8.1760918025295845e-165", "-5.718982935293264e+157", "4.6415376602064e-89", "4.67564697289707e+79", "2.3934771561707038e-55", "-1.3761909320047582e-148", "3.437939250100383e-100", "-2.4541145602475405e+104", "5.714441430451611e-185", "-1.1785295262967328e+105", "-2.294421783521177e+75", "-1.3174075236549087e-07", "-5.77511821380731e-147", "-5.303616544396042e-256", "-4.148989320371033e+19", "-1.5420028318008296e+39", "6.904933429059196e-251", "-9.534921533617612e+165", "5.183270914486436e+104", "-1.308212183522454e+108", "1.7086441666948204e+190", "-2.0238913382482774e-254", "1.7378384312269737e+72", "9.501744232450367e+240", "-1.2874736855979259e-202", "-1.8509696608101587e+86", "-1.4547357998388627e+21", "1.6350917516400875e+179", "4.198641299614664e+263", "-3.7713876545323856e-206", "-7.453649555732886e-307", "1.2563246140310772e+126", "5.503933469991141e+300", "-951.6534949878467", "7.830465763245518e-120", "-5.178095097287286e-94", "-2.0522404565502315e-105", "1.3341836699026448e-116", "-1.5881744316894283e+49", "1.9059906755493753e+63", "1.267827404882921e-56", "9.744171637951969e+93", "-2.2556724633515996e+56", "7.562733235654833e+279", "1.0659589027321438e-240", "2.0468621793284866e-152", "1.3377308275852152e+303", "17447858.709647175", "-6.391786231575201e-276", "4.983514747553155e+110", "-6.120157409119696e+205", "2.6582886107306015e+95", "4.7733908985480354e+157", "9.987675929317397e+207", "2.5904294883827768e+144", "-2.5615479783078426e+295", "7.725953811666692e+205", "1.1845102132967917e-201", "-1.3536792869959176e+212", "-7.258437324708587e+113", "-4.0142675496510186e-219", "-3.981664285870322e+155", "1.2123702300539566e-195", "7.442519071976582e-267", "-1.5677536158747047e-83", "8.409984629701833e+55", "-1.4331241364862913e-201", "-2.0964784521565124e-116", "2.483793135514549e+165", "4.2519355358224753e-296", "3.4619546138922366e-168", "-2.6867309888379632e-132", "1.4229754951998302e-104", "1.9071636756986735e-115", "-1.7816029201041522e+98", "7.264546738562131e+181", "9.968769299230984e+224", "-3.5751759542386054e+245", "2.294958474458581e-56", "-4.027800103428398e+181", "9.049345851670785e+124", "-2.4236621897871578e+212", "6.205073505656716e+254", "-2.2621734148674803e-37", "1.8964071632368322e-42", "4.289913647246596e-15", "7.0116291149729045e-214", "1.5756966395303664e-115", "-3.4064790299039103e+189", "-1.2090045480777646e+178", "-1.3914664190292554e+25", "-4.025178050081478e-37", "-3.899605397095344e+256", "4.408989473674158e-261", "8.289942898989964e-72", "9.723102715446766e-179", "-6.369277935862763e+31", "-7.403453587243638e+41", "1.7810550265525154e+221", "8.24667757241865e-277", "7.588350943857743e-101", "1.1908840647815965e-154", "2.969449724976084e+250", "7.74500167715692e+271", "3.64701205119583e-131", "-1.0961696293375239e-163", "1.4712328125600642e-42", "4.56983078079639e-123", "4.301193500122842e-213", "-4.578029831610337e-09", "-1.532437914852086e-246", "6.795228481766585e+46", "1.0534583069543091e-27", "6.391294513234249e-164", "2.1330600099439003e-78", "2.7045553235976163e+27", "-1.0176376042697158e+86", "-6.353320643281802e-261", "3.786062571435811e+130", "-3.685632890195155e+215", "-1.1773721385852804e-88", "1.3735270437618996e-43", "7.614363768225685e+158", "3.597414587487501e-49", "-2.4749782134270107e+240", "-1.1280028103274696e-281", "-7.859506091166072e-285", "1.7564721202455523e-274", "2.4007937029487983e+221", "-3.804953957854113e-243", "8.431353757331707e+18", "-1.2571045097817758e-264", "9.129949113925257e+284", "-3.584829886850359e+72", "-3.984386266510876e+91", "-1.0983190516098408e+221", "3.216582620967586e-207", "-7.574082992884371e-248", "2.375958586450427e-71", "-1.4090089524844746e-14", "-2.3585330102740882e-211", "-3.6737227819455623e+204", "3.1310322401554886e-92", "-4.2350703581464353e-296", "-4.6674682055085334e-79", "-1.039637470402782e+94", "9.267328337727027e+294", "-3.454510082292333e+239", "1.2393929738818278e-97", "-7.260610861500457e-34", "-6.092538120156525e+306", "3.462222187937053e-241", "3.544132295275142e-294", "3.463201295779588e+215", "5.331604400412773e+230", "-7.042669017931632e-158", "5.987375015215134e+61", "8.431049837499494e+64", "-5.2021267096459626e-207", "2.2505849102835377e-229", "2.6482548358187143e-54", "1.1356925268747558e+186", "-5.494969391004811e-228", "2.1358336894344843e+301", "-4.8233456819556705e+196", "-9.634531263924854e+146", "-3.6004183475574435e-303", "1.7347424310780257e+51", "-4.1847423486166646e-169", "7.967731546988948e+31", "9.209139301547769e+193", "2.9145282408420992e-102", "-77359618915.03093", "-5.0081157011370425e+151", "-6.7161732761581155e-254", "-2.8500816513130486e-136", "1.3979638993383852e+51", "8.134739342323483e+279", "-1.0112139779514897e+61", "-4.132575579109589e+177", "6.036609289561306e-78", "NaN", "-4.757781716870998e-271", "-1.3982236941295052e+305", "7.914316408083723e-145", "-5.943238287395745e+131", "6.519277919966758e+61", "-2.080728752218384e-15", "-9.261111226840757e-132", "6.368545933927265e+297", "4.271359074079561e+84", "-6.652663016004561e+258", "-2.028028868144433e+221", "-108855.76234937923", "-1.5622404403575093e-245", "6.649391824230723e+142", "-3.1184590156451686e+271", "-4.6233204208371256e-30", "-4.533135055251921e-113", "-4.3534956788829856e+95", "1.5226084334413166e+301", "2.6853262806961137e-190", "-1.133954160486225e-20", "5.139317396971476e+48", "8.14026819702231e-100", "-1.1073351901077303e-45", "4.746899892449457e+154", "8.28398205011313e+290", "-7.643755155924512e+88", "8.494570210345696e-212", "-3.906067196157431e-239", "2.8794364263927574e-214", "-4.7588484293125746e+122", "1.1963986979182926e-70", "3.528787914799546e+127", "5.875204101458041e+78", "-1.8337056908900197e-158", "1.5830340163297496e-233", "9.84922596942365e-128", "3.4933134523974866e+258", "7.398108265555124e+186", "-6.890044999926777e-127", "2.347177486420039e-266", "9.8858493749064e-273", "5.122568560761241e-110", "-1.7273112687255676e+181", "1.78293319399541e+166", "-3.85190185475867e+154", "-4.554281881936085e+150", "1.6550078469133306e+105", "-1.0914186939957898e+214", "-14.944939100149195", "2.5332839421425664e-248", "5.292488399472253e+113", "-1.244099230776942e+264", "-134743909112.37209", "6.981383445817815e+63", "2.1043570590220524e+271", "-6.645016024040225e-139", "-2.9881739879687573e+77", "-2.900196736891054e-133", "-3.527029584163244e+67", "3.415080215390903e+24", "-8.011398050586322e+55", "1.9563660655906964e-295", "2.082602673865195e-28", "-6.404545177328053e-293", "-1.2034058669174466e-296", "2.6515795706330014e+275", "6.88434955242063e-168", "-2.633123478646874e-251", "1.458883257402641e+16", "-8.684546438313431e+23", "8.621514325256298e+292", "1.4437244781195877e-265", "-1.070565757432804e+228", "1.0782396656366986e-84", "5.7546043151474535e+281", "8.043652694757605e+237", "-3.0866497670551033e-299", "-1.8657309957517277e-130", "2.438453670785835e-177", "-3.086649562165491e+16", "-1.1680447957468289e-226", "6.839582500028053e-224", "-1.24482763213174e+38", "-2.5866949797056122e+129", "-4.714842610994308e+299", "1.2032307288071237e-265", "4.209327219607571e+113", "1.3210710446966214e+39", "5.953357513030782e-166", "1.4914638384059592e-102", "-2.8710368536307994e-120", "-1.2726406853207374e-85", "-6.873239259680309e+59", "-4.8829442862096054e+190", "-2.4032849857440027e+118", "2.4181222648969927e+283", "-2.0493069334736216e-68", "2.0964771861822625e-15", "-9.941666960940452e-157", "-4.6383939574699154e+253", "-2.7705163155499445e+118", "3.2177150718926774e-67", "3.0377402509864253e+219", "9.769970038086436e-144", "6.547629543996198e-228", "-3.8520706522754574e+38", "3.2312467534090597e-55", "-1.0927902452368839e+253", "-0.42468426071518445", "-2.1460244660691575e+36", "8.782513457790445e+139", "1.4827730366890065e+21", "9.57329504379944e-170", "-8.23991907857266e+150", "2.5034731374487303e-126", "-3.27377018104478e+230", "9.736742246039772e-05", "7.647643960904303e+238", "-2.603747723585215e+287", "2.3829992644460206e-27", "2.156087189212498e+174", "1.9544041059886855e+149", "1.8489175066581798e+305", "5.113847933235267e-139", "-5.504842753224052e-67", "-8.224919844180538e+283", "9.83454006351325e-125", "-8.746964488434159e-214", "3.6130649287400326e-239", "-3.7939202875597095e+38", "-1.018472230229795e-86", "-2.3551289143853364e+157", "1.1150930935360203e-182", "2.7369820376543695e+243", "4.2427248912989037e-238", "3.7464522474611052e+286", "1.1460023992098574e+218", "3.941956897550597e-37", "-1.0057601578628582e-100", "-1.672099315607243e-62", "5.518616389775814e+301", "-1.3012481812199798e+289", "-9.854623172424379e+129", "2.5812322642232015e+178", "1.8661143076714808e+139", "1.1825945761912267e+33", "-1.2216874513185513e+261", "-9.548714615590454e-201", "1.491462243679465e-60", "-5.359669467870761e+80", "6.784338586517525e-165", "-6.189714520872873e-108", "-6.649795059073661e+270", "-9.027572805991512e-215", "-4.311788735606512e-283", "-3.6258723749809724e-06", "1.0591150823759582e+158", "4.003791139216603e-149", "-9.43822038027073e+190", "2.1507981416855262e-159", "-1.0492311121694527e+257", "-5.0655860420592577e-48", "-6.094805797345556e-163", "-2.08978710868183e-95", "7.487683325124162e+104", "-1.068314403460099e-44", "5.953445458499676e-285", "-1.1703988496406234e-265", "2.590783214328026e+150", "-2.0358369114338423e+225", "4.863972298779214e+173", "-9.504874523866758e-299", "-5.791315992370541e+251", "-5.0530372265940974e-126", "-1.6514927072453338e-237", "-2.80538569966019e-224", "6.554041667505691e-230", "-3.3894258322305616e+297", "-9.3239703108772e-303", "-2.1787351255740332e+307", "-1.4661675492791537e-267", "1.0294645236975104e-114", "-6.030860314363258e-21", "7.541132862298298e-266", "-6.4845593292404435e+124", "2.3378990923520737e+144", "6.783188307465472e+19", "-6.427020071854409e-288", "7.029963666005185e+61", "-1.1213222906627832e-175", "-6.997513995545294e-102", "3.121179367072297e+253", "3.97735943630596e+63", "-1.7534071265916247e-278", "-3.4420678817860248e+233", "-9.115705545315344e-192", "-3.4241873126340574e+81", "1.4574856974390959e-64", "-1.0387606181462514", "-2.3009965996261037e-137", "4.852343668829363e+25", "-8.156120036509443e-162", "2.9037525638723856e-289", "1.7650226648086426e+304", "1.8730418850434677e-116", "-3.67613796216045e-127", "1.5630439302513607e-238", "-2.4638768969508864e+282", "1.2726360180882067e+149", "-8.762211128527273e-61", "3.6577354878756735e+78", "1.9621873803691482e+96", "-5.779019151478995e-17", "-8.294836618292996e-74", "-9.338556162472233e+163", "1.7719848036918219e-134", "-7.996220434160862e+185", "-3.848290752297965e-98", "-2.834936622293738e-182", "-7.2802304328225454e-155", "1.2328634835645801e-300", "6.082197954794208e-246", "3.5133710212287986e-45", "-4.690788414981205e-209", "3.241337222520157e+294", "-9.46947289091102e+154", "-1.1324679294469433e-249", "-8.99707902840893e+44", "-1.6061366885362643e+128", "-1.922954463824211e-58", "-1.106912276606364e+85", "7.304263070745108e-165", "-1.6997087785200958e+266", "3.770901782640262e-38", "2.447905198912385e-168", "0.03723377854912374", "2.2139825070352093e-184", "-3.361217660384164e-185", "-4.7920415325354334e-219", "4.521782665127668e-32", "-6.465999949774419e-16", "-2.667744428675398e+197", "4.481441814520486e+196", "1.1801603117569993e+88", "2.7476772203029877e-240", "-4.9695280522151783e-234", "3.3060243084817583e-169", "2.8947001147668235e-202", "1.693998433333834e+120", "7.911768385243275e-63", "6.138646343474482e+174", "1.330727387770968e+155", "3.9977202244893413e+142", "8.261018241511292e-135", "-1.0557415137762814e+145", "-1.6162284643305726e-208", "8.866737026072947e-150", "-7.979727776558318e-215", "3.2463731019501143e+102", "4.446252157794066e+86", "1.4205676261003026e+191", "-1.1177129818971646e+192", "-1.4795963663169723e+50", "-4.956957500778702e-275", "-6.613885871265352e+76", "-7.593723859120694e-34", "-1.7981638944085138e-88", "-1.4323636707932367e-261", "1.1531354204724452e-186", "-3.5620851361099885e-276", "3.26152981188008e+128", "-1.4932672829516107e+214", "1.102520798358279e+138", "4.687940700846551e-305", "3.407967661039772e-85", "-7.883308535680799e+278", "-3.8309600985599575e-78", "2.7484573449401903e+178", "-2.2876572240158708e+185", "-1.5024178141352075e+294", "-6.495909575220052e-55", "-3.794531335740339e-107", "-4.241867471007307e-193", "5.3791055175215875e-199", "1.3347079834559878e+298", "-1.133197522782365e+111", "5.465817780648139e-166", "-0.0005627905128769705", "5.188454509286092e+307", "-1.0701628597844184e-259", "-7.135476765060312e-16", "9.602231619851116e+111", "4.507125312299331e-48", "6.031613673062403e+127", "2.1763910181430554e-100", "9.635108974708963e-275", "-1.3051825717822762e+270", "-8.024086529041313e+65", "-4.975432187154897e+207", "1.6250425576425147e+106", "-3.546789607544566e+156", "-4.469425392614253e+95", "1.2257337113799057e-20", "3.263171800480319e-26", "6.987230913585024e+210", "6.0906894030527806e-192", "5.1497878104513936e-197", "1.427800873266489e-213", "-1.8358727923103226e-19", "-2.7501683789558456e-49", "-1.1969151791585224e+181", "-1.1308583467530993e-199", "1.5011485377150944e-247", "1.9493479898689064e-258", "2.0034619425846812e-89", "1.5524816759336669e+144", "4.169861654172206e+25", "-1.4223209928636417e-290", "-4.108570023144272e-201", "1.7539854450777366e+157", "4.6862084444749785e-18", "4.0675107448548453e-122", "-2.1400694554615088e-129", "-1.344905233589487e-255", "3.9153859424414084e+89", "1.830086798352511e-177", "-1.2792462728679932e+119", "1.8148938009640722e-303", "-6.274929641782958e+219", "-1.2781602804893964e+33", "4.592814335290113e-29", "5.359708758759941e-143", "-3.009495695774145e-71", "-3.576132205590216e-194", "7.1459721306136295e-43", "0.0006661913927178016", "1.7672583502489112e-51", "5.464022008779775e+59", "-1.625003172121125e+263", "-5.821121098161155e-12", "-9.374370746136923e-224", "-6.092277080099756e-143", "-4.3271410346143804e+158", "-5.025687673534397e-269", "-1.1121463430762017e-10", "8.229294887651881e-272", "-5.819852347897811e+178", "-4.250293362153765e+56", "7.806626628558444e+26", "4.7118565333113246e-240", "-1.7769279617169142e+294", "1.7156848855793676e-05", "1.6981108430426884e+253", "1.2458840280400252e+141", "2.2425333435441246e-104", "-8.068167029165871e+124", "2.4696724717165265e+102", "-1.1032180600469444e+18", "7.065723973350948e-268", "-2.5942993371599217e-38", "7.57380238020904e-189", "-1.5649845837896255e+117", "-9.365221545839276e-198", "-1.0621081774620695e+168", "-7.994438151781002e+237", "1.6386992208700302e+285", "-1.2462887390198632e+162", "1.8134016440894578e+156", "-1.2985286665563564e+21", "-4.647340440599806e-32", "-6.401644833157747e+173", "-4.7872095660868897e-197", "-1.9176255681172236e-138", "1.0560084874905563e-101", "-8.835060549626682e+153", "-1.1434988543125524e+246", "-8.523735993555382e+49", "7.287282753332911e-90", "-1.311251277334574e-279", "-1.6326235356149086e+130", "-4.428482447922952e+69", "-1.2051681383243857e+38", "-1.305755135819095e-198", "-8.045487388811081e+60", "2.953700502100393e-182", "-3.987175567677525e+107", "8.825298339900702e+176", "6.459686186382018e+183", "-6.742703321455504e+305", "-1.6073665723672745e+134", "-2.8632278206412573e+294", "-3.6722406618285185e+181", "8.195853474617389e+63", "5.714922387567743e-09", "3.377257955455159e+146", "-2.115360399626422e-195", "-4.306075719146393e+279", "-2.767537199882595e+79", "-5.822963236306348e-80", "-1.1230765583131566e-20", "-8.910305094390474e+150", "-2.1054415094638063e-92", "-6.708207369849547e+157", "-9.17349998426866e-13", "-5.6120093019672296e+150", "3.700079159960349e-88", "-3.791562258035478e-171", "3.3299246789883355e+208", "9.783497192359107e-285", "-2.1082055488333797e+28", "-1.6894812553485902e-62", "5.041247169747978e+166", "-5.200647664439961e+232", "-2.0682040493008045e-87", "5.564037052974966e-292", "-1.5150460192026162e-19", "3.710941803357873e+225", "-6.436074092067859e+151", "-1.4044959816392597e-155", "1.567039776487285e+53", "-1.904488120867168e-122", "8.014000509028692e+190", "8.20677080579783e-210", "4.60627855035342e-136", "-2.8433314333666204e-239", "-1.826868714856715e-109", "-1.4266518292051708e+79", "-2.5859047556495194e+241", "8.800209917629074e+238", "-5.949801206901912e-235", "2.3189093736215657e-32", "-4.562796562281855e+123", "7.541414588744207e-212", "-2.340353613154462e-306", "3.857832533976579e-70", "-5.153097002155379e+100", "1.8350641717000585e-203", "7.249743870726203e-106", "-4.21556554443782e+241", "9.03255841699167e+63", "-3.3123673596528865e-166", "-1.9803375042433182e-85", "-6.177008372737883e-64", "2.3683364653031222e-259", "-1.1139039565381917e+122", "-3.3782337732286322e-217", "-2.9853490665655274e+103", "-1.1735432137933286e-282", "-4.9663445950796e-164", "-1.2400338815617478e-42", "6.298844560317017e+44", "2.7460155882381602e-189", "-7.20753989980081e-154", "2.4462701240540923e-138", "1.6260920670267719e-298", "-1.2791228281334697e-224", "-5.378584828909841e-252", "-1.219241095090917e+117", "6.416415123692655e+284", "4.499095126525701e-90", "-6.81589776051487e-11", "6.209000816583897e-78", "1.016661000908139e+97", "-5.343322245965463e+169", "-2.0526435329817497e+246", "-1.5344499830301681e-235", "-5.21873355855017e+244", "-2.2740413313770148e+290", "3.804510381420155e+150", "-4.0514587596772097e+161", "-3.141082577924466e+39", "-4.4943883461145116e-132", "-1.633140151468363e-118", "-3.6956876865492796e+276", "0.100129952155742", "5.433518265746266e+252", "-1.2797040061668581e-161", "1.6945722147559731e+187", "-1.0186432288846184e+217", "-1.3983159444639081e+196", "1.7448496203921587e-63", "-6.635594058536044e+279", "-1.1234941461864112e+126", "1.2054669899658381e-297", "2.767412846569534e-205", "-3.1282927347136386e-184", "-3.997640305762595e-193", "1.4670099898710553e-237", "1.7230551841759468e-154", "1.75650576843075e+69", "-1.0125624105258599e+300", "-5.241869046251962e-102", "-2.5098627816739381e+179", "5.510335834072426e-178", "1.525021621556679e+162", "3.4839845069525374e+124", "2.0525671656782213e+155", "1.0124402566481022e+24", "-8.033480482609694e-103", "5.7672612497505514e+141", "6.033737255800742e+258", "1.8918221917565732e+286", "-3.1775818693656413e-289", "-2.435263223940224e-57", "6.886430183276024e-112", "-7.85837823227262e+195", "1.3436365838410161e-108", "-1.2126595280842865e+48", "9.007812215479791e-43", "1.1799712408592776e+256", "-1.1160849357254867e-101", "3.282953237955428e+47", "-1.2772221830010433e+246", "-2.1527868800001334e+17", "6.233974316087039e-178", "1.0141973852613714e-264", "-2.224460671096708e+204", "5.770523183149133e-254", "6.050683877869019e-25", "-1.172936485216366e-115", "-1.5774045702982058e-12", "8.512440125590324e-19", "1.7397747496843428e+18", "1.0107609790069438e-234", "1.1826837671965698e-161", "8.9737953929842e-88", "3.820640986736404e+301", "3.653641941504072e+306", "6.205338539492522e+77", "-1.6402186895783848e-180", "1.0331901792247754e-225", "-3.0386250637612133e-140", "3.178526882956543e-57", "9.596767172611336e-127", "-1.810916217947908e+238", "-6.66231572600683e+38", "1.1822984483565875e-278", "1.3844705160705041e-296", "-1.6992434718078167e+163", "2.510103450876877e+238", "3.0778536348165516e-148", "2.0991586825111443e+270", "-1.6627491563913548e-84", "-2.865070769577768e+37", "-1.6898210789508749e-141", "-7.24893057078571e+46", "-4.4805890171812975e-06", "-3.181629409540319e-55", "-3.611309464751476e-188", "7.321846962424312e+296", "-4.6940100818281004e-188", "-2.148861291165726e-128", "1.5693388313195024e-283", "7.014382088418618e+300", "-2.940776566223649e+98", "-3.360911094698845e+138", "-2.648745512905781e-161", "-4.0810790155592495e-11", "3.092071845316148e+89", "3.356989677886879e+170", "-2.2684291739950896e-132", "1.4072933296368924e-14", "1.7071271609146346e+275", "1.4054076720479792e+211", "1.928570736475082e+224", "-2.46355101308095e+300", "1.823309666137109e-265", "-3.8894164698197414e-132", "-4.6183776780825905e-298", "1.9146503494551705e-166", "-8.751029660711012e+32", "1.0039274853317896e-282", "-2.251197705185192e+246", "4.236805559698854e+28", "1.744441669153506e-106", "-9.202638285646313e-234", "-1.0730797753921935e+226", "-1.4763170806020224e+52", "-9.575000394392419e+132", "4.2906995668549117e-162", "2.6624507981518667e-167", "2.712499841039131e-179", "-8.922096412889745e+288", "89405.13239521002", "-6.2565675095701825e+60", "-1.1998268206117234e+87", "40930705822586.53", "-6.830799630386997e-308", "-1.3492587219288823e-157", "3.488858342997122e+105", "-1.8587502267059618e+51", "-8.455689234337846e-252", "-6.653218679020546e-101", "-6.550377495420273e-259", "-8.258830714625834e+181", "7.388070561769501e-92", "1.5764695617231691e-10", "3.2862849671901685e-132", "9204650721719.834", "-1.0289620638122905e+102", "-5.024659638930701e-84", "-9.425567941916532e+273", "-1.7088950074195838e-15", "-4.786220565044793e+181", "1.327720191368347e+16", "8.11120083167467e+121", "-3.3767954307536896e+172", "-3.914003826719185e-79", "3.014824850305245e+139", "3.6877809545836133e+151", "-4.733763376478766e+128", "1.9500272732542551e+143", "1.2536159333160396e+150", "-5.903943406686917e-32", "-2.7326808069766124e-270", "-8.109019367720804e-94", "-1.5267590145397027e-07", "8.046689703909192e-283", "3.064715165012497e+58", "9.403196610432605e-234", "4.599473894291378e+251", "-4.281163822748623e-267", "-4.080013108319729e-53", "-3.158218707034705e-29", "1.2003351277618518e+191", "-4.808707116077338e+141", "1.3312881240293597e+265", "3.387042822506752e+101", "1.2733655260942675e-58", "-2.109297786242122e+57", "-5.022374832433293e-308", "1.8972684015767613e-187", "-6.672772963718875e+256", "-3.942550488314015e-62", "-1.945838019075906e-265", "1.001430638388024e-171", "-1.9455912851750678e+154", "-1.2952795908856725e-94", "-6.948929065131879e-33", "-2.2480998862542792e+142", "-6.01339999248734e-70", "5.947984519416106e+279", "1.1318528766529014e+41", "-1.8906746942927387e-59", "-9.204281475286469e+154", "6.88709108918303e-242", "1.0599111253305484e-23", "1.2201586488533183e+173", "-1.0692714801668314e+216", "-4.8254561808692004e+286", "1.4538428003555366e+32", "-4.362456201963545e-216", "1.1871871158749096e+19", "4.5279601423711124e+257", "-2.0325239308894039e-112", "11454263.845483877", "-365929889.82412606", "-1.151643356102642e-172", "1.469813974588764e-118", "7.982402043548826e+191", "-1.8529739253311944e-262", "-4.397762674998113e+114", "1.4762131518039342e-74", "3.4816813394632295e+289", "7.2910412631406595e+193", "-4.5916415523281397e-237", "-4.615246614032151e+251", "3.370566579754564e-109", "1.0464096599939182e-73", "6.786961026585485e-28", "-1.3145490339214073e+256", "3.3488249984867003e-162", "2.967148471173962e+158", "8.187011429213569e+121", "-2.093575042450672e+19", "-1.0400201937484986e+71", "-5.883957981540205e+225", "3.953762719172765e-143", "1.6021117159656709e-146", "-1.9395731487460898e-102", "1.8337902556500163e-228", "-1.3345516874751636e+119", "-2.6482726874254546e+227", "-3.981847850257255e+79", "-2.9565805606680527e+152", "-7.278823417608265e+233", "3.054717794541595e-236", "1.7404604479083417e-210", "-5.133812350251031e-186", "4.1999694324462974e-284", "1.2340746082155723e-89", "-1.1240335917814869e-60", "7.499817147839304e+76", "-8.097733947379441e-103", "7.643722578335988e+122", "5.205948398567427e+219", "-2.2450683015226834e+207", "-6.011992058958562e+28", "2.845653579053369e+178", "-2.9942885274547704e+207", "4.7503985591835246e-216", "1.2517562079329049e+139", "1.4631372767188753e-12", "-3.8046208516617636e+195", "1.0719120407537842e+306", "2.6832815088686896e+122", "6.225847263912772e-215", "-3.568187449146223e+210", "-1.6513365798661414e+144", "-1.1243567879042419e+282", "2.265503137234428e-32", "4.6849212593119023e+281", "9.844432883903436e+135", "-1.7204279024156063e-220", "4.181220392470326e-78", "-1.0902121990706133e+255", "-2.7078492456104046e-126", "-7.799080630272393e+173", "2.6873172046130045e-10", "2.813109417343924e-162", "8.173281090496447e+244", "8.769870104284598e-186", "2.2463643075896467e-124", "-3.525261568876818e+183", "-1.1002847213763035e+17", "-1.7072597925560653e-269", "1.200196109175883e-139", "6.38095891066921e-236", "-1.9362792001340616e-149", "4.398928712154474e+51", "1.2169524620734333e-201", "8.123132169280555e-25", "-2.396777603920921e-111", "4.505255661429435e-47", "-1.3408170624807222e-167", "-5.469227866228435e+49", "4.643252196371166e-293", "6.818832663873541e-172", "-5.886613053450036e-146", "9.583090878816745e+195", "-5.968608321863451e+126", "-9.776199987351044e+32", "-8.100623077058297e-207", "2.1264153163396367e-243", "-8.453989075627593e-72", "-1.0493148706452675e+306", "-8.523667432583181e+64", "-1.7261778447963045e-150", "-1.012411484052901e-44", "7.438205867301748e+267", "3.2978819253114997e+115", "-1.152614448502253e+270", "-1.2282681665983095e+19", "6.99680985502975e-171", "-1.2591419291847227e+225", "-7.505762341781998e-292", "2.22785737582551e-149", "4.178055696224329e+158", "7.003148157656677e-167", "1.9592068250820462e-154", "9.857174427659181e+92", "4.9493741232461504e-195", "2.0607270900137782e-108", "-182.40932567878747", "1.2559587476334784e+162", "-2.531407189072907e+59", "1.840114636942844e-70", "2.614916359111855e+57", "1.7716810532412995e+274", "-5.501300201212171e-228", "-6.384316675095784e+77", "3.7113415263893716e-261", "4.444512829495385e-125", "2.186392989209587e-162", "-1.991356239434913e-170", "5.336552403931697e-100", "2.3512041467883542e+201", "-1.5877205523155748e-249", "-1.6502390033774982e-271", "-4.485893649465669e-73", "-8.865709364587489e-71", "-1.525736066588014e-230", "-1.8235786084946722e+96", "5.3176624807309383e-48", "-8.386357285150465e-235", "2.072658736842711e-57", "-1.2609888910120239e-220", "4.9024170888845e-54", "-1.0340741863302503e+123", "9.125037256521099e-69", "-3.7758519542790024e-170", "1.0220423315040532e-35", "5.409785097487925e+237", "1.1733863818106808e+242", "-1.7363961481697736e+286", "-5.3164392200798e-09", "2.2553155951059117e-96", "1.0965184364157229e-239", "3.652703638712896e-89", "-1.0287918912452476e-34", "7.865887949531065e-138", "-3.5525513328948103e-292", "-1.613799684897786e+218", "1.266408983266128e-101", "2.589006314134692e+43", "-1.8350420884363729e-168", "4.6790408943228615e-290", "3.1269011617567306e+303", "2.13647308065591e+179", "1.496491090921831e+193", "1.9715686485767354e-89", "6.752682553792154e+178", "1.602430531345974e+252", "-7.426623698897968e-19", "-2.244386804951394e+226", "4.169233291290515e+273", "-9.596466892525714e+230", "-3.819967770378907e+37", "-1.0217318283209024e-219", "4.0414121197660154e+31", "2.8300697065090694e+118", "2.6783925240541984e+54", "2.172022876409093e-131", "2.5730605776109167e+200", "-1.4044629753669689e+172", "-3.0940275244243617e+66", "4.485832244486958e-218", "2.4722280773682003e+235", "-2.4557793440361236e+129", "3.409017477219738e+117", "1.0469011518928503e+193", "1.1894279891028248e-12", "2.152638879712551e-41", "2.402539875147921e-247", "-5.946895595575629e-151", "1.5105624783475462e-162", "-1.5749474996115624e+154", "-3.242425323008618e+61", "-5.590094819989803e+237", "-3.8079161301944725e-272", "8.851746655420514e-175", "-4.234095338363023e+281", "9.627559155813256e+228", "9.500783475759118e+61", "3.1595512062127685e+121", "1.0437679209603693e-296", "-5.530909081226657e+30", "1.1312454992012532e+251", "-1.0245039909427376e+217", "4.204423085515754e+175", "4.640477932062335e+302", "1.5877078197316572e-50", "6.524303655412874e-298", "2.590647842738372e+114", "1.3759014724757199e+234", "-5.791121759181567e+263", "1.4943528152335255e-87", "-6.873411367750345e-220", "5.9510621913194555e-288", "2331419918950.1587", "2.141352556465473e-184", "4.419543088288063e+21", "2.683104518374202e+211", "1.621029204303488e+178", "7.375758885407313e+107", "-9.886424325173193e+75", "-1.0475943440460156e+288", "1.155502488222782e+66", "1.2190559786496575e+183", "1.289031240517221e+120", "8.574108183574373e+172", "-3.202970449720949e+130", "2.4078502864238535e+102", "-4.2837443366418344e-10", "2.0719058314962162e-65", "-5.3284805190721286e+156", "6.157477312543779e+60", "-4.5833982157569665e-244", "2.8940039192161604e-126", "2.2191694030053365e+206", "-2.5821262470976883e-187", "2.010490200040547e+124", "-8.651069440258951e-301", "2.6744284536426083e+192", "-1.3018468017443424e+188", "1.469524825713759e-19", "-3.377395047790344e-295", "-5.6265033726081275e+73", "1.4334975842182382e-297", "7.57836056172829e-128", "113004017638864.77", "-6.735054104871773e+143", "-3.407009697759966e+208", "-7.272428337794514e-215", "2.5885377245706523e-96", "1.8890125271002676e-145", "-1.6511766539417258e+132", "6.500728697493166e-109", "-4.0861130574714515e-160", "-1.0923092295933148e+260", "-8.153853632545997e-280", "2.3755162152566605e-210", "5.252585687125554e-64", "1.0606757053389691e-183", "-4.317961073716451e+141", "-228410541047.36325", "2.536169677019696e+302", "6.768578912625804e+46", "-2.507745715500072e-239", "4.490421096167811e-231", "-9.190188964191398e-26", "6.747511313946092e+103", "-1.5641304576755065e+296", "2.0269047172943138e-115", "-1.3501700115512788e+169", "-4.8955946342515976e-287", "1.172284218096363e-118", "-5.1669651333166815e+296", "3.5425317144289946e+17", "-2.080621030583131e+185", "-8.252443322767123e-307", "2.6212074359510037e-217", "1.9699369601420764e+16", "5.491532330847888e+177", "-5.007363392870384e-276", "-7.962176434909182e-281", "-1.9423680679480995e+259", "7.307265286612785e+284", "3.781324513914723e+50", "-1.6230320410541455e-193", "-9.936850971206974e-64", "-2.82019551348531e-294", "-2.4410578054070454e-300", "-2926.6457123402884", "6.042579182509423e-10", "2.5374616580141546e-138", "-1.1975485735967393e+306", "1.0390274323394712e+51", "1.016648065529673e+259", "2.573279424647655e-300", "-4.147923323959845e-255", "2.7897145420514245e-53", "-9.136243225203284e+194", "3.1309899450072136e+285", "-2.695233297073471e+139", "-1.9615139273807283e-58", "-1.1201646359295765e+242", "-6.596311556092459e+71", "1.8011272120884093e+144", "-1.5713290983809417e-49", "-2.4620879908181603e-42", "-3.756134983797939e-124", "1.620967404263879e-261", "-7.690956457746694e+182", "6.007263585261658e+214", "-2.7111324847034232e+234", "1.3774516379033573e+26", "-4.2247999592032504e+291", "-1.3219389187300022e-251", "-4.98876169549295e+97", "4.213121627026838e-151", "2.411853722903365e+23", "-1.6459038547853027e+51", "4.141939288805442e-17", "-3.1769565890489124e+35", "2.216816087493276e+261", "1.4245658533452955e-78", "1.1930138496543048e+229", "-1.4368957503345657e+235", "5.161330156805906e-278", "6.51128306762042e-295", "-2.473727821092897e-31", "1.123432872099591e-196", "1.3523961670962703e-12", "1.547794566950934e-09", "1.2699220868682576e-271", "1.8967787039158823e+69", "7.884117822338364e-13", "-8.260410589585516e+52", "-2.8405104247326403e-235", "-2.2265592831363773e+193", "-5.599065710852749e-124", "16070839.406999731", "-5.629794675653228e-289", "-1.326660910940601e+66", "-2.8528321540629853e+250", "-2.2621025084111532e-166", "-1.7731545126861232e-29", "-4.4456625473339634e+303", "-6.51998826436472e+115", "6.284636006320194e-123", "1.3621709716574986e-237", "-2.396387207884395e-195", "-2.3951773103816147e-304", "1.8467394729680133e-77", "-1.8952411512160493e+191", "-3.3102535186623193e-257", "-6.00672784514226e+165", "4.177859470993524e+35", "9.964179462628055e-132", "-3.019710547496613e+292", "-6.460365140107184e-154", "-1.1184310933128545e+124", "10125.412163163053", "1.5440433401398414e-271", "-1.0120765438251467e+173", "-5.439784282104974e-95", "-2.8034977479745806e+38", "5.814439124637735e+69", "-4.142227359164115e-05", "1.2914807266531814e-297", "-2.395085025900621e-214", "-3.866611870627138e-214", "2.392301554249851e-186", "6.794224910763278e+41", "9.125953251305513e+201", "6.390068632572345e-221", "1297670.953305191", "6.374026718662583e+146", "-1.6173121877076386e+131", "9.974796161365559e+283", "9.14964598015498e-40", "3.71224045502964e+165", "2.1351587137120997e-198", "1.7941654230407098e+197", "-2.857719070363326e-291", "-1.4702341266181613e-294", "-3.7087438176004123e+71", "3.2697149611368086e-244", "2.5664069649386822e-85", "-6.824993874539666e-50", "-1.7058004324310837e+79", "-2.1587966075042606e-284", "-2.2725814938327316e-164", "-1.499558344228586e-81", "2.033240000326808e+36", "-2.3883548341071077e+122", "7.447923389554071e-200", "1.4119627420537279e-38", "1727.7268414353953", "-4.0769425640687214e-143", "-0.002852582340231081", "1.8702140802753548e-112", "4.2345640375197355e-248", "6.061923249787738e+219", "-4.876681923373483e+156", "1.2682878559609436e-113", "-3.4921903696293687e+53", "-4.731076438024558e-267", "1.2882434271287214e+150", "-1.0211141244531089e+36", "-1904686.6808181829", "9.874348056859114e-247", "2.4458340532030694e-251", "4.3280462931311486e-274", "-6.1603666380410026e+32", "1.8491477206073495e+259", "3.6448068488781893e-34", "8.385595730371139e-299", "3.583499356972461e-06", "-9.430946234115297e+234", "-3.6834950076255207e+251", "-1.3448579394362645e+141", "-3.8097188656247774e-252", "-7.708644943249674e+227", "-9.33404309365889e+144", "4.377962149830342e+196", "-4.8530211456450806e+42", "2.4947004796779613e-36", "-4.567057315989818e+46", "6.640421102197562e-289", "78445077513241.48", "-4.156878606643522e-81", "4.3757517755599474e-134", "-4.12480700508264e-234", "-6.354009619969606e-167", "-8.18631298406145e-200", "1.9647315134536526e+27", "-1.148924580322887e-256", "8.320523803432202e+77", "-1.3909295231458754e-224", "2.6920865155096934e-89", "-5.550898614710221e+151", "3.8840904942216134e+271", "-5.8763152906032805e-176", "9.813074358134237e-20", "1.134696333630419e-19", "2.1143204333248426e-278", "-2.818285465845291e-167", "-6.575638315565483e-101", "-6.640888495844116e-205", "2.4072959077244175e+31", "-2.896869086871167e-192", "-4.2437702231583423e+92", "-0.00010360290000784199", "7.842992738522374e+102", "7.155055808726304e+134", "-6.31126712384528e+59", "-9.497169981624497e-179", "1.0328018059946926e-74", "1.0161389635780683e+287", "1.7001262243116404e+21", "-1.2708189375521487e+120", "-4.714692104365678e+163", "-7.050829213255396e+79", "-6.081075017131906e+40", "-5.978540768007129e+80", "-5.244986121600117e-141", "2.692339525155969e-87", "6.051164923728615e+96", "2.5293896376509696e+231", "638693573.3240124", "-1.760932567897052e-129", "4.819588118997683e-219", "1.0540621907372332e+284", "-9.336706993120045e-291", "7.091324010558259e+51", "7.937390676394756e-250", "-4.229738579502559e+244", "2.9313685690987096e-140", "-2.3529550225911526e+113", "-9.102207699521254e-240", "-1.0191474561787132e+149", "2.507239559527875e-283", "-1.4815531845380243e+26", "-2715897824433089.0", "4.17664792444238e-222", "-1.0272190266916984e-132", "4.5414596869610476e+72", "-1344338.5048697044", "-2.2604185119892595e+39", "1.6369826565525712e-30", "-2.4403574178296793e+234", "1.0232364167535897e+37", "-5.160268574817023e+27", "-9.422014932167869e-142", "-6.06332641299389e+282", "-7.338451989421871e-48", "2.853790196102488e+68", "5.421899407629383e+98", "-6.518822355736133e-259", "1.3523879968958212e+296", "1.8710129106899487e+286", "2.2947046862822454e+179", "7.002476858653092e+22", "1.603526159379435e+300", "NaN", "5.177414231903075e-260", "-2.4300200969668717e+148", "8.286250413270373e+307", "-1.4229768475271118e+144", "2.0267000475429595e+114", "-1.4023361922665122e-61", "-3.1536052177285855e-98", "2.4891882347362354e+113", "-4.9886477802214896e+165", "8.996689153951748e+279", "1.1097880865727631e-215", "3.7424112933303326e-182", "-3.103942891996944e-247", "1.6145303496037545e-145", "3.346954009824795e-178", "3.635370959238353e+208", "-0.04514248310709134", "-7.47938008044023e-145", "-4.46612977931912e+218", "-2.3881577471743046e-46", "1.2125024060803974e-191", "-1.9918946057205744e+118", "1.4609215222606587e+274", "1.5349226687181333e-169", "2.9412105230057476e+212", "-1.6123309344852606e-270", "-1.6042542326909943e-16", "1.1900560019258799e+210", "-8.20273378552781e+229", "8.288829906169034e-172", "-3.259415091750426e-37", "4.613441178057637e+61", "1.638098960235744e+162", "1.5001845570985454e-304", "1.879262927965627e+19", "-2.7063934041460024e+35", "2.658749792949521e-51", "2.8026867211747318e-269", "-4.256278041522368e+109", "-1.1306683718171359e-135", "7.724059030492184e-37", "5.894945795422249e-62", "-16842215660698.154", "2.0675775619027006e-196", "6.577784422403408e-183", "-2.352995002595578e-67", "-4.756403325391541e-35", "8.601483080271107e+198", "1.7693020982474067e-184", "4.285761456537954e+193", "7.485926353789234e-222", "6.49470391279984e-162", "-6.929781085415985e-39", "-1.0361661799537034e-33", "2.7553858417071526e+92", "1.0156951851396778e+65", "-8.288678377097739e-179", "-7.990160798315309e-70", "-3.640656121122903e-295", "1.5708929373556734e+244", "10150810908.320747", "-1.1315899624097984e-213", "7.339959354421781e+124", "3.812719162678602e-06", "-4.273588368877033e+256", "47843.684172599584", "-1.2648642052070817e+238", "-3.719380717312021e-78", "1.5250775505917253e-67", "-1.386361073085749e+183", "4.4799773430730787e+297", "-3.923614034319496e-200", "2.169294847614884e-269", "-2.810068586655972e+212", "4.2806620208661755e+89", "2.309608840954333e+268", "-3.8660754995958198", "2.9122939311172555e+291", "-1.8688853819005593e+305", "-2.503289334508193e-150", "-1312.9840768985625", "2.820792112473097e+305", "-4.517682939579718e+250", "1.4287599928762372e-274", "9.471203073481004e+70", "5.481746984380088e-232", "-1.028272682959671e+41", "1.071735768914333e+16", "1.7018093075134612e-283", "-1.5629895028745864e-109", "4.251503175396615e+252", "-3.352230330097545e-150", "-3.1419427582215096e+264", "1.4117397967979963e-224", "1.1702199457325087e+157", "1.1742139569207875e+99", "6.257896117732255e-248", "-4.412436613417896e+244", "3.1632997615194354e-267", "2.319513592726612e-50", "1.4324767318793002e-208", "-5.284930228534759e-90", "1.6625221741109162e-223", "3.499683104523618e-18", "6.568119492253978e-38", "-1.334345957806657e-53", "5.221442902925825e+104", "-2.269054071702529e-160", "-2.747934907238969e+98", "-1.70109161180177e+73", "1.0570459578533584e-26", "1.4933933007011573e+272", "9.196132997349083e-216", "-5.892432815209948e-286", "-1.5014539664206183e-216", "-2.745248193427454e+133", "2.5248393902494683e+53", "1.4427484252841549e+190", "3.551412054626809e-92", "-2.6784080549786573e+223", "1.47565264222007e-152", "-1.4935858688762066e+89", "2.0948524878817693e-194", "2.738801100349723e+132", "1.6906130787818993e-84", "9.766056577109671e+116", "-1.7300380964436974e-270", "-2.3793781618113767e-192", "1.3130312483343682e-278", "-2.2549448139291362e-186", "-1.5944926394406484e-198", "3.883261574409889e+176", "1.5402671631851988e-189", "-6.97156656023303e-172", "6.952637644423853e-127", "3.2546328116823045e-237", "2.01390224225406e-66", "-7.786547592746291e-149", "5.8693952128076705e-121", "-2.702223481241233e-93", "0.007362884467300422", "1.6964510162212337e-177", "-1.359298681878293e+86", "-4.321401802004083e-134", "7.426103994198568e+225", "-1.4273600505117728e+107", "-1.789670887690257e+296", "-5.805012230638906e+80", "1.312366226050483e-189", "1.459904494078432e-64", "6.464586254266653e+67", "-4.581119536193374e-90", "9.477429751052229e+90", "-1.0189584065224844e-237", "8.932119527993615e+264", "-7.503942749337009e+162", "3.122742427134169e-27", "-1.2406324706677419e-191", "1.2537790870563693e+237", "6.240451301303701e-70", "-2.3517311695446202e+55", "-7.384297450611786e-100", "-9.177782435412553e-154", "-1.6516927858795805e+77", "-2.869402955806779e+113", "-4.973214580521471e+89", "-2.616819101866882e-19", "-3.8980365781246664e+124", "-3.4287509028094533e-190", "3.0432188780879757e-243", "-6.181099045463568e-35", "-2.5262256075554206e+259", "1.3185459487605186e+215", "-6.599714230459394e-241", "2.704964183846699e+280", "-4.3369231919037295e+165", "-1.1283146660330008e+244", "-3.990333734031839e+258", "2.4074501544211996e+102", "-2.937124085902698e-240", "-1.0057686947354715e+107", "-4.265231320019217e-247", "7.276379484060619e-146", "-3.360851499817018e+31", "-1.5725114668094047e-233", "45863759.84853055", "-3.708930587441273e+237", "-1.0931131834203312e+191", "-1.259778401930293e-279", "-1.5498665749545826e+204", "-5.4402452797761576e+193", "5.217552103606046e-154", "-1.1041268661703919e-288", "1.7093653443095055e-171", "-1.3319398077144745e-122", "7.001268722201368e+40", "-2.3138500439708245e+130", "2.561476709176367e-130", "1.6093246751430819e-217", "7.318463019439402e-42", "2.1724097559859677e-126", "3.7085648544038884e-100", "-2.4062286552639594e+47", "-4.532513854787148e-196", "1.8962938357372387e-208", "9.96555525563244e+279", "1.8421298598462394e-246", "-4.428613087419503e-59", "2.1358476606590318e-134", "-1.1351139760235113e+283", "-2.1520938499295132e+136", "-1.4364210783911871e+308", "2.66256880184827e+198", "1.4064038936361437e-99", "3.358580703126137e-167", "4.3850906008993293e-134", "1.6866396809129094e-10", "8.408810938510058e+155", "-1.5021084563895858e-50", "3.570468133548144e-101", "-2.5228576262983215e-226", "8.27085368459315e+213", "5.152113596385657e+148", "2.6045481148125738e-166", "5.2764253232324e+38", "-7.205292026039365e+290", "9.143401954544112e-198", "2.3723851611025493e+78", "8.884257378577945e+88", "8.590245071963504e+173", "2.9234342392049097e+272", "-8835880976946.531", "4.792597463412882e+213", "4.059962265764368e-96", "8.717699913961524e+218", "8.079421936784366e-115", "-2.9556402787906527e-24", "3.496335322308977e+282", "4.947338567246235e-303", "-1.1123715260047873e+235", "6.758185640236459e+126", "1.0383273612579707e-269", "-4.3802447431256454e+74", "-1.0792881876463415e-156", "6.707809402901626e-30", "3.5195369446482517e+134", "-2.4768885405240293e+173", "1.7215051942062144e-73", "-3.289934593196176e+243", "-4.043948434659255e+209", "-9.125532628487878e+197", "-5.688197803951172e+173", "1.2355092377900638e+238", "2.2355629705604958e-215", "-3.027183165365357e+17", "2.57626599606623e-244", "1.354258889342769e+157", "4.3583955588258934e-246", "4.4010677690121337e+223", "-7.13141378068209e+108", "4.425153575695933e+35", "2.155262619126924e-79", "-4.8351485967915986e-266", "-1.3478348793398083e-173", "-1.3928067708200754e-07", "9.56711184387132e+241", "-3.854674110465508e+190", "3.6907845985997268e-174", "-7.523212867518976e-204", "1.6849799917852218e-67", "5.775959330131817e-306", "8.340422970541313e+258", "-2.66973216864315e+183", "-23376306892.300697", "-3.5006477130500354e+204", "-1.8177615177515859e-40", "-1.0915403419116488e-52", "2.078633560190833e-219", "6.436449312250425e-44", "-6.887645110355243e+81", "1.6181360306866526e-51", "1.940608727259317e+72", "2.451405046942013e-199", "-2.686369175685547e-112", "3.525586114740215e-71", "-3.342161460312132e+96", "5.307516631562884e-240", "-5.888308879392186e+180", "-2.0905247578773002e+179", "-4.632618734875777e-290", "-7.35908890513819e-225", "-4.0946336311651517e+226", "1.3028526459268632e+217", "-9.169604998676343e-220", "1.4793465240134258e+173", "-3.6062412288254387e-143", "-7.7838183523693e+187", "8.163970004379183e-299", "-8.29937665495672e+25", "4.233300740567531e+113", "-8559.139245613738", "5.1277624057470015e+197", "3.62659923388437e+70", "4.902841051692452e-271", "-1.4588613809213573e+275", "-15221853809031.24", "6.667680251324503e+98", "-9.268645212348697e-90", "4.1091522116084565e+262", "6.918684983396824e+100", "1.1425609184146265e-215", "4.847109411877916e+174", "4.7719953943092426e+23", "-2.751537226137315e-87", "5.802849522981996e-147", "1.773205270625127e-271", "2.7875022243500123e+296", "-8.107063431540967e-153", "-6.971035771873466e+107", "4.600853169787319e-145", "1.0832460935155397e+222", "-6.531815361649228e+136", "-1.334191971466302e-254", "-7.555433086033817e+260", "7.043164114100595e-202", "-6.950619714496458e-68", "1.810325527965227e-73", "-2.6969933733800662e-33", "-2.4142657295495257e+73", "4.136393241993479e+113", "-5.064881665247145e-298", "9.379245599072895e+73", "-7.197609769101272e-125", "3.461348851966052e-294", "-8.898372997528336e+297", "6.331559599389077e+22", "-2.9367979866227853e-95", "3.008826094542658e+71", "-6.798435673406203e+285", "1.682394820989383e-286", "-5.333231228386687e-08", "-824538907018.2773", "-1.470163835323855e+34", "-2.973423779383294e+255", "-1.7245930220806646e+279", "-1.6257329409540678e+184", "-3.717431210110811e+116", "9.402207632246324e-47", "9.597822644839365e-150", "-4.333914557906162e+62", "1.9056535828936014e-293", "1.7320046407369148e+148", "-2.492024327805476e-13", "6.284445332961201e+45", "1.657504252367569e+301", "1.0930546582708523e+44", "-4.8374477781217946e+259", "-5.220088207343662e+239", "17467608.16710668", "6.620654516561027e-234", "1.461877756458567e+240", "9.636482695067925e-48", "4.723195402985909e+102", "8.132763141116097e+69", "-0.4193343750621462", "4.959583400041344e+200", "138251467752.4585", "-1.3021930092413542e+92", "6.5818947327808665e-298", "3.5611123712497334e-225", "7.292560997295502e-164", "-1.753088650623723e+276", "-7.12345457563004e+48", "-3.0393826773983796e-43", "-5.704378036743918e-90", "-2.89224004146001e-180", "8.366968344347338e+88", "-1.5089883810216737e-155", "-5.3393262025638725e-303", "2.0963599923342494e+25", "-4.228267905352218e-236", "-2.247339136917852e-291", "9.623663837527783e+86", "-1.6903097835373764e+196", "-6.526344957937795e-215", "-1.742770292935203e+232", "-8.090253424550213e-241", "2.0231759597786343e-286", "8.063899584388757e+81", "1.303338065111688e-124", "-4.048658680671725e-35", "1.2415580152290475e-264", "4.78048098062599e-218", "3.666641684418399e+122", "-5.4310407217907066e-185", "2.0470828224648114e+58", "1.4552540247160253e+289", "1.4517775817716324e+42", "1.0421012049377786e+217", "1.5539548546384533e+192", "-3.9974084445323874e+187", "5.876417979752139e+239", "1.0611838883826681e-218", "1.3329679197666775e-228", "3.3934633425595266e-141", "-7.872996070038968e+283", "6.807002645861501e+134", "2.1937343757490662e+108", "-7.107909067942892e-15", "4.951541180110746e-147", "-1.6250415047650153e-236", "1.5570233164474987e-34", "-5.243961751813645e-16", "-2.9998513050993318e-99", "2.1534884097628987e+100", "-3.764189661802923e-62", "-8.887849750501245e-289", "-2.2736316387568872e-77", "-5.688119432652234e-225", "-2.0665389431251525e-240", "2.0946611407479538e-60", "3.468015177014397e+167", "-1.3623641584894406e-30", "-3.4955636360401477e-94", "-5.78535273356684e+150", "-1.1774410593333235e-170", "8.668337694398529e-240", "1.2547515076650701e-58", "1.521840100276358e+254", "-1.9918371942325043e+201", "2.3938931557294555e-200", "1.4462702731910802e-56", "-5.250231227164632e+234", "1.3916654222721593e+197", "-1.1958914539653062e+225", "6.183454442119508e-61", "1.6003507655044194e-261", "3.1262895793927086e-306", "4.780579745817409e-74", "-3.536847521767789e+243", "-2.9475893579392227e+187", "3.5138788902802187e+31", "-9.492046205728647e+134", "1.3935549443943845e+59", "1.227376489303004e+140", "-1.4971523176866642e+46", "4.1731971345315907e-13", "9.104386018419356e+233", "7.491385726580261e+259", "-2.280863668076063e+95", "-2.365116022367616e-62", "-1.7174742731302675e-150", "-7.529324282323764e+307", "2.7087075936950163e+304", "-7.870918023753844e+212", "-8.03161868482736e+241", "-7.486846814749099e-169", "2.309202693487869e+214", "-7.590255949303787e-182", "-2.840158450310422e-259", "-2.2072493776686233e+276", "-3.436219151801994e-81", "-3.433481358833622e+172", "-1.4924265835246452e+50", "-2.0891565142534505e+220", "-7.672093068785155e+99", "1.9431621102305673e-290", "-4.3299173160995087e+185", "-8.472394880017948e+155", "1.868150883300096e+112", "-2.570098407926674e-271", "5.284175333120224e-132", "1.0676954619428153e-163", "3.765197441406676e-153", "-1.4234079478626925e+82", "-3.8069366425985136e-192", "5.2753024238133314e-182", "-7.195496552194582e+63", "5.402107851724914e+127", "-7342294.196450272", "2.7407859026578984e+237", "-2.341917274317668e-47", "-1.0879438896471977e+296", "8.36459216158916e+284", "-1.6469998387311258e+224", "3.6663386134098364e-57", "-9.641691899610131e+29", "3.198457950008439e+198", "-1.0567080376353445e+269", "1.8470945585778674e-272", "2.692065368321005e-226", "-2.41088146732013e-224", "2.381697483722745e+88", "3.5517999248686393e-73", "-9.345970309405105e+255", "4.050476978014895e+55", "1.7054321904440862e+88", "-3.561520067450984e-209", "-1.7619951021597052e+111", "6.819251674386677e+155", "2.8872076102021e-298", "-2.089601050669032e+40", "8.1461329677437745e+124", "1.406629893018705e+107", "-5.542251669473314e-279", "3.686421611009353e-89", "1.1720540514451702e-286", "-6.141830032844223e+261", "-1.6056294232023765e-257", "-6.616707353605672e-234", "-3.51430451936675e+77", "-5.0645117677866925e+277", "-1.7810392505543965e-150", "6.178486388466204e-110", "-1.817911446764453e+224", "-0.0233439713434979", "9.422843924132523e+262", "4.028721183429199e-54", "-9.012057575126374e-246", "-4.802237870926397e+210", "-3.6488042213799316e+49", "1.9746672142982445e+117", "4.521625376444071e+129", "-6.974933670185014e+95", "-3.3388089932160232e+193", "-1.3290007262464102e+225", "2.763441094130761e-47", "-1.6092606773512445e+247", "-1.168178250387891e+243", "-2.457395406611156e-278", "6.686139363695161e+211", "4.867843235068027e-214", "5.04028376177376e+247", "4.678189361847713e-18", "6.70292096866885e-162", "-1.466378101038166e+161", "4.9888910523950107e+36", "0.0007232800807224289", "-8.998963287381634e-137", "2.5673296522317387e+96", "-1.8210928640972395e+202", "-5.990506560496339e-187", "3.6513596693511183e-62", "-1.2287532339454727e+58", "6.729604587997925e-162", "1.45901580412343e-182", "-2.272032982145905e+251", "-87814926658762.05", "3.563015460428604e-250", "6.753638350314544e-117", "-1.2713551431529773e-204", "9.493344774331455e+20", "4.835566785569825e+216", "-5.365336750341759e-87", "1.1663803090583532e+154", "-8.919116058701445e-263", "2.6697836426388867e-247", "749104232936.9539", "-6.751649342511974e-71", "-1.0366510777500381e-11", "-2.2225720071219377e-71", "-1.8113777556587091e-273", "-1.7792565259689834e+166", "1.6150567405988403e+175", "-7.65017029533633e-263", "6.100085739105244e+234", "2.935148352566222e+167", "-1.0274163281655216e-140", "6.751333990085589e+195", "7.417229713871886e+198", "4.519505413656541e-61", "5.487571417416273e+199", "7.223539943347923e-106", "-3.17385886678633e-35", "4021254732692935.0", "-1.3339314881599975e+264", "3.805342973508675e+121", "-3.1394184285518996e-246", "6.953850857621841e+277", "-3.8014865289753834e+85", "2.494571808300253e-187", "-1.5867808537265018e+195", "2.639567660382803e+174", "1.6635372909909178e-222", "8.46177521595404e+102", "-1.032741851655834e-108", "1.274600834109439e+120", "4.6469860462287867e+80", "-4326.020583443849", "-3.370914807248404e+192", "3.5501879739311197e+164", "-3.0441737290171736e+83", "3.6945660980688886e+229", "7.207676170627844e-112", "-1.1341023893290259e-235", "6.439255480882018e-265", "1.0616004194783813e-99", "1.477280396244214e-159", "2.7183904910553477e-09", "-7.983342969955337e+275", "3.633139605825296e-137", "1.3833563261052508e+79", "6.462653968431151e-30", "5.484535467074533e-200", "3.04743926013867e+155", "-6.014102639079126e-196", "-8.318761717504308e-146", "3.1181421172305224e-238", "-5.828753132066947e-262", "3.3034522344342144e+198", "5.413036657269683e+251", "6.343592667036566e-05", "-9.983771904952985e+36", "-1.0974181838081254e+204", "-1.0789729383973736e+91", "6.448810246665149e-126", "1.4711311967925726e-151", "-1.1628516099618528e+181", "-9.137785490736364e-277", "7.146025594135374e-154", "2.1033740821421192e-16", "1.8660218990121575e+53", "-7.692373846109341e+293", "3.826114927734252e-30", "2.3178318470972093e+256", "-6.873598206818796e-307", "5.6218210202809955e-28", "2.187122111208478e+119", "1.0114449870873876e+89", "5.707238076179116e-71", "-4.999583371150835e-279", "8.4911950219315e+64", "-2.031962272899387e-296", "1.0077614916253313e+145", "4.284825707327945e-46", "-32604154.87928955", "1.8619908830612257e-141", "-9.128269569869806e+168", "-8.785246881511227e-131", "1.8371932944826346e-250", "-7842388955406.596", "-2.647926806077917e-223", "2.3895055358912365e+294", "9.414773261902443e+216", "-1.051601412369464e-254", "2.1687550465176314e-160", "-9.872708152390866e+27", "9.031324131685693e+274", "4.406674352384598e-156", "-4.2118009586127415e+161", "-2.959800667319701e+228", "1.1291882285434604e-290", "2.1724296270147288e-47", "2.8822713374492044e-07", "-4.535335054559622e-75", "-7.559477402994433e-199", "-1.9321274605233639e+62", "-4.5574344133037334e-191", "1.8250643009012368e-221", "5.50463052835285e+279", "1.4481060353538484e-266", "5.776784965588195e-198", "4.444235979428467e+239", "9.047756276186518e-293", "-4.9553165870117934e+188", "-0.8699082699749782", "-2.7295306773948677e-278", "1.4455383151963607e+101", "1.5440239310142695e-18", "1.0439738743584172e-71", "2.631882653749896e-302", "-1.9396186128112385e+233", "1.8476788340238428e-161", "1.932320215819567e-278", "-54851959.255135", "-2.5877497620885827e-132", "-2.55171384288645e+284", "-2336.0040380837368", "-1.8741977245824125e-221", "-7.452046175213683e+176", "6.9400167536943824e-46", "1.5888010804501965e-120", "-5.4423010615334e+257", "1.3400222777203887e-78", "-1.2807184593298988e+187", "8.150299616982059e+139", "6.404866903067532e-248", "-1.174792995753081e-242", "6.616791923188546e-168", "8.047465666823276e+254", "-2.550868742977242e-200", "7.230018660747309e-57", "-1.9626380551328635e-70", "-9.230910104273336e-217", "-1170149364979120.0", "-1.193721516396763e-229", "-6.09611655778601e-199", "2.4010183413635416e-271", "-2.329632951659537e-76", "-6.000045549607636e-17", "-3.2466372644922912e-288", "1.6630458133566993e+31", "-1.5766292069075076e+122", "-6.703860618082174e+229", "7.926804881295133", "-9.084882766016192e+285", "2.9637861293985022e+280", "3.018379773240858e+235", "1.131217052643618e+46", "3.78844620149631e-140", "-2.133417616126285e+63", "-1.7380060778672986e-142", "7.432326861216028e+81", "-1.5152617967382402e-111", "-1.0161805500211605e-47", "5.896919963716305e+288", "2.4400058056022838e-74", "-2.620872784946632e-172", "-2.2351202305274433e+170", "2.1196572270622976e+20", "-5.7545284397804074e+94", "-1.6187842768063501e+268", "-1.5146749771882905e-200", "1.1657984347089312e-233", "8.952229078096582e-209", "1.8096138513555296e-131", "2.201740968014999e-200", "5.613696741288932e-107", "1.754022028782553e-191", "1.363581708131915e+108", "-1.1447241726843056e-236", "-7.066013730198812e+272", "-6.800088200015499e+198", "-1.0842534220080876e+52", "-6.566119029816181e-153", "-9.144830864816776e-119", "-2.52896231198714e-61", "5.117372092895652e+111", "1.2829747866707864e+132", "1.0569540703027363e+114", "-1.4295153884686772e+39", "5.9854526249281595e+51", "2.433278052494118e+30", "-3.485193813802333e-245", "-8.122281256268315e-186", "2.8204413002359178e-06", "3.368762175349016e+94", "7.99047609157707e+266", "-2.2130364029488534e+218", "-6.522680645421945e+51", "0.03843137815180427", "1.0181678761680949e+83", "6.12060005179323e+21", "-1.041991719128189e-231", "-4.876635930921774e+183", "7.338899358094861e+90", "-5.176384239962476e+240", "1.185749224858142e-40", "3.3425560108911203e-184", "1.1355623139051922e+297", "2.4778368290546056e+181", "-8.029368805830267e+220", "-6.456601087657327e-153", "9.094716590917837e+89", "-1.3824840594346112e-135", "1.7634597480808837e-267", "-3.5281915881305834e+27", "-6.184143072205816e-60", "1.2003264219766694e+235", "2.1449759015716986e-27", "2.2891731483353923e-108", "-5.466616725141152e-92", "2.3316412219520445e-185", "-1.1907861196703686e-261", "-2.935903246352599e+38", "7.361182944234805e-155", "-3.6761540185965024e-174", "-3.9529788194884226e-43", "4.314826681167413e-219", "2.5807768034925608e-37", "2.7312538627287833e-57", "-1.498467991652577e+292", "1.182581913004189e-220", "2388076.043055305", "9.945996559136764e-32", "-1.2872861341941458e-269", "-8.213595383665751e-230", "-8.122215796353875e-296", "-1.9947690045141102e+196", "-2.1933731750283026e+57", "-1.3548332780174633e-42", "2.867044594302087e-250", "3.418275882645493e+104", "-1.3530740237321316e-120", "-1.6322057445959386e-257", "-6.6511508488805136e-124", "5.436783576897793e-126", "-1.7451156637370987e-90", "5.354973864018993e-27", "-5.239250586703622e+146", "1.8099648334679947e+68", "5.0279338905712245e-95", "-9.3628578397912e-296", "-2.8899868029658186e+204", "-9.65377164555268e-228", "1.9243987483054115e-291", "-2.0250718648549398e+212", "3.6692873909742687e+171", "-2.452093655366156e-230", "5.3876637480285355e+60", "2.688433815776593e-299", "9.421963133910026e+140", "3.5688266582971803e-34", "1770797070944031.2", "0.003295067494996647", "-1.2225204875936813e-259", "-3.4578852729020544e+146", "-3.913678418588344e+197", "2.1181541130849335e+171", "7.212508274667649e+60", "-9.280654898395147e+132", "-9.432524996785497e+30", "2.94403861746356e-186", "-1.4308986210462648e+243", "-8.166111458347801e+217", "-2.0372253877334984e+291", "5.321963969633944e-104", "-4.414731862485346e+40", "-42168595.4851037", "-2.0246400396368348e-73", "-3.752839533105092e+209", "11210244800825.662", "-4.3998729452085534e-85", "1.7543188608296554e+92", "6.230382396665872e+289", "-8.458116843350632e-215", "-6.660196733545297e+99", "1.152655575035197e-19", "-1324.919139592096", "7.786454728277803e+161", "-2.713166528695375e-139", "-1.921498411142908e-259", "1.48483454675408e-82", "-6.986144021760244e-81", "7.120768500622703e+288", "-6.189062943517046e+253", "1.2557726238577697e+234", "-1.266015755784048e+259", "3.0231195461351493e+90", "-7.511903179355147e-161", "0.00028588235972380277", "-3.627415406787738e-107", "6.788087042495393e+91", "5.183706821373471e-165", "-1.2200467646733692e+141", "-4.171291029640116e+38", "3.856710876636522e+203", "2.1536082702017813e+217", "-3.427003678526721e-64", "-1.9332593059407963e+115", "-9.009891855261339e+32", "1.4214465592691405e-245", "5.380383858419279e-268", "4.7450191098896507e-296", "1.402933673366162e-15", "-1.8910040414982317e-143", "-5.483505173726035e+182", "2.3918654682257053e-59", "-1.6555340719780515e+38", "-2.0736747571702285e-69", "3.288152422733252e-100", "-9.596688267598735e+19", "-1.0161688114891112e+242", "1.2309492119183911e-24", "4.359486983475756e+257", "4.4059808021422786e+226", "1.0915032550865715e-53", "1.0592205159074219e+90", "-6.801314849986881e+72", "9.141706264287403e-82", "1.1201477278242171e-169", "-7.057482746467825e+169", "4.203964205143908e+120", "-8.145003200884147e+250", "1.063987883723402e+59", "3.567224446918319e+29", "6.9613001619675975e+196", "2.5951076100234504e-120", "1.9015599078300272e+45", "3.322781094828702e+273", "3.3521372123104233e+70", "3.3187122356976812e-40", "-1.619303475577152e-285", "1.092578085160334e-160", "-4.412149998048395e-43", "-3.5470297717449154e+261", "-4.087394979598344e+69", "1.348252800281424e+197", "1.6930744977336842e+253", "2.5727960172251645e+175", "-6.421698182150218e-217", "7.231878920813329e-68", "-1.858362757465076e+273", "2.789693662031876e-196", "1.8358580277314173e+244", "-8.103660389543594e+53", "-2.841387459106933e+34", "-1.8162183689165625e+189", "9.525316062317693e-109", "1.1592426966251106e-228", "2.1469927148003905e-272", "1.1168164284946789e-57", "-2.869235361161849e+265", "1.7252767065115878e-142", "7.971816226654594e-141", "-1.2501216765879273e-161", "-2.535856000023321e-242", "-8.092328621477496e-122", "-3.846529030290495e+96", "2.0930779567578033e+142", "1908693193.7907016", "-7.425386894480935e+211", "-2.316883432402963e-176", "-6.838374321629756e+111", "-9.440805627836663e+166", "2.3833970716641653e-300", "-3.393773787032939e-62", "-2.5943987930186108e-287", "9.536119378923245e-182", "-2.7283947192344493e-236", "7.23555092517749e-130", "4.61347511937125e-240", "-5.850070667055878e-267", "-1.4665920290722008e-124", "-4.0802350030869205e+147", "-7.318623223291207e+167", "-3.579148040635678e-129", "3.680628053541226e-238", "-7.688485525254763e+262", "-3.7718934268635095e-146", "-14099180524177.236", "-2.7996452420417034e+225", "9.61366252608706e+253", "-7.515795438917439e+269", "-8.890356077771099e+156", "4.219699039268021e-36", "3.590289561548958e-205", "-1.7919406568730632e+163", "2.6447382225062006e+152", "1.3370366729195742e-248", "2.929863393623706e+278", "1.8529686281354992e+126", "-8.909792315203001e-161", "-2.504974097173492e-110", "4.868632596221819e-39", "-7.083127335291403e-62", "3.311419419876014e-27", "-7.841934017180321e-172", "-2.5207289183290293e-50", "-2.1710843073862145e+104", "7.993841516810285e+198", "-1.3444059351392948e-214", "1.7712513727366336e+50", "3.6031366706595484e-178", "-2.554004778615277e-185", "1.928309040459684e+228", "-3.658498940929795e-257", "1.9641235119214933e-255", "-2.6163859291510693e-187", "1.1297391643557056e+45", "-7.139771544995866e-133", "-42404680534.89009", "4.5092178301561983e+136", "4.1415407061680283e-103", "-1.4722405251062005e-179", "4.402237566336451e-15", "1.4868117545476724e-254", "-1.601089373845409e-171", "-1.400401541468096e-300", "-1.6407649740870886e+164", "5.109484909269181e+248", "-9.464249602022776e+133", "-1.1942349429163856e+56", "-4.940570161423363e+88", "1.562923537017891e-68", "-8.002770211874556e+216", "2.7244593096870464e+134", "-9.967244634444072e+272", "-2.6908478441014138e+275", "7.986154012700056e+76", "1.597302935813016e+64", "6.797369888722416e+69", "-6.3784215155067585e-201", "-3.57095359267234e-266", "-2.626443713802771e-233", "-3.945330981430009e-136", "-1.0769584701772162e+132", "2.736034768972701e+230", "6.265426613669574e+178", "-3.957531342752619e-230", "1.7454915985500024e-289", "-3.480957432045756e-55", "-6.090384482567739e+262", "-4.700901020813785e+40", "-1.9882946556015684e+34", "3.1252264155437984e-248", "-4.2329461192028395e-20", "1.8135143036638846e-50", "-1.1085756253100215e-97", "-5.503338191072312e+301", "-5.0527258453638e+22", "-2.4505082720311224e-134", "9.842012043502113e+22", "1.0415368415300009e+46", "1.722582517982771e-156", "6.2879083990922445e-86", "-7.796457473636687e-35", "3.853501536583109e+201", "4.153872489058762e-290", "-2.863216848248547e-112", "-8.42304216089577e-93", "-7.05410797956278e-46", "1.3366636611175833e-202", "8.343945537536839e+279", "9.147176070983961e+232", "-3.6950699051067185e-58", "2.1272243050383914e+172", "6.247787965078218e+103", "-1.8848283112639116e+270", "5.718595302588445e+243", "7.390941199874271e+24", "1.3152258325298732e-307", "2.116232950372525e+207", "-6.414541738718066e-116", "-1.1184288273599329e-262", "-4.642503359847694e-224", "5.845914223567448e+110", "1.9038050886397336e+160", "1.8033120921618324e+212", "-3.95511135890888e+283", "-1.3410232174740774e+201", "-8.179132996567225e-286", "1.116296734043404e+51", "1.1347117343895829e-298", "7.856974608423832e+144", "2.2574823632922115e+249", "-1.560583446946402e-243", "-5.1376789072832777e-275", "-2.661582868216914e+56", "-1.455577296853022e-148", "-2.3189991729228935e-251", "-6.27594959358242e+182", "-1.263329095510059e+47", "1.36748497237334e-154", "2.275992919964053e-69", "8.133213363250274e-233", "-5.174024674432829e+121", "-4.3117178081809165e-307", "2.720908652345563e+298", "-3.1887569270657236e+240", "3.4458621371639624e-66", "-1.3200757841420265e-258", "-1.4321718686432197e-245", "-2.5643484156362623e-138", "3.171063024991188e-85", "0.00013515974611463387", "-4.127772928250833e-101", "5.164580396156131e-285", "-3.7636702692552925e-212", "-2.7673661436550537e+271", "-2.15240637153323e+262", "-4.042200805083253e-22", "-2.2345912572573446e-176", "2.586499291697266e+249", "-5.6474596685983885e+258", "3.092380018145912e-155", "-2.5745437021862572e-101", "-6.5351986545530645e+180", "3.750520208391188e+241", "4.401460961647905e-192", "-1.2282563973464773e-142", "-644924420728920.5", "-7.285832418044771e-297", "1.846342982971376e-217", "1.6651910051001975e+96", "-5.563721977521037e-118", "-132180948297292.45", "-2.457143078100257e-117", "-0.028519472767855162", "1.0369289955092484e-58", "-1.3408275740916441e+68", "2.078524190703896e+273", "8.325339467449475e+177", "1.326977384104622e+50", "-4.5822156333857195e-97", "-1.4179840240149812e-78", "1.0852615363382486e-60", "-3.361349517658885e-66", "9.755937841907441e-126", "-6.784638341792682e+16", "7.310262789288433e-140", "7.019896527775227e-182", "-2.4626907354967067e-225", "-1.6807038556317734e+287", "2.376901824639893e-306", "7.086241165862634e+121", "-1.1415375204611231e-57", "-1.384003205914455e+82", "8.171458493590588e-26", "2.4769118660695368e+275", "2.850124596059576e+188", "4.416152827992462e+284", "3.6694977017881075e-308", "7.2714089482878675e-155", "-1.022691233200786e-87", "-1.1342172980966012e-80", "-2.97187603848102e+127", "6.96495066124513e-306", "-6.574629587556456e-138", "-8.73011231685863e+281", "2.0697422230045566e-262", "9.118104210728652e-289", "-4308496657588560.0", "-9.804432803803356e-95", "-5.381898205372417e-122", "-1.577803570739796e+22", "3.0781526385358103e+164", "-3.634091433694622e-271", "1.912614379578837e-105", "7.0811724704320865e-295", "-2.1291226844607562e-289", "3.0486748106423885e+120", "-1.1421877504451947e-32", "8.779618678374038e+101", "-1.634491303263154e-204", "-15359467807.924776", "-4.189429074083425e+230", "2.473497314983324e+123", "-1.3200038619764503e+252", "-5.310529973605345e+285", "1.9101453643330036e+251", "1.5639621784797693e+226", "9.623632224712088e+75", "-1.17528766884814e+75", "-3.53000749081121e-209", "-1.3973074462692433e-238", "-6.306281398688713e-238", "1.2276399800599022e+178", "-5.731863595997653e+138", "-2.7113868034684315e-174", "-5.2670957480783105e+42", "-7.303477716951584e+255", "1.6927486963198673e+251", "2.6411982664501503e+142", "2.6434301871912147e+205", "-1.515985511099928e-261", "5.399394415343001e+150", "2.0784867540220963e-65", "2.8088475074227667e-100", "-3.316003586039113e-290", "-5.06631013525054e-294", "1.5470144733555642e-102", "-5.144208812171524e-58", "1.1818461525307798e-16", "1.8834227069858681e+233", "7.175191167606017e+144", "1.9991600040083493e-15", "3.065277821500367e+285", "1.0948349558773508e-303", "-1.790662319956357e-93", "-8.306539092375453e+123", "4.283069467731973e+65", "2.317905317474753e+56", "2.820558877589468e-73", "2.1810901455513846e-47", "2.8156773438291227e-69", "-1.5562397267958196e+214", "115.82241811666366", "-1.0401883962895735e+233", "6.9175915264406e-202", "-1.5111393637544444e-295", "-1.8205808576424455e-48", "1.1423945684963063e-229", "-3.346664147697553e+228", "2.3177381688819368e+19", "6.979636631016453e+242", "-2.5439199105267442e-185", "-1.5085709884441086e+271", "3.335397174830717e-208", "-5.539470162963445e+205", "-3.680487446133126e-23", "2.750688500342261e-29", "5.514228675842754e-185", "4.711794504873746e+43", "-3.1882838180048643e-175", "2.699127112957018e-239", "6.35155061202949e+283", "-8.143551556192445e-08", "4.5591864602343747e-287", "-7.985954424317754e-06", "-7.750416097437253e+276", "4.546245993494288e-75", "-3.860943070797649e+115", "1.5671165174889023e-262", "5.787637301754634e-74", "-1.3424729588244961e+63", "-0.001316298364352845", "-2.0695773005347966e-98", "5.627862517625863e-228", "-1.547460394756272e-35", "2.073713948617789e-266", "4403423259574.434", "8.322362587931934e+245", "-4.8640907403551957e-241", "-4.0112846226758144e+263", "1.2512703765575034e+220", "-1.0088132012101119e-67", "-6.635615036289917e+202", "-7.033565707591121e-206", "4.47208519547745e+169", "-6.915222129719902e-211", "-1.649985337664262e-26", "-2.569192704151614e-244", "1.8605164471947995e+249", "1.6770537446571897e+177", "1.3187305640052295e-281", "4.130533715476591e-271", "1.0440055768093002e+286", "-3.62878429042134e-132", "-2.130858031744151e+275", "7.494369677356833e+297", "1.0273439183239692e-282", "3.919496071884341e-280", "-1.0559779635235147e+267", "9.605230692415809e+162", "-5.5310160140439664e+26", "-9.226916844479003e-135", "-1.3010207114715514e-306", "-6.312587378147934e-12", "-2.320020797352669e-216", "-1.44240513965835e+282", "-1.0937020597730734e-160", "8.232898251943382e+19", "4.4655988935459125e+196", "-9.701141992476085e+102", "-1.1836745199287745e-18", "4.707392785615725e-98", "-9.198897360229677e+184", "-1.0843108160864926e-268", "2.9053350169112892e+215", "3.912618374856039e-35", "754474452.7466435", "1.6992122501820752e-76", "-3.6255891391115703e-97", "8.164138894233773e-152", "-4.700329730369299e+219", "2.23681061157696e+276", "-1.8233786397378243e-199", "-5.26886921924167e-298", "-1.5398226627981013e-25", "-5.770270138825527e-131", "2.0392250005362051e-100", "-552884696164628.2", "-2.43221564921984e-228", "3.468163778951305e-231", "-2.131938329429948e-88", "2.897521888328631e-95", "2.706865463225119e+125", "1.6914929311029544e+203", "2.1112556383136947e-183", "5.470546005446529e+244", "9.279429541461322e-240", "1.7507272379482062e+179", "-13776328.166750226", "1.689260092953537e-28", "3.5790744891807305e+217", "-3335173699897.126", "-2.5203777825503516e-271", "3.830088289091212e+120", "2.1286968761799226e+20", "2.385957533849418e-184", "-1.0885392498364481e+81", "-7.28137109373442e+296", "-2.9383833678992366e+279", "7.285991199853191e-289", "-2.6570942305198304e+239", "1.2316321216366757e+202", "-4.920466408121359e-122", "1.575612594404898e-239", "-1.3210549501802127e-125", "-2.887596046107851e+204", "5.201925864232684e-165", "6.60205888492936e+258", "3.6580265083471475e+180", "-4.619231616693742e-87", "-4.8903930140541315e+264", "-3.928740157414625e-290", "-1.5612209725557686e+53", "3.8734930759750488e-199", "2.7384105672059525e+283", "-3.5758787825606353e-196", "-4.2241802695301806e+237", "-6.412978146132116e-252", "1.2390858094862877e-126", "1.1627151855746658e-172", "-4.100324548046206e-180", "1.426471016124441e-289", "-5.540254075956229e-72", "1.7465508211473472e-174", "-6.220093866195871e+296", "-6.924551245875157e-98", "-1.7298559970189922e-152", "6.738816070579239e-279", "-1.7722762624723966e+67", "2.347339633589777e+248", "-1.1806131647441455e-47", "3.013670212765773e+152", "-2.970231432365413e-195", "6.442903396247781e+75", "5.270327420955224e+87", "-7.021972175285601e+50", "2.0481411600672822e-262", "-9.267429784932555e+262", "2.488338123291821e+92", "-2.410554793186403e-183", "-1.5879023249940666e+305", "1.5920718843632862e+245", "-1.470083405744e+206", "-1.249534435184869e-48", "3.3739922308638367e+283", "1.8214404190630796e+113", "4.7260131739949704e+198", "-3.254100421691358e+237", "6.263043343725858e+60", "2.4276631971139807e-255", "9.037858231495863e+136", "4.488970447616998e-286", "4.447093907843621e-135", "-2.1514709701954008e+182", "-5.757360100643112e-205", "1.7389062296319436e-73", "4.725695235299144e-199", "5.7326317327058824e+252", "1.1145143654813883e-131", "-7.349241970134414e-183", "9.776855518847378e-269", "1.8468104007954544e-71", "-8.385813536734479e+253", "2.715668827013996e-49", "3.052865841742036e+226", "-3.7378077969638654e-92", "3.1401445357462777e-215", "-3.1706616868234703e-204", "1.4237851467013359e+146", "-3.8900317103163485e+305", "5.697188756847353e+192", "2.4934927127327163e-107", "-6.74251895529024e-172", "4.5449430353300894e+142", "-3.592680684089194e+194", "-1.1291347371662693e+152", "9.373705745142685e+217", "-1.6429114216803454e-146", "7.650354436193234e-262", "1.9766881162718704e-223", "1.7995949986704017e+53", "-8.776230962678395e+73", "-5.6252645781323954e+256", "3.998735319405673e-166", "-3.279263596273951e+126", "-1.5822127663982237e+45", "5.122187244687846e+228", "-1.2704023008698982e+239", "-1.292035966558676e+94", "1.4959158712072013e+275", "1.2997283825642008e-86", "-1.4912607615592599e+256", "-1.6520505704028542e+164", "1.4461636839420027e-284", "-8.606495923282802e-241", "-5.009961820215513e-27", "-6.789680854306074e-244", "3.003347283701127e+21", "-1.5721268647653846e-13", "-4.044704005322285e-114", "4.870389974799292e+141", "-3.063993214707586e-80", "-1.776956527629071e-155", "3.7369820810246736e+216", "-1.7477064451199342e+298", "2.8009827942141876e-188", "9.67491896908788e-231", "-4.400105855217379e-83", "-8.506975308134214e+23", "-3.265840920279624e+261", "6.855736529278026e+29", "-3.575976514932537e-146", "2.485925014986127e-174", "-2.1749767991505672e+233", "-5.6764345162581864e+191", "1.1445469094071032e-242", "1.5759009342250537e+156", "-2.5561503622893798e-26", "4.0241913855267935e+149", "-2.829162939028424e-232", "-2.3767278369478717e+246", "-3.1794492736848657e-258", "7.531396316446306e+241", "1.0476407076405603e-70", "0.0014214676326388515", "-1.17113059989676e-94", "-1.3755929784825548e-253", "-0.22339563692164288", "-1.0999723128265963e+186", "-2.6152923500871377e+199", "-9.886488649075075e-172", "-1.3058944772910415e-110", "1.9833287570910004e-203", "1.2153549590763951e-141", "-1.556540230549621e+41", "1.3621411590858197e+286", "-8.8504889716769e-174", "1.4003316952559992e+101", "-1.1170239547608079e+68", "-5.261461284715635e+253", "-1.7147338612762949e-258", "-7.203219161495976e-85", "-8.28608249221711e+172", "1.422694165484762e-236", "-2.6061617049352446e+175", "-3.540355701290993e-60", "2.121174352810433e+206", "9.017027734514591e+86", "-9.826362766041952e+51", "-1.1566200567433259e-298", "-4.775140954575833e+205", "-8.35828474375012e+29", "-7.719746771105524e-140", "-2.4595377652447603e+138", "-1.388811570744502e+262", "-3.0478835061610483e-207", "-1.3665419313987955e+137", "2.230955476182495e-199", "2.9976848892246623e-144", "4.239949937643099e-287", "-3.4385426085274607e-18", "1.8703775568395478e-276", "-1.9933928162761242e-237", "-2.6936219560299146e-89", "-1.977541074422499e+43", "-3.3528446731398277e-236", "3.815996624280859e+296", "4.0942125888396095e-239", "-8.915516616870421e+172", "1.1615738599447382e+62", "2.7997212073831903e-308", "-1.126876780149226e+255", "3.331561017619624e-110", "-4.989302861445052e-38", "-1.6544527037500624e-259", "1.0433237245362619e-23", "3.071181514413455e+251", "5.030938271587827e-248", "2.495544269104194e+26", "-1.1639638096813188e+257", "1.0401335947443117e+128", "2.158687348788446e-06", "3.453691688880846e-47", "4.135752033066676e-216", "-2.3800037038516416e-281", "4.824707962997472e-125", "-1.4482966822957265e+38", "1.7669185331483869e+118", "-2.1290839238458787e+80", "-2.03608974295224e-106", "-1.2968221431467056e-232", "1.7617055004034298e-67", "-71933.61282394745", "9.472977564741164e+242", "1.2593778098414259e+206", "1.0309860937711248e-283", "-7.186203217222067e+19", "-4.716143762535985e-247", "6.813414613876326e-201", "-5.083761732653061e-306", "4.523891964677103e+276", "2.7601542381850928e+175", "9.84732362702465e-279", "-7.18660054055874e-89", "2.6239819783640427e-51", "1.5172567775498796e+85", "-2.4027850161488328e-107", "-1.1789247489828099e+140", "-2.6163833743779624e+131", "2.7996318326379485e-25", "-9.55068428916554e-90", "-2.795502953576166e+291", "-0.21631430478924446", "-5.624893252201016e+31", "-1.3757826515927562e-198", "5.701652025575705e+283", "-1.8237348410796974e-123", "4.265825018332853e+253", "2.234295733058951e-105", "-2.821386757369126e-225", "-8.291525687243278e+102", "-1.666099582918775e+251", "-3.251663206415283e+143", "7.107620169268023e-199", "-5.27819051195719e+183", "-7.081767827337632", "4.937946401839697e-216", "-3.107661036639902e-260", "1.5401490698170763e+265", "-9.979654655400105e-141", "1.0352991502796564e+47", "8.370358761298612e+142", "4.573135165830334e-194", "1.9926213575466652e-234", "1.1162895440833792e+158", "7.620202328560768e-59", "-9.287432532069383e+139", "-7.990679910658655e+28", "6.430142730221935e-263", "-2.3700360500168334e+172", "-4.2115580937952898e+217", "-1.936013613354686e+138", "2.3226796523324247e-125", "-1.028606718510925e+31", "3.4041292992721143e-242", "-3.3235915523054988e-226", "1.7812551679456592e-73", "-2.593981520161423e-148", "3.9200944619785244e-140", "5.243127674353118e+120", "-1.2961167104007971e+116", "1.417986050100114e+227", "-9.825211992278428e+112", "1.0663278949420007e+181", "-3.595796367349121e-226", "7.948026997384208e+148", "8.116833604192064e-308", "6.724907167595603e+109", "1.5316766439068983e+286", "-1.8282137144923303e-126", "-7.225414408379776e-34", "-4.200259414846143e+59", "77837888.6838076", "-1.6995861579890716e+219", "1.6671734812964729e+171", "-9.296578850553376e+44", "7.096697232249097e-50", "-3.6929611186959136e-231", "8.182916347301786e+298", "7.013857303024022e+261", "1.1928859346192216e+213", "-7.357570632137172e-156", "-8.411273303805841e+70", "-1.0721631228704666e+277", "-2.4318809232371708e-290", "4.2784047435255654e-136", "6.771085856681996e-294", "4.10924048010518e-253", "-2.220488357685428e-243", "3.949635866522964e-287", "1.6695610787440058e-301", "-2.0451614232772886e+206", "3.3638468079101903e-25", "-1.7069769164367006e-221", "8.74397134293819e+97", "-2.858126010183707e-249", "3.2915000819430885e-258", "1.551148999745968e+199", "-1.3600847385545741e+247", "2.8151636119601294e+288", "3.6166650051929027e-69", "-1.1124454135603856e+106", "-1.3263399542883185e+97", "1355807.627445635", "3.281570562943852e-09", "-2.267938322266823e+93", "-1.480774938467604e-236", "1.3808304221900693e-250", "-3.234524601541265e-27", "9.868581422210193e-152", "7.673772952448324e-90", "-2.8825259640282474e+61", "1.8744429884845357e-147", "8.180806683799798e+138", "-2.6001407507175152e+262", "4.4975567382910025e-306", "-1.8416524938857742e+271", "-4.037753388746549e+98", "-4.185657816180672e-07", "1.0249487119031098e+94", "4.598521508271008e+286", "2.297784402619399e-101", "5.909152123644234e+93", "-3.1812009032261625e+52", "1.301574682428829e-81", "4.578460796350193e-290", "1.29721223694228e-299", "-2.4486854531517454e+129", "-4.763622801084769e+304", "-4.661330421974173e+298", "4.1921379013731935e-294", "-4.573409457629662e+80", "5.545345941329375e-08", "-3.5926052646071904e-205", "1.3636191534540324e+289", "-2.9861306061828346e+296", "-1.297985648298373e+277", "-7.442770489277331e-103", "-9.833056272518546e-163", "6.105628540359666e-108", "1.0492289675331861e+68", "-8.577506700741801e+130", "1.6905295539821518e-236", "-1.605614715780006e-101", "-6.396279374009174e-248", "-9.124667602002092e+121", "1.8325811495226888e+99", "-1.5549221941939072e-06", "2.3897798752874038e+138", "8.189839500827135e+193", "-1.8562970285193452e+101", "1.961878192153354e+257", "-2.140249788321324e-72", "-1.3577300285943397e+92", "-3.0974687665840895e-293", "-2.3574672291873105e+104", "7.571539712655057e-27", "7.472544972222521e-14", "-6.866055439694837e+161", "-2.049529210881025e+306", "-3.11826597667438e+189", "-9.00157623621128e+204", "-2.5377264216397026e+71", "6.751626538639311e+77", "7.158106761815015e-161", "-8.360521680415092e-185", "7.756298857345357e+72", "6.324289705906463e+272", "-1.97836200487237e-163", "-6.482419870897955e-80", "-4.7732695604544743e-101", "1.7861385350190745e-80", "3.941670779057936e-62", "4.515939968900247e+226", "-2.2047357286108793e-283", "-1.8499957336303927e-148", "2.991910866551446e+220", "-5.208095743907462e-60", "-2.373531920581699e+106", "-1.1724120445605988e+116", "-2.673117493150116e-303", "3.1625410663989336e-71", "-2.533264147635097e+264", "-1.4085531150411323e+45", "7.014304308161793e+236", "9.910369375829997e+197", "8.246477795011742e+39", "-14.250546540752648", "2.4638794504206843e-67", "-8.428599538367238e+265", "8.513920480889875e-140", "-1.265117134831838e-185", "-3.3477031090811613e+112", "2.413129513899282e-119", "6.114289764303016e+66", "2.7991317907142655e-10", "5.2306677126510745e-14", "1.528513376718278e+247", "-1.6143148483690533e-294", "-2.8074028020218327e+219", "-2.4628022250080454e-180", "6.147916888878995e+242", "-1.772879096934481e+156", "8.585051952130896e+117", "-3.100663802265159e+49", "-2.8614171513856886e-183", "-4.6671325431158795e-182", "-6.954331020345569e+136", "6.2494658897815285e+143", "4.7553016171387427e+200", "2.3569486349677716e-283", "-2.966618661878415e+247", "4.653784201582628e-123", "8.994599156367682e+141", "3.5311038264284838e+28", "-2.7851308253640942e-111", "-2.546792419475138e+69", "6.724522533660698e+34", "7.66705288317749e+304", "3.4681050187378132e-12", "-1.5443657445297148e-198", "4.485114137245191e+259", "-1.896239225241441e+212", "1.1807525577511939e-11", "4.6523518952962865e+278", "3.514863160174668e-126", "1.9511521925751844e-276", "9.409256020316e+30", "5.372394917054076e-29", "-2.904055969740621e+173", "5.978148324580367e-156", "9.312292441185199e-262", "-3.671213569637747e+104", "-9.848729554602191e+209", "4.4520391388850105e-66", "4.601112035620407e+201", "-4.357836962101527e-287", "2.482021277757644e+233", "1.3895619767999533e+239", "-4.063452515797981e-212", "-1.7993391267410141e+161", "4.0494452451848217e+123", "-2.151637075334865e+241", "1.899800245229974e-122", "9.40496704255153e-268", "-6.753452821125656e-193", "6.684117050857163e+143", "-4.750133893077683e-89", "4.298435336422115e-85", "-1.0354534872281447e-298", "-4.2698421575364606e+43", "1.4534825460941654e-214", "-5.419451919718718e-46", "-5.3988572525929166e+185", "2.71724695151968e+185", "9.56199131431011e-284", "1.377193653225794e-282", "-4.7334235610373175e+151", "-1.030983088989496e-200", "3.1653062962910475e-08", "-2.7812595727497478e+35", "2.8015918187665785e+108", "-6.522961211971426e+74", "-1.4827617206513157e+200", "3.2447349135805346e+51", "6.5222348957882455e-50", "-1.1927645556042628e-97", "4.025745967278333e+74", "-5.709123943143456e+230", "3.956811727566249e+55", "1.8009429509251122e+202", "-3.5141497440625935e+292", "-1.521177560814419e-94", "1.5680209512452038e+252", "1.0912268487979087e-98", "-1.3480623853612598e-87", "-2.9361327986710535e-53", "2.0903253431044456e-195", "-6.070460888077066e-197", "3.2022001219509398e+296", "2.378839620532048e-129", "3.1392166888972766e+209", "-3.769863079110795e+97", "-7.776278218335564e+126", "1.024632905409325e-91", "1.0619938725701159e-159", "4.412959343100013e+285", "-7.257117062400603e-119", "-6.362951603180744e-67", "-2.905641851770138e-88", "1.6429328747944105e+228", "-1.0450662927207013e-30", "-5.104643891628911e+271", "-1.6348097601879552e-199", "9.011062059556024e+251", "1.687367964905693e-99", "8.294677338558383e-181", "-4.138812736981577e-201", "-8.238113510863373e+268", "1.8833486229046666e+293", "1.9293498195170125e+180", "1.9405267303164964e-200", "-1.812426424614407e+79", "-1.9710371485874367e+153", "2.458171700566777e-117", "-8.256556093430289e-260", "1.5836375254965044e-22", "-4.347311572888191e-292", "2.8368029355261065e-252", "-9.994968670741558e-130", "-1.0798860702067849e+156", "-1.8840943365201387e+112", "3.1622841287322064e-232", "-9.756740195136654e+172", "-3.509787313131034e-55", "1.695455800966575e+275", "-1.2611804783606827e-219", "-3.5463521638824706e-297", "5.110383960874048e+75", "-3.7217978228548697e-298", "3.5220584073791036e+247", "7.989088664976656e+180", "3.3241517213791115e-226", "2.603379331092975e-270", "-4.1494485484556294e+153", "-7.03920374371438e-70", "3.321047699620875e+122", "3.2315717540206793e-40", "-1.4408967754074793e+262", "3.535190224087387e-226", "-4.640550698036398e-136", "8.043729374530185e-68", "-3.198947691080665e-207", "-8.757756512080587e-09", "8.810699949125527e+107", "-3.027498206099407e-202", "5.771397929412278e+265", "8.493537517286783e+195", "3.040761710331716e-88", "-2.222635631622907e+42", "-1.3038091360260573e-10", "-3.975650501110708e+149", "-5.831388006517735e-303", "-0.0002038502214383532", "-4.7498591562835825e+29", "-4.6525575740476866e+262", "3.0159159634862604e+99", "1.7764899138696332e+226", "2.5861432833243235e-278", "1.8115314700106052e-178", "1.021350762939225e+224", "-3.061731966017217e-281", "-3.2159523410958907e-286", "1.2775624326880647e+92", "4.0923462153342936e+303", "-4.640429083699113e-187", "-1.4386397546894718e-42", "-2.564964561547418e-193", "-2.080481431955267e+195", "1.1862263673657668e+23", "-9.680354803548205e-60", "-1.7526079913727093e+99", "-8.717910037050933e-263", "-1.141890886894434e-116", "2.331066508688693e+304", "3.325201606953593e-16", "6.789579067770028e+103", "-2.791930660134209e-151", "-1.6797559772950133e-134", "1.5846370695079094e+103", "-2.4097678200170157e+138", "-5.694536892151962e-139", "-4.226509575584234e-205", "1.6296939766928538e+25", "2.8747539373050632e+239", "-2.893125555773451e+174", "1.01811930758409e-148", "-2.268279031563736e+272", "7.468645828700993e-170", "535.4131848917466", "1.3568152455838435e+72", "-3.9968150643786446e+89", "2.2997650455504936e-195", "-1.585229023567139e-157", "-5.749654460338862e-275", "3.8929901684550985e+200", "3.9872868506474567e+248", "-3.2729647290102645e+186", "1670842785.6267102", "-3.054464085633964e+264", "-3.832128818643454e+17", "4.966580494310355e-289", "2.0517719505788514e-29", "-3.3478052802893003e-06", "6.528033989260555e+177", "-3197.709019428617", "1.2206284040674051e-98", "1.6752845553541243e-304", "7.209264238957295e+238", "8.430031613982343e+27", "-3.5322639585486e-17", "-5.792584331600336e-237", "-7.445424042135012e+112", "5.863369989830946e+43", "9.5927246909989e-55", "3.0426011966968996e-84", "-9.366457770509168e+56", "-6.161788477668598e+111", "-1.1767162102500987e+139", "1.4203796786052952e-177", "6.213857543032584e-221", "-1.818319012122807e+208", "-1.214036312875039e-272", "-9.286063448217715e-67", "-2.545014614153241e-33", "2.899718760398443e-265", "4.91719372961589e-190", "-9.533058330380636e-199", "1.3100952546875089e-55", "-8.407191706770851e-75", "-1.0365577128901962e+133", "4.9185520522440955e-173", "8.653382044140788e+227", "-2.3100150677247785e+198", "-7.435958582334237e+25", "-1.1771192067167657e-114", "4.853200702080353e+303", "1.7186234707428154e-303", "9.513790569312915e+136", "-3.810518014660491e-221", "4.3465572131306684e+77", "1.4079774681263195e-286", "-8.939417762374851e+282", "-1.2836877722064263e-183", "1.2969150539439297e-221", "4.76134134522221e-180", "9.513762089325273e-133", "1.6794984584094978e+58", "-3.2078383145735204e-101", "-1.2460546600518754e-214", "-1.6833220633680025e-232", "2.579120944077893e-277", "-1.2716068320717354e-261", "-3.1693864179825974e+209", "4.213900944090768e+212", "1.4701196491083191e-152", "-4.323505880694621e+38", "-3.0122108725477693e-111", "5.868313275616303e-289", "-5.633487638086528e+110", "4.468557833907728e+162", "-1.4377987209416023e-165", "-6.449763174599191e-279", "8.851709290014875e+295", "-6.347954897843334e+76", "1.4805899755673042e+149", "-4.52111884563717e+94", "2.558363544862355e-75", "6.20942414419699e+103", "4.440914171359559e-34", "7.083928120830716e+202", "23766506342389.004", "-2.2039342964189597e-47", "1.2105216462377294e-144", "2.622486553810102e-201", "-8.513256176305367e+129", "-8.808842718197857e-25", "-3.4382876557221327e-283", "9.137211441774454e+165", "-2.9034866442209997e-113", "-6.905699468667514e+43", "-1.4129684416527637e-09", "-1.9094829394056354e+67", "-1.239670277234182e+178", "1.3589420624628437e+66", "4.352141009004142e+203", "-2.0615209978144256e-131", "5.113889998281276e-53", "-1.7922359918467436e+105", "2.2877696207401155e-106", "2.159155927609449e+72", "3.652327761656249e+104", "5.274058143017582e-86", "-3.0484926675594067e+214", "-1.8563024198964787e+83", "3.3808584314499897e+184", "1.2478239129787174e-58", "-4.462548937733605e+70", "7.679071563599847e+66", "1.8943870129389805e+256", "-103064899384.70326", "1.3139482362680503e-166", "-1.3010162693923491e+272", "-9.047046143191254e-20", "8.31987607711539e-121", "-1.3014331762319867e+30", "4.242023668053045e+180", "-2.479891199466541e+40", "1.7306443622865916e+281", "-2.852626598241626e+17", "2.872283592549902e-172", "1.5029436169293364e-284", "-4.069554312299661e+220", "5.6266421713059464e+66", "4.488327966545193e-298", "-1.23521083439625e-142", "4.709963682210444e+74", "1.4521889094376718e-32", "-4.899225084037426e+281", "-1.1540550310594008e-157", "1.4815572041976825e-30", "1.0123333413108531e-112", "1.9155755838029895e-175", "2989175577448.818", "9.322923218943863e-88", "-1.4462740583146263e-162", "-3.573253196737644e+188", "-4.2819482602245484e+150", "-1.2361218613437007e+31", "-6.096145645322815e+169", "4.376889688691046e+219", "3.07963542547681e-288", "-1.3087604927379152e+113", "8.76342119212024e+265", "1.149011697365533e-194", "-1.255234394343601e-37", "-6.2088040220486825e+62", "-8.319164813857117e-146", "-8.358765943566773e+188", "-1.1061970785217241e-218", "4.614776428988252e+225", "-1.3341719510005188e-97", "4.954104104732288e-131", "3.607582678328247e+151", "7.844601484467366e+272", "-7.453256143011784e+165", "3.615516926364633e+292", "-6.163417556935999e-106", "6.717882782407382e-22", "-3.269140290317768e+239", "-6.3358822230954065e+118", "-3.5171326761828663e-78", "1.3858550838905918e+146", "1.6473177435703095e+308", "-3.2333555881933894e-55", "1.410300565351792e-127", "-5.510757478022458e+49", "6.147163405821454e-262", "4.887848096878954e+45", "-3.3023884860735344e+35", "-3.2725600118943895e-212", "-6.279618261189014e-186", "1.6716848933936025e+236", "1.1563504537668598e+105", "1.741292615223456e+165", "-2.1555461120351973e+284", "-3.410274703840751e-284", "1.208632825297392e-132", "-1.212571468822956e-219", "1.9851386912461014e-270", "-4.467390234296757e-150", "-4.218529663049706e-279", "-2.182364027933362e-102", "7.531486058304282e-237", "-1.1071878767028609e+290", "7.136820431537839e-104", "1.2047671302456535e-249", "-2.005368421469735e+172", "9.220587327872443e+306", "3.14666658522286e-62", "1.6198501041231225e+229", "-2.3436175951074583e-107", "-1.085983105012022e+240", "-8.523177636388222e+118", "8.646732392972537e+259", "2.8553205156829664e+175", "-3.1751822341773036e+210", "2.9690551008862014e-85", "7.3099219907858625e-183", "-1.627659900365893e-234", "-1.5753955462988804e+234", "1.606734930165109e-38", "1.7111223061840504e+168", "2.121740306491515e-217", "2.566204105623728e-52", "-7.696573834194341e+146", "-3.8677141054019715e-236", "-2.54204719220038e+31", "1.120446400183588e-06", "-1.3085059554199172e+94", "-1.1079382478050444e-235", "2.362504734688585e-284", "4.178341950377589e+192", "-6.927237125213572e+278", "-2.8098057303783613e+294", "2.5134139273299818e-238", "-4.884957673421496e+50", "-6.886024098729303e-93", "-1.5805322827110769e+100", "-2.6945142259973995e-15", "-6.273755058081809e+250", "-7.15367885111454e-19", "-6.753549351128326e+31", "3.069130431165847e-174", "6.697683271603011e-246", "6.922217573101017e+200", "-1.6242807147027555e-136", "4.266260051785813e+158", "6.214574529912041e+113", "-2.1926428857366514e-61", "-1.1564830525879296e-182", "4.804941077236196e-14", "6.159474284430938e+113", "-1.5329645502427456e+241", "-3.9863988584948266e-75", "1.292670808391005e-226", "1.3945460383488466e+51", "1.9607818952396216e+248", "3.085345474126712e-76", "8.437137863709256e+152", "-3.118030965053011e-97", "-1.6371572999175718e-300", "-2.376798442153272e+47", "2.1983585833890225e-198", "4.3852596178219045e+84", "2.925172135267577e-39", "2.4366076667918583e-247", "1.8498707700752074e-79", "5.3152743752719024e+281", "-6.08456881332034e-215", "-8.573963683779502e-251", "8.352351651731937e+254", "6.138273591159775e-281", "-2.923986092599195e+263", "1.2134170559696541e+146", "-4.631115949443888e-182", "-5.540785316989404e+273", "2.0722438796135748e-191", "-4.683054132994326e+57", "-1.3234950967305974e+175", "2.407837403569056e+246", "5.0302215447175965e-20", "1.858305408148325e+102", "-8.046936369017505e+284", "2.7924881080714513e-127", "-1.6659451168503707e-229", "2.8752780732062577e-56", "1.4923050535119446e+282", "1.9822918052056807e-23", "6.251212379629765e-226", "-6.6585702499544825e-270", "-7.027752110154386e-83", "-8.32755988242284e-252", "2.0006319184116312e+307", "2.451756758851443e-08", "9.838633296770118e-143", "-1.7391024045101398e+79", "3.7943315276468886e+139", "2.9391674011127454e-80", "1.1127224919883807e+233", "3.7978939087665236e+55", "-1.9562657418964757e+305", "9.175887889416183e-149", "1.0673270680073069e-163", "-1.0418635796685468e+77", "1.8103523757223515e+244", "-45492621179.79183", "7.337384422006358e+140", "-0.00014560360512720034", "-8.246145869151518e-86", "5.962665038591489e-10", "4.140907997900036e-214", "6.714604353056175e-50", "-4.759619116755163e-197", "NaN", "3.0707735518183916e-224", "-6.303658609610443e+148", "-8.999431448130672e+90", "-1.3988328090363584e+133", "-7.734921510930271e-252", "1.5582408416653006e+155", "-4.013757420291942e+19", "-6.776638205537228e+106", "-5.070097129335567e+241", "-2.8848764400460145e-235", "-5.382575745569765e-153", "7.652017011646093e+32", "5.2530081706323225e+131", "1.2135463702946263e-198", "4.571069329234511e+21", "2.487017638089032e+43", "-2.483248708490682e-126", "1.6927250560989194e-119", "-6.380226323671812e-163", "-7.192747558381393e-278", "7.876273725313805e-191", "-4.0688585139080577e+93", "1.155360525016531e+292", "6.305585739230826e+216", "-8.856843891035965e+35", "-4.6942005495282664e+27", "-3.5683902346740426e-280", "3.135740371598565e+22", "2.2149204809627224e-144", "7.546000144907933e+242", "2.1892689515459655e+242", "9.045029277866665e+112", "-2.089931773034816e-136", "6.922078723043115e-270", "12004.441556720772", "-1.422777149089092e-89", "1.2035412929836378e-286", "5.058043880126109e+18", "4.25357301834788e-226", "-1.4778968792971365e-153", "1.862863312366809e-123", "-2.986710441313458e-209", "1.033359188884112e+270", "-2.3991225481366408e-60", "9.176711091494622e-06", "1.8975149154982666e-117", "9.33815158620592e-67", "-2.8114442081807755e-118", "-1.5060481136527568e+102", "5.062993561386717e-29", "9124795.429252576", "-5.249997741498965e+300", "2.6398241882254823e-76", "1.0053883474167949e+273", "3.957516122244306e+291", "2.8352763625233264e-116", "-5.334728304275677e-257", "-4.075121806457843e-160", "-4.627660466537007e+250", "2.3939691435869366e-170", "-1.5779606170330942e+102", "-1.8634894264529436e+59", "-6.98942002167568e-55", "-4.924291180926205e-275", "1.3203341136038488e-109", "-1.2243425865196169e-231", "-2.2659610495321427e-146", "-4.7623998479628435e-135", "-4.2637822064982887e-172", "4.799198754514708e-271", "-7.2401779871096e-29", "5.827621563114422e-163", "1.3379474731651064e+178", "-1.871061714626481e-141", "7.950224372731925e+45", "4.048744860093319e-291", "-2.0333536453677674e+254", "1061113880.5471997", "-7.149835539153094e+230", "1943.8322279850386", "-6.080631787752817e+123", "7.871138210825474e+305", "2.3608133562865308e-267", "6.476305672624334e+270", "2.908452874939749e-226", "-10204015165766.502", "-9.093246397517387e+220", "6.95457773314253e+211", "-3.010335628059422e-48", "1.3963662072385752e+158", "2.5198353019605366e+163", "-4.262059189420016e+219", "-4.2538667646334016e-33", "3.2499408262936455e-252", "1.5124255926132393e-10", "-2.809260946707893e+23", "9.502560803958361e+257", "-3.1701751541962336e-33", "5.94882706117647e-110", "6.096059309608852e+112", "-6.337150117200962e+179", "2.4211254365311913e+228", "-4.166141083490287e-50", "-5.285068192125516e-52", "-5.157488137671165e+124", "-1.2308050151129204e-283", "2481891.087672976", "-4.709007925705347e+139", "-2.486074124636777e+29", "5.238554835386543e+225", "-5.942966156766595e+177", "2.543582426622614e-138", "3.3880798169057718e+199", "7.897951707259899e-275", "1.1427546651545527e-184", "-8.869603528658167e-265", "2.950514080794884e+271", "5.611386094168765e+71", "-2.805865986647973e-141", "4.434677856843522e+277", "5.828122745646182e+41", "-2.588703682207834e-303", "1.5147280831947348e-261", "-4.836504351776479e+99", "-5.677561390043487e+263", "6.967736021441424e+102", "-2.5632278817180481e+179", "1.7239578778311303e-181", "4.836912212049595e-25", "1.0149066727580872e+180", "-4.845075617709937e-39", "-2.1345265890884194e+61", "-1.9307214670692255e-84", "-3.563620169121963e-94", "1.3382136752275367e+51", "-1.423248585752507e+61", "-9.189117689786204e-299", "2.926566104092147e-22", "1.6885997266784554e-179", "5.959645792674005e+122", "-3.314770800218026e-133", "1.375332146470882e+53", "4.882247122787137e-90", "-2.1458730222434408e-183", "5.086628070799535e-188", "-2.8926702563139163e-251", "-6.697529745063969e-152", "-1.1357145143516277e-173", "-1.6767249229242475e-211", "6.885999805613039e+133", "2.0013023496547791e-174", "-2.4847222847051894e-119", "3.041576505882239e-298", "4404572195160.991", "-1.7341360783416315e-248", "-2.454951857188738e+212", "-5.576119589937488e-135", "2.7436079154409516e+202", "8.183559559713491e-76", "1.3495011019383755e+170", "4.0024892383604326e+18", "-2.5871545961485857e+195", "1.3299857155974882e+72", "7.517104090265079e-208", "3.185921142880355e+26", "1.436686961101025e-120", "-6.710433853542713e+50", "-9.63568650938991e-303", "-2.792707434431416e+160", "166.40440705250666", "-0.740410860876669", "-2.1412362593069668e-104", "-2996457.2528918553", "3.6838696863137445e+218", "-2.827603659744039e+114", "5.978033964403925e-61", "1.5267455546643823e+294", "5.8136635513186955e-167", "2.72812168438278e-14", "3.0238319828220583e-198", "1.8269146522461186e+174", "-9.414391863745987e+239", "9.034381677182193e+26", "6.708574939304593e+244", "-3.187271586779082e-170", "2.1642039530591126e-76", "1.7888660548793244e+278", "4.5906629622915013e-94", "-1.85626616638938e-205", "0.0004134688397811168", "-4.994896955009239e+96", "9.459898609814473e+175", "-4.516377923903619e+116", "-6.414624172986175e+91", "-3.6258711084604682e-84", "-4.2763066768560584e+161", "4.881621940181741e+188", "-5.9216627722347746e-142", "2.6829031076274587e-267", "2.4587478292641787e-09", "-3.0152141160143302e-239", "8.75490973240425e+264", "4.329627930036355e-163", "3.654908982402415e+145", "-2.4736061300420978e+107", "4.262511944960097e-252", "-9.382022088001096e+29", "7.45193519326415e+264", "-1.24968986470032e+150", "-1918444032.3579614", "2.6357148548634628e+16", "-1.7413519390946733e+74", "-1.2561989320458412e+307", "1.2472774445292792e-153", "2.1539593575745655e+168", "6.768247845570792e-248", "-9.27616294458753e-210", "3.363157900852245e-229", "-1.0936023900308923e+216", "7.354209754687701e-159", "-2.1258678489116763e+173", "-7.432416092760604e-211", "-7.2090275494662015e-183", "-1.750619990990828e-206", "3.758856202663051e-240", "2.082937476859653e-238", "2.31258700227365e+219", "5.516962773283437e-224", "-1.3541474837728273e-25", "-6.875396817829691e+37", "-1.3250969728510478e+243", "-5.078410239096475e-51", "3.732921449181883e-307", "-1.459141337729224e-302", "1.2077060274563588e-172", "2.4474511099625542e-275", "1.2414771518753246e+233", "-1.2620377804989442e+197", "1.5150992770929722e-295", "-1.6921417314898376e+136", "-3.053958932736373e-304", "1.125317204490472e+157", "-2.1833634203141295e-80", "4.1839497453036943e+248", "2.6451452594309937e-153", "4.598989615607958e+131", "-2.6827776777733103e-88", "4.085701972471582e-93", "-7.546612580996334e-125", "1.0636746410042554e-306", "3.8126542216178386e-59", "4.531872456818165e+209", "-3.778852061473314e-238", "-7.669529360843346e+66", "-8.798035071240674e-287", "2.3983972306848175e-21", "1.0955709821912734e-179", "1.087118460397992e-114", "3.913964256965499e-213", "-8.396517481769757e+63", "-3.964576879584136e-74", "1.0542701582998936e+287", "2.8193287597787762e-129", "-1.094862524983423e-152", "-8.565631724838802e+153", "16408013797.45695", "1.7711286476731376e-120", "-5.53223448502291e+95", "-1.0269964624383037e+225", "1.1123591695454121e+163", "1.2091635810348368e+90", "4.239116687578461e-114", "-6.297660846121977e-56", "-1.1937176117284208e+27", "-8.477096364530781e+177", "6.198188171803362e+132", "-5.396898725495609e-301", "-1.1726955367771936e+113", "3.388465803848714e+48", "7.0372018042350276e+280", "-2.258857919908562e-32", "1.1665336151943764e+183", "2.4632641491306095e-199", "-9.509067996837338e+112", "5.029328677707477e+272", "7.697603768473504e-08", "4.924770689694438e-240", "-1.7149771826493236e-173", "1.245701023406438e+52", "-1.3382318076359897e-173", "1.598034438628285e-258", "-5.79914472410023e+225", "1.9282640289041672e+289", "-2.8283243417111628e+116", "2.4621631925211567e-74", "1.143692322161612e-292", "-1.349371070262633e-164", "-1.9715683696341849e+90", "-1.8334334660692084e+232", "-9.964700475625297e+174", "2.783082703543004e+166", "-4.381812188749948e-242", "1.9318867558645156e+273", "-5.200484915399607e+229", "-1.0645112385805341e+253", "-8.919309739735019e+216", "-7.430027099903715e-176", "-4.669842332371691e-274", "2.8672153687176014e-36", "2.526404656106291e-243", "-4.1930258458973663e-128", "-9.458850497830993e+88", "1.8263341415262458e+279", "-4.59139993616969e-91", "-1.0474262988529659e-28", "3.56900139661417e+291", "1.3141931246990227e+220", "-5.220844970261067e+291", "-7.668126369107244e-166", "325567161.83446586", "-10622132.5104283", "-1.6899126304852128e-195", "4.692243797711423", "1.0284493801095106e-217", "2.6698218393314753e-122", "-7.017691364883144e-233", "-1.575645025488409e+306", "-1.668483279983436e-05", "-3.5203353968211145e+126", "3.8537230714979715e-200", "-5.271167894877449e+114", "-5.1627454122421305e-303", "-2.3000021118548095e+227", "-2.059666018086382e+98", "-6.710456618219642e+118", "1.4318257557738297e+64", "-1.558271152108443e-261", "-2.470218960066333e+159", "5.574652481202122e+48", "5.645042179985462e-149", "1.28341106654184e+46", "-9.18731505538101e-273", "1.2470465826961406e-230", "-1.316951705120448e+117", "3.8848917843844005e-32", "3.4033591466052685e-108", "-1.1338827246642695e-51", "2.593956278318721e+80", "1.8966162141832634e-171", "2.2736901347364435e-303", "-1.8634312638958118e-146", "-4.279143349667637e-48", "-9.28607910998104e+22", "-4.0052186932214973e+118", "3.788664436984411e+284", "-1.802416592305664e-166", "8.550166772230086e+290", "-6.355780472160876e+294", "9.261673635052035e+135", "1.0661904064786775e+92", "9.272772065528304e+98", "6.93299995859522e-35", "-6.716842049418241e-134", "1.6601209675439949e+289", "4.3598472150996785e-80", "1.627320666473185e-293", "-1.590533560482167e-216", "-1.6229744779708745e+305", "-1.9759037153899966e-193", "-2.1003521249929004e+113", "8.093734434419862e-264", "-2.0679855386366723e-302", "-7.997570487114773e+141", "1.1561059452405914e-232", "-3.012736413281926e-87", "8.511659134797602e+108", "-1.0847285788086198e+186", "6.093662883421088e+298", "2.752203998562319e+76", "-9.828753159406166e-182", "1.2580280314971186e-113", "-2.3794581206932353e-53", "-5.475989273399602e-127", "1.8526727736623327e-237", "6.775985917124988e+260", "3.7890687564029334e-103", "4.331358838610281e+94", "5.227026811092536e+150", "5.074080985068479e-287", "-7.075913247854576e+175", "4.749094438224875e-126", "1.6234542782022018e-302", "-8.483923435303415e+154", "-9.812795949609018e+297", "1.5380002577955987e-269", "1.653488986578972e+116", "-3.2450035467090097e-295", "-1.3032679074358579e+293", "9.409889315124529e-281", "-3.610582085149284e+147", "1.5208125866257563e-296", "-5.799154657764499e+173", "-1.042808344635253e-132", "7.787895997453288e-31", "-4.3294360186020954e+102", "1.3767422431763415e+89", "7.533209840599608e-136", "-7.600710263160702e-210", "-7.156034119108015e+103", "-2.7792855900846445e-275", "-2.8663410642006074e+289", "1.3775504651555973e+248", "-3.1182047378070424e-219", "-2.419020808631841e-240", "7.39905420491029e+198", "-3.945433354193436e+120", "1.0223190094430055e-37", "4.583750967691592e-185", "1.686713112547258e+308", "1.2189560993008804e+108", "4.8074647724929046e+290", "4.989588336413065e+80", "-3.0103887934385368e+237", "1.9514032534421942e+259", "-4.458753420436523e-163", "-7.607841900919334e-160", "-7.44755912842814e+163", "6.157604709933819e-18", "1.4904955895972942e+116", "-1.0678889912938254e+55", "17306273835421.885", "-1.3363052150994911e-67", "-788879221609.5513", "-5.9813361907578895e-90", "1.1958808959973428e-122", "-2.613914111799164e-274", "-3.7785587571689363e-205", "3.460046752684472e-200", "3.0260370407473476e-53", "-1.3317729537462296e-194", "-5.61633136110182e+227", "-5.20537029736287e-201", "0.00023151270527024093", "1.5206658548242883e+74", "1.4891141465499068e+137", "2.0456496798112655e+76", "-4.314635860151704e+219", "-2.6698604413481096e+33", "-9.217545102677279e-252", "8.730222323603401e+222", "6.289756261556374e+290", "-2.909545060769037e+262", "-1.5777938151625806e+283", "33290439.59529465", "9.28412498585589e-75", "-5.619264182891882e+114", "-2.5453093902603914e-192", "5.39127912366305e-159", "7.084026395071633e+22", "-3.3436296487882927e+183", "2.442934224450922e-136", "-1.059719899687406e+295", "-2.292837404195723e+61", "-4.907519029985006e-74", "-7.61781433705012e-294", "-3.0785059286273356e-08", "-1.8614910203234177e-284", "1.2788231073082009e+191", "-1.9423314534841068e+73", "-1.2648692118815038e+202", "6.796516939130294e+228", "-2.411912066018384e-76", "-7.759217618076823e+263", "-7.108713699464386e+22", "-5.166465476499355e-219", "3.4093747434387566e-109", "-2.7352835108275072e+78", "-8.510486604236543e+299", "-2.6108717856323494e-229", "-9.586185927352646e-34", "3.000157698510851e-45", "8.252043895821402e+71", "-7.620120400571308e+250", "-8.041425665532412e-128", "8.812536624693208e+164", "-8.953870230311288e+142", "7.579724554032103e+234", "4.806938676611178e+30", "-2.068446850267415e-280", "3.8292958195662126e+112", "6.959338697439545e-293", "3.1342634556454395e-114", "1.4078472068254592e+290", "2.362496142947128e+46", "1.2204013869461168e-99", "6.982332496539583e+77", "-1.9733617650520292e-121", "3.0750880929926454e+107", "4.2655979463867734e-97", "-5.053633557405978e-222", "8.447028964774113e+300", "4.929402351803322e-296", "-8651854.268078726", "4.991402036837507e+121", "-1.7184838196312713e-91", "-5.48967863094138e+181", "2.221354503675022e+88", "2.2869860951445637e+48", "-9.313869536049236e-179", "9.557191119792181e-12", "-5.369419136026258e+52", "-5.742471138905176e+193", "-3.719538111992311e-109", "-2.440351474111146e-20", "4.764657846573683e+166", "3.8074148143552036e+174", "5.537463507519328e+184", "-1.1471033758640907e-123", "1.1506982282059488e+141", "7.60823725301782e+44", "-1.4021223807185649e-155", "1.2276910586059844e+139", "6.8308339093942436e+19", "4.390412908869349e+292", "5.272108886568755e-141", "-2.1095642989606552e-126", "-1.8397998852381905e+99", "-2.3495550566987116e-206", "-1.066052128232305e-63", "1.0754964863234001e+200", "-1.1295145822366088e-110", "1.16324062015263e-170", "2.1150838348976814e-271", "1.361327416218359e-80", "1.385040914024759e+45", "-1.5814342508302814e-120", "-6.156636492203773e+119", "1.44420528205703e+106", "-36.39292940999622", "466810340.55377567", "-6.633479573352355e-80", "1.970483680144067e+51", "-3.121915243773475e-210", "1.9569327672963866e-289", "3.5382176315902216e+286", "-2.589515075821088e+80", "1.0523912009492283e-133", "6.911925182912808e-194", "-7.656822681729737e-204", "-8.674730892922107e-308", "-1.1492426299108998e-73", "2.389910394818487e-55", "-2389321725610183.5", "-2.0049779519173218e-238", "5.422903467586892e-17", "-2.40458021955249e+260", "1.1810792394125098e+22", "9.838027046355003e-163", "-8.491416188803436e-145", "-6.772473908412387e+190", "-1.5519514926613152e+110", "-8.301825935563534e+95", "-5.063197175760111e+105", "4.868816408674298e+49", "3.929334118216704e+236", "-7.865187221662554e-302", "-4.115614090284706e+272", "2.845180146331559e-248", "-2.5731510410677794e-08", "-1.0305766128812665e-302", "3.4099206848158463e+50", "-1.7501406278690195e+254", "-1.2132649631129501e-273", "8.503601373847332e-63", "-2.2423501703168664e+232", "1.5188599791884082e-10", "-6.428654734523787e-159", "-9.70625765703809e+57", "1.5819826377959196e-173", "-1.8079477570237352e-137", "-5.413435900444189e-298", "-1.2813245258696755e-288", "6.440181086283636e+80", "-2.4225073022811996e+192", "0.10107371423121597", "-5.058980893855127e-47", "40834480671.26545", "-1.0595134771331928e-98", "-8.084579831248441e-53", "-5.882377798688989e+300", "3.807482213724064e+140", "-7.112578003166129e-158", "1.8064989619958614e+233", "3.800618851050164e+171", "-3.8819240795323005e-98", "-3.0180371221065066e-189", "-1.230139707364524e-76", "2.4182708291104283e-274", "-1.4728630959429465e+124", "-8.8878534224908e-29", "-6.93362608897649e-22", "8.761180118808347e+38", "6.7021041479488156e+128", "-3.1157656257209344e+218", "7.482035944896663e-238", "0.014398039903236126", "7.06187555218943e+146", "6.8840458762600455e-127", "-4.5770838353071846e-98", "1.5906317321271037e+277", "-1.267869796152933e-245", "-9.211876840332099e-123", "0.0009039178547521918", "7.50596323645806e+238", "-5.856267907752356e+108", "-6.076389505346256e+285", "-2.3586172311442466e-255", "-1.3816188406829007e-179", "-1753.2602320969754", "-1.8748976605992636e+83", "8.37452435088758e-139", "8.019166371040865e+18", "7.089274361460954e+106", "1.8041766759764962e+130", "-1.554888674424551e+138", "1.0423683511016409e-50", "6.418335314971734e+247", "1.1540343706027138e+123", "2.2303571454302058e+123", "-4.784427674341146e-27", "9.799912548144874e-273", "-6.176665834904361e-125", "-1.3065428446574675e+100", "-4.657447651282614e+111", "-3.3439436291928892", "-1.4740215519597094e+27", "1.480415256294112e-49", "1.8253963073308867e-37", "-3.1764765179652857e+42", "-1.1705299962122294e+77", "1.0962048211177035e-259", "1.0389213693576098e-263", "1.0544553257206783e-154", "-1.846088270279241e+46", "-2.1266828246396915e-214", "3.5509836388269063e-65", "-1.7410661161624623e+98", "-6.099698249393978e+171", "-1.3012626801881727e-170", "4.2718589919902674e+179", "-8.626027022971635e+102", "-5.544271646038297e-221", "-8.633540134010595e+193", "-7.287547683072019e-208", "-3.299605509922937e+43", "-1.4309030455960744e+170", "9.33551653344017e-185", "-4.870260413748795e-208", "-6.527740868189257e-212", "-241334162107756.56", "-4.669098456355752e+279", "2.2859779678777775e-64", "9.49331870609039e+86", "-7.96166762566543e-305", "5.068065249240964e+108", "2.2248784473786464e-243", "-4.350574387946081e+256", "-4.859281519856233e-219", "2.658051920537506e+106", "-4.8763077248291814e+231", "-1.3681730644655364e-17", "3.1733205795367426e-46", "1.5604313148342072e-46", "1.1974407134167077e+179", "-5.463571504837136e-200", "3.20644176958043e-154", "1.472104252221138e+220", "-1.6377829879217732e+180", "1.628794694046766e+52", "-9.180573639464e+225", "-7.458258147178182e-123", "1.593127902461627e-191", "6.269612013607688e-189", "-4.3976536434139456e-191", "-7.81996477026574e-250", "-5.95620667548626e-148", "-6.272288706794651e-241", "-2.4302566302930534e-110", "-1.1028366664415621e-181", "-2.0689617397640845e+235", "7.00513697104949e-217", "-2.5086397361789173e-205", "-2.544031013094239e-305", "-4.2377619599682e+45", "2.0130140416012358e-184", "4481853068139640.5", "6.9970674660248e+17", "1.9509907624924076e+213", "4.0949705685414255e-69", "-3.4586682395268e+153", "-2.3201571009020396e+287", "-2.537318077055129e-284", "-1.683382155525169e-163", "1.931841941611314e+255", "2.3019472283805723e+249", "7.992513095801879e+214", "-3.4983426547121753e+155", "-2.0493425502409567e-209", "1.4136598307372764e-80", "-1.7132694905339987e-187", "-2.1612613628203088e+160", "3.248848021466435e-259", "-4.919124277613768e-219", "3.9666483602546815e-291", "18679312.665726725", "-3.2161674873648513e+115", "1.6452152685148736e-275", "92321969.2014012", "5.661932358717571e-250", "4037125166638.0103", "-2.0972003458397805e+121", "9086439005.774239", "-2.546702972606973e-117", "6.090262782771298e-201", "-1.8663288753915102e+184", "1.1381941946950093e+224", "-5.809854784904372e+250", "-4.10467175543639e+63", "-9.513106297413601e-29", "3.4909647030065266e+138", "-7.189300128082688e-199", "5.01615506231114e-162", "-3.305762208513025e-26", "1.0243628239255327e+207", "9.758287260524125e-192", "-4.926234371915761e-47", "1.1158833595557073e+210", "4.531232655785361e-139", "-1.2386207394106713e-161", "5.56192350029709e-35", "-1.5580772199911557e-302", "3.1579472440151733e-273", "2.474915017468231e+289", "4.9673060146838864e-177", "-2.188531951048061e-238", "1.5898745166122145e+271", "-1.865948839483367e-25", "-4.81066249074407e-70", "-7.552063837749008e-239", "5.584779021546784e-53", "-6.061275341949982e-253", "4.137125101467873e+46", "6.737814185834885e+241", "-1.244826867850603e+211", "-1.6266104338624612e+156", "2.7271650579931334e+66", "-2.323045857063896e-189", "3.610807241717789e-298", "1.8145614207125334e+193", "5.093551090110631e-44", "-8.459361909828504e-101", "-3.034234991422484e-245", "6.422864326423672e+138", "7.224830787541169e+97", "3.501376588986062e-188", "2.4804308782520378e+117", "8.506638262105255e-240", "-4.390945946509697e+263", "-1.4801413180667306e+246", "2.2320414996508782e-97", "5.204210193715726e+183", "-1.6715425921389464e-163", "-9.562232678999299e-256", "4.280446114292583e-125", "-6.854564176880318e-265", "-1.5627638751740982e+187", "-2.184293637199756e-232", "2.210743916048926e+45", "4.973369619442422e+292", "-3.7863460740289225e-272", "-8304795216238.706", "-1.2619828106585601e-120", "-4.541032045189032e-41", "-3.984988877373332e+87", "-4.5092871885171303e+288", "2.914738195511674e+251", "-1.67976333445961e+127", "-5.458960603174342e+82", "1.625358139320863e-239", "9.690690371981737e-74", "2.8810999669577255e+113", "86402977.00482231", "-1.7737463217773225e-299", "1.4996574194952937e+198", "1.717107646851723e-259", "2.0557674321772185e-39", "-3.5540896766353546e+18", "-4.1524786893802656e-23", "1.1762217921639102e-229", "4.584706186554123e-81", "3.722096606750549e+282", "9.111281113099198e-214", "1.5562246115411242e-130", "-7.225489103829126e-187", "-2.7551714987603484e-251", "-2.5628305300579217e+47", "9.235882543177597e-178", "3.213139163813645e-185", "6.714973531306015e-129", "1.7971264367483596e+256", "-1.5113054622093693e-187", "3.4108722683631445e+260", "6.0566252580598545e-49", "-9.137554923794027e-31", "2.0101483403171634e-22", "9.424203347711454e-288", "-8.159000012620976e-44", "9.948199600836907e+78", "7.154567967448831e+182", "-1.0398119924401441e+242", "8.607079013685368e-10", "-1.3931468728883076e+232", "9.638186231378375e-193", "-5.455702817074425e-306", "4.3567596841864614e+82", "1.5344743944350083e+139", "-1.1905338978575735e-69", "-4.674743835439051e-215", "3.1012421720088054e+240", "-3.7765022371872937e+230", "-5.239523195753246e-196", "4.4017191376007023e+223", "-4.70374640595568e+282", "-0.014020641573754645", "4.049028148421564e+106", "-4.344477148137919e-60", "-1.3369021322545251e-247", "8.177373906123603e-138", "-8.462393896990211e+246", "-2.276240573324252e+121", "-7.481678412998485e-72", "1.9898283224857704e-113", "6.457936951334917e-167", "3.0463229366807934e-33", "-1.2318873418331145e-57", "-2.567854989869408e-24", "4.491836492477887e-264", "2.3589563675669122e-161", "-3.7666278204131696e-136", "-2.2553722406510557e+45", "1.467316491447731e-97", "-8.260178665629254e-112", "-4.405693058737317e-168", "1.2717542299873302e-48", "1.6948048953563463e-167", "8.278567487524493e-252", "4.135075192535914e-123", "-1.1045734066477785e-97", "1.1657645550451934e+280", "-4.8453467851260286e+104", "2.2687615389552753e+164", "6.043273162805641e-250", "1.2400788877768845e-293", "-2.3358181492319524e-81", "1.6050337985623172e+53", "1.3789939557471818e+178", "1.8624237051863858e-56", "1.3084667772412808e+167", "1.4593208918769267e+107", "4.6844293935684707e+176", "-2.512901043533706e-41", "5.4573596400308086e+157", "-6.055497010558918e-32", "3.660341567799291e-187", "-3.857274398542802e-288", "-7.74084441068822e+41", "-5.427536165246384e-105", "-1.154418733197399e+223", "-6.676572750542597e+164", "-7.756505779490149e-136", "1.318537471929373e+128", "2.3357960585036837e-210", "4.176619461380977e-266", "1.6980984230789247e-286", "-7.947045447412485e+254", "7.673491307089804e+296", "-1.0571737367054879e+176", "6.370033387301531e-274", "-5.261214284196356e+186", "5.234292545303391e+114", "-6.402610061143457e-18", "1.0500974706484422e+130", "-2.994233970897377e-181", "-4.208351093939203e-301", "3.9732603937361545e-61", "2.956356981375113e+164", "-8.881205183152739e+146", "1.0199133698901082e+279", "-3.350081824527952e+232", "1.1576767387804191e-138", "-2.911037207127903e+19", "-2.321262338150105e+33", "2.9201175182008583e+94", "3.290352928090605e+112", "-1.801748482758309e+29", "2033435559716681.0", "6.414566081429603e-235", "-1.622795798202284e-281", "-1.2098502493409772e-96", "2.3570408920839027e+148", "3.89689479373713e+265", "149364476342793.06", "-7229728117.313039", "-4.414122436922947e+37", "-1.0585903077841078e-78", "9.346207207249809e+203", "6.30606860109599e-228", "1.702513427282792e-194", "1.973175223259688e+300", "-1.393164674255519e-170", "-4.0617433592489914e-171", "2.487334605822909e-204", "-3.0698286112424426e+71", "-3.292972680468134e-72", "-2.685690639101456e-148", "-1.8267989168083125e-48", "4.945831260411722e-158", "-1.138547329633151e-62", "1.971751112563315e+271", "7.432259368563883e-39", "-1.5558178433968494e-148", "8.578242187871868e-118", "-3.73531719349981e+193", "-2.358002664541261e-284", "-3.0497764703071526e+51", "1.623499461023034e+306", "6.564932855700234e-254", "-0.00010577586019189625", "3808465887246252.5", "-1.573125221421394e-45", "5.2106426051128406e-238", "-3.998556414959237e-238", "-1.6134612300034156e-265", "-6.449142406972475e-265", "-2.551659680704108e-239", "9.123864113396351e+17", "-9.566204338369753e+282", "-1.6914360311290583e-51", "1.3250223403164848e-305", "1.2084162516530873e+56", "-2.9170115825100946e-243", "-1.1076976215016538e+148", "9.564689283983091e-170", "1.4898847948204557e-183", "-9.021616464429274e+306", "-9.453179426208193e+257", "1.375453545476517e-116", "-4.2832731141009933e-271", "2.2260211678947268e-26", "-1.286266610705137e-144", "7.613176292448416e-272", "1.755151947093131e-254", "-1.3113589351344618e-57", "8.559205912990493e-19", "6.404059307839949e-252", "3.9524395109729503e+37", "6.164799790146958e-196", "-2.0212334172498945e-203", "-277157366205429.56", "3.235137360264296e+125", "6.143932789892117e-106", "3.8071459442384186e+143", "8.008084357391125e+168", "-7.510768681065419e+148", "2.2357059136204393e+124", "3.623812993381189e-257", "-28029730483202.984", "-6.1958664174626866e+128", "8.797550460517106e+199", "-1.2987826039780635e-65", "4.67137951351238e+301", "-5.636027548532178e-13", "-1.409224900418476e-199", "-1.879089470116132e+260", "-5.876487182590096e-241", "1.311744303786783e+87", "-5.739149548216679e+135", "-57423.62399707539", "3.255495695028926e-146", "442185692656016.75", "7.66008067718603e-303", "2.035352703245329e+36", "3.120404167103385e+216", "1588.0165799223832", "1.841391896180025e+265", "-799.7784057874842", "4.975014421024584e+264", "-2.9362294073551702e+38", "8.449305969640922e-46", "-0.010785466207182341", "3.864630943888382e-12", "5.326242435542379e-288", "-5.802984014663374e+187", "-8.232373306009391e-53", "5.881235193886132e+63", "1.7017726443592425e-61", "-5.645891249363088e-219", "-1.0470141262210389e-252", "2.5441375144269837e+104", "-4.5182187773721084e-73", "-1.7062356824770447e+121", "-3.564864369443418e+64", "-1.3700230748456941e+217", "1.1670335357996339e+250", "1.322366484894518e+168", "8.840397537347428e-21", "-3.066455459798287e+170", "1.7371305957528945e+203", "2.844450059254945e-208", "4.012657886200772e-49", "5.655180074078335e+193", "-8.25247579697159e+143", "-1.426808752309966e+212", "-9.321509963602004e-82", "2.8813687149073965e+156", "-2.7888672409170545e-79", "-2.0190858752074068e+68", "-5.086768995890497e+231", "-1.1936719969869927e+253", "2.3450527061779195e-292", "3.788447222161937e-205", "9.467028850485867e-150", "-2.644884032805941e+78", "1.5159373880818624e+277", "-2.999732050793207e-99", "2.795447668113559e-270", "1.1269739945162534e+166", "-6.003761283793917e+34", "-3.565318447022294e-25", "-9.371365414860424e+160", "-7.367111011033369e+25", "-8.404874808148793e-156", "-1.1715320459548743e+111", "-4.370664965538763e+24", "1.2196528454461143e+42", "-9.805898039067595e+96", "8.506614276740497e-205", "-4.59563236028779e-38", "-1.3240368461316288e-248", "-4.420497285146011e-145", "1.7544953728246338e-92", "-3.89300893973992e-108", "-5.476914886311598e-159", "-1.5029351935266943e+206", "9.069276036331406e-272", "-4.7883829779826284e+88", "1.402765465467456e-29", "-1.757483915056279e-257", "-2.056526448475944e+103", "3.8655059843870537e+37", "2.0513429528570485e-248", "1.0390266080502929e-215", "-1.4817039727920653e+282", "-2.096650388830535e-196", "0.00019173066706895702", "2.907263367224241e-153", "-1.9020527019989568e+266", "-9.68186241819222e-259", "7.317634267077083e+120", "1.4552443275920314e+17", "-1.2837678451855956e+94", "3.8450196061392554e-151", "-1.411637860681092e-48", "1.9140777596626106e+154", "-8.483426169280187e+246", "-1.052298380237972e-177", "-1.7530017595387362e+127", "2.826924647461059e-287", "-1.0430283737852016e-200", "-6.976655557570412e+122", "1.7882715120428702e-50", "-8.315189537397873e+280", "-5.655572174590126e-58", "-3.1098505529896334e+78", "1.3312039353481051e+73", "-1.5579730542257857e+48", "2.8638559696220607e-276", "-2.768576322935255e+61", "9.528334628267092e-269", "4.3500381490554715e+164", "6.267350601679483e-234", "2.4962897262609147e-173", "-4.346445416464266e-253", "-3.525333827428307e-65", "-3.2889467024919905e-21", "1.2802817021324372e+69", "-4.977758046846361e+305", "-4.133011805369036e-269", "-2.6046001866494165e-296", "-8.190488022047257e-51", "3.354161993765212e-201", "-4.8976472851912845e-306", "-1.59442352530496e+142", "1.7340991501941083e-156", "2.647391813628198e+54", "-8.961083728057862e+154", "1.980760731661398e+44", "-8.74699031294314e+238", "1.7442774619049847e+87", "-9.918469261298443e+117", "7.844270868541204e+282", "-5.105193438031696e-290", "-1.1130468021196486e+197", "-1.5443176050685914e-235", "-7.740829219789001e-71", "-8.854008803235497e-103", "6.821584503784181e-131", "1.4421362396024216e-176", "-4.261031191401173e-161", "5.351251237990076e-198", "4.4827261926808665e-35", "-1.7738112883559252e-258", "-6.539948728203858e-19", "2.309127716505971e-75", "2.1025601910312262e-301", "-1.6421581896008913e+80", "-2.1015589606310112e+65", "6.450639486285923e+238", "-5.582644257769788e-294", "-2.9661498226977786e+199", "-1.4080069434654403e+188", "1.597726795070894e+56", "1.2441470964323102e+65", "7.716772325249335e-185", "-5.737841406139087e-307", "-3.55057877879264e+178", "7.645782318248184e+239", "-2.3676350567557314e+202", "-1.354964653404404e-31", "-4.32411972522339e+65", "4.4221807498023484e+122", "-1.685588193151725e-82", "-8.692439079020619e-166", "-5.014931638213625e+194", "-3.453624739998577e-122", "4.4789444275805755e+193", "-1.6336273271174817e-162", "-9.914520371793786e-53", "-2.6825613504328813e-109", "-8.908835736894128e+289", "1.4522712028943114e+35", "-1.225683016769596e+83", "-3.81164581718338e-45", "1.6824766437395683e-225", "-5.033348238392829e-206", "1.991002745932908e+76", "-3.320512852458665e-41", "-2.967134556863017e-32", "-7.37228405104085e-110", "1.3537337293045719e-286", "-1.1259262647974821e+27", "-6.172171811140094e-261", "1.0957839028483232e-134", "-3.5726105619473755e-60", "-3.4940985620465803e+166", "4034193.140950368", "40785.59872719151", "-3.409340779494256e+129", "4.1732546511582544e-293", "5.0273966082016316e-238", "4.74760919768354e-168", "2.116963093671774e+120", "-1.4772824450271001e+271", "8.88658457938515e-172", "-1.5395803412616216e+263", "2.458980784233134e+42", "-1.8700213974879438e+51", "-1.9043894317206288e+236", "-1.2751986961850671e+33", "-2.7719163546405945e+58", "9.335299196298511e+108", "2.466028650186692e-65", "-5.686803892022257e-67", "-2.4819609129236304e+52", "-1.3004194640999767e+47", "-3.2944220823742295e-167", "-1.8145014923040592e+178", "-1.7174075555724402e+174", "-6.05943238407195e-81", "-2.260466068664037e+285", "-6.461303065743816e+40", "2.5219419159052644e+40", "8.642834462087374e+194", "4.508677220169959e+130", "-7.781054165951131e+96", "1.2533148650261318e-21", "8.711696072971746e-92", "-8.481145282544368e-205", "1.1223867866828433e-253", "-1.4626366721195041e-80", "2.4812166711647908e-90", "-4.955691754332828e-161", "-2.5609969030260353e+249", "-5.1014135538395795e+259", "1.853064492565978e+80", "-3.9819266873421115e-36", "59763942789858.68", "3.546037869486778e-262", "-1.7956204936280897e+301", "2.5114041005100274e-96", "6.050899499088711e+215", "-4.193206087945306e+77", "6.538742453838699e+35", "-2.6681846726346333e+293", "8.85553439073365e+222", "-4.890988198783354e-128", "4.679006718048704e-208", "2.250554977364906e+74", "-2.4679535061765626e-285", "-3.488924690294353e+106", "1.4644287793653866e-158", "-5.737021521395809e+53", "4.15250989127055e+45", "5.2663966994883674e+17", "1.5764106097907887e+262", "-2.0186965104834114e+282", "-1.7973762099216005e+253", "1.4906522535474596e-26", "-1.773624423968837e+257", "-1.6414977463627567e+215", "7.096006551992088e+16", "-1.1205256037566519e-218", "-2.155322261058093e-131", "-9.735183731046647e-132", "1.4899566884062864e+223", "8.995958923111926e-270", "-8.12912333077801e-157", "-66696815347578.414", "5.042119244843862e-59", "3.744959951157774e+23", "-1.6953405213149948e+103", "5.336967781803267e+233", "8.851115399752012e+254", "-4.135103486374112e-149", "1.4507619487430587e+140", "-2.2950685392668225e+288", "-5.550326655594898e-128", "1.0025169181192807e+75", "1.2404645665802328e-130", "-4.2230897910326135e-103", "1.0973047439962736e-77", "1.5167958620761836e+74", "-1.2042283467200017e+27", "-2.759881272488892e-224", "4.9876488938748814e+213", "7.031749981851288e+129", "1.8327613129845528e-290", "2.7417027498004945e+234", "3.333279538244224e-189", "2.8320920768952073e+168", "1.4438559808286762e-111", "1.1372758303481648e-251", "7.936114086527737e-150", "-4.195763437686325e-07", "1.301987533623166e-30", "7.518771673809661e+136", "1.4458408191965814e-280", "2.2901559996889628e+145", "-6.493494952208919e-114", "7.860429765353249e-81", "2.0860662230644835e+220", "-1.1391896424034148e-290", "-2.2326261765786455e+214", "-7.425344181390813e-260", "-1.1390658001936869e-28", "7.292906763818131e+174", "-2.570926082238073e-69", "-1.4018611863983913e-234", "-2.6387234544669963e+51", "-1.7381261525503791e+307", "-2.616618287318332e+177", "-2.8308286871977316e+140", "2.043096938618955e-101", "3.678389532116191e-85", "2.651894105860309e-227", "-1.6539999345416692e-125", "-5.60110211599259e-232", "1.5172667894398194e+43", "-1.2397065515660828e+101", "4.125148459784042e-223", "-1.037990096710387e+40", "-8.773806802478086e+80", "2.7875203399529096e-106", "-1.4404841554087056e-69", "-2.956432048157104e-45", "-5.926861984209961e-199", "1.6695888898940195e+70", "-4.55463261064634e-261", "2.773570447203134e-240", "-3.077688726460394e+161", "1.7635702192870758e-102", "-1.249909849642545e-176", "1.1487611223805719e-34", "-3.2593459303442493e-06", "-3.2086556522381705e+253", "-3.3231524213533876e+75", "-9.164407515822606e+219", "-1.2992364450573746e-99", "6.185121667823582e+252", "-1.294266283852676e+78", "-1.8310273450166542e+207", "9.29925640974695e-265", "-4.153374192139488e+173", "2.3001170533614592e-234", "-6.07385240778636e-285", "3.804070830003572e-180", "-1.3005397971887719e+131", "-6.248163144496189e+128", "1.815709848136453e+256", "-1.1732790105560014e+284", "-6.08497011925172e-157", "-4.3251315953709006e+70", "1.0137296555651322e-202", "-1.1306827651647263e+70", "4.869648513439415e+67", "2.815774033871252e-220", "-3.572087037234799e+159", "-5.294693175336173e-36", "4.3581139677056e-28", "6.012458442658632e+160", "5.926998637976078e+31", "-3.2205610647822385e+304", "-7343225.758804232", "1.3779903456314335e-86", "1.2761170554125966e-200", "2.6841824731463315e-238", "-7.118273000865705e+277", "-9.773790033438565e+74", "7.862641264574988e-147", "-4.593153717032953e+41", "0.1841125572314725", "-4.167730168514372e-80", "38650.68390061038", "4.413272609067751e+249", "9.969772523538401e+144", "6.067695271683181e-42", "-1.0248819283224847e+39", "-1.388960025421947e-64", "1.1427210808766997e+261", "-4.007615818431962e+289", "-1.0453904005388567e+105", "-5.465889496141293e-19", "-1.1234476125040388e+186", "3.706989382313204e+300", "8.979816167020101e-296", "3.138003581193585e-100", "-5.953221606895033e-108", "-3.6830884012121386e-25", "-1.8432174229478613e+168", "1.1649622782644465e-156", "1.1474371015929958e+247", "-5.258121386504999e+259", "-2.1263494761823797e-110", "9.3379501585153e-98", "-1.3118805243362755e+237", "6.150047494303438e-125", "6.59804566629632e-128", "2.063545044103278e+223", "-5.310743527946005e-250", "-1.757160024250903e+78", "6.711136426285447e-141", "-8.28118539286169e+192", "-1.007538509247773e-98", "-1.8761074030448452e-23", "-1.2462689670055784e-58", "-3.735336500172866e+146", "-1.1227003385535892e+257", "6.201841658834099e+231", "3.537120891127059e-174", "1.5825029227126504e+40", "1.956083571773539e+122", "-4.8425710255610084e+104", "4.114775746461503e+200", "-5.3520188108814934e+247", "-9.59268974851989e+197", "6.056683919576385e+194", "1.157482415056212e-286", "-2.7697509989545783e-47", "7.274390148176102e+202", "3.936121010611372e-202", "5.214385071461182e+234", "5.042508117629775e+299", "-7.05880129007608e+192", "2.8792627426077953e-303", "-3.0759074116588716e-178", "-1.023166861458371e-112", "1.1540825739490207e-103", "-1.8771315096085608e+116", "-1.6772352252671838e+213", "-2.3864587356008963e-262", "-3.5724041154347505e-35", "-5.987982853209865e+96", "-5.14212695922241e-129", "-2.836515963203107e+146", "5.984306801113138e-122", "-9.104242841296665e-166", "-4.5562336945736554e-144", "2.4052939477603883e+28", "-6.98818665300546e+89", "2.242985396836455e+284", "3.666355183430119e+110", "7.873383814386888e-168", "1.0848728987984662e-48", "2.487293443400355e-18", "9.221943199531042e-55", "-86.27520557094266", "-1.5414979131495e-05", "7.589421284512696e-267", "-6.974570629243491e-61", "1.0643049180487023e-91", "7.619150604912615e-90", "-5.659425583170808e-225", "7.673734160247668e-104", "3.341427849773958e+282", "-1.6250757330965966e-230", "3.154946180767873e+131", "-6.520097554630158e-63", "2.32349653140584e-272", "-3.254188264119013e+253", "1943118411.5907466", "-3.1982762142028712e+22", "5.869466098806108e+215", "6.518823922775219e-68", "-6.80448480006758e+218", "-4.9502773817793785e-273", "-7.1517837494227525e-84", "-2.226440886829582e+158", "-2.0926297602778674e+121", "-1.127007940495927e-12", "-4.416033980430017e-27", "4.494716311150141e-251", "1.4526450431501046e+287", "-1.9738925584316716e-237", "2.347824210128672e-177", "-7.827576856779035e-193", "7.244131246709484e+274", "-2.996324955298439e+199", "1.9918909654503592e-246", "-5.84990446991129e+155", "1.25135527814841e-38", "5.437171814692879e+256", "-71368796.42814822", "-5.848961015888952e-60", "-9.643462863016923e-20", "1.4835854818469688e+273", "-2.761719503427459e+199", "-1.3515957271839316e-44", "-5.4725626209102846e-303", "3.8204812496229825e-296", "-5.964128015546859e+188", "-4.85963003540566e-206", "2.2054814416805123e-264", "1.7761896374993984e-146", "3.1352079686934043e-197", "-1.0576940573517524e+114", "-8.021874813393785e+289", "-1.3149476394819016e-46", "2341552.7087986376", "1.6631092902252964e-284", "3.9277636509181533e-115", "-6.926175602589544e-106", "3.392025585287548e+279", "2.1056606688377685e-234", "1.3469259447705898e-198", "8.982531902177853e+293", "6.0904971410424355e-155", "6.2556147106644515e+243", "1.1159081542628888e-251", "1.1436860584214989e-76", "1.7453367996432535e+251", "3.590915602736704e+26", "3.511112135024079e+279", "-3.03822197893673e+272", "9.571244975835345e+231", "4.1472097958672124e+244", "-8.645942319021437e-277", "-4.752100722936523e-28", "7.593845845667151e+177", "-5.051041399712591e+251", "1.3907177202715056e+74", "7.612508819900247e-212", "4.1432615699642846e-153", "6.4387123628483165e+239", "1.2357164533356323e+157", "-1.891054858864053e+254", "8.253137402512599e-105", "-1.7141936139039375e+300", "-5.959599748039554e-118", "-1.0983611423540648e+60", "-5.4746101535740274e+293", "2.9410867260719263e-164", "-8.94035881819411e-224", "-3.688327212601005e-192", "-1.8688040945860973e+78", "-1.7381408841779996e-70", "1.6088893453155652e-29", "5.7230765820266075e+172", "2.328011414023566e+172", "5.928864429037999e-194", "-2.71978339635294e-206", "-1.2128751558927315e+46", "-3.392534771052045e-253", "1.9621902230658232e-295", "5.910360214955514e+274", "3.8521962880960145e+259", "-1.0917731208004237e-80", "6.421696758126359e-27", "-1.9543946065152964e+147", "1.0832536806914853e-117", "-473978597542586.8", "-8.157061060310493e-44", "-8.010337385740624e+295", "-33845486438.067837", "4.399592616920358e+48", "1.272241246081518e-31", "4.178016873922017e+81", "-2.6686759826355854e-223", "6.644340516443552e+43", "8.222080709626738e-243", "1.051588740816846e-05", "-3.0650736942565176e+224", "-6.405660867907657e-08", "-2.396650417278287e-198", "1.8154036605182248e+86", "2.3744116409663725e+206", "1.1695333637829176e-141", "1.40581559611202e-290", "-1.302188042480225e-179", "-6.880138677012203e-93", "-2.3243125693370847e+252", "1.6252777666540888e-16", "-3.7519175056756776e+302", "-6570886453322641.0", "6.101612927561302e+233", "1.0323111372881808e-298", "-6.544029188175651e+90", "-1.8649543957475803e-268", "-1.2090083727498157e+81", "-1.8652476576818358e+135", "6.857342235761361e+146", "-7.463083826533229e+141", "2.945699797633081e-09", "-1.3038196001636252e+28", "-1.63871837996459e+301", "-7.139113021575112e+33", "2.1993971031379693e-17", "-1.0525131588124751e+161", "-4.255671065984755e-297", "1.3522894080824944e+160", "-8.504009083197899e-64", "1.2798463214764795e+80", "2.2295274821360197e+244", "1.1187511073975434e+307", "6.968023814491347e-155", "-5.237311985205735e-252", "3.3713228402729106e+190", "1.3637042416173577e-174", "131407469.43039483", "-5.492571292371906e+231", "-1.545147474019975e+300", "-1.737799340623972e+123", "1.9404662305690624e+274", "-142814366433821.47", "3.041328765536025e+84", "1.0398479091828651e-101", "-7.53496546320619e+23", "5.4771782666241124e+194", "1.9475906744503114e+97", "4.4093720073528785e-243", "3.1406306635790077e+248", "-6.485428219557796e+292", "-7.294229037215777e-84", "-2.808202395517446e-77", "3.135371360987576e+246", "1.1898424981541976e+98", "1.9664833285344669e+87", "-1.6438086433356093e-39", "1.7040378846411434e+137", "6.019830683877174e+162", "-8.485009874634537e-49", "-3.035409307210708e+224", "-3.2252216515064504e-175", "-6.211339760495985e-151", "-1.2402640863286762e+265", "-1.1766947282658062e-182", "-9.652857291886589e-82", "-2.7108704228405224e-244", "5.0904037241366356e-210", "-5.519395141123003e+222", "-1.2067497976039373e-21", "-1.761409531361997e-159", "5.224376732034823e-182", "-1.2070131611777926e+182", "-5694.152927034522", "-8.211943979116734e+40", "1.2040930050355488e-238", "1.854305256261341e-69", "9.056201280119092e-100", "2.2847081171284496e+232", "-2.150616912966924e+149", "1.1962524028300678e-124", "-1.5362288332617106e+41", "-1.573813060193143e+116", "5.683071573980045e-42", "-3.8924341948819353e-47", "-8.856220448829928e-74", "-1.111074735603832e+308", "-2.1361780454090116e+98", "-3.336031549568067e-239", "-3.650575178067949e-263", "-4.476217307196007e+111", "1.6157090841360936e-111", "-2.732233608511382e+273", "18069349072287.695", "1.2029095366598868e+96", "-2.534338872150239e+132", "-5.214465207363865e+30", "-8.800077592424991e-221"];
 
 lexical_generator!(atof_f64_lexical, F64_DATA, f64);
+parser_generator!(atof_f64_parser, F64_DATA, f64);
+fast_generator!(atof_f64_fast, F64_DATA);
 parse_generator!(atof_f64_parse, F64_DATA, f64);
 
+// F64 LONG
+
+// Randomly generated decimal floats with 16 to 40 significant digits.
+const F64_LONG_DATA: [&'static str; 1000] = ["988611632443790646053270.8", "710337678998210212925.7569538833", "648007600499148254.62", "21952.420839106223983", "1.3784707599327039314", "207942415084424958067037.2791800", "275168450704906289.48620865117", "49556276291288.366174126980033471", "29590888946444190.1553909122", "87134.18679324589461", "67.4398414633016848784", "524165097731590508723448635782.803", "84853532.8866061548623639178345", "5818.66103416095650109", "424688047989127.709780917637923441", "89217711618400095.05445", "18.95418114012457187", "428723.43290591920293090117522120", "78364493470891864773293.39175445", "8.457420217892278100192", "368295011.90724088587738415064746999234", "76447380286404676331418819.09", "366914636555784884284236730229863.671", "30447573250664583016.246495556162", "57133829376.79057236186", "6305260201.94622119625", "3719292592.83572506", "28068.819952284293", "2147365125310568.43211794888277231056751", "12.809143650568520", "7.01659795280978255236500", "230089.0615350495223", "2697770964411.19522322315611496993491", "5464890032864760.504579282", "4952296731820672996.498269650247519", "5453258872887337523663937.3", "521543321504545466699284216763.8390", "909244664070254.904463832962371", "3498339329278721867610772.381380118017557", "8.315407607623303", "1574266030389485.3986046386907207325", "6666098471884978382275596735609.48799", "7186695191806.8605870655031", "49.4068787358989734643755584597895", "55144578.693611328", "748656950650466.7740449", "196692374383.98059927", "96075.995383686123325518231178943380339", "58162544398872956248597041358.03074515", "4.4101664810873280477", "793573964586.2571", "186.19276666836267736678924", "696031107473.92822493882002", "81141099751200078.956473283606034894899", "781583540270.30954220226003785834", "857626305116267068401366.115088", "82.520866056945917855435355538510029", "94079183746.06481858292791375347262923", "7367926298991.955856109444256142424177", "609849.867692144296", "3142326150830595.1296663191527296", "42732404895221280.09886646384476963", "5641650268975661460628312.3243018733", "294020416040621628639929833747187624.8874", "9.2587992564243645633773436771894046894", "3226360398680309634026830422877.395924", "13673585688516.88", "4060064490335216724947069.9510874415229", "504031.8623706081250389639506551", "490674587056812845.434824", "374734491086937.72314091284796033252466", "50683021522499.21101226636502", "271860893576757069129219.229827236882", "48145906994646.6212990405915542399", "9299264004432414311737577.547504010", "55501024504922.701081913739265807239995", "75525.40160111225102549599549", "8318889996513.6981740898926", "4569632212319865172056.4", "4062899438811600988292028.656096", "499231860494753486954405000969751.32464", "21520.05901293954939595290", "7437096859.0675589", "81029663570918.244469883207", "9186255795620190489731649616522.2787332", "176081.7887578367980", "923170012308530583356784.7719575869607", "6721.02372224706539639671", "8705330727742294524.9568951113226", "71618680173527000.80197", "398702.78490649137608900086", "7915178565698.1456950", "2205758705943015.47173892436877233", "43.283813344951662974160", "7226695124.883640817", "71857938268.811620634", "33531742892953708.14330657199222506", "7610719.87027556495162788729356", "879104.3897112438855894253", "61.436238295282967", "27702593559.81406399", "217963081506562.26123800919635985", "5711554818843.7007275964777430", "4513608637089130166026853.07760071690", "2935022822465585230804.7207740632803201", "61609146018707982595.73941", "3178381.2758332886758359349534", "66751786441598.3225335075", "24809921486854.495243", "9703829057929756164896481120472.378972177", "10119945.18864251", "186559577359244724.74609", "94483095288872612.662488", "8475757249729962.45445188827406693", "811775620.05806605567737", "32073.1740976137854801539", "28475194677162.2704653259089053656740128", "31679281389606453997062090863.7556", "27.00150459687420663", "54.12822243339806750248", "607397688321532259049.97", "83600.168759106297865786627536", "269222612.9478621371149151", "22630991526699062.845650557519264374044", "697.6659352745522008263968976439207", "621382107548.80793219435125461359019723", "3734316578137.3574682738552123993", "761133370700416478737793857572346.2936841", "70559380408530506772998620838416.668", "1684654.47706870234", "339831114906.356553105438537953920305528", "39553231410619049452004.11149451", "459955987409735.66272125542738", "49775.7646175640800711339", "40619064.153010643883938176772286", "463530655.8534939", "248380052663.325684434", "224594086153906.37392639668975", "19276495487631495165286325406866.02", "27441160400595728156.68", "73.35576407099870", "85842263963835702868.59817451284", "7632385.006700062967", "27033626209245368898562709098065.6", "460701269014019.044007614136354", "353200.026342017307178750", "686414738243731840545063.83319", "1336219961150.6374171068957250155599", "38287936819.15506712394365", "413273993894495128520848792363.49374894", "9008435327741.9954103486663215674", "14479.5022237271120594", "27531742368087.57188067463383", "4707567922883853578713.61000", "724.683247974430000302458", "401.4726158541871197329747122", "2957753495863.9479", "986999590.67219004464", "5912602520130974.00238781664181376", "4794476774382554395126124732428.237751", "8727310239345509401227519768.30432131153", "660793747634056097783503172454.2729812624", "152114022428094912897024546670191.769", "2248530261888263475.331149340", "417893273452320470276826.883851739", "5.6600604039727085", "10608676483108106502791497487185.486", "7885752224894699.7927443415", "7482152887472.8695247743663389518", "273965686795789.154241596", "7.0129285110261065891", "809045429768562376.4632038413497", "2444759088179005744979099502197660.936003", "982075674166173933300.34281", "1437244410718587101594622114271642.569", "608788.94042618808014764199088835", "29571596492164233.25543895290516021", "18006985854229197.77147485809330", "594893842195343035031.700678261", "6429.3088495079703", "20670076736848975.1300940005", "26432761688671714690934801758498.012285", "63079563210.143854", "4261339763372.3639431685222629696", "818425885768.5415", "7639502.7075379515", "6187921491631775.241895480527", "33985209682.59166942720469012766126772", "51369459090376019416670.54995", "132229.87498252417", "1117664079.15820635757900248209", "63563534013050170259.980207000885944333", "1313.100250374846017940773003", "45695.844034012378091317", "27011201369763815883173329.808704477", "57086.1305155481382310", "2243876702104895141082.15", "8315782789.04218714", "89.538429280604353641819809424017330", "132.5457676883108754741", "4199681.68528677772324632111109373028634", "3461105231825580238.9972560195474420813", "2024.59379010546825272128", "1409504899248345001325948.9478", "71874601259.3555974395225", "519553.202529217337423121455679", "8834139815178866294659143501705.309", "20112765.3397083217512352233140588", "592433993031401.4871134372147441", "614806.0463558486", "7556587.710112923044259779868145702", "109.0287821461734824", "7430037017468026998271771850.0", "92880928699990518.03038718982674844379376", "1437.06165336931583189348197", "877283.112080745460254749209151", "84935015566054271700.4775090", "1022.668085083902", "75655.85538903757", "4704041287444436.501857812641", "7972312072047218454935929464832.2384605", "5971007220399.5615808795", "4.813851289280480247711", "205465973039326.71810", "9818179.006139333275062627094", "2320447697525355457631954.6", "372638328997508412995608693.748314", "6478999242349.939167469417069693473022616", "76375235.17057338896096075046456644715", "6306019983485417.994871155930", "176375921.187994932526970804212873551113", "6440024.90805604925", "5650763146614.22966210148574120715403", "2558948099039940893279383760.97115", "5463504102390826227875.187", "4392.9456214979675954886213695573082906", "667513.30704208328725", "4586889977486038.400519816722", "6907491743399725260625.005", "544823788564448.83783862259", "458271318033500233142.4800", "4179284163808.16753912324134", "4.28901668424970728759590083305417", "3665337095042344.5052", "474496014.00527836840084663301927648", "506.27601855433224", "6159191083343257648451533.7006", "5635996072846.2472", "42922.198145598083713694163652323398", "389575.24067305334134121056600", "75923043.750775930407", "2762161378732880872.06530", "805409218585744297497.6155734", "708883.094553577816405322680701307001003", "43921768113.70512049", "99181695559486157267533.042223514245227", "1456277723785445836864587991.44", "978.7523961859076607", "584240597389.83100658191977741", "846602576745430370.8", "55075484111369601295893144.25518808", "4955823567477427.36843629294308993", "660454049.30904132354493994384", "507599.95584341924792117985649", "4499103572.01265357681", "5152028616368609056173.27529264032318960", "7574428155770142642683176.657", "4162981116.26281620", "373455586771.1310577", "597433902255297209774.6759", "8573240241053626900.320", "9642.81380288170852320", "314892.251483669522016", "15268964831216303440102.60141403005477", "4675.317155256348765", "910908341752595316430290703434.14853", "52986155652440.23935", "56693802459310009605.498913674443946332", "3209855646522187683103070874.2501097", "2349927963131339845185632418623.023", "627568788.254036498309367", "7823.5627019897455142175080920", "54986561422.53428726090511915", "570306033595324232428896057.03492714019", "1968344.201618849566316405046153860", "568609582668094185885921.953167", "314511167240417911638.8271300747", "41135386867.5472205897962541", "35111495827.649249429034825", "78060536316400442559998530063.9", "87782400498408317815301.25988175456716343", "50574739009156928097638.74", "9390794325295778986635.73", "76107885658.86182", "9077347108.831605", "603610431407.7118915217159177737517174051", "2921504915284214.84562523515", "41756410817528510394.680055787826731079", "19453.82570547215", "287977346.0369709608902903", "713497528764028821142.342938", "86679.73762532784899458982115974895", "68101470243.91406628819745537", "449691674482376290865.6852368734083113", "30023013.54445758781663858802", "240545.2409427516", "310624583930.191431950554798662902645743", "83.133363869616802997751080", "65533484037678666858634.020204069501", "94.9470310106439748611", "8.738513209255181495", "90255.278032449171640758638", "27484522345062.49766092264938054319", "6.49542197394465774015", "877281313.2220188878596675165797", "51502896915.25483533412421157084", "5244805065173823084230913.210368683", "20780.772941811262779", "156355698682.754860", "987400002053.421616056", "6852763512305795261720723614.492009635", "588127794060820030.635", "20955.632790487916171861", "68.498853355568450324862016", "6018696633847.759383417852815", "74171089417155.3889", "5197576038.944010273633828", "93523860951657672315887854772.9172567", "40264.50456837426209344057", "371317944273521.80688097", "2888.678012031633", "30.96785926724018493818", "64299178104440618092885488.75", "2105452825225706627653.423", "639148821747527610.52146846108024230", "25286508127172.1442263837031923348153", "951954562498.89614601", "37357395330326306227.08", "7298312858851.910", "93.15367528571950463288574", "7562267407842.738025", "50809205.522353097568236464463608", "81133079.0709534110972951568", "530199905325259033.51316218296625144024", "8554630619106288841.189793198272603399", "52420824.8481888012234221159677", "94180007298456213140536333962540789327.8", "6683.890956958583558388846473573", "21523943626103.006970606", "1661.162659282822572978", "348752135067310978002691183.9597182860915", "28.011362593630239189913970962814466925", "92816305463346088.72878", "2078.289184015525", "9336.1673131174753008773757039566655982", "60041196212893556153447.94473346", "14603081.89509010658434423", "523530713522463844325234.324", "67114090919.298443", "380799968194012189.312450", "7246166603077222436200028960206043.64", "8550208.0964144409863464112", "706151247957475728021851678.307", "20618235607718353573977850050.22", "4649719.8079671806691", "5259645234.778741830099701784", "7.853007509167078435774995863275551895", "5295196139.130688934899509105038063968806", "99855970927.369743527", "3995896.98574253830747", "1342.5762039949226823066358274320", "463021478622748371222.0339", "315677874262963.5802664425282459", "3680500189183173649.0", "353380897707840327847.610059659", "9040757727.132232984", "1060727698.615284729326841067183715523", "5844595014692861.02254347", "66194238679.521000", "7788607032432.071044538883547", "29555259625914108746.12834275657773315", "374037.5444182834077952581915419928082931", "42684255319532483058376960588.16549708994", "499145635362902530.4218368", "50.9689203117105784202", "267028864.0219527735", "61892.496981033591815301747937", "7760614203852786.720374", "4788108642151.286773270978473102662", "64294629159344959.621342538370131341", "992430890676.38118146921399486971", "27735720833891.262107093997809", "509.7023784058586", "927442.7126945104630", "505314.550821600322422767553", "270563.87543789918", "59466035435767488913.5713877816", "242850687101554833413175795011948.620", "50731939.72953949", "416937.7846649559809", "875235027.404738711622861", "3.1003929802669621527248", "11761702.79108679", "3900418864433054.4046185", "760128140252767.574076334034", "20854965.6045282602956", "66205373508539397417.43727362867706884", "70563026689055032031.2945", "108634036001566.5155530950591", "74860847.241164792", "347320936917434.56801860466648545", "6868233075146193018729531074621245.0585", "216968104659104975666.48517329", "55389139818480970694067942.14077225544871", "489860.7957406021", "730388055875184163882200891551.5093392", "27996374685158156954348.91507768368183", "47436034985138616.05", "70073874152.7948084974953258283", "1418.166371209446752981135984790242", "8506457555339867.2148", "13808986721.84315", "1761603170710087623.97484461842001547738", "280137595713079270.66332022033", "342543.77167475039171384268161990", "7826954301770139.8355", "3796344992613989822.15950376504281", "12767414579180629.59893018053820", "4924572066210.335181754181", "9591458.1741857131511619229310611", "9814574362505.6229517534", "5235775972944571.943", "84372080.243342830048434860851280262875", "570.53676526242578291912813124197351330", "287310275.05336192928641647139873908", "94374749023211.98", "747255311518.076316458042832665811", "61508654.23616550181561819617", "4736849087961.91546", "404251667002784554414829130457164.5", "787484.8707730643616596591512961421", "412.25678889105537784", "9865513163061233492433.877643269", "18.02230059757436464", "4145036764.9767869586529593429", "89.448468123688505267159215147253", "4752766266108193527922.854858775109176506", "3280293379262514972171188223.53320", "919890638.171606451842378283815117030", "6085.66128764805317429469888657225735", "5945363004.993467", "813334240784693647.199778588", "176.183183217967818", "3557852746022005366413.0950332905589", "39162387869216623560731506.8394", "6478924240141132.6589632645673328827", "7597371103523.6388639687455024", "6.911593746118371682276962918417", "3676843.772149520981708909", "49.74320020460761410795979", "39343929.30508650", "8.95376651701961718", "9370.185172515369", "8.606859855239712513417", "4040214536917266.373111", "370.52173550583286106780293862125545428", "2806596.6099184276729550", "28.2102803308961484374459057496688", "91850168522784738229467710816816559.62213", "74071417.1901996698643", "8158562.2272175398048", "82135097680869547625966078.41", "4548304564385.356292569875283295530521", "61859639.61321044095253646051722239", "501829405841701.9412372491378", "4863017721515779.47130072128951204", "8147562824951436.1359351834696122", "813260689801363344335744551133030.729842", "10843.726443533387947717403625734", "732887039362309.380243357572319358164", "228817932757681301693399.51374", "773566070.160656364089194338890", "6637299939710127748.32501086816109", "68116949098.39216109383451431283", "18504370733225330.792423", "262716.9284523827542", "806556912879.77638", "514972799380196743793.307", "4.9485496617804188", "540.64911283519679669871099494454", "624748668225570.330030703956582", "72982477665134.32550562362696388272449814", "59226717991977669619872481237055563.5586", "7.7336909769869512", "962306.7508090898366", "74764119993.593176118", "2639372131341174.90275816315343476017052", "860414339178329786929236185589.9837899735", "42448.284133859495535", "10990409933335004921.2552", "59484857997580141402050.37385801080161", "642886681122212.1819333081468", "1144.8413084754820293459589", "265016999588.437882", "65827.6852455193274300931", "631393.37995429278", "559663945.155245534874005982136163", "8633969348466870276946658.5457700", "3700536996080.6065241359045632430258072", "995744312264579009564295639.9289434565829", "354058.926270422515490", "735977563309618597313152704.9534", "704101.3299829922824425127746884126122", "57163151.5563361109", "91685503773008041263670320076.2282", "7323276494528454986.99858603171", "25290826314182680032.21", "181479850180.51567349053", "98137487579431880.86697", "11633690967510172085.818855", "4090262384701736848915234786.136205", "732217847022994.231761237707688786541", "92052897.3929183740911620", "9694326365146278663.2402416935", "5753506948873.14173033041375081052677600", "92719041.3981527557787093979557031", "702943550953211.77284", "32460710976.6033092710411741165809", "2514027628569.73997400", "148253.755749417391349", "99446312120006472183.41011767457533836", "1874522309461532496640676388870.12", "7123.694479099293655234001868", "8001671219922.993610", "1.64633964677915330440092977", "89.89196235050897", "7935994693588308.132936382811709342", "83722428304449.18053581902291535070950", "3787064373989212.52305678593255063", "445215531575.60274", "77832104278.22491819789106758428119301976", "42963416769500876080435173.34", "13325994279359737091228645475.5", "28168903394.1984535280670", "20562.72706472061983560159787721", "292186238.846408413600786923045420805", "7364306287156560.802", "83988083092502547538010270.95019389", "529371.1830836564", "24518460641818102067027474.3", "7615264.90697807683213691437895", "732066125607.3560", "5872016220988.362396286712", "79723.4867781935982409449170404", "549933.3631037353794026655573214171", "20591326.16715989096920407", "45278979735.7769372", "7497.9603196806095", "402156599066884306495793665906580146.49", "57910647620629187784816.026502118548", "176969.03109408698533103", "378887693078135.413997", "339096215981544416497146.33", "171561717.08369340", "39213.5049623386707554365", "778988269032126434.96538801852369", "21001981458705209425800.05097795", "97.5310634121978097398", "32557061690237179348860720930340947.07434", "2850522376247.3273294577417174", "218370399224999010.13", "89.6143562979495675", "8581126.920675766070970002621770282775", "85329.00512777590", "665430358.7122868340626373442261798150", "3048146.00725593656561819204867929", "40.75381588325591570988098090241", "92659791531290000.4832941248", "353240309638.27909984", "5486303700.2507961297840", "63410.2502811748749", "7978658827.659801326459834858775978", "10.97574358843249139971920994", "16673172354.790569", "693730652128.854719360499587265916", "65158390.2095951572261432607575", "49908875.72280387612190", "3695110202410727274.33660657", "7186250614311256390679197.0702", "991378133692.7217", "298557423076270.7410355234681", "31727056371762.66613593191063", "360796513021775528838336922263.074", "347380225280419.60051083449423268642640", "60537626004759265528.1959630329", "68635486306633928028044713700.05044", "74718705133.0453732875", "7731590999.7076494", "36162959457314177100457.29461718120", "3574881019092427105531.2234238751192", "6.186863686940218", "174342675.444314964383265452", "73462408063.347699828893809", "594970842301225298866.11", "94.828832071165097928328626556", "157032567751128338089813.28235", "527105865080.91516898069523", "4253265222132.32532892247821637113", "1664799455502946346151.3968102970501856", "9984262426161266.0779859", "3720.21438358898376369491", "74663614611450.891261937565937", "17106.46304127576492235460474242", "6723280655.3065479254350118946444454950", "170114998642125427027400.79281593158", "83826771180997756344933315.2105869", "4571461900273856204578682.7789385", "19294679.0503802328379", "1053431117671882469209133004190.1994", "509743242952860032664451616.062851627", "502321010184.93410881", "3093862244853.106714546815610858453664", "835909535338724890602413803766798.5285787", "968702039.514066134052625236191432006416", "5058.2554650664460698567097941", "47401628261455374993701096.8848623730520", "95757762403892.559294167", "91287308100803038399.98905791102974596", "8588873829498044224.5", "90.608331088978443128493977104", "881.4868498823351378349309314339130669", "996.1819556499729", "183738296987264585071.03", "687931070187061.82764425", "74258.671174186250611489739377908799", "84692370235645512.056240530504203", "280992.1073300285879739", "495782559844.55482833026279", "1323589.93703259082106356071004", "97071018704090864771.928679355990288210", "945710574196677.77314", "6371906876771999691.0", "365802.554407405843269923007748", "126832616.449100461633494955582360768464", "72916681380061212.28150529690132", "181638580840.3288896955563150371294603022", "4793.4732004494597", "1.96276047485854739230355836", "91809.43013098906", "350168522.1451024", "887329.1848152387942875243711807", "970842.5454553917247792021205149", "86043470.245073753223777701792546", "65985257628081891575601991.7", "82.388355271235352380749469281263852870", "506534445700051290.092", "7657869.050168209394080269780605", "914148494913812.18722011482030046", "632926314098333.95", "4542482241666476399443463.7305272237", "508785129386206008355189937.40792", "809370195199550581480513.773327056258", "42022.0800770158415436749265379625565155", "79.24023250623189152160656392623551632566", "844344029655944046408585712678061015975.2", "60718896.9016526967683016928055", "24169.87617736769723209446305", "932946712038552557504165607.25738751792", "71929.091795341175829231371170", "580756575854.349889585062660224612316", "5334467027291674407261739.88296", "647883.6952008490093408", "131847945404902128382.33", "635.32435994095251959006", "616417792339573.691", "8721744750999.756", "2093222215569670762061324798366.1946", "884.91509171758325834287", "446986.746552220911085379587351633418", "2588893802530888978.8593", "90920836536367934266.25", "659425351.4897756889526426614787", "8413349552350.4676289", "109071551833.62477957943998107069466341", "80592963296079795358.9964583", "51597.48031535217630761112045048173", "137195892880209.5219609", "2757898885196166849276454.454", "6516140177572989196.9", "71507.272226216195904103790337", "2026315950397.1306073415319138604147972", "71.633446229996348592844157337515933", "162888458501088741915433814107929.969", "66385095097.3126422919960748", "79845174411603029806.09013581079", "69.6792787587247101873347374684378138", "488104113631979.4521", "29878.55222065584771623169748", "56891.058717434275234", "58550342716400595004901.7406609", "61901420576.5877381184629201", "582484240162500730332.793", "344431600768905.31652334656197", "4522060496001640715.7611123480", "46771554455981451565.63565768615213708447", "367405968281239746142549040990.23773", "674.9939104439637601", "7569304913360.0905249800", "38355219348516881599511.417", "7608044909620.6317", "3565126187544725921.015946", "583939245945076.3752572838754371184126435", "584717064945837055.2946708352", "554492098010546462359792.610923279221", "5.845521014077245", "711618.0210553553240139049630", "302336784086831436245627434985772.4304447", "75593384413465131808448110.6861327123", "365.548329149012442398", "570335303.4190701469325194998621", "84.5348678148876156817", "18657559.29249508158204762423906804210", "2404335696032.595620014714565218526491280", "123621.3065136102584576403", "520049412036953966667473336436859999.7974", "372722.51673563504475833821640495312483", "37516997786042198988697038319748.76608", "70625819.219735653364274805237086", "4356541304646050484.4", "6.392453863378936", "7846.67793546156452", "5822856472371046439244953178065552.23754", "6867259972705150673317.9196664723398", "91544892622806607357.2059640686401792", "22.62509939578374817586349390640608", "387.30155801723807280589230", "8689523738731324393.64", "46163892805948895841989526950484.413628", "658336856450.6914591", "72532670828204782401.6", "21545.2187058718311983869", "286.64612118894929424306899519511", "487416735198700172677132798.794286800225", "9731139276205938704772193766.483673", "4694262976401904599453875497089096.88590", "54.831458660476790", "84047900952766852.2766202678", "415800597142003361655.2497", "78521.645042493847557184", "138276606776086902450813468747.43", "1363.54038390620740431", "301337628132.393798353839", "369991437455.076096", "67845777976.05512452452550192", "18572513185.55501894936285823", "97826464202451917980102.3168471574066", "189697876211507395.1970902934", "987581955785256777281.0", "23096631721699215862413.8", "662190637554698.82742", "6818466.13009019689", "2.7586364550794292676230522563780333015", "17122292.2135854974112388229865915", "213381748288093.2539", "938.027317021190860", "7.5930479709084186418396076696", "300040930992070898463737226498365.1554247", "831234937.6482687331567527678", "84.21868903350285", "498.034318765486491616", "4952288659141840797020.321047394", "63.7140438130467372063566", "54733.85988401966", "969283019886119284.5907494344297128", "4828044.45854304434006097564598578339783", "1.48810788659760431662061191", "97409080.915910873243026319046920112", "5116921349391.015844838695305025068706", "24377867288180542359009639.23724187129793", "27.2066300879612022843306080378970915", "614113234911390773992433.5443831707880412", "555973743084168429498535.6545945742", "454622102.7616344457606440812", "2.189302220995687480582", "9000093999538461264627307.14359099", "2719495322280568926.714920", "378170975239688749972401224915072864.68", "675627182337.54098", "893164953.2204251", "89667750402.010414898368725", "41910533905378596894149709.76", "75986360706835161428928.2273223799725356", "823051.000651780800369", "6253141830183537745971266280.5", "94024147546803642.1827", "461402.9673842484750435974323791", "42356152.15019463404939806670", "2832246469677515617805535.791581252942", "838825780612906558.4835747012000281698", "415053215095094389187857.5155422927689", "142371498481459743095595079.02645938014", "2310400676536015.9412868743357142", "7043597288579198895.14080", "20586542595988470833449228952624051.62208", "5509850483265.0144082301814", "4111245676913865797399.476830696", "29572331108716.0967159926708964711769231", "939634.1275501059727", "9427.40806991962828", "7206.75711212238050844", "1521214.66582418903000292662590", "95068801252839949692.464077084398", "1310.040234908867777264235223", "1540820655585.74723292482", "12595528584165183759579.017882790512", "902685326462.1026332572886", "5580427130.39759048470854643398123", "4570818594302792830711.68940475", "2104242.636554259796004127631359885886190", "385522523264719016198.728190779058213788", "2188462423.0172327612", "45.9552226568036858", "438945985.7017657922341990896761840515549", "458352990581599596816519.1417257181", "860.1332281020727007547026073139304", "447132743220883356110731390.3", "213235639059468414270923919.08", "33866.719476448048946910045548465", "290.2740133805909", "12608132561204696364.25", "46646118802.603408937517147", "2092773093689254.16895215626231259299547", "73154500.37374782297313203516570167334", "4458.962442078169", "138610161582164522914845509410868568457.5", "627.4216221355928", "2869242659791078836.529155635181430", "8911447823778936628.5282985506834479", "81331896500852406429099500.019756186", "14863876351.50990145161", "2285083230.02489226", "92125093802168.234", "823935825442031801.8367858972877271916476", "394947970658617502617.7739", "5325469497217340690.0572912956093210", "381408922833697845846.1", "2762054642.039558165104378514404", "57.7037349811583328230378154660074", "33.0111853615335197986953841", "71555733645597743.60696539136379", "38.92772930249029909619019550", "5330769113910.486", "135423299412390.698740", "154352171068366348977554355980925.27989", "7083946150396.510302048617", "21464480104772393426734710238.9394563740", "4988.85168722485321945871763553", "74.0593502742035679358915371707", "313811.36907717869944256", "6.89109264002246619619839", "27736808987652046.74817598", "6724245179188.349", "704338447.546420662528", "42.276770563915377", "272027111195596756848621098036.1547977", "570904252.83601018074711444110049105756", "55243045513065681360777597.9512164", "81885661306799723108.70609614", "59781089281755.520892880872967156183718", "925.062219342905823439512", "163070136.45322606798699584285203", "285362289640630468392448696277206516.52", "8501128.8794685246", "23906982.9511774284685428", "55598.845153068614586414053", "766074409808448000843.01", "453799019.0404856501", "197643775076.90402", "6449.5235154246208448096964310430824104", "9684810955065508.113896650", "47792.500401319287778", "46145293425006932.177071185869700157", "98.489199802796584711348554023", "109810911150847839534152301651.304", "53.56250101040774", "3628.923117764940508733", "724163.229036308563323940185933585809", "2181699016458724993802437435.95", "428672058.337421643451576524791851", "4158724708077284659211106.70", "2109422.358122856", "4.772537710957233974419883393890290731746", "149021478743051645.1906259", "853227.936238023222937920646134", "78698944621525602504289614.9", "7956638.10883943170867784790474283007", "14.21894105715880202931758934290", "58460413852.97169", "5542529906509172126157821097499897451.832", "29152009172.755880577893743809255625833", "597540029103629403793511466.2311", "9787.584675361046456499526619505109462", "3369664057126760251100421313756164.59", "62762188022048397216.239123015519", "8618414.34172838314631981433", "308876505484624095858439135416594.0909927", "34543524067507.888", "69970669986513.59161", "491604687528338638.722074", "2968781737500296658629.209618772301434", "95055963020084.5484708019530744", "763758423265132684956.800934449957", "191616272581144077255627519.12", "474423497264607.5", "90070552209087.5489461910825", "42741634.97318580695", "49583013803194454429.06", "9337357179776550.5393332790", "463337.4918624703829", "924593.93178628968", "460356714008943219874649.7000090799", "274.6808255676406133409638365717768", "565320911631397289.4225949", "1811814478153911720403490082244562.809858", "98215723726690821876436.61499", "3193741503.5445437", "7843727486905.29222894215", "2450.103578203623", "77628741.2481681002414889730", "537638078728887468.21091", "63091149371.447643779", "6055.90483130872919883", "998742820370.438827", "9428528.3742338460745923254238657521552", "560910529415061551072.879339104", "8868758929552737.6344389073081", "1609953696413302974998.215823866454", "4642212.80842444086934", "1080583622.324681", "17995.9466893954706223", "2759.487643218110402734154", "9672860135.8265331504", "5360612344.79814307292535069258", "52621701200967328746730.9160391", "828133.9168653106285144227322", "7971513064044808.92259", "15389.30345321183782732468079", "989651688.16062084946", "7990856193403.3002936672072", "339449972888214.4700435790043530333690923", "85111247.468129383913164266889148858579", "29889698217797871.61023402159949453", "888683448214707721112440643137212295.3418", "349.0454726207018", "719446465652.4316910939380114", "317731764666620926.681", "7114.9070463571423911310071651529", "21398843376569219068245236.49285", "482699518340355.1", "62709337.7416967999751", "2241112426396790589015682713.38", "398.69734842524175251783666178967", "8141789445943032380475738794.725909783525", "7535645513772050174755455154187116068.148", "338664.17758203091382212614906122", "537728.8461154989166265750", "52577.954227333052", "173566879049.2243041657", "32938665729708.43", "23884646340702718470.7", "1060585262068739.0991569552135901727203", "369795294.757917918049247059628", "208970452.907260020372966081527", "406235360600199.29829", "9792741592.55381930236271426", "31292758903783394430639.3", "432542717863139333.8086232115961395725669", "97128496194.2603846", "23137.3500137989262855278084", "83044.950122694429152", "95288.39340431035880678170549079401390283", "3459061276875370543706052.8802", "63121667821826398725900133.64866569951", "50547005795.99782759448", "15880.45479497892201063338", "35458592397575709208514.87623638", "87856046044731623633382.56", "734608925131126940687755000.4897316047349", "31470136486114884.871", "1314942291797.88838552", "7084320368126.517001", "97085766.6497493755973769054978857698590", "50187.26290709385971357557", "4221.20371890344886836", "149617632661985424.92", "847730495022412538480857.95234", "87670446528341622004076.5848", "55.9926444316832736419920", "53619849829258.8209088583460760350", "9375905826296856585047.27"];
+
+lexical_generator!(atof_f64_long_lexical, F64_LONG_DATA, f64);
+parse_generator!(atof_f64_long_parse, F64_LONG_DATA, f64);
+
+// F64 ZEROS
+
+// Randomly generated decimal floats with 1 to 12 trailing zeros, and small
+// exponents, which move the trailing zeros to the exponent.
+const F64_ZEROS_DATA: [&'static str; 1000] = ["22313152468267300e-41", "900000e-17", "65769713819418900000e-31", "3425908988799900000e-25", "535621332000000000000e-37", "925824000e-7", "6820855328000e-34", "132600000e-44", "1866092499218000000000000e4", "9000000000000e-8", "7258019190e-15", "15000000000e5", "2000000000e-9", "22634000000e-31", "1748755191271000000000e-12", "65300e-7", "799699205636340000000000e-43", "6392539000000000e4", "1103980000000000e-2", "30617868977880000000e3", "10e-33", "96808086465803000e-41", "9798304684534220000e-38", "8085220000e-37", "983120943300000000000e-6", "2300675569752900000000000e-29", "184000000000000e-35", "714845140000000000e3", "43059000000000e-33", "224420223337890000000000e0", "48913330640e-12", "3646000000e-12", "64960e-11", "57887699068900e-3", "97360e-26", "590000e-13", "9199000000e-18", "4036381850185000e-28", "82049000e-27", "2866067708000000e-12", "534024000000000e-29", "2176427514080000000000e-18", "29969090270000000e-37", "237000000000e-43", "8246728915400000000e3", "2626603823997600000000000e-9", "70023593000000000000e-29", "77174860198000000e-9", "3000000000e-2", "986430603783600000000000e-36", "12858840249000e-11", "420000e-24", "40109410641050000000e-36", "73800e-22", "538586300000000e3", "1956779957000e-2", "94000000000000e-43", "29765316631000000000e-2", "653588057000e-3", "6220026260000e-15", "1639378376690000e-33", "10000000000e-7", "84282513688000000000e-42", "62600000000000e-44", "68440000000e0", "677353900000000000e-36", "480399733655880000000e-11", "34420000000000e2", "626916781000000000000e-21", "5930e-38", "57843272090010000000000e0", "49000000e-25", "1594893300e-35", "80000000e-41", "9142594322280300e-44", "53791500000e-28", "60920000e1", "96356000000000e-10", "364603550000e-33", "619219208928498000000e-11", "163100000e-31", "16934653153000000e-40", "581119480e3", "1000000e-10", "990790499956800000000e-32", "1300000000e-33", "40491431300000e-10", "28897331544364900000000e-18", "300689733106600000000e-23", "309716500000000e-3", "2412735707054170e-16", "9191600000000e4", "6730180000e-39", "590e5", "82590296000000000e-37", "220e-32", "6985481273450000000000e-15", "61550000e-42", "886800e-33", "577978023000000000000e-16", "404468310000e-43", "444600e-23", "192071400000000e1", "87089511940e-44", "91300000000000e-11", "87201824998700e-29", "4956113550000000e-12", "73555762434010000000e-22", "333394957300000e-18", "748235218000000000000e-5", "8730000000000e-28", "489660182000e-38", "40860239330000000e-34", "46600000000e-15", "1000000000000e4", "48602253113327000000000000e-40", "96000000000000e-13", "40000000e-19", "62100000000e-8", "79104857293861000000000e-14", "77296677121350000000e3", "43062076600000000000e3", "4060342310925780000e-9", "2710000000e-20", "55949020000000e-45", "3680257000e-40", "67091681815161000000000000e-28", "5789648388400000000e-42", "64619255901049000000000000e-21", "1800000000000e-39", "2546572757707690000000e-35", "1792150480210000000000e-14", "31110578847900000000000e-45", "52690000000e-18", "4576000e-11", "40610300000000000e-8", "7156098400000000000e-37", "4512533100e-23", "3437944100e-22", "82956040000e-8", "734667000e-1", "525516800e-12", "59076622417900000e-43", "4824620400e-12", "701300000000000e-17", "16680900000000000e-40", "89150269476040000000000e-14", "9043763409392240000e4", "10722794700000e0", "641694293597600000000e-1", "30201172000000e-2", "4437138793521030e-20", "77441889672553100000e-25", "498932281900000000000e-32", "923401460000000e-2", "17613573610000000e1", "9030578777880e5", "7049492327926800000000000e-30", "34641102425624000000000e-35", "9886859420000e-41", "95020390640000000000e-32", "248933971000e-7", "447059340600e-21", "686170829932900000000e-25", "1133583988122200000000e-3", "7373871821169000e-20", "49761400000000e-10", "6341282809000000e-24", "76994566260e-1", "963819828000000e-42", "5000000e-38", "1104476439020e-20", "6421772000000000000e-41", "358256093800000000000e0", "7655831997070000000000e-1", "6119363615110000e-30", "462350310710000e-5", "198577263480e-41", "660800000e-34", "944200000000000e-21", "67853000000000e-3", "23166682978800000000000e-41", "12208412000000000e-34", "7230e-5", "132342090239790000000e-1", "137301640e-16", "718305256400000e-16", "5154790e-41", "42000000e-36", "285054828840000e-45", "459723950e-35", "51636498000000000000e-5", "264318824000000000000e-33", "595643185510000000e-12", "3610000e-4", "11200000000000e5", "70353600000e-19", "34139206701000e-40", "29038000000000e-15", "9000000e-29", "910000000000e-38", "2397138886000e-16", "1620000000000e-43", "8141830384950690000e-21", "14000e-8", "40116654000000000000e4", "355546881000000000e-42", "4341229176229800000000000e-3", "875500e-40", "21800000e-38", "18126253999200e5", "2541339894728100000000e-23", "920295170000000000e-41", "36871900000e-16", "8557480000000e-28", "17000000000e-9", "22418104472200000000e-39", "253697830000e-9", "90000000e3", "4698822000000000000e-42", "46946244831811700000e-41", "94433000000000e4", "762008667730000000e-33", "7422619550e2", "8818100000000000e-4", "494454260000000e-44", "800e3", "60e0", "99000000e-29", "69133187214000000000e-43", "7899330786100000e-40", "10e-1", "645854002390000000e-4", "700000e4", "265800000000000e-43", "4274390000000000e-7", "528886727827000000e-41", "6788522282994500000000e-34", "264611159603900000000e-43", "672400000e-30", "84903572000e-38", "53875615240519000000000000e-34", "1570593406600000000000e-37", "30219896000e5", "3983423360104000000000e-1", "340e-7", "72947295838000000e-12", "603873136600000000000e-45", "3070000000000e-6", "800000000e-31", "492465563135214000000000e5", "5052149000000000e-16", "28949977460000000e-41", "681400000000e-23", "17401020290000000e-17", "460683100000e-41", "666227538900e-28", "5883693500000e3", "3407391100000000e-15", "8339927795000000000000e-45", "41470e0", "69507964473300000e-14", "7550e-44", "6053012431575970000000000e-35", "131000e-17", "3931246770000000000e-4", "666000e-35", "80e-22", "61000e-24", "10229598850000000e3", "800000000e-27", "266000000e5", "4087638395790e-24", "10098700000000e3", "869835192600000000000e-27", "980486012648800000000e-29", "70e-11", "1590000000e-28", "820300000e-28", "392873332130000000000e-7", "622758975300000e-39", "25060511461670e-40", "650e4", "2930716224303500000000e-42", "68300000000e-10", "85904610e-37", "62453650400e-31", "51543190000000e-18", "8489286832000e-33", "79003197963200e-12", "5191577400000e-11", "66419216607538500e-43", "71943900000e-28", "809039200000000e-20", "90814155060000e-11", "933370e-42", "53673340000000000e-25", "9920434200000000e-14", "7973900000000000e-3", "6334263749000000000e-28", "6560539821975000000e-30", "8781426957035900e-37", "942505414470000000e-5", "848010000000000e1", "236309089000000e-33", "91596061216000000000000e-6", "871553629883710000000e-18", "4800000e-5", "7220279794000e4", "8746795400e-1", "317452900e-8", "57491345000000e-5", "88966162500e-39", "2340e-39", "80000e-38", "8182700000000e-17", "812327266034000000e-30", "4179029769428800000000e-20", "1874000e-25", "54140000e-40", "4696200e-32", "4616016000000e-45", "50e-14", "511418000000000e-7", "347752000000000000e-36", "2000000e-14", "920e2", "421367338000000e-40", "5866290930000000000e-27", "350000000e-22", "9320000000e-4", "4679830000e-34", "2011700815945000e-18", "563681000000000e-34", "199873400e1", "44448968246464300000000e-18", "10000000000e-3", "303174000e-44", "30780000e-22", "595441543000e-23", "24690000000000e-42", "5495692592000e-2", "282000e-38", "3500000e-29", "9502673373000000000e-15", "555347163900000000000e-35", "36998375800000e-24", "98077049312600000000000e-33", "746088379512000000000000e-5", "8155000000000e-39", "6839920053000000000e-36", "43490000e-12", "111656000000000000e-10", "7510e-34", "40229233764353000000000e-14", "9862199000e-28", "93000000000000e2", "90280000000000e-38", "19565000000000e-32", "59850000e-28", "4596359000000000e-17", "8542500000000e-17", "82500000e-32", "4242831996473900000000000e-21", "100000000e5", "220307960981000000e-14", "85443300e-13", "8717340986650e-12", "8259448000000e-7", "17360000000e-19", "614757028774259000e-8", "9968000000000000e-11", "7906563569460e-35", "3278291128660e3", "955240000e-6", "2640657500000000e-32", "19600000000000e-1", "369597401936108000e-30", "853448114999390000000000e-18", "500e-21", "7363316120000e-38", "85617497596800000e-27", "8632877111145600000e-45", "5528640000e-35", "9980348686300000e-41", "4987782958639000e-23", "65900000000000e-17", "36331771000000000000e-3", "86192166595273000000000000e-27", "7444520e-19", "492162458500e-30", "4136933826883500000000e-41", "2506913767426000000000000e2", "62890000000000e5", "10000e5", "9970000000000e-29", "367000000000000e-7", "470484508554000e-42", "923396600000000e-45", "90193000000000e1", "3000e-31", "400000000000e-45", "395976121865800000000e4", "4808172501000e-15", "299150124800000000000e-19", "5170458324285000000e-6", "97763767600032000e-40", "77986784878398400e-28", "38482718800000e0", "7000000000e-1", "930420645384000000000000e3", "15000e-5", "509872541393400000000000e-37", "576319260000e-17", "103070e-18", "880000e0", "827664536738485000000000e-5", "2900000e-26", "2940000000000e-24", "68510000000000e-3", "59718205800000000000e-31", "10900000000000e-25", "10417502845000e-3", "1000e3", "61486679315200000e-20", "9128600e-15", "412759154496000000000e-18", "61000000000000e-12", "6526119702000e-19", "3455178155847000000e-10", "290891911419100000000000e-21", "40659123730e-15", "231947000000000e-7", "3680000000000e-12", "88770000e-22", "756391570000000000e-14", "9763316300e-27", "499134530000e-14", "25101377279000e-10", "26000000000000e-39", "2713903578600000000e-15", "183070081462930000000000e-15", "52664346228000000e-20", "9000e-22", "47100000000e3", "6000e-2", "184874529628300000000e-15", "37244725396260000000000e-42", "827000000000e-25", "122533000e-3", "88166900e-8", "3363000000000e-1", "55820000000e4", "1000000000e2", "82243083024000000000e-3", "1700000000000e-19", "3086253163885600e-45", "146910039591400000e-6", "3749570000000e-13", "98000000e0", "690000000e-36", "89975953000000000000e-40", "6342512780e-37", "889706677700000000e2", "94141635500000000000e-4", "7957528235000e-18", "3663500000000000e1", "53700000000000e-23", "28008430000000e-39", "7697219591676880000000000e-30", "893202000000000e-23", "6949000000000000e5", "74086130e0", "7348339593000000000e2", "6460418729067000e-45", "59281200e-28", "2576889750000000e-28", "72530864200000000e-37", "19195471006955200000e-45", "59800e1", "1900e0", "74835761840e-43", "117869241724884000000000e-1", "9074844000000000000e-9", "98180858100000000000e1", "195215000000000000e-1", "89386686120000000e-5", "400000e-19", "324370824200000000000e-26", "730334297710000e-5", "2489000e-42", "580000000000e-41", "672000e-32", "318534000e-37", "764190750e-16", "902827086957530000000000e4", "8603596844679000000000000e-1", "491795651000000000000e-34", "9821914500000000e-9", "5680000000000e-38", "300000000000e-27", "7836073330000e-12", "249195124200000000000e-8", "839735406274499000000000e-16", "135602610000000e-4", "6077950702424000e-32", "81315370225138000e-22", "4000000000000e-40", "177775650000e-10", "56000000000000e-7", "8152345737000000e-44", "980000000000e-45", "664001960000000e-34", "25000000000e2", "8114940000000000e-41", "830000000000e5", "6940427543771400e-42", "96000000000e-20", "2773512778319580000000e-28", "271000000000000e-33", "4902327000000000e-34", "591197888455000000000000e-25", "5473819890000e-36", "792807522500000e-14", "622059840e-12", "38808996000000000e-14", "4836290000000e-32", "76040893347000000000000e-15", "42097200000e-27", "5376368818800000000e-37", "1158700000000e3", "5474765200000000e-2", "896601000000000000e5", "767378793500000000e-24", "687000000000e-6", "13109215700000000e0", "500210000000000e0", "54468000000000e-44", "62218560192500000000e-41", "19815462094600000000e-13", "300557000000000000e-7", "3319964200000e-13", "490e-2", "9359114486380e-17", "1163100000000000e-40", "130280227163370000000000e1", "28674269709800000000e-10", "26090582686989000000000e-2", "4511090e-13", "4959379000e-15", "59215588071000000000000e1", "50000000e-7", "4019334969249300000e-6", "7435945922151000e-41", "8030669040000e3", "5000000000e-36", "700000000e-24", "10000000000e-37", "92687130340357000000000000e-22", "714827453500000000000e-31", "778347598000000e-1", "620e-17", "39604889474020e-28", "9259782883730000e-32", "9896583280381400000000e-28", "918612100000000000e4", "45591100040000000e-11", "380e-14", "9560e-2", "341033851770000e-38", "8277666680000000000e-40", "6834536810e-1", "391357470000000e-22", "6109600000000000e-36", "98960413918500000000000e-4", "741216000000000000e-15", "18390350e5", "4318145997960000000e-27", "9682613178528570000000e-40", "580000000e-16", "59901299404580000000e-33", "64205900000000e-12", "8073000000000000e-15", "651159311613300e-30", "3000000e-21", "2292623000000000e-37", "80021942000000000e-15", "93200e-2", "845616442173570000e-22", "1286007706711000000e-44", "68918000000000e-23", "162848000000000e5", "82437900000000e-28", "863616600000e-32", "84900000e-26", "323809000000000000e-2", "6375031000000e-16", "5826471828832000e-2", "75947613000000000e-37", "68800000000000e-15", "9260669000000000e0", "159700430000e4", "11005000000e-15", "18073502464358000e-18", "8095208158700e-44", "8787883092667300000e-21", "179722147000e-4", "7211650e-13", "59278707558172700000000000e1", "521438831877560000e-3", "3115707651000000000000e-1", "75125325062344600000000000e-43", "5288550543997000000000e-6", "1552017002660000e-37", "727259600e-43", "2039617742755000000e3", "7202525230000000e-25", "23299700000000000e3", "40000e-17", "7652900e-45", "2459912170750040000000000e-11", "287614835513000000000e-4", "7811254461100000000000e5", "6363856000e-32", "171149000e-25", "46568879000000000e-35", "700000000000e5", "768170000000000e-45", "334355020769300000000000e3", "21188754360000000e-22", "65846250600000000e-20", "313360000e-28", "13656580000000e-12", "3700000000000e-13", "43619597000e-15", "65653561900000000e-3", "1000e-12", "277317430000000000e-8", "693723000e-36", "333793300000000000e-6", "8956151700000000e-2", "400000e-18", "79000e-22", "221970000000e-40", "281820610e-29", "175990000e-11", "144900000e4", "90000000000e-7", "62857060000000000e-10", "96707918977000e-45", "136328982817029000000000e-31", "71609251371945700e-34", "3210900000e-35", "8139062539000000000e-13", "50966839000000e-7", "6662605476300000e-31", "45191281589400000e4", "9086428591900e1", "51465575324600e-17", "100390000e-30", "3894553500e-27", "183600000000000e-3", "90000000e-38", "80000000000e-18", "410000000e-1", "60000000e-11", "2122957101700000000e-30", "8363000e5", "8392774000000e-40", "842152812358543000000e5", "138657400e-33", "9874425305500000000e-27", "3654800000000000e-19", "6472249620000e-3", "9105800000e-39", "5093775770000000e-38", "770417512031700e-25", "8400700000000e-36", "61451000000000e-37", "9823928000000e-39", "371435688400e-30", "6000e-12", "2738100e-45", "890349855330900e-14", "8329000e0", "95942728000e-2", "35284795694710e-21", "5519930023320000000e-38", "47855400000000e-13", "69412746000e-25", "53676592985000000000e-16", "8474650438000000000000e-11", "230000e-2", "11187842000000000e-28", "928865202346687000e-35", "87000000000000e-43", "135224040000000e-24", "5900e-16", "1256631583000000000e-25", "427970000000000e-9", "498000000000000e-11", "546902674500e-36", "45296241932000000e-31", "6775304798500000000e-12", "224500000e-18", "4000000000e-23", "4969418100938000000e-23", "631631023213737000e-7", "431680000000e-9", "823264593586850e-8", "340e-20", "19170000000e-24", "35758686024980e-43", "97840000e-26", "140461161048310000000000e-17", "115673276592000000000000e-37", "123540e-16", "9992000000000e-33", "8440e-25", "82876500e4", "100e-45", "49767661926746200000e-33", "9163300000000e-37", "63144751669000e-29", "1080757520000e-1", "210500000000000e-23", "9475000000000000e-22", "2109193756444000000000e-7", "422486355135400000e-2", "877102127437200000e-18", "141502800e-39", "423610669100000000e-33", "84645392905700000000000e-33", "64546733497000000000e-34", "9000000000e-45", "600000000000e-12", "3700e-23", "330093031170e-33", "8000000e-21", "830000000000e1", "50000e-36", "313520305600000000e2", "5933000000e-43", "6335643704200e-8", "30000000000e-20", "7952690019000e-27", "4378441000000000000e-17", "350000000e-34", "98240000000000e-24", "391520000000000e-29", "5010347333178900e3", "63457633243000000e-10", "44026198825000000000000e-22", "400000e-22", "73047769980000e-31", "19554746741000e-24", "153890000e-21", "836738006826000000e2", "562392469184620000000e-2", "2230552800000e-44", "24900000000e-5", "2157800e-31", "88778092170e-9", "29800000e-34", "6200000000e-28", "728103470e-32", "8028501751700000000000e-10", "84723788100000000000e-38", "58300000000000e1", "2046853001566300e-14", "8600185301180800000000e-27", "6711662270808000000000000e-23", "5926954160e-28", "88069232773700000e-45", "1201542590000e-39", "82382860831800000000000e-40", "8237003100000000e-21", "528729000000000e1", "9153000000e-25", "799363000e-21", "315828300000000000e-16", "2342533818306500000000e-26", "298000000000e-40", "5803400028000000000000e-18", "59390e-19", "100000000000e-15", "798700000000000e-29", "1000e-33", "5801000000000000e-23", "8178096500e-33", "16676172340000e-23", "27248130000e3", "968998628852000000e-3", "600e-39", "8400000000000e0", "600000000e-41", "765543000000e-25", "5000e-9", "15812826600000000000e-11", "12827175155601000000e-23", "776374549665847000000000e-30", "175000000e-40", "826300e-25", "474975342221161000e-31", "867343564233190e-7", "430953588991000000000000e-5", "32090000000000e-44", "135208000000000000e-33", "7074200e-18", "34663418299100000000e-8", "49306561653800e-34", "3441590000e-8", "3211289242268000e-37", "6908940e-18", "922770e-34", "2984800000000000e-24", "843986360000000000e0", "430758420000000e-17", "472000000000e-32", "53809111247100e-44", "7850939132700000000e-6", "900e-5", "795433410000000e-19", "200000e-20", "6409600000000e-16", "2054722100000000000e-36", "5564807688470000e-26", "19184881754700000000e-40", "5525077130450000e-39", "596213900000000e-23", "3325812973800000e4", "7700000000e-13", "465335752481828000000000000e-15", "972987298723470e2", "5151665896246770000000000e-1", "735179203354470000000e-2", "1138424463419000000000000e-5", "69990000e3", "8291097000e-42", "8961374980000e-36", "716832611775564000e0", "515383160000e-14", "5342500000e-22", "930178000000e-27", "79331620e-26", "1550895200e-4", "33322610000e2", "1967731000000000000e-31", "500e-27", "39700000000e-9", "603459596000000000000e-39", "4787375789000000000e-4", "25645436710e-15", "9123600000e-11", "773000000e-26", "965894753390600000000e-17", "32799510000000000e-39", "1649886500000000e3", "2427870000000e-41", "56338634196645900e-29", "1700000e-37", "24000e-21", "12228171270890000000e-27", "388848687944900000000e-21", "22917500000000000e-37", "38865549969593000000000e-31", "100000000e-26", "85337770327694100000000e2", "4000000000e-30", "59586200e-7", "83600e-23", "778246406900e-12", "8738669740e-30", "3126254308720e-5", "9645836148700300000e2", "783581187505000000000e-27", "300000000e4", "6666212987240000e-15", "531785079061780000e-28", "140000000000e-29", "4327320796000000000000e2", "19206939000e-20", "24596687800e-21", "36309106100000e-40", "970e-45", "1100000000e-3", "3726902671964170000e-35", "100000000e0", "629081346783440000000e-29", "70747022527567700000000000e-40", "9150459685778670000e-18", "60000e-15", "3010000000000e-43", "7855201000000000e-32", "1619915394811500e-14", "5734072444774000e-45", "233920000000e-42", "2754188000e-7", "643300000000000e-12", "4491096716480000000e-18", "482369017000000000000e-35", "748554645544600e-39", "666235013040000e-43", "4764054305615950000000e-26", "5213572440000000e-4", "6712181437628000000000000e4", "90013721002000e-22", "433972062710000000e-3", "796896700000000000e-42", "81211948948000000000e3", "803080607922000000000e-29", "871905539200000e-3", "37354600e3", "462264491350000e-38", "20348100e-23", "89407603000370000000000e-44", "743598767800e-17", "24203710557600000000000e-45", "40000000e-6", "1818200000000e-6", "894661040928600000000e2", "82455257870000000e-31", "404440974000000000000e-19", "373139847030803000e-4", "699519935610000000000e-5", "39439300000000e-26", "4739507300000e-43", "43000e-42", "5278240e1", "89408400000e-43", "619100200000000000e2", "588000000e-5", "962213994020420000000e-15", "462186605469195000000e-6", "258338543010000e-11", "70000000000e-38", "8410e-5", "225821514103570000000000e-12", "574230958239200e-31", "772390000e-2", "4889815609216440000000e-11", "7010e-30", "1000000000000e-33", "63800000000000e3", "507470965700e-29", "160000000e-38", "16329445362057000000e-3", "910944100000000000e-18", "272107100e-33", "16283464366000000e-34", "86400000000e-43", "800000000000e-32", "40000000000e-40", "4630158200e-26", "6276656775863480000e-40", "41610000000000e-2", "69634000000000e-3", "20046298125800000000e-1", "24319120000000000e-27", "66350110000000000e-37", "67826000000e4", "501424900000000e-44", "49513344000000e-39", "800000000e-8", "15362970000000000e4", "110e-5", "465440600650590e-18", "939317393000000e-24", "41931488000000e0", "655493284000000e-15", "6887067200e-10", "222000000000000e-27", "86159600e5", "62700000e-23", "6540000e-14", "39000000000e-27", "50605469000e-34", "6509583460000000000e-32", "24600000000000e-8", "60e-31", "7769606666814000000e-21", "50933189365000000000e-28", "53032898517890000e-20", "2895758150000000000e-38", "200000000000e-39", "38814144127000e-32", "8210000e-36", "74076200000000000e-20", "94478946000e-37", "6996813420000000e-44", "518000e-4", "42355000000e-18", "5900000000e-33", "1200000000000e-38", "36000e-6", "100e-8", "397505100000000e-33", "6005186682810e-24", "3127002797560e-4", "6843700000000e-34"];
+
+lexical_generator!(atof_f64_zeros_lexical, F64_ZEROS_DATA, f64);
+fast_generator!(atof_f64_zeros_fast, F64_ZEROS_DATA);
+parse_generator!(atof_f64_zeros_parse, F64_ZEROS_DATA, f64);
+
+// F64 SHORT
+
+// Randomly generated decimal floats with 1 to 15 significant digits,
+// and small exponents, which are exactly representable on the fast path.
+const F64_SHORT_DATA: [&'static str; 1000] = ["21", "25454037.55794e-5", "64", "0.9748", "16762662531", "0.3", "6687.1367523065", "0.81549", "-662.25495791", "583661.51024", "19766621886010", "-0.920544010807", "-11.5", "9e1", "-7760474015.7697", "4.21", "-9.584e-2", "2806.831", "8553.8", "-0.60103328", "6058175.0604", "0.9", "25.18310", "-52.931353", "-5.81192455", "-9.65669318", "345.672764e-4", "-93124.02456", "8.62797050382", "995.0350574602e2", "-82.532262815", "7.3e-4", "8.7", "-52154.1764420e-1", "-0.17", "-7506987.02", "9630.18560e-2", "-41303.66990", "8052.6517298e0", "329904352451288", "0.91345438022e-1", "-0.12", "53.5588e3", "32043344.202", "74.627455435e0", "7.3", "2556.02213e1", "0.4", "0.79528879791360", "69377832720.2653e-2", "77.50274", "-6849439419303.3", "-65.6", "887959474.66", "22.943714", "1.5750213365e-1", "0.451", "0.7e0", "-2936523600.53410", "0.39918024655", "57995053934.30", "0.158099", "6879e3", "-0.1114", "85673", "10931327806e-4", "0.454", "-0.6", "-0.5109", "37.726648e-3", "8.2", "153.842722e2", "-176.39", "2928", "12906.28468784", "-70626938126816e4", "-713538332.86", "84.0e3", "10351451516.23e4", "0.36", "495.58740795278", "-68793.7598", "98723638.74e-1", "7699487.4628e-2", "-3407920.16507", "0.7", "284466.529e-2", "-188.7", "-0.5776897038874", "-0.286440677212", "-561.01536923", "5162304883816.9", "-7013113e0", "0.340346359", "673433.181807", "89900251.6134295", "4161.2799", "-6.7791292", "9", "0.236e4", "71567356.0635", "8476348948425.0e1", "0.86e-5", "13406.2", "-2.49008138", "5884.452056", "0.9", "94", "31855.7841943839", "-3558194.86916323e0", "0.729", "0.51", "-42.14500", "-25266.94", "-79950.2", "761.60", "266.549789", "-741113999165.98", "0.22166", "704.4889262e5", "3863065575.85", "34772522.2683080", "0.51", "-504.17517e0", "619566439", "36836.9616", "732.6977", "38367270776", "3.4625", "9", "507688.049062074", "53.16e3", "2794.77", "-5794.4150", "1415.929", "-98639.4778572769", "1820.7e-2", "8356768.9615505", "-17.249923338124", "4.0374513530", "-688229.68", "4.10", "-74785.6422", "-499984439.636", "7219.53494727270", "34", "-711271763.9395e2", "-19968234e1", "3.983570272", "-63", "2.13", "-98.15265", "-31.0573843108185", "0.2", "353.981", "-3.201", "1283", "55691852", "-0.59857532591", "0.7", "58", "1126.9", "-338.89e-3", "0.155", "4.76551406258813", "875.42", "26", "157402178.209444", "-88317064.736", "36.79087825", "0.1", "77975.24717", "-0.6", "0.569537320290e4", "45515", "-0.150940954822", "516306", "8.2022483164283", "5.6894583", "4.1606e-4", "84861171719.65", "733.235821", "607159.224285771", "534254023532.4", "-18019242480.1252e-4", "-80512837.66", "-9403202626.9353", "2655.15238348", "8603863.13", "59", "-7.1", "0.768e-2", "452078.1", "9161", "8752", "32851303", "0.64110241", "5.4305e-5", "-31.61121073e0", "-64356860.481", "5.667", "45.6677825568", "5.563059e2", "5515100.95056", "40.7605757838103e4", "6459031.5315e1", "-0.897048", "5722620.4", "2.603707", "7", "305.837030e0", "488955954050.989", "46.0e-4", "-8.3", "9.138462", "9201016631878e4", "378.0", "8.16579821010644", "-56.4338", "1402175.75740873", "-26e2", "171405874050073e0", "6", "-0.178", "2844175608.8522e3", "-80857241.22057e3", "-0.70e-2", "-0.2651901", "619247e-1", "-282642.293", "7.820788", "0.45", "4.7691", "0.7e-5", "62", "-156.431170306e-4", "466592934.7085", "-0.94069241843571e-5", "-3.453887e-3", "-679.6", "338868.662836187", "8", "8.61637100714", "-4097653.7113935e0", "81.9", "7.80", "20533.3066", "88.880", "0.2285", "2.8164e-1", "9364004.13487142e4", "132670.9407996", "-134758.5", "-6.37291490170113", "0.24120951", "786", "13.611534", "-0.23588340", "-0.25999996217", "131.75", "47875.7", "-0.46210582829630", "44471.5698598966", "4.01272354070e-2", "9.4377841093566", "5529.081", "9635.13672566248", "119307.73391560", "6.270", "759.19", "735.6886", "28.5426951268599e-1", "0.92075", "-5829142.60098504", "0.94923", "-31007116.25310", "-0.3813", "5.366", "-27781249.306", "-2.1e-1", "-0.873e5", "-543975722724.2", "-206", "21176565.06918", "0.75", "-0.90446310222660", "-61", "547905531.6", "30676.78692", "32795426721.6644", "-0.4e-4", "-644.0", "68756e-3", "8.74", "599", "-92033437.4", "-0.2", "73892.7410e3", "-10.422e2", "-85494793.19547", "-75120.8e-5", "6783881929.92", "8804.57342651913e5", "9", "3.967742", "347638039e0", "40.475587e-1", "9.4e0", "301471.1", "-8021.1", "9053671915.05312", "53.9761394545e-3", "8939823.83709", "3168559.06e-5", "-24.8799784495848", "0.7961439462", "0.603754", "-798.3578769632", "0.48031806952e-2", "79362849657.25e-4", "51.5", "-862630.26103", "-56638", "-734704.61", "851917760744.08", "-598115.61", "-8.2", "-40472.4", "6245846013", "0.52", "-586254.1", "-82462826", "-0.49", "42234752204.9628", "486979.606562", "697237789.2", "57944.24", "91589.356", "817e3", "0.9814979", "328.416", "0.6362", "0.82658316636", "0.4", "-409256410.61685", "3262.80097288", "399858643.277", "13265637.4629", "1286573.1066856", "-542.31618589379", "47806846688.78", "5", "-71.98684", "53.1", "98133134.8560e-4", "-342.5126422079", "184.32", "9.29378221113503", "-0.56921", "7976.12734333217", "8.79722305862288", "542", "-2.5356202170840", "-35", "822155.860375", "4256412444661.80", "0.8177", "26237442019564.9", "7323932.54", "-396173", "-3274.441493", "539.844875e5", "-0.2", "7.8", "-1104.686452030", "-8118", "87.4849421", "4.0", "6165.16066021", "-7.3", "0.63", "-255.522", "786.869607", "94.0", "9077536.4731", "-0.786459", "-9428981.8503", "0.656786534287", "2557307016.8", "43.336e1", "9446891414202e4", "76832361", "53436.93284852", "81417026962351", "8999112", "0.2247906960e-5", "52844879.27664", "-934455e0", "78565.74558", "8.562e-4", "7698882218666.79", "0.90525756193", "92954.8e-3", "-754105556083", "35265826496.06e-4", "18.522", "-0.6", "-110806323.6017", "820809020.53", "-82.937268", "1.8628e-5", "99.68172506", "172480952", "509787e-2", "54753.1833", "-23759915023164", "-0.7", "836.06", "-0.647", "0.98457", "99.30904874192e-3", "58360770239.7", "-476341515.2", "939972.601e3", "9456593.00e2", "-0.9405164", "31.98069219345", "2.84424132827", "-9411.149", "-62.934", "0.382", "29.5e0", "1", "6.08078", "311452.6551", "-26513895.8380", "898932.882310129", "-265.36", "-210578.329464", "244.73610213e3", "44.812007050", "1926269.180e-4", "-0.3903e-1", "150.04003", "-1.25", "705879907328.772", "-6e3", "-29472.3225149851", "60.9878904", "-97716125", "471.4812498", "0.22", "-690.17388358", "706275654", "-32.85496671e1", "4257487.0908765", "-227413281", "893199669.866882", "-3816716976.0070e5", "44054.694", "817728e-3", "-998869696.12977e0", "87", "7.8e-1", "28.99875", "-0.86489", "88.6", "-18.32", "-66404.682", "-812", "62.093e4", "-6880206.81358", "630.22e1", "-9", "571", "-2808", "28.05", "5592682.46", "901895.5903", "1.55227e-4", "12431806.6862e-2", "6.521760927035e-2", "197.19", "616071", "-7", "38104366009.5", "-65416.016e-2", "90.38966492e-5", "5947062534.031e4", "60.04679362", "-8325.7404546", "17e2", "-3.983979", "99.757687637195", "-676660683.0271", "-3780.40007", "-816.396", "481124.829337e5", "931260717297.52", "41.1", "7816", "-481878140.1e5", "511666.1534", "249217743949.263", "0.1", "75255251330.6916", "0.3826131", "593221852.527577", "86.0731340250e-4", "1.6", "0.196648838", "94047.12", "821.91e-1", "0.7640816", "-4.1e-5", "6.706", "44", "-82.434", "-440", "83917814.4e1", "5427449.05729707e-2", "359", "-50633286", "-454093835e-5", "35.987e2", "-98419832.00", "13869", "-25.469850289", "6.42558134602496e3", "0.55", "434291.8", "14276290134.343", "621324.7", "232527825447.25e-4", "-0.75", "-4", "0.701107229", "0.349", "0.169282", "0.1", "763858.2", "531109.9", "-0.8", "-1307070.96", "5", "879", "138.1", "745771.7", "5543699.253e-4", "213.395e-2", "3.392", "8.36284", "1756554431.899", "7.5828647e5", "3", "-2889076309.160e-4", "857.409", "34245.07", "-5111850996.75", "4795.94", "5", "-37488834762.82e-3", "-295694.86", "89.14", "669.36106559", "3", "0.884", "7675638480325e-2", "-1603.9519e-1", "284.71e4", "-65.13800", "0.3925", "8.81954188670015", "0.9", "7.3", "3107280.5442672", "763.0223258367", "-3123.070", "475", "92471610485.5949", "9.23", "-65.0734016773", "-5.6", "85.9584", "-30218.212032802", "25.8613", "686.28e2", "5619.7", "43524.90194", "0.1", "0.6", "0.59249", "-7", "-896", "41.570", "-20.238845", "8.0", "7540", "-421.3644153", "-45582439997.4", "4260.563", "8.8", "-0.89", "9481937734957.3", "0.11", "7.7479755083159", "862589.682e0", "-95.543227", "883.5165239", "-3205.2", "-32.5918071993277", "0.4e-3", "9119.3500311", "-623165.3169", "970216", "-593493.9717", "737.252", "-568.380", "2127", "666804.106939604", "6.1", "85211.447516287", "145.6e-2", "-684.92109", "7224428", "996.57e5", "93740341e4", "392569007875.6", "-15810.38837e-4", "-5350416537352e-2", "0.6e0", "-1", "85610.8640280826", "901", "7.87905816", "147.962291", "1337.25595e1", "0.108333528777", "8.4e-4", "819339.60", "5357901.9401", "0.259393", "5374.1122513742", "1676483.36216", "9", "0.25450570e5", "-551.148", "7.41e-3", "3.19368569534e1", "4.495e-2", "68741.54", "8.85626260453098", "0.3649315", "3725.6728e1", "-0.751", "-998470050.3", "7475351415.54e-5", "524477940.061697e2", "49.684", "-1609057748.014", "-5.92", "991008793.57488", "0.333", "512319795.73", "0.762", "12593255915282", "0.8061939960e-3", "66.4712170007734", "0.81679", "7.2179", "12936.940918", "-735.0184998", "-7298500.9", "-32.8177", "86229579.5", "56.98", "-19.0201", "-79251661.2", "67.92230", "18667.73524", "82", "15738.2e-3", "1100895.4", "-56116e4", "5.6433276982807", "-59625260443271e-2", "-6.88106", "-0.35", "1", "43058640.4e-3", "-215", "-846.09", "3855526.1", "-568189.542e4", "-0.3889909527317e2", "4706610257.2", "21791116823.85e-2", "7", "8463701.632572", "0.2", "28.039544276", "8312264036026.6", "-7265004.899", "9.1870502136", "91954.5163086", "-84655565e1", "6669127128.65", "732730174746.3", "0.1506e1", "-7.113827851", "-10657746746.631", "6058.55709", "-886e-5", "33.366715814", "2.4699939", "21e3", "0.153025413121000", "7.46157195", "462915", "-32745684.9", "-0.6898854e-1", "58381.89e3", "6e0", "0.49131106977", "8.6807938", "8296156947218.45", "2594.7293553", "1440778.48", "2808154e0", "430.24955251", "60075995257893.3e4", "5659.7408628", "12838102.0017443", "68073924.8054e3", "9995001.756428e-2", "63534566708391.8e-1", "19659314.3854", "-118565617626", "-5.2697732476735", "599.967196", "-5688.663427", "-79273204415218", "2.528948", "552.3768", "0.32508433868874", "4431.3", "-75699", "0.509", "-0.3", "85.56961431078", "3.1", "-1.67e0", "-956181592.854142", "-48393.743869", "8.5", "5.67417956056543", "8e-1", "5.6", "3367.232", "-1205.73", "-991", "-163.75589202633", "570.0", "60388.770404e-1", "889.4410", "401", "-9070.0e0", "-8.344535028", "-211.2164", "1986120.285e0", "215061.1845", "50.359", "-42892.469146", "564426693.4", "-0.771545", "68596", "-21.23747", "9877.2750", "8847.593020e0", "-8.4424", "-5.015219530", "-54.307452", "-3.84895102", "47341.6953920", "14.7662849", "767113812.9", "7.21", "3446.36", "192156442471.71", "8", "803255.4", "8.1", "766.75806", "459.42", "-28975801.9e-2", "1355.82634229", "-278.5661176664", "-60801", "-564", "-456.35686", "353", "52316.0862724202", "6.76", "929451.7459", "74.294", "1.47", "74e-2", "1994.5738493e-1", "-20.4", "8097.3", "-29.3872075476687", "815482918040612", "7588154735.2e1", "2432819.145456", "87.462", "0.96", "-209840328.648", "-792183542761", "9993154.1171880e0", "895963339.8", "5.1674361e-2", "9.50", "565", "-77089351.1560475", "9956725.32237", "643938.10646894", "0.2e4", "-0.75723e3", "553935258552.8", "-288.854909738935", "0.41e-3", "98515e3", "4.9", "7226756.8535", "-47.12792", "-584.129", "14.32", "9.652867333", "-214600", "0.171094", "-5757394483285e-2", "8.71855572414", "7.455", "0.737205", "-7.4929788423807", "-92475666", "6.81899519", "-8", "252", "4823247191959.27", "664231", "546834.5732e5", "6.3", "564168.15", "898908.01702125", "7677170e-4", "-1.1989923", "-51.3048442826", "0.5409", "643.001178e-2", "0.142", "-28923654698.6", "-0.3443", "0.2e2", "654579.2", "2.7", "9484977.031303", "0.68735", "-2.94032308980", "3.76", "688.941", "0.1779", "0.400e-2", "0.9", "43.28586", "-84079044880.42", "0.3778", "516.50", "0.3", "-0.1", "2e3", "12.61", "393964", "-0.7", "-49.64908", "-822.743263751", "0.656", "61427.2919", "-40617223.8", "53", "0.1", "0.625", "6", "70966236.214", "-52.5", "0.77013e5", "938675294.7", "0.505", "4.2121571", "61.120752638", "3", "-15.5", "-516", "-67.1607871620690", "52558.781032444", "9.944341", "-3.213322", "-15.54", "7.825", "0.239455308651409e5", "-73192590.537", "66798.2992016", "53", "-35.7", "587443874.53", "7.32", "-0.90", "60856.550", "-0.3098428580e2", "41567.274", "557131.79", "-43e0", "368169775.27208e4", "4", "0.474", "8.781", "8.050901", "986e3", "651037.2", "393.32e1", "867.2", "22611.31422507", "6.9626", "16662.706", "-635.1135295", "0.896771e-3", "-430258.287636846", "6613.16e5", "-0.4", "560818685239.642", "2", "5348851274.59", "94.12", "-0.77", "516.85", "1.54410", "4604032146.4441", "-0.5", "19803e-5", "0.2925", "32.132076", "48149.883", "-119969.129", "0.407", "1.5e-5", "-0.44974", "-0.94", "93883630.0", "-64.6240", "0.6842", "848804985924.6", "7199.50", "5.1", "-0.7176", "154917.1", "6131.31", "8574590521.39", "726313003", "8", "-4086402.368881", "67546.6", "0.731451481", "9612258.133104", "0.9001", "7398892783.96", "6.5e0", "6.94", "-55016459.4048", "0.1", "-2.931935", "-0.13791319917", "0.55113948", "996535.082206912", "0.20375174e4", "9.8", "-0.117617688e0", "-1.685101277893", "-0.78", "3185442.546", "86.30403171342", "-9.507e3", "4.9042647918026e3", "61.6070907458", "5.921954", "7", "82.9237782", "93123510755865.9e0", "9792288877979", "8704.81783401", "11942497.37135"];
+
+lexical_generator!(atof_f64_short_lexical, F64_SHORT_DATA, f64);
+fast_generator!(atof_f64_short_fast, F64_SHORT_DATA);
+parse_generator!(atof_f64_short_parse, F64_SHORT_DATA, f64);
+
 // MAIN
 
-criterion_group!(f32_benches, atof_f32_lexical, atof_f32_parse);
-criterion_group!(f64_benches, atof_f64_lexical, atof_f64_parse);
-criterion_main!(f32_benches, f64_benches);
+criterion_group!(f32_benches, atof_f32_lexical, atof_f32_parser, atof_f32_parse);
+criterion_group!(f64_benches, atof_f64_lexical, atof_f64_parser, atof_f64_fast, atof_f64_parse);
+criterion_group!(f64_long_benches, atof_f64_long_lexical, atof_f64_long_parse);
+criterion_group!(f64_zeros_benches, atof_f64_zeros_lexical, atof_f64_zeros_fast, atof_f64_zeros_parse);
+criterion_group!(f64_short_benches, atof_f64_short_lexical, atof_f64_short_fast, atof_f64_short_parse);
+criterion_main!(f32_benches, f64_benches, f64_long_benches, f64_zeros_benches, f64_short_benches);
@@ -32,6 +32,35 @@ macro_rules! parse_generator {
     );
 }
 
+// Lexical list atoi generator.
+macro_rules! lexical_list_generator {
+    ($name:ident, $data:ident) => (
+        fn $name(criterion: &mut Criterion) {
+            let list = $data.join(",");
+            let mut values = vec![0u64; $data.len()];
+            criterion.bench_function(stringify!($name), |b| b.iter(|| {
+                black_box(lexical_core::parse_u64_list(list.as_bytes(), b',', &mut values).unwrap());
+            }));
+        }
+    );
+}
+
+// Parse list atoi generator.
+macro_rules! parse_list_generator {
+    ($name:ident, $data:ident) => (
+        fn $name(criterion: &mut Criterion) {
+            let list = $data.join(",");
+            let mut values = vec![0u64; $data.len()];
+            criterion.bench_function(stringify!($name), |b| b.iter(|| {
+                list.split(',').zip(values.iter_mut()).for_each(|(x, v)| {
+                    *v = x.parse::<u64>().unwrap();
+                });
+                black_box(&values);
+            }));
+        }
+    );
+}
+
 // U8
 
 // Converted from randomly generated data in itoa.
@@ -112,6 +141,11 @@ const U64_SIMPLE_DATA: [&'static str; 10000] = ["114", "242", "443", "305", "11"
 lexical_generator!(atoi_u64_simple_lexical, U64_SIMPLE_DATA, u64);
 parse_generator!(atoi_u64_simple_parse, U64_SIMPLE_DATA, u64);
 
+lexical_list_generator!(atoi_u64_list_lexical, U64_DATA);
+parse_list_generator!(atoi_u64_list_parse, U64_DATA);
+lexical_list_generator!(atoi_u64_simple_list_lexical, U64_SIMPLE_DATA);
+parse_list_generator!(atoi_u64_simple_list_parse, U64_SIMPLE_DATA);
+
 // I64
 
 // Converted from randomly generated data in itoa.
@@ -163,9 +197,14 @@ criterion_group!(u32_simple_benches, atoi_u32_simple_lexical, atoi_u32_simple_pa
 criterion_group!(u64_simple_benches, atoi_u64_simple_lexical, atoi_u64_simple_parse);
 criterion_group!(u128_simple_benches, atoi_u128_simple_lexical, atoi_u128_simple_parse);
 
+// Lists
+criterion_group!(u64_list_benches, atoi_u64_list_lexical, atoi_u64_list_parse, atoi_u64_simple_list_lexical, atoi_u64_simple_list_parse);
+
 criterion_main!(
     // Random data
     u8_benches, u16_benches, u32_benches, u64_benches, u128_benches, i8_benches, i16_benches, i32_benches, i64_benches, i128_benches,
     // Simple data
-    u8_simple_benches, u16_simple_benches, u32_simple_benches, u64_simple_benches, u128_simple_benches
+    u8_simple_benches, u16_simple_benches, u32_simple_benches, u64_simple_benches, u128_simple_benches,
+    // Lists
+    u64_list_benches
 );
@@ -1,8 +1,11 @@
 extern crate criterion;
+extern crate itertools;
 extern crate itoa;
+extern crate lexical;
 extern crate lexical_core;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use itertools::Itertools;
 use itoa::write as itoa_write;
 use lexical_core::write as lexical_write;
 
@@ -289,6 +292,22 @@ lexical_generator!(itoa_i128_lexical, I128_DATA.iter());
 itoa_generator!(itoa_i128_itoa, I128_DATA.iter());
 fmt_generator!(itoa_i128_std, I128_DATA.iter());
 
+// I64 LIST
+
+fn itoa_i64_list_lexical(criterion: &mut Criterion) {
+    criterion.bench_function("itoa_i64_list_lexical", |b| b.iter(|| {
+        let mut buffer = Vec::new();
+        lexical::write_i64_list(&mut buffer, &I64_DATA, b',');
+        black_box(buffer)
+    }));
+}
+
+fn itoa_i64_list_join(criterion: &mut Criterion) {
+    criterion.bench_function("itoa_i64_list_join", |b| b.iter(|| {
+        black_box(I64_DATA.iter().join(","))
+    }));
+}
+
 // MAIN
 
 // Random data
@@ -302,6 +321,7 @@ criterion_group!(i16_benches, itoa_i16_lexical, itoa_i16_itoa, itoa_i16_std);
 criterion_group!(i32_benches, itoa_i32_lexical, itoa_i32_itoa, itoa_i32_std);
 criterion_group!(i64_benches, itoa_i64_lexical, itoa_i64_itoa, itoa_i64_std);
 criterion_group!(i128_benches, itoa_i128_lexical, itoa_i128_itoa, itoa_i128_std);
+criterion_group!(i64_list_benches, itoa_i64_list_lexical, itoa_i64_list_join);
 
 // Simple data
 criterion_group!(u8_simple_benches, itoa_u8_simple_lexical, itoa_u8_simple_itoa, itoa_u8_simple_std);
@@ -320,6 +340,7 @@ criterion_group!(u128_heterogeneous_benches, itoa_u128_heterogeneous_lexical, it
 criterion_main!(
     // Random data
     u8_benches, u16_benches, u32_benches, u64_benches, u128_benches, i8_benches, i16_benches, i32_benches, i64_benches, i128_benches,
+    i64_list_benches,
     // Simple data
     u8_simple_benches, u16_simple_benches, u32_simple_benches, u64_simple_benches, u128_simple_benches,
     // Heterogeneous data.
@@ -56,6 +56,8 @@ else
         "table"
         "table,radix"
         "table,unchecked_index"
+        "correct,table"
+        "correct,table,radix"
     )
 fi
 
@@ -183,6 +183,32 @@ enum lexical_error_code {
     lexical_exponent_without_fraction = -14,
     // Integer had invalid leading zeros.
     lexical_invalid_leading_zeros = -15,
+    // Integer had digit separators between incorrectly-sized groups.
+    lexical_invalid_digit_grouping = -16,
+    // Parsed value was outside the valid range.
+    lexical_out_of_range = -17,
+    // List had more elements than the output buffer could hold.
+    lexical_too_many_elements = -18,
+    // Parsed value was zero, but a non-zero value was required.
+    lexical_zero = -19,
+    // Parsed value was infinite or NaN, but a finite value was required.
+    lexical_non_finite = -20,
+    // Decimal point was found without any mantissa digits.
+    lexical_lone_dot = -21,
+    // Denominator of a ratio was zero.
+    lexical_division_by_zero = -22,
+    // Significant digits did not fit in the output buffer.
+    lexical_too_many_digits = -23,
+    // Negative sign was found, but an unsigned value was required.
+    lexical_negative_not_allowed = -24,
+    // Scratch storage was too small, the index is the required number of limbs.
+    lexical_scratch_too_small = -25,
+    // Exponent notation is required, but was not found.
+    lexical_missing_exponent = -26,
+    // A sign was followed by another sign.
+    lexical_invalid_sign = -27,
+    // The radix was not in the range [2, 36].
+    lexical_invalid_radix = -28,
 };
 
 // Declare extern to lexical function definitions.
@@ -271,6 +297,11 @@ enum lexical_error_code {
     #define lexical_case_sensitive_special                  0x400ull
     #define lexical_no_integer_leading_zeros                0x800ull
     #define lexical_no_float_leading_zeros                  0x1000ull
+    #define lexical_d_exponent_notation                     0x2000ull
+    #define lexical_exponent_without_notation               0x4000ull
+    #define lexical_exponent_base_10                        0x10000ull
+    #define lexical_required_exponent_notation              0x20000ull
+    #define lexical_normalized_mantissa                     0x40000ull
 
     // DIGIT SEPARATOR FLAGS
     #define lexical_integer_internal_digit_separator        0x100000000ull
@@ -352,6 +383,10 @@ enum lexical_error_code {
         | lexical_no_positive_exponent_sign                             \
         | lexical_required_exponent_sign                                \
         | lexical_no_exponent_without_fraction                          \
+        | lexical_d_exponent_notation                                   \
+        | lexical_exponent_without_notation                             \
+        | lexical_exponent_base_10                                      \
+        | lexical_required_exponent_notation                            \
         | lexical_exponent_internal_digit_separator                     \
         | lexical_exponent_leading_digit_separator                      \
         | lexical_exponent_trailing_digit_separator                     \
@@ -370,6 +405,11 @@ enum lexical_error_code {
         | lexical_case_sensitive_special                                \
         | lexical_no_integer_leading_zeros                              \
         | lexical_no_float_leading_zeros                                \
+        | lexical_d_exponent_notation                                   \
+        | lexical_exponent_without_notation                             \
+        | lexical_exponent_base_10                                      \
+        | lexical_required_exponent_notation                            \
+        | lexical_normalized_mantissa                                   \
         | lexical_internal_digit_separator                              \
         | lexical_leading_digit_separator                               \
         | lexical_trailing_digit_separator                              \
@@ -841,10 +881,21 @@ enum lexical_error_code {
     #define lexical_fortran_literal (                                   \
         lexical_required_exponent_digits                                \
         | lexical_no_special                                            \
+        | lexical_d_exponent_notation                                   \
     )
 
     // Float format to parse a FORTRAN float from string.
-    #define lexical_fortran_string lexical_required_exponent_digits
+    #define lexical_fortran_string (                                    \
+        lexical_required_exponent_digits                                \
+        | lexical_d_exponent_notation                                   \
+    )
+
+    // Float format to parse a FORTRAN float from string, allowing exponents without notation.
+    #define lexical_fortran_lenient_string (                            \
+        lexical_required_exponent_digits                                \
+        | lexical_d_exponent_notation                                   \
+        | lexical_exponent_without_notation                             \
+    )
 
     // Float format for a D literal floating-point number.
     #define lexical_d_literal (                                         \
@@ -1461,6 +1512,36 @@ enum lexical_error_code {
         return lexical_number_format_intersects(format, lexical_no_float_leading_zeros);
     }
 
+    // Get if `d` and `D` are also allowed as exponent notation.
+    inline bool lexical_number_format_d_exponent_notation(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_d_exponent_notation);
+    }
+
+    // Get if an exponent sign without exponent notation starts the exponent.
+    inline bool lexical_number_format_exponent_without_notation(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_exponent_without_notation);
+    }
+
+    // Get if exponent digits are always decimal, regardless of the radix.
+    inline bool lexical_number_format_exponent_base_10(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_exponent_base_10);
+    }
+
+    // Get if exponent notation is required.
+    inline bool lexical_number_format_required_exponent_notation(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_required_exponent_notation);
+    }
+
+    // Get if exactly one digit is required before the decimal point.
+    inline bool lexical_number_format_normalized_mantissa(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_normalized_mantissa);
+    }
+
     // Get if digit separators are allowed between integer digits.
     inline bool lexical_number_format_integer_internal_digit_separator(uint64_t format)
     {
@@ -1592,6 +1673,19 @@ lexical_is_error(invalid_positive_exponent_sign);
 lexical_is_error(missing_exponent_sign);
 lexical_is_error(exponent_without_fraction);
 lexical_is_error(invalid_leading_zeros);
+lexical_is_error(invalid_digit_grouping);
+lexical_is_error(out_of_range);
+lexical_is_error(too_many_elements);
+lexical_is_error(zero);
+lexical_is_error(non_finite);
+lexical_is_error(lone_dot);
+lexical_is_error(division_by_zero);
+lexical_is_error(too_many_digits);
+lexical_is_error(negative_not_allowed);
+lexical_is_error(scratch_too_small);
+lexical_is_error(missing_exponent);
+lexical_is_error(invalid_sign);
+lexical_is_error(invalid_radix);
 
 // RESULT TAG
 
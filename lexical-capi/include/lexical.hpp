@@ -121,6 +121,11 @@ inline void set_exponent_default_char(uint8_t ch)
         case_sensitive_special = lexical_case_sensitive_special,
         no_integer_leading_zeros = lexical_no_integer_leading_zeros,
         no_float_leading_zeros = lexical_no_float_leading_zeros,
+        d_exponent_notation = lexical_d_exponent_notation,
+        exponent_without_notation = lexical_exponent_without_notation,
+        exponent_base_10 = lexical_exponent_base_10,
+        required_exponent_notation = lexical_required_exponent_notation,
+        normalized_mantissa = lexical_normalized_mantissa,
         integer_internal_digit_separator = lexical_integer_internal_digit_separator,
         fraction_internal_digit_separator = lexical_fraction_internal_digit_separator,
         exponent_internal_digit_separator = lexical_exponent_internal_digit_separator,
@@ -231,6 +236,7 @@ inline void set_exponent_default_char(uint8_t ch)
         elixir_string = lexical_elixir_string,
         fortran_literal = lexical_fortran_literal,
         fortran_string = lexical_fortran_string,
+        fortran_lenient_string = lexical_fortran_lenient_string,
         d_literal = lexical_d_literal,
         d_string = lexical_d_string,
         coffeescript_literal = lexical_coffeescript_literal,
@@ -576,6 +582,41 @@ inline void set_exponent_default_char(uint8_t ch)
         return ::lexical_number_format_no_float_leading_zeros(f);
     }
 
+    // Get if `d` and `D` are also allowed as exponent notation.
+    inline bool number_format_d_exponent_notation(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_d_exponent_notation(f);
+    }
+
+    // Get if an exponent sign without exponent notation starts the exponent.
+    inline bool number_format_exponent_without_notation(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_exponent_without_notation(f);
+    }
+
+    // Get if exponent digits are always decimal, regardless of the radix.
+    inline bool number_format_exponent_base_10(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_exponent_base_10(f);
+    }
+
+    // Get if exponent notation is required.
+    inline bool number_format_required_exponent_notation(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_required_exponent_notation(f);
+    }
+
+    // Get if exactly one digit is required before the decimal point.
+    inline bool number_format_normalized_mantissa(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_normalized_mantissa(f);
+    }
+
     // Get if digit separators are allowed between integer digits.
     inline bool number_format_integer_internal_digit_separator(number_format format)
     {
@@ -825,6 +866,19 @@ enum class error_code: int32_t {
     missing_exponent_sign = ::lexical_missing_exponent_sign,
     exponent_without_fraction = ::lexical_exponent_without_fraction,
     invalid_leading_zeros = ::lexical_invalid_leading_zeros,
+    invalid_digit_grouping = ::lexical_invalid_digit_grouping,
+    out_of_range = ::lexical_out_of_range,
+    too_many_elements = ::lexical_too_many_elements,
+    zero = ::lexical_zero,
+    non_finite = ::lexical_non_finite,
+    lone_dot = ::lexical_lone_dot,
+    division_by_zero = ::lexical_division_by_zero,
+    too_many_digits = ::lexical_too_many_digits,
+    negative_not_allowed = ::lexical_negative_not_allowed,
+    scratch_too_small = ::lexical_scratch_too_small,
+    missing_exponent = ::lexical_missing_exponent,
+    invalid_sign = ::lexical_invalid_sign,
+    invalid_radix = ::lexical_invalid_radix,
 };
 
 // Determine if an error code matches the desired code.
@@ -855,6 +909,19 @@ struct error
     lexical_is_error(missing_exponent_sign);
     lexical_is_error(exponent_without_fraction);
     lexical_is_error(invalid_leading_zeros);
+    lexical_is_error(invalid_digit_grouping);
+    lexical_is_error(out_of_range);
+    lexical_is_error(too_many_elements);
+    lexical_is_error(zero);
+    lexical_is_error(non_finite);
+    lexical_is_error(lone_dot);
+    lexical_is_error(division_by_zero);
+    lexical_is_error(too_many_digits);
+    lexical_is_error(negative_not_allowed);
+    lexical_is_error(scratch_too_small);
+    lexical_is_error(missing_exponent);
+    lexical_is_error(invalid_sign);
+    lexical_is_error(invalid_radix);
 
     inline friend bool operator==(const error& lhs, const error& rhs)
     {
@@ -233,6 +233,11 @@ if HAVE_FORMAT:
         CaseSensitiveSpecial                = 0b0000000000000000000000000000000000000000000000000000010000000000
         NoIntegerLeadingZeros               = 0b0000000000000000000000000000000000000000000000000000100000000000
         NoFloatLeadingZeros                 = 0b0000000000000000000000000000000000000000000000000001000000000000
+        DExponentNotation                   = 0b0000000000000000000000000000000000000000000000000010000000000000
+        ExponentWithoutNotation             = 0b0000000000000000000000000000000000000000000000000100000000000000
+        ExponentBase10                      = 0b0000000000000000000000000000000000000000000000010000000000000000
+        RequiredExponentNotation            = 0b0000000000000000000000000000000000000000000000100000000000000000
+        NormalizedMantissa                  = 0b0000000000000000000000000000000000000000000001000000000000000000
 
         # DIGIT SEPARATOR FLAGS
         IntegerInternalDigitSeparator       = 0b0000000000000000000000000000000100000000000000000000000000000000
@@ -315,6 +320,10 @@ if HAVE_FORMAT:
             | NoPositiveExponentSign
             | RequiredExponentSign
             | NoExponentWithoutFraction
+            | DExponentNotation
+            | ExponentWithoutNotation
+            | ExponentBase10
+            | RequiredExponentNotation
             | ExponentInternalDigitSeparator
             | ExponentLeadingDigitSeparator
             | ExponentTrailingDigitSeparator
@@ -333,6 +342,11 @@ if HAVE_FORMAT:
             | CaseSensitiveSpecial
             | NoIntegerLeadingZeros
             | NoFloatLeadingZeros
+            | DExponentNotation
+            | ExponentWithoutNotation
+            | ExponentBase10
+            | RequiredExponentNotation
+            | NormalizedMantissa
             | InternalDigitSeparator
             | LeadingDigitSeparator
             | TrailingDigitSeparator
@@ -626,6 +640,31 @@ if HAVE_FORMAT:
             '''Get if leading zeros before a float are not allowed.'''
             return self.intersects(NumberFormatFlags.NoFloatLeadingZeros)
 
+        @property
+        def d_exponent_notation(self):
+            '''Get if `d` and `D` are also allowed as exponent notation.'''
+            return self.intersects(NumberFormatFlags.DExponentNotation)
+
+        @property
+        def exponent_without_notation(self):
+            '''Get if an exponent sign without exponent notation starts the exponent.'''
+            return self.intersects(NumberFormatFlags.ExponentWithoutNotation)
+
+        @property
+        def exponent_base_10(self):
+            '''Get if exponent digits are always decimal, regardless of the radix.'''
+            return self.intersects(NumberFormatFlags.ExponentBase10)
+
+        @property
+        def required_exponent_notation(self):
+            '''Get if exponent notation is required.'''
+            return self.intersects(NumberFormatFlags.RequiredExponentNotation)
+
+        @property
+        def normalized_mantissa(self):
+            '''Get if exactly one digit is required before the decimal point.'''
+            return self.intersects(NumberFormatFlags.NormalizedMantissa)
+
         @property
         def integer_internal_digit_separator(self):
             '''Get if digit separators are allowed between integer digits.'''
@@ -1205,11 +1244,20 @@ if HAVE_FORMAT:
     NumberFormat.FortranLiteral = NumberFormat(
         NumberFormatFlags.RequiredExponentDigits.value
         | NumberFormatFlags.NoSpecial.value
+        | NumberFormatFlags.DExponentNotation.value
     )
 
     # Float format to parse a FORTRAN float from string.
     NumberFormat.FortranString = NumberFormat(
         NumberFormatFlags.RequiredExponentDigits.value
+        | NumberFormatFlags.DExponentNotation.value
+    )
+
+    # Float format to parse a FORTRAN float from string, allowing exponents without notation.
+    NumberFormat.FortranLenientString = NumberFormat(
+        NumberFormatFlags.RequiredExponentDigits.value
+        | NumberFormatFlags.DExponentNotation.value
+        | NumberFormatFlags.ExponentWithoutNotation.value
     )
 
     # Float format for a D literal floating-point number.
@@ -1523,6 +1571,19 @@ class ErrorCode(enum.Enum):
     MissingExponentSign = -13
     ExponentWithoutFraction = -14
     InvalidLeadingZeros = -15
+    InvalidDigitGrouping = -16
+    OutOfRange = -17
+    TooManyElements = -18
+    Zero = -19
+    NonFinite = -20
+    LoneDot = -21
+    DivisionByZero = -22
+    TooManyDigits = -23
+    NegativeNotAllowed = -24
+    ScratchTooSmall = -25
+    MissingExponent = -26
+    InvalidSign = -27
+    InvalidRadix = -28
 
 class Error(Structure):
     '''C-compatible error for FFI.'''
@@ -1587,6 +1648,45 @@ class Error(Structure):
     def is_invalid_leading_zeros(self):
         return self.code == ErrorCode.InvalidLeadingZeros
 
+    def is_invalid_digit_grouping(self):
+        return self.code == ErrorCode.InvalidDigitGrouping
+
+    def is_out_of_range(self):
+        return self.code == ErrorCode.OutOfRange
+
+    def is_too_many_elements(self):
+        return self.code == ErrorCode.TooManyElements
+
+    def is_zero(self):
+        return self.code == ErrorCode.Zero
+
+    def is_non_finite(self):
+        return self.code == ErrorCode.NonFinite
+
+    def is_lone_dot(self):
+        return self.code == ErrorCode.LoneDot
+
+    def is_division_by_zero(self):
+        return self.code == ErrorCode.DivisionByZero
+
+    def is_too_many_digits(self):
+        return self.code == ErrorCode.TooManyDigits
+
+    def is_negative_not_allowed(self):
+        return self.code == ErrorCode.NegativeNotAllowed
+
+    def is_scratch_too_small(self):
+        return self.code == ErrorCode.ScratchTooSmall
+
+    def is_missing_exponent(self):
+        return self.code == ErrorCode.MissingExponent
+
+    def is_invalid_sign(self):
+        return self.code == ErrorCode.InvalidSign
+
+    def is_invalid_radix(self):
+        return self.code == ErrorCode.InvalidRadix
+
 class LexicalError(Exception):
     '''Python-native exception raised during errors in lexical parsing.'''
 
@@ -108,6 +108,19 @@ lexical_result_error(invalid_positive_exponent_sign);
 lexical_result_error(missing_exponent_sign);
 lexical_result_error(exponent_without_fraction);
 lexical_result_error(invalid_leading_zeros);
+lexical_result_error(invalid_digit_grouping);
+lexical_result_error(out_of_range);
+lexical_result_error(too_many_elements);
+lexical_result_error(zero);
+lexical_result_error(non_finite);
+lexical_result_error(lone_dot);
+lexical_result_error(division_by_zero);
+lexical_result_error(too_many_digits);
+lexical_result_error(negative_not_allowed);
+lexical_result_error(scratch_too_small);
+lexical_result_error(missing_exponent);
+lexical_result_error(invalid_sign);
+lexical_result_error(invalid_radix);
 
 inline lexical_i8_partial_result partial_result_ok(lexical_i8 value, size_t index)
 {
@@ -168,6 +181,19 @@ lexical_partial_result_error(invalid_positive_exponent_sign);
 lexical_partial_result_error(missing_exponent_sign);
 lexical_partial_result_error(exponent_without_fraction);
 lexical_partial_result_error(invalid_leading_zeros);
+lexical_partial_result_error(invalid_digit_grouping);
+lexical_partial_result_error(out_of_range);
+lexical_partial_result_error(too_many_elements);
+lexical_partial_result_error(zero);
+lexical_partial_result_error(non_finite);
+lexical_partial_result_error(lone_dot);
+lexical_partial_result_error(division_by_zero);
+lexical_partial_result_error(too_many_digits);
+lexical_partial_result_error(negative_not_allowed);
+lexical_partial_result_error(scratch_too_small);
+lexical_partial_result_error(missing_exponent);
+lexical_partial_result_error(invalid_sign);
+lexical_partial_result_error(invalid_radix);
 
 #define lexical_is_error(type)                                                  \
     inline bool is_##type(lexical_error error)                                  \
@@ -190,6 +216,19 @@ lexical_is_error(invalid_positive_exponent_sign);
 lexical_is_error(missing_exponent_sign);
 lexical_is_error(exponent_without_fraction);
 lexical_is_error(invalid_leading_zeros);
+lexical_is_error(invalid_digit_grouping);
+lexical_is_error(out_of_range);
+lexical_is_error(too_many_elements);
+lexical_is_error(zero);
+lexical_is_error(non_finite);
+lexical_is_error(lone_dot);
+lexical_is_error(division_by_zero);
+lexical_is_error(too_many_digits);
+lexical_is_error(negative_not_allowed);
+lexical_is_error(scratch_too_small);
+lexical_is_error(missing_exponent);
+lexical_is_error(invalid_sign);
+lexical_is_error(invalid_radix);
 
 // CONFIG TESTS
 // ------------
@@ -422,6 +461,110 @@ TEST(test_is_invalid_leading_zeros, error_tests)
     EXPECT_TRUE(lexical_error_is_invalid_leading_zeros(&invalid_leading_zeros));
 }
 
+TEST(test_is_invalid_digit_grouping, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error invalid_digit_grouping = { lexical_invalid_digit_grouping, 0 };
+    EXPECT_FALSE(lexical_error_is_invalid_digit_grouping(&overflow));
+    EXPECT_TRUE(lexical_error_is_invalid_digit_grouping(&invalid_digit_grouping));
+}
+
+TEST(test_is_out_of_range, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error out_of_range = { lexical_out_of_range, 0 };
+    EXPECT_FALSE(lexical_error_is_out_of_range(&overflow));
+    EXPECT_TRUE(lexical_error_is_out_of_range(&out_of_range));
+}
+
+TEST(test_is_too_many_elements, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error too_many_elements = { lexical_too_many_elements, 0 };
+    EXPECT_FALSE(lexical_error_is_too_many_elements(&overflow));
+    EXPECT_TRUE(lexical_error_is_too_many_elements(&too_many_elements));
+}
+
+TEST(test_is_zero, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error zero = { lexical_zero, 0 };
+    EXPECT_FALSE(lexical_error_is_zero(&overflow));
+    EXPECT_TRUE(lexical_error_is_zero(&zero));
+}
+
+TEST(test_is_non_finite, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error non_finite = { lexical_non_finite, 0 };
+    EXPECT_FALSE(lexical_error_is_non_finite(&overflow));
+    EXPECT_TRUE(lexical_error_is_non_finite(&non_finite));
+}
+
+TEST(test_is_lone_dot, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error lone_dot = { lexical_lone_dot, 0 };
+    EXPECT_FALSE(lexical_error_is_lone_dot(&overflow));
+    EXPECT_TRUE(lexical_error_is_lone_dot(&lone_dot));
+}
+
+TEST(test_is_division_by_zero, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error division_by_zero = { lexical_division_by_zero, 0 };
+    EXPECT_FALSE(lexical_error_is_division_by_zero(&overflow));
+    EXPECT_TRUE(lexical_error_is_division_by_zero(&division_by_zero));
+}
+
+TEST(test_is_too_many_digits, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error too_many_digits = { lexical_too_many_digits, 0 };
+    EXPECT_FALSE(lexical_error_is_too_many_digits(&overflow));
+    EXPECT_TRUE(lexical_error_is_too_many_digits(&too_many_digits));
+}
+
+TEST(test_is_negative_not_allowed, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error negative_not_allowed = { lexical_negative_not_allowed, 0 };
+    EXPECT_FALSE(lexical_error_is_negative_not_allowed(&overflow));
+    EXPECT_TRUE(lexical_error_is_negative_not_allowed(&negative_not_allowed));
+}
+
+TEST(test_is_scratch_too_small, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error scratch_too_small = { lexical_scratch_too_small, 0 };
+    EXPECT_FALSE(lexical_error_is_scratch_too_small(&overflow));
+    EXPECT_TRUE(lexical_error_is_scratch_too_small(&scratch_too_small));
+}
+
+TEST(test_is_missing_exponent, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error missing_exponent = { lexical_missing_exponent, 0 };
+    EXPECT_FALSE(lexical_error_is_missing_exponent(&overflow));
+    EXPECT_TRUE(lexical_error_is_missing_exponent(&missing_exponent));
+}
+
+TEST(test_is_invalid_sign, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error invalid_sign = { lexical_invalid_sign, 0 };
+    EXPECT_FALSE(lexical_error_is_invalid_sign(&overflow));
+    EXPECT_TRUE(lexical_error_is_invalid_sign(&invalid_sign));
+}
+
+TEST(test_is_invalid_radix, error_tests)
+{
+    lexical_error overflow = { lexical_overflow, 0 };
+    lexical_error invalid_radix = { lexical_invalid_radix, 0 };
+    EXPECT_FALSE(lexical_error_is_invalid_radix(&overflow));
+    EXPECT_TRUE(lexical_error_is_invalid_radix(&invalid_radix));
+}
+
 // RESULT TESTS
 
 TEST(result, result_tests)
@@ -95,6 +95,19 @@ lexical_result_error(invalid_positive_exponent_sign);
 lexical_result_error(missing_exponent_sign);
 lexical_result_error(exponent_without_fraction);
 lexical_result_error(invalid_leading_zeros);
+lexical_result_error(invalid_digit_grouping);
+lexical_result_error(out_of_range);
+lexical_result_error(too_many_elements);
+lexical_result_error(zero);
+lexical_result_error(non_finite);
+lexical_result_error(lone_dot);
+lexical_result_error(division_by_zero);
+lexical_result_error(too_many_digits);
+lexical_result_error(negative_not_allowed);
+lexical_result_error(scratch_too_small);
+lexical_result_error(missing_exponent);
+lexical_result_error(invalid_sign);
+lexical_result_error(invalid_radix);
 
 template <typename T>
 inline partial_result<T> partial_result_ok(T value, size_t index)
@@ -158,6 +171,19 @@ lexical_partial_result_error(invalid_positive_exponent_sign);
 lexical_partial_result_error(missing_exponent_sign);
 lexical_partial_result_error(exponent_without_fraction);
 lexical_partial_result_error(invalid_leading_zeros);
+lexical_partial_result_error(invalid_digit_grouping);
+lexical_partial_result_error(out_of_range);
+lexical_partial_result_error(too_many_elements);
+lexical_partial_result_error(zero);
+lexical_partial_result_error(non_finite);
+lexical_partial_result_error(lone_dot);
+lexical_partial_result_error(division_by_zero);
+lexical_partial_result_error(too_many_digits);
+lexical_partial_result_error(negative_not_allowed);
+lexical_partial_result_error(scratch_too_small);
+lexical_partial_result_error(missing_exponent);
+lexical_partial_result_error(invalid_sign);
+lexical_partial_result_error(invalid_radix);
 
 // CONFIG TESTS
 // ------------
@@ -466,6 +492,110 @@ TEST(test_is_invalid_leading_zeros, error_tests)
     EXPECT_TRUE(invalid_leading_zeros.is_invalid_leading_zeros());
 }
 
+TEST(test_is_invalid_digit_grouping, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error invalid_digit_grouping = { error_code::invalid_digit_grouping, 0 };
+    EXPECT_FALSE(overflow.is_invalid_digit_grouping());
+    EXPECT_TRUE(invalid_digit_grouping.is_invalid_digit_grouping());
+}
+
+TEST(test_is_out_of_range, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error out_of_range = { error_code::out_of_range, 0 };
+    EXPECT_FALSE(overflow.is_out_of_range());
+    EXPECT_TRUE(out_of_range.is_out_of_range());
+}
+
+TEST(test_is_too_many_elements, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error too_many_elements = { error_code::too_many_elements, 0 };
+    EXPECT_FALSE(overflow.is_too_many_elements());
+    EXPECT_TRUE(too_many_elements.is_too_many_elements());
+}
+
+TEST(test_is_zero, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error zero = { error_code::zero, 0 };
+    EXPECT_FALSE(overflow.is_zero());
+    EXPECT_TRUE(zero.is_zero());
+}
+
+TEST(test_is_non_finite, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error non_finite = { error_code::non_finite, 0 };
+    EXPECT_FALSE(overflow.is_non_finite());
+    EXPECT_TRUE(non_finite.is_non_finite());
+}
+
+TEST(test_is_lone_dot, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error lone_dot = { error_code::lone_dot, 0 };
+    EXPECT_FALSE(overflow.is_lone_dot());
+    EXPECT_TRUE(lone_dot.is_lone_dot());
+}
+
+TEST(test_is_division_by_zero, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error division_by_zero = { error_code::division_by_zero, 0 };
+    EXPECT_FALSE(overflow.is_division_by_zero());
+    EXPECT_TRUE(division_by_zero.is_division_by_zero());
+}
+
+TEST(test_is_too_many_digits, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error too_many_digits = { error_code::too_many_digits, 0 };
+    EXPECT_FALSE(overflow.is_too_many_digits());
+    EXPECT_TRUE(too_many_digits.is_too_many_digits());
+}
+
+TEST(test_is_negative_not_allowed, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error negative_not_allowed = { error_code::negative_not_allowed, 0 };
+    EXPECT_FALSE(overflow.is_negative_not_allowed());
+    EXPECT_TRUE(negative_not_allowed.is_negative_not_allowed());
+}
+
+TEST(test_is_scratch_too_small, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error scratch_too_small = { error_code::scratch_too_small, 0 };
+    EXPECT_FALSE(overflow.is_scratch_too_small());
+    EXPECT_TRUE(scratch_too_small.is_scratch_too_small());
+}
+
+TEST(test_is_missing_exponent, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error missing_exponent = { error_code::missing_exponent, 0 };
+    EXPECT_FALSE(overflow.is_missing_exponent());
+    EXPECT_TRUE(missing_exponent.is_missing_exponent());
+}
+
+TEST(test_is_invalid_sign, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error invalid_sign = { error_code::invalid_sign, 0 };
+    EXPECT_FALSE(overflow.is_invalid_sign());
+    EXPECT_TRUE(invalid_sign.is_invalid_sign());
+}
+
+TEST(test_is_invalid_radix, error_tests)
+{
+    error overflow = { error_code::overflow, 0 };
+    error invalid_radix = { error_code::invalid_radix, 0 };
+    EXPECT_FALSE(overflow.is_invalid_radix());
+    EXPECT_TRUE(invalid_radix.is_invalid_radix());
+}
+
 // RESULT TESTS
 
 TEST(result, result_tests)
@@ -123,6 +123,11 @@ class ConfigTests(unittest.TestCase):
             self.assertTrue(format.required_digits)
             self.assertTrue(format.no_special)
 
+            format = lexical.NumberFormat.FortranLenientString
+            self.assertTrue(format.d_exponent_notation)
+            self.assertTrue(format.exponent_without_notation)
+            self.assertFalse(lexical.NumberFormat.FortranString.exponent_without_notation)
+
 
 class GlobalTests(unittest.TestCase):
     '''Test the global config variables.'''
@@ -179,6 +184,19 @@ class ErrorTests(unittest.TestCase):
         self.missing_exponent_sign = lexical.Error(lexical.ErrorCode.MissingExponentSign.value, 0)
         self.exponent_without_fraction = lexical.Error(lexical.ErrorCode.ExponentWithoutFraction.value, 0)
         self.invalid_leading_zeros = lexical.Error(lexical.ErrorCode.InvalidLeadingZeros.value, 0)
+        self.invalid_digit_grouping = lexical.Error(lexical.ErrorCode.InvalidDigitGrouping.value, 0)
+        self.out_of_range = lexical.Error(lexical.ErrorCode.OutOfRange.value, 0)
+        self.too_many_elements = lexical.Error(lexical.ErrorCode.TooManyElements.value, 0)
+        self.zero = lexical.Error(lexical.ErrorCode.Zero.value, 0)
+        self.non_finite = lexical.Error(lexical.ErrorCode.NonFinite.value, 0)
+        self.lone_dot = lexical.Error(lexical.ErrorCode.LoneDot.value, 0)
+        self.division_by_zero = lexical.Error(lexical.ErrorCode.DivisionByZero.value, 0)
+        self.too_many_digits = lexical.Error(lexical.ErrorCode.TooManyDigits.value, 0)
+        self.negative_not_allowed = lexical.Error(lexical.ErrorCode.NegativeNotAllowed.value, 0)
+        self.scratch_too_small = lexical.Error(lexical.ErrorCode.ScratchTooSmall.value, 0)
+        self.missing_exponent = lexical.Error(lexical.ErrorCode.MissingExponent.value, 0)
+        self.invalid_sign = lexical.Error(lexical.ErrorCode.InvalidSign.value, 0)
+        self.invalid_radix = lexical.Error(lexical.ErrorCode.InvalidRadix.value, 0)
 
     def test_is_overflow(self):
         self.assertTrue(self.overflow.is_overflow())
@@ -240,6 +258,58 @@ class ErrorTests(unittest.TestCase):
         self.assertFalse(self.overflow.is_invalid_leading_zeros())
         self.assertTrue(self.invalid_leading_zeros.is_invalid_leading_zeros())
 
+    def test_is_invalid_digit_grouping(self):
+        self.assertFalse(self.overflow.is_invalid_digit_grouping())
+        self.assertTrue(self.invalid_digit_grouping.is_invalid_digit_grouping())
+
+    def test_is_out_of_range(self):
+        self.assertFalse(self.overflow.is_out_of_range())
+        self.assertTrue(self.out_of_range.is_out_of_range())
+
+    def test_is_too_many_elements(self):
+        self.assertFalse(self.overflow.is_too_many_elements())
+        self.assertTrue(self.too_many_elements.is_too_many_elements())
+
+    def test_is_zero(self):
+        self.assertFalse(self.overflow.is_zero())
+        self.assertTrue(self.zero.is_zero())
+
+    def test_is_non_finite(self):
+        self.assertFalse(self.overflow.is_non_finite())
+        self.assertTrue(self.non_finite.is_non_finite())
+
+    def test_is_lone_dot(self):
+        self.assertFalse(self.overflow.is_lone_dot())
+        self.assertTrue(self.lone_dot.is_lone_dot())
+
+    def test_is_division_by_zero(self):
+        self.assertFalse(self.overflow.is_division_by_zero())
+        self.assertTrue(self.division_by_zero.is_division_by_zero())
+
+    def test_is_too_many_digits(self):
+        self.assertFalse(self.overflow.is_too_many_digits())
+        self.assertTrue(self.too_many_digits.is_too_many_digits())
+
+    def test_is_negative_not_allowed(self):
+        self.assertFalse(self.overflow.is_negative_not_allowed())
+        self.assertTrue(self.negative_not_allowed.is_negative_not_allowed())
+
+    def test_is_scratch_too_small(self):
+        self.assertFalse(self.overflow.is_scratch_too_small())
+        self.assertTrue(self.scratch_too_small.is_scratch_too_small())
+
+    def test_is_missing_exponent(self):
+        self.assertFalse(self.overflow.is_missing_exponent())
+        self.assertTrue(self.missing_exponent.is_missing_exponent())
+
+    def test_is_invalid_sign(self):
+        self.assertFalse(self.overflow.is_invalid_sign())
+        self.assertTrue(self.invalid_sign.is_invalid_sign())
+
+    def test_is_invalid_radix(self):
+        self.assertFalse(self.overflow.is_invalid_radix())
+        self.assertTrue(self.invalid_radix.is_invalid_radix())
+
 
 class ResultTests(unittest.TestCase):
     '''Test complete and partial result types.'''
@@ -4,6 +4,7 @@ use crate::float::*;
 use crate::util::*;
 use super::bignum::ToBigfloat;
 use super::errors::FloatErrors;
+use super::lemire::LemireFloat;
 
 // TRAITS
 
@@ -11,6 +12,7 @@ use super::errors::FloatErrors;
 pub(super) trait FloatType:
     FloatRounding<u64> +
     FloatRounding<u128> +
+    LemireFloat +
     StablePower
 {
     type Mantissa: Mantissa;
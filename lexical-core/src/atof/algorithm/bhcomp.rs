@@ -6,6 +6,7 @@
 use crate::float::*;
 use crate::float::convert::*;
 use crate::float::rounding::*;
+use crate::lib::cmp;
 use crate::util::*;
 use super::alias::*;
 use super::bigcomp;
@@ -51,12 +52,14 @@ macro_rules! add_digits {
 
 /// Parse the full mantissa into a big integer.
 ///
-/// Max digits is the maximum number of digits plus one.
-pub(super) fn parse_mantissa<'a, Data>(data: Data, radix: u32, max_digits: usize)
-    -> Bigint
-    where Data: SlowDataInterface<'a>
+/// The big integer must have a value of zero. Max digits is the
+/// maximum number of digits plus one.
+pub(super) fn parse_mantissa<'a, Data, Big>(data: Data, radix: u32, max_digits: usize, mut result: Big)
+    -> Big
+    where Data: SlowDataInterface<'a>,
+          Big: SmallOps
 {
-    let small_powers = Bigint::small_powers(radix);
+    let small_powers = Big::small_powers(radix);
     let count = data.mantissa_digits();
     let bits = count / integral_binary_factor(radix).as_usize();
     let bytes = bits / (<Limb as crate::util::Integer>::BITS as usize);
@@ -68,8 +71,7 @@ pub(super) fn parse_mantissa<'a, Data>(data: Data, radix: u32, max_digits: usize
     let mut counter = 0;
     let mut value: Limb = 0;
     let mut i: usize = 0;
-    let mut result = Bigint::default();
-    result.data.reserve(bytes);
+    result.data_mut().reserve(bytes);
 
     // Iteratively process all the data in the mantissa.
     let mut integer_iter = data.integer_iter();
@@ -87,16 +89,19 @@ pub(super) fn parse_mantissa<'a, Data>(data: Data, radix: u32, max_digits: usize
         result.iadd_small(value);
     }
 
-    // If we have any remaining digits after the last value, we need
-    // to add a 1 after the rest of the array, it doesn't matter where,
+    // If we have any non-zero remaining digits after the last value, we
+    // need to add a 1 after the rest of the array, it doesn't matter where,
     // just move it up. This is good for the worst-possible float
     // representation. We also need to return an index.
-    // Since we already trimmed trailing zeros, we know there has
-    // to be a non-zero digit if there are any left.
+    // Trailing zeros are only trimmed from the fraction, so the remaining
+    // digits may all be zero for a large integer, which must add a 0.
     let is_consumed = integer_iter.consumed() && fraction_iter.consumed();
     if !is_consumed {
+        let mut remaining = integer_iter.chain(fraction_iter);
         result.imul_small(base);
-        result.iadd_small(1);
+        if remaining.any(|&c| c != b'0') {
+            result.iadd_small(1);
+        }
     }
 
     result
@@ -269,16 +274,19 @@ pub(super) fn use_bigcomp(radix: u32, count: usize)
 }}
 
 /// Calculate the mantissa for a big integer with a positive exponent.
-pub(super) fn large_atof<'a, F, Data>(data: Data, radix: u32, max_digits: usize, exponent: i32, kind: RoundingKind)
+///
+/// The big integer must have a value of zero.
+pub(super) fn large_atof<'a, F, Data, Big>(data: Data, radix: u32, max_digits: usize, exponent: i32, kind: RoundingKind, bigmant: Big)
     -> F
     where F: FloatType,
-          Data: SlowDataInterface<'a>
+          Data: SlowDataInterface<'a>,
+          Big: SmallOps
 {
     // Simple, we just need to multiply by the power of the radix.
     // Now, we can calculate the mantissa and the exponent from this.
     // The binary exponent is the binary exponent for the mantissa
     // shifted to the hidden bit.
-    let mut bigmant = parse_mantissa(data, radix, max_digits);
+    let mut bigmant = parse_mantissa(data, radix, max_digits, bigmant);
     bigmant.imul_power(radix, exponent.as_u32());
 
     // Get the exact representation of the float from the big integer.
@@ -293,20 +301,23 @@ pub(super) fn large_atof<'a, F, Data>(data: Data, radix: u32, max_digits: usize,
 
 /// Calculate the mantissa for a big integer with a negative exponent.
 ///
-/// This invokes the comparison with `b+h`.
-pub(super) fn small_atof<'a, F, Data>(data: Data, radix: u32, max_digits: usize, exponent: i32, f: F, kind: RoundingKind)
+/// This invokes the comparison with `b+h`. Both big integers must
+/// have a value of zero.
+pub(super) fn small_atof<'a, F, Data, Big>(data: Data, radix: u32, max_digits: usize, exponent: i32, f: F, kind: RoundingKind, real_digits: Big, mut theor_digits: Big)
     -> F
     where F: FloatType,
-          Data: SlowDataInterface<'a>
+          Data: SlowDataInterface<'a>,
+          Big: SmallOps
 {
     // Get the significant digits and radix exponent for the real digits.
-    let mut real_digits = parse_mantissa(data, radix, max_digits);
+    let mut real_digits = parse_mantissa(data, radix, max_digits, real_digits);
     let real_exp = exponent;
     debug_assert!(real_exp < 0);
 
     // Get the significant digits and the binary exponent for `b+h`.
     let theor = bigcomp::theoretical_float(f, kind);
-    let mut theor_digits = Bigint::from_u64(theor.mant().as_u64());
+    theor_digits.data_mut().extend_from_slice(&split_u64(theor.mant().as_u64()));
+    theor_digits.normalize();
     let theor_exp = theor.exp();
 
     // We need to scale the real digits and `b+h` digits to be the same
@@ -370,8 +381,100 @@ pub(super) fn atof<'a, F, Data>(data: Data, radix: u32, f: F, kind: RoundingKind
         // Use the slower algorithm for giant data, since we use a lot less memory.
         bigcomp::atof(data, radix, f, kind)
     } else if exponent >= 0 {
-        large_atof(data, radix, max_digits, exponent, kind)
+        large_atof(data, radix, max_digits, exponent, kind, Bigint::default())
     } else {
-        small_atof(data, radix, max_digits, exponent, f, kind)
+        small_atof(data, radix, max_digits, exponent, f, kind, Bigint::default(), Bigint::default())
     }
 }
+
+// ROUNDING ERROR
+
+/// Calculate the rounding error of the float, relative to the exact value.
+///
+/// The rounding error is the difference between the exact value of the
+/// digits and the float, divided by the gap between the float and its
+/// neighbor in the direction of the exact value. It is positive if the
+/// exact value is above the float, is 0 if the float is exact, and is
+/// `-inf` if the float overflowed to infinity.
+///
+/// Notes:
+///     The digits iterator must not have any trailing zeros (true for
+///     `FloatState2`).
+///     The mantissa must not be zero.
+pub(super) fn rounding_error<'a, F, Data>(data: Data, radix: u32, f: F)
+    -> f64
+    where F: FloatType,
+          Data: SlowDataInterface<'a>
+{
+    if f.is_special() {
+        // Overflow, the exact value is finite and below the float.
+        return f64::NEG_INFINITY;
+    }
+
+    // Exact values far below the smallest denormal float have a rounding
+    // error below the smallest denormal f64, which is 0. Return early,
+    // so the big integers cannot exceed their capacity.
+    let scientific_exponent = data.scientific_exponent();
+    if f.is_zero() {
+        let log2_radix = (31 - radix.leading_zeros()).as_i32();
+        let bits = log2_radix.saturating_mul(scientific_exponent.saturating_add(1));
+        if bits < F::DENORMAL_EXPONENT + f64::DENORMAL_EXPONENT - 1 {
+            return 0.0;
+        }
+    }
+
+    // Get the significant digits and radix exponent for the real digits,
+    // like `atof`.
+    let max_digits = unwrap_or_max(max_digits::<F>(radix));
+    let count = max_digits.min(data.mantissa_digits());
+    let exponent = scientific_exponent + 1 - count.as_i32();
+    let mut real_digits = parse_mantissa(data, radix, max_digits, Bigint::default());
+
+    // Factor the radix into a power of two and an odd radix, and scale
+    // the real digits and the float by the odd power to integers. The
+    // factor is the odd power each side was multiplied by for negative
+    // exponents, and scales the gap between floats.
+    let pow2_exp = radix.trailing_zeros();
+    let odd_radix = radix >> pow2_exp;
+    let real_exp = pow2_exp.as_i32() * exponent;
+    let mut factor = Bigint::from_u32(1);
+    if odd_radix != 1 && exponent >= 0 {
+        real_digits.imul_power(odd_radix, exponent.as_u32());
+    } else if odd_radix != 1 {
+        factor.imul_power(odd_radix, (-exponent).as_u32());
+    }
+
+    // Get the significant digits and binary exponent for the float,
+    // and scale both to the same binary exponent. The gap below the
+    // float may be half the gap above it, so shift to one bit below
+    // the float's exponent.
+    let b = bigcomp::b(f);
+    let shift = real_exp.min(b.exp() - 1);
+    real_digits.ishl((real_exp - shift).as_usize());
+    let mut float_digits = Bigint::from_u64(b.mant().as_u64());
+    if !float_digits.is_zero() {
+        float_digits.imul_large(&factor);
+        float_digits.ishl((b.exp() - shift).as_usize());
+    }
+
+    // Calculate the difference, and the gap to the adjacent float.
+    let (diff, gap_exp, sign) = match real_digits.compare(&float_digits) {
+        cmp::Ordering::Equal    => return 0.0,
+        cmp::Ordering::Greater  => {
+            real_digits.isub_large(&float_digits);
+            (real_digits, b.exp(), 1.0)
+        },
+        cmp::Ordering::Less     => {
+            float_digits.isub_large(&real_digits);
+            (float_digits, bigcomp::b(f.prev_positive()).exp(), -1.0)
+        },
+    };
+    let mut gap = factor;
+    gap.ishl((gap_exp - shift).as_usize());
+
+    // Calculate the ratio from the high bits of each.
+    let (diff_hi, _) = diff.hi64();
+    let (gap_hi, _) = gap.hi64();
+    let bits = diff.bit_length().as_i32() - gap.bit_length().as_i32();
+    sign * (diff_hi.as_f64() / gap_hi.as_f64()) * f64::powi(2.0, bits)
+}
@@ -1,6 +1,8 @@
 //! Big integer type definition.
 
 use crate::float::*;
+#[cfg(feature = "bignum")]
+use crate::lib::{cmp, fmt};
 use crate::util::*;
 use super::math::*;
 
@@ -113,6 +115,49 @@ impl SmallOps for Bigint {
 impl LargeOps for Bigint {
 }
 
+// SCRATCH BIGINT
+
+/// Storage for a big integer type in borrowed limbs.
+///
+/// The limbs have a fixed capacity, so any operation which exceeds
+/// the capacity panics. Only the small operations are implemented,
+/// since the large operations require temporary storage.
+#[derive(Default)]
+pub(crate) struct ScratchBigint<'a> {
+    /// Internal storage for the Bigint, in little-endian order.
+    pub(crate) data: SliceVec<'a, Limb>,
+}
+
+impl<'a> ScratchBigint<'a> {
+    /// Create a big integer with a value of zero, stored in the limbs.
+    perftools_inline!{
+    pub(crate) fn new(limbs: &'a mut [Limb]) -> Self {
+        ScratchBigint { data: SliceVec::from_storage(limbs) }
+    }}
+}
+
+impl<'a> SharedOps for ScratchBigint<'a> {
+    type StorageType = SliceVec<'a, Limb>;
+
+    perftools_inline_always!{
+    fn data<'b>(&'b self) -> &'b Self::StorageType {
+        &self.data
+    }}
+
+    perftools_inline_always!{
+    fn data_mut<'b>(&'b mut self) -> &'b mut Self::StorageType {
+        &mut self.data
+    }}
+}
+
+impl<'a> SmallOps for ScratchBigint<'a> {
+    perftools_inline!{
+    fn imul_power_impl(&mut self, radix: u32, n: u32) {
+        // Multiplying by the large powers requires temporary storage.
+        small::imul_small_powers(self.data_mut(), radix, n);
+    }}
+}
+
 // BIGFLOAT
 
 // Adjust the storage capacity for the underlying array.
@@ -206,13 +251,350 @@ impl ToBigfloat<u128> for ExtendedFloat<u128> {
     }}
 }
 
+// BIGNUM
+
+/// Arbitrary-precision unsigned integer for decimal-to-float conversions.
+///
+/// A minimal big integer with the operations required by the slow path
+/// of the correct parser: multiplication by a small integer or a power
+/// of the radix, comparison and shifts. All operations modify the value
+/// in-place, and `set_u64`, `set_u128` and `clear` reuse the existing
+/// storage, so a single `Bignum` may be used as a scratch buffer for
+/// many conversions without reallocating.
+///
+/// Without the `radix` feature, the storage is a fixed-size array of
+/// 4096 bits, enough for any decimal-to-`f64` conversion, and no
+/// allocation is done. With the `radix` feature, the storage is a `Vec`.
+///
+/// # Panics
+///
+/// Without the `radix` feature, any operation which overflows the
+/// fixed-size storage panics.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// use lexical_core::bignum::Bignum;
+///
+/// // 5 * 10^20 == 2^20 * 476837158203125
+/// let mut x = Bignum::from_u64(5);
+/// x.mul_pow(10, 20);
+/// let mut y = Bignum::from_u64(476837158203125);
+/// y.shl(20);
+/// assert_eq!(x, y);
+///
+/// // Reuse the storage for another value.
+/// y.set_u64(1);
+/// assert!(y < x);
+/// # }
+/// ```
+#[cfg(feature = "bignum")]
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Bignum {
+    int: Bigint,
+}
+
+#[cfg(feature = "bignum")]
+impl Bignum {
+    /// Create a new `Bignum` with a value of zero.
+    #[inline]
+    pub fn new() -> Bignum {
+        Bignum::default()
+    }
+
+    /// Create a new `Bignum` from a 64-bit integer.
+    #[inline]
+    pub fn from_u64(x: u64) -> Bignum {
+        Bignum { int: Bigint::from_u64(x) }
+    }
+
+    /// Create a new `Bignum` from a 128-bit integer.
+    #[inline]
+    pub fn from_u128(x: u128) -> Bignum {
+        Bignum { int: Bigint::from_u128(x) }
+    }
+
+    /// Set the value to zero, keeping the storage.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.int.data.clear();
+    }
+
+    /// Set the value from a 64-bit integer, keeping the storage.
+    #[inline]
+    pub fn set_u64(&mut self, x: u64) {
+        self.int.data.clear();
+        self.int.data.extend_from_slice(&split_u64(x));
+        self.int.normalize();
+    }
+
+    /// Set the value from a 128-bit integer, keeping the storage.
+    #[inline]
+    pub fn set_u128(&mut self, x: u128) {
+        self.int.data.clear();
+        self.int.data.extend_from_slice(&split_u128(x));
+        self.int.normalize();
+    }
+
+    /// Get if the value is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.int.is_zero()
+    }
+
+    /// Get the number of bits required to store the value.
+    #[inline]
+    pub fn bit_length(&self) -> usize {
+        self.int.bit_length()
+    }
+
+    /// Get the 64 most significant bits of the value, normalized so the
+    /// most significant bit is set, and if any lower bits were truncated.
+    #[inline]
+    pub fn hi64(&self) -> (u64, bool) {
+        self.int.hi64()
+    }
+
+    /// Add a small integer to the value.
+    #[inline]
+    pub fn add_small(&mut self, y: u32) {
+        self.int.iadd_small(as_limb(y));
+    }
+
+    /// Multiply the value by a small integer.
+    #[inline]
+    pub fn mul_small(&mut self, y: u32) {
+        self.int.imul_small(as_limb(y));
+        self.int.normalize();
+    }
+
+    /// Multiply the value by `base^exp`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base is not 2, 5 or 10, or, with the `radix`
+    /// feature, if the base is not in the range `[2, 36]`.
+    #[inline]
+    pub fn mul_pow(&mut self, base: u32, exp: u32) {
+        #[cfg(not(feature = "radix"))]
+        assert!(base == 2 || base == 5 || base == 10, "Bignum::mul_pow requires a base of 2, 5 or 10.");
+
+        #[cfg(feature = "radix")]
+        assert!((2..=36).contains(&base), "Bignum::mul_pow requires a base in the range [2, 36].");
+
+        self.int.imul_power(base, exp);
+    }
+
+    /// Multiply the value by another `Bignum`.
+    #[inline]
+    pub fn mul(&mut self, y: &Bignum) {
+        self.int.imul_large(&y.int);
+        self.int.normalize();
+    }
+
+    /// Shift the value left by `n` bits.
+    #[inline]
+    pub fn shl(&mut self, n: usize) {
+        self.int.ishl(n);
+    }
+}
+
+#[cfg(feature = "bignum")]
+impl PartialOrd for Bignum {
+    #[inline]
+    fn partial_cmp(&self, other: &Bignum) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "bignum")]
+impl Ord for Bignum {
+    #[inline]
+    fn cmp(&self, other: &Bignum) -> cmp::Ordering {
+        self.int.compare(&other.int)
+    }
+}
+
+#[cfg(feature = "bignum")]
+impl fmt::Debug for Bignum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Bignum").field(&&self.int.data[..]).finish()
+    }
+}
+
 // TESTS
 // -----
 
-#[cfg(all(test, feature = "correct", feature = "radix"))]
+#[cfg(all(test, any(feature = "radix", feature = "bignum")))]
 mod test {
     use super::*;
 
+    #[cfg(feature = "bignum")]
+    fn from_decimal(s: &str) -> Bignum {
+        let mut x = Bignum::new();
+        for c in s.bytes() {
+            x.mul_small(10);
+            x.add_small((c - b'0') as u32);
+        }
+        x
+    }
+
+    #[cfg(feature = "bignum")]
+    #[test]
+    fn bignum_set_test() {
+        let mut x = Bignum::from_u128(1 << 100);
+        assert_eq!(x.bit_length(), 101);
+        x.set_u64(3);
+        assert_eq!(x, Bignum::from_u64(3));
+        assert_eq!(x.hi64(), (3 << 62, false));
+        x.set_u128(1 << 64);
+        assert_eq!(x, Bignum::from_u128(1 << 64));
+        x.set_u64(0);
+        assert!(x.is_zero());
+        assert_eq!(x.bit_length(), 0);
+        x.set_u64(1);
+        x.clear();
+        assert!(x.is_zero());
+        assert_eq!(x, Bignum::new());
+    }
+
+    #[cfg(feature = "bignum")]
+    #[test]
+    fn bignum_add_small_test() {
+        let mut x = Bignum::from_u64(u64::max_value());
+        x.add_small(1);
+        assert_eq!(x, Bignum::from_u128(1 << 64));
+
+        let mut x = Bignum::new();
+        x.add_small(5);
+        assert_eq!(x, Bignum::from_u64(5));
+    }
+
+    #[cfg(feature = "bignum")]
+    #[test]
+    fn bignum_mul_small_test() {
+        let mut x = Bignum::from_u64(u64::max_value());
+        x.mul_small(3);
+        assert_eq!(x, Bignum::from_u128(3 * u64::max_value() as u128));
+
+        let mut x = Bignum::new();
+        x.mul_small(7);
+        assert!(x.is_zero());
+
+        let mut x = Bignum::from_u64(12345);
+        x.mul_small(0);
+        assert!(x.is_zero());
+    }
+
+    #[cfg(feature = "bignum")]
+    #[test]
+    fn bignum_mul_pow_test() {
+        let mut x = Bignum::from_u64(1);
+        x.mul_pow(10, 38);
+        assert_eq!(x, Bignum::from_u128(10u128.pow(38)));
+
+        let mut x = Bignum::from_u64(3);
+        x.mul_pow(5, 27);
+        assert_eq!(x, Bignum::from_u128(3 * 5u128.pow(27)));
+
+        let mut x = Bignum::from_u64(3);
+        x.mul_pow(2, 100);
+        assert_eq!(x, Bignum::from_u128(3 << 100));
+
+        let mut x = Bignum::from_u64(17);
+        x.mul_pow(10, 0);
+        assert_eq!(x, Bignum::from_u64(17));
+
+        // Beyond a single small power.
+        let mut x = Bignum::from_u64(123);
+        x.mul_pow(10, 320);
+        let mut expected = String::from("123");
+        expected.extend((0..320).map(|_| '0'));
+        assert_eq!(x, from_decimal(&expected));
+    }
+
+    #[cfg(all(feature = "bignum", feature = "radix"))]
+    #[test]
+    fn bignum_mul_pow_radix_test() {
+        let mut x = Bignum::from_u64(2);
+        x.mul_pow(3, 40);
+        assert_eq!(x, Bignum::from_u128(2 * 3u128.pow(40)));
+
+        let mut x = Bignum::from_u64(1);
+        x.mul_pow(36, 24);
+        assert_eq!(x, Bignum::from_u128(36u128.pow(24)));
+    }
+
+    #[cfg(feature = "bignum")]
+    #[test]
+    #[should_panic]
+    fn bignum_mul_pow_invalid_test() {
+        Bignum::from_u64(1).mul_pow(37, 1);
+    }
+
+    #[cfg(feature = "bignum")]
+    #[test]
+    fn bignum_mul_test() {
+        let mut x = Bignum::from_u64(u64::max_value());
+        x.mul(&Bignum::from_u64(u64::max_value()));
+        assert_eq!(x, Bignum::from_u128(u64::max_value() as u128 * u64::max_value() as u128));
+
+        let mut x = from_decimal("123456789012345678901234567890");
+        x.mul(&from_decimal("987654321098765432109876543210"));
+        assert_eq!(x, from_decimal("121932631137021795226185032733622923332237463801111263526900"));
+
+        let mut x = Bignum::from_u64(5);
+        x.mul(&Bignum::new());
+        assert!(x.is_zero());
+    }
+
+    #[cfg(feature = "bignum")]
+    #[test]
+    fn bignum_shl_test() {
+        let mut x = Bignum::from_u64(1);
+        x.shl(0);
+        assert_eq!(x, Bignum::from_u64(1));
+        x.shl(63);
+        assert_eq!(x, Bignum::from_u64(1 << 63));
+        x.shl(1);
+        assert_eq!(x, Bignum::from_u128(1 << 64));
+        x.shl(63);
+        assert_eq!(x, Bignum::from_u128(1 << 127));
+        assert_eq!(x.bit_length(), 128);
+
+        let mut x = Bignum::from_u64(0xABCD);
+        x.shl(200);
+        assert_eq!(x.bit_length(), 216);
+        assert_eq!(x.hi64(), (0xABCD << 48, false));
+    }
+
+    #[cfg(feature = "bignum")]
+    #[test]
+    fn bignum_cmp_test() {
+        let zero = Bignum::new();
+        let one = Bignum::from_u64(1);
+        let big = Bignum::from_u128(1 << 100);
+        let bigger = Bignum::from_u128((1 << 100) + 1);
+        assert_eq!(zero.cmp(&zero), cmp::Ordering::Equal);
+        assert_eq!(zero.cmp(&one), cmp::Ordering::Less);
+        assert_eq!(big.cmp(&one), cmp::Ordering::Greater);
+        assert_eq!(big.cmp(&bigger), cmp::Ordering::Less);
+        assert_eq!(bigger.cmp(&big), cmp::Ordering::Greater);
+        assert!(zero < one && one < big && big < bigger);
+        assert_eq!(big.clone().max(bigger.clone()), bigger);
+    }
+
+    #[cfg(feature = "bignum")]
+    #[test]
+    fn bignum_hi64_test() {
+        assert_eq!(Bignum::new().hi64(), (0, false));
+        assert_eq!(Bignum::from_u64(1).hi64(), (1 << 63, false));
+        assert_eq!(Bignum::from_u128((1 << 64) | 1).hi64(), (1 << 63, true));
+    }
+
+    #[cfg(feature = "radix")]
     #[test]
     fn integral_binary_factor_test() {
         const TABLE: [u32; 35] = [1, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 6, 6, 6, 6];
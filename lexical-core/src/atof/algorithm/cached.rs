@@ -1,6 +1,9 @@
 //! Cached powers trait for extended-precision floats.
 
 use crate::float::{ExtendedFloat, Mantissa};
+
+cfg_if! {
+if #[cfg(feature = "table")] {
 use super::cached_float80;
 use super::cached_float160;
 
@@ -65,6 +68,11 @@ impl<M: Mantissa> ModeratePathPowers<M> {
     pub fn get_small_int(&self, index: usize) -> M {
         self.small_int[index]
     }}
+
+    perftools_inline!{
+    pub fn large_len(&self) -> usize {
+        self.large.len()
+    }}
 }
 
 // CACHED EXTENDED POWERS
@@ -88,3 +96,28 @@ impl ModeratePathCache<u128> for ExtendedFloat<u128> {
         cached_float160::get_powers(radix)
     }}
 }
+} else {
+use super::cached_runtime::{self, ModeratePathPowers};
+
+// CALCULATED EXTENDED POWERS
+
+/// Calculated powers as a trait for a floating-point type.
+pub(super) trait ModeratePathCache<M: Mantissa> {
+    /// Get powers from radix.
+    fn get_powers(radix: u32) -> ModeratePathPowers<M>;
+}
+
+impl ModeratePathCache<u64> for ExtendedFloat<u64> {
+    perftools_inline!{
+    fn get_powers(radix: u32) -> ModeratePathPowers<u64> {
+        cached_runtime::get_powers(radix)
+    }}
+}
+
+impl ModeratePathCache<u128> for ExtendedFloat<u128> {
+    perftools_inline!{
+    fn get_powers(radix: u32) -> ModeratePathPowers<u128> {
+        cached_runtime::get_powers(radix)
+    }}
+}
+}}  // cfg_if
@@ -0,0 +1,185 @@
+//! Calculated exponents for basen values with extended floats.
+//!
+//! Calculates the same powers as the pre-computed tables in `cached_float80`
+//! and `cached_float160` on demand, using arbitrary-precision arithmetic,
+//! rather than storing ~70KB of tables in the binary. Each value is an
+//! exact power of the base, truncated to the mantissa size and normalized.
+//!
+//! This is significantly slower than using the pre-computed tables:
+//! every large power requires a big-integer exponentiation, and every
+//! negative large power also requires a big-integer division. Only use
+//! this for size-constrained builds.
+
+use crate::float::{ExtendedFloat, Mantissa};
+use crate::lib::marker::PhantomData;
+use crate::util::*;
+use super::bignum::Bigint;
+use super::math::*;
+
+// POWERS
+
+/// Powers of base N for the moderate path, calculated on demand.
+#[doc(hidden)]
+pub(crate) struct ModeratePathPowers<M: Mantissa> {
+    // Radix for the powers.
+    radix: u32,
+    // Step between large powers and number of small powers.
+    pub step: i32,
+    // Exponent bias for the large powers.
+    pub bias: i32,
+    // Number of large powers.
+    large_len: usize,
+    // Mantissa type for the powers.
+    marker: PhantomData<M>,
+}
+
+impl<M: Mantissa> ModeratePathPowers<M> {
+    perftools_inline!{
+    pub fn get_small(&self, index: usize) -> ExtendedFloat<M> {
+        let mut fp = ExtendedFloat { mant: self.get_small_int(index), exp: 0 };
+        fp.normalize();
+        fp
+    }}
+
+    perftools_inline!{
+    pub fn get_large(&self, index: usize) -> ExtendedFloat<M> {
+        debug_assert!(index < self.large_len);
+        let exponent = index.as_i32() * self.step - self.bias;
+        let mut power = Bigint::from_u32(1);
+        power.imul_power(self.radix, exponent.wrapping_abs().as_u32());
+        let bit_length = power.bit_length().as_i32();
+
+        if exponent >= 0 {
+            // Truncate the exact power to the high bits.
+            let (hi, _) = power.hi128();
+            let mant = as_cast(hi >> (128 - M::FULL));
+            ExtendedFloat { mant: mant, exp: bit_length - M::FULL }
+        } else {
+            // Truncate the reciprocal to the high bits. The power is
+            // never a power of 2, so the quotient always has exactly
+            // `M::FULL` bits.
+            let shift = bit_length - 1 + M::FULL;
+            let mut quotient = Bigint::from_u32(1);
+            quotient.ishl(shift.as_usize());
+            quotient.idiv_large(&power);
+            let (hi, _) = quotient.hi128();
+            let mant = as_cast(hi >> (128 - M::FULL));
+            ExtendedFloat { mant: mant, exp: -shift }
+        }
+    }}
+
+    perftools_inline!{
+    pub fn get_small_int(&self, index: usize) -> M {
+        debug_assert!(index < self.step.as_usize());
+        as_cast(self.radix.as_u64().pow(index.as_u32()))
+    }}
+
+    perftools_inline!{
+    pub fn large_len(&self) -> usize {
+        self.large_len
+    }}
+}
+
+/// Get powers from base.
+pub(crate) fn get_powers<M: Mantissa>(radix: u32)
+    -> ModeratePathPowers<M>
+{
+    debug_assert_radix!(radix);
+
+    // Step, bias and number of large powers for each base, which
+    // match the pre-computed tables.
+    #[cfg(not(feature = "radix"))]
+    let (step, bias, large_len) = (10, 350, 66);
+
+    #[cfg(feature = "radix")]
+    let (step, bias, large_len) = match radix {
+        3  => (20, 720, 69),
+        5  => (14, 504, 68),
+        6  => (12, 444, 71),
+        7  => (11, 407, 71),
+        9  => (10, 360, 69),
+        10 => (10, 350, 66),
+        11 => (9, 333, 70),
+        12 => (9, 324, 68),
+        13 => (8, 312, 74),
+        14 => (8, 304, 72),
+        15 => (8, 296, 70),
+        17 => (8, 280, 67),
+        18 => (7, 273, 75),
+        19 => (7, 273, 74),
+        20 => (7, 266, 72),
+        21 => (7, 266, 72),
+        22 => (7, 259, 70),
+        23 => (7, 252, 69),
+        24 => (7, 252, 68),
+        25 => (7, 252, 68),
+        26 => (7, 245, 67),
+        27 => (6, 240, 76),
+        28 => (6, 240, 76),
+        29 => (6, 240, 76),
+        30 => (6, 234, 74),
+        31 => (6, 234, 74),
+        33 => (6, 228, 72),
+        34 => (6, 228, 72),
+        35 => (6, 222, 71),
+        36 => (6, 222, 71),
+        // Powers of 2, and others, should already be handled by now.
+        _  => unreachable!(),
+    };
+
+    ModeratePathPowers {
+        radix: radix,
+        step: step,
+        bias: bias,
+        large_len: large_len,
+        marker: PhantomData,
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(all(test, feature = "table"))]
+mod tests {
+    use crate::util::test::*;
+    use super::super::{cached_float80, cached_float160};
+    use super::*;
+
+    #[test]
+    fn float80_test() {
+        // Ensure the calculated powers exactly match the tables.
+        for base in BASE_POWN.iter().cloned() {
+            let expected = cached_float80::get_powers(base);
+            let actual = get_powers::<u64>(base);
+            assert_eq!(actual.step, expected.step);
+            assert_eq!(actual.bias, expected.bias);
+            assert_eq!(actual.large_len(), expected.large.len());
+            for idx in 0..expected.small.len() {
+                assert_eq!(actual.get_small(idx), expected.get_small(idx));
+                assert_eq!(actual.get_small_int(idx), expected.get_small_int(idx));
+            }
+            for idx in 0..expected.large.len() {
+                assert_eq!(actual.get_large(idx), expected.get_large(idx));
+            }
+        }
+    }
+
+    #[test]
+    fn float160_test() {
+        // Ensure the calculated powers exactly match the tables.
+        for base in BASE_POWN.iter().cloned() {
+            let expected = cached_float160::get_powers(base);
+            let actual = get_powers::<u128>(base);
+            assert_eq!(actual.step, expected.step);
+            assert_eq!(actual.bias, expected.bias);
+            assert_eq!(actual.large_len(), expected.large.len());
+            for idx in 0..expected.small.len() {
+                assert_eq!(actual.get_small(idx), expected.get_small(idx));
+                assert_eq!(actual.get_small_int(idx), expected.get_small_int(idx));
+            }
+            for idx in 0..expected.large.len() {
+                assert_eq!(actual.get_large(idx), expected.get_large(idx));
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Classify the digits of a numeric string, without parsing the value.
+
+use crate::util::*;
+use super::super::classify::NumberKind;
+use super::format::*;
+
+// Determine if the integer digits fit in a 64-bit integer with the sign.
+perftools_inline!{
+fn is_integer<'a, Iter>(iter: Iter, radix: u32, sign: Sign)
+    -> bool
+    where Iter: Iterator<Item=&'a u8>
+{
+    let max = match sign {
+        Sign::Positive => u64::max_value(),
+        Sign::Negative => i64::min_value().wrapping_abs() as u64,
+    };
+    let mut value: u64 = 0;
+    for &c in iter {
+        let digit = as_cast((c as char).to_digit(radix).unwrap());
+        value = match value.checked_mul(radix.as_u64()).and_then(|v| v.checked_add(digit)) {
+            Some(v) if v <= max => v,
+            _                   => return false,
+        };
+    }
+    true
+}}
+
+/// Classify the digits of a numeric string.
+///
+/// The string must be non-special and unsigned.
+fn to_kind<'a, Data>(mut data: Data, bytes: &'a [u8], radix: u32, sign: Sign)
+    -> ParseResult<(NumberKind, *const u8)>
+    where Data: FastDataInterface<'a>
+{
+    let ptr = data.extract(bytes, radix)?;
+    let is_float = data.fraction().is_some() || data.exponent().is_some();
+    let kind = match is_float || !is_integer(data.integer_iter(), radix, sign) {
+        true  => NumberKind::Float,
+        false => NumberKind::Integer,
+    };
+    Ok((kind, ptr))
+}
+
+// Classify the digits of a numeric string.
+//
+// The string must be non-special and unsigned.
+perftools_inline!{
+pub(crate) fn classify_digits(bytes: &[u8], radix: u32, sign: Sign, format: NumberFormat)
+    -> ParseResult<(NumberKind, *const u8)>
+{
+    apply_interface!(to_kind, format, bytes, radix, sign)
+}}
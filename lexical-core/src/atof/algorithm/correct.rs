@@ -6,12 +6,16 @@
 
 use crate::atoi;
 use crate::float::*;
+use crate::lib::result::Result as StdResult;
 use crate::util::*;
+use super::super::cost::ParseCost;
 use super::alias::*;
 use super::bhcomp;
 use super::cached::ModeratePathCache;
 use super::errors::FloatErrors;
 use super::format::*;
+use super::lemire::eisel_lemire_truncated;
+use super::scratch;
 use super::small_powers::get_small_powers_64;
 
 // HELPERS
@@ -25,42 +29,264 @@ fn process_mantissa<'a, M, Data>(data: &Data, radix: u32)
     where M: Mantissa,
           Data: FastDataInterface<'a>
 {
-    atoi::standalone_mantissa(data.integer_iter(), data.fraction_iter(), radix)
+    // Decimal digits without digit separators are parsed 8 at a time.
+    match radix == 10 && is_contiguous(data.format()) {
+        true  => atoi::standalone_mantissa_decimal(data.integer(), data.fraction().unwrap_or(&[])),
+        false => atoi::standalone_mantissa(data.integer_iter(), data.fraction_iter(), radix),
+    }
+}}
+
+// Determine if any truncated digits are non-zero.
+//
+// This is the sticky bit of the truncated digits: if all truncated digits
+// are zero, such as the trailing zeros of a large integer, the mantissa is
+// exact, and the truncation does not add any error.
+perftools_inline!{
+fn is_truncated_nonzero<'a, Data>(data: &Data)
+    -> bool
+    where Data: SlowDataInterface<'a>
+{
+    let index = data.mantissa_digits() - data.truncated_digits();
+    let iter = data.integer_iter().chain(data.significant_fraction_iter()).skip(index);
+    let count = iter.take_while(|&&c| c == b'0').count();
+    count < data.truncated_digits()
+}}
+
+/// Value of the truncated digits, relative to half a unit in the last
+/// digit of the truncated mantissa.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Truncated {
+    /// All truncated digits are zero, so the mantissa is exact.
+    Zero,
+    /// The truncated digits are non-zero and below halfway.
+    BelowHalf,
+    /// The truncated digits are exactly halfway.
+    Half,
+    /// The truncated digits are above halfway.
+    AboveHalf,
+}
+
+// Compare the truncated digits to halfway.
+//
+// Halfway is `radix / 2` followed by zeros for an even radix. For an odd
+// radix, halfway is `radix / 2` repeated forever, so the truncated digits
+// are never exactly halfway.
+perftools_inline!{
+fn truncated_summary<'a, Data>(data: &Data, radix: u32)
+    -> Truncated
+    where Data: SlowDataInterface<'a>
+{
+    if !is_truncated_nonzero(data) {
+        return Truncated::Zero;
+    }
+
+    let index = data.mantissa_digits() - data.truncated_digits();
+    let iter = data.integer_iter().chain(data.significant_fraction_iter()).skip(index);
+    let is_odd = radix % 2 == 1;
+    for (i, &c) in iter.enumerate() {
+        let half = match i == 0 || is_odd {
+            true  => radix / 2,
+            false => 0,
+        };
+        let digit = (c as char).to_digit(radix).unwrap();
+        if digit < half {
+            return Truncated::BelowHalf;
+        } else if digit > half {
+            return Truncated::AboveHalf;
+        }
+    }
+
+    match is_odd {
+        true  => Truncated::BelowHalf,
+        false => Truncated::Half,
+    }
+}}
+
+// Determine if the integer and fraction digits cannot contain digit separators.
+perftools_inline!{
+#[cfg(feature = "format")]
+fn is_contiguous(format: NumberFormat) -> bool {
+    let mask = NumberFormat::INTEGER_DIGIT_SEPARATOR_FLAG_MASK
+        | NumberFormat::FRACTION_DIGIT_SEPARATOR_FLAG_MASK;
+    !format.intersects(mask)
+}}
+
+// Determine if the integer and fraction digits cannot contain digit separators.
+perftools_inline!{
+#[cfg(not(feature = "format"))]
+fn is_contiguous(_: NumberFormat) -> bool {
+    true
 }}
 
 // FAST
 // ----
 
+// PATH
+
+/// Sub-case taken by the fast path, to check the intended branch in tests.
+#[cfg(test)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FastPath {
+    /// The mantissa would be truncated.
+    InexactMantissa,
+    /// The exponent is 0, so the float is the mantissa.
+    ZeroExponent,
+    /// The exponent is within the exact exponent limits.
+    InRange,
+    /// Digits are shifted from the exponent into the mantissa.
+    Disguised,
+    /// Shifting digits into the mantissa would truncate it.
+    DisguisedInexact,
+    /// The exponent is too small or too large.
+    OutOfRange,
+    /// The power-of-two exponent is above the exponent limits.
+    Pow2Infinity,
+    /// The power-of-two exponent underflows to zero.
+    Pow2Zero,
+    /// The power-of-two exponent is denormal, scaled in two steps.
+    Pow2Denormal,
+    /// The power-of-two exponent is within the exponent limits.
+    Pow2InRange,
+}
+
+/// Tag for the sub-case taken by the fast path, only recorded in tests.
+#[cfg(test)]
+type PathTag = FastPath;
+
+/// Tag for the sub-case taken by the fast path, only recorded in tests.
+#[cfg(not(test))]
+type PathTag = ();
+
+// Get the tag for the sub-case taken by the fast path.
+#[cfg(test)]
+macro_rules! path_tag {
+    ($path:ident) => (FastPath::$path);
+}
+
+// Get the tag for the sub-case taken by the fast path.
+#[cfg(not(test))]
+macro_rules! path_tag {
+    ($path:ident) => (());
+}
+
 // POWN
 
+// Detect if the mantissa can be exactly represented by the float.
+//
+// Trailing zeros only affect the exponent, so a mantissa can be exactly
+// represented if the bits between the most and least-significant set bits
+// fit in the significand, including the hidden bit. This includes values
+// at or above the significand boundary, like `2^53` for `f64`.
+perftools_inline!{
+fn is_exact_mantissa<F: FloatType>(mantissa: u64)
+    -> bool
+{
+    mantissa == 0 || (mantissa >> mantissa.trailing_zeros()) >> (F::MANTISSA_SIZE + 1) == 0
+}}
+
+// Move trailing factors of the radix from the mantissa to the exponent.
+//
+// Normalization only changes the representation, not the value, but
+// may make the mantissa exact, or bring a small exponent within the
+// exponent limits, so `100e-24` can use the fast path as `1e-22`.
+// Factors are only moved when the fast path would otherwise fail,
+// since the disguised fast path already shifts large exponents back
+// into the mantissa.
+perftools_inline!{
+fn normalize_mantissa<F: FloatType>(mantissa: u64, radix: u32, exponent: i32)
+    -> (u64, i32)
+{
+    let (min_exp, _) = F::exponent_limit(radix);
+    if mantissa == 0 || (is_exact_mantissa::<F>(mantissa) && exponent >= min_exp) {
+        return (mantissa, exponent);
+    }
+
+    if radix == 10 {
+        return normalize_decimal_mantissa(mantissa, exponent);
+    }
+
+    debug_assert!(radix >= 2);
+    let radix = radix.as_u64();
+    let mut mantissa = mantissa;
+    let mut exponent = exponent;
+    while mantissa % radix == 0 {
+        mantissa /= radix;
+        exponent = exponent.saturating_add(1);
+    }
+    (mantissa, exponent)
+}}
+
+// Move trailing factors of 10 from a non-zero mantissa to the exponent.
+//
+// The power of 10 dividing the mantissa is the lesser of the powers of
+// 2 and 5 dividing it. The power of 2 is the number of trailing zero
+// bits, and the power of 5 (at most 27 for a `u64`) is found by testing
+// division by `5^16`, `5^8`, `5^4`, `5^2` and `5`, limited by the power
+// of 2, rather than with a division for every trailing zero digit.
+perftools_inline!{
+fn normalize_decimal_mantissa(mantissa: u64, exponent: i32)
+    -> (u64, i32)
+{
+    debug_assert!(mantissa != 0);
+    const POWERS: [(u32, u64); 5] = [(16, 152587890625), (8, 390625), (4, 625), (2, 25), (1, 5)];
+
+    let twos = mantissa.trailing_zeros();
+    let mut quotient = mantissa;
+    let mut fives = 0;
+    for &(step, power) in POWERS.iter() {
+        if fives + step <= twos && quotient % power == 0 {
+            quotient /= power;
+            fives += step;
+        }
+    }
+    (quotient >> fives, exponent.saturating_add(fives as i32))
+}}
+
 /// Convert mantissa to exact value for a non-base2 power.
 ///
 /// Returns the resulting float and if the value can be represented exactly.
 fn fast_path<F>(mantissa: u64, radix: u32, exponent: i32)
     -> Option<F>
     where F: FloatType
+{
+    fast_path_tagged(mantissa, radix, exponent).0
+}
+
+/// Convert mantissa to exact value for a non-base2 power.
+///
+/// Returns the resulting float if the value can be represented exactly,
+/// and the tag for the sub-case taken.
+fn fast_path_tagged<F>(mantissa: u64, radix: u32, exponent: i32)
+    -> (Option<F>, PathTag)
+    where F: FloatType
 {
     debug_assert_radix!(radix);
     debug_assert!(pow2_exponent(radix) == 0, "Cannot use `fast_path` with a power of 2.");
 
-    // `mantissa >> (F::MANTISSA_SIZE+1) != 0` effectively checks if the
-    // value has a no bits above the hidden bit, which is what we want.
+    let (mantissa, exponent) = normalize_mantissa::<F>(mantissa, radix, exponent);
+
+    // The mantissa must be exactly representable, however, it may have
+    // bits above the hidden bit if the low bits are zero. The result is
+    // then a single, correctly-rounded operation on exact values.
+    // `value >> (F::MANTISSA_SIZE+1) != 0` effectively checks if the
+    // value has a no bits above the hidden bit, which is what we want
+    // for the disguised fast-path.
     let (min_exp, max_exp) = F::exponent_limit(radix);
     let shift_exp = F::mantissa_limit(radix);
     let mantissa_size = F::MANTISSA_SIZE + 1;
-    if mantissa >> mantissa_size != 0 {
+    if !is_exact_mantissa::<F>(mantissa) {
         // Would require truncation of the mantissa.
-        None
+        (None, path_tag!(InexactMantissa))
     } else if exponent == 0 {
         // 0 exponent, same as value, exact representation.
         let float: F = as_cast(mantissa);
-        Some(float)
+        (Some(float), path_tag!(ZeroExponent))
     } else if exponent >= min_exp && exponent <= max_exp {
         // Value can be exactly represented, return the value.
         // Use powi, since it's correct, and faster on
         // the fast-path.
         let float: F = as_cast(mantissa);
-        Some(float.pow(radix, exponent))
+        (Some(float.pow(radix, exponent)), path_tag!(InRange))
     } else if exponent >= 0 && exponent <= max_exp + shift_exp {
         // Check to see if we have a disguised fast-path, where the
         // number of digits in the mantissa is very small, but and
@@ -73,60 +299,51 @@ fn fast_path<F>(mantissa: u64, radix: u32, exponent: i32)
         // Compute the product of the power, if it overflows,
         // prematurely return early, otherwise, if we didn't overshoot,
         // we can get an exact value.
-        let value = mantissa.checked_mul(power)?;
-        if value >> mantissa_size != 0 {
-            None
-        } else {
-            // Use powi, since it's correct, and faster on
-            // the fast-path.
-            let float: F = as_cast(value);
-            Some(float.pow(radix, max_exp))
+        match mantissa.checked_mul(power) {
+            Some(value) if value >> mantissa_size == 0 => {
+                // Use powi, since it's correct, and faster on
+                // the fast-path.
+                let float: F = as_cast(value);
+                (Some(float.pow(radix, max_exp)), path_tag!(Disguised))
+            },
+            _ => (None, path_tag!(DisguisedInexact)),
         }
     } else {
         // Cannot be exactly represented, exponent too small or too big,
         // would require truncation.
-        None
+        (None, path_tag!(OutOfRange))
     }
 }
 
-// POW2
-
-// Detect if a float representation is exactly halfway after truncation.
-#[cfg(feature = "radix")]
-perftools_inline!{
-fn is_halfway<F: FloatType>(mantissa: u64)
-    -> bool
-{
-    // Get the leading and trailing zeros from the least-significant bit.
-    let bit_length: i32 = 64 - mantissa.leading_zeros().as_i32();
-    let trailing_zeros: i32 = mantissa.trailing_zeros().as_i32();
-
-    // We need exactly mantissa+2 elements between these if it is halfway.
-    // The hidden bit is mantissa+1 elements away, which is the last non-
-    // truncated bit, while mantissa+2
-    bit_length - trailing_zeros == F::MANTISSA_SIZE + 2
-}}
-
-// Detect if a float representation is odd after truncation.
-#[cfg(feature = "radix")]
+// Detect if the fast path can calculate the float, without calculating it.
+//
+// Must exactly match the conditions in `fast_path`.
 perftools_inline!{
-fn is_odd<F: FloatType>(mantissa: u64)
+fn is_fast_path_mantissa<F: FloatType>(mantissa: u64, radix: u32, exponent: i32)
     -> bool
 {
-    // Get the leading and trailing zeros from the least-significant bit.
-    let bit_length: i32 = 64 - mantissa.leading_zeros().as_i32();
-    let shift = bit_length - (F::MANTISSA_SIZE + 1);
-    if shift >= 0 {
-        // Have enough bits to have a full mantissa in the float, need to
-        // check if that last bit is set.
-        let mask = 1u64 << shift;
-        mantissa & mask == mask
+    let (mantissa, exponent) = normalize_mantissa::<F>(mantissa, radix, exponent);
+    let (min_exp, max_exp) = F::exponent_limit(radix);
+    let shift_exp = F::mantissa_limit(radix);
+    if !is_exact_mantissa::<F>(mantissa) {
+        false
+    } else if exponent >= min_exp && exponent <= max_exp {
+        true
+    } else if exponent >= 0 && exponent <= max_exp + shift_exp {
+        // Disguised fast-path, check the shifted mantissa is exact.
+        let small_powers = get_small_powers_64(radix);
+        let power = small_powers[(exponent - max_exp).as_usize()];
+        match mantissa.checked_mul(power) {
+            Some(value) => value >> (F::MANTISSA_SIZE + 1) == 0,
+            None        => false,
+        }
     } else {
-        // Not enough bits for a full mantissa, must be even.
         false
     }
 }}
 
+// POW2
+
 /// Convert power-of-two to exact value.
 ///
 /// We will always get an exact representation.
@@ -138,6 +355,15 @@ fn is_odd<F: FloatType>(mantissa: u64)
 fn pow2_fast_path<F>(mantissa: u64, radix: u32, pow2_exp: i32, exponent: i32)
     -> F
     where F: FloatType
+{
+    pow2_fast_path_tagged(mantissa, radix, pow2_exp, exponent).0
+}
+
+/// Convert power-of-two to exact value, with the tag for the sub-case taken.
+#[cfg(feature = "radix")]
+fn pow2_fast_path_tagged<F>(mantissa: u64, radix: u32, pow2_exp: i32, exponent: i32)
+    -> (F, PathTag)
+    where F: FloatType
 {
     debug_assert!(pow2_exp != 0, "Not a power of 2.");
 
@@ -152,9 +378,9 @@ fn pow2_fast_path<F>(mantissa: u64, radix: u32, pow2_exp: i32, exponent: i32)
     let (min_exp, max_exp) = F::exponent_limit(radix);
     let underflow_exp = min_exp - (65 / pow2_exp);
     if exponent > max_exp {
-        F::INFINITY
+        (F::INFINITY, path_tag!(Pow2Infinity))
     } else if exponent < underflow_exp{
-        F::ZERO
+        (F::ZERO, path_tag!(Pow2Zero))
     } else if exponent < min_exp {
         // We know the mantissa is somewhere <= 65 below min_exp.
         // May still underflow, but it's close. Use the first multiplication
@@ -163,11 +389,11 @@ fn pow2_fast_path<F>(mantissa: u64, radix: u32, pow2_exp: i32, exponent: i32)
         let remainder = exponent - min_exp;
         let float: F = as_cast(mantissa);
         let float = float.pow2(pow2_exp * remainder).pow2(pow2_exp * min_exp);
-        float
+        (float, path_tag!(Pow2Denormal))
     } else {
         let float: F = as_cast(mantissa);
         let float = float.pow2(pow2_exp * exponent);
-        float
+        (float, path_tag!(Pow2InRange))
     }
 }
 
@@ -193,10 +419,12 @@ fn multiply_exponent_extended<F, M>(fp: &mut ExtendedFloat<M>, radix: u32, expon
         // Guaranteed underflow (assign 0).
         fp.mant = M::ZERO;
         true
-    } else if large_index as usize >= powers.large.len() {
+    } else if large_index as usize >= powers.large_len() {
         // Overflow (assign infinity)
-        fp.mant = M::ONE << 63;
-        fp.exp = 0x7FF;
+        // Use the smallest normalized value that rounds to `F::MAX_EXPONENT`,
+        // which is always exported as infinity, regardless of `F` and `M`.
+        fp.mant = M::NORMALIZED_MASK;
+        fp.exp = F::MAX_EXPONENT + F::MANTISSA_SIZE - (M::FULL - 1);
         true
     } else {
         // Within the valid exponent range, multiply by the large and small
@@ -244,6 +472,9 @@ fn multiply_exponent_extended<F, M>(fp: &mut ExtendedFloat<M>, radix: u32, expon
 //
 // Return the float approximation and if the value can be accurately
 // represented with mantissa bits of precision.
+//
+// A zero mantissa is exactly zero, and cannot be normalized, so it is
+// returned early, like an underflowing value.
 perftools_inline_always!{
 pub(super) fn moderate_path<F, M>(mantissa: M, radix: u32, exponent: i32, truncated: bool, kind: RoundingKind)
     -> (ExtendedFloat<M>, bool)
@@ -251,6 +482,9 @@ pub(super) fn moderate_path<F, M>(mantissa: M, radix: u32, exponent: i32, trunca
           F: FloatRounding<M> + StablePower,
           ExtendedFloat<M>: ModeratePathCache<M>
 {
+    if mantissa.is_zero() {
+        return (ExtendedFloat { mant: mantissa, exp: 0 }, true);
+    }
     let mut fp = ExtendedFloat { mant: mantissa, exp: 0 };
     let valid = multiply_exponent_extended::<F, M>(&mut fp, radix, exponent, truncated, kind);
     (fp, valid)
@@ -262,46 +496,126 @@ pub(super) fn moderate_path<F, M>(mantissa: M, radix: u32, exponent: i32, trunca
 // POWN
 
 /// Fallback method. Do not inline so the stack requirements only occur
-/// if required.
-fn pown_fallback<'a, F, Data>(data: Data, mantissa: u64, radix: u32, lossy: bool, sign: Sign)
+/// if required, and the fast path remains small.
+#[inline(never)]
+fn pown_fallback<'a, F, Data>(data: Data, mantissa: u64, radix: u32, lossy: bool, sign: Sign, truncated: Truncated)
     -> F
     where F: FloatType,
           Data: SlowDataInterface<'a>
 {
     let kind = global_rounding(sign);
+    match pown_moderate_path::<F>(mantissa, radix, data.mantissa_exponent(), lossy, truncated, kind) {
+        Ok(float) => float,
+        Err(fp)   => pown_slow_path(data, radix, fp, kind),
+    }
+}
+
+// Try the Eisel-Lemire algorithm and the moderate path, returning the
+// extended float for the slow path if neither is accurate.
+perftools_inline!{
+fn pown_moderate_path<F>(mantissa: u64, radix: u32, exponent: i32, lossy: bool, truncated: Truncated, kind: RoundingKind)
+    -> StdResult<F, ExtendedFloat<u64>>
+    where F: FloatType
+{
+    // Eisel-Lemire algorithm (use a 128-bit product with a power of 5).
+    let is_truncated = truncated != Truncated::Zero;
+    if radix == 10 && kind == RoundingKind::NearestTieEven {
+        if let Some(float) = eisel_lemire_truncated::<F>(mantissa, exponent, is_truncated) {
+            return Ok(float);
+        }
+    }
 
     // Moderate path (use an extended 80-bit representation).
-    let exponent = data.mantissa_exponent();
-    let is_truncated = data.truncated_digits() != 0;
+    // Round the mantissa to nearest, so the truncated digits add at most
+    // half a unit of error. If the mantissa would overflow, it is off by
+    // up to a full unit, so the result is not known to be accurate.
+    let (mantissa, is_rounded) = match truncated {
+        Truncated::Half | Truncated::AboveHalf => match mantissa.checked_add(1) {
+            Some(mantissa) => (mantissa, true),
+            None           => (mantissa, false),
+        },
+        _ => (mantissa, true),
+    };
     let (fp, valid) = moderate_path::<F, _>(mantissa, radix, exponent, is_truncated, kind);
-    if valid || lossy {
-        let float = fp.into_rounded_float_impl::<F>(kind);
-        return float;
+    if (valid && is_rounded) || lossy {
+        Ok(fp.into_rounded_float_impl::<F>(kind))
+    } else {
+        Err(fp)
     }
+}}
 
-    // Slow path
+/// Slow path, using arbitrary-precision arithmetic. This is rarely
+/// required, so it is cold and never inlined into the moderate path.
+#[cold]
+#[inline(never)]
+fn pown_slow_path<'a, F, Data>(data: Data, radix: u32, fp: ExtendedFloat<u64>, kind: RoundingKind)
+    -> F
+    where F: FloatType,
+          Data: SlowDataInterface<'a>
+{
     let b = fp.into_rounded_float_impl::<F>(RoundingKind::Downward);
     if b.is_special() {
         // We have a non-finite number, we get to leave early.
-        return b;
+        b
     } else {
-        let float = bhcomp::atof(data, radix, b, kind);
-        return float;
+        bhcomp::atof(data, radix, b, kind)
+    }
+}
+
+/// Truncated mantissa. Do not inline, since truncation is uncommon, and
+/// the fast path remains small.
+///
+/// If the truncated digits are all zero, the mantissa is exact, and we
+/// can still try the fast path.
+#[inline(never)]
+fn pown_truncated<'a, F, Data>(data: Data, mantissa: u64, radix: u32, lossy: bool, sign: Sign, truncated: usize)
+    -> F
+    where F: FloatType,
+          Data: FastDataInterface<'a>
+{
+    let slow = data.to_slow(truncated);
+    let truncated = truncated_summary(&slow, radix);
+    let fast = match truncated {
+        Truncated::Zero => fast_path::<F>(mantissa, radix, slow.mantissa_exponent()),
+        _               => None,
+    };
+    match fast {
+        Some(float) => float,
+        None        => pown_fallback(slow, mantissa, radix, lossy, sign, truncated),
     }
 }
 
+// Divide the extracted float by `radix^scale`, by shifting the exponent.
+perftools_inline!{
+fn scale_exponent<'a, Data>(data: &mut Data, scale: i64)
+    where Data: FastDataInterface<'a>
+{
+    let exponent = data.raw_exponent().saturating_sub(scale);
+    data.set_raw_exponent(exponent);
+}}
+
 /// Parse non-power-of-two radix string to native float.
-fn pown_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, lossy: bool, sign: Sign)
+fn pown_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, lossy: bool, sign: Sign, scale: i64)
     -> ParseResult<(F, *const u8)>
     where F: FloatType,
           Data: FastDataInterface<'a>
 {
     // Parse the mantissa and exponent.
     let ptr = data.extract(bytes, radix)?;
+    scale_exponent(&mut data, scale);
     let (mantissa, truncated) = process_mantissa::<u64, _>(&data, radix);
+    let float = pown_mantissa_to_native(data, mantissa, truncated, radix, lossy, sign);
+    Ok((float, ptr))
+}
 
+/// Process the extracted mantissa of a non-power-of-two radix string to native float.
+fn pown_mantissa_to_native<'a, F, Data>(data: Data, mantissa: u64, truncated: usize, radix: u32, lossy: bool, sign: Sign)
+    -> F
+    where F: FloatType,
+          Data: FastDataInterface<'a>
+{
     // Process the state to a float.
-    let float = if mantissa.is_zero() {
+    if mantissa.is_zero() {
         // Literal 0, return early.
         // Value cannot be truncated, since truncation only occurs on
         // overflow or underflow.
@@ -313,59 +627,48 @@ fn pown_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, loss
             float
         } else {
             let slow = data.to_slow(truncated);
-            pown_fallback(slow, mantissa, radix, lossy, sign)
+            pown_fallback(slow, mantissa, radix, lossy, sign, Truncated::Zero)
         }
     } else {
-        // Can only use the moderate/slow path.
-        let slow = data.to_slow(truncated);
-        pown_fallback(slow, mantissa, radix, lossy, sign)
-    };
-    Ok((float, ptr))
+        // Truncated mantissa.
+        pown_truncated(data, mantissa, radix, lossy, sign, truncated)
+    }
 }
 
 // POW2
 
 /// Parse power-of-two radix string to native float.
 #[cfg(feature = "radix")]
-fn pow2_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, pow2_exp: i32, sign: Sign)
+fn pow2_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, pow2_exp: i32, sign: Sign, scale: i64)
     -> ParseResult<(F, *const u8)>
     where F: FloatType,
           Data: FastDataInterface<'a>
 {
     // Parse the mantissa and exponent.
     let ptr = data.extract(bytes, radix)?;
+    scale_exponent(&mut data, scale);
     let (mut mantissa, truncated) = process_mantissa::<u64, _>(&data, radix);
 
-    // We have a power of 2, can get an exact value even if the mantissa
-    // was truncated. Check to see if there are any truncated digits, depending
-    // on our rounding scheme.
+    // We have a power of 2, so the value is exact if the mantissa was not
+    // truncated. Otherwise, the truncated digits only affect rounding.
     let mantissa_size = F::MANTISSA_SIZE + 1;
-    let float = if !truncated.is_zero() {
-        // Truncated mantissa.
+    let float = if mantissa.is_zero() {
+        // Literal 0, return early.
+        // Value cannot be truncated, since truncation only occurs on
+        // overflow or underflow, and the exponent cannot affect the value.
+        F::ZERO
+    } else if !truncated.is_zero() {
+        // Truncated mantissa. The mantissa has at least `64 - log2(radix)`
+        // significant bits, well below the rounding position of any
+        // float, including denormal floats, so only whether the truncated
+        // digits are zero affects rounding. Non-zero truncated digits are
+        // stored in the lowest bit of the mantissa, which rounds correctly
+        // for every rounding kind.
         let kind = global_rounding(sign);
         let slow = data.to_slow(truncated);
-        if kind != RoundingKind::Downward {
-            if cfg!(feature = "rounding") || kind == RoundingKind::NearestTieEven {
-                // Need to check if we're exactly halfway and if there are truncated digits.
-                if is_halfway::<F>(mantissa) && is_odd::<F>(mantissa) {
-                    mantissa += 1;
-                }
-            } else if kind == RoundingKind::NearestTieAwayZero {
-                // Need to check if we're exactly halfway and if there are truncated digits.
-                if is_halfway::<F>(mantissa) {
-                    mantissa += 1;
-                }
-            } else {
-                // Need to check if there are any bytes present.
-                // Check if there were any truncated bytes.
-                let index = slow.mantissa_digits() - slow.truncated_digits();
-                let iter = slow.integer_iter().chain(slow.fraction_iter()).skip(index);
-                let count = iter.take_while(|&&c| c == b'0').count();
-                let is_truncated = count < slow.truncated_digits();
-                if is_truncated {
-                    mantissa += 1;
-                }
-            }
+        match truncated_summary(&slow, radix) {
+            Truncated::Zero => (),
+            _               => mantissa |= 1,
         }
 
         // Create exact representation and return.
@@ -400,25 +703,256 @@ fn pow2_exponent(radix: u32) -> i32 {
     }
 }}
 
+// PARSE COST
+
+// Check if the Eisel-Lemire algorithm returns zero or infinity early,
+// so a truncated mantissa never requires the slow path.
+perftools_inline!{
+fn is_exponent_out_of_range<F: FloatType>(radix: u32, exponent: i32) -> bool {
+    radix == 10
+        && global_rounding(Sign::Positive) == RoundingKind::NearestTieEven
+        && (exponent < F::SMALLEST_POWER_OF_TEN || exponent > F::LARGEST_POWER_OF_TEN)
+}}
+
+/// Classify the algorithm a non-power-of-two radix string is parsed with.
+///
+/// The slow path is only required if non-zero digits were truncated,
+/// since otherwise the moderate path is accurate for all but a few
+/// near-halfway values.
+fn pown_parse_cost<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32)
+    -> ParseResult<(ParseCost, *const u8)>
+    where F: FloatType,
+          Data: FastDataInterface<'a>
+{
+    let ptr = data.extract(bytes, radix)?;
+    let (mantissa, truncated) = process_mantissa::<u64, _>(&data, radix);
+    if mantissa.is_zero() {
+        return Ok((ParseCost::Fast, ptr));
+    }
+
+    let (exponent, is_truncated) = if truncated.is_zero() {
+        (data.mantissa_exponent(0), false)
+    } else {
+        let slow = data.to_slow(truncated);
+        (slow.mantissa_exponent(), is_truncated_nonzero(&slow))
+    };
+    let cost = if !is_truncated && is_fast_path_mantissa::<F>(mantissa, radix, exponent) {
+        ParseCost::Fast
+    } else if !is_truncated || is_exponent_out_of_range::<F>(radix, exponent) {
+        ParseCost::Moderate
+    } else {
+        ParseCost::Slow
+    };
+    Ok((cost, ptr))
+}
+
+/// Classify the algorithm a power-of-two radix string is parsed with.
+///
+/// The value is always exact or rounded with a sticky bit, so the
+/// slow path is never required.
+#[cfg(feature = "radix")]
+fn pow2_parse_cost<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32)
+    -> ParseResult<(ParseCost, *const u8)>
+    where F: FloatType,
+          Data: FastDataInterface<'a>
+{
+    let ptr = data.extract(bytes, radix)?;
+    let (mantissa, truncated) = process_mantissa::<u64, _>(&data, radix);
+    let mantissa_size = F::MANTISSA_SIZE + 1;
+    let cost = match mantissa.is_zero() || (truncated.is_zero() && mantissa >> mantissa_size == 0) {
+        true  => ParseCost::Fast,
+        false => ParseCost::Moderate,
+    };
+    Ok((cost, ptr))
+}
+
+// ROUNDING ERROR
+
+/// Calculate the rounding error of the float parsed from the string.
+///
+/// The float string must be non-special and unsigned, and the float
+/// must be positive.
+fn to_rounding_error<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, float: F)
+    -> ParseResult<(f64, *const u8)>
+    where F: FloatType,
+          Data: FastDataInterface<'a>
+{
+    let ptr = data.extract(bytes, radix)?;
+    let (mantissa, truncated) = process_mantissa::<u64, _>(&data, radix);
+    let error = match mantissa.is_zero() {
+        // Literal 0, always exact.
+        true  => 0.0,
+        false => bhcomp::rounding_error(data.to_slow(truncated), radix, float),
+    };
+    Ok((error, ptr))
+}
+
+// SCRATCH
+
+/// Slow path, using arbitrary-precision arithmetic in the scratch limbs.
+#[cold]
+#[inline(never)]
+fn decimal_slow_path_scratch<'a, F, Data>(data: Data, fp: ExtendedFloat<u64>, kind: RoundingKind, scratch: &mut [u64])
+    -> StdResult<F, usize>
+    where F: FloatType,
+          Data: SlowDataInterface<'a>
+{
+    let b = fp.into_rounded_float_impl::<F>(RoundingKind::Downward);
+    if b.is_special() {
+        // We have a non-finite number, we get to leave early.
+        Ok(b)
+    } else {
+        scratch::atof(data, b, kind, scratch)
+    }
+}
+
+/// Parse decimal string to native float, using the scratch limbs for
+/// the slow path.
+///
+/// Returns the number of limbs required if the slow path is required,
+/// and the scratch limbs are too small.
+fn decimal_to_native_scratch<'a, F, Data>(mut data: Data, bytes: &'a [u8], sign: Sign, scratch: &mut [u64])
+    -> ParseResult<(StdResult<F, usize>, *const u8)>
+    where F: FloatType,
+          Data: FastDataInterface<'a>
+{
+    let radix = 10;
+    let ptr = data.extract(bytes, radix)?;
+    let (mantissa, truncated) = process_mantissa::<u64, _>(&data, radix);
+    if mantissa.is_zero() {
+        // Literal 0, return early.
+        return Ok((Ok(F::ZERO), ptr));
+    }
+
+    let slow = data.to_slow(truncated);
+    let truncated = match truncated.is_zero() {
+        true  => Truncated::Zero,
+        false => truncated_summary(&slow, radix),
+    };
+    let exponent = slow.mantissa_exponent();
+    let fast = match truncated {
+        Truncated::Zero => fast_path::<F>(mantissa, radix, exponent),
+        _               => None,
+    };
+    let float = match fast {
+        Some(float) => Ok(float),
+        None        => {
+            let kind = global_rounding(sign);
+            match pown_moderate_path::<F>(mantissa, radix, exponent, false, truncated, kind) {
+                Ok(float) => Ok(float),
+                Err(fp)   => decimal_slow_path_scratch(slow, fp, kind, scratch),
+            }
+        },
+    };
+    Ok((float, ptr))
+}
+
+/// Parse decimal string to both native floats.
+///
+/// The digits are extracted and the mantissa is processed once, and
+/// only the conversion to each float is repeated, so both floats are
+/// correctly rounded from the string.
+fn decimal_to_native_both<'a, Data>(mut data: Data, bytes: &'a [u8], sign: Sign)
+    -> ParseResult<((f32, f64), *const u8)>
+    where Data: FastDataInterface<'a> + Clone
+{
+    let radix = 10;
+    let ptr = data.extract(bytes, radix)?;
+    let (mantissa, truncated) = process_mantissa::<u64, _>(&data, radix);
+    let float = pown_mantissa_to_native(data.clone(), mantissa, truncated, radix, false, sign);
+    let double = pown_mantissa_to_native(data, mantissa, truncated, radix, false, sign);
+    Ok(((float, double), ptr))
+}
+
 // DISPATCHER
 
+// Classify the algorithm the float string is parsed with.
+//
+// The float string must be non-special and unsigned.
+perftools_inline!{
+fn parse_cost<F>(bytes: &[u8], radix: u32, format: NumberFormat)
+    -> ParseResult<(ParseCost, *const u8)>
+    where F: FloatType
+{
+    #[cfg(not(feature = "radix"))] {
+        apply_interface!(pown_parse_cost::<F, _>, format, bytes, radix)
+    }
+
+    #[cfg(feature = "radix")] {
+        match pow2_exponent(radix) {
+            0 => apply_interface!(pown_parse_cost::<F, _>, format, bytes, radix),
+            _ => apply_interface!(pow2_parse_cost::<F, _>, format, bytes, radix)
+        }
+    }
+}}
+
+// Detect if the float string is parsed on the fast path.
+//
+// The float string must be non-special and unsigned.
+perftools_inline!{
+fn is_fast_path<F>(bytes: &[u8], radix: u32, format: NumberFormat)
+    -> ParseResult<(bool, *const u8)>
+    where F: FloatType
+{
+    let (cost, ptr) = parse_cost::<F>(bytes, radix, format)?;
+    Ok((cost == ParseCost::Fast, ptr))
+}}
+
+// Calculate the rounding error of the float parsed from the string.
+//
+// The float string must be non-special and unsigned.
+perftools_inline!{
+fn rounding_error<F>(bytes: &[u8], radix: u32, format: NumberFormat, float: F)
+    -> ParseResult<(f64, *const u8)>
+    where F: FloatType
+{
+    apply_interface!(to_rounding_error::<F, _>, format, bytes, radix, float)
+}}
+
+// Parse native float from a decimal string, using the scratch limbs
+// for the slow path.
+//
+// The float string must be non-special and unsigned.
+perftools_inline!{
+fn to_native_scratch<F>(bytes: &[u8], sign: Sign, format: NumberFormat, scratch: &mut [u64])
+    -> ParseResult<(StdResult<F, usize>, *const u8)>
+    where F: FloatType
+{
+    apply_interface!(decimal_to_native_scratch, format, bytes, sign, scratch)
+}}
+
+// Parse both native floats from a decimal string.
+//
+// The float string must be non-special and unsigned.
+perftools_inline!{
+fn to_native_both(bytes: &[u8], sign: Sign, format: NumberFormat)
+    -> ParseResult<((f32, f64), *const u8)>
+{
+    apply_interface!(decimal_to_native_both, format, bytes, sign)
+}}
+
 // Parse native float from string.
 //
 // The float string must be non-special, non-zero, and positive.
+// Every path returns the pointer from `extract`, so the exponent is
+// consumed even if the mantissa is zero. A mantissa without any digits,
+// such as `"e5"`, fails validation in `extract` with `EmptyMantissa`,
+// so the zero short-circuit never sees a bare exponent. The value is divided by
+// `radix^scale`, exactly, before rounding.
 perftools_inline!{
-fn to_native<F>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+fn to_native<F>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, scale: i64)
     -> ParseResult<(F, *const u8)>
     where F: FloatType
 {
     #[cfg(not(feature = "radix"))] {
-        apply_interface!(pown_to_native, format, bytes, radix,  lossy, sign)
+        apply_interface!(pown_to_native, format, bytes, radix,  lossy, sign, scale)
     }
 
     #[cfg(feature = "radix")] {
         let pow2_exp = pow2_exponent(radix);
         match pow2_exp {
-            0 => apply_interface!(pown_to_native, format, bytes, radix, lossy, sign),
-            _ => apply_interface!(pow2_to_native, format, bytes, radix, pow2_exp, sign)
+            0 => apply_interface!(pown_to_native, format, bytes, radix, lossy, sign, scale),
+            _ => apply_interface!(pow2_to_native, format, bytes, radix, pow2_exp, sign, scale)
         }
     }
 }}
@@ -431,7 +965,15 @@ perftools_inline!{
 pub(crate) fn atof(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
     -> ParseResult<(f32, *const u8)>
 {
-    to_native::<f32>(bytes, radix, lossy, sign, format)
+    to_native::<f32>(bytes, radix, lossy, sign, format, 0)
+}}
+
+// Parse 32-bit float from string, divided by `radix^scale`.
+perftools_inline!{
+pub(crate) fn atof_scaled(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, scale: i64)
+    -> ParseResult<(f32, *const u8)>
+{
+    to_native::<f32>(bytes, radix, lossy, sign, format, scale)
 }}
 
 // Parse 64-bit float from string.
@@ -439,7 +981,79 @@ perftools_inline!{
 pub(crate) fn atod(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
     -> ParseResult<(f64, *const u8)>
 {
-    to_native::<f64>(bytes, radix, lossy, sign, format)
+    to_native::<f64>(bytes, radix, lossy, sign, format, 0)
+}}
+
+// Parse 64-bit float from string, divided by `radix^scale`.
+perftools_inline!{
+pub(crate) fn atod_scaled(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, scale: i64)
+    -> ParseResult<(f64, *const u8)>
+{
+    to_native::<f64>(bytes, radix, lossy, sign, format, scale)
+}}
+
+// Parse 32-bit float from decimal string, using the scratch limbs for the slow path.
+perftools_inline!{
+pub(crate) fn atof_scratch(bytes: &[u8], sign: Sign, format: NumberFormat, scratch: &mut [u64])
+    -> ParseResult<(StdResult<f32, usize>, *const u8)>
+{
+    to_native_scratch::<f32>(bytes, sign, format, scratch)
+}}
+
+// Parse 64-bit float from decimal string, using the scratch limbs for the slow path.
+perftools_inline!{
+pub(crate) fn atod_scratch(bytes: &[u8], sign: Sign, format: NumberFormat, scratch: &mut [u64])
+    -> ParseResult<(StdResult<f64, usize>, *const u8)>
+{
+    to_native_scratch::<f64>(bytes, sign, format, scratch)
+}}
+
+// Parse 32-bit and 64-bit floats from a decimal string.
+perftools_inline!{
+pub(crate) fn atofd_both(bytes: &[u8], sign: Sign, format: NumberFormat)
+    -> ParseResult<((f32, f64), *const u8)>
+{
+    to_native_both(bytes, sign, format)
+}}
+
+// Detect if the 32-bit float string is parsed on the fast path.
+perftools_inline!{
+pub(crate) fn atof_is_fast_path(bytes: &[u8], radix: u32, format: NumberFormat)
+    -> ParseResult<(bool, *const u8)>
+{
+    is_fast_path::<f32>(bytes, radix, format)
+}}
+
+// Detect if the 64-bit float string is parsed on the fast path.
+perftools_inline!{
+pub(crate) fn atod_is_fast_path(bytes: &[u8], radix: u32, format: NumberFormat)
+    -> ParseResult<(bool, *const u8)>
+{
+    is_fast_path::<f64>(bytes, radix, format)
+}}
+
+// Classify the algorithm the 64-bit float string is parsed with.
+perftools_inline!{
+pub(crate) fn atod_parse_cost(bytes: &[u8], radix: u32, format: NumberFormat)
+    -> ParseResult<(ParseCost, *const u8)>
+{
+    parse_cost::<f64>(bytes, radix, format)
+}}
+
+// Calculate the rounding error of the 32-bit float parsed from the string.
+perftools_inline!{
+pub(crate) fn atof_rounding_error(bytes: &[u8], radix: u32, format: NumberFormat, float: f32)
+    -> ParseResult<(f64, *const u8)>
+{
+    rounding_error::<f32>(bytes, radix, format, float)
+}}
+
+// Calculate the rounding error of the 64-bit float parsed from the string.
+perftools_inline!{
+pub(crate) fn atod_rounding_error(bytes: &[u8], radix: u32, format: NumberFormat, float: f64)
+    -> ParseResult<(f64, *const u8)>
+{
+    rounding_error::<f64>(bytes, radix, format, float)
 }}
 
 // TESTS
@@ -498,82 +1112,6 @@ mod tests {
         assert_eq!((100000000000000000001, 0), process_mantissa::<u128, Data>(&data, 10));
     }
 
-    #[cfg(feature = "radix")]
-    #[test]
-    fn is_odd_test() {
-        // Variant of b1000000000000000000000001, a halfway value for f32.
-        assert!(is_odd::<f32>(0x1000002));
-        assert!(is_odd::<f32>(0x2000004));
-        assert!(is_odd::<f32>(0x8000010000000000));
-        assert!(!is_odd::<f64>(0x1000002));
-        assert!(!is_odd::<f64>(0x2000004));
-        assert!(!is_odd::<f64>(0x8000010000000000));
-
-        assert!(!is_odd::<f32>(0x1000001));
-        assert!(!is_odd::<f32>(0x2000002));
-        assert!(!is_odd::<f32>(0x8000008000000000));
-        assert!(!is_odd::<f64>(0x1000001));
-        assert!(!is_odd::<f64>(0x2000002));
-        assert!(!is_odd::<f64>(0x8000008000000000));
-
-        // Variant of b100000000000000000000000000000000000000000000000000001,
-        // a halfway value for f64
-        assert!(!is_odd::<f32>(0x3f000000000002));
-        assert!(!is_odd::<f32>(0x3f000000000003));
-        assert!(!is_odd::<f32>(0xFC00000000000800));
-        assert!(!is_odd::<f32>(0xFC00000000000C00));
-        assert!(is_odd::<f64>(0x3f000000000002));
-        assert!(is_odd::<f64>(0x3f000000000003));
-        assert!(is_odd::<f64>(0xFC00000000000800));
-        assert!(is_odd::<f64>(0xFC00000000000C00));
-
-        assert!(!is_odd::<f32>(0x3f000000000001));
-        assert!(!is_odd::<f32>(0x3f000000000004));
-        assert!(!is_odd::<f32>(0xFC00000000000400));
-        assert!(!is_odd::<f32>(0xFC00000000001000));
-        assert!(!is_odd::<f64>(0x3f000000000001));
-        assert!(!is_odd::<f64>(0x3f000000000004));
-        assert!(!is_odd::<f64>(0xFC00000000000400));
-        assert!(!is_odd::<f64>(0xFC00000000001000));
-    }
-
-    #[cfg(feature = "radix")]
-    #[test]
-    fn is_halfway_test() {
-        // Variant of b1000000000000000000000001, a halfway value for f32.
-        assert!(is_halfway::<f32>(0x1000001));
-        assert!(is_halfway::<f32>(0x2000002));
-        assert!(is_halfway::<f32>(0x8000008000000000));
-        assert!(!is_halfway::<f64>(0x1000001));
-        assert!(!is_halfway::<f64>(0x2000002));
-        assert!(!is_halfway::<f64>(0x8000008000000000));
-
-        // Variant of b10000000000000000000000001, which is 1-off a halfway value.
-        assert!(!is_halfway::<f32>(0x2000001));
-        assert!(!is_halfway::<f64>(0x2000001));
-
-        // Variant of b100000000000000000000000000000000000000000000000000001,
-        // a halfway value for f64
-        assert!(!is_halfway::<f32>(0x20000000000001));
-        assert!(!is_halfway::<f32>(0x40000000000002));
-        assert!(!is_halfway::<f32>(0x8000000000000400));
-        assert!(is_halfway::<f64>(0x20000000000001));
-        assert!(is_halfway::<f64>(0x40000000000002));
-        assert!(is_halfway::<f64>(0x8000000000000400));
-
-        // Variant of b111111000000000000000000000000000000000000000000000001,
-        // a halfway value for f64.
-        assert!(!is_halfway::<f32>(0x3f000000000001));
-        assert!(!is_halfway::<f32>(0xFC00000000000400));
-        assert!(is_halfway::<f64>(0x3f000000000001));
-        assert!(is_halfway::<f64>(0xFC00000000000400));
-
-        // Variant of b1000000000000000000000000000000000000000000000000000001,
-        // which is 1-off a halfway value.
-        assert!(!is_halfway::<f32>(0x40000000000001));
-        assert!(!is_halfway::<f64>(0x40000000000001));
-    }
-
     #[cfg(feature = "radix")]
     #[test]
     fn float_pow2_fast_path() {
@@ -665,7 +1203,9 @@ mod tests {
             assert!(f.is_none(), "invalid mantissa");
         }
 
-        // invalid exponents
+        // invalid exponents, the mantissa must not have factors of the
+        // radix, otherwise, they're moved to the exponent.
+        let mantissa = (1 << f64::MANTISSA_SIZE) - 47;
         for base in BASE_POWN.iter().cloned() {
             let (min_exp, max_exp) = f64::exponent_limit(base);
             let f = fast_path::<f64>(mantissa, base, min_exp-1);
@@ -676,6 +1216,206 @@ mod tests {
         }
     }
 
+    #[test]
+    fn float_fast_path_boundary_test() {
+        // Mantissas at or above the significand boundary with trailing zeros.
+        let boundary: u64 = 1 << (f32::MANTISSA_SIZE + 1);
+        assert_eq!(fast_path::<f32>(boundary, 10, 0), Some(16777216.0));
+        assert_eq!(fast_path::<f32>(boundary, 10, 3), Some(16777216000.0));
+        assert_eq!(fast_path::<f32>(boundary, 10, -3), Some(16777.216));
+        assert_eq!(fast_path::<f32>(boundary + 2, 10, -1), Some(1677721.8));
+        assert_eq!(fast_path::<f32>(boundary << 1, 10, -2), Some(335544.32));
+        assert_eq!(fast_path::<f32>(1 << 63, 10, 0), Some(9223372036854775808.0));
+
+        // Mantissas that would require truncation.
+        assert!(fast_path::<f32>(boundary + 1, 10, 0).is_none());
+        assert!(fast_path::<f32>(boundary + 3, 10, -1).is_none());
+        assert!(fast_path::<f32>((boundary << 1) + 2, 10, 0).is_none());
+        assert!(fast_path::<f32>(u64::max_value(), 10, 0).is_none());
+    }
+
+    #[test]
+    fn fast_path_tagged_test() {
+        let boundary: u64 = 1 << (f64::MANTISSA_SIZE + 1);
+        assert_eq!(fast_path_tagged::<f64>(1, 10, 0), (Some(1.0), FastPath::ZeroExponent));
+        assert_eq!(fast_path_tagged::<f64>(123, 10, 5), (Some(12300000.0), FastPath::InRange));
+        assert_eq!(fast_path_tagged::<f64>(123, 10, -22), (Some(123e-22), FastPath::InRange));
+        assert_eq!(fast_path_tagged::<f64>(1000, 10, -3), (Some(1.0), FastPath::InRange));
+        assert_eq!(fast_path_tagged::<f64>(123, 10, 25), (Some(1.23e27), FastPath::Disguised));
+        assert_eq!(fast_path_tagged::<f64>(boundary - 1, 10, 23), (None, FastPath::DisguisedInexact));
+        assert_eq!(fast_path_tagged::<f64>(123, 10, 38), (None, FastPath::OutOfRange));
+        assert_eq!(fast_path_tagged::<f64>(123, 10, -23), (None, FastPath::OutOfRange));
+        assert_eq!(fast_path_tagged::<f64>(boundary + 1, 10, 0), (None, FastPath::InexactMantissa));
+
+        // Trailing zeros are normalized into the exponent.
+        assert_eq!(fast_path_tagged::<f64>(100, 10, -24), (Some(1e-22), FastPath::InRange));
+        assert_eq!(fast_path_tagged::<f32>((boundary + 1) * 10, 10, 0), (None, FastPath::InexactMantissa));
+        assert_eq!(fast_path_tagged::<f32>(123, 10, 11), (Some(1.23e13), FastPath::Disguised));
+        assert_eq!(fast_path_tagged::<f32>(1 << 24, 10, 11), (None, FastPath::DisguisedInexact));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn pow2_fast_path_tagged_test() {
+        let (min_exp, max_exp) = f64::exponent_limit(2);
+        assert_eq!(pow2_fast_path_tagged::<f64>(1, 2, 1, 0), (1.0, FastPath::Pow2InRange));
+        assert_eq!(pow2_fast_path_tagged::<f64>(1, 2, 1, max_exp), (2f64.powi(max_exp), FastPath::Pow2InRange));
+        assert_eq!(pow2_fast_path_tagged::<f64>(1, 2, 1, max_exp + 1), (f64::INFINITY, FastPath::Pow2Infinity));
+        assert_eq!(pow2_fast_path_tagged::<f64>(1, 2, 1, min_exp - 1), (2f64.powi(min_exp - 1), FastPath::Pow2Denormal));
+        assert_eq!(pow2_fast_path_tagged::<f64>(1, 2, 1, min_exp - 65), (0.0, FastPath::Pow2Denormal));
+        assert_eq!(pow2_fast_path_tagged::<f64>(1, 2, 1, min_exp - 66), (0.0, FastPath::Pow2Zero));
+        assert_eq!(pow2_fast_path_tagged::<f64>(1, 16, 4, min_exp - 17), (0.0, FastPath::Pow2Zero));
+    }
+
+    #[test]
+    fn double_fast_path_boundary_test() {
+        // Mantissas at or above the significand boundary with trailing zeros.
+        let boundary: u64 = 1 << (f64::MANTISSA_SIZE + 1);
+        assert_eq!(fast_path::<f64>(boundary, 10, 0), Some(9007199254740992.0));
+        assert_eq!(fast_path::<f64>(boundary, 10, 5), Some(900719925474099200000.0));
+        assert_eq!(fast_path::<f64>(boundary, 10, -3), Some(9007199254740.992));
+        assert_eq!(fast_path::<f64>(boundary + 2, 10, -1), Some(900719925474099.4));
+        assert_eq!(fast_path::<f64>(boundary << 1, 10, -2), Some(180143985094819.84));
+        assert_eq!(fast_path::<f64>(1 << 63, 10, 0), Some(9223372036854775808.0));
+
+        // Mantissas that would require truncation.
+        assert!(fast_path::<f64>(boundary + 1, 10, 0).is_none());
+        assert!(fast_path::<f64>(boundary + 3, 10, -1).is_none());
+        assert!(fast_path::<f64>((boundary << 1) + 2, 10, 0).is_none());
+        assert!(fast_path::<f64>(u64::max_value(), 10, 0).is_none());
+
+        // The full parser gives identical results on either side of the cutoff.
+        assert_eq!(Ok(9007199254740.992), crate::parse::<f64>(b"9007199254740992e-3"));
+        assert_eq!(Ok(9007199254740.993), crate::parse::<f64>(b"9007199254740993e-3"));
+        assert_eq!(Ok(9007199254740.994), crate::parse::<f64>(b"9007199254740994e-3"));
+        assert_eq!(Ok(18014398509481.984), crate::parse::<f64>(b"18014398509481984e-3"));
+    }
+
+    #[test]
+    fn is_fast_path_mantissa_test() {
+        // Ensure the predicate exactly matches the fast path.
+        let mantissas = [
+            0u64, 1, 5, 9, 10, 123456789, 9007199254740991, 9007199254740992,
+            9007199254740993, 16777215, 16777216, 16777217, 1 << 63, u64::max_value(),
+            1000000, 4503599627370497000, 16777217000
+        ];
+        for &mantissa in mantissas.iter() {
+            for exponent in -60..60 {
+                assert_eq!(is_fast_path_mantissa::<f32>(mantissa, 10, exponent), fast_path::<f32>(mantissa, 10, exponent).is_some());
+                assert_eq!(is_fast_path_mantissa::<f64>(mantissa, 10, exponent), fast_path::<f64>(mantissa, 10, exponent).is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn is_fast_path_test() {
+        let format = NumberFormat::standard().unwrap();
+        let is_fast_path = | bytes: &[u8] | super::is_fast_path::<f64>(bytes, 10, format).map(|r| r.0);
+        assert_eq!(is_fast_path(b"0"), Ok(true));
+        assert_eq!(is_fast_path(b"1.5"), Ok(true));
+        assert_eq!(is_fast_path(b"0.1"), Ok(true));
+        assert_eq!(is_fast_path(b"1e22"), Ok(true));
+        assert_eq!(is_fast_path(b"1e37"), Ok(true));
+        assert_eq!(is_fast_path(b"1e38"), Ok(false));
+        assert_eq!(is_fast_path(b"1e-22"), Ok(true));
+        assert_eq!(is_fast_path(b"1e-23"), Ok(false));
+        assert_eq!(is_fast_path(b"9007199254740992"), Ok(true));
+        assert_eq!(is_fast_path(b"9007199254740993"), Ok(false));
+        assert_eq!(is_fast_path(b"12345678901234567890123"), Ok(false));
+        assert_eq!(is_fast_path(b"100000000000000000000000"), Ok(true));
+        assert_eq!(is_fast_path(b"100000000000000000000001"), Ok(false));
+        assert!(is_fast_path(b"").is_err());
+
+        // Trailing zeros are moved to the exponent.
+        assert_eq!(is_fast_path(b"1000000e-28"), Ok(true));
+        assert_eq!(is_fast_path(b"1000000e-29"), Ok(false));
+        assert_eq!(is_fast_path(b"4503599627370497000"), Ok(true));
+        assert_eq!(is_fast_path(b"4503599627370497000e-25"), Ok(true));
+        assert_eq!(is_fast_path(b"4503599627370497001"), Ok(false));
+    }
+
+    #[test]
+    fn normalize_mantissa_test() {
+        // Only normalize if the fast path would otherwise fail.
+        assert_eq!(normalize_mantissa::<f64>(0, 10, -30), (0, -30));
+        assert_eq!(normalize_mantissa::<f64>(1000000, 10, 0), (1000000, 0));
+        assert_eq!(normalize_mantissa::<f64>(1000000, 10, -28), (1, -22));
+        assert_eq!(normalize_mantissa::<f64>(1000001, 10, -28), (1000001, -28));
+        assert_eq!(normalize_mantissa::<f64>(4503599627370497000, 10, 0), (4503599627370497, 3));
+        assert_eq!(normalize_mantissa::<f32>(16777217000, 10, 0), (16777217, 3));
+
+        // The decimal mantissa matches stripping factors of 10 one at a time.
+        let mantissas = [1, 5, 10, 25, 1250, 390625000, 1220703125, 152587890625000000, 10000000000000000000, u64::max_value()];
+        for &mantissa in mantissas.iter() {
+            let (mut expected, mut exponent) = (mantissa, -30);
+            while expected % 10 == 0 {
+                expected /= 10;
+                exponent += 1;
+            }
+            assert_eq!(normalize_decimal_mantissa(mantissa, -30), (expected, exponent));
+        }
+
+        // Values are unchanged by the exact path.
+        assert_eq!(fast_path::<f64>(1000000, 10, -28), Some(1e-22));
+        assert_eq!(fast_path::<f64>(4503599627370497000, 10, 0), Some(4503599627370497000.0));
+        assert_eq!(fast_path::<f64>(4503599627370497000, 10, -25), Some(4503599627370497e-22));
+        assert_eq!(Ok(4503599627370497e-22), crate::parse::<f64>(b"4503599627370497000e-25"));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn normalize_mantissa_radix_test() {
+        // `1000000` in every non-power-of-two radix is exact, both as an
+        // integer and with the smallest exact exponent.
+        for base in BASE_POWN.iter().cloned() {
+            let mantissa = (base as u64).pow(6);
+            let (min_exp, _) = f64::exponent_limit(base);
+            assert_eq!(normalize_mantissa::<f64>(mantissa, base, min_exp - 6), (1, min_exp));
+            assert!(is_fast_path_mantissa::<f64>(mantissa, base, 0));
+            assert!(is_fast_path_mantissa::<f64>(mantissa, base, min_exp - 6));
+            assert!(!is_fast_path_mantissa::<f64>(mantissa, base, min_exp - 7));
+            assert_eq!(fast_path::<f64>(mantissa, base, min_exp - 6), fast_path::<f64>(1, base, min_exp));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn truncated_digits_test() {
+        // Validate truncated mantissas against the big-integer rounding error.
+        let format = NumberFormat::standard().unwrap();
+        let mantissas = [
+            "10000000000000000000", "9007199254740993", "18014398509481985",
+            "12345678901234567890", "18446744073709551615", "17976931348623157"
+        ];
+        let tails = ["", "0", "0000000", "1", "5", "50", "500000001", "4999999", "9999999"];
+        let exponents = ["", "e-30", "e-5", "e5", "e30", "e-320", "e270"];
+        for mantissa in mantissas.iter() {
+            for tail in tails.iter() {
+                for exponent in exponents.iter() {
+                    let string = format!("{}{}{}", mantissa, tail, exponent);
+                    let bytes = string.as_bytes();
+                    let float = atod(bytes, 10, false, Sign::Positive, format).unwrap().0;
+                    let error = atod_rounding_error(bytes, 10, format, float).unwrap().0;
+                    assert!(error.abs() <= 0.5, "{} {}", string, error);
+                }
+            }
+        }
+
+        // Truncated digits that are all zero are exact.
+        let atod10 = | bytes: &[u8] | atod(bytes, 10, false, Sign::Positive, format).unwrap().0;
+        assert_eq!(1e23, atod10(b"100000000000000000000000"));
+        assert_eq!(9007199254740993e10, atod10(b"90071992547409930000000000"));
+        assert_eq!(1.8446744073709552e23, atod10(b"184467440737095516150000"));
+
+        // Trailing zeros of the integer past the maximum digits for the
+        // slow path, exactly halfway and just above halfway.
+        let zeros = "0".repeat(720);
+        let string = format!("100000000000000011102230246251565404236316680908203125{}e-773", zeros);
+        assert_eq!(1.0, atod10(string.as_bytes()));
+        let string = format!("100000000000000011102230246251565404236316680908203126{}e-773", zeros);
+        assert_eq!(1.0000000000000002, atod10(string.as_bytes()));
+    }
+
     #[cfg(feature = "radix")]
     #[test]
     fn float_moderate_path_test() {
@@ -724,6 +1464,64 @@ mod tests {
         assert!(!valid, "exponent should be valid");
     }
 
+    #[test]
+    fn moderate_path_zero_test() {
+        // Zero is exact for any exponent, and never normalized.
+        for &exponent in [-400, -31, 0, 1, 22, 400].iter() {
+            for &truncated in [false, true].iter() {
+                let (f, valid) = moderate_path::<f32, _>(0u64, 10, exponent, truncated, RoundingKind::NearestTieEven);
+                assert_eq!(f.into_f32().to_bits(), 0);
+                assert!(valid, "zero should be valid");
+
+                let (f, valid) = moderate_path::<f64, _>(0u64, 10, exponent, truncated, RoundingKind::NearestTieEven);
+                assert_eq!(f.into_f64().to_bits(), 0);
+                assert!(valid, "zero should be valid");
+
+                let (f, valid) = moderate_path::<f64, _>(0u128, 3, exponent, truncated, RoundingKind::NearestTieEven);
+                assert_eq!(f.into_f64().to_bits(), 0);
+                assert!(valid, "zero should be valid");
+            }
+        }
+    }
+
+    #[test]
+    fn float_moderate_path_boundary_test() {
+        // overflow
+        let (f, valid) = moderate_path::<f32, u64>(1, 10, i32::max_value(), false, RoundingKind::NearestTieEven);
+        assert!(valid, "exponent should be valid");
+        assert_eq!(f.into_f32().to_bits(), f32::INFINITY_BITS);
+        let (f, valid) = moderate_path::<f32, u128>(1, 10, i32::max_value(), false, RoundingKind::NearestTieEven);
+        assert!(valid, "exponent should be valid");
+        assert_eq!(f.into_f32().to_bits(), f32::INFINITY_BITS);
+
+        // underflow
+        let (f, valid) = moderate_path::<f32, u64>(1, 10, i32::min_value(), false, RoundingKind::NearestTieEven);
+        assert!(valid, "exponent should be valid");
+        assert_eq!(f.into_f32().to_bits(), 0);
+        let (f, valid) = moderate_path::<f32, u128>(1, 10, i32::min_value(), false, RoundingKind::NearestTieEven);
+        assert!(valid, "exponent should be valid");
+        assert_eq!(f.into_f32().to_bits(), 0);
+    }
+
+    #[test]
+    fn double_moderate_path_boundary_test() {
+        // overflow
+        let (f, valid) = moderate_path::<f64, u64>(1, 10, i32::max_value(), false, RoundingKind::NearestTieEven);
+        assert!(valid, "exponent should be valid");
+        assert_eq!(f.into_f64().to_bits(), f64::INFINITY_BITS);
+        let (f, valid) = moderate_path::<f64, u128>(1, 10, i32::max_value(), false, RoundingKind::NearestTieEven);
+        assert!(valid, "exponent should be valid");
+        assert_eq!(f.into_f64().to_bits(), f64::INFINITY_BITS);
+
+        // underflow
+        let (f, valid) = moderate_path::<f64, u64>(1, 10, i32::min_value(), false, RoundingKind::NearestTieEven);
+        assert!(valid, "exponent should be valid");
+        assert_eq!(f.into_f64().to_bits(), 0);
+        let (f, valid) = moderate_path::<f64, u128>(1, 10, i32::min_value(), false, RoundingKind::NearestTieEven);
+        assert!(valid, "exponent should be valid");
+        assert_eq!(f.into_f64().to_bits(), 0);
+    }
+
     #[test]
     fn atof_test() {
         let atof10 = move |x| match atof(x, 10, false, Sign::Positive, NumberFormat::standard().unwrap()) {
@@ -772,8 +1570,8 @@ mod tests {
 
         // Invalid or partially-parsed
         assert_eq!(Err((ErrorCode::EmptyMantissa, 0)), atof10(b"e10"));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0)), atof10(b"."));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0)), atof10(b".e10"));
+        assert_eq!(Err((ErrorCode::LoneDot, 0)), atof10(b"."));
+        assert_eq!(Err((ErrorCode::LoneDot, 0)), atof10(b".e10"));
         assert_eq!(Err((ErrorCode::EmptyExponent, 2)), atof10(b"0e"));
         assert_eq!(Ok((1.23, 4)), atof10(b"1.23/"));
     }
@@ -879,6 +1677,93 @@ mod tests {
         assert_eq!(Ok((38652960461239320000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000.0, 310)), atod10(b"38652960461239320000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000.0"));
     }
 
+    // Inputs historically mis-parsed (or never parsed) by other libraries,
+    // with the bits of the correctly-rounded f64.
+    const ATOD_REGRESSIONS: [(&str, u64); 30] = [
+        // Near-underflow denormals.
+        // PHP and Java hung on the first two inputs, respectively,
+        // which would hang this test rather than fail an assertion.
+        //  https://www.exploringbinary.com/php-hangs-on-numeric-value-2-2250738585072011e-308/
+        //  https://www.exploringbinary.com/java-hangs-when-converting-2-2250738585072012e-308/
+        ("2.2250738585072011e-308", 0x000FFFFFFFFFFFFF),
+        ("2.2250738585072012e-308", 0x0010000000000000),
+        ("2.2250738585072009e-308", 0x000FFFFFFFFFFFFF),
+        ("2.2250738585072013e-308", 0x0010000000000000),
+        ("2.2250738585072014e-308", 0x0010000000000000),
+        ("0.00022250738585072012e-304", 0x0010000000000000),
+        ("2.225073858507201136057409796709131975934819546351645648e-308", 0x000FFFFFFFFFFFFF),
+        ("4.9406564584124654e-324", 0x0000000000000001),
+        ("2.4703282292062328e-324", 0x0000000000000001),
+        ("2.4703282292062327e-324", 0x0000000000000000),
+        ("1e-323", 0x0000000000000002),
+        ("1.5e-323", 0x0000000000000003),
+
+        // Large integers at the f64 precision boundary.
+        ("7.2057594037927933e16", 0x4370000000000000),
+        ("7.2057594037927932e16", 0x4370000000000000),
+        ("72057594037927944", 0x4370000000000000),
+        ("72057594037927945", 0x4370000000000001),
+        ("9007199254740993", 0x4340000000000000),
+        ("9007199254740995", 0x4340000000000002),
+        ("9007199254740993.000000000000000000000000000001", 0x4340000000000001),
+        ("18014398509481985", 0x4350000000000000),
+        ("18014398509481987", 0x4350000000000001),
+        ("9223372036854775807", 0x43E0000000000000),
+        ("18446744073709551615", 0x43F0000000000000),
+        ("1e23", 0x44B52D02C7E14AF6),
+
+        // Halfway cases with many digits and near-overflow values.
+        ("0.500000000000000166533453693773481063544750213623046875", 0x3FE0000000000002),
+        ("1.00000000000000011102230246251565404236316680908203125", 0x3FF0000000000000),
+        ("1.00000000000000011102230246251565404236316680908203126", 0x3FF0000000000001),
+        ("1.7976931348623157e308", 0x7FEFFFFFFFFFFFFF),
+        ("1.7976931348623158e308", 0x7FEFFFFFFFFFFFFF),
+        ("1.7976931348623159e308", 0x7FF0000000000000),
+    ];
+
+    #[test]
+    fn atod_regression_test() {
+        let format = NumberFormat::standard().unwrap();
+        for &(string, bits) in ATOD_REGRESSIONS.iter() {
+            let bytes = string.as_bytes();
+            let (value, ptr) = atod(bytes, 10, false, Sign::Positive, format).unwrap();
+            assert_eq!(value.to_bits(), bits, "{}", string);
+            assert_eq!(distance(bytes.as_ptr(), ptr), bytes.len());
+
+            // The lossy parser never uses the slow path, and is within 1 ULP.
+            let (lossy, _) = atod(bytes, 10, true, Sign::Positive, format).unwrap();
+            let (x, y) = (lossy.to_bits(), bits);
+            assert!(x.max(y) - x.min(y) <= 1, "{}", string);
+        }
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn pow2_zero_test() {
+        // Zero with a large exponent must be exactly zero, and consume
+        // the entire exponent, for every power-of-two radix.
+        let format = NumberFormat::standard().unwrap();
+        let exponents: [&[u8]; 4] = [b"11111111111", b"-11111111111", b"1111111111111111111111111111111111111111", b"-1111111111111111111111111111111111111111"];
+        for &radix in [2, 4, 8, 16, 32].iter() {
+            for &mantissa in [&b"0"[..], b"0.0", b"000.000"].iter() {
+                for &exponent in exponents.iter() {
+                    let mut bytes = mantissa.to_vec();
+                    bytes.push(exponent_notation_char(radix));
+                    bytes.extend_from_slice(exponent);
+                    let x = bytes.as_slice();
+
+                    let (value, ptr) = atof(x, radix, false, Sign::Positive, format).unwrap();
+                    assert_eq!(value.to_bits(), 0);
+                    assert_eq!(distance(x.as_ptr(), ptr), x.len());
+
+                    let (value, ptr) = atod(x, radix, false, Sign::Positive, format).unwrap();
+                    assert_eq!(value.to_bits(), 0);
+                    assert_eq!(distance(x.as_ptr(), ptr), x.len());
+                }
+            }
+        }
+    }
+
     #[test]
     fn atof_lossy_test() {
         let atof10 = move |x| match atof(x, 10, true, Sign::Positive, NumberFormat::standard().unwrap()) {
@@ -0,0 +1,103 @@
+//! Extract the exact decimal components of a float string.
+
+use crate::util::*;
+use super::super::decimal::DecimalComponents;
+use super::format::*;
+
+/// Extract the significand, exponent and scale of a float string.
+///
+/// Returns None if the significand does not fit in a 64-bit integer.
+///
+/// The string must be non-special and unsigned.
+fn to_components<'a, Data>(mut data: Data, bytes: &'a [u8], radix: u32)
+    -> ParseResult<(Option<DecimalComponents>, *const u8)>
+    where Data: FastDataInterface<'a>
+{
+    let ptr = data.extract(bytes, radix)?;
+
+    // Restore the trailing zeros trimmed from the fraction, since
+    // they are part of the significand and the scale.
+    if let Some(fraction) = data.fraction() {
+        let start = distance(bytes.as_ptr(), fraction.as_ptr());
+        let (fraction, _) = data.consume_fraction_digits(&index!(bytes[start..]), radix);
+        data.set_fraction(Some(fraction));
+    }
+
+    let radix_u64 = radix.as_u64();
+    let mut significand: u64 = 0;
+    for &c in data.integer_iter().chain(data.fraction_iter()) {
+        let digit = as_cast((c as char).to_digit(radix).unwrap());
+        significand = match significand.checked_mul(radix_u64).and_then(|v| v.checked_add(digit)) {
+            Some(v) => v,
+            None    => return Ok((None, ptr)),
+        };
+    }
+
+    let components = DecimalComponents {
+        significand,
+        exponent: data.raw_exponent(),
+        scale: data.fraction_iter().count(),
+    };
+    Ok((Some(components), ptr))
+}
+
+// Extract the significand, exponent and scale of a float string.
+//
+// The string must be non-special and unsigned.
+perftools_inline!{
+pub(crate) fn decimal_components(bytes: &[u8], radix: u32, format: NumberFormat)
+    -> ParseResult<(Option<DecimalComponents>, *const u8)>
+{
+    apply_interface!(to_components, format, bytes, radix)
+}}
+
+/// Write the significant digits of a float string to the buffer.
+///
+/// Returns the number of significant digits and the exponent of the
+/// last digit, or an error at the first digit that does not fit.
+///
+/// The string must be non-special and unsigned.
+fn to_digits<'a, Data>(mut data: Data, bytes: &'a [u8], radix: u32, buffer: &mut [u8])
+    -> ParseResult<((usize, i32), *const u8)>
+    where Data: FastDataInterface<'a>
+{
+    let ptr = data.extract(bytes, radix)?;
+
+    // Skip leading zeros, and defer trailing zeros until a non-zero digit.
+    let mut length = 0;
+    let mut zeros = 0;
+    for c in data.integer_iter().chain(data.fraction_iter()).skip_while(|&&c| c == b'0') {
+        if *c == b'0' {
+            zeros += 1;
+        } else if length + zeros < buffer.len() {
+            for slot in buffer[length..length+zeros].iter_mut() {
+                *slot = b'0';
+            }
+            length += zeros;
+            zeros = 0;
+            buffer[length] = *c;
+            length += 1;
+        } else {
+            return Err((ErrorCode::TooManyDigits, c as *const u8));
+        }
+    }
+    if length == 0 {
+        return Ok(((0, 0), ptr));
+    }
+
+    // Saturate the exponent, which is out of range of any float.
+    let fraction_digits = data.fraction_iter().count().as_i64();
+    let exponent = data.raw_exponent().saturating_sub(fraction_digits).saturating_add(zeros.as_i64());
+    let exponent = exponent.max(i32::min_value().as_i64()).min(i32::max_value().as_i64());
+    Ok(((length, exponent.as_i32()), ptr))
+}
+
+// Write the significant digits of a float string to the buffer.
+//
+// The string must be non-special and unsigned.
+perftools_inline!{
+pub(crate) fn significant_digits(bytes: &[u8], radix: u32, format: NumberFormat, buffer: &mut [u8])
+    -> ParseResult<((usize, i32), *const u8)>
+{
+    apply_interface!(to_digits, format, bytes, radix, buffer)
+}}
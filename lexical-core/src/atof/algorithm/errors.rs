@@ -112,6 +112,8 @@ impl FloatErrors for u64 {
         let denormal_exp = bias - 63;
         // This is always a valid u32, since (denormal_exp - fp.exp)
         // will always be positive and the significand size is {23, 52}.
+        // This cannot overflow, even for `fp.exp == i32::min_value()`,
+        // since `64 - F::MANTISSA_SIZE + denormal_exp` is negative.
         let extrabits = match fp.exp <= denormal_exp {
             true  => 64 - F::MANTISSA_SIZE + denormal_exp - fp.exp,
             false => 63 - F::MANTISSA_SIZE,
@@ -169,6 +171,10 @@ impl FloatErrors for u64 {
             return true;
         }
 
+        // We always have at least `63 - F::MANTISSA_SIZE` extra bits,
+        // so all the shifts below are in the range `[1, 64)`.
+        debug_assert!(extrabits >= (63 - F::MANTISSA_SIZE).as_u64(), "error_is_accurate() extrabits underflow.");
+
         #[cfg(not(feature = "rounding"))] {
             nearest_error_is_accurate(errors, fp, extrabits)
         }
@@ -202,3 +208,88 @@ impl FloatErrors for u128 {
         true
     }}
 }
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Check the extra bits at the boundaries and extremes of the exponent range.
+    fn check_error_is_accurate<F: Float>(kind: RoundingKind) {
+        let denormal_exp = F::MANTISSA_SIZE - F::EXPONENT_BIAS - 63;
+        let normal_halfway = 1u64 << (62 - F::MANTISSA_SIZE);
+        let mantissas = [1u64 << 63, (1u64 << 63) | normal_halfway, u64::max_value()];
+        let counts = [0u32, 1, u64::error_halfscale(), u64::error_scale(), 64];
+        let mut exponents = vec![
+            i32::min_value(),
+            i32::min_value() + 1,
+            denormal_exp - 66,
+            i32::max_value() - 1,
+            i32::max_value(),
+        ];
+        exponents.extend(denormal_exp-70..denormal_exp+70);
+        for &exp in exponents.iter() {
+            for &mant in mantissas.iter() {
+                for &count in counts.iter() {
+                    let fp = ExtendedFloat { mant, exp };
+                    u64::error_is_accurate::<F>(count, &fp, kind);
+                }
+            }
+        }
+
+        // Literal 0, always accurate.
+        let fp = ExtendedFloat { mant: u64::max_value(), exp: i32::min_value() };
+        assert!(u64::error_is_accurate::<F>(64, &fp, kind));
+        let fp = ExtendedFloat { mant: u64::max_value(), exp: denormal_exp - 54 };
+        assert!(u64::error_is_accurate::<F>(64, &fp, kind));
+
+        // No errors, always accurate.
+        let fp = ExtendedFloat { mant: 1u64 << 63, exp: i32::max_value() };
+        assert!(u64::error_is_accurate::<F>(0, &fp, kind));
+        let fp = ExtendedFloat { mant: 1u64 << 63, exp: i32::min_value() };
+        assert!(u64::error_is_accurate::<F>(0, &fp, kind));
+    }
+
+    #[test]
+    fn f32_error_is_accurate_test() {
+        check_error_is_accurate::<f32>(RoundingKind::NearestTieEven);
+
+        // Halfway with errors at the largest exponent is inaccurate.
+        let fp = ExtendedFloat { mant: (1u64 << 63) | (1u64 << 39), exp: i32::max_value() };
+        assert!(!u64::error_is_accurate::<f32>(1, &fp, RoundingKind::NearestTieEven));
+
+        // Halfway with errors at 64 and 65 extra bits is inaccurate.
+        let denormal_exp = f32::MANTISSA_SIZE - f32::EXPONENT_BIAS - 63;
+        let fp = ExtendedFloat { mant: 1u64 << 63, exp: denormal_exp - 23 };
+        assert!(!u64::error_is_accurate::<f32>(1, &fp, RoundingKind::NearestTieEven));
+        let fp = ExtendedFloat { mant: u64::max_value(), exp: denormal_exp - 24 };
+        assert!(!u64::error_is_accurate::<f32>(1, &fp, RoundingKind::NearestTieEven));
+    }
+
+    #[test]
+    fn f64_error_is_accurate_test() {
+        check_error_is_accurate::<f64>(RoundingKind::NearestTieEven);
+
+        // Halfway with errors at the largest exponent is inaccurate.
+        let fp = ExtendedFloat { mant: (1u64 << 63) | (1u64 << 10), exp: i32::max_value() };
+        assert!(!u64::error_is_accurate::<f64>(1, &fp, RoundingKind::NearestTieEven));
+
+        // Halfway with errors at 64 and 65 extra bits is inaccurate.
+        let denormal_exp = f64::MANTISSA_SIZE - f64::EXPONENT_BIAS - 63;
+        let fp = ExtendedFloat { mant: 1u64 << 63, exp: denormal_exp - 52 };
+        assert!(!u64::error_is_accurate::<f64>(1, &fp, RoundingKind::NearestTieEven));
+        let fp = ExtendedFloat { mant: u64::max_value(), exp: denormal_exp - 53 };
+        assert!(!u64::error_is_accurate::<f64>(1, &fp, RoundingKind::NearestTieEven));
+    }
+
+    #[cfg(feature = "rounding")]
+    #[test]
+    fn toward_error_is_accurate_test() {
+        check_error_is_accurate::<f32>(RoundingKind::Upward);
+        check_error_is_accurate::<f32>(RoundingKind::TowardZero);
+        check_error_is_accurate::<f64>(RoundingKind::Upward);
+        check_error_is_accurate::<f64>(RoundingKind::TowardZero);
+    }
+}
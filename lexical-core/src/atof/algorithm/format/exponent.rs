@@ -11,21 +11,25 @@ type FloatType = f64;
 
 // EXPONENT CALCULATION
 
+// The exponents are combined using an i64, and only saturated to an i32
+// once combined. The digit counts are bounded by the length of the input,
+// so cannot overflow an i64, and therefore an exponent outside the range
+// of an i32 is still combined exactly with the digit counts.
+
 // Calculate the scientific notation exponent without overflow.
 //
 // For example, 0.1 would be -1, and 10 would be 1 in base 10.
 perftools_inline!{
 #[cfg(feature = "correct")]
-pub(super) fn scientific_exponent(exponent: i32, integer_digits: usize, fraction_start: usize)
+pub(super) fn scientific_exponent(exponent: i64, integer_digits: usize, fraction_start: usize)
     -> i32
 {
-    if integer_digits == 0 {
-        let fraction_start = fraction_start.try_i32_or_max();
-        exponent.saturating_sub(fraction_start).saturating_sub(1)
+    let exponent = if integer_digits == 0 {
+        exponent.saturating_sub(fraction_start.try_i64_or_max()).saturating_sub(1)
     } else {
-        let integer_shift = (integer_digits - 1).try_i32_or_max();
-        exponent.saturating_add(integer_shift)
-    }
+        exponent.saturating_add((integer_digits - 1).try_i64_or_max())
+    };
+    try_cast_or_saturate(exponent)
 }}
 
 // Calculate the mantissa exponent without overflow.
@@ -35,14 +39,11 @@ pub(super) fn scientific_exponent(exponent: i32, integer_digits: usize, fraction
 // to calculate the scaling factor for the mantissa from a raw exponent.
 perftools_inline!{
 #[cfg(feature = "correct")]
-pub(super) fn mantissa_exponent(raw_exponent: i32, fraction_digits: usize, truncated: usize)
+pub(super) fn mantissa_exponent(raw_exponent: i64, fraction_digits: usize, truncated: usize)
     -> i32
 {
-    if fraction_digits > truncated {
-        raw_exponent.saturating_sub((fraction_digits - truncated).try_i32_or_max())
-    } else {
-        raw_exponent.saturating_add((truncated - fraction_digits).try_i32_or_max())
-    }
+    let shift = truncated.try_i64_or_max().saturating_sub(fraction_digits.try_i64_or_max());
+    try_cast_or_saturate(raw_exponent.saturating_add(shift))
 }}
 
 // EXPONENT EXTRACTORS
@@ -361,12 +362,18 @@ mod test {
         assert_eq!(scientific_exponent(-10, 2, 20), -9);
 
         // Underflow
-        assert_eq!(scientific_exponent(i32::min_value(), 0, 0), i32::min_value());
-        assert_eq!(scientific_exponent(i32::min_value(), 0, 5), i32::min_value());
+        let min = i32::min_value() as i64;
+        assert_eq!(scientific_exponent(min, 0, 0), i32::min_value());
+        assert_eq!(scientific_exponent(min, 0, 5), i32::min_value());
+        assert_eq!(scientific_exponent(min - 10, 20, 0), i32::min_value() + 9);
+        assert_eq!(scientific_exponent(i64::min_value(), 0, usize::max_value()), i32::min_value());
 
         // Overflow
-        assert_eq!(scientific_exponent(i32::max_value(), 0, 0), i32::max_value()-1);
-        assert_eq!(scientific_exponent(i32::max_value(), 5, 0), i32::max_value());
+        let max = i32::max_value() as i64;
+        assert_eq!(scientific_exponent(max, 0, 0), i32::max_value()-1);
+        assert_eq!(scientific_exponent(max, 5, 0), i32::max_value());
+        assert_eq!(scientific_exponent(max + 10, 0, 20), i32::max_value() - 11);
+        assert_eq!(scientific_exponent(i64::max_value(), usize::max_value(), 0), i32::max_value());
     }
 
     #[cfg(feature = "correct")]
@@ -374,10 +381,26 @@ mod test {
     fn mantissa_exponent_test() {
         assert_eq!(mantissa_exponent(10, 5, 0), 5);
         assert_eq!(mantissa_exponent(0, 5, 0), -5);
-        assert_eq!(mantissa_exponent(i32::max_value(), 5, 0), i32::max_value()-5);
-        assert_eq!(mantissa_exponent(i32::max_value(), 0, 5), i32::max_value());
-        assert_eq!(mantissa_exponent(i32::min_value(), 5, 0), i32::min_value());
-        assert_eq!(mantissa_exponent(i32::min_value(), 0, 5), i32::min_value()+5);
+        let max = i32::max_value() as i64;
+        let min = i32::min_value() as i64;
+        assert_eq!(mantissa_exponent(max, 5, 0), i32::max_value()-5);
+        assert_eq!(mantissa_exponent(max, 0, 5), i32::max_value());
+        assert_eq!(mantissa_exponent(min, 5, 0), i32::min_value());
+        assert_eq!(mantissa_exponent(min, 0, 5), i32::min_value()+5);
+
+        // Exponents that only fit in an i32 once combined.
+        assert_eq!(mantissa_exponent(max + 10, 20, 0), i32::max_value()-10);
+        assert_eq!(mantissa_exponent(min - 10, 0, 20), i32::min_value()+10);
+        assert_eq!(mantissa_exponent(max + 1000, 3000, 1000), i32::max_value()-1000);
+        assert_eq!(mantissa_exponent(min - 1000, 1000, 3000), i32::min_value()+1000);
+
+        // Saturate once combined.
+        assert_eq!(mantissa_exponent(max + 10, 5, 0), i32::max_value());
+        assert_eq!(mantissa_exponent(min - 10, 0, 5), i32::min_value());
+        assert_eq!(mantissa_exponent(i64::max_value(), 0, 5), i32::max_value());
+        assert_eq!(mantissa_exponent(i64::max_value(), 5, 0), i32::max_value());
+        assert_eq!(mantissa_exponent(i64::min_value(), 5, 0), i32::min_value());
+        assert_eq!(mantissa_exponent(i64::min_value(), 0, 5), i32::min_value());
     }
 
     #[test]
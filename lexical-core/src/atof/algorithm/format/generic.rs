@@ -11,6 +11,8 @@ use super::validate::*;
 //          1). [I]nteger.
 //          2). [F]raction.
 //          3). [E]xponent.
+//      GenericU*Interface, where the integer digit separators also
+//          include Unicode thin spaces.
 
 /// Shared definition for all generic fast data interfaces.
 macro_rules! generic_data_interface {
@@ -190,6 +192,70 @@ generic_data_interface!(
     rtrim_separator => rtrim_separator_separator
 );
 
+// Generic data interface with integer digit separators, and Unicode thin spaces.
+generic_data_interface!(
+    struct GenericUIFastDataInterface,
+    struct GenericUISlowDataInterface,
+    integer_iter => (IteratorUnicodeSeparator, iterate_digits_ignore_unicode_separator),
+    fraction_iter => (IteratorNoSeparator, iterate_digits_no_separator),
+    exponent_iter => (IteratorNoSeparator, iterate_digits_no_separator),
+    consume_integer_digits => consume_integer_digits_unicode_separator,
+    consume_fraction_digits => consume_digits_no_separator,
+    extract_exponent => extract_exponent_no_separator,
+    ltrim_zero => ltrim_zero_unicode_separator,
+    ltrim_separator => ltrim_separator_separator,
+    rtrim_zero => rtrim_zero_separator,
+    rtrim_separator => rtrim_separator_separator
+);
+
+// Generic data interface with integer and fraction digit separators, and Unicode thin spaces.
+generic_data_interface!(
+    struct GenericUIFFastDataInterface,
+    struct GenericUIFSlowDataInterface,
+    integer_iter => (IteratorUnicodeSeparator, iterate_digits_ignore_unicode_separator),
+    fraction_iter => (IteratorSeparator, iterate_digits_ignore_separator),
+    exponent_iter => (IteratorNoSeparator, iterate_digits_no_separator),
+    consume_integer_digits => consume_integer_digits_unicode_separator,
+    consume_fraction_digits => consume_fraction_digits_separator,
+    extract_exponent => extract_exponent_no_separator,
+    ltrim_zero => ltrim_zero_unicode_separator,
+    ltrim_separator => ltrim_separator_separator,
+    rtrim_zero => rtrim_zero_separator,
+    rtrim_separator => rtrim_separator_separator
+);
+
+// Generic data interface with integer and exponent digit separators, and Unicode thin spaces.
+generic_data_interface!(
+    struct GenericUIEFastDataInterface,
+    struct GenericUIESlowDataInterface,
+    integer_iter => (IteratorUnicodeSeparator, iterate_digits_ignore_unicode_separator),
+    fraction_iter => (IteratorNoSeparator, iterate_digits_no_separator),
+    exponent_iter => (IteratorSeparator, iterate_digits_ignore_separator),
+    consume_integer_digits => consume_integer_digits_unicode_separator,
+    consume_fraction_digits => consume_digits_no_separator,
+    extract_exponent => extract_exponent_separator,
+    ltrim_zero => ltrim_zero_unicode_separator,
+    ltrim_separator => ltrim_separator_separator,
+    rtrim_zero => rtrim_zero_separator,
+    rtrim_separator => rtrim_separator_separator
+);
+
+// Generic data interface with integer, fraction, and exponent digit separators, and Unicode thin spaces.
+generic_data_interface!(
+    struct GenericUIFEFastDataInterface,
+    struct GenericUIFESlowDataInterface,
+    integer_iter => (IteratorUnicodeSeparator, iterate_digits_ignore_unicode_separator),
+    fraction_iter => (IteratorSeparator, iterate_digits_ignore_separator),
+    exponent_iter => (IteratorSeparator, iterate_digits_ignore_separator),
+    consume_integer_digits => consume_integer_digits_unicode_separator,
+    consume_fraction_digits => consume_fraction_digits_separator,
+    extract_exponent => extract_exponent_separator,
+    ltrim_zero => ltrim_zero_unicode_separator,
+    ltrim_separator => ltrim_separator_separator,
+    rtrim_zero => rtrim_zero_separator,
+    rtrim_separator => rtrim_separator_separator
+);
+
 // TESTS
 // -----
 
@@ -346,4 +412,23 @@ mod tests {
             ("1.2345e+1_0", Ok(generic!(Generic, b"1", Some(b!("2345")), Some(b!("+1_0")), 10)))
         ].iter())
     }
+
+    #[test]
+    fn extract_ui_test() {
+        type Generic<'a> = GenericUIFastDataInterface<'a>;
+        let format = NumberFormat::grouped(b' ', 3).unwrap()
+            | NumberFormat::INTEGER_UNICODE_DIGIT_SEPARATOR;
+        Generic::new(format).run_tests([
+            // Valid
+            ("1.2345", Ok(generic!(Generic, b"1", Some(b!("2345")), None, 0))),
+            ("1 000\u{2009}000.5", Ok(generic!(Generic, b!("1 000\u{2009}000"), Some(b!("5")), None, 0))),
+            ("1\u{202F}000e-3", Ok(generic!(Generic, b!("1\u{202F}000"), None, Some(b!("-3")), -3))),
+
+            // Invalid
+            ("1\u{2009}\u{2009}000", Ok(generic!(Generic, b"1", None, None, 0))),
+            ("1\u{2009}.5", Ok(generic!(Generic, b"1", None, None, 0))),
+            ("1\u{2008}000", Ok(generic!(Generic, b"1", None, None, 0))),
+            ("1 000.2 345", Ok(generic!(Generic, b!("1 000"), Some(b!("2")), None, 0)))
+        ].iter())
+    }
 }
@@ -93,7 +93,7 @@ mod tests {
             ("", Err(ErrorCode::EmptyMantissa)),
             ("+", Err(ErrorCode::EmptyMantissa)),
             ("-", Err(ErrorCode::EmptyMantissa)),
-            (".", Err(ErrorCode::EmptyMantissa)),
+            (".", Err(ErrorCode::LoneDot)),
             ("+.", Err(ErrorCode::EmptyMantissa)),
             ("-.", Err(ErrorCode::EmptyMantissa)),
             ("e", Err(ErrorCode::EmptyMantissa)),
@@ -101,11 +101,11 @@ mod tests {
             ("e1", Err(ErrorCode::EmptyMantissa)),
             ("e+1", Err(ErrorCode::EmptyMantissa)),
             ("e-1", Err(ErrorCode::EmptyMantissa)),
-            (".e", Err(ErrorCode::EmptyMantissa)),
-            (".E", Err(ErrorCode::EmptyMantissa)),
-            (".e1", Err(ErrorCode::EmptyMantissa)),
-            (".e+1", Err(ErrorCode::EmptyMantissa)),
-            (".e-1", Err(ErrorCode::EmptyMantissa)),
+            (".e", Err(ErrorCode::LoneDot)),
+            (".E", Err(ErrorCode::LoneDot)),
+            (".e1", Err(ErrorCode::LoneDot)),
+            (".e+1", Err(ErrorCode::LoneDot)),
+            (".e-1", Err(ErrorCode::LoneDot)),
         ].iter());
     }
 }
@@ -3,7 +3,7 @@
 /// Convert format to interface, and call function with new item as first argument.
 #[cfg(not(feature = "format"))]
 macro_rules! apply_interface {
-    ($fn:ident, $format:expr $(,$args:ident)*) => {
+    ($fn:path, $format:expr $(,$args:ident)*) => {
         $fn(StandardFastDataInterface::new($format) $(,$args)*)
     };
 }
@@ -11,7 +11,7 @@ macro_rules! apply_interface {
 /// Convert format to interface, and call function with new item as first argument.
 #[cfg(feature = "format")]
 macro_rules! apply_interface {
-    ($fn:ident, $format:expr $(,$args:ident)*) => {
+    ($fn:path, $format:expr $(,$args:ident)*) => {
         match $format.interface_flags() {
             NumberFormat::PERMISSIVE_INTERFACE  => $fn(PermissiveFastDataInterface::new($format) $(,$args)*),
             NumberFormat::STANDARD_INTERFACE    => $fn(StandardFastDataInterface::new($format) $(,$args)*),
@@ -20,7 +20,14 @@ macro_rules! apply_interface {
                 let integer = flags.intersects(NumberFormat::INTEGER_DIGIT_SEPARATOR_FLAG_MASK);
                 let fraction = flags.intersects(NumberFormat::FRACTION_DIGIT_SEPARATOR_FLAG_MASK);
                 let exponent = flags.intersects(NumberFormat::EXPONENT_DIGIT_SEPARATOR_FLAG_MASK);
+                let unicode = flags.intersects(NumberFormat::INTEGER_UNICODE_DIGIT_SEPARATOR);
                 match (integer, fraction, exponent) {
+                    _ if unicode => match (fraction, exponent) {
+                        (true, true)        => $fn(GenericUIFEFastDataInterface::new($format) $(,$args)*),
+                        (false, true)       => $fn(GenericUIEFastDataInterface::new($format) $(,$args)*),
+                        (true, false)       => $fn(GenericUIFFastDataInterface::new($format) $(,$args)*),
+                        (false, false)      => $fn(GenericUIFastDataInterface::new($format) $(,$args)*)
+                    },
                     (true, true, true)      => $fn(GenericIFEFastDataInterface::new($format) $(,$args)*),
                     (false, true, true)     => $fn(GenericFEFastDataInterface::new($format) $(,$args)*),
                     (true, false, true)     => $fn(GenericIEFastDataInterface::new($format) $(,$args)*),
@@ -88,7 +88,7 @@ mod tests {
             ("", Err(ErrorCode::EmptyMantissa)),
             ("+", Err(ErrorCode::EmptyMantissa)),
             ("-", Err(ErrorCode::EmptyMantissa)),
-            (".", Err(ErrorCode::EmptyMantissa)),
+            (".", Err(ErrorCode::LoneDot)),
             ("+.", Err(ErrorCode::EmptyMantissa)),
             ("-.", Err(ErrorCode::EmptyMantissa)),
             ("e", Err(ErrorCode::EmptyMantissa)),
@@ -96,11 +96,11 @@ mod tests {
             ("e1", Err(ErrorCode::EmptyMantissa)),
             ("e+1", Err(ErrorCode::EmptyMantissa)),
             ("e-1", Err(ErrorCode::EmptyMantissa)),
-            (".e", Err(ErrorCode::EmptyMantissa)),
-            (".E", Err(ErrorCode::EmptyMantissa)),
-            (".e1", Err(ErrorCode::EmptyMantissa)),
-            (".e+1", Err(ErrorCode::EmptyMantissa)),
-            (".e-1", Err(ErrorCode::EmptyMantissa)),
+            (".e", Err(ErrorCode::LoneDot)),
+            (".E", Err(ErrorCode::LoneDot)),
+            (".e1", Err(ErrorCode::LoneDot)),
+            (".e+1", Err(ErrorCode::LoneDot)),
+            (".e-1", Err(ErrorCode::LoneDot)),
         ].iter());
     }
 }
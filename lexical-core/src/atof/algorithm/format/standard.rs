@@ -46,7 +46,7 @@ data_interface!(
 
 // FROM
 
-type DataTuple<'a> = (&'a [u8], Option<&'a [u8]>, Option<&'a [u8]>, i32);
+type DataTuple<'a> = (&'a [u8], Option<&'a [u8]>, Option<&'a [u8]>, i64);
 
 // Add `From` to remove repition in unit-testing.
 impl<'a> From<DataTuple<'a>> for StandardFastDataInterface<'a> {
@@ -100,13 +100,15 @@ mod tests {
             (".1", Ok(standard!(b"", Some(b!("1")), None, 0))),
             (".12", Ok(standard!(b"", Some(b!("12")), None, 0))),
             (".1234567", Ok(standard!(b"", Some(b!("1234567")), None, 0))),
+            ("1.e5", Ok(standard!(b"1", Some(b!("")), Some(b!("5")), 5))),
+            ("1.E-3", Ok(standard!(b"1", Some(b!("")), Some(b!("-3")), -3))),
 
             // Invalid
             ("1.2345e", Err(ErrorCode::EmptyExponent)),
             ("", Err(ErrorCode::EmptyMantissa)),
             ("+", Err(ErrorCode::EmptyMantissa)),
             ("-", Err(ErrorCode::EmptyMantissa)),
-            (".", Err(ErrorCode::EmptyMantissa)),
+            (".", Err(ErrorCode::LoneDot)),
             ("+.", Err(ErrorCode::EmptyMantissa)),
             ("-.", Err(ErrorCode::EmptyMantissa)),
             ("e", Err(ErrorCode::EmptyMantissa)),
@@ -114,11 +116,11 @@ mod tests {
             ("e1", Err(ErrorCode::EmptyMantissa)),
             ("e+1", Err(ErrorCode::EmptyMantissa)),
             ("e-1", Err(ErrorCode::EmptyMantissa)),
-            (".e", Err(ErrorCode::EmptyMantissa)),
-            (".E", Err(ErrorCode::EmptyMantissa)),
-            (".e1", Err(ErrorCode::EmptyMantissa)),
-            (".e+1", Err(ErrorCode::EmptyMantissa)),
-            (".e-1", Err(ErrorCode::EmptyMantissa)),
+            (".e", Err(ErrorCode::LoneDot)),
+            (".E", Err(ErrorCode::LoneDot)),
+            (".e1", Err(ErrorCode::LoneDot)),
+            (".e+1", Err(ErrorCode::LoneDot)),
+            (".e-1", Err(ErrorCode::LoneDot)),
             (".3e", Err(ErrorCode::EmptyExponent))
         ].iter());
     }
@@ -26,10 +26,10 @@ pub(crate) trait FastDataInterfaceImpl<'a>: Sized {
     fn set_exponent(&mut self, exponent: Option<&'a [u8]>);
 
     /// Get raw exponent component of float.
-    fn raw_exponent(&self) -> i32;
+    fn raw_exponent(&self) -> i64;
 
     /// Set raw exponent component of float.
-    fn set_raw_exponent(&mut self, raw_exponent: i32);
+    fn set_raw_exponent(&mut self, raw_exponent: i64);
 }
 
 /// Private data interface for local utilities.
@@ -48,10 +48,10 @@ pub(crate) trait SlowDataInterfaceImpl<'a>: Sized {
     fn set_fraction(&mut self, fraction: &'a [u8]);
 
     /// Get raw exponent component of float.
-    fn raw_exponent(&self) -> i32;
+    fn raw_exponent(&self) -> i64;
 
     /// Set raw exponent component of float.
-    fn set_raw_exponent(&mut self, raw_exponent: i32);
+    fn set_raw_exponent(&mut self, raw_exponent: i64);
 }
 
 // Implement FastDataInterfaceImpl for a default structure.
@@ -89,12 +89,12 @@ macro_rules! fast_data_interface_impl {
             }}
 
             perftools_inline!{
-            fn raw_exponent(&self) -> i32 {
+            fn raw_exponent(&self) -> i64 {
                 self.raw_exponent
             }}
 
             perftools_inline!{
-            fn set_raw_exponent(&mut self, raw_exponent: i32) {
+            fn set_raw_exponent(&mut self, raw_exponent: i64) {
                 self.raw_exponent = raw_exponent
             }}
         }
@@ -127,12 +127,12 @@ macro_rules! slow_data_interface_impl {
             }}
 
             perftools_inline!{
-            fn raw_exponent(&self) -> i32 {
+            fn raw_exponent(&self) -> i64 {
                 self.raw_exponent
             }}
 
             perftools_inline!{
-            fn set_raw_exponent(&mut self, raw_exponent: i32) {
+            fn set_raw_exponent(&mut self, raw_exponent: i64) {
                 self.raw_exponent = raw_exponent
             }}
         }
@@ -201,7 +201,7 @@ pub(crate) trait FastDataInterface<'a>: FastDataInterfaceImpl<'a> {
     // Extract the fraction substring from the float.
     //
     //  Preconditions:
-    //      `bytes.len()` >= 1 and `bytes[0] == b'.'`.
+    //      `bytes.len()` >= 1 and `bytes[0]` is the decimal point.
     perftools_inline!{
     fn extract_fraction(&mut self, bytes: &'a [u8], radix: u32)
         -> &'a [u8]
@@ -248,6 +248,65 @@ pub(crate) trait FastDataInterface<'a>: FastDataInterfaceImpl<'a> {
         self.set_fraction(self.fraction().map(|x| self.rtrim_zero(x).0));
     }}
 
+    // Determine if the character is an alias for the exponent character.
+    perftools_inline!{
+    #[cfg(feature = "format")]
+    fn is_exponent_alias(&self, c: u8) -> bool {
+        self.format().d_exponent_notation() && c.eq_ignore_ascii_case(&b'd')
+    }}
+
+    // Determine if the character is an alias for the exponent character.
+    perftools_inline!{
+    #[cfg(not(feature = "format"))]
+    fn is_exponent_alias(&self, _: u8) -> bool {
+        false
+    }}
+
+    // Determine if the character is a sign starting an exponent without notation.
+    perftools_inline!{
+    #[cfg(feature = "format")]
+    fn is_exponent_sign(&self, c: u8) -> bool {
+        self.format().exponent_without_notation() && (c == b'+' || c == b'-')
+    }}
+
+    // Determine if the character is a sign starting an exponent without notation.
+    perftools_inline!{
+    #[cfg(not(feature = "format"))]
+    fn is_exponent_sign(&self, _: u8) -> bool {
+        false
+    }}
+
+    // Get the radix of the exponent digits.
+    perftools_inline!{
+    #[cfg(feature = "format")]
+    fn exponent_radix(&self, radix: u32) -> u32 {
+        match self.format().exponent_base_10() {
+            true  => 10,
+            false => radix,
+        }
+    }}
+
+    // Get the radix of the exponent digits.
+    perftools_inline!{
+    #[cfg(not(feature = "format"))]
+    fn exponent_radix(&self, radix: u32) -> u32 {
+        radix
+    }}
+
+    // Get the character separating the integer and fraction.
+    perftools_inline!{
+    #[cfg(feature = "format")]
+    fn decimal_point(&self) -> u8 {
+        self.format().decimal_point()
+    }}
+
+    // Get the character separating the integer and fraction.
+    perftools_inline!{
+    #[cfg(not(feature = "format"))]
+    fn decimal_point(&self) -> u8 {
+        b'.'
+    }}
+
     /// Extract float subcomponents from input bytes.
     perftools_inline!{
     fn extract(&mut self, bytes: &'a [u8], radix: u32) -> ParseResult<*const u8> {
@@ -257,15 +316,21 @@ pub(crate) trait FastDataInterface<'a>: FastDataInterfaceImpl<'a> {
 
         // Parse and validate a fraction, if present.
         let exp_char = exponent_notation_char(radix).to_ascii_lowercase();
-        if let Some(&b'.') = digits.first() {
+        if digits.first() == Some(&self.decimal_point()) {
             digits = self.extract_fraction(digits, radix);
         }
         self.validate_mantissa()?;
 
         // Parse and validate an exponent, if present.
         if let Some(&c) = digits.first() {
-            if c.to_ascii_lowercase() == exp_char {
-                digits = self.extract_exponent(digits, radix);
+            if c.to_ascii_lowercase() == exp_char || self.is_exponent_alias(c) {
+                digits = self.extract_exponent(digits, self.exponent_radix(radix));
+            } else if self.is_exponent_sign(c) {
+                // The exponent extractors skip the exponent character,
+                // so start from the character preceding the sign. The
+                // mantissa was validated, so that character must exist.
+                let index = bytes.len() - digits.len();
+                digits = self.extract_exponent(&index!(bytes[index-1..]), self.exponent_radix(radix));
             }
         }
         self.validate_exponent()?;
@@ -359,12 +424,13 @@ macro_rules! fast_data_interface {
         rtrim_separator => $rtrim_separator:ident,
         new => $($new:tt)*
     ) => (
+        #[derive(Clone)]
         pub(crate) struct $name<'a> {
             $( $field : $type, )*
             integer: &'a [u8],
             fraction: Option<&'a [u8]>,
             exponent: Option<&'a [u8]>,
-            raw_exponent: i32
+            raw_exponent: i64
         }
 
         fast_data_interface_impl!($name);
@@ -566,7 +632,7 @@ macro_rules! slow_data_interface {
             fraction: &'a [u8],
             digits_start: usize,
             truncated_digits: usize,
-            raw_exponent: i32
+            raw_exponent: i64
         }
 
         #[cfg(feature = "correct")]
@@ -22,6 +22,18 @@ pub(super) fn ltrim_zero_separator<'a>(bytes: &'a [u8], digit_separator: u8)
     ltrim_char2_slice(bytes, b'0', digit_separator)
 }}
 
+// Trim leading 0s, digit separators and Unicode thin spaces.
+perftools_inline!{
+#[cfg(feature = "format")]
+pub(super) fn ltrim_zero_unicode_separator<'a>(bytes: &'a [u8], digit_separator: u8)
+    -> (&'a [u8], usize)
+{
+    let count = bytes.iter()
+        .take_while(|&&c| c == b'0' || c == digit_separator || !c.is_ascii())
+        .count();
+    (&index!(bytes[count..]), count)
+}}
+
 // Trim leading digit separators (so, nothing).
 // Does not consume any digit separators.
 perftools_inline!{
@@ -102,6 +114,13 @@ mod tests {
         assert_eq!(rtrim_zero_separator(b!("2345_0_"), b'_'), (b!("2345"), 3));
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn trim_zero_unicode_separator_test() {
+        assert_eq!(ltrim_zero_unicode_separator(b"0\xE2\x80\x89001 2", b' '), (&b"1 2"[..], 6));
+        assert_eq!(ltrim_zero_unicode_separator(b"0 0\xE2\x80\xAF", b' '), (&b""[..], 6));
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn trim_separator_iltc_separator_test() {
@@ -25,7 +25,6 @@ fn is_fraction_empty<'a, Data>(data: &Data)
 
 // Determine if the fraction component exists.
 perftools_inline!{
-#[cfg(feature = "format")]
 fn has_fraction<'a, Data>(data: &Data)
     -> bool
     where Data: FastDataInterface<'a>
@@ -72,6 +71,40 @@ pub(super) fn validate_no_leading_zeros<'a, Data>(data: &Data)
     }
 }}
 
+// Validate the extracted integer has correctly grouped digit separators.
+//      1. Validate the first group has from 1 to `group_size` digits.
+//      2. Validate every subsequent group has exactly `group_size` digits.
+//
+// Leading, trailing, and consecutive digit separators are rejected
+// during extraction, so every group is non-empty.
+perftools_inline!{
+#[cfg(feature = "format")]
+pub(super) fn validate_integer_grouping<'a, Data>(data: &Data, format: NumberFormat)
+    -> ParseResult<()>
+    where Data: FastDataInterface<'a>
+{
+    format.validate_grouping(data.integer())
+}}
+
+// Validate the extracted integer has exactly one digit.
+perftools_inline!{
+#[cfg(feature = "format")]
+pub(super) fn validate_normalized_mantissa<'a, Data>(data: &Data)
+    -> ParseResult<()>
+    where Data: FastDataInterface<'a>
+{
+    let mut iter = data.integer_iter();
+    if iter.next().is_none() {
+        return Err((ErrorCode::EmptyInteger, data.integer().as_ptr()));
+    }
+
+    // Only here if we have a leading digit.
+    match iter.next() {
+        Some(c) => Err((ErrorCode::InvalidDigit, c as *const u8)),
+        None    => Ok(())
+    }
+}}
+
 // Validate the extracted mantissa float components.
 //      1. Validate non-empty significant digits (integer or fraction).
 perftools_inline!{
@@ -81,7 +114,10 @@ pub(super) fn validate_permissive_mantissa<'a, Data>(data: &Data)
 {
     let integer_empty = is_integer_empty(data);
     let fraction_empty = is_fraction_empty(data);
-    if integer_empty && fraction_empty {
+    if integer_empty && fraction_empty && has_fraction(data) {
+        // Invalid floating-point number, only a decimal point.
+        Err((ErrorCode::LoneDot, data.integer().as_ptr()))
+    } else if integer_empty && fraction_empty {
         // Invalid floating-point number, no integer or fraction components.
         Err((ErrorCode::EmptyMantissa, data.integer().as_ptr()))
     } else {
@@ -153,6 +189,11 @@ pub(super) fn validate_mantissa<'a, Data>(data: &Data, format: NumberFormat)
         validate_no_leading_zeros(data)?;
     }
 
+    // Check integer digit groups.
+    if format.integer_grouped_digit_separator() {
+        validate_integer_grouping(data, format)?;
+    }
+
     // Check required digits.
     let required_integer = format.required_integer_digits();
     let required_fraction = format.required_fraction_digits();
@@ -161,6 +202,12 @@ pub(super) fn validate_mantissa<'a, Data>(data: &Data, format: NumberFormat)
         (false, true)   => validate_required_fraction(data),
         (true, false)   => validate_required_integer(data),
         (false, false)  => validate_permissive_mantissa(data)
+    }?;
+
+    // Check a single integer digit.
+    match format.normalized_mantissa() {
+        true  => validate_normalized_mantissa(data),
+        false => Ok(())
     }
 }}
 
@@ -217,6 +264,22 @@ pub(super) fn validate_invalid_exponent<'a, Data>(data: &Data)
     }
 }}
 
+// Validate the exponent component is present.
+perftools_inline!{
+#[cfg(feature = "format")]
+pub(super) fn validate_required_exponent_notation<'a, Data>(data: &Data)
+    -> ParseResult<()>
+    where Data: FastDataInterface<'a>
+{
+    if has_exponent(data) {
+        return Ok(())
+    }
+
+    // The exponent should follow the mantissa.
+    let mantissa = data.fraction().unwrap_or(data.integer());
+    Err((ErrorCode::MissingExponent, mantissa[mantissa.len()..].as_ptr()))
+}}
+
 // Validate exponent depending on float format.
 perftools_inline!{
 #[cfg(feature = "format")]
@@ -224,6 +287,10 @@ pub(super) fn validate_exponent<'a, Data>(data: &Data, format: NumberFormat)
     -> ParseResult<()>
     where Data: FastDataInterface<'a>
 {
+    if format.required_exponent_notation() {
+        validate_required_exponent_notation(data)?;
+    }
+
     let required = format.required_exponent_digits();
     let invalid = format.no_exponent_notation();
     match (required, invalid) {
@@ -354,6 +421,40 @@ mod tests {
         assert!(validate_no_leading_zeros(&data).is_ok());
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn validate_integer_grouping_test() {
+        type Data<'a> = StandardFastDataInterface<'a>;
+        let format = NumberFormat::grouped(b',', 3).unwrap();
+        let data: Data = (b!("1,234,567"), Some(b!("89")), None, 0).into();
+        assert!(validate_integer_grouping(&data, format).is_ok());
+
+        let data: Data = (b!("123,456"), None, None, 0).into();
+        assert!(validate_integer_grouping(&data, format).is_ok());
+
+        let data: Data = (b!("1234567"), None, None, 0).into();
+        assert!(validate_integer_grouping(&data, format).is_ok());
+
+        let data: Data = (b!(""), Some(b!("5")), None, 0).into();
+        assert!(validate_integer_grouping(&data, format).is_ok());
+
+        let data: Data = (b!("1,23,456"), None, None, 0).into();
+        assert!(validate_integer_grouping(&data, format).is_err());
+
+        let data: Data = (b!("12,34"), Some(b!("5")), None, 0).into();
+        assert!(validate_integer_grouping(&data, format).is_err());
+
+        let data: Data = (b!("1234,567"), None, None, 0).into();
+        assert!(validate_integer_grouping(&data, format).is_err());
+
+        let format = NumberFormat::grouped(b'\'', 4).unwrap();
+        let data: Data = (b!("1'2345'6789"), None, None, 0).into();
+        assert!(validate_integer_grouping(&data, format).is_ok());
+
+        let data: Data = (b!("1'234'567"), None, None, 0).into();
+        assert!(validate_integer_grouping(&data, format).is_err());
+    }
+
     #[test]
     fn validate_permissive_mantissa_test() {
         type Data<'a> = StandardFastDataInterface<'a>;
@@ -370,6 +471,23 @@ mod tests {
         assert!(validate_permissive_mantissa(&data).is_err());
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn validate_normalized_mantissa_test() {
+        type Data<'a> = StandardFastDataInterface<'a>;
+        let data: Data = (b!("1"), Some(b!("23450")), None, 0).into();
+        assert!(validate_normalized_mantissa(&data).is_ok());
+
+        let data: Data = (b!("0"), None, Some(b!("5")), 0).into();
+        assert!(validate_normalized_mantissa(&data).is_ok());
+
+        let data: Data = (b!("12"), Some(b!("3")), None, 0).into();
+        assert!(validate_normalized_mantissa(&data).is_err());
+
+        let data: Data = (b!(""), Some(b!("3")), None, 0).into();
+        assert!(validate_normalized_mantissa(&data).is_err());
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn validate_required_integer_test() {
@@ -460,6 +578,23 @@ mod tests {
         assert!(validate_optional_exponent(&data).is_ok());
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn validate_required_exponent_notation_test() {
+        type Data<'a> = StandardFastDataInterface<'a>;
+        let data: Data = (b!("0"), Some(b!("")), None, 0).into();
+        assert!(validate_required_exponent_notation(&data).is_err());
+
+        let data: Data = (b!("0"), None, None, 0).into();
+        assert!(validate_required_exponent_notation(&data).is_err());
+
+        let data: Data = (b!("0"), Some(b!("")), Some(b!("")), 0).into();
+        assert!(validate_required_exponent_notation(&data).is_ok());
+
+        let data: Data = (b!("0"), None, Some(b!("+2")), 0).into();
+        assert!(validate_required_exponent_notation(&data).is_ok());
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn validate_invalid_exponent_test() {
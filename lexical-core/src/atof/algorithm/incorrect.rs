@@ -50,27 +50,29 @@ fn process_fraction<'a, F, Data>(data: &Data, radix: u32)
 
 // Convert the float string to a native floating-point number.
 perftools_inline!{
-fn to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32)
+fn to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, scale: i64)
     -> ParseResult<(F, *const u8)>
     where F: StablePower,
           Data: FastDataInterface<'a>
 {
     let ptr = data.extract(bytes, radix)?;
+    let exponent = data.raw_exponent().saturating_sub(scale);
+    data.set_raw_exponent(exponent);
     let integer: F = process_integer(&data, radix);
     let fraction: F = process_fraction(&data, radix);
     let mut value = integer + fraction;
     if !data.raw_exponent().is_zero() && !value.is_zero() {
-        value = value.iterative_pow(radix, data.raw_exponent());
+        value = value.iterative_pow(radix, try_cast_or_saturate(data.raw_exponent()));
     }
     Ok((value, ptr))
 }}
 
 perftools_inline!{
-pub(crate) fn atof_generic<'a, F>(bytes: &'a [u8], radix: u32, _: bool, _: Sign, format: NumberFormat)
+pub(crate) fn atof_generic<'a, F>(bytes: &'a [u8], radix: u32, _: bool, _: Sign, format: NumberFormat, scale: i64)
     -> ParseResult<(F, *const u8)>
     where F: StablePower
 {
-    apply_interface!(to_native, format, bytes, radix)
+    apply_interface!(to_native, format, bytes, radix, scale)
 }}
 
 // ATOF/ATOD
@@ -81,7 +83,15 @@ perftools_inline!{
 pub(crate) fn atof<'a>(bytes: &'a [u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
     -> ParseResult<(f32, *const u8)>
 {
-    atof_generic(bytes, radix, lossy, sign, format)
+    atof_generic(bytes, radix, lossy, sign, format, 0)
+}}
+
+// Parse 32-bit float from string, divided by `radix^scale`.
+perftools_inline!{
+pub(crate) fn atof_scaled<'a>(bytes: &'a [u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, scale: i64)
+    -> ParseResult<(f32, *const u8)>
+{
+    atof_generic(bytes, radix, lossy, sign, format, scale)
 }}
 
 // Parse 64-bit float from string.
@@ -89,7 +99,15 @@ perftools_inline!{
 pub(crate) fn atod<'a>(bytes: &'a [u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
     -> ParseResult<(f64, *const u8)>
 {
-    atof_generic(bytes, radix, lossy, sign, format)
+    atof_generic(bytes, radix, lossy, sign, format, 0)
+}}
+
+// Parse 64-bit float from string, divided by `radix^scale`.
+perftools_inline!{
+pub(crate) fn atod_scaled<'a>(bytes: &'a [u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, scale: i64)
+    -> ParseResult<(f64, *const u8)>
+{
+    atof_generic(bytes, radix, lossy, sign, format, scale)
 }}
 
 // TESTS
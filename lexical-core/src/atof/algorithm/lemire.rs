@@ -0,0 +1,265 @@
+//! Eisel-Lemire algorithm for decimal strings.
+//!
+//! This implementation is based off the Rust core implementation,
+//! which is a port of the fast_float library, found here:
+//!     https://github.com/fastfloat/fast_float
+//!
+//! The algorithm multiplies the normalized mantissa by a cached 128-bit
+//! power of five, and the result is correct unless the product lies
+//! within the error window of a halfway point, which is rare.
+//! See "Number Parsing at a Gigabyte per Second", Lemire (2021).
+
+use crate::util::*;
+use super::powers_of_five::*;
+
+// FLOAT
+
+/// Float constants for the Eisel-Lemire algorithm.
+pub(super) trait LemireFloat: Float {
+    /// Smallest decimal exponent for a non-zero value.
+    const SMALLEST_POWER_OF_TEN: i32;
+    /// Largest decimal exponent for a non-infinite value.
+    const LARGEST_POWER_OF_TEN: i32;
+    /// Smallest decimal exponent which may need round-nearest, tie-even.
+    const MIN_EXPONENT_ROUND_TO_EVEN: i32;
+    /// Largest decimal exponent which may need round-nearest, tie-even.
+    const MAX_EXPONENT_ROUND_TO_EVEN: i32;
+}
+
+impl LemireFloat for f32 {
+    const SMALLEST_POWER_OF_TEN: i32 = -65;
+    const LARGEST_POWER_OF_TEN: i32 = 38;
+    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = -17;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 10;
+}
+
+impl LemireFloat for f64 {
+    const SMALLEST_POWER_OF_TEN: i32 = -342;
+    const LARGEST_POWER_OF_TEN: i32 = 308;
+    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = -4;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 23;
+}
+
+// HELPERS
+
+// Get the full 128-bit product of two 64-bit values, as the low and high bits.
+perftools_inline!{
+fn full_multiplication(x: u64, y: u64) -> (u64, u64) {
+    let product = (x as u128) * (y as u128);
+    (product as u64, (product >> 64) as u64)
+}}
+
+// Approximate the product of the mantissa and `5^q`, as the low and high bits.
+//
+// Only the high 64-bit power is multiplied, unless the bits below the
+// `precision` high bits of the product are all set, which may carry.
+perftools_inline!{
+fn compute_product_approx(q: i32, w: u64, precision: i32) -> (u64, u64) {
+    debug_assert!((SMALLEST_POWER_OF_FIVE..=LARGEST_POWER_OF_FIVE).contains(&q));
+    debug_assert!((0..=64).contains(&precision));
+
+    let mask = match precision < 64 {
+        true  => u64::max_value() >> precision,
+        false => u64::max_value(),
+    };
+    let (hi5, lo5) = POWER_OF_FIVE_128[(q - SMALLEST_POWER_OF_FIVE) as usize];
+    let (mut first_lo, mut first_hi) = full_multiplication(w, hi5);
+    if first_hi & mask == mask {
+        let (_, second_hi) = full_multiplication(w, lo5);
+        first_lo = first_lo.wrapping_add(second_hi);
+        if second_hi > first_lo {
+            first_hi += 1;
+        }
+    }
+    (first_lo, first_hi)
+}}
+
+// Approximate `floor(log2(10^q))`, plus 63, exact for q in `[-1233, 1233]`.
+perftools_inline!{
+fn power(q: i32) -> i32 {
+    (q.wrapping_mul(152_170 + 65536) >> 16) + 63
+}}
+
+// Create the float from the biased binary exponent and the explicit mantissa bits.
+perftools_inline!{
+fn to_float<F: LemireFloat>(mantissa: u64, power2: i32) -> F {
+    let bits = mantissa | ((power2 as u64) << F::MANTISSA_SIZE);
+    F::from_bits(as_cast(bits))
+}}
+
+// ALGORITHM
+
+/// Compute the float from the decimal mantissa and exponent, `w * 10^q`.
+///
+/// Returns `None` if the product is within the error window of a halfway
+/// point, so the float must be calculated with a slower algorithm. The
+/// float is rounded to nearest, tie-even.
+pub(super) fn eisel_lemire<F: LemireFloat>(mut w: u64, q: i32) -> Option<F> {
+    let mantissa_size = F::MANTISSA_SIZE;
+    let infinite_power = F::MAX_EXPONENT + F::EXPONENT_BIAS;
+    let minimum_exponent = -F::EXPONENT_BIAS + mantissa_size;
+    if w == 0 || q < F::SMALLEST_POWER_OF_TEN {
+        return Some(F::ZERO);
+    } else if q > F::LARGEST_POWER_OF_TEN {
+        return Some(F::INFINITY);
+    }
+
+    // Normalize the mantissa, and multiply by the power of five. The
+    // product needs the mantissa bits, a hidden bit, a rounding bit and
+    // a possible leading zero bit.
+    let lz = w.leading_zeros() as i32;
+    w <<= lz;
+    let (lo, hi) = compute_product_approx(q, w, mantissa_size + 3);
+    if lo == u64::max_value() {
+        // The product may be a truncated halfway point, so adding 1 to
+        // the product could round up over the halfway point. This can
+        // only occur if q is in `[-27, 55]`: above 55, `5^q` has more
+        // than 128 bits, and below -27, `5^-q` has more than 64 bits,
+        // so the value cannot be exactly halfway.
+        if !(-27..=55).contains(&q) {
+            return None;
+        }
+    }
+
+    // Shift the product to the mantissa bits plus a rounding bit.
+    let upperbit = (hi >> 63) as i32;
+    let shift = upperbit + 64 - mantissa_size - 3;
+    let mut mantissa = hi >> shift;
+    let mut power2 = power(q) + upperbit - lz - minimum_exponent;
+    if power2 <= 0 {
+        // Denormal result, or underflow to zero.
+        if -power2 + 1 >= 64 {
+            return Some(F::ZERO);
+        }
+        mantissa >>= -power2 + 1;
+        mantissa += mantissa & 1;
+        mantissa >>= 1;
+
+        // Rounding may create a normal float, with a hidden bit.
+        power2 = (mantissa >= (1 << mantissa_size)) as i32;
+        return Some(to_float::<F>(mantissa & !(1 << mantissa_size), power2));
+    }
+
+    // If the product is exactly halfway, round-nearest, tie-even rounds
+    // down to the even mantissa. This can only occur for small exponents,
+    // where the power of five is exact.
+    if lo <= 1
+        && q >= F::MIN_EXPONENT_ROUND_TO_EVEN
+        && q <= F::MAX_EXPONENT_ROUND_TO_EVEN
+        && mantissa & 3 == 1
+        && (mantissa << shift) == hi
+    {
+        mantissa &= !1;
+    }
+
+    // Round to nearest, and shift off the rounding bit.
+    mantissa += mantissa & 1;
+    mantissa >>= 1;
+    if mantissa >= (2 << mantissa_size) {
+        // Rounding overflowed the mantissa, shift into the exponent.
+        mantissa = 1 << mantissa_size;
+        power2 += 1;
+    }
+    mantissa &= !(1 << mantissa_size);
+    if power2 >= infinite_power {
+        return Some(F::INFINITY);
+    }
+    Some(to_float::<F>(mantissa, power2))
+}
+
+/// Compute the float from a decimal mantissa, which may be truncated.
+///
+/// If the mantissa is truncated, the float is between `w * 10^q` and
+/// `(w + 1) * 10^q`, so the float is only returned if both round to
+/// the same float.
+pub(super) fn eisel_lemire_truncated<F: LemireFloat>(w: u64, q: i32, truncated: bool) -> Option<F> {
+    let float = eisel_lemire::<F>(w, q)?;
+    if truncated {
+        let upper = eisel_lemire::<F>(w.checked_add(1)?, q)?;
+        if float != upper {
+            return None;
+        }
+    }
+    Some(float)
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn power_test() {
+        // Must match `floor(log2(10^q)) + 63`.
+        assert_eq!(power(0), 63);
+        assert_eq!(power(1), 66);
+        assert_eq!(power(22), 136);
+        assert_eq!(power(-1), 59);
+        assert_eq!(power(-342), -1074);
+        assert_eq!(power(308), 1086);
+    }
+
+    #[test]
+    fn powers_of_five_test() {
+        // Every power is normalized.
+        for &(hi, _) in POWER_OF_FIVE_128.iter() {
+            assert_eq!(hi >> 63, 1);
+        }
+        assert_eq!(POWER_OF_FIVE_128[(0 - SMALLEST_POWER_OF_FIVE) as usize], (1 << 63, 0));
+        assert_eq!(POWER_OF_FIVE_128[(1 - SMALLEST_POWER_OF_FIVE) as usize], (5 << 61, 0));
+    }
+
+    #[test]
+    fn f64_eisel_lemire_test() {
+        let check = | w: u64, q: i32, expected: u64 | {
+            assert_eq!(eisel_lemire::<f64>(w, q).map(f64::to_bits), Some(expected));
+        };
+        check(1, 0, 0x3ff0000000000000);
+        check(1, -1, 0x3fb999999999999a);
+        check(1, 23, 0x44b52d02c7e14af6);
+        check(17976931348623157, 292, 0x7fefffffffffffff);
+        check(22250738585072014, -324, 0x0010000000000000);
+        check(22250738585072011, -324, 0x000fffffffffffff);
+        check(49406564584124654, -340, 0x0000000000000001);
+        check(24703282292062328, -340, 0x0000000000000001);
+        check(24703282292062327, -340, 0x0000000000000000);
+        check(1, -400, 0);
+        check(1, 400, 0x7ff0000000000000);
+        check(17976931348623159, 292, 0x7ff0000000000000);
+
+        // Halfway points, which round-nearest, tie-even.
+        check(9007199254740993, 0, 0x4340000000000000);
+        check(9007199254740995, 0, 0x4340000000000002);
+        check(9007199254740993, 1, 0x4374000000000001);
+    }
+
+    #[test]
+    fn f32_eisel_lemire_test() {
+        let check = | w: u64, q: i32, expected: u32 | {
+            assert_eq!(eisel_lemire::<f32>(w, q).map(f32::to_bits), Some(expected));
+        };
+        check(1, 0, 0x3f800000);
+        check(1, -1, 0x3dcccccd);
+        check(34028235, 31, 0x7f7fffff);
+        check(1401298464324817, -60, 0x00000001);
+        check(1, -70, 0);
+        check(1, 40, 0x7f800000);
+
+        // Halfway points, which round-nearest, tie-even.
+        check(16777217, 0, 0x4b800000);
+        check(16777219, 0, 0x4b800002);
+    }
+
+    #[test]
+    fn eisel_lemire_truncated_test() {
+        // The exact halfway point between 1 and the next float, 1 + 2^-53,
+        // truncated to 19 digits. Both bounds must round to the same float.
+        let w = 1000000000000000111;
+        assert_eq!(None, eisel_lemire_truncated::<f64>(w, -18, true));
+        assert_eq!(Some(1.0), eisel_lemire_truncated::<f64>(w, -18, false));
+        assert_eq!(Some(0.1), eisel_lemire_truncated::<f64>(1000000000000000000, -19, true));
+        assert_eq!(None, eisel_lemire_truncated::<f64>(u64::max_value(), 0, true));
+    }
+}
@@ -86,28 +86,28 @@ fn split_u32(x: u32) -> [Limb; 1] {
 /// Split u64 into limbs, in little-endian order.
 perftools_inline!{
 #[cfg(limb_width_32)]
-fn split_u64(x: u64) -> [Limb; 2] {
+pub(super) fn split_u64(x: u64) -> [Limb; 2] {
     [as_limb(x), as_limb(x >> 32)]
 }}
 
 /// Split u64 into limbs, in little-endian order.
 perftools_inline!{
 #[cfg(limb_width_64)]
-fn split_u64(x: u64) -> [Limb; 1] {
+pub(super) fn split_u64(x: u64) -> [Limb; 1] {
     [as_limb(x)]
 }}
 
 /// Split u128 into limbs, in little-endian order.
 perftools_inline!{
 #[cfg(limb_width_32)]
-fn split_u128(x: u128) -> [Limb; 4] {
+pub(super) fn split_u128(x: u128) -> [Limb; 4] {
     [as_limb(x), as_limb(x >> 32), as_limb(x >> 64), as_limb(x >> 96)]
 }}
 
 /// Split u128 into limbs, in little-endian order.
 perftools_inline!{
 #[cfg(limb_width_64)]
-fn split_u128(x: u128) -> [Limb; 2] {
+pub(super) fn split_u128(x: u128) -> [Limb; 2] {
     [as_limb(x), as_limb(x >> 64)]
 }}
 
@@ -1409,6 +1409,27 @@ pub fn mul<T>(x: &[Limb], y: Limb)
     z
 }}
 
+/// MulAssign by a power, using iterative small powers.
+///
+/// Unlike `imul_power`, this never requires temporary storage.
+pub fn imul_small_powers<T>(x: &mut T, radix: u32, n: u32)
+    where T: CloneableVecLike<Limb>
+{
+    let small_powers = get_small_powers(radix);
+
+    // Multiply by the largest small power until n < step.
+    let step = small_powers.len() - 1;
+    let power = small_powers[step];
+    let mut n = n.as_usize();
+    while n >= step {
+        imul(x, power);
+        n -= step;
+    }
+
+    // Multiply by the remainder.
+    imul(x, small_powers[n]);
+}
+
 /// MulAssign by a power.
 ///
 /// Theoretically...
@@ -1446,7 +1467,6 @@ pub fn imul_power<T>(x: &mut T, radix: u32, n: u32)
 {
     use super::large::KARATSUBA_CUTOFF;
 
-    let small_powers = get_small_powers(radix);
     let large_powers = get_large_powers(radix);
 
     if n == 0 {
@@ -1464,18 +1484,7 @@ pub fn imul_power<T>(x: &mut T, radix: u32, n: u32)
     if x.len() + large_powers[bit_length-1].len() < 2*KARATSUBA_CUTOFF {
         // We can use iterative small powers to make this faster for the
         // easy cases.
-
-        // Multiply by the largest small power until n < step.
-        let step = small_powers.len() - 1;
-        let power = small_powers[step];
-        let mut n = n.as_usize();
-        while n >= step {
-            imul(x, power);
-            n -= step;
-        }
-
-        // Multiply by the remainder.
-        imul(x, small_powers[n]);
+        imul_small_powers(x, radix, n);
     } else {
         // In theory, this code should be asymptotically a lot faster,
         // in practice, our small::imul seems to be the limiting step,
@@ -2250,7 +2259,7 @@ macro_rules! imul_power {
 /// None of these are implemented using normal traits, since these
 /// are very expensive operations, and we want to deliberately
 /// and explicitly use these functions.
-pub(in crate::atof::algorithm) trait SharedOps: Clone + Sized + Default {
+pub(crate) trait SharedOps: Sized + Default {
     /// Underlying storage type for a SmallOps.
     type StorageType: CloneableVecLike<Limb>;
 
@@ -2451,7 +2460,9 @@ pub(in crate::atof::algorithm) trait SharedOps: Clone + Sized + Default {
 
     /// Shift-left the entire buffer n bits.
     perftools_inline!{
-    fn shl(&self, n: usize) -> Self {
+    fn shl(&self, n: usize) -> Self
+        where Self: Clone
+    {
         let mut x = self.clone();
         x.ishl(n);
         x
@@ -2474,7 +2485,9 @@ pub(in crate::atof::algorithm) trait SharedOps: Clone + Sized + Default {
 
     /// Shift-right the entire buffer n bits.
     perftools_inline!{
-    fn shr(&self, n: usize, roundup: bool) -> Self {
+    fn shr(&self, n: usize, roundup: bool) -> Self
+        where Self: Clone
+    {
         let mut x = self.clone();
         x.ishr(n, roundup);
         x
@@ -2482,7 +2495,7 @@ pub(in crate::atof::algorithm) trait SharedOps: Clone + Sized + Default {
 }
 
 /// Trait for small operations for arbitrary-precision numbers.
-pub(in crate::atof::algorithm) trait SmallOps: SharedOps {
+pub(crate) trait SmallOps: SharedOps {
     // SMALL POWERS
 
     /// Get the small powers from the radix.
@@ -2507,7 +2520,9 @@ pub(in crate::atof::algorithm) trait SmallOps: SharedOps {
 
     /// Add small integer to a copy of self.
     perftools_inline!{
-    fn add_small(&self, y: Limb) -> Self {
+    fn add_small(&self, y: Limb) -> Self
+        where Self: Clone
+    {
         let mut x = self.clone();
         x.iadd_small(y);
         x
@@ -2525,7 +2540,9 @@ pub(in crate::atof::algorithm) trait SmallOps: SharedOps {
     /// Sub small integer to a copy of self.
     /// Warning: Does no overflow checking, x must be >= y.
     perftools_inline!{
-    fn sub_small(&mut self, y: Limb) -> Self {
+    fn sub_small(&mut self, y: Limb) -> Self
+        where Self: Clone
+    {
         let mut x = self.clone();
         x.isub_small(y);
         x
@@ -2541,7 +2558,9 @@ pub(in crate::atof::algorithm) trait SmallOps: SharedOps {
 
     /// Mul small integer to a copy of self.
     perftools_inline!{
-    fn mul_small(&self, y: Limb) -> Self {
+    fn mul_small(&self, y: Limb) -> Self
+        where Self: Clone
+    {
         let mut x = self.clone();
         x.imul_small(y);
         x
@@ -2795,7 +2814,9 @@ pub(in crate::atof::algorithm) trait SmallOps: SharedOps {
 
     /// Div small integer to a copy of self, and return the remainder.
     perftools_inline!{
-    fn div_small(&self, y: Limb) -> (Self, Limb) {
+    fn div_small(&self, y: Limb) -> (Self, Limb)
+        where Self: Clone
+    {
         let mut x = self.clone();
         let rem = x.idiv_small(y);
         (x, rem)
@@ -2811,7 +2832,9 @@ pub(in crate::atof::algorithm) trait SmallOps: SharedOps {
 
     /// Calculate self^n
     perftools_inline!{
-    fn pow(&self, n: Limb) -> Self {
+    fn pow(&self, n: Limb) -> Self
+        where Self: Clone
+    {
         let mut x = self.clone();
         x.ipow(n);
         x
@@ -2819,7 +2842,7 @@ pub(in crate::atof::algorithm) trait SmallOps: SharedOps {
 }
 
 /// Trait for large operations for arbitrary-precision numbers.
-pub(in crate::atof::algorithm) trait LargeOps: SmallOps {
+pub(crate) trait LargeOps: SmallOps {
     // ADDITION
 
     /// AddAssign large integer.
@@ -2830,7 +2853,9 @@ pub(in crate::atof::algorithm) trait LargeOps: SmallOps {
 
     /// Add large integer to a copy of self.
     perftools_inline!{
-    fn add_large(&mut self, y: &Self) -> Self {
+    fn add_large(&mut self, y: &Self) -> Self
+        where Self: Clone
+    {
         let mut x = self.clone();
         x.iadd_large(y);
         x
@@ -2848,7 +2873,9 @@ pub(in crate::atof::algorithm) trait LargeOps: SmallOps {
     /// Sub large integer to a copy of self.
     /// Warning: Does no overflow checking, x must be >= y.
     perftools_inline!{
-    fn sub_large(&mut self, y: &Self) -> Self {
+    fn sub_large(&mut self, y: &Self) -> Self
+        where Self: Clone
+    {
         let mut x = self.clone();
         x.isub_large(y);
         x
@@ -2864,7 +2891,9 @@ pub(in crate::atof::algorithm) trait LargeOps: SmallOps {
 
     /// Mul large integer to a copy of self.
     perftools_inline!{
-    fn mul_large(&mut self, y: &Self) -> Self {
+    fn mul_large(&mut self, y: &Self) -> Self
+        where Self: Clone
+    {
         let mut x = self.clone();
         x.imul_large(y);
         x
@@ -2882,7 +2911,9 @@ pub(in crate::atof::algorithm) trait LargeOps: SmallOps {
 
     /// Div large integer to a copy of self and get quotient and remainder.
     perftools_inline!{
-    fn div_large(&mut self, y: &Self) -> (Self, Self) {
+    fn div_large(&mut self, y: &Self) -> (Self, Self)
+        where Self: Clone
+    {
         let mut x = self.clone();
         let rem = x.idiv_large(y);
         (x, rem)
@@ -11,13 +11,23 @@ mod bigcomp;
 mod alias;
 mod bignum;
 mod cached;
-mod cached_float80;
-mod cached_float160;
 mod errors;
 mod large_powers;
+mod lemire;
 mod math;
+mod powers_of_five;
+mod scratch;
 mod small_powers;
 
+#[cfg(feature = "table")]
+mod cached_float80;
+
+#[cfg(feature = "table")]
+mod cached_float160;
+
+#[cfg(any(not(feature = "table"), test))]
+mod cached_runtime;
+
 #[cfg(limb_width_32)]
 mod large_powers_32;
 
@@ -33,8 +43,22 @@ mod small_powers_64;
 }}  // cfg_if
 
 // Export algorithms.
+pub(crate) mod classify;
+pub(crate) mod decimal;
+pub(crate) mod truncate;
+
 #[cfg(feature = "correct")]
 pub(crate) mod correct;
 
 #[cfg(not(feature = "correct"))]
 pub(crate) mod incorrect;
+
+// Re-export the big integer type, for the float writers.
+#[cfg(feature = "correct")]
+pub(crate) use self::bignum::Bigint;
+#[cfg(feature = "correct")]
+pub(crate) use self::math::{LargeOps, SharedOps, SmallOps};
+
+// Re-export the public big integer type.
+#[cfg(feature = "bignum")]
+pub use self::bignum::Bignum;
@@ -0,0 +1,155 @@
+//! Compare the mantissa to the halfway representation of the float,
+//! using limbs provided by the caller.
+//!
+//! This uses the same algorithm as `bhcomp`, for decimal strings, but the
+//! big integers are stored in a borrowed slice of 64-bit limbs, rather
+//! than a growable vector, so the slow path never allocates. The number
+//! of limbs required is calculated before any digits are parsed, so if
+//! the slice is too small, the required number of limbs is returned.
+
+use crate::float::*;
+use crate::lib::result::Result as StdResult;
+#[cfg(limb_width_32)]
+use crate::lib::slice;
+use crate::util::*;
+use super::alias::*;
+use super::bhcomp;
+use super::bigcomp;
+use super::bignum::*;
+use super::format::*;
+use super::math::*;
+
+// BITS
+
+// Upper bound for the number of bits in `10^n`, using `log2(10) < 1701 / 512`.
+perftools_inline!{
+fn pow10_bits(n: usize) -> usize {
+    n * 1701 / 512 + 1
+}}
+
+// Upper bound for the number of bits in `5^n`, using `log2(5) < 1189 / 512`.
+perftools_inline!{
+fn pow5_bits(n: usize) -> usize {
+    n * 1189 / 512 + 1
+}}
+
+// Number of 64-bit limbs to store a number of bits.
+perftools_inline!{
+fn limbs(bits: usize) -> usize {
+    bits / 64 + 1
+}}
+
+// LIMBS
+
+// Get the scratch limbs as native limbs.
+#[cfg(limb_width_64)]
+perftools_inline!{
+fn as_limbs(scratch: &mut [u64]) -> &mut [Limb] {
+    scratch
+}}
+
+// Get the scratch limbs as native limbs.
+#[cfg(limb_width_32)]
+perftools_inline!{
+fn as_limbs(scratch: &mut [u64]) -> &mut [Limb] {
+    // Safe, since a 64-bit limb has the size of two 32-bit limbs,
+    // and at least the same alignment.
+    let len = scratch.len() * 2;
+    unsafe { slice::from_raw_parts_mut(scratch.as_mut_ptr() as *mut Limb, len) }
+}}
+
+// BHCOMP
+
+/// Calculate the exact value of the float from a decimal string.
+///
+/// Returns the number of limbs required if the scratch limbs are
+/// too small, without modifying the scratch limbs.
+///
+/// Notes:
+///     The digits iterator must not have any trailing zeros (true for
+///     `FloatState2`).
+///     sci_exponent and digits.size_hint() must not overflow i32.
+pub(super) fn atof<'a, F, Data>(data: Data, f: F, kind: RoundingKind, scratch: &mut [u64])
+    -> StdResult<F, usize>
+    where F: FloatType,
+          Data: SlowDataInterface<'a>
+{
+    // Decimal floats have a finite number of significant digits.
+    let max_digits = bhcomp::max_digits::<F>(10).unwrap();
+    let count = max_digits.min(data.mantissa_digits());
+    let exponent = data.scientific_exponent() + 1 - count.as_i32();
+
+    if exponent >= 0 {
+        let required = limbs(pow10_bits(count + exponent.as_usize()));
+        if scratch.len() < required {
+            return Err(required);
+        }
+
+        let bigmant = ScratchBigint::new(as_limbs(scratch));
+        Ok(bhcomp::large_atof(data, 10, max_digits, exponent, kind, bigmant))
+    } else {
+        // `bhcomp` factors the power of two out of `10^exponent`, so the
+        // real digits are multiplied by a power of 2, and `b+h` by powers
+        // of 5 and 2. Split the limbs between the real digits and `b+h`.
+        let theor = bigcomp::theoretical_float(f, kind);
+        let halfradix_exp = -exponent;
+        let binary_exp = theor.exp() - exponent;
+        let mut real_bits = pow10_bits(count);
+        let mut theor_bits = <u64 as crate::util::Integer>::BITS.as_usize() + pow5_bits(halfradix_exp.as_usize());
+        if binary_exp > 0 {
+            theor_bits += binary_exp.as_usize();
+        } else {
+            real_bits += (-binary_exp).as_usize();
+        }
+        let real_limbs = limbs(real_bits);
+        let required = real_limbs + limbs(theor_bits);
+        if scratch.len() < required {
+            return Err(required);
+        }
+
+        let (real, theor) = scratch.split_at_mut(real_limbs);
+        let real_digits = ScratchBigint::new(as_limbs(real));
+        let theor_digits = ScratchBigint::new(as_limbs(theor));
+        Ok(bhcomp::small_atof(data, 10, max_digits, exponent, f, kind, real_digits, theor_digits))
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use crate::lib::cmp;
+    use super::*;
+
+    #[test]
+    fn scratch_bigint_test() {
+        let mut limbs = [0u64; 8];
+        let mut x = ScratchBigint::new(as_limbs(&mut limbs));
+        x.iadd_small(1);
+        x.imul_pow10(40);
+        assert_eq!(x.hi64(), (0xeb194f8e1ae525fd, true));
+        assert_eq!(x.bit_length(), 133);
+
+        let mut limbs = [0u64; 8];
+        let mut y = ScratchBigint::new(as_limbs(&mut limbs));
+        y.iadd_small(3);
+        y.imul_pow2(130);
+        assert_eq!(y.hi64(), (3 << 62, false));
+        assert_eq!(x.compare(&y), cmp::Ordering::Greater);
+        assert_eq!(y.compare(&x), cmp::Ordering::Less);
+        assert_eq!(x.compare(&x), cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn bits_test() {
+        // Upper bounds, but within a bit for small powers.
+        assert_eq!(pow10_bits(0), 1);
+        assert_eq!(pow10_bits(19), 64);
+        assert_eq!(pow10_bits(308), 1024);
+        assert_eq!(pow5_bits(27), 63);
+        assert_eq!(pow5_bits(1074), 2495);
+        assert_eq!(limbs(63), 1);
+        assert_eq!(limbs(64), 2);
+    }
+}
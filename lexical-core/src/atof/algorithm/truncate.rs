@@ -0,0 +1,55 @@
+//! Parse the integer portion of a float string, truncated toward zero.
+
+use crate::util::*;
+use super::format::*;
+
+/// Parse the magnitude of the integer portion of a float string.
+///
+/// Returns None if the magnitude is larger than `max`.
+///
+/// The string must be unsigned.
+fn to_truncated<'a, Data>(mut data: Data, bytes: &'a [u8], radix: u32, max: u64)
+    -> ParseResult<(Option<u64>, *const u8)>
+    where Data: FastDataInterface<'a>
+{
+    let ptr = data.extract(bytes, radix)?;
+
+    // The exponent shifts the decimal point past the integer digits,
+    // into the fraction digits or past the last digit.
+    let integer_digits = data.integer_iter().count();
+    let mut remaining = integer_digits.try_i64_or_max().saturating_add(data.raw_exponent());
+    let mut value: u64 = 0;
+    let radix_u64 = radix.as_u64();
+    for &c in data.integer_iter().chain(data.fraction_iter()) {
+        if remaining <= 0 {
+            break;
+        }
+        let digit = as_cast((c as char).to_digit(radix).unwrap());
+        value = match value.checked_mul(radix_u64).and_then(|v| v.checked_add(digit)) {
+            Some(v) if v <= max => v,
+            _                   => return Ok((None, ptr)),
+        };
+        remaining -= 1;
+    }
+
+    // Shift past the last digit, which overflows within 64 iterations.
+    while remaining > 0 && value != 0 {
+        value = match value.checked_mul(radix_u64) {
+            Some(v) if v <= max => v,
+            _                   => return Ok((None, ptr)),
+        };
+        remaining -= 1;
+    }
+
+    Ok((Some(value), ptr))
+}
+
+// Parse the magnitude of the integer portion of a float string.
+//
+// The string must be unsigned.
+perftools_inline!{
+pub(crate) fn truncated_int(bytes: &[u8], radix: u32, max: u64, format: NumberFormat)
+    -> ParseResult<(Option<u64>, *const u8)>
+{
+    apply_interface!(to_truncated, format, bytes, radix, max)
+}}
@@ -0,0 +1,156 @@
+//! Infer the decimal point of numbers with ambiguous separators.
+
+use crate::util::*;
+
+// INFERRED SEPARATORS
+
+/// Interpretation of the comma and period in an ambiguous number.
+///
+/// Numbers from unknown locales may use either the comma or the period
+/// as the decimal point, with the other separating groups of 3 integer
+/// digits, so `"1,234.5"` and `"1.234,5"` are both `1234.5`. The
+/// interpretation is inferred from the separators in the string, using
+/// the following rules, in order:
+///
+/// 1. If neither a comma nor a period appears, the number has no
+///    separators, and is parsed with the standard number format.
+/// 2. If both appear, the last separator in the string is the decimal
+///    point, and the other separates groups of digits, so `"1,234.56"`
+///    is `1234.56` and `"1.234,56"` is `1234.56`.
+/// 3. If only one appears, more than once, it separates groups of digits,
+///    so `"1,234,567"` is `1234567` and `"1.234.567"` is `1234567`.
+/// 4. If only one appears, exactly once, and it is followed by a group
+///    of exactly 3 digits, after a group of 1 to 3 digits, it separates
+///    groups of digits, so `"1,234"` is `1234` and `"1.234"` is `1234`.
+/// 5. Otherwise, the single separator is near the end of the number,
+///    or cannot separate groups, so it is the decimal point, so `"1,5"`
+///    is `1.5`, `"1.2345"` is `1.2345` and `"1234,567"` is `1234.567`.
+///
+/// The number is then parsed with the inferred separators, where
+/// groups of digits must follow the European or grouped number format
+/// rules: the first group has from 1 to 3 digits, and every subsequent
+/// group has exactly 3 digits. Invalid groups, or a second decimal
+/// point, return an error. The rules are a best-effort heuristic, and
+/// cannot distinguish a single grouping separator from a decimal point,
+/// so callers should check the interpretation for a number with a single
+/// separator followed by 3 digits, like `"1,234"`, which may be `1.234`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InferredSeparators {
+    /// Neither a comma nor a period appears in the number.
+    Neither,
+    /// The period is the decimal point, and the comma groups digits.
+    PeriodDecimalPoint,
+    /// The comma is the decimal point, and the period groups digits.
+    CommaDecimalPoint,
+}
+
+impl InferredSeparators {
+    /// Infer the separators from the comma and period in the string.
+    ///
+    /// * `bytes`   - Byte slice containing a numeric string.
+    #[inline]
+    pub fn infer(bytes: &[u8]) -> InferredSeparators {
+        let is_separator = | c: &u8 | *c == b',' || *c == b'.';
+        let index = match bytes.iter().rposition(is_separator) {
+            Some(index) => index,
+            None        => return InferredSeparators::Neither,
+        };
+        let last = bytes[index];
+        let other = if last == b',' { b'.' } else { b',' };
+        let count = bytes.iter().filter(|&&c| c == last).count();
+        let decimal_point = match (bytes.contains(&other), count) {
+            // Rule 2: the last separator is the decimal point.
+            (true, _)   => last,
+            // Rules 4 and 5: a single separator is the decimal point,
+            // unless it is between groups of digits.
+            (false, 1)  => {
+                let leading = bytes[..index].iter().rev().take_while(|c| c.is_ascii_digit()).count();
+                let trailing = bytes[index+1..].iter().take_while(|c| c.is_ascii_digit()).count();
+                match (1..=3).contains(&leading) && trailing == 3 {
+                    true  => other,
+                    false => last,
+                }
+            },
+            // Rule 3: the only separator groups digits.
+            (false, _)  => other,
+        };
+        match decimal_point {
+            b'.' => InferredSeparators::PeriodDecimalPoint,
+            _    => InferredSeparators::CommaDecimalPoint,
+        }
+    }
+
+    /// Get the character separating the integer and fraction.
+    #[inline]
+    pub fn decimal_point(self) -> u8 {
+        match self {
+            InferredSeparators::CommaDecimalPoint => b',',
+            _                                     => b'.',
+        }
+    }
+
+    /// Get the character separating groups of digits, if present.
+    #[inline]
+    pub fn digit_separator(self) -> Option<u8> {
+        match self {
+            InferredSeparators::Neither            => None,
+            InferredSeparators::PeriodDecimalPoint => Some(b','),
+            InferredSeparators::CommaDecimalPoint  => Some(b'.'),
+        }
+    }
+
+    // Get the number format for the inferred separators.
+    perftools_inline!{
+    pub(super) fn format(self) -> NumberFormat {
+        match self {
+            InferredSeparators::Neither            => NumberFormat::standard(),
+            InferredSeparators::PeriodDecimalPoint => NumberFormat::grouped(b',', 3),
+            InferredSeparators::CommaDecimalPoint  => NumberFormat::european(b'.'),
+        }.unwrap()
+    }}
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infer_test() {
+        assert_eq!(InferredSeparators::infer(b"1234"), InferredSeparators::Neither);
+        assert_eq!(InferredSeparators::infer(b"1e5"), InferredSeparators::Neither);
+
+        // A single separator between groups of digits groups digits.
+        assert_eq!(InferredSeparators::infer(b"1,234"), InferredSeparators::PeriodDecimalPoint);
+        assert_eq!(InferredSeparators::infer(b"1.234"), InferredSeparators::CommaDecimalPoint);
+        assert_eq!(InferredSeparators::infer(b"-123,456e5"), InferredSeparators::PeriodDecimalPoint);
+
+        // Any other single separator is the decimal point.
+        assert_eq!(InferredSeparators::infer(b"1,5"), InferredSeparators::CommaDecimalPoint);
+        assert_eq!(InferredSeparators::infer(b"1.25"), InferredSeparators::PeriodDecimalPoint);
+        assert_eq!(InferredSeparators::infer(b"1,2345"), InferredSeparators::CommaDecimalPoint);
+        assert_eq!(InferredSeparators::infer(b"1234.567"), InferredSeparators::PeriodDecimalPoint);
+        assert_eq!(InferredSeparators::infer(b",567"), InferredSeparators::CommaDecimalPoint);
+
+        // Repeated separators group digits.
+        assert_eq!(InferredSeparators::infer(b"1,234,567"), InferredSeparators::PeriodDecimalPoint);
+        assert_eq!(InferredSeparators::infer(b"1.234.567"), InferredSeparators::CommaDecimalPoint);
+
+        // With both separators, the last is the decimal point.
+        assert_eq!(InferredSeparators::infer(b"1,234.56"), InferredSeparators::PeriodDecimalPoint);
+        assert_eq!(InferredSeparators::infer(b"1.234,56"), InferredSeparators::CommaDecimalPoint);
+        assert_eq!(InferredSeparators::infer(b"1.234.567,8"), InferredSeparators::CommaDecimalPoint);
+    }
+
+    #[test]
+    fn separators_test() {
+        assert_eq!(InferredSeparators::Neither.decimal_point(), b'.');
+        assert_eq!(InferredSeparators::Neither.digit_separator(), None);
+        assert_eq!(InferredSeparators::PeriodDecimalPoint.decimal_point(), b'.');
+        assert_eq!(InferredSeparators::PeriodDecimalPoint.digit_separator(), Some(b','));
+        assert_eq!(InferredSeparators::CommaDecimalPoint.decimal_point(), b',');
+        assert_eq!(InferredSeparators::CommaDecimalPoint.digit_separator(), Some(b'.'));
+    }
+}
@@ -3,7 +3,10 @@
 //! Uses either the imprecise or the precise algorithm.
 
 use crate::lib::slice;
+#[cfg(feature = "correct")]
+use crate::lib::result::Result as StdResult;
 use crate::util::*;
+use super::special::SpecialValues;
 
 // Select the back-end
 cfg_if! {
@@ -16,9 +19,24 @@ if #[cfg(feature = "correct")] {
 // TRAITS
 
 /// Trait to define parsing of a string to float.
-trait StringToFloat: Float {
+pub(super) trait StringToFloat: Float {
     /// Serialize string to float, favoring correctness.
     fn default(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat) -> ParseResult<(Self, *const u8)>;
+
+    /// Serialize string to float, divided by `radix^scale`, favoring correctness.
+    fn scaled(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, scale: i64) -> ParseResult<(Self, *const u8)>;
+
+    /// Detect if the string is parsed on the fast path.
+    #[cfg(feature = "correct")]
+    fn is_fast_path(bytes: &[u8], radix: u32, format: NumberFormat) -> ParseResult<(bool, *const u8)>;
+
+    /// Calculate the rounding error of the float parsed from the string.
+    #[cfg(feature = "correct")]
+    fn rounding_error(bytes: &[u8], radix: u32, format: NumberFormat, float: Self) -> ParseResult<(f64, *const u8)>;
+
+    /// Serialize decimal string to float, using the scratch limbs for the slow path.
+    #[cfg(feature = "correct")]
+    fn scratch(bytes: &[u8], sign: Sign, format: NumberFormat, scratch: &mut [u64]) -> ParseResult<(StdResult<Self, usize>, *const u8)>;
 }
 
 impl StringToFloat for f32 {
@@ -28,6 +46,37 @@ impl StringToFloat for f32 {
     {
         algorithm::atof(bytes, radix, lossy, sign, format)
     }}
+
+    perftools_inline_always!{
+    fn scaled(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, scale: i64)
+        -> ParseResult<(f32, *const u8)>
+    {
+        algorithm::atof_scaled(bytes, radix, lossy, sign, format, scale)
+    }}
+
+    perftools_inline_always!{
+    #[cfg(feature = "correct")]
+    fn is_fast_path(bytes: &[u8], radix: u32, format: NumberFormat)
+        -> ParseResult<(bool, *const u8)>
+    {
+        algorithm::atof_is_fast_path(bytes, radix, format)
+    }}
+
+    perftools_inline_always!{
+    #[cfg(feature = "correct")]
+    fn rounding_error(bytes: &[u8], radix: u32, format: NumberFormat, float: f32)
+        -> ParseResult<(f64, *const u8)>
+    {
+        algorithm::atof_rounding_error(bytes, radix, format, float)
+    }}
+
+    perftools_inline_always!{
+    #[cfg(feature = "correct")]
+    fn scratch(bytes: &[u8], sign: Sign, format: NumberFormat, scratch: &mut [u64])
+        -> ParseResult<(StdResult<f32, usize>, *const u8)>
+    {
+        algorithm::atof_scratch(bytes, sign, format, scratch)
+    }}
 }
 
 impl StringToFloat for f64 {
@@ -37,6 +86,37 @@ impl StringToFloat for f64 {
     {
         algorithm::atod(bytes, radix, lossy, sign, format)
     }}
+
+    perftools_inline_always!{
+    fn scaled(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, scale: i64)
+        -> ParseResult<(f64, *const u8)>
+    {
+        algorithm::atod_scaled(bytes, radix, lossy, sign, format, scale)
+    }}
+
+    perftools_inline_always!{
+    #[cfg(feature = "correct")]
+    fn is_fast_path(bytes: &[u8], radix: u32, format: NumberFormat)
+        -> ParseResult<(bool, *const u8)>
+    {
+        algorithm::atod_is_fast_path(bytes, radix, format)
+    }}
+
+    perftools_inline_always!{
+    #[cfg(feature = "correct")]
+    fn rounding_error(bytes: &[u8], radix: u32, format: NumberFormat, float: f64)
+        -> ParseResult<(f64, *const u8)>
+    {
+        algorithm::atod_rounding_error(bytes, radix, format, float)
+    }}
+
+    perftools_inline_always!{
+    #[cfg(feature = "correct")]
+    fn scratch(bytes: &[u8], sign: Sign, format: NumberFormat, scratch: &mut [u64])
+        -> ParseResult<(StdResult<f64, usize>, *const u8)>
+    {
+        algorithm::atod_scratch(bytes, sign, format, scratch)
+    }}
 }
 
 // SPECIAL
@@ -55,6 +135,24 @@ fn to_iter_s<'a>(bytes: &'a [u8], digit_separator: u8) -> SkipValueIterator<'a,
     SkipValueIterator::new(bytes, digit_separator)
 }}
 
+// Assert the returned pointer is within `[first, last]`, where `last`
+// is one-past-the-end of the bytes, for both values and errors.
+perftools_inline!{
+fn check_ptr<T>(bytes: &[u8], result: ParseResult<(T, *const u8)>)
+    -> ParseResult<(T, *const u8)>
+{
+    debug_assert!({
+        let ptr = match result {
+            Ok((_, ptr))  => ptr,
+            Err((_, ptr)) => ptr,
+        };
+        let first = bytes.as_ptr();
+        let last = bytes[bytes.len()..].as_ptr();
+        first <= ptr && ptr <= last
+    }, "returned pointer is outside of the bytes");
+    result
+}}
+
 // PARSER
 
 // Parse infinity from string.
@@ -76,7 +174,7 @@ fn parse_infinity<'a, ToIter, StartsWith, Iter, F>(
 {
     let infinity = get_infinity_string();
     let inf = get_inf_string();
-    if let (true, iter) = starts_with(to_iter(bytes, format.digit_separator()), infinity.iter()) {
+    let result = if let (true, iter) = starts_with(to_iter(bytes, format.digit_separator()), infinity.iter()) {
         Ok((F::INFINITY, iter.as_ptr()))
     } else if let (true, iter) = starts_with(to_iter(bytes, format.digit_separator()), inf.iter()) {
         Ok((F::INFINITY, iter.as_ptr()))
@@ -87,7 +185,8 @@ fn parse_infinity<'a, ToIter, StartsWith, Iter, F>(
         } else {
             Err((ErrorCode::InvalidDigit, bytes.as_ptr()))
         }
-    }
+    };
+    check_ptr(bytes, result)
 }}
 
 // Parse NaN from string.
@@ -108,7 +207,7 @@ fn parse_nan<'a, ToIter, StartsWith, Iter, F>(
           StartsWith: Fn(Iter, slice::Iter<'a, u8>) -> (bool, Iter)
 {
     let nan = get_nan_string();
-    if let (true, iter) = starts_with(to_iter(bytes, format.digit_separator()), nan.iter()) {
+    let result = if let (true, iter) = starts_with(to_iter(bytes, format.digit_separator()), nan.iter()) {
         Ok((F::NAN, iter.as_ptr()))
     } else {
         // Not NaN, may be valid with a different radix.
@@ -117,7 +216,88 @@ fn parse_nan<'a, ToIter, StartsWith, Iter, F>(
         } else {
             Err((ErrorCode::InvalidDigit, bytes.as_ptr()))
         }
+    };
+    check_ptr(bytes, result)
+}}
+
+// Parse infinity or NaN from string, using the longest accepted spelling.
+// Returns None if no special value matches, so the caller may parse
+// the bytes as a float instead.
+perftools_inline!{
+fn parse_special<'a, ToIter, StartsWith, Iter, F>(
+    bytes: &'a [u8],
+    format: NumberFormat,
+    special: &SpecialValues,
+    to_iter: ToIter,
+    starts_with: StartsWith
+)
+    -> Option<ParseResult<(F, *const u8)>>
+    where F: StringToFloat,
+          ToIter: Fn(&'a [u8], u8) -> Iter,
+          Iter: AsPtrIterator<'a, u8>,
+          StartsWith: Fn(Iter, slice::Iter<'a, u8>) -> (bool, Iter)
+{
+    let infinity = special.get_infinity().iter().map(|&s| (F::INFINITY, s));
+    let nan = special.get_nan().iter().map(|&s| (F::NAN, s));
+    let mut result: Option<(F, usize, *const u8)> = None;
+    for (value, string) in infinity.chain(nan) {
+        if let (true, iter) = starts_with(to_iter(bytes, format.digit_separator()), string.iter()) {
+            match result {
+                Some((_, length, _)) if length >= string.len() => (),
+                _ => result = Some((value, string.len(), iter.as_ptr())),
+            }
+        }
     }
+
+    // Reject a special value followed by other token characters.
+    let is_token = | c: Option<&u8> | match c {
+        Some(&c) => c.is_ascii_alphanumeric() || c == b'_',
+        None     => false,
+    };
+    result.map(|(value, _, ptr)| {
+        match special.get_complete_token() && is_token(bytes.get(distance(bytes.as_ptr(), ptr))) {
+            true  => Err((ErrorCode::InvalidDigit, ptr)),
+            false => Ok((value, ptr)),
+        }
+    })
+}}
+
+// Parse special or float values with custom special values.
+// The match is case-sensitive if set by the special values,
+// and no digit separators are allowed.
+perftools_inline!{
+#[cfg(not(feature = "format"))]
+fn parse_float_special<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, special: &SpecialValues)
+    -> ParseResult<(F, *const u8)>
+{
+    let result = match special.get_case_sensitive() {
+        true  => parse_special(bytes, format, special, to_iter, starts_with_iter),
+        false => parse_special(bytes, format, special, to_iter, case_insensitive_starts_with_iter),
+    };
+    let result = result.unwrap_or_else(|| F::default(bytes, radix, lossy, sign, format));
+    check_ptr(bytes, result)
+}}
+
+// Parse special or float values with custom special values.
+// The match is case-sensitive if set by the special values,
+// and digit separators are allowed if set by the format.
+perftools_inline!{
+#[cfg(feature = "format")]
+fn parse_float_special<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat, special: &SpecialValues)
+    -> ParseResult<(F, *const u8)>
+{
+    let no_special = format.no_special();
+    let case = special.get_case_sensitive();
+    let has_sep = format.special_digit_separator();
+    let result = match (no_special, case, has_sep) {
+        (true, _, _)            => None,
+        (false, true, true)     => parse_special(bytes, format, special, to_iter_s, starts_with_iter),
+        (false, false, true)    => parse_special(bytes, format, special, to_iter_s, case_insensitive_starts_with_iter),
+        (false, true, false)    => parse_special(bytes, format, special, to_iter, starts_with_iter),
+        (false, false, false)   => parse_special(bytes, format, special, to_iter, case_insensitive_starts_with_iter),
+    };
+    let result = result.unwrap_or_else(|| F::default(bytes, radix, lossy, sign, format));
+    check_ptr(bytes, result)
 }}
 
 // ATOF/ATOD
@@ -197,7 +377,7 @@ perftools_inline!{
 fn parse_float<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
     -> ParseResult<(F, *const u8)>
 {
-    parse_float_standard(bytes, radix, lossy, sign, format)
+    check_ptr(bytes, parse_float_standard(bytes, radix, lossy, sign, format))
 }}
 
 // Parse special or float values with the default formatter.
@@ -213,19 +393,20 @@ fn parse_float<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Si
     let no_special = format.no_special();
     let case = format.case_sensitive_special();
     let has_sep = format.special_digit_separator();
-    match (no_special, case, has_sep) {
+    let result = match (no_special, case, has_sep) {
         (true, _, _)            => F::default(bytes, radix, lossy, sign, format),
         (false, true, true)     => parse_float_cs(bytes, radix, lossy, sign, format),
         (false, false, true)    => parse_float_s(bytes, radix, lossy, sign, format),
         (false, true, false)    => parse_float_c(bytes, radix, lossy, sign, format),
         (false, false, false)   => parse_float_standard(bytes, radix, lossy, sign, format),
-    }
+    };
+    check_ptr(bytes, result)
 }}
 
 // Validate sign byte is valid.
 perftools_inline!{
 #[cfg(not(feature = "format"))]
-fn validate_sign(_: &[u8], _: &[u8], _: Sign, _: NumberFormat)
+pub(super) fn validate_sign(_: &[u8], _: &[u8], _: Sign, _: NumberFormat)
     -> ParseResult<()>
 {
     Ok(())
@@ -234,7 +415,7 @@ fn validate_sign(_: &[u8], _: &[u8], _: Sign, _: NumberFormat)
 // Validate sign byte is valid.
 perftools_inline!{
 #[cfg(feature = "format")]
-fn validate_sign(bytes: &[u8], digits: &[u8], sign: Sign, format: NumberFormat)
+pub(super) fn validate_sign(bytes: &[u8], digits: &[u8], sign: Sign, format: NumberFormat)
     -> ParseResult<()>
 {
     let has_sign = bytes.as_ptr() != digits.as_ptr();
@@ -249,7 +430,7 @@ fn validate_sign(bytes: &[u8], digits: &[u8], sign: Sign, format: NumberFormat)
 
 // Convert float to signed representation.
 perftools_inline!{
-fn to_signed<F: StringToFloat>(float: F, sign: Sign) -> F
+pub(super) fn to_signed<F: StringToFloat>(float: F, sign: Sign) -> F
 {
     match sign {
         Sign::Positive => float,
@@ -259,19 +440,138 @@ fn to_signed<F: StringToFloat>(float: F, sign: Sign) -> F
 
 // Standalone atof processor.
 perftools_inline!{
-fn atof<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, format: NumberFormat)
+pub(super) fn atof<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, format: NumberFormat)
     -> ParseResult<(F, *const u8)>
 {
     let (sign, digits) = parse_sign::<F>(bytes, format);
     if digits.is_empty() {
         return Err((ErrorCode::Empty, digits.as_ptr()));
     }
+    validate_sign_sequence(bytes, digits)?;
     let (float, ptr): (F, *const u8) = parse_float(digits, radix, lossy, sign, format)?;
     validate_sign(bytes, digits, sign, format)?;
 
     Ok((to_signed(float, sign), ptr))
 }}
 
+// Standalone atof processor with custom special values.
+perftools_inline!{
+pub(super) fn atof_special<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, format: NumberFormat, special: &SpecialValues)
+    -> ParseResult<(F, *const u8)>
+{
+    let (sign, digits) = parse_sign::<F>(bytes, format);
+    if digits.is_empty() {
+        return Err((ErrorCode::Empty, digits.as_ptr()));
+    }
+    validate_sign_sequence(bytes, digits)?;
+    let (float, ptr): (F, *const u8) = parse_float_special(digits, radix, lossy, sign, format, special)?;
+    validate_sign(bytes, digits, sign, format)?;
+
+    Ok((to_signed(float, sign), ptr))
+}}
+
+// Standalone atof processor for a magnitude with a sign parsed by the caller.
+//
+// The magnitude must not contain a sign, which is an invalid digit.
+// Negative zero is preserved, since the sign is applied after parsing.
+perftools_inline!{
+pub(super) fn atof_signed<F: StringToFloat>(digits: &[u8], radix: u32, lossy: bool, format: NumberFormat, special: Option<&SpecialValues>, sign: Sign)
+    -> ParseResult<(F, *const u8)>
+{
+    if digits.is_empty() {
+        return Err((ErrorCode::Empty, digits.as_ptr()));
+    }
+    let (float, ptr): (F, *const u8) = match special {
+        Some(special) => parse_float_special(digits, radix, lossy, sign, format, special)?,
+        None          => parse_float(digits, radix, lossy, sign, format)?,
+    };
+
+    Ok((to_signed(float, sign), ptr))
+}}
+
+// Standalone atof processor for decimal strings, using the scratch limbs
+// for the slow path.
+//
+// Returns the number of limbs required if the scratch limbs are too
+// small. Special values and invalid floats fail to extract the digits,
+// and are parsed by the generic parser, which never reaches the slow
+// path for them.
+perftools_inline!{
+#[cfg(feature = "correct")]
+pub(super) fn atof_scratch<F: StringToFloat>(bytes: &[u8], format: NumberFormat, scratch: &mut [u64])
+    -> ParseResult<(StdResult<F, usize>, *const u8)>
+{
+    let (sign, digits) = parse_sign::<F>(bytes, format);
+    if digits.is_empty() {
+        return Err((ErrorCode::Empty, digits.as_ptr()));
+    }
+    match F::scratch(digits, sign, format, scratch) {
+        Ok((float, ptr)) => {
+            validate_sign(bytes, digits, sign, format)?;
+            Ok((float.map(|float| to_signed(float, sign)), ptr))
+        },
+        Err(_)           => {
+            let (float, ptr) = atof::<F>(bytes, 10, false, format)?;
+            Ok((Ok(float), ptr))
+        },
+    }
+}}
+
+// Standalone atof processor for both 32-bit and 64-bit floats, sharing
+// the extracted digits.
+//
+// Special values and invalid floats fail to extract the digits, and are
+// parsed by the generic parser for each float.
+perftools_inline!{
+#[cfg(feature = "correct")]
+pub(super) fn atof_both(bytes: &[u8], format: NumberFormat)
+    -> ParseResult<((f32, f64), *const u8)>
+{
+    let (sign, digits) = parse_sign::<f64>(bytes, format);
+    if digits.is_empty() {
+        return Err((ErrorCode::Empty, digits.as_ptr()));
+    }
+    match algorithm::atofd_both(digits, sign, format) {
+        Ok(((float, double), ptr)) => {
+            validate_sign(bytes, digits, sign, format)?;
+            Ok(((to_signed(float, sign), to_signed(double, sign)), ptr))
+        },
+        Err(_)                     => {
+            let (float, _) = atof::<f32>(bytes, 10, false, format)?;
+            let (double, ptr) = atof::<f64>(bytes, 10, false, format)?;
+            Ok(((float, double), ptr))
+        },
+    }
+}}
+
+// Detect if the float string is parsed on the fast path.
+perftools_inline!{
+#[cfg(feature = "correct")]
+pub(super) fn is_fast_path<F: StringToFloat>(bytes: &[u8], radix: u32, format: NumberFormat)
+    -> ParseResult<(bool, *const u8)>
+{
+    let (sign, digits) = parse_sign::<F>(bytes, format);
+    if digits.is_empty() {
+        return Err((ErrorCode::Empty, digits.as_ptr()));
+    }
+    validate_sign(bytes, digits, sign, format)?;
+    F::is_fast_path(digits, radix, format)
+}}
+
+// Calculate the rounding error of the float parsed from the string.
+perftools_inline!{
+#[cfg(feature = "correct")]
+pub(super) fn rounding_error<F: StringToFloat>(bytes: &[u8], radix: u32, format: NumberFormat, float: F)
+    -> ParseResult<(f64, *const u8)>
+{
+    let (sign, digits) = parse_sign::<F>(bytes, format);
+    let (error, ptr) = F::rounding_error(digits, radix, format, float.abs())?;
+    match sign {
+        Sign::Positive => Ok((error, ptr)),
+        Sign::Negative => Ok((-error, ptr)),
+    }
+}}
+
 perftools_inline!{
 fn atof_lossy<F: StringToFloat>(bytes: &[u8], radix: u32)
     -> Result<(F, usize)>
@@ -340,6 +640,7 @@ if #[cfg(feature = "format")] {
 #[cfg(test)]
 mod tests {
     use crate::util::*;
+    use super::*;
 
     #[test]
     fn f32_decimal_test() {
@@ -403,8 +704,8 @@ mod tests {
         assert_eq!(Err(ErrorCode::Empty.into()), f32::from_lexical(b""));
         assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f32::from_lexical(b"e"));
         assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f32::from_lexical(b"E"));
-        assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f32::from_lexical(b".e1"));
-        assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f32::from_lexical(b".e-1"));
+        assert_eq!(Err(ErrorCode::LoneDot.into()), f32::from_lexical(b".e1"));
+        assert_eq!(Err(ErrorCode::LoneDot.into()), f32::from_lexical(b".e-1"));
         assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f32::from_lexical(b"e1"));
         assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f32::from_lexical(b"e-1"));
         assert_eq!(Err((ErrorCode::Empty, 1).into()), f32::from_lexical(b"+"));
@@ -522,25 +823,25 @@ mod tests {
         assert_eq!(Err(ErrorCode::Empty.into()), f64::from_lexical(b""));
         assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"e"));
         assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"E"));
-        assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f64::from_lexical(b".e1"));
-        assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f64::from_lexical(b".e-1"));
+        assert_eq!(Err(ErrorCode::LoneDot.into()), f64::from_lexical(b".e1"));
+        assert_eq!(Err(ErrorCode::LoneDot.into()), f64::from_lexical(b".e-1"));
         assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"e1"));
         assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"e-1"));
 
         // Check various reports from a fuzzer.
         assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical(b"0e"));
         assert_eq!(Err((ErrorCode::EmptyExponent, 4).into()), f64::from_lexical(b"0.0e"));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b".E"));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b".e"));
+        assert_eq!(Err((ErrorCode::LoneDot, 0).into()), f64::from_lexical(b".E"));
+        assert_eq!(Err((ErrorCode::LoneDot, 0).into()), f64::from_lexical(b".e"));
         assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"E2252525225"));
         assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"e2252525225"));
         assert_eq!(Ok(f64::INFINITY), f64::from_lexical(b"2E200000000000"));
 
         // Add various unittests from proptests.
         assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical(b"0e"));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"."));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical(b"+."));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical(b"-."));
+        assert_eq!(Err((ErrorCode::LoneDot, 0).into()), f64::from_lexical(b"."));
+        assert_eq!(Err((ErrorCode::LoneDot, 1).into()), f64::from_lexical(b"+."));
+        assert_eq!(Err((ErrorCode::LoneDot, 1).into()), f64::from_lexical(b"-."));
         assert_eq!(Err((ErrorCode::Empty, 1).into()), f64::from_lexical(b"+"));
         assert_eq!(Err((ErrorCode::Empty, 1).into()), f64::from_lexical(b"-"));
 
@@ -548,6 +849,245 @@ mod tests {
         assert_eq!(Ok(5.002868148396374), f64::from_lexical(b"5.002868148396374"));
     }
 
+    #[test]
+    fn f64_dot_exponent_test() {
+        // A dot immediately followed by the exponent, without fraction digits.
+        assert_eq!(Ok(100000.0), f64::from_lexical(b"1.e5"));
+        assert_eq!(Ok(100000.0), f64::from_lexical(b"1.e+5"));
+        assert_eq!(Ok(-100000.0), f64::from_lexical(b"-1.e5"));
+        assert_eq!(Ok(0.001), f64::from_lexical(b"1.E-3"));
+        assert_eq!(Ok(0.0), f64::from_lexical(b"0.e0"));
+        assert_eq!(Ok((100000.0, 4)), f64::from_lexical_partial(b"1.e5"));
+        assert_eq!(Ok((0.001, 5)), f64::from_lexical_partial(b"1.E-3"));
+        assert_eq!(Ok((100000.0, 4)), f64::from_lexical_partial(b"1.e5x"));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 3).into()), f64::from_lexical(b"1.e"));
+        assert_eq!(Err((ErrorCode::LoneDot, 0).into()), f64::from_lexical(b".e5"));
+        assert_eq!(Err((ErrorCode::LoneDot, 1).into()), f64::from_lexical(b"-.e5"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn f64_fraction_boundary_test() {
+        // Each input is copied to an allocation of the exact length, so
+        // reading the fraction past the last byte is caught by Miri.
+        let parse = | bytes: &[u8] | f64::from_lexical(&bytes.to_vec());
+        let parse_partial = | bytes: &[u8] | f64::from_lexical_partial(&bytes.to_vec());
+        let parse_lossy = | bytes: &[u8] | f64::from_lexical_lossy(&bytes.to_vec());
+
+        // The integer consumes every byte, without a fraction.
+        assert_eq!(Ok(1.0), parse(b"1"));
+        assert_eq!(Ok(-12.0), parse(b"-12"));
+        assert_eq!(Ok(123.0), parse_lossy(b"123"));
+        assert_eq!(Ok((123.0, 3)), parse_partial(b"123"));
+        assert_eq!(Ok(123.0), f32::from_lexical(&b"123".to_vec()));
+
+        // Exactly one byte remains after the integer.
+        assert_eq!(Ok(1.0), parse(b"1."));
+        assert_eq!(Ok(-12.0), parse(b"-12."));
+        assert_eq!(Ok(12.0), parse_lossy(b"12."));
+        assert_eq!(Ok((12.0, 3)), parse_partial(b"12."));
+        assert_eq!(Ok((12.0, 2)), parse_partial(b"12x"));
+        assert_eq!(Ok(12.0), f32::from_lexical(&b"12.".to_vec()));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), parse(b"1e"));
+        assert_eq!(Err((ErrorCode::LoneDot, 0).into()), parse(b"."));
+        assert_eq!(Err((ErrorCode::LoneDot, 1).into()), parse(b"-."));
+    }
+
+    #[test]
+    fn f64_error_code_test() {
+        // Each malformed input returns the most specific error.
+        let err = | code, index | -> Result<f64> { Err((code, index).into()) };
+        assert_eq!(err(ErrorCode::Empty, 0), f64::from_lexical(b""));
+        assert_eq!(err(ErrorCode::Empty, 1), f64::from_lexical(b"-"));
+        assert_eq!(err(ErrorCode::EmptyMantissa, 0), f64::from_lexical(b"e5"));
+        assert_eq!(err(ErrorCode::EmptyMantissa, 1), f64::from_lexical(b"+x"));
+        assert_eq!(err(ErrorCode::LoneDot, 0), f64::from_lexical(b"."));
+        assert_eq!(err(ErrorCode::LoneDot, 1), f64::from_lexical(b"-."));
+        assert_eq!(err(ErrorCode::LoneDot, 0), f64::from_lexical(b".e5"));
+        assert_eq!(err(ErrorCode::EmptyExponent, 2), f64::from_lexical(b"1e"));
+        assert_eq!(err(ErrorCode::EmptyExponent, 4), f64::from_lexical(b"1.5e-"));
+        assert_eq!(err(ErrorCode::InvalidDigit, 1), f64::from_lexical(b"1x5"));
+        assert_eq!(err(ErrorCode::InvalidDigit, 3), f64::from_lexical(b"1.5 "));
+        assert_eq!(Ok((1.5, 3)), f64::from_lexical_partial(b"1.5 "));
+        assert_eq!(Ok((1.0, 2)), f64::from_lexical_partial(b"1.x"));
+        assert_eq!(Err((ErrorCode::LoneDot, 0).into()), f64::from_lexical_partial(b".x"));
+    }
+
+    #[test]
+    fn f64_extreme_exponent_test() {
+        // Exponents outside the range of an i32.
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical(b"1e2147483648"));
+        assert_eq!(Ok(0.0), f64::from_lexical(b"1e-2147483649"));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical(b"0.00001e2147483650"));
+        assert_eq!(Ok(0.0), f64::from_lexical(b"100000e-2147483650"));
+
+        // Exponents outside the range of an i64.
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical(b"1e9223372036854775808"));
+        assert_eq!(Ok(0.0), f64::from_lexical(b"1e-9223372036854775809"));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical(b"0.1e99999999999999999999"));
+        assert_eq!(Ok(0.0), f64::from_lexical(b"10e-99999999999999999999"));
+        assert_eq!(Ok(0.0), f64::from_lexical(b"0e99999999999999999999"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn f64_long_exponent_test() {
+        // The exponent saturates after the first digits, and the
+        // remaining digits are only validated.
+        let digits = 1_000_000;
+        let mut bytes = b"1e".to_vec();
+        bytes.resize(digits + 2, b'9');
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical(&bytes));
+        assert_eq!(Ok(f32::INFINITY), f32::from_lexical(&bytes));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_lossy(&bytes));
+
+        // The entire exponent is consumed, and trailing bytes are still rejected.
+        bytes.push(b'x');
+        assert_eq!(Ok((f64::INFINITY, digits + 2)), f64::from_lexical_partial(&bytes));
+        assert_eq!(Err((ErrorCode::InvalidDigit, digits + 2).into()), f64::from_lexical(&bytes));
+
+        let mut bytes = b"1e-".to_vec();
+        bytes.resize(digits + 3, b'9');
+        assert_eq!(Ok(0.0), f64::from_lexical(&bytes));
+        assert_eq!(Ok(0.0), f32::from_lexical(&bytes));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn f64_long_fraction_test() {
+        // Leading fraction zeros far past the range of the exponent.
+        let zeros = 2_000_000;
+        let mut bytes = b"0.".to_vec();
+        bytes.resize(zeros + 2, b'0');
+        bytes.push(b'1');
+        assert_eq!(Ok(0.0), f64::from_lexical(&bytes));
+        assert_eq!(Ok(0.0), f32::from_lexical(&bytes));
+        assert_eq!(Ok(0.0), f64::from_lexical_lossy(&bytes));
+        assert_eq!(Ok((0.0, bytes.len())), f64::from_lexical_partial(&bytes));
+
+        // The exponent is still applied after the fraction.
+        let mut scaled = bytes.clone();
+        scaled.extend_from_slice(format!("e{}", zeros).as_bytes());
+        assert_eq!(Ok(0.1), f64::from_lexical(&scaled));
+        assert_eq!(Ok(0.1), f64::from_lexical_lossy(&scaled));
+
+        // Trailing integer zeros past the range of the exponent.
+        let mut integer = vec![b'1'];
+        integer.resize(zeros + 1, b'0');
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical(&integer));
+        integer.extend_from_slice(format!("e-{}", zeros).as_bytes());
+        assert_eq!(Ok(1.0), f64::from_lexical(&integer));
+    }
+
+    #[test]
+    fn consumed_length_test() {
+        // The exponent is consumed for zero, like any other mantissa,
+        // regardless of the algorithm used to parse the float.
+        let cases: [(&[u8], &[u8]); 7] = [
+            (b"0e5", b"1e5"),
+            (b"0e5,", b"1e5,"),
+            (b"0.0e-5x", b"1.0e-5x"),
+            (b"-0E+5 ", b"-1E+5 "),
+            (b"0e400", b"1e400"),
+            (b"0e99999999999999999999", b"1e99999999999999999999"),
+            (b"0.000000000000000000000e-330", b"2.225073858507201136057409796709131975934819546351645648e-330"),
+        ];
+        for &(zero, nonzero) in cases.iter() {
+            let digits = |s: &[u8]| s.iter().take_while(|&&c| c != b',' && c != b'x' && c != b' ').count();
+            assert_eq!(digits(zero), f64::from_lexical_partial(zero).unwrap().1);
+            assert_eq!(digits(nonzero), f64::from_lexical_partial(nonzero).unwrap().1);
+            assert_eq!(digits(zero), f32::from_lexical_partial(zero).unwrap().1);
+            assert_eq!(digits(nonzero), f32::from_lexical_partial(nonzero).unwrap().1);
+            assert_eq!(digits(zero), f64::from_lexical_partial_lossy(zero).unwrap().1);
+            assert_eq!(digits(nonzero), f64::from_lexical_partial_lossy(nonzero).unwrap().1);
+        }
+    }
+
+    #[test]
+    fn consumed_length_dot_test() {
+        // The consumed length is the literal length of the input, and
+        // does not include the implicit integer or fraction digits.
+        let cases: [(&[u8], f64, usize); 10] = [
+            (b".5", 0.5, 2),
+            (b".5x", 0.5, 2),
+            (b"-.5,", -0.5, 3),
+            (b"+.25 ", 0.25, 4),
+            (b".5e1", 5.0, 4),
+            (b"5.", 5.0, 2),
+            (b"5.x", 5.0, 2),
+            (b"-5.,", -5.0, 3),
+            (b"5.e1", 50.0, 4),
+            (b"0.5", 0.5, 3),
+        ];
+        for &(bytes, value, processed) in cases.iter() {
+            assert_eq!(Ok((value, processed)), f64::from_lexical_partial(bytes));
+            assert_eq!(Ok((value as f32, processed)), f32::from_lexical_partial(bytes));
+            assert_eq!(Ok((value, processed)), f64::from_lexical_partial_lossy(bytes));
+        }
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn consumed_length_radix_test() {
+        // Power-of-two and non-power-of-two radixes use different algorithms.
+        for &radix in [2, 3, 10, 16, 36].iter() {
+            let exponent = exponent_notation_char(radix.as_u32());
+            for &mantissa in [b'0', b'1'].iter() {
+                let bytes = [mantissa, exponent, b'1', b'0', b'1', b','];
+                assert_eq!(5, f64::from_lexical_partial_radix(&bytes, radix).unwrap().1);
+                assert_eq!(5, f32::from_lexical_partial_radix(&bytes, radix).unwrap().1);
+            }
+        }
+    }
+
+    #[test]
+    fn parse_float_ptr_bounds_test() {
+        // Each parser checks the returned pointer is within the bytes,
+        // for values and errors, so exercise every branch.
+        fn check<F: StringToFloat>(bytes: &[u8], radix: u32, format: NumberFormat, special: &SpecialValues) {
+            let index = | result: ParseResult<(F, *const u8)> | match result {
+                Ok((_, ptr))  => distance(bytes.as_ptr(), ptr),
+                Err((_, ptr)) => distance(bytes.as_ptr(), ptr),
+            };
+            assert!(index(parse_float::<F>(bytes, radix, false, Sign::Positive, format)) <= bytes.len());
+            assert!(index(parse_float::<F>(bytes, radix, true, Sign::Negative, format)) <= bytes.len());
+            assert!(index(parse_float_special::<F>(bytes, radix, false, Sign::Positive, format, special)) <= bytes.len());
+        }
+
+        let cases: [&[u8]; 18] = [
+            b"1", b"1.5e3x", b"e5", b"1e", b".", b"1_0",
+            b"inf", b"Infinity", b"infx", b"i", b"ix", b"i_nf",
+            b"nan", b"NaN1", b"n", b"nx", b"n_an", b"nanx",
+        ];
+        let specials = [
+            SpecialValues::lenient(),
+            SpecialValues::strict().complete_token(true),
+            SpecialValues::json5().case_sensitive(true),
+        ];
+        let mut formats = vec![NumberFormat::standard().unwrap()];
+        #[cfg(feature = "format")] {
+            let ignore = NumberFormat::ignore(b'_').unwrap();
+            formats.push(ignore);
+            formats.push(formats[0] | NumberFormat::NO_SPECIAL);
+            formats.push(formats[0] | NumberFormat::CASE_SENSITIVE_SPECIAL);
+            formats.push(ignore | NumberFormat::CASE_SENSITIVE_SPECIAL);
+        }
+        let radixes: &[u32] = match cfg!(feature = "radix") {
+            true  => &[2, 10, 16, 36],
+            false => &[10],
+        };
+        for &bytes in cases.iter() {
+            for &format in formats.iter() {
+                for special in specials.iter() {
+                    for &radix in radixes.iter() {
+                        check::<f32>(bytes, radix, format, special);
+                        check::<f64>(bytes, radix, format, special);
+                    }
+                }
+            }
+        }
+    }
+
     #[test]
     #[should_panic]
     fn limit_test() {
@@ -561,9 +1101,131 @@ mod tests {
         assert_f64_eq!(1234.0, f64::from_lexical_lossy_radix(b"YA", 36).unwrap());
     }
 
+    #[cfg(feature = "radix")]
+    #[test]
+    fn f64_radix_exponent_test() {
+        // The backup exponent is signed, and parsed in the mantissa's radix.
+        assert_eq!(Ok(15f64.powi(30)), f64::from_lexical_radix(b"1^20", 15));
+        assert_eq!(Ok(15f64.powi(30)), f64::from_lexical_radix(b"1^+20", 15));
+        assert_eq!(Ok(15f64.powi(-30)), f64::from_lexical_radix(b"1^-20", 15));
+        assert_eq!(Ok(2f64.powi(1020)), f64::from_lexical_radix(b"1^FF", 16));
+        assert_eq!(Ok(2f64.powi(-1020)), f64::from_lexical_radix(b"1^-ff", 16));
+        assert_eq!(Ok(384.0), f64::from_lexical_radix(b"1.8^2", 16));
+
+        // The default exponent character is a digit in these radixes.
+        assert_eq!(Ok(437.0), f64::from_lexical_radix(b"1e2", 15));
+
+        // Exponents without digits are invalid.
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical_radix(b"1^", 15));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical_radix(b"1^-", 15));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical_radix(b"1^G", 16));
+    }
+
+    #[test]
+    fn f64_empty_mantissa_exponent_test() {
+        // An exponent without a mantissa is an error, and is never consumed.
+        let err = | index | -> Result<(f64, usize)> { Err((ErrorCode::EmptyMantissa, index).into()) };
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"e5"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"E-3"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical(b"-e5"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_lossy(b"E-3"));
+        assert_eq!(err(0), f64::from_lexical_partial(b"e5"));
+        assert_eq!(err(0), f64::from_lexical_partial(b"E-3"));
+        assert_eq!(err(1), f64::from_lexical_partial(b"+E3"));
+        assert_eq!(err(0), f64::from_lexical_partial_lossy(b"e5"));
+
+        // A zero mantissa still consumes the exponent.
+        assert_eq!(Ok((0.0, 3)), f64::from_lexical_partial(b"0e5"));
+        assert_eq!(Ok((-0.0, 5)), f64::from_lexical_partial(b"-0E-3"));
+    }
+
+    #[test]
+    fn f64_sign_sequence_test() {
+        // A single sign is valid.
+        assert_eq!(Ok(5.0), f64::from_lexical(b"+5"));
+        assert_eq!(Ok(-5.0), f64::from_lexical(b"-5"));
+        assert_eq!(Ok((-5.0, 2)), f64::from_lexical_partial(b"-5"));
+
+        // A second sign is an error at the second sign.
+        let err = | index | -> Result<f64> { Err((ErrorCode::InvalidSign, index).into()) };
+        assert_eq!(err(1), f64::from_lexical(b"--5"));
+        assert_eq!(err(1), f64::from_lexical(b"++5"));
+        assert_eq!(err(1), f64::from_lexical(b"+-5"));
+        assert_eq!(err(1), f64::from_lexical(b"-+5"));
+        assert_eq!(err(1), f64::from_lexical(b"--inf"));
+        assert_eq!(err(1), f64::from_lexical_lossy(b"--5"));
+        assert_eq!(Err((ErrorCode::InvalidSign, 1).into()), f64::from_lexical_partial(b"+-5"));
+
+        // A lone sign has no digits.
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), f64::from_lexical(b"-"));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), f64::from_lexical(b"+"));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), f32::from_lexical_partial(b"-"));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn f64_empty_mantissa_exponent_format_test() {
+        // The most permissive format still requires mantissa digits.
+        let format = NumberFormat::ignore(b'_').unwrap();
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_format(b"e5", format));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_format(b"E-3", format));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_partial_format(b"E-3", format));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn f64_radix_empty_mantissa_exponent_test() {
+        // The backup exponent character follows the same rule.
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_radix(b"^2", 16));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_radix(b"^-3", 36));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_partial_radix(b"^2", 16));
+        assert_eq!(Ok((0.0, 3)), f64::from_lexical_partial_radix(b"0^2", 16));
+    }
+
+    #[cfg(all(feature = "format", feature = "radix"))]
+    #[test]
+    fn f64_radix_exponent_base_10_test() {
+        // The exponent digits are decimal, but the exponent is a power of the radix.
+        let format = NumberFormat::STANDARD | NumberFormat::EXPONENT_BASE_10;
+        // 20 is `15` in radix 15.
+        assert_eq!(f64::from_lexical_radix(b"1^15", 15), f64::from_lexical_format_radix(b"1^20", 15, format));
+        assert_eq!(f64::from_lexical_radix(b"1^-15", 15), f64::from_lexical_format_radix(b"1^-20", 15, format));
+        assert_eq!(Ok(15f64.powi(30)), f64::from_lexical_format_radix(b"1^20", 15, NumberFormat::STANDARD));
+        assert_eq!(Ok(2f64.powi(40)), f64::from_lexical_format_radix(b"1^10", 16, format));
+        assert_eq!(Ok(1e20), f64::from_lexical_format_radix(b"1e20", 10, format));
+
+        // Digits of the radix are not valid exponent digits.
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical_format_radix(b"1^FF", 16, format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), f64::from_lexical_format_radix(b"1^2A", 15, format));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn f64_radix_truncated_test() {
+        // Halfway, with non-zero truncated digits, rounds up.
+        let value = f64::from_lexical_radix(b"200000000000010000000001", 16).unwrap();
+        assert_eq!(0x45C0000000000001, value.to_bits());
+        let value = f64::from_lexical_radix(b"200000000000010000000000", 16).unwrap();
+        assert_eq!(0x45C0000000000000, value.to_bits());
+
+        // Denormal results round with the truncated digits.
+        let value = f64::from_lexical_radix(b"20000000000000001^-11d", 16).unwrap();
+        assert_eq!(0x1, value.to_bits());
+        let value = f64::from_lexical_radix(b"20000000000000000^-11d", 16).unwrap();
+        assert_eq!(0x0, value.to_bits());
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn f64_invalid_radix_test() {
+        assert_eq!(Err(ErrorCode::InvalidRadix.into()), f64::from_lexical_radix(b"1.0", 0));
+        assert_eq!(Err(ErrorCode::InvalidRadix.into()), f64::from_lexical_partial_lossy_radix(b"1.0", 1));
+        assert_eq!(Err(ErrorCode::InvalidRadix.into()), f64::from_lexical_radix(b"1.0", 37));
+    }
+
     #[test]
     fn f32_lossy_decimal_test() {
-        assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f32::from_lexical_lossy(b"."));
+        assert_eq!(Err(ErrorCode::LoneDot.into()), f32::from_lexical_lossy(b"."));
         assert_eq!(Err(ErrorCode::Empty.into()), f32::from_lexical_lossy(b""));
         assert_eq!(Ok(0.0), f32::from_lexical_lossy(b"0.0"));
         assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f32::from_lexical_lossy(b"1a"));
@@ -574,7 +1236,7 @@ mod tests {
 
     #[test]
     fn f64_lossy_decimal_test() {
-        assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f64::from_lexical_lossy(b"."));
+        assert_eq!(Err(ErrorCode::LoneDot.into()), f64::from_lexical_lossy(b"."));
         assert_eq!(Err(ErrorCode::Empty.into()), f64::from_lexical_lossy(b""));
         assert_eq!(Ok(0.0), f64::from_lexical_lossy(b"0.0"));
         assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f64::from_lexical_lossy(b"1a"));
@@ -583,6 +1245,274 @@ mod tests {
         assert_eq!(Ok(5.002868148396374), f64::from_lexical_lossy(b"5.002868148396374"));
     }
 
+    // Distance in ULPs between two finite floats with the same sign.
+    #[cfg(feature = "correct")]
+    macro_rules! ulp_distance {
+        ($x:expr, $y:expr) => {{
+            let (x, y) = ($x.to_bits(), $y.to_bits());
+            if x > y { x - y } else { y - x }
+        }};
+    }
+
+    // Advance the Xorshift64 state, to generate pseudo-random samples.
+    #[cfg(feature = "correct")]
+    fn xorshift64(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    // Generate a decimal string with many significant digits from a seed.
+    #[cfg(feature = "correct")]
+    fn lossy_sample(state: &mut u64, max_exp: u64) -> String {
+        let digits = 1 + (xorshift64(state) % 40) as usize;
+        let mut sample = String::new();
+        for _ in 0..digits {
+            sample.push((b'0' + (xorshift64(state) % 10) as u8) as char);
+        }
+        let exp = (xorshift64(state) % (2 * max_exp)) as i64 - max_exp as i64;
+        sample.push_str(&format!("e{}", exp));
+        sample
+    }
+
+    #[test]
+    #[cfg(feature = "correct")]
+    fn f32_lossy_error_bound_test() {
+        let mut state = 0x2545F4914F6CDD1D;
+        for _ in 0..20000 {
+            let sample = lossy_sample(&mut state, 90);
+            let exact = f32::from_lexical(sample.as_bytes()).unwrap();
+            let lossy = f32::from_lexical_lossy(sample.as_bytes()).unwrap();
+            assert!(ulp_distance!(exact, lossy) <= 1, "{}", sample);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "correct")]
+    fn f64_lossy_error_bound_test() {
+        let mut state = 0x2545F4914F6CDD1D;
+        for _ in 0..20000 {
+            let sample = lossy_sample(&mut state, 360);
+            let exact = f64::from_lexical(sample.as_bytes()).unwrap();
+            let lossy = f64::from_lexical_lossy(sample.as_bytes()).unwrap();
+            assert!(ulp_distance!(exact, lossy) <= 1, "{}", sample);
+        }
+
+        // Halfway cases, which require the slow path to round correctly.
+        let halfway: [&[u8]; 4] = [
+            b"9007199254740993",
+            b"9007199254740992.999999999999999999999999999999",
+            b"2.4703282292062328e-324",
+            b"1.7976931348623158079372897140530341507993413271003782693617377898044496829276475094664736e308",
+        ];
+        for &sample in halfway.iter() {
+            let exact = f64::from_lexical(sample).unwrap();
+            let lossy = f64::from_lexical_lossy(sample).unwrap();
+            assert!(ulp_distance!(exact, lossy) <= 1);
+        }
+    }
+
+    // Multiply a little-endian, base 10^9 big integer by a small factor.
+    #[cfg(feature = "correct")]
+    fn decimal_imul(limbs: &mut Vec<u64>, factor: u64) {
+        let mut carry = 0;
+        for limb in limbs.iter_mut() {
+            let value = *limb * factor + carry;
+            *limb = value % 1_000_000_000;
+            carry = value / 1_000_000_000;
+        }
+        while carry != 0 {
+            limbs.push(carry % 1_000_000_000);
+            carry /= 1_000_000_000;
+        }
+    }
+
+    // Generate the exact decimal digits and exponent of the midpoint
+    // between a positive float and the next float.
+    #[cfg(feature = "correct")]
+    fn halfway_sample<F: Float>(f: F) -> (String, i32) {
+        // The midpoint is `(2*mant + 1) * 2^(exp-1)`, or, for negative
+        // binary exponents, `(2*mant + 1) * 5^(1-exp) * 10^(exp-1)`.
+        let mant = f.mantissa().as_u64() * 2 + 1;
+        let exp = f.exponent() - 1;
+        let mut limbs = vec![mant % 1_000_000_000, mant / 1_000_000_000];
+        let (base, count, max_count) = match exp >= 0 {
+            true  => (2, exp, 29),
+            false => (5, -exp, 12),
+        };
+        let mut remaining = count;
+        while remaining > 0 {
+            let step = remaining.min(max_count);
+            decimal_imul(&mut limbs, (base as u64).pow(step as u32));
+            remaining -= step;
+        }
+
+        let mut digits = String::new();
+        for (index, limb) in limbs.iter().rev().enumerate() {
+            match index {
+                0 => digits.push_str(&format!("{}", limb)),
+                _ => digits.push_str(&format!("{:09}", limb)),
+            }
+        }
+        let digits = digits.trim_start_matches('0').to_string();
+        (digits, exp.min(0))
+    }
+
+    // Subtract 1 from the decimal digits, which must be non-zero.
+    #[cfg(feature = "correct")]
+    fn decimal_decrement(digits: &str) -> String {
+        let mut bytes = digits.as_bytes().to_vec();
+        let mut index = bytes.len() - 1;
+        while bytes[index] == b'0' {
+            bytes[index] = b'9';
+            index -= 1;
+        }
+        bytes[index] -= 1;
+        String::from_utf8(bytes).unwrap()
+    }
+
+    // Check exactly halfway samples round to even, and samples just
+    // above or below halfway round to the nearest float.
+    #[cfg(feature = "correct")]
+    macro_rules! halfway_round_even_test {
+        ($f:ty, $state:expr, $count:expr) => {{
+            let mut state: u64 = $state;
+            let mut samples = 0;
+            while samples < $count {
+                // Random bits, to sample floats across all exponents.
+                let bits = xorshift64(&mut state);
+                let f = <$f>::from_bits(as_cast(bits >> (64 - <$f>::BITS)));
+                let f = f.abs();
+                if !f.is_finite() || !f.next().is_finite() {
+                    continue;
+                }
+                samples += 1;
+
+                let even = match f.to_bits().is_odd() {
+                    true  => f.next(),
+                    false => f,
+                };
+                let (digits, exp) = halfway_sample(f);
+                let halfway = format!("{}e{}", digits, exp);
+                assert_eq!(Ok(even), <$f>::from_lexical(halfway.as_bytes()), "{}", halfway);
+                let halfway = format!("-{}e{}", digits, exp);
+                assert_eq!(Ok(-even), <$f>::from_lexical(halfway.as_bytes()), "{}", halfway);
+
+                let above = format!("{}1e{}", digits, exp - 1);
+                assert_eq!(Ok(f.next()), <$f>::from_lexical(above.as_bytes()), "{}", above);
+                let below = format!("{}e{}", decimal_decrement(&format!("{}0", digits)), exp - 1);
+                assert_eq!(Ok(f), <$f>::from_lexical(below.as_bytes()), "{}", below);
+            }
+        }};
+    }
+
+    #[test]
+    #[cfg(feature = "correct")]
+    fn f32_halfway_round_even_test() {
+        halfway_round_even_test!(f32, 0x2545F4914F6CDD1D, 2000);
+        // Denormal and boundary floats.
+        for &f in [0.0f32, 1e-45, 1.1754942e-38, 1.1754944e-38, 1.0, 16777216.0].iter() {
+            let (digits, exp) = halfway_sample(f);
+            let halfway = format!("{}e{}", digits, exp);
+            let even = if f.to_bits().is_odd() { f.next() } else { f };
+            assert_eq!(Ok(even), f32::from_lexical(halfway.as_bytes()), "{}", halfway);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "correct")]
+    fn f64_halfway_round_even_test() {
+        halfway_round_even_test!(f64, 0x2545F4914F6CDD1D, 1000);
+        // Denormal and boundary floats.
+        for &f in [0.0f64, 5e-324, 2.2250738585072009e-308, 2.2250738585072014e-308, 1.0, 9007199254740992.0].iter() {
+            let (digits, exp) = halfway_sample(f);
+            let halfway = format!("{}e{}", digits, exp);
+            let even = if f.to_bits().is_odd() { f.next() } else { f };
+            assert_eq!(Ok(even), f64::from_lexical(halfway.as_bytes()), "{}", halfway);
+        }
+    }
+
+    // Generate a decimal string, with an optional sign, decimal point
+    // and exponent, from a seed.
+    #[cfg(feature = "correct")]
+    fn std_parse_sample(state: &mut u64, max_exp: u64) -> String {
+        let mut sample = lossy_sample(state, max_exp);
+        let digits = sample.find('e').unwrap();
+        let point = (xorshift64(state) % (digits as u64 + 8)) as usize;
+        if point < digits {
+            sample.insert(point, '.');
+        }
+        if xorshift64(state) % 8 == 0 {
+            let exp = sample.find('e').unwrap();
+            sample.truncate(exp);
+        }
+        if xorshift64(state) % 4 == 0 {
+            sample.insert(0, '-');
+        }
+        sample
+    }
+
+    // Number of random samples to compare against `str::parse`, which
+    // may be set with the `LEXICAL_STD_PARSE_SAMPLES` environment variable.
+    #[cfg(feature = "correct")]
+    fn std_parse_samples() -> usize {
+        std::env::var("LEXICAL_STD_PARSE_SAMPLES").ok()
+            .and_then(|count| count.parse().ok())
+            .unwrap_or(10000)
+    }
+
+    // Compare random samples against `str::parse`, which is independently
+    // correctly rounded, printing the exact values of any mismatches.
+    #[cfg(feature = "correct")]
+    macro_rules! std_parse_test {
+        ($f:ty, $state:expr, $max_exp:expr) => {{
+            let mut state: u64 = $state;
+            let mut failures = 0;
+            for _ in 0..std_parse_samples() {
+                let sample = std_parse_sample(&mut state, $max_exp);
+                let expected = sample.parse::<$f>().unwrap();
+                let actual = <$f>::from_lexical(sample.as_bytes()).unwrap();
+                if actual.to_bits() != expected.to_bits() {
+                    let exact = | f: $f | crate::exact_digits(f).map(|c| c as char).collect::<String>();
+                    println!("{}: lexical {} = {}, std {} = {}", sample, actual, exact(actual), expected, exact(expected));
+                    failures += 1;
+                }
+            }
+            assert_eq!(failures, 0);
+        }};
+    }
+
+    #[test]
+    #[cfg(feature = "correct")]
+    fn f32_std_parse_test() {
+        std_parse_test!(f32, 0x2545F4914F6CDD1D, 60);
+    }
+
+    #[test]
+    #[cfg(feature = "correct")]
+    fn f64_std_parse_test() {
+        std_parse_test!(f64, 0x2545F4914F6CDD1D, 360);
+    }
+
+    #[test]
+    fn parse_partial_str_test() {
+        assert_eq!(Ok((1.5, "")), crate::parse_partial_str::<f64>("1.5"));
+        assert_eq!(Ok((1.5e3, " kg")), crate::parse_partial_str::<f64>("1.5e3 kg"));
+        assert_eq!(Ok((-2.5, "\u{b0}C")), crate::parse_partial_str::<f32>("-2.5\u{b0}C"));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), crate::parse_partial_str::<f64>("1e\u{2212}1"));
+        assert_eq!(Ok((f64::INFINITY, "\u{221e}")), crate::parse_partial_str::<f64>("inf\u{221e}"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), crate::parse_partial_str::<f64>("\u{221e}"));
+
+        // The remainder is valid for every split of a multi-byte string.
+        let string = "3.25\u{e9}\u{1f600}x";
+        for end in (0..string.len()+1).filter(|&i| string.is_char_boundary(i)) {
+            if let Ok((_, rest)) = crate::parse_partial_str::<f64>(&string[..end]) {
+                assert!(string[..end].ends_with(rest));
+            }
+        }
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn f64_special_test() {
@@ -622,6 +1552,31 @@ mod tests {
         assert!(f64::from_lexical_format(b"n_an", f5).is_err());
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_decimal_point_grouping_test() {
+        let format = NumberFormat::STANDARD
+            .with_decimal_point(b',').unwrap()
+            .with_grouping(b'.', 3).unwrap();
+        assert_eq!(Ok(123456789.0), f64::from_lexical_format(b"1.234.567,89e2", format));
+        assert_eq!(Ok(1234567.89), f64::from_lexical_format(b"1.234.567,89", format));
+        assert_eq!(Ok(1234.5), f64::from_lexical_format(b"1234,5", format));
+        assert_eq!(Ok(1500.0), f64::from_lexical_format(b"1,5E3", format));
+        assert_eq!(Err((ErrorCode::InvalidDigitGrouping, 2).into()), f64::from_lexical_format(b"1.23,5", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), f64::from_lexical_format(b"1,5,5", format));
+        assert_eq!(Ok((1.5, 3)), f64::from_lexical_partial_format(b"1,5,5", format));
+
+        // Only the decimal point.
+        let format = NumberFormat::STANDARD.with_decimal_point(b',').unwrap();
+        assert_eq!(Ok(123456.789), f64::from_lexical_format(b"1234,56789e2", format));
+        assert_eq!(Ok(0.5), f64::from_lexical_format(b",5", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f64::from_lexical_format(b"1.234", format));
+
+        // Only the grouping.
+        let format = NumberFormat::STANDARD.with_grouping(b',', 3).unwrap();
+        assert_eq!(Ok(123456789.0), f64::from_lexical_format(b"1,234,567.89e2", format));
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn f64_required_integer_digits_test() {
@@ -639,6 +1594,8 @@ mod tests {
         assert!(f64::from_lexical_format(b"3.0", format).is_ok());
         assert!(f64::from_lexical_format(b"3.", format).is_err());
         assert!(f64::from_lexical_format(b"3", format).is_ok());
+        assert!(f64::from_lexical_format(b"3.e5", format).is_err());
+        assert!(f64::from_lexical_format(b"3e5", format).is_ok());
     }
 
     #[test]
@@ -682,6 +1639,65 @@ mod tests {
         assert!(f64::from_lexical_format(b"+3", format).is_ok());
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_required_exponent_notation_test() {
+        let format = NumberFormat::REQUIRED_EXPONENT_NOTATION;
+        assert_eq!(Ok(1500.0), f64::from_lexical_format(b"1.5e3", format));
+        assert_eq!(Ok(1500.0), f64::from_lexical_format(b"15e2", format));
+        assert_eq!(Ok(0.15), f64::from_lexical_format(b".15e0", format));
+        assert_eq!(Err((ErrorCode::MissingExponent, 4).into()), f64::from_lexical_format(b"1500", format));
+        assert_eq!(Err((ErrorCode::MissingExponent, 3).into()), f64::from_lexical_format(b"1.5", format));
+        assert_eq!(Err((ErrorCode::MissingExponent, 3).into()), f64::from_lexical_format(b"-1.", format));
+        assert_eq!(Err((ErrorCode::MissingExponent, 3).into()), f64::from_lexical_format(b"1.5x", format));
+        assert!(f64::from_lexical_format(b"inf", format).unwrap().is_infinite());
+
+        // The partial parser also requires the exponent.
+        assert_eq!(Err((ErrorCode::MissingExponent, 3).into()), f64::from_lexical_partial_format(b"1.5,2e3", format));
+        assert_eq!(Ok((1.5, 5)), f64::from_lexical_partial_format(b"1.5e0,", format));
+
+        // Normalized scientific notation, with exactly one integer digit.
+        let format = format | NumberFormat::NORMALIZED_MANTISSA;
+        assert_eq!(Ok(1500.0), f64::from_lexical_format(b"1.5e3", format));
+        assert_eq!(Ok(-1500.0), f64::from_lexical_format(b"-1.5e+3", format));
+        assert_eq!(Ok(0.0), f64::from_lexical_format(b"0.0e0", format));
+        assert_eq!(Ok(5.0), f64::from_lexical_format(b"5e0", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f64::from_lexical_format(b"15.0e2", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), f64::from_lexical_format(b"-15e2", format));
+        assert_eq!(Err((ErrorCode::EmptyInteger, 0).into()), f64::from_lexical_format(b".15e4", format));
+        assert_eq!(Err((ErrorCode::MissingExponent, 3).into()), f64::from_lexical_format(b"1.5", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f64::from_lexical_format(b"1500", format));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_d_exponent_notation_test() {
+        let format = NumberFormat::FORTRAN_STRING;
+        assert_eq!(Ok(1500.0), f64::from_lexical_format(b"1.5D+03", format));
+        assert_eq!(Ok(1500.0), f64::from_lexical_format(b"1.5d+03", format));
+        assert_eq!(Ok(1500.0), f64::from_lexical_format(b"1.5E3", format));
+        assert_eq!(Ok(1500.0), f64::from_lexical_format(b"1.5e3", format));
+        assert_eq!(Ok(0.0015), f64::from_lexical_format(b"1.5D-3", format));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 4).into()), f64::from_lexical_format(b"1.5D", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), f64::from_lexical_format(b"1.5+03", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), f64::from_lexical(b"1.5D+03"));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_exponent_without_notation_test() {
+        let format = NumberFormat::FORTRAN_LENIENT_STRING;
+        assert_eq!(Ok(1500.0), f64::from_lexical_format(b"1.5+03", format));
+        assert_eq!(Ok(1500.0), f64::from_lexical_format(b"1.5+3", format));
+        assert_eq!(Ok(0.0015), f64::from_lexical_format(b"1.5-3", format));
+        assert_eq!(Ok(1500.0), f64::from_lexical_format(b"15.+2", format));
+        assert_eq!(Ok(1500.0), f64::from_lexical_format(b"1.5D+03", format));
+        assert_eq!(Ok(1500.0), f64::from_lexical_format(b"1.5E3", format));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 3).into()), f64::from_lexical_format(b"1.5+", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), f64::from_lexical_format(b"1.5+3+2", format));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 3).into()), f64::from_lexical_format(b"1.5+a", format));
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn f64_optional_exponent_test() {
@@ -720,6 +1736,17 @@ mod tests {
         assert!(f64::from_lexical_format(b"3.0e7", format).is_err());
         assert!(f64::from_lexical_format(b"3.0e+7", format).is_ok());
         assert!(f64::from_lexical_format(b"3.0e-7", format).is_ok());
+        assert!(f64::from_lexical_format(b"3.0", format).is_ok());
+        assert_eq!(Err((ErrorCode::MissingExponentSign, 4).into()), f64::from_lexical_format(b"1.5e03", format));
+        assert_eq!(Err((ErrorCode::MissingExponentSign, 4).into()), f64::from_lexical_partial_format(b"1.5e03", format));
+        assert_eq!(Ok(1500.0), f64::from_lexical_format(b"1.5e+03", format));
+        assert_eq!(Ok((1500.0, 7)), f64::from_lexical_partial_format(b"1.5e+03", format));
+        assert_eq!(Ok((0.0015, 7)), f64::from_lexical_partial_format(b"1.5e-03", format));
+        assert_eq!(Ok((1500.0, 7)), f64::from_lexical_partial_format(b"1.5e+03 m", format));
+
+        // The exponent sign is optional by default.
+        let format = NumberFormat::standard().unwrap();
+        assert_eq!(Ok((1500.0, 6)), f64::from_lexical_partial_format(b"1.5e03", format));
     }
 
     #[test]
@@ -861,6 +1888,74 @@ mod tests {
         assert!(f64::from_lexical_format(b"31.01e71_", format).is_err());
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_integer_grouped_digit_separator_test() {
+        let format = NumberFormat::grouped(b',', 3).unwrap();
+        assert_eq!(Ok(1234567.89), f64::from_lexical_format(b"1,234,567.89", format));
+        assert_eq!(Ok(-123456.0), f64::from_lexical_format(b"-123,456", format));
+        assert_eq!(Ok(1234567.89), f64::from_lexical_format(b"1234567.89", format));
+        assert_eq!(Ok(1.5e3), f64::from_lexical_format(b"1.5e3", format));
+        assert_eq!(Ok(1.2345e6), f64::from_lexical_format(b"1,234.5e3", format));
+        assert_eq!(Err((ErrorCode::InvalidDigitGrouping, 2).into()), f64::from_lexical_format(b"1,23,456", format));
+        assert_eq!(Err((ErrorCode::InvalidDigitGrouping, 3).into()), f64::from_lexical_format(b"12,34.5", format));
+        assert_eq!(Err((ErrorCode::InvalidDigitGrouping, 0).into()), f64::from_lexical_format(b"1234,567", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), f64::from_lexical_format(b"1,234,.5", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), f64::from_lexical_format(b"1.23,4", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f64::from_lexical_format(b"1,,234", format));
+        assert!(f64::from_lexical_format(b",123", format).is_err());
+
+        let format = NumberFormat::grouped(b'\'', 4).unwrap();
+        assert_eq!(Ok(123456789.0), f64::from_lexical_format(b"1'2345'6789", format));
+        assert_eq!(Err((ErrorCode::InvalidDigitGrouping, 2).into()), f64::from_lexical_format(b"1'234'567", format));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_grouped_exponent_digit_separator_test() {
+        // Exponent digit separators are disabled by default.
+        let format = NumberFormat::grouped(b'_', 3).unwrap();
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), f64::from_lexical_format(b"1.5e1_0", format));
+
+        let format = format | NumberFormat::EXPONENT_INTERNAL_DIGIT_SEPARATOR;
+        assert_eq!(Ok(1.5e10), f64::from_lexical_format(b"1.5e1_0", format));
+        assert_eq!(Ok(1.5e-10), f64::from_lexical_format(b"1.5e-1_0", format));
+        assert_eq!(Ok(1.2345e13), f64::from_lexical_format(b"1_234.5e1_0", format));
+        assert_eq!(Ok(1.5e10), f64::from_lexical_format(b"1.5e10", format));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 4).into()), f64::from_lexical_format(b"1.5e_10", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 6).into()), f64::from_lexical_format(b"1.5e10_", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), f64::from_lexical_format(b"1.5e1__0", format));
+        assert_eq!(Err((ErrorCode::InvalidDigitGrouping, 2).into()), f64::from_lexical_format(b"1_23e1_0", format));
+
+        let format = NumberFormat::grouped(b' ', 3).unwrap() | NumberFormat::EXPONENT_INTERNAL_DIGIT_SEPARATOR;
+        assert_eq!(Ok(1.2345e13), f64::from_lexical_format(b"1 234.5e1 0", format));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_european_test() {
+        let format = NumberFormat::european(b'.').unwrap();
+        assert_eq!(Ok(1234567.89), f64::from_lexical_format(b"1.234.567,89", format));
+        assert_eq!(Ok(1234567.89), f64::from_lexical_format(b"1234567,89", format));
+        assert_eq!(Ok(-0.5), f64::from_lexical_format(b"-0,5", format));
+        assert_eq!(Ok(1.5e3), f64::from_lexical_format(b"1,5e3", format));
+        assert_eq!(Ok((1.0, 1)), f64::from_lexical_partial_format(b"1;5", format));
+        assert_eq!(Err((ErrorCode::InvalidDigitGrouping, 2).into()), f64::from_lexical_format(b"1.5", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 7).into()), f64::from_lexical_format(b"1.234,5.6", format));
+
+        // Both the period and the Unicode spaces separate groups of digits.
+        assert_eq!(Ok(1234567.89), f64::from_lexical_format(b"1\xC2\xA0234\xC2\xA0567,89", format));
+        assert_eq!(Ok(1234567.89), f64::from_lexical_format(b"1\xE2\x80\xAF234.567,89", format));
+        assert_eq!(Err((ErrorCode::InvalidDigitGrouping, 3).into()), f64::from_lexical_format(b"1\xC2\xA05", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f64::from_lexical_format(b"1\xC2\xA0.234", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f64::from_lexical_format(b"1\xC2\xA0", format));
+
+        let format = NumberFormat::european(b' ').unwrap();
+        assert_eq!(Ok(1234567.89), f64::from_lexical_format(b"1 234 567,89", format));
+        assert_eq!(Ok((1234.5, 7)), f64::from_lexical_partial_format(b"1 234,5 m", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f64::from_lexical_format(b"1.5", format));
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn f64_json_exponent_without_dot() {
@@ -901,6 +1996,16 @@ mod tests {
         assert!(f64::from_lexical_format(b"-12.0", format).is_ok());
         assert!(f64::from_lexical_format(b"012.0", format).is_err());
         assert!(f64::from_lexical_format(b"-012.0", format).is_err());
+
+        // A lone zero is allowed before the fraction or exponent.
+        assert_eq!(Ok(0.0), f64::from_lexical_format(b"0", format));
+        assert_eq!(Ok(0.1), f64::from_lexical_format(b"0.1", format));
+        assert_eq!(Ok(-0.5), f64::from_lexical_format(b"-0.5", format));
+        assert_eq!(Ok(0.0), f64::from_lexical_format(b"0e3", format));
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 0).into()), f64::from_lexical_format(b"01", format));
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 0).into()), f64::from_lexical_format(b"007", format));
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 1).into()), f64::from_lexical_format(b"-00.5", format));
+        assert_eq!(Ok(1.0), f64::from_lexical_format(b"01", NumberFormat::STANDARD));
     }
 
     #[cfg(feature = "std")]
@@ -918,8 +2023,8 @@ mod tests {
             let res = f32::from_lexical(i.as_bytes());
             prop_assert!(res.is_err());
             let err = res.err().unwrap();
-            prop_assert!(err.code == ErrorCode::InvalidDigit || err.code == ErrorCode::EmptyMantissa);
-            prop_assert!(err.index == 0 || err.index == 1);
+            prop_assert_eq!(err.code, ErrorCode::InvalidSign);
+            prop_assert_eq!(err.index, 1);
         }
 
         #[test]
@@ -927,7 +2032,7 @@ mod tests {
             let res = f32::from_lexical(i.as_bytes());
             prop_assert!(res.is_err());
             let err = res.err().unwrap();
-            prop_assert!(err.code == ErrorCode::Empty || err.code == ErrorCode::EmptyMantissa);
+            prop_assert!(err.code == ErrorCode::Empty || err.code == ErrorCode::LoneDot);
             prop_assert!(err.index == 0 || err.index == 1);
         }
 
@@ -947,6 +2052,19 @@ mod tests {
             prop_assert_eq!(err.code, ErrorCode::EmptyExponent);
         }
 
+        #[test]
+        fn f32_lossy_no_panic_proptest(i in r"[+-]?[0-9]{0,40}\.?[0-9]{0,40}([eE][+-]?[0-9]{0,5})?.?") {
+            let _ = f32::from_lexical_lossy(i.as_bytes());
+        }
+
+        #[cfg(feature = "correct")]
+        #[test]
+        fn f32_lossy_error_bound_proptest(i in r"[+-]?[0-9]{1,40}\.[0-9]{0,40}e[+-]?[0-9]{1,2}") {
+            let exact = f32::from_lexical(i.as_bytes()).unwrap();
+            let lossy = f32::from_lexical_lossy(i.as_bytes()).unwrap();
+            prop_assert!(ulp_distance!(exact, lossy) <= 1);
+        }
+
         #[cfg(feature = "correct")]
         #[test]
         fn f32_roundtrip_display_proptest(i in f32::MIN..f32::MAX) {
@@ -981,8 +2099,8 @@ mod tests {
             let res = f64::from_lexical(i.as_bytes());
             prop_assert!(res.is_err());
             let err = res.err().unwrap();
-            prop_assert!(err.code == ErrorCode::InvalidDigit || err.code == ErrorCode::EmptyMantissa);
-            prop_assert!(err.index == 0 || err.index == 1);
+            prop_assert_eq!(err.code, ErrorCode::InvalidSign);
+            prop_assert_eq!(err.index, 1);
         }
 
         #[test]
@@ -990,7 +2108,7 @@ mod tests {
             let res = f64::from_lexical(i.as_bytes());
             prop_assert!(res.is_err());
             let err = res.err().unwrap();
-            prop_assert!(err.code == ErrorCode::Empty || err.code == ErrorCode::EmptyMantissa);
+            prop_assert!(err.code == ErrorCode::Empty || err.code == ErrorCode::LoneDot);
             prop_assert!(err.index == 0 || err.index == 1);
         }
 
@@ -1010,6 +2128,19 @@ mod tests {
             prop_assert_eq!(err.code, ErrorCode::EmptyExponent);
         }
 
+        #[test]
+        fn f64_lossy_no_panic_proptest(i in r"[+-]?[0-9]{0,40}\.?[0-9]{0,40}([eE][+-]?[0-9]{0,5})?.?") {
+            let _ = f64::from_lexical_lossy(i.as_bytes());
+        }
+
+        #[cfg(feature = "correct")]
+        #[test]
+        fn f64_lossy_error_bound_proptest(i in r"[+-]?[0-9]{1,40}\.[0-9]{0,40}e[+-]?[0-9]{1,3}") {
+            let exact = f64::from_lexical(i.as_bytes()).unwrap();
+            let lossy = f64::from_lexical_lossy(i.as_bytes()).unwrap();
+            prop_assert!(ulp_distance!(exact, lossy) <= 1);
+        }
+
         #[cfg(feature = "correct")]
         #[test]
         fn f64_roundtrip_display_proptest(i in f64::MIN..f64::MAX) {
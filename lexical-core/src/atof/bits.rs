@@ -0,0 +1,93 @@
+//! Parse floats directly to their IEEE-754 bit patterns.
+
+use crate::util::*;
+
+// Currently, the float is constructed and then converted to bits, which
+// is exact. This allows the bit pattern to be constructed directly from
+// the extended-precision float in the future, without the final float.
+
+/// Parse `f32` from string, returning the IEEE-754 bit pattern.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits and the
+/// bit pattern of the parsed value, identical to `f32::to_bits` of the
+/// value from [`parse_partial`]. The sign of zero, infinity and NaN is
+/// preserved, so `"-0"` is parsed as `0x80000000`.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// [`parse_partial`]: fn.parse_partial.html
+#[inline]
+pub fn parse_f32_bits(bytes: &[u8])
+    -> Result<(u32, usize)>
+{
+    let (value, processed) = f32::from_lexical_partial(bytes)?;
+    Ok((value.to_bits(), processed))
+}
+
+/// Parse `f64` from string, returning the IEEE-754 bit pattern.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits and the
+/// bit pattern of the parsed value, identical to `f64::to_bits` of the
+/// value from [`parse_partial`]. The sign of zero, infinity and NaN is
+/// preserved, so `"-0"` is parsed as `0x8000000000000000`.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_f64_bits(b"1.5"), Ok((0x3FF8000000000000, 3)));
+/// assert_eq!(lexical_core::parse_f64_bits(b"-0,"), Ok((0x8000000000000000, 2)));
+/// assert_eq!(lexical_core::parse_f64_bits(b"-inf"), Ok((0xFFF0000000000000, 4)));
+/// # }
+/// ```
+///
+/// [`parse_partial`]: fn.parse_partial.html
+#[inline]
+pub fn parse_f64_bits(bytes: &[u8])
+    -> Result<(u64, usize)>
+{
+    let (value, processed) = f64::from_lexical_partial(bytes)?;
+    Ok((value.to_bits(), processed))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_f32_bits_test() {
+        assert_eq!(Ok((0x3FC00000, 3)), parse_f32_bits(b"1.5"));
+        assert_eq!(Ok((0x00000000, 1)), parse_f32_bits(b"0"));
+        assert_eq!(Ok((0x80000000, 2)), parse_f32_bits(b"-0"));
+        assert_eq!(Ok((0x80000000, 6)), parse_f32_bits(b"-1e-50"));
+        assert_eq!(Ok((0x7F800000, 3)), parse_f32_bits(b"inf"));
+        assert_eq!(Ok((0xFF800000, 5)), parse_f32_bits(b"-1e50"));
+        assert_eq!(Ok((f32::NAN.to_bits(), 3)), parse_f32_bits(b"NaN"));
+        assert_eq!(Ok((0x00000001, 7)), parse_f32_bits(b"1.4e-45x"));
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), parse_f32_bits(b""));
+    }
+
+    #[test]
+    fn parse_f64_bits_test() {
+        assert_eq!(Ok((0x3FF8000000000000, 3)), parse_f64_bits(b"1.5"));
+        assert_eq!(Ok((0x0000000000000000, 3)), parse_f64_bits(b"0.0"));
+        assert_eq!(Ok((0x8000000000000000, 4)), parse_f64_bits(b"-0.0"));
+        assert_eq!(Ok((0x8000000000000000, 7)), parse_f64_bits(b"-1e-400"));
+        assert_eq!(Ok((0x7FF0000000000000, 8)), parse_f64_bits(b"infinity"));
+        assert_eq!(Ok((0xFFF0000000000000, 4)), parse_f64_bits(b"-inf"));
+        assert_eq!(Ok((0x7FF0000000000000, 5)), parse_f64_bits(b"1e400"));
+        assert_eq!(Ok((f64::NAN.to_bits(), 3)), parse_f64_bits(b"nan"));
+        assert_eq!(Ok((f64::NAN.to_bits() | 0x8000000000000000, 4)), parse_f64_bits(b"-nan"));
+        assert_eq!(Ok((0x0000000000000001, 6)), parse_f64_bits(b"5e-324"));
+        assert_eq!(Ok((0x7FEFFFFFFFFFFFFF, 22)), parse_f64_bits(b"1.7976931348623157e308"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parse_f64_bits(b"x"));
+    }
+}
@@ -0,0 +1,105 @@
+//! Parse a decimal string to both 32-bit and 64-bit floats.
+
+use crate::util::*;
+use super::api::atof_both;
+
+/// Parse `f32` and `f64` from a decimal string in one pass.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing, exactly like [`parse`]
+/// for an `f32` and an `f64`. The digits are scanned and the mantissa
+/// and exponent are extracted once, and only the final rounding is
+/// done for each float, so both are correctly rounded from the string.
+/// The `f32` is never rounded from the `f64`, which could round twice
+/// and differ from the correctly-rounded `f32` for strings close to
+/// halfway between two `f32` values.
+///
+/// Special values are parsed as usual.
+///
+/// * `bytes`   - Byte slice containing a decimal string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_both(b"-1.5"), Ok((-1.5f32, -1.5f64)));
+///
+/// // Just above halfway between 1 and the next `f32`.
+/// let (float, double) = lexical_core::parse_both(b"1.0000000596046447753906250000001").unwrap();
+/// assert_eq!(float, 1.0000001);
+/// assert_eq!(double as f32, 1.0);
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+#[inline]
+pub fn parse_both(bytes: &[u8])
+    -> Result<(f32, f64)>
+{
+    let index = | ptr | distance(bytes.as_ptr(), ptr);
+    match atof_both(bytes, NumberFormat::standard().unwrap()) {
+        Ok((_, ptr)) if index(ptr) != bytes.len()   => Err((ErrorCode::InvalidDigit, index(ptr)).into()),
+        Ok((value, _))                              => Ok(value),
+        Err((code, ptr))                            => Err((code, index(ptr)).into()),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_both_test() {
+        assert_eq!(Ok((0.0, 0.0)), parse_both(b"0"));
+        assert_eq!(Ok((1.5, 1.5)), parse_both(b"1.5"));
+        assert_eq!(Ok((-0.1, -0.1)), parse_both(b"-0.1"));
+        assert_eq!(Ok((1e-40, 1e-40)), parse_both(b"1e-40"));
+        assert_eq!(Ok((f32::INFINITY, 1e300)), parse_both(b"1e300"));
+        assert_eq!(Ok((f32::INFINITY, f64::INFINITY)), parse_both(b"inf"));
+        assert_eq!(Ok((f32::NEG_INFINITY, f64::NEG_INFINITY)), parse_both(b"-1e400"));
+        let (float, double) = parse_both(b"NaN").unwrap();
+        assert!(float.is_nan() && double.is_nan());
+
+        // Errors match the generic parser.
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), parse_both(b""));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), parse_both(b"-"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parse_both(b"1x"));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), parse_both(b"1e"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parse_both(b"e5"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), parse_both(b"infx"));
+    }
+
+    #[test]
+    fn parse_both_double_rounding_test() {
+        // The `f64` rounds to exactly halfway between 1 and the next `f32`,
+        // so casting the `f64` would round down to even.
+        let (float, double) = parse_both(b"1.0000000596046447753906250000001").unwrap();
+        assert_eq!(float, 1.0000001);
+        assert_eq!(double, 1.0000000596046448);
+        assert_eq!(double as f32, 1.0);
+
+        // Just below halfway, which the `f64` rounds up to halfway.
+        let (float, double) = parse_both(b"1.0000000596046447753906249999999").unwrap();
+        assert_eq!(float, 1.0);
+        assert_eq!(double, 1.0000000596046448);
+
+        // Truncated digits, requiring the slow path.
+        let (float, double) = parse_both(b"1.00000005960464477539062500000000000000000000000000000000000000000000000000000001").unwrap();
+        assert_eq!(float, 1.0000001);
+        assert_eq!(double as f32, 1.0);
+    }
+
+    #[cfg(feature = "std")]
+    proptest! {
+        #[test]
+        fn parse_both_proptest(i in r"[+-]?[0-9]{1,20}(\.[0-9]{0,20})?([eE][+-]?[0-9]{1,3})?") {
+            let (float, double) = parse_both(i.as_bytes()).unwrap();
+            prop_assert_eq!(float, i.parse::<f32>().unwrap());
+            prop_assert_eq!(double, i.parse::<f64>().unwrap());
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Range of valid values for bounded float parsing.
+
+use crate::util::*;
+
+// BOUNDS
+
+/// Range of valid values for a parsed float.
+///
+/// By default, both bounds are inclusive and NaN is out of range.
+/// Infinity is only in range if it lies within the bounds, for
+/// example, `FloatBounds::new(0.0, f64::INFINITY)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::FloatBounds;
+/// # pub fn main() {
+/// // Probability, in `[0.0, 1.0]`.
+/// let bounds = FloatBounds::new(0.0, 1.0);
+/// assert!(bounds.contains(0.0));
+/// assert!(!bounds.contains(1.5));
+///
+/// // Positive, finite value, in `(0.0, inf)`.
+/// let bounds = FloatBounds::new(0.0, std::f64::INFINITY)
+///     .exclusive_min(true)
+///     .exclusive_max(true);
+/// assert!(!bounds.contains(0.0));
+/// assert!(!bounds.contains(std::f64::INFINITY));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FloatBounds<F> {
+    /// Lower bound.
+    min: F,
+    /// Upper bound.
+    max: F,
+    /// Exclude the lower bound from the range.
+    exclusive_min: bool,
+    /// Exclude the upper bound from the range.
+    exclusive_max: bool,
+    /// Allow NaN values.
+    allow_nan: bool,
+}
+
+impl<F: Float> FloatBounds<F> {
+    /// Create inclusive bounds for the range `[min, max]`.
+    ///
+    /// * `min`     - Lower bound.
+    /// * `max`     - Upper bound.
+    #[inline]
+    pub fn new(min: F, max: F) -> FloatBounds<F> {
+        FloatBounds {
+            min,
+            max,
+            exclusive_min: false,
+            exclusive_max: false,
+            allow_nan: false,
+        }
+    }
+
+    /// Set whether to exclude the lower bound from the range.
+    ///
+    /// * `exclusive`   - Exclude the lower bound.
+    #[inline]
+    pub fn exclusive_min(mut self, exclusive: bool) -> FloatBounds<F> {
+        self.exclusive_min = exclusive;
+        self
+    }
+
+    /// Set whether to exclude the upper bound from the range.
+    ///
+    /// * `exclusive`   - Exclude the upper bound.
+    #[inline]
+    pub fn exclusive_max(mut self, exclusive: bool) -> FloatBounds<F> {
+        self.exclusive_max = exclusive;
+        self
+    }
+
+    /// Set whether NaN values are in range.
+    ///
+    /// * `allow`   - Allow NaN values.
+    #[inline]
+    pub fn allow_nan(mut self, allow: bool) -> FloatBounds<F> {
+        self.allow_nan = allow;
+        self
+    }
+
+    /// Get the lower bound.
+    #[inline]
+    pub fn get_min(&self) -> F {
+        self.min
+    }
+
+    /// Get the upper bound.
+    #[inline]
+    pub fn get_max(&self) -> F {
+        self.max
+    }
+
+    /// Get if the lower bound is excluded from the range.
+    #[inline]
+    pub fn get_exclusive_min(&self) -> bool {
+        self.exclusive_min
+    }
+
+    /// Get if the upper bound is excluded from the range.
+    #[inline]
+    pub fn get_exclusive_max(&self) -> bool {
+        self.exclusive_max
+    }
+
+    /// Get if NaN values are in range.
+    #[inline]
+    pub fn get_allow_nan(&self) -> bool {
+        self.allow_nan
+    }
+
+    /// Determine if the value is within the bounds.
+    ///
+    /// * `value`   - Value to check.
+    #[inline]
+    pub fn contains(&self, value: F) -> bool {
+        if value.is_nan() {
+            return self.allow_nan;
+        }
+
+        let above_min = match self.exclusive_min {
+            true  => value > self.min,
+            false => value >= self.min,
+        };
+        let below_max = match self.exclusive_max {
+            true  => value < self.max,
+            false => value <= self.max,
+        };
+        above_min && below_max
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use crate::util::*;
+    use super::*;
+
+    #[test]
+    fn contains_test() {
+        let bounds = FloatBounds::new(0.0f64, 1.0);
+        assert!(bounds.contains(0.0));
+        assert!(bounds.contains(-0.0));
+        assert!(bounds.contains(0.5));
+        assert!(bounds.contains(1.0));
+        assert!(!bounds.contains(-1e-300));
+        assert!(!bounds.contains(1.0000000000000002));
+        assert!(!bounds.contains(f64::INFINITY));
+        assert!(!bounds.contains(f64::NEG_INFINITY));
+        assert!(!bounds.contains(f64::NAN));
+
+        let bounds = bounds.exclusive_min(true).exclusive_max(true);
+        assert!(!bounds.contains(0.0));
+        assert!(bounds.contains(0.5));
+        assert!(!bounds.contains(1.0));
+
+        let bounds = FloatBounds::new(f32::NEG_INFINITY, f32::INFINITY);
+        assert!(bounds.contains(f32::INFINITY));
+        assert!(bounds.contains(f32::NEG_INFINITY));
+        assert!(!bounds.contains(f32::NAN));
+        assert!(bounds.allow_nan(true).contains(f32::NAN));
+    }
+}
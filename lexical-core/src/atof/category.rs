@@ -0,0 +1,84 @@
+//! Parse floats with the IEEE category of the result.
+
+use crate::lib::num::FpCategory;
+use crate::util::*;
+
+/// Parse `f32` from string, with the IEEE category of the result.
+///
+/// See [`parse_f64_classified`].
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// [`parse_f64_classified`]: fn.parse_f64_classified.html
+#[inline]
+pub fn parse_f32_classified(bytes: &[u8])
+    -> Result<(f32, FpCategory)>
+{
+    let value = f32::from_lexical(bytes)?;
+    Ok((value, value.classify()))
+}
+
+/// Parse `f64` from string, with the IEEE category of the result.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. Returns the parsed
+/// float, and the category of the float, so inputs which underflow
+/// to a subnormal float or zero, or overflow to infinity, may be
+/// detected without calling `classify`. The category is of the
+/// rounded result, so `"1e-400"` is `Zero`, and `"1e400"` is
+/// `Infinite`.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use std::num::FpCategory;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_f64_classified(b"1.5"), Ok((1.5, FpCategory::Normal)));
+/// assert_eq!(lexical_core::parse_f64_classified(b"1e-320"), Ok((1e-320, FpCategory::Subnormal)));
+/// assert_eq!(lexical_core::parse_f64_classified(b"1e400"), Ok((core::f64::INFINITY, FpCategory::Infinite)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_f64_classified(bytes: &[u8])
+    -> Result<(f64, FpCategory)>
+{
+    let value = f64::from_lexical(bytes)?;
+    Ok((value, value.classify()))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_f64_classified_test() {
+        assert_eq!(Ok((1.5, FpCategory::Normal)), parse_f64_classified(b"1.5"));
+        assert_eq!(Ok(FpCategory::Subnormal), parse_f64_classified(b"1e-320").map(|(_, c)| c));
+        assert_eq!(Ok((f64::INFINITY, FpCategory::Infinite)), parse_f64_classified(b"1e400"));
+        assert_eq!(Ok((f64::NEG_INFINITY, FpCategory::Infinite)), parse_f64_classified(b"-inf"));
+        assert_eq!(Ok((0.0, FpCategory::Zero)), parse_f64_classified(b"0"));
+        assert_eq!(Ok((0.0, FpCategory::Zero)), parse_f64_classified(b"1e-400"));
+        assert_eq!(Ok(FpCategory::Nan), parse_f64_classified(b"NaN").map(|(_, c)| c));
+
+        // The category is of the rounded result.
+        assert_eq!(Ok(FpCategory::Normal), parse_f64_classified(b"2.2250738585072014e-308").map(|(_, c)| c));
+        assert_eq!(Ok(FpCategory::Subnormal), parse_f64_classified(b"2.2250738585072011e-308").map(|(_, c)| c));
+        assert_eq!(Ok(FpCategory::Normal), parse_f64_classified(b"1.7976931348623157e308").map(|(_, c)| c));
+        assert_eq!(Ok(FpCategory::Infinite), parse_f64_classified(b"1.7976931348623159e308").map(|(_, c)| c));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parse_f64_classified(b"1x"));
+    }
+
+    #[test]
+    fn parse_f32_classified_test() {
+        assert_eq!(Ok((1.5, FpCategory::Normal)), parse_f32_classified(b"1.5"));
+        assert_eq!(Ok(FpCategory::Subnormal), parse_f32_classified(b"1e-40").map(|(_, c)| c));
+        assert_eq!(Ok(FpCategory::Infinite), parse_f32_classified(b"1e40").map(|(_, c)| c));
+        assert_eq!(Ok(FpCategory::Zero), parse_f32_classified(b"-0").map(|(_, c)| c));
+    }
+}
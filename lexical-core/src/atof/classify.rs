@@ -0,0 +1,94 @@
+//! Classify numeric strings as integers, floats or special values.
+
+use crate::util::*;
+use super::algorithm::classify::classify_digits;
+
+// NUMBER KIND
+
+/// Kind of number a numeric string contains.
+///
+/// The kind is determined by the float grammar, without parsing the
+/// value, as if by [`classify_number`].
+///
+/// [`classify_number`]: fn.classify_number.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NumberKind {
+    /// Integer that fits in a 64-bit integer.
+    Integer,
+    /// Float, including integers too large for a 64-bit integer.
+    Float,
+    /// Infinity or NaN.
+    Special,
+    /// Invalid numeric string.
+    NotANumber,
+}
+
+// Determine if the string is a special value, matching the parser.
+perftools_inline!{
+fn is_special(digits: &[u8]) -> bool {
+    let strings = [get_inf_string(), get_infinity_string(), get_nan_string()];
+    strings.iter().any(|s| digits.eq_ignore_ascii_case(s))
+}}
+
+// Classify a numeric string.
+perftools_inline!{
+pub(crate) fn classify(bytes: &[u8], radix: u32) -> NumberKind {
+    let format = NumberFormat::standard().unwrap();
+    let (sign, digits) = parse_sign::<f64>(bytes, format);
+    if is_special(digits) {
+        return NumberKind::Special;
+    }
+    match classify_digits(digits, radix, sign, format) {
+        Ok((kind, ptr)) if distance(bytes.as_ptr(), ptr) == bytes.len() => kind,
+        _ => NumberKind::NotANumber,
+    }
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_test() {
+        assert_eq!(NumberKind::Integer, classify(b"0", 10));
+        assert_eq!(NumberKind::Integer, classify(b"-0", 10));
+        assert_eq!(NumberKind::Integer, classify(b"+123", 10));
+        assert_eq!(NumberKind::Integer, classify(b"00012", 10));
+        assert_eq!(NumberKind::Integer, classify(b"18446744073709551615", 10));
+        assert_eq!(NumberKind::Integer, classify(b"-9223372036854775808", 10));
+        assert_eq!(NumberKind::Float, classify(b"18446744073709551616", 10));
+        assert_eq!(NumberKind::Float, classify(b"-9223372036854775809", 10));
+        assert_eq!(NumberKind::Float, classify(b"1.5", 10));
+        assert_eq!(NumberKind::Float, classify(b"5.", 10));
+        assert_eq!(NumberKind::Float, classify(b".5", 10));
+        assert_eq!(NumberKind::Float, classify(b"5.0", 10));
+        assert_eq!(NumberKind::Float, classify(b"5e0", 10));
+        assert_eq!(NumberKind::Float, classify(b"-5E+10", 10));
+        assert_eq!(NumberKind::Special, classify(b"inf", 10));
+        assert_eq!(NumberKind::Special, classify(b"-Infinity", 10));
+        assert_eq!(NumberKind::Special, classify(b"NaN", 10));
+        assert_eq!(NumberKind::NotANumber, classify(b"", 10));
+        assert_eq!(NumberKind::NotANumber, classify(b"-", 10));
+        assert_eq!(NumberKind::NotANumber, classify(b".", 10));
+        assert_eq!(NumberKind::NotANumber, classify(b"5x", 10));
+        assert_eq!(NumberKind::NotANumber, classify(b"5e", 10));
+        assert_eq!(NumberKind::NotANumber, classify(b"1.5.", 10));
+        assert_eq!(NumberKind::NotANumber, classify(b"infx", 10));
+        assert_eq!(NumberKind::NotANumber, classify(b"nan1", 10));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn classify_radix_test() {
+        assert_eq!(NumberKind::Integer, classify(b"1010", 2));
+        assert_eq!(NumberKind::Float, classify(b"1010.1", 2));
+        assert_eq!(NumberKind::NotANumber, classify(b"12", 2));
+        assert_eq!(NumberKind::Integer, classify(b"ffffffffffffffff", 16));
+        assert_eq!(NumberKind::Float, classify(b"10000000000000000", 16));
+        assert_eq!(NumberKind::Integer, classify(b"YA", 36));
+        assert_eq!(NumberKind::Special, classify(b"inf", 36));
+    }
+}
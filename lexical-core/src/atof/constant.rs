@@ -0,0 +1,337 @@
+//! Const-evaluable parsers for simple decimal float literals.
+//!
+//! These parsers only implement the exact (fast) path, since the
+//! extended and arbitrary-precision algorithms use traits and tables
+//! which are not usable in a `const fn`. Any literal which would
+//! require those algorithms panics, which is a compile error when
+//! evaluated in a `const` context.
+//!
+//! Const float arithmetic requires Rust 1.82+, so this module is only
+//! compiled with the `const_fn` feature.
+
+// Exactly representable powers of 10 for f32.
+const F32_POW10: [f32; 11] = [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10];
+
+// Exactly representable powers of 10 for f64.
+const F64_POW10: [f64; 23] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11,
+    1e12, 1e13, 1e14, 1e15, 1e16, 1e17, 1e18, 1e19, 1e20, 1e21, 1e22
+];
+
+// Powers of 10 which fit in a u64.
+const U64_POW10: [u64; 20] = [
+    1, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000,
+    1000000000, 10000000000, 100000000000, 1000000000000,
+    10000000000000, 100000000000000, 1000000000000000,
+    10000000000000000, 100000000000000000, 1000000000000000000,
+    10000000000000000000
+];
+
+// Maximum exponent magnitude, to avoid overflow while parsing.
+const MAX_EXPONENT: i64 = 0x10000;
+
+/// Parsed components of a decimal float literal.
+struct Decimal {
+    /// If the literal is negative.
+    negative: bool,
+    /// Significant digits, without trailing zeros.
+    mantissa: u64,
+    /// Decimal exponent of the mantissa.
+    exponent: i64,
+}
+
+// Multiply the mantissa by `10^zeros` and add a digit, or panic.
+const fn push_digit(mantissa: u64, zeros: usize, digit: u64) -> u64 {
+    if mantissa == 0 {
+        return digit;
+    } else if zeros + 1 >= U64_POW10.len() {
+        panic!("const float literal has too many significant digits");
+    }
+    match mantissa.checked_mul(U64_POW10[zeros + 1]) {
+        Some(v) => match v.checked_add(digit) {
+            Some(v) => v,
+            None    => panic!("const float literal has too many significant digits"),
+        },
+        None    => panic!("const float literal has too many significant digits"),
+    }
+}
+
+// Parse the literal into a sign, mantissa and exponent, or panic.
+//
+// Trailing zeros are deferred, and only added to the mantissa when
+// a non-zero digit follows, so `1.50000000000000000000` is valid.
+const fn decompose(bytes: &[u8]) -> Decimal {
+    let length = bytes.len();
+    let mut index = 0;
+
+    // Sign
+    let mut negative = false;
+    if index < length && (bytes[index] == b'+' || bytes[index] == b'-') {
+        negative = bytes[index] == b'-';
+        index += 1;
+    }
+
+    // Mantissa
+    let mut mantissa: u64 = 0;
+    let mut zeros: usize = 0;
+    let mut digits: usize = 0;
+    let mut fraction_digits: i64 = 0;
+    let mut is_fraction = false;
+    while index < length {
+        let c = bytes[index];
+        if c >= b'0' && c <= b'9' {
+            if c == b'0' {
+                zeros += 1;
+            } else {
+                mantissa = push_digit(mantissa, zeros, (c - b'0') as u64);
+                zeros = 0;
+            }
+            digits += 1;
+            if is_fraction {
+                fraction_digits += 1;
+            }
+        } else if c == b'.' && !is_fraction {
+            is_fraction = true;
+        } else {
+            break;
+        }
+        index += 1;
+    }
+    if digits == 0 {
+        panic!("const float literal has no mantissa digits");
+    }
+
+    // Exponent
+    let mut exponent: i64 = 0;
+    if index < length && (bytes[index] == b'e' || bytes[index] == b'E') {
+        index += 1;
+        let mut exponent_negative = false;
+        if index < length && (bytes[index] == b'+' || bytes[index] == b'-') {
+            exponent_negative = bytes[index] == b'-';
+            index += 1;
+        }
+        let start = index;
+        while index < length && bytes[index] >= b'0' && bytes[index] <= b'9' {
+            if exponent < MAX_EXPONENT {
+                exponent = exponent * 10 + (bytes[index] - b'0') as i64;
+            }
+            index += 1;
+        }
+        if index == start {
+            panic!("const float literal has no exponent digits");
+        }
+        if exponent_negative {
+            exponent = -exponent;
+        }
+    }
+    if index != length {
+        panic!("const float literal has an invalid digit");
+    }
+
+    Decimal {
+        negative,
+        mantissa,
+        exponent: exponent + zeros as i64 - fraction_digits,
+    }
+}
+
+// Move powers of 10 from the exponent into the mantissa, so the
+// exponent is at most `max_exponent`, or panic.
+const fn disguised_mantissa(mantissa: u64, exponent: i64, max_exponent: i64, max_mantissa: u64)
+    -> u64
+{
+    let shift = (exponent - max_exponent) as usize;
+    if shift >= U64_POW10.len() {
+        panic!("const float literal cannot be parsed exactly");
+    }
+    match mantissa.checked_mul(U64_POW10[shift]) {
+        Some(v) if v <= max_mantissa => v,
+        _                           => panic!("const float literal cannot be parsed exactly"),
+    }
+}
+
+/// Parse a simple decimal literal into an `f32` in a `const` context.
+///
+/// The literal has an optional sign, decimal digits with an optional
+/// decimal point, and an optional exponent starting with `e` or `E`.
+/// Digit separators, special values and non-decimal radixes are not
+/// supported.
+///
+/// Only literals which can be parsed exactly are supported: the
+/// significant digits, without trailing zeros, must fit in 24 bits,
+/// and the decimal exponent must be exactly representable. This is
+/// the case for most hand-written literals, such as `3.14` or
+/// `1.5e-3`.
+///
+/// # Panics
+///
+/// Panics if the literal is invalid or cannot be parsed exactly,
+/// which is a compile error when evaluated in a `const` context.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// const X: f32 = lexical_core::const_parse_f32("3.14");
+/// assert_eq!(X, 3.14);
+/// # }
+/// ```
+pub const fn const_parse_f32(string: &str) -> f32 {
+    const MAX_MANTISSA: u64 = 1 << 24;
+    let decimal = decompose(string.as_bytes());
+    let mut exponent = decimal.exponent;
+    let mut mantissa = decimal.mantissa;
+    if mantissa == 0 {
+        exponent = 0;
+    } else if mantissa > MAX_MANTISSA {
+        panic!("const float literal cannot be parsed exactly");
+    } else if exponent > 10 {
+        mantissa = disguised_mantissa(mantissa, exponent, 10, MAX_MANTISSA);
+        exponent = 10;
+    } else if exponent < -10 {
+        panic!("const float literal cannot be parsed exactly");
+    }
+
+    let value = if exponent >= 0 {
+        mantissa as f32 * F32_POW10[exponent as usize]
+    } else {
+        mantissa as f32 / F32_POW10[(-exponent) as usize]
+    };
+    if decimal.negative { -value } else { value }
+}
+
+/// Parse a simple decimal literal into an `f64` in a `const` context.
+///
+/// The literal has an optional sign, decimal digits with an optional
+/// decimal point, and an optional exponent starting with `e` or `E`.
+/// Digit separators, special values and non-decimal radixes are not
+/// supported.
+///
+/// Only literals which can be parsed exactly are supported: the
+/// significant digits, without trailing zeros, must fit in 53 bits,
+/// and the decimal exponent must be exactly representable. This is
+/// the case for most hand-written literals, such as `3.14` or
+/// `1.5e-3`.
+///
+/// # Panics
+///
+/// Panics if the literal is invalid or cannot be parsed exactly,
+/// which is a compile error when evaluated in a `const` context.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// const X: f64 = lexical_core::const_parse_f64("3.14");
+/// const Y: f64 = lexical_core::const_parse_f64("-1.5e-3");
+/// assert_eq!(X, 3.14);
+/// assert_eq!(Y, -0.0015);
+/// # }
+/// ```
+///
+/// Literals which cannot be parsed exactly fail to compile:
+///
+/// ```rust,compile_fail
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// const X: f64 = lexical_core::const_parse_f64("1e300");
+/// # }
+/// ```
+pub const fn const_parse_f64(string: &str) -> f64 {
+    const MAX_MANTISSA: u64 = 1 << 53;
+    let decimal = decompose(string.as_bytes());
+    let mut exponent = decimal.exponent;
+    let mut mantissa = decimal.mantissa;
+    if mantissa == 0 {
+        exponent = 0;
+    } else if mantissa > MAX_MANTISSA {
+        panic!("const float literal cannot be parsed exactly");
+    } else if exponent > 22 {
+        mantissa = disguised_mantissa(mantissa, exponent, 22, MAX_MANTISSA);
+        exponent = 22;
+    } else if exponent < -22 {
+        panic!("const float literal cannot be parsed exactly");
+    }
+
+    let value = if exponent >= 0 {
+        mantissa as f64 * F64_POW10[exponent as usize]
+    } else {
+        mantissa as f64 / F64_POW10[(-exponent) as usize]
+    };
+    if decimal.negative { -value } else { value }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const F32_PI: f32 = const_parse_f32("3.14");
+    const F64_PI: f64 = const_parse_f64("3.14");
+    const F64_SMALL: f64 = const_parse_f64("-1.5e-3");
+    const F64_LARGE: f64 = const_parse_f64("1.5E+25");
+    const F64_ZEROS: f64 = const_parse_f64("1.50000000000000000000000000");
+    const F64_NEG_ZERO: f64 = const_parse_f64("-0.0e999");
+
+    #[test]
+    fn const_parse_f32_test() {
+        assert_eq!(F32_PI, 3.14);
+        assert_eq!(const_parse_f32("0"), 0.0);
+        assert_eq!(const_parse_f32("+1"), 1.0);
+        assert_eq!(const_parse_f32("1."), 1.0);
+        assert_eq!(const_parse_f32(".5"), 0.5);
+        assert_eq!(const_parse_f32("16777216"), 16777216.0);
+        assert_eq!(const_parse_f32("1e-10"), 1e-10);
+        assert_eq!(const_parse_f32("1e15"), 1e15);
+        assert_eq!(const_parse_f32("-2.5e3"), -2500.0);
+    }
+
+    #[test]
+    fn const_parse_f64_test() {
+        assert_eq!(F64_PI, 3.14);
+        assert_eq!(F64_SMALL, -0.0015);
+        assert_eq!(F64_LARGE, 1.5e25);
+        assert_eq!(F64_ZEROS, 1.5);
+        assert!(F64_NEG_ZERO == 0.0 && F64_NEG_ZERO.is_sign_negative());
+        assert_eq!(const_parse_f64("9007199254740992"), 9007199254740992.0);
+        assert_eq!(const_parse_f64("0.1"), 0.1);
+        assert_eq!(const_parse_f64("0.00000000000000000000000001e5"), 1e-21);
+        assert_eq!(const_parse_f64("1e-22"), 1e-22);
+        assert_eq!(const_parse_f64("123456789e25"), 123456789e25);
+        assert_eq!(const_parse_f64("1000000000000000000000000000000"), 1e30);
+    }
+
+    #[test]
+    #[should_panic]
+    fn const_parse_f64_slow_test() {
+        const_parse_f64("9007199254740993");
+    }
+
+    #[test]
+    #[should_panic]
+    fn const_parse_f64_exponent_test() {
+        const_parse_f64("1e-23");
+    }
+
+    #[test]
+    #[should_panic]
+    fn const_parse_f64_invalid_test() {
+        const_parse_f64("1.5x");
+    }
+
+    #[test]
+    #[should_panic]
+    fn const_parse_f64_special_test() {
+        const_parse_f64("inf");
+    }
+
+    #[test]
+    #[should_panic]
+    fn const_parse_f64_empty_exponent_test() {
+        const_parse_f64("1e");
+    }
+}
@@ -0,0 +1,149 @@
+//! Estimate the cost of parsing a float string.
+
+use crate::util::*;
+use super::algorithm::correct::atod_parse_cost;
+
+// PARSE COST
+
+/// Estimated cost of parsing a float string, as if by [`estimate_cost`].
+///
+/// [`estimate_cost`]: fn.estimate_cost.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseCost {
+    /// Parsed with native float arithmetic on the fast path.
+    Fast,
+    /// Parsed with an extended-precision float on the moderate path.
+    Moderate,
+    /// May require arbitrary-precision arithmetic on the slow path.
+    Slow,
+}
+
+// Determine if the string is a special value, matching the parser.
+perftools_inline!{
+fn is_special(digits: &[u8]) -> bool {
+    let strings = [get_inf_string(), get_infinity_string(), get_nan_string()];
+    strings.iter().any(|s| digits.eq_ignore_ascii_case(s))
+}}
+
+/// Estimate the cost of parsing an `f64` from a numeric string.
+///
+/// This method scans the string once, without parsing the value, and
+/// classifies it by the algorithm [`parse`] would use, from the number
+/// of significant digits, whether non-zero digits are truncated, and
+/// the range of the exponent:
+///
+/// * `Fast` if the mantissa and the power of the radix are exactly
+///   representable, so the value is parsed with native float
+///   arithmetic. Special values and invalid strings are also `Fast`,
+///   since they are rejected or returned without arithmetic.
+/// * `Moderate` if no non-zero digits are truncated, so the value is
+///   parsed with an extended-precision float. Strings in a power-of-two
+///   radix are never slower, since they are always exact.
+/// * `Slow` if non-zero digits are truncated, so the value may require
+///   arbitrary-precision arithmetic to round correctly.
+///
+/// The estimate is conservative: a `Slow` string is often parsed
+/// without big integers, if the truncated digits cannot change the
+/// rounded value, but a `Fast` or `Moderate` string never needs them,
+/// except for rare values close to halfway between two floats.
+///
+/// * `radix`   - Radix for number decoding.
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`, or is not 10
+/// without the `radix` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ParseCost;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::estimate_cost(10, b"1.5"), ParseCost::Fast);
+/// assert_eq!(lexical_core::estimate_cost(10, b"1.5e300"), ParseCost::Moderate);
+/// assert_eq!(lexical_core::estimate_cost(10, b"9007199254740993"), ParseCost::Moderate);
+/// assert_eq!(lexical_core::estimate_cost(10, b"1.00000000000000011102230246251565404236316680908203125"), ParseCost::Slow);
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+#[inline]
+pub fn estimate_cost(radix: u8, bytes: &[u8])
+    -> ParseCost
+{
+    #[cfg(feature = "radix")]
+    assert_radix!(radix);
+
+    #[cfg(not(feature = "radix"))]
+    assert!(radix == 10, "Numerical base must be 10.");
+
+    let format = NumberFormat::standard().unwrap();
+    let (_, digits) = parse_sign::<f64>(bytes, format);
+    if digits.is_empty() || is_special(digits) {
+        return ParseCost::Fast;
+    }
+    match atod_parse_cost(digits, radix.as_u32(), format) {
+        Ok((cost, ptr)) if distance(bytes.as_ptr(), ptr) == bytes.len() => cost,
+        _ => ParseCost::Fast,
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_cost_test() {
+        // Invalid and special values.
+        assert_eq!(ParseCost::Fast, estimate_cost(10, b""));
+        assert_eq!(ParseCost::Fast, estimate_cost(10, b"-"));
+        assert_eq!(ParseCost::Fast, estimate_cost(10, b"1.5x"));
+        assert_eq!(ParseCost::Fast, estimate_cost(10, b"12345678901234567890123x"));
+        assert_eq!(ParseCost::Fast, estimate_cost(10, b"inf"));
+        assert_eq!(ParseCost::Fast, estimate_cost(10, b"-NaN"));
+
+        // Fast path.
+        assert_eq!(ParseCost::Fast, estimate_cost(10, b"0"));
+        assert_eq!(ParseCost::Fast, estimate_cost(10, b"-0.0e400"));
+        assert_eq!(ParseCost::Fast, estimate_cost(10, b"1.5"));
+        assert_eq!(ParseCost::Fast, estimate_cost(10, b"-1e22"));
+        assert_eq!(ParseCost::Fast, estimate_cost(10, b"9007199254740992"));
+        assert_eq!(ParseCost::Fast, estimate_cost(10, b"1000000e-28"));
+        assert_eq!(ParseCost::Fast, estimate_cost(10, b"100000000000000000000000"));
+
+        // Moderate path.
+        assert_eq!(ParseCost::Moderate, estimate_cost(10, b"0.1e-23"));
+        assert_eq!(ParseCost::Moderate, estimate_cost(10, b"1e308"));
+        assert_eq!(ParseCost::Moderate, estimate_cost(10, b"9007199254740993"));
+        assert_eq!(ParseCost::Moderate, estimate_cost(10, b"18446744073709551615"));
+        assert_eq!(ParseCost::Moderate, estimate_cost(10, b"123456789012345678900000"));
+
+        // Slow path, unless the exponent is out of range.
+        assert_eq!(ParseCost::Slow, estimate_cost(10, b"12345678901234567890123"));
+        assert_eq!(ParseCost::Slow, estimate_cost(10, b"2.4703282292062327208828439643411068618252990130716238221279284125033775364e-324"));
+        assert_eq!(ParseCost::Moderate, estimate_cost(10, b"12345678901234567890123e-400"));
+        assert_eq!(ParseCost::Moderate, estimate_cost(10, b"12345678901234567890123e400"));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn estimate_cost_radix_test() {
+        assert_eq!(ParseCost::Fast, estimate_cost(2, b"1010.1"));
+        assert_eq!(ParseCost::Fast, estimate_cost(16, b"1F.8"));
+        assert_eq!(ParseCost::Moderate, estimate_cost(2, b"100000000000000000000000000000000000000000000000000001"));
+        assert_eq!(ParseCost::Moderate, estimate_cost(16, b"123456789ABCDEF0123456789"));
+        assert_eq!(ParseCost::Fast, estimate_cost(3, b"12.1"));
+        assert_eq!(ParseCost::Slow, estimate_cost(3, b"1111111111111111111111111111111111111111111111111"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn estimate_cost_invalid_radix_test() {
+        estimate_cost(1, b"1.5");
+    }
+}
@@ -0,0 +1,131 @@
+//! Currency symbol prefixes for monetary amounts.
+
+use crate::lib::str;
+
+// CURRENCY SYMBOLS
+
+/// Accepted currency symbols before a monetary amount.
+///
+/// The currency symbol is optional, and may be multi-byte, like the
+/// euro sign `€` (U+20AC). By default, the symbol precedes the sign,
+/// like `"$-5"`. If the symbol follows the sign, like `"-$5"`, the
+/// sign is parsed before the symbol, and a sign after the symbol is
+/// invalid. If multiple symbols match, the longest is used.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{CurrencySymbols, FloatParser};
+/// # pub fn main() {
+/// const SYMBOLS: &[&[u8]] = &[b"$", b"\xE2\x82\xAC", b"US$"];
+/// let parser = FloatParser::new();
+/// let currency = CurrencySymbols::new(SYMBOLS);
+/// assert_eq!(parser.parse_currency::<f64>(b"$-5", &currency), Ok((-5.0, Some(0))));
+/// assert_eq!(parser.parse_currency::<f64>("\u{20AC}1.5".as_bytes(), &currency), Ok((1.5, Some(1))));
+/// assert_eq!(parser.parse_currency::<f64>(b"1.5", &currency), Ok((1.5, None)));
+///
+/// // Parse the symbol after the sign.
+/// let currency = CurrencySymbols::new(SYMBOLS).after_sign(true);
+/// assert_eq!(parser.parse_currency::<f64>(b"-US$5", &currency), Ok((-5.0, Some(2))));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CurrencySymbols {
+    /// Accepted currency symbols.
+    symbols: &'static [&'static [u8]],
+    /// Parse the symbol after the sign.
+    after_sign: bool,
+}
+
+impl CurrencySymbols {
+    /// Create currency symbols from accepted symbols, before the sign.
+    ///
+    /// * `symbols`     - Accepted currency symbols.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any symbol is empty or is not valid UTF-8, or if any
+    /// symbol starts with an ASCII digit, sign or decimal point.
+    #[inline]
+    pub fn new(symbols: &'static [&'static [u8]]) -> CurrencySymbols {
+        let is_valid = | s: &&[u8] | match s.first() {
+            Some(&c) => !c.is_ascii_digit() && c != b'+' && c != b'-' && c != b'.' && str::from_utf8(s).is_ok(),
+            None     => false,
+        };
+        assert!(symbols.iter().all(is_valid));
+        CurrencySymbols {
+            symbols,
+            after_sign: false,
+        }
+    }
+
+    /// Set whether the symbol follows the sign.
+    ///
+    /// * `after_sign`  - Parse the symbol after the sign, like `"-$5"`.
+    #[inline]
+    pub fn after_sign(mut self, after_sign: bool) -> CurrencySymbols {
+        self.after_sign = after_sign;
+        self
+    }
+
+    /// Get the accepted currency symbols.
+    #[inline]
+    pub fn get_symbols(&self) -> &'static [&'static [u8]] {
+        self.symbols
+    }
+
+    /// Get if the symbol follows the sign.
+    #[inline]
+    pub fn get_after_sign(&self) -> bool {
+        self.after_sign
+    }
+
+    // Match the longest currency symbol at the start of the bytes.
+    //
+    // Returns the index of the symbol, and the length of the symbol.
+    perftools_inline!{
+    pub(super) fn parse_symbol(&self, bytes: &[u8]) -> Option<(usize, usize)> {
+        self.symbols.iter()
+            .enumerate()
+            .filter(|&(_, s)| bytes.starts_with(s))
+            .max_by_key(|&(_, s)| s.len())
+            .map(|(index, s)| (index, s.len()))
+    }}
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_symbol_test() {
+        let currency = CurrencySymbols::new(&[b"$", b"\xE2\x82\xAC", b"US$"]);
+        assert_eq!(currency.parse_symbol(b"$5"), Some((0, 1)));
+        assert_eq!(currency.parse_symbol(b"\xE2\x82\xAC5"), Some((1, 3)));
+        assert_eq!(currency.parse_symbol(b"US$5"), Some((2, 3)));
+        assert_eq!(currency.parse_symbol(b"U$5"), None);
+        assert_eq!(currency.parse_symbol(b"5"), None);
+        assert_eq!(currency.parse_symbol(b""), None);
+
+        // Use the longest symbol.
+        let currency = CurrencySymbols::new(&[b"R", b"R$"]);
+        assert_eq!(currency.parse_symbol(b"R$5"), Some((1, 2)));
+        assert_eq!(currency.parse_symbol(b"R5"), Some((0, 1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_empty_test() {
+        CurrencySymbols::new(&[b""]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_sign_test() {
+        CurrencySymbols::new(&[b"-$"]);
+    }
+}
@@ -0,0 +1,209 @@
+//! Parse float strings to exact sign-magnitude decimal components.
+
+use crate::util::*;
+use super::algorithm::decimal::{decimal_components, significant_digits};
+use super::api::validate_sign;
+
+// DECIMAL COMPONENTS
+
+/// Exact magnitude of a float string, without rounding.
+///
+/// The magnitude is `significand * radix^(exponent - scale)`, where the
+/// significand contains every integer and fraction digit, including
+/// leading and trailing zeros, so `"1.50e3"` has a significand of `150`,
+/// an exponent of `3` and a scale of `2`. The sign is never part of
+/// the magnitude, so negative zero is a zero significand with a
+/// negative sign.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DecimalComponents {
+    /// Integer and fraction digits, as an integer.
+    pub significand: u64,
+    /// Exponent from the exponent notation, or 0 if not present.
+    pub exponent: i64,
+    /// Number of fraction digits.
+    pub scale: usize,
+}
+
+// Parse the sign and exact magnitude of a float string.
+perftools_inline!{
+pub(crate) fn parse_sign_magnitude(bytes: &[u8], radix: u32, format: NumberFormat)
+    -> Result<((bool, DecimalComponents), usize)>
+{
+    let index = | ptr | distance(bytes.as_ptr(), ptr);
+    let (sign, digits) = parse_sign::<f64>(bytes, format);
+    if digits.is_empty() {
+        return Err((ErrorCode::Empty, index(digits.as_ptr())).into());
+    }
+    let result = decimal_components(digits, radix, format)
+        .and_then(|result| validate_sign(bytes, digits, sign, format).map(|_| result));
+    match result {
+        Ok((Some(components), ptr)) => Ok(((sign == Sign::Negative, components), index(ptr))),
+        Ok((None, _))               => Err((ErrorCode::Overflow, 0).into()),
+        Err((code, ptr))            => Err((code, index(ptr)).into()),
+    }
+}}
+
+/// Write the significant digits of a float string to a buffer.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. The significant digits
+/// of the mantissa are written to the buffer, without leading or
+/// trailing zeros or the decimal point, and the number of digits and
+/// the exponent of the last digit are returned, so the value is the
+/// digits, as an integer, multiplied by `radix^exponent`. Zero has no
+/// significant digits, and an exponent of 0. The digits are written as
+/// they appear in the string, and the exponent saturates at the range
+/// of an `i32`, which is well beyond the range of any float.
+///
+/// The string must be unsigned and may not be a special value: a sign
+/// returns an error with `ErrorCode::InvalidDigit`. If the significant
+/// digits do not fit in the buffer, this returns an error with
+/// `ErrorCode::TooManyDigits`, at the index of the first digit that did
+/// not fit, and the buffer contains the digits preceding it.
+///
+/// * `bytes`   - Byte slice containing an unsigned numeric string.
+/// * `radix`   - Radix for number decoding.
+/// * `digits`  - Buffer for the significant digits.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`, or is not 10
+/// without the `radix` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ErrorCode;
+/// # pub fn main() {
+/// let mut digits = [0u8; 8];
+/// assert_eq!(lexical_core::extract_digits(b"0012.3400", 10, &mut digits), Ok((4, -2)));
+/// assert_eq!(&digits[..4], b"1234");
+/// assert_eq!(lexical_core::extract_digits(b"1.5e10", 10, &mut digits), Ok((2, 9)));
+/// assert_eq!(lexical_core::extract_digits(b"1200", 10, &mut digits), Ok((2, 2)));
+///
+/// let error = lexical_core::extract_digits(b"0.123456789", 10, &mut digits).unwrap_err();
+/// assert_eq!(error.code, ErrorCode::TooManyDigits);
+/// assert_eq!(error.index, 10);
+/// # }
+/// ```
+#[inline]
+pub fn extract_digits(bytes: &[u8], radix: u8, digits: &mut [u8])
+    -> Result<(usize, i32)>
+{
+    #[cfg(feature = "radix")]
+    assert_radix!(radix);
+
+    #[cfg(not(feature = "radix"))]
+    assert!(radix == 10, "Numerical base must be 10.");
+
+    let index = | ptr | distance(bytes.as_ptr(), ptr);
+    match bytes.first() {
+        None                        => return Err((ErrorCode::Empty, 0).into()),
+        Some(&b'+') | Some(&b'-')   => return Err((ErrorCode::InvalidDigit, 0).into()),
+        _                           => (),
+    }
+    let format = NumberFormat::standard().unwrap();
+    match significant_digits(bytes, radix.as_u32(), format, digits) {
+        Ok((result, ptr)) if index(ptr) == bytes.len() => Ok(result),
+        Ok((_, ptr))                                   => Err((ErrorCode::InvalidDigit, index(ptr)).into()),
+        Err((code, ptr))                               => Err((code, index(ptr)).into()),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(bytes: &[u8]) -> Result<((bool, DecimalComponents), usize)> {
+        parse_sign_magnitude(bytes, 10, NumberFormat::standard().unwrap())
+    }
+
+    fn components(significand: u64, exponent: i64, scale: usize) -> DecimalComponents {
+        DecimalComponents { significand: significand, exponent: exponent, scale: scale }
+    }
+
+    #[test]
+    fn parse_sign_magnitude_test() {
+        assert_eq!(Ok(((true, components(0, 0, 2)), 5)), parse(b"-0.00"));
+        assert_eq!(Ok(((false, components(0, 0, 2)), 4)), parse(b"0.00"));
+        assert_eq!(Ok(((false, components(0, 0, 0)), 2)), parse(b"+0"));
+        assert_eq!(Ok(((false, components(150, 3, 2)), 6)), parse(b"1.50e3"));
+        assert_eq!(Ok(((true, components(12345, -2, 3)), 10)), parse(b"-12.345e-2"));
+        assert_eq!(Ok(((false, components(5, 0, 1)), 2)), parse(b".5"));
+        assert_eq!(Ok(((false, components(5, 0, 0)), 2)), parse(b"5."));
+        assert_eq!(Ok(((false, components(15, 0, 1)), 3)), parse(b"1.5x"));
+        assert_eq!(Ok(((false, components(u64::max_value(), 0, 0)), 20)), parse(b"18446744073709551615"));
+        assert_eq!(Ok(((false, components(1, 0, 30)), 32)), parse(b"0.000000000000000000000000000001"));
+
+        // Errors.
+        assert_eq!(Err((ErrorCode::Overflow, 0).into()), parse(b"18446744073709551616"));
+        assert_eq!(Err((ErrorCode::Overflow, 0).into()), parse(b"-1.8446744073709551616"));
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), parse(b""));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), parse(b"-"));
+        assert_eq!(Err((ErrorCode::LoneDot, 0).into()), parse(b"."));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), parse(b"1e"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parse(b"inf"));
+    }
+
+    #[test]
+    fn extract_digits_test() {
+        let mut digits = [0u8; 8];
+        let mut extract = | bytes: &[u8] | extract_digits(bytes, 10, &mut digits).map(|(n, e)| (digits[..n].to_vec(), e));
+        assert_eq!(Ok((b"1234".to_vec(), -2)), extract(b"0012.3400"));
+        assert_eq!(Ok((b"15".to_vec(), 9)), extract(b"1.5e10"));
+        assert_eq!(Ok((b"12".to_vec(), 2)), extract(b"1200"));
+        assert_eq!(Ok((b"1".to_vec(), -30)), extract(b"0.000000000000000000000000000001"));
+        assert_eq!(Ok((b"10000001".to_vec(), 0)), extract(b"10000001"));
+        assert_eq!(Ok((b"1".to_vec(), 7)), extract(b"100000000000000e-7"));
+        assert_eq!(Ok((b"".to_vec(), 0)), extract(b"0.000e5"));
+        assert_eq!(Ok((b"5".to_vec(), -1)), extract(b".5"));
+        assert_eq!(Ok((b"1".to_vec(), i32::max_value())), extract(b"1e99999999999"));
+        assert_eq!(Ok((b"1".to_vec(), i32::min_value())), extract(b"1e-99999999999"));
+
+        // Trailing zeros do not need to fit in the buffer.
+        assert_eq!(Ok((b"12345678".to_vec(), 2)), extract(b"1234567800"));
+        assert_eq!(Ok((b"12345678".to_vec(), -8)), extract(b"0.12345678000000000000"));
+
+        // Errors.
+        let err = | code, index | Err((code, index).into());
+        assert_eq!(err(ErrorCode::TooManyDigits, 10), extract(b"0.123456789"));
+        assert_eq!(err(ErrorCode::TooManyDigits, 11), extract(b"123456780001"));
+        assert_eq!(err(ErrorCode::InvalidDigit, 0), extract(b"-1.5"));
+        assert_eq!(err(ErrorCode::InvalidDigit, 3), extract(b"1.5x"));
+        assert_eq!(err(ErrorCode::Empty, 0), extract(b""));
+        assert_eq!(err(ErrorCode::EmptyMantissa, 0), extract(b"inf"));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn extract_digits_radix_test() {
+        let mut digits = [0u8; 8];
+        assert_eq!(Ok((3, -1)), extract_digits(b"AB.C", 16, &mut digits));
+        assert_eq!(&digits[..3], b"ABC");
+        assert_eq!(Ok((2, 1)), extract_digits(b"1.1e10", 2, &mut digits));
+        assert_eq!(&digits[..2], b"11");
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn parse_sign_magnitude_radix_test() {
+        let format = NumberFormat::standard().unwrap();
+        assert_eq!(Ok(((true, components(11, 1, 1)), 8)), parse_sign_magnitude(b"-101.1e1", 2, format));
+        assert_eq!(Ok(((false, components(0xABC, 0, 1)), 4)), parse_sign_magnitude(b"AB.C", 16, format));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn parse_sign_magnitude_format_test() {
+        let format = NumberFormat::REQUIRED_MANTISSA_SIGN;
+        assert_eq!(Ok(((false, components(15, 0, 1)), 4)), parse_sign_magnitude(b"+1.5", 10, format));
+        assert_eq!(Err((ErrorCode::MissingMantissaSign, 0).into()), parse_sign_magnitude(b"1.5", 10, format));
+
+        let format = NumberFormat::from_separator(b'_') | NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR;
+        assert_eq!(Ok(((false, components(12345, 0, 1)), 7)), parse_sign_magnitude(b"1_234.5", 10, format));
+    }
+}
@@ -0,0 +1,184 @@
+//! Specialized parser for short decimal `f64` strings.
+
+use crate::util::*;
+
+// The `f64` constants are inlined, rather than read from the `Float`
+// traits, so the checks fold to immediate comparisons. The generic
+// parser remains the reference: every string that isn't exactly
+// representable on the fast path is parsed by the generic parser.
+
+/// Maximum mantissa exactly representable by an `f64`, `2^53`.
+const MAX_MANTISSA: u64 = 1 << 53;
+
+/// Maximum number of mantissa digits that cannot overflow a `u64`.
+const MAX_DIGITS: usize = 19;
+
+/// Maximum power of 10 exactly representable by an `f64`.
+const MAX_EXPONENT: i64 = 22;
+
+/// Exact powers of 10, from `10^0` to `10^22`.
+const POWERS: [f64; 23] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11,
+    1e12, 1e13, 1e14, 1e15, 1e16, 1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+];
+
+// Parse digits into the mantissa, returning the number of digits.
+perftools_inline!{
+fn parse_digits(bytes: &[u8], index: &mut usize, mantissa: &mut u64) -> usize {
+    let start = *index;
+    while let Some(&c) = bytes.get(*index) {
+        if !c.is_ascii_digit() {
+            break;
+        }
+        *mantissa = mantissa.wrapping_mul(10).wrapping_add((c - b'0') as u64);
+        *index += 1;
+    }
+    *index - start
+}}
+
+// Parse the float on the fast path, returning `None` if the string
+// isn't exactly representable on the fast path, or is invalid.
+perftools_inline!{
+fn parse_fast(bytes: &[u8]) -> Option<f64> {
+    #[cfg(feature = "rounding")] {
+        if get_float_rounding() != RoundingKind::NearestTieEven {
+            return None;
+        }
+    }
+
+    // Parse the sign, integer and fraction.
+    let mut index = 0;
+    let negative = match bytes.first() {
+        Some(&b'-') => { index += 1; true },
+        Some(&b'+') => { index += 1; false },
+        _           => false,
+    };
+    let mut mantissa = 0;
+    let integer_digits = parse_digits(bytes, &mut index, &mut mantissa);
+    let mut fraction_digits = 0;
+    if bytes.get(index) == Some(&b'.') {
+        index += 1;
+        fraction_digits = parse_digits(bytes, &mut index, &mut mantissa);
+    }
+    let digits = integer_digits + fraction_digits;
+    if digits == 0 || digits > MAX_DIGITS || mantissa > MAX_MANTISSA {
+        return None;
+    }
+
+    // Parse the exponent, which must end the string.
+    let mut exponent = -(fraction_digits as i64);
+    if let Some(&c) = bytes.get(index) {
+        if !c.eq_ignore_ascii_case(&get_exponent_default_char()) {
+            return None;
+        }
+        index += 1;
+        let exponent_negative = match bytes.get(index) {
+            Some(&b'-') => { index += 1; true },
+            Some(&b'+') => { index += 1; false },
+            _           => false,
+        };
+        let mut value = 0;
+        let exponent_digits = parse_digits(bytes, &mut index, &mut value);
+        if exponent_digits == 0 || exponent_digits > 3 || index != bytes.len() {
+            return None;
+        }
+        exponent += if exponent_negative { -(value as i64) } else { value as i64 };
+    }
+
+    // Both the mantissa and power are exact, so the result is rounded once.
+    let float = mantissa as f64;
+    let float = if !(-MAX_EXPONENT..=MAX_EXPONENT).contains(&exponent) {
+        return None;
+    } else if exponent >= 0 {
+        float * POWERS[exponent as usize]
+    } else {
+        float / POWERS[-exponent as usize]
+    };
+    Some(if negative { -float } else { float })
+}}
+
+/// Parse `f64` from a short decimal string, on a specialized fast path.
+///
+/// This method parses the entire string, returning an error if any
+/// invalid digits are found during parsing, exactly like [`parse`]
+/// for an `f64`. Strings with at most 19 mantissa digits, a mantissa
+/// of at most `2^53`, and a decimal exponent from -22 to 22, such as
+/// `"1.5"` or `"-0.001e3"`, are parsed with a single multiplication or
+/// division, without the generic float parser. Every other string,
+/// including special values and invalid strings, is parsed with the
+/// generic float parser, so with the `correct` feature, the result is
+/// identical to [`parse`].
+///
+/// This is faster than [`parse`] for short decimal strings, which
+/// avoids dispatching through the generic parser, but slower for
+/// strings that fail the fast path, which are scanned twice.
+///
+/// * `bytes`   - Byte slice containing a decimal string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_f64_fast(b"1.5"), Ok(1.5));
+/// assert_eq!(lexical_core::parse_f64_fast(b"-0.001e3"), Ok(-1.0));
+/// assert_eq!(lexical_core::parse_f64_fast(b"1e300"), Ok(1e300));
+/// assert!(lexical_core::parse_f64_fast(b"1.5x").is_err());
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+#[inline]
+pub fn parse_f64_fast(bytes: &[u8]) -> Result<f64> {
+    match parse_fast(bytes) {
+        Some(float) => Ok(float),
+        None        => f64::from_lexical(bytes),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_fast_test() {
+        assert_eq!(Some(1.5), parse_fast(b"1.5"));
+        assert_eq!(Some(-1.0), parse_fast(b"-0.001e3"));
+        assert_eq!(Some(0.1), parse_fast(b"+.1"));
+        assert_eq!(Some(5.0), parse_fast(b"5."));
+        assert_eq!(Some(1e22), parse_fast(b"1E22"));
+        assert_eq!(Some(9007199254740992.0), parse_fast(b"9007199254740992"));
+        assert_eq!(Some(-0.0), parse_fast(b"-0"));
+
+        // Strings parsed by the generic parser.
+        assert_eq!(None, parse_fast(b"9007199254740993"));
+        assert_eq!(None, parse_fast(b"12345678901234567890"));
+        assert_eq!(None, parse_fast(b"1e23"));
+        assert_eq!(None, parse_fast(b"1e-23"));
+        assert_eq!(None, parse_fast(b"1e0001"));
+        assert_eq!(None, parse_fast(b"NaN"));
+        assert_eq!(None, parse_fast(b"1e"));
+        assert_eq!(None, parse_fast(b"1.5x"));
+        assert_eq!(None, parse_fast(b"."));
+        assert_eq!(None, parse_fast(b""));
+    }
+
+    #[test]
+    fn parse_f64_fast_test() {
+        // The result is identical to the generic parser.
+        let strings: &[&[u8]] = &[
+            b"0", b"-0.0", b"1.5", b"0.1", b"0.3", b"123456.789", b"-2.5e-3", b"1e22", b"1e23",
+            b"9007199254740993", b"1.7976931348623157e308", b"5e-324", b"1e400", b"inf", b"-NaN",
+            b"", b".", b"1e", b"1.5x", b"+", b"1e+", b"1_0",
+        ];
+        for &string in strings.iter() {
+            match (parse_f64_fast(string), f64::from_lexical(string)) {
+                (Ok(x), Ok(y)) => assert!(x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan())),
+                (x, y)         => assert_eq!(x, y),
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Hemisphere suffixes for geographic coordinates.
+
+// HEMISPHERE
+
+/// Hemisphere parsed from the suffix of a geographic coordinate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hemisphere {
+    /// Northern hemisphere, for latitudes.
+    North,
+    /// Southern hemisphere, for latitudes.
+    South,
+    /// Eastern hemisphere, for longitudes.
+    East,
+    /// Western hemisphere, for longitudes.
+    West,
+}
+
+impl Hemisphere {
+    /// Get if coordinates in the hemisphere are negative.
+    ///
+    /// Southern latitudes and western longitudes are negative.
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        match *self {
+            Hemisphere::South | Hemisphere::West => true,
+            Hemisphere::North | Hemisphere::East => false,
+        }
+    }
+}
+
+// HEMISPHERES
+
+/// Accepted hemisphere suffixes for geographic coordinates.
+///
+/// By default, the letters `N`, `S`, `E` and `W` are accepted,
+/// case-insensitively, and the hemisphere does not change the sign
+/// of the value. If signed, southern and western coordinates are
+/// negated, so `"12.5S"` is parsed as `-12.5`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{FloatParser, Hemisphere, Hemispheres};
+/// # pub fn main() {
+/// let parser = FloatParser::new();
+/// let hemispheres = Hemispheres::new();
+/// assert_eq!(parser.parse_hemisphere::<f64>(b"12.5N", &hemispheres), Ok((12.5, Some(Hemisphere::North))));
+/// assert_eq!(parser.parse_hemisphere::<f64>(b"12.5", &hemispheres), Ok((12.5, None)));
+///
+/// // Derive the sign from the hemisphere, with French letters for west.
+/// let hemispheres = Hemispheres::new().letters(b'N', b'S', b'E', b'O').signed(true);
+/// assert_eq!(parser.parse_hemisphere::<f64>(b"1.5o", &hemispheres), Ok((-1.5, Some(Hemisphere::West))));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hemispheres {
+    /// Letters for the north, south, east and west hemispheres.
+    letters: [u8; 4],
+    /// Match the letters case-sensitively.
+    case_sensitive: bool,
+    /// Negate southern and western coordinates.
+    signed: bool,
+}
+
+impl Hemispheres {
+    /// Create hemispheres accepting `N`, `S`, `E` and `W`, case-insensitively.
+    #[inline]
+    pub fn new() -> Hemispheres {
+        Hemispheres {
+            letters: *b"NSEW",
+            case_sensitive: false,
+            signed: false,
+        }
+    }
+
+    /// Set the letters for each hemisphere.
+    ///
+    /// * `north`   - Letter for the northern hemisphere.
+    /// * `south`   - Letter for the southern hemisphere.
+    /// * `east`    - Letter for the eastern hemisphere.
+    /// * `west`    - Letter for the western hemisphere.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any letter is not an ASCII letter.
+    #[inline]
+    pub fn letters(mut self, north: u8, south: u8, east: u8, west: u8) -> Hemispheres {
+        let letters = [north, south, east, west];
+        assert!(letters.iter().all(|c| c.is_ascii_alphabetic()));
+        self.letters = letters;
+        self
+    }
+
+    /// Set whether the letters are matched case-sensitively.
+    ///
+    /// * `case_sensitive`  - Match the letters case-sensitively.
+    #[inline]
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> Hemispheres {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Set whether southern and western coordinates are negated.
+    ///
+    /// If set, southern and western coordinates may not have an
+    /// explicit sign, since the sign would be applied twice.
+    ///
+    /// * `signed`  - Derive the sign from the hemisphere.
+    #[inline]
+    pub fn signed(mut self, signed: bool) -> Hemispheres {
+        self.signed = signed;
+        self
+    }
+
+    /// Get the letters for the north, south, east and west hemispheres.
+    #[inline]
+    pub fn get_letters(&self) -> [u8; 4] {
+        self.letters
+    }
+
+    /// Get if the letters are matched case-sensitively.
+    #[inline]
+    pub fn get_case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+
+    /// Get if southern and western coordinates are negated.
+    #[inline]
+    pub fn get_signed(&self) -> bool {
+        self.signed
+    }
+
+    /// Get the hemisphere for a letter, if the letter is accepted.
+    ///
+    /// * `c`   - Letter following the coordinate.
+    #[inline]
+    pub fn hemisphere(&self, c: u8) -> Option<Hemisphere> {
+        const HEMISPHERES: [Hemisphere; 4] = [
+            Hemisphere::North, Hemisphere::South, Hemisphere::East, Hemisphere::West
+        ];
+        let case_sensitive = self.case_sensitive;
+        let is_match = | &l: &u8 | if case_sensitive { l == c } else { l.eq_ignore_ascii_case(&c) };
+        self.letters.iter().position(is_match).map(|i| HEMISPHERES[i])
+    }
+}
+
+impl Default for Hemispheres {
+    #[inline]
+    fn default() -> Hemispheres {
+        Hemispheres::new()
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hemisphere_test() {
+        let hemispheres = Hemispheres::new();
+        assert_eq!(hemispheres, Hemispheres::default());
+        assert_eq!(hemispheres.hemisphere(b'N'), Some(Hemisphere::North));
+        assert_eq!(hemispheres.hemisphere(b's'), Some(Hemisphere::South));
+        assert_eq!(hemispheres.hemisphere(b'E'), Some(Hemisphere::East));
+        assert_eq!(hemispheres.hemisphere(b'w'), Some(Hemisphere::West));
+        assert_eq!(hemispheres.hemisphere(b'X'), None);
+        assert_eq!(hemispheres.hemisphere(b'0'), None);
+
+        let hemispheres = hemispheres.letters(b'N', b'S', b'O', b'W').case_sensitive(true);
+        assert_eq!(hemispheres.get_letters(), *b"NSOW");
+        assert_eq!(hemispheres.hemisphere(b'O'), Some(Hemisphere::East));
+        assert_eq!(hemispheres.hemisphere(b'E'), None);
+        assert_eq!(hemispheres.hemisphere(b'n'), None);
+
+        assert!(Hemisphere::South.is_negative());
+        assert!(Hemisphere::West.is_negative());
+        assert!(!Hemisphere::North.is_negative());
+        assert!(!Hemisphere::East.is_negative());
+    }
+
+    #[test]
+    #[should_panic]
+    fn letters_digit_test() {
+        Hemispheres::new().letters(b'N', b'S', b'E', b'1');
+    }
+}
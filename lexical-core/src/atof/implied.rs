@@ -0,0 +1,81 @@
+//! Parse fixed-format fields with an implied radix point.
+
+use crate::util::*;
+use super::api::{to_signed, StringToFloat};
+
+// Parse the integer digits of a field, and shift the radix point left
+// by `scale` digits.
+//
+// The field has an optional sign and integer digits: a radix point,
+// exponent or special value is invalid. The scaling is exact, so the
+// value is rounded once, as if the radix point was present.
+perftools_inline!{
+pub(super) fn parse_implied<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, scale: usize)
+    -> Result<(F, usize)>
+{
+    let format = NumberFormat::standard().unwrap();
+    let (sign, digits) = parse_sign::<F>(bytes, format);
+    let index = | ptr | distance(bytes.as_ptr(), ptr);
+    if digits.is_empty() {
+        return Err((ErrorCode::Empty, index(digits.as_ptr())).into());
+    }
+
+    let count = digits.iter()
+        .take_while(|&&c| (c as char).is_digit(radix))
+        .count();
+    if count == 0 {
+        return Err((ErrorCode::EmptyMantissa, index(digits.as_ptr())).into());
+    }
+
+    let scale = scale.try_i64_or_max();
+    match F::scaled(&index!(digits[..count]), radix, lossy, sign, format, scale) {
+        Ok((value, ptr)) => Ok((to_signed(value, sign), index(ptr))),
+        Err((code, ptr)) => Err((code, index(ptr)).into()),
+    }
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_implied_test() {
+        assert_eq!(Ok((123.45, 5)), parse_implied::<f64>(b"12345", 10, false, 2));
+        assert_eq!(Ok((12345.0, 5)), parse_implied::<f64>(b"12345", 10, false, 0));
+        assert_eq!(Ok((0.12345, 5)), parse_implied::<f64>(b"12345", 10, false, 5));
+        assert_eq!(Ok((0.05, 1)), parse_implied::<f64>(b"5", 10, false, 2));
+        assert_eq!(Ok((0.05, 3)), parse_implied::<f64>(b"005", 10, false, 2));
+        assert_eq!(Ok((5e-30, 1)), parse_implied::<f64>(b"5", 10, false, 30));
+        assert_eq!(Ok((-123.45, 6)), parse_implied::<f64>(b"-12345", 10, false, 2));
+        assert_eq!(Ok((123.45, 6)), parse_implied::<f64>(b"+12345", 10, false, 2));
+        assert_eq!(Ok((123.45, 5)), parse_implied::<f32>(b"12345", 10, false, 2));
+        assert_eq!(Ok((0.1, 1)), parse_implied::<f32>(b"1", 10, false, 1));
+        assert_eq!(Ok((12.34, 4)), parse_implied::<f64>(b"1234.5", 10, false, 2));
+
+        // Zero keeps the sign.
+        let (value, processed) = parse_implied::<f64>(b"-000", 10, false, 2).unwrap();
+        assert!(value == 0.0 && value.is_sign_negative());
+        assert_eq!(processed, 4);
+
+        // Correctly rounded, even on the slow path.
+        assert_eq!(Ok((2.2250738585072014e-308, 17)), parse_implied::<f64>(b"22250738585072014", 10, false, 324));
+        assert_eq!(Ok((9007199254740993e-3, 16)), parse_implied::<f64>(b"9007199254740993", 10, false, 3));
+        assert_eq!(Ok((1.0000000000000002, 17)), parse_implied::<f64>(b"10000000000000002", 10, false, 16));
+
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), parse_implied::<f64>(b"", 10, false, 2));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), parse_implied::<f64>(b"-", 10, false, 2));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parse_implied::<f64>(b".5", 10, false, 2));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parse_implied::<f64>(b"inf", 10, false, 2));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn parse_implied_radix_test() {
+        assert_eq!(Ok((1.25, 3)), parse_implied::<f64>(b"101", 2, false, 2));
+        assert_eq!(Ok((-0.09375, 3)), parse_implied::<f64>(b"-18", 16, false, 2));
+        assert_eq!(Ok((0.04, 1)), parse_implied::<f64>(b"1", 5, false, 2));
+    }
+}
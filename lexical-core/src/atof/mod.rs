@@ -2,7 +2,67 @@
 
 // Hide implementation details.
 mod algorithm;
+#[cfg(feature = "format")]
+mod ambiguous;
 mod api;
+mod bits;
+#[cfg(feature = "correct")]
+mod both;
+mod bounds;
+mod category;
+mod classify;
+#[cfg(feature = "const_fn")]
+mod constant;
+#[cfg(feature = "correct")]
+mod cost;
+mod currency;
+mod decimal;
+mod fast;
+mod hemisphere;
+mod implied;
+mod numbers;
+mod parser;
+mod ratio;
+mod raw;
+#[cfg(feature = "correct")]
+mod residual;
+mod rounded;
+#[cfg(feature = "correct")]
+mod scratch;
+mod signs;
+mod special;
+mod truncate;
 
 // Re-exports
-pub use self::api::*;
+#[cfg(feature = "correct")]
+pub(crate) use self::algorithm::{Bigint, LargeOps, SharedOps, SmallOps};
+#[cfg(feature = "bignum")]
+pub use self::algorithm::Bignum;
+#[cfg(feature = "format")]
+pub use self::ambiguous::*;
+pub use self::bits::*;
+#[cfg(feature = "correct")]
+pub use self::both::*;
+pub use self::bounds::*;
+pub use self::category::*;
+pub use self::classify::*;
+#[cfg(feature = "const_fn")]
+pub use self::constant::*;
+#[cfg(feature = "correct")]
+pub use self::cost::*;
+pub use self::currency::*;
+pub use self::decimal::*;
+pub use self::fast::*;
+pub use self::hemisphere::*;
+pub use self::numbers::*;
+pub use self::parser::*;
+pub use self::ratio::*;
+pub use self::raw::*;
+#[cfg(feature = "correct")]
+pub use self::residual::*;
+pub use self::rounded::*;
+#[cfg(feature = "correct")]
+pub use self::scratch::*;
+pub use self::signs::*;
+pub use self::special::*;
+pub(crate) use self::truncate::*;
@@ -0,0 +1,180 @@
+//! Extract every number embedded in a buffer.
+
+use crate::lib::ops::Range;
+use crate::util::*;
+use super::parser::FloatParser;
+
+// NUMBERS
+
+/// Iterator over the numbers embedded in a buffer.
+///
+/// Created by [`numbers`]. Yields each number as an `f64`, with the
+/// range of bytes of the number in the buffer.
+///
+/// [`numbers`]: fn.numbers.html
+#[derive(Clone, Debug)]
+pub struct Numbers<'a> {
+    /// Buffer containing the numbers.
+    bytes: &'a [u8],
+    /// Index of the next byte to scan.
+    index: usize,
+    /// Radix for number decoding.
+    radix: u32,
+    /// Partial parser for each number.
+    parser: FloatParser,
+}
+
+impl<'a> Numbers<'a> {
+    // Create the iterator for the radix.
+    perftools_inline!{
+    fn new(bytes: &'a [u8], radix: u8) -> Numbers<'a> {
+        #[cfg(feature = "radix")]
+        let parser = FloatParser::new().radix(radix);
+
+        #[cfg(not(feature = "radix"))]
+        let parser = FloatParser::new();
+
+        Numbers {
+            bytes,
+            index: 0,
+            radix: radix.as_u32(),
+            parser: parser.dangling_exponent(true),
+        }
+    }}
+
+    // Determine if the byte at the index is a digit.
+    perftools_inline!{
+    fn is_digit(&self, index: usize) -> bool {
+        match self.bytes.get(index) {
+            Some(&c) => (c as char).is_digit(self.radix),
+            None     => false,
+        }
+    }}
+
+    // Determine if a number starts at the index.
+    perftools_inline!{
+    fn is_start(&self, index: usize) -> bool {
+        let is_dot = |i: usize| self.bytes.get(i) == Some(&b'.');
+        match self.bytes[index] {
+            b'+' | b'-' => self.is_digit(index + 1) || (is_dot(index + 1) && self.is_digit(index + 2)),
+            b'.'        => self.is_digit(index + 1),
+            _           => self.is_digit(index),
+        }
+    }}
+}
+
+impl<'a> Iterator for Numbers<'a> {
+    type Item = (f64, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.bytes.len() {
+            let start = self.index;
+            self.index += 1;
+            if !self.is_start(start) {
+                continue;
+            }
+            if let Ok((value, processed)) = self.parser.parse_partial::<f64>(&self.bytes[start..]) {
+                if processed != 0 {
+                    self.index = start + processed;
+                    return Some((value, start..self.index));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Iterate over every decimal number embedded in a buffer.
+///
+/// This method scans the buffer, skipping bytes that cannot start a
+/// number, and yields each number as an `f64`, with the range of bytes
+/// of the number in the buffer. A number starts at a digit, at a `.`
+/// followed by a digit, or at a `+` or `-` followed by a digit or by a
+/// `.` and a digit, and extends as far as a partial parser parses it.
+/// Ambiguous characters between numbers are handled as follows:
+///
+/// - A sign before a number is part of the number, even directly after
+///   another number, so `"3-5"` yields `3.0` and `-5.0`, not a range.
+/// - A `.` after the digits of a number is part of the number, if it is
+///   followed by a fraction, or by no digits, so `"1.2.3"` yields `1.2`
+///   and `0.3`, and `"5."` yields `5.0`, including the `.`.
+/// - An exponent marker without exponent digits is not part of the
+///   number, so `"5e"` yields `5.0`, excluding the `e`.
+/// - Special values, like `NaN` and `inf`, are not numbers, since they
+///   do not start with a digit, so words like `"information"` are
+///   skipped.
+///
+/// Since every byte is skipped if it cannot start a number, any other
+/// characters may separate the numbers, including letters and digit
+/// separators, so `"1,000"` yields `1.0` and `0.0`.
+///
+/// * `bytes`   - Byte slice containing the numbers.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let line = b"GET /api took 12.5ms, retries=-3, load 1e3.";
+/// let numbers: Vec<(f64, _)> = lexical_core::numbers(line).collect();
+/// assert_eq!(numbers, vec![(12.5, 14..18), (-3.0, 30..32), (1000.0, 39..42)]);
+/// # }
+/// ```
+#[inline]
+pub fn numbers(bytes: &[u8]) -> Numbers<'_> {
+    Numbers::new(bytes, 10)
+}
+
+/// Iterate over every number in the radix embedded in a buffer.
+///
+/// See [`numbers`]. In a radix above 10, letters are digits, so words
+/// made of the digits of the radix are numbers, for example, `"bad"`
+/// in radix 16.
+///
+/// * `bytes`   - Byte slice containing the numbers.
+/// * `radix`   - Radix for number decoding.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+///
+/// [`numbers`]: fn.numbers.html
+#[inline]
+#[cfg(feature = "radix")]
+pub fn numbers_radix(bytes: &[u8], radix: u8) -> Numbers<'_> {
+    Numbers::new(bytes, radix)
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_test() {
+        let line = b"t=0.25s x:-3 y:+.5 [1.5e3, 2E-2] 7";
+        let expected = vec![(0.25, 2..6), (-3.0, 10..12), (0.5, 15..18), (1500.0, 20..25), (0.02, 27..31), (7.0, 33..34)];
+        assert_eq!(expected, numbers(line).collect::<Vec<_>>());
+
+        // Signs and dots between numbers.
+        assert_eq!(vec![(3.0, 0..1), (-5.0, 1..3)], numbers(b"3-5").collect::<Vec<_>>());
+        assert_eq!(vec![(1.2, 0..3), (0.3, 3..5)], numbers(b"1.2.3").collect::<Vec<_>>());
+        assert_eq!(vec![(5.0, 4..6)], numbers(b"end 5. - . +").collect::<Vec<_>>());
+        assert_eq!(vec![(5.0, 0..1), (2.0, 3..4)], numbers(b"5e 2").collect::<Vec<_>>());
+
+        // Special values and buffers without numbers.
+        assert_eq!(vec![(1.0, 10..11)], numbers(b"inf NaN e 1").collect::<Vec<_>>());
+        assert_eq!(0, numbers(b"information").count());
+        assert_eq!(0, numbers(b"").count());
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn numbers_radix_test() {
+        // The `a` and `d` in `and` are hexadecimal digits.
+        let numbers = numbers_radix(b"ff, 0.8 and -10", 16).collect::<Vec<_>>();
+        assert_eq!(vec![(255.0, 0..2), (0.5, 4..7), (10.0, 8..9), (13.0, 10..11), (-16.0, 12..15)], numbers);
+    }
+}
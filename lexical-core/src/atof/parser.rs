@@ -0,0 +1,2442 @@
+//! Reusable float parser with a pre-resolved configuration.
+//!
+//! Parsing large numbers of floats with the same options through the
+//! stateless API re-resolves the configuration on every call. The
+//! `FloatParser` resolves the radix, lossiness and number format once,
+//! and then dispatches directly to the parsing algorithm.
+
+use crate::lib::ops;
+use crate::util::*;
+#[cfg(feature = "format")]
+use super::ambiguous::InferredSeparators;
+use super::api::{atof, atof_signed, atof_special, validate_sign, StringToFloat};
+#[cfg(feature = "correct")]
+use super::api::{is_fast_path, rounding_error};
+use super::bounds::FloatBounds;
+use super::currency::CurrencySymbols;
+use super::decimal::{parse_sign_magnitude, DecimalComponents};
+use super::hemisphere::{Hemisphere, Hemispheres};
+use super::implied::parse_implied;
+use super::signs::SignSpellings;
+use super::special::SpecialValues;
+
+// PARSER
+
+/// Reusable parser for floats with a fixed configuration.
+///
+/// The parser is cheap to copy and may be shared between threads, so
+/// it should be built once and re-used for every value parsed with the
+/// same options.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::FloatParser;
+/// # pub fn main() {
+/// let parser = FloatParser::new();
+/// assert_eq!(parser.parse::<f64>(b"1.5"), Ok(1.5));
+/// assert_eq!(parser.parse_partial::<f32>(b"1.5e3abc"), Ok((1500.0, 5)));
+///
+/// let lossy = FloatParser::new().lossy(true);
+/// assert_eq!(lossy.parse::<f64>(b"2.5"), Ok(2.5));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FloatParser {
+    /// Radix for number decoding.
+    radix: u32,
+    /// Use the lossy, fast algorithm.
+    lossy: bool,
+    /// Number format for the float grammar.
+    format: NumberFormat,
+    /// Accepted spellings for special values, if not the global config.
+    special: Option<SpecialValues>,
+    /// Accepted spellings for the sign, other than ASCII signs.
+    signs: Option<SignSpellings>,
+    /// Quote character surrounding the number, if quotes are accepted.
+    quote: Option<u8>,
+    /// Reject a leading quote without a trailing quote.
+    strict_quote: bool,
+    /// Reject infinite and NaN results.
+    reject_non_finite: bool,
+    /// Flush denormal results to zero.
+    flush_to_zero: bool,
+    /// Leave an exponent marker without exponent digits unconsumed.
+    dangling_exponent: bool,
+    /// Consume leading and trailing whitespace around the number.
+    trim_whitespace: bool,
+    /// Whitespace accepted between the sign and the number, if accepted.
+    sign_whitespace: Option<&'static [u8]>,
+    /// Consume the Unicode thin spaces around the number.
+    unicode_spaces: bool,
+    /// Reject a negative sign.
+    require_unsigned: bool,
+    /// Parse a negative zero as zero if a negative sign is rejected.
+    allow_negative_zero: bool,
+}
+
+impl FloatParser {
+    /// Create a parser for decimal floats with the standard number format.
+    #[inline]
+    pub fn new() -> FloatParser {
+        FloatParser {
+            radix: 10,
+            lossy: false,
+            format: NumberFormat::standard().unwrap(),
+            special: None,
+            signs: None,
+            quote: None,
+            strict_quote: false,
+            reject_non_finite: false,
+            flush_to_zero: false,
+            dangling_exponent: false,
+            trim_whitespace: false,
+            sign_whitespace: None,
+            unicode_spaces: false,
+            require_unsigned: false,
+            allow_negative_zero: false,
+        }
+    }
+
+    /// Set the radix for number decoding.
+    ///
+    /// * `radix`   - Radix for number decoding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn radix(mut self, radix: u8) -> FloatParser {
+        assert_radix!(radix);
+        self.radix = radix.as_u32();
+        self
+    }
+
+    /// Set whether to use the lossy, fast algorithm.
+    ///
+    /// * `lossy`   - Allow numerical rounding during parsing.
+    #[inline]
+    pub fn lossy(mut self, lossy: bool) -> FloatParser {
+        self.lossy = lossy;
+        self
+    }
+
+    /// Set the number format for the float grammar.
+    ///
+    /// * `format`  - Number format.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn format(mut self, format: NumberFormat) -> FloatParser {
+        self.format = format;
+        self
+    }
+
+    /// Set the accepted spellings for special (non-finite) values.
+    ///
+    /// By default, the parser accepts the global NaN and infinity
+    /// strings, with the case-sensitivity set by the number format.
+    /// The special values replace both, however, special values are
+    /// still rejected if the number format does not allow them.
+    ///
+    /// * `special` - Accepted spellings for special values.
+    #[inline]
+    pub fn special(mut self, special: SpecialValues) -> FloatParser {
+        self.special = Some(special);
+        self
+    }
+
+    /// Set the accepted spellings for the sign, other than ASCII signs.
+    ///
+    /// By default, only the ASCII signs permitted by the number format
+    /// are accepted. The sign spellings are recognized before the
+    /// number, and the number of processed bytes includes the full
+    /// spelling, so `"\u{2212}1.5"` is parsed as `-1.5` with 6 processed
+    /// bytes. A sign spelling may not be followed by an ASCII sign.
+    ///
+    /// * `signs`   - Accepted spellings for the sign.
+    #[inline]
+    pub fn signs(mut self, signs: SignSpellings) -> FloatParser {
+        self.signs = Some(signs);
+        self
+    }
+
+    /// Set the quote character accepted around the number.
+    ///
+    /// By default, quotes are not accepted. If set, a single leading
+    /// quote and a single trailing quote are consumed around the number,
+    /// and the number of processed bytes includes both quotes, so
+    /// `"\"3.14\""` is parsed as `3.14`. The quotes are optional, and
+    /// the contents are not unescaped, so the number must immediately
+    /// follow the leading quote and be immediately followed by the
+    /// trailing quote.
+    ///
+    /// * `quote`   - Quote character, or `None` to reject quotes.
+    #[inline]
+    pub fn quote(mut self, quote: Option<u8>) -> FloatParser {
+        self.quote = quote;
+        self
+    }
+
+    /// Set whether to reject a leading quote without a trailing quote.
+    ///
+    /// By default, a leading quote without a trailing quote is consumed
+    /// without the trailing quote. If set, an unbalanced quote returns
+    /// an error with `ErrorCode::InvalidDigit`, with the index where
+    /// the trailing quote was expected.
+    ///
+    /// * `strict`  - Require a trailing quote after a leading quote.
+    #[inline]
+    pub fn strict_quote(mut self, strict: bool) -> FloatParser {
+        self.strict_quote = strict;
+        self
+    }
+
+    /// Set whether to reject infinite and NaN results.
+    ///
+    /// By default, special values are parsed as infinity or NaN, and
+    /// a value which overflows is parsed as infinity. If set, any
+    /// non-finite result returns an error with `ErrorCode::NonFinite`,
+    /// with an index of 0.
+    ///
+    /// * `reject`  - Reject non-finite results.
+    #[inline]
+    pub fn reject_non_finite(mut self, reject: bool) -> FloatParser {
+        self.reject_non_finite = reject;
+        self
+    }
+
+    /// Set whether to flush denormal results to zero.
+    ///
+    /// By default, values below the smallest normal float are parsed as
+    /// denormal (subnormal) floats. If set, any denormal result is
+    /// replaced by a zero with the same sign, like the flush-to-zero
+    /// modes of some hardware. The value is flushed after rounding, so
+    /// a value which rounds up to the smallest normal float is kept.
+    ///
+    /// * `flush`   - Flush denormal results to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::FloatParser;
+    /// # pub fn main() {
+    /// let parser = FloatParser::new().flush_to_zero(true);
+    /// assert_eq!(parser.parse::<f64>(b"5e-320"), Ok(0.0));
+    /// assert_eq!(parser.parse::<f64>(b"-5e-320").map(f64::is_sign_negative), Ok(true));
+    /// assert_eq!(parser.parse::<f64>(b"2.3e-308"), Ok(2.3e-308));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn flush_to_zero(mut self, flush: bool) -> FloatParser {
+        self.flush_to_zero = flush;
+        self
+    }
+
+    /// Set whether to accept an exponent marker without exponent digits.
+    ///
+    /// By default, an exponent marker without any following exponent
+    /// digits, such as `"5e"`, returns an error with
+    /// `ErrorCode::EmptyExponent`. If set, the value is parsed from the
+    /// mantissa, and the exponent marker and any exponent sign are not
+    /// consumed, so partial parsers parse `"5e"` as `5.0` with 1
+    /// processed byte. This only affects number formats that require
+    /// exponent digits.
+    ///
+    /// * `dangling`    - Leave a dangling exponent marker unconsumed.
+    #[inline]
+    pub fn dangling_exponent(mut self, dangling: bool) -> FloatParser {
+        self.dangling_exponent = dangling;
+        self
+    }
+
+    /// Set whether to consume whitespace around the number.
+    ///
+    /// By default, whitespace is not consumed, so `parse` rejects
+    /// `"3.14 "` with `ErrorCode::InvalidDigit`, like `str::parse`. If
+    /// set, any leading and trailing ASCII whitespace is consumed around
+    /// the number, and around the quotes if the number is quoted, and
+    /// the number of processed bytes includes the whitespace.
+    ///
+    /// * `trim`    - Consume whitespace around the number.
+    #[inline]
+    pub fn trim_whitespace(mut self, trim: bool) -> FloatParser {
+        self.trim_whitespace = trim;
+        self
+    }
+
+    /// Set the whitespace accepted between the sign and the number.
+    ///
+    /// By default, whitespace after the sign is not accepted, so `parse`
+    /// rejects `"- 5"` with `ErrorCode::EmptyMantissa`. If set, any
+    /// bytes in the whitespace are consumed between a sign, including
+    /// the sign spellings, and the number, so `"- 5"` is parsed as
+    /// `-5.0`, and the number of processed bytes includes the
+    /// whitespace. Whitespace without a sign is not consumed.
+    ///
+    /// * `whitespace`  - Accepted whitespace bytes, or `None` to reject whitespace.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the whitespace contains an ASCII digit, sign or decimal point.
+    #[inline]
+    pub fn sign_whitespace(mut self, whitespace: Option<&'static [u8]>) -> FloatParser {
+        let is_valid = | c: &u8 | !c.is_ascii_digit() && *c != b'+' && *c != b'-' && *c != b'.';
+        assert!(whitespace.unwrap_or(&[]).iter().all(is_valid));
+        self.sign_whitespace = whitespace;
+        self
+    }
+
+    /// Set whether to accept the Unicode thin spaces in and around the number.
+    ///
+    /// SI notation groups digits with a thin space, such as
+    /// `"1 000 000.5"`, and tables often align numbers with thin spaces.
+    /// If set, leading and trailing thin spaces (U+2009) and narrow
+    /// no-break spaces (U+202F) around the number are consumed, and are
+    /// included in the number of processed bytes. If the number format
+    /// groups integer digits, such as `NumberFormat::grouped(b' ', 3)`,
+    /// the thin spaces and the no-break space (U+00A0) are also digit
+    /// separators between the groups of integer digits, with the same
+    /// validation as the digit separator.
+    /// Otherwise, thin spaces within the number end the number. The
+    /// string is never copied.
+    ///
+    /// * `unicode` - Accept the Unicode thin spaces in and around the number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ErrorCode, FloatParser, NumberFormat};
+    /// # pub fn main() {
+    /// let parser = FloatParser::new().unicode_spaces(true);
+    /// assert_eq!(parser.parse::<f64>("\u{2009}1000000.5\u{202F}".as_bytes()), Ok(1000000.5));
+    /// assert_eq!(parser.parse_partial::<f64>("1.5\u{2009}|".as_bytes()), Ok((1.5, 6)));
+    /// let error = parser.parse::<f64>("1\u{2009}000".as_bytes()).unwrap_err();
+    /// assert_eq!(error.code, ErrorCode::InvalidDigit);
+    ///
+    /// # #[cfg(feature = "format")] {
+    /// let format = NumberFormat::grouped(b' ', 3).unwrap();
+    /// let parser = parser.format(format);
+    /// assert_eq!(parser.parse::<f64>(b"1 000 000.5"), Ok(1000000.5));
+    /// assert_eq!(parser.parse::<f64>("1\u{2009}000\u{2009}000.5".as_bytes()), Ok(1000000.5));
+    /// assert_eq!(parser.parse::<f64>("1\u{202F}000 000.5".as_bytes()), Ok(1000000.5));
+    /// # }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn unicode_spaces(mut self, unicode: bool) -> FloatParser {
+        self.unicode_spaces = unicode;
+        self
+    }
+
+    /// Set whether to reject a negative sign.
+    ///
+    /// By default, a negative sign is parsed as a negative value. If
+    /// set, a negative sign, including the sign spellings, returns an
+    /// error with `ErrorCode::NegativeNotAllowed`, at the index of the
+    /// sign, so fields which must not be negative, like sizes or
+    /// probabilities, fail without parsing the value. A negative zero
+    /// also returns an error, unless [`allow_negative_zero`] is set.
+    ///
+    /// * `require` - Reject a negative sign.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ErrorCode, FloatParser};
+    /// # pub fn main() {
+    /// let parser = FloatParser::new().require_unsigned(true);
+    /// assert_eq!(parser.parse::<f64>(b"5"), Ok(5.0));
+    /// assert_eq!(parser.parse::<f64>(b"-5").map_err(|e| e.code), Err(ErrorCode::NegativeNotAllowed));
+    /// # }
+    /// ```
+    ///
+    /// [`allow_negative_zero`]: #method.allow_negative_zero
+    #[inline]
+    pub fn require_unsigned(mut self, require: bool) -> FloatParser {
+        self.require_unsigned = require;
+        self
+    }
+
+    /// Set whether to parse a negative zero if a negative sign is rejected.
+    ///
+    /// By default, with [`require_unsigned`], a negative zero, such as
+    /// `"-0.0"`, returns an error with `ErrorCode::NegativeNotAllowed`.
+    /// If set, a negative value which is zero, including a value which
+    /// underflows to zero, is parsed as a positive zero, and any other
+    /// negative value returns the error, after the value is parsed.
+    /// This has no effect without [`require_unsigned`].
+    ///
+    /// * `allow`   - Parse a negative zero as zero.
+    ///
+    /// [`require_unsigned`]: #method.require_unsigned
+    #[inline]
+    pub fn allow_negative_zero(mut self, allow: bool) -> FloatParser {
+        self.allow_negative_zero = allow;
+        self
+    }
+
+    /// Get the radix for number decoding.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn get_radix(&self) -> u8 {
+        self.radix as u8
+    }
+
+    /// Get if the parser uses the lossy, fast algorithm.
+    #[inline]
+    pub fn get_lossy(&self) -> bool {
+        self.lossy
+    }
+
+    /// Get the number format for the float grammar.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn get_format(&self) -> NumberFormat {
+        self.format
+    }
+
+    /// Get the accepted spellings for special values, if set.
+    #[inline]
+    pub fn get_special(&self) -> Option<SpecialValues> {
+        self.special
+    }
+
+    /// Get the accepted spellings for the sign, other than ASCII signs, if set.
+    #[inline]
+    pub fn get_signs(&self) -> Option<SignSpellings> {
+        self.signs
+    }
+
+    /// Get the quote character accepted around the number, if set.
+    #[inline]
+    pub fn get_quote(&self) -> Option<u8> {
+        self.quote
+    }
+
+    /// Get if the parser rejects a leading quote without a trailing quote.
+    #[inline]
+    pub fn get_strict_quote(&self) -> bool {
+        self.strict_quote
+    }
+
+    /// Get if the parser rejects infinite and NaN results.
+    #[inline]
+    pub fn get_reject_non_finite(&self) -> bool {
+        self.reject_non_finite
+    }
+
+    /// Get if denormal results are flushed to zero.
+    #[inline]
+    pub fn get_flush_to_zero(&self) -> bool {
+        self.flush_to_zero
+    }
+
+    /// Get if the parser leaves a dangling exponent marker unconsumed.
+    #[inline]
+    pub fn get_dangling_exponent(&self) -> bool {
+        self.dangling_exponent
+    }
+
+    /// Get if the parser consumes whitespace around the number.
+    #[inline]
+    pub fn get_trim_whitespace(&self) -> bool {
+        self.trim_whitespace
+    }
+
+    /// Get the whitespace accepted between the sign and the number, if set.
+    #[inline]
+    pub fn get_sign_whitespace(&self) -> Option<&'static [u8]> {
+        self.sign_whitespace
+    }
+
+    /// Get if the parser consumes the Unicode thin spaces around the number.
+    #[inline]
+    pub fn get_unicode_spaces(&self) -> bool {
+        self.unicode_spaces
+    }
+
+    /// Get if the parser rejects a negative sign.
+    #[inline]
+    pub fn get_require_unsigned(&self) -> bool {
+        self.require_unsigned
+    }
+
+    /// Get if the parser parses a negative zero if a negative sign is rejected.
+    #[inline]
+    pub fn get_allow_negative_zero(&self) -> bool {
+        self.allow_negative_zero
+    }
+
+    /// Parse float from string.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing.
+    ///
+    /// * `bytes`   - Byte slice containing a numeric string.
+    #[inline]
+    pub fn parse<F: FromFloatParser>(&self, bytes: &[u8])
+        -> Result<F>
+    {
+        to_complete!(F::from_float_parser, bytes, self)
+    }
+
+    /// Parse float from string.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed value until that point.
+    ///
+    /// * `bytes`   - Byte slice containing a numeric string.
+    #[inline]
+    pub fn parse_partial<F: FromFloatParser>(&self, bytes: &[u8])
+        -> Result<(F, usize)>
+    {
+        F::from_float_parser(bytes, self)
+    }
+
+    /// Parse float from a fixed-format field with an implied radix point.
+    ///
+    /// The field contains an optional sign and integer digits, and the
+    /// radix point is shifted left by `implied_digits`, so `"12345"`
+    /// with 2 implied digits is parsed as `123.45`, and `"5"` with 2
+    /// implied digits as `0.05`. The scaling is exact, so the value is
+    /// rounded once, as if the radix point was present. The number
+    /// format is not used, and a radix point, exponent or special
+    /// value is an invalid digit.
+    ///
+    /// * `bytes`           - Byte slice containing the field.
+    /// * `implied_digits`  - Number of implied fraction digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::FloatParser;
+    /// # pub fn main() {
+    /// let parser = FloatParser::new();
+    /// assert_eq!(parser.parse_implied_decimal::<f64>(b"12345", 2), Ok(123.45));
+    /// assert_eq!(parser.parse_implied_decimal::<f64>(b"-5", 2), Ok(-0.05));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn parse_implied_decimal<F: FromFloatParser>(&self, bytes: &[u8], implied_digits: usize)
+        -> Result<F>
+    {
+        let (value, processed) = F::implied_float_parser(bytes, implied_digits, self)?;
+        if processed == bytes.len() {
+            Ok(value)
+        } else {
+            Err((ErrorCode::InvalidDigit, processed).into())
+        }
+    }
+
+    /// Parse float from an unsigned magnitude, with a sign already parsed.
+    ///
+    /// For callers, such as tokenizers, which have already consumed the
+    /// sign, this avoids scanning for the sign again. The magnitude must
+    /// not contain a sign, which is an invalid digit, and the sign is
+    /// applied to the parsed value, so `"0"` with `negative` set is
+    /// parsed as `-0.0`. Since the number format cannot validate a sign
+    /// it did not parse, the mantissa sign flags are not checked, and
+    /// quotes are not accepted.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing.
+    ///
+    /// * `bytes`       - Byte slice containing the magnitude.
+    /// * `negative`    - If the value is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::FloatParser;
+    /// # pub fn main() {
+    /// let parser = FloatParser::new();
+    /// assert_eq!(parser.parse_signed::<f64>(b"1.5", true), Ok(-1.5));
+    /// assert_eq!(parser.parse_signed::<f64>(b"1.5", false), Ok(1.5));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn parse_signed<F: FromFloatParser>(&self, bytes: &[u8], negative: bool)
+        -> Result<F>
+    {
+        to_complete!(F::signed_float_parser, bytes, negative, self)
+    }
+
+    /// Parse float from an unsigned magnitude, with a sign already parsed.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed value until that point, with the same rules as
+    /// [`parse_signed`].
+    ///
+    /// * `bytes`       - Byte slice containing the magnitude.
+    /// * `negative`    - If the value is negative.
+    ///
+    /// [`parse_signed`]: #method.parse_signed
+    #[inline]
+    pub fn parse_partial_signed<F: FromFloatParser>(&self, bytes: &[u8], negative: bool)
+        -> Result<(F, usize)>
+    {
+        F::signed_float_parser(bytes, negative, self)
+    }
+
+    /// Parse a geographic coordinate from string, with a hemisphere suffix.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing. The coordinate may
+    /// be followed by a single hemisphere letter, so `"12.5N"` is
+    /// parsed as `12.5` in the northern hemisphere. If the hemispheres
+    /// are signed, southern and western coordinates are negated, and
+    /// an explicit sign followed by a southern or western hemisphere,
+    /// such as `"-12.5S"`, returns an error with `ErrorCode::InvalidDigit`,
+    /// at the index of the sign.
+    ///
+    /// An exponent marker without exponent digits is not consumed, so
+    /// `"12.5E"` is parsed in the eastern hemisphere, while `"12.5E1"`
+    /// is parsed as `125.0`, without a hemisphere.
+    ///
+    /// * `bytes`       - Byte slice containing a numeric string.
+    /// * `hemispheres` - Accepted hemisphere suffixes.
+    #[inline]
+    pub fn parse_hemisphere<F>(&self, bytes: &[u8], hemispheres: &Hemispheres)
+        -> Result<(F, Option<Hemisphere>)>
+        where F: FromFloatParser + ops::Neg<Output=F>
+    {
+        let (value, hemisphere, processed) = self.parse_partial_hemisphere(bytes, hemispheres)?;
+        match processed == bytes.len() {
+            true  => Ok((value, hemisphere)),
+            false => Err((ErrorCode::InvalidDigit, processed).into()),
+        }
+    }
+
+    /// Parse a geographic coordinate from string, with a hemisphere suffix.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the parsed value, the hemisphere, if
+    /// present, and the number of processed bytes, including the
+    /// hemisphere letter. See [`parse_hemisphere`](#method.parse_hemisphere).
+    ///
+    /// * `bytes`       - Byte slice containing a numeric string.
+    /// * `hemispheres` - Accepted hemisphere suffixes.
+    #[inline]
+    pub fn parse_partial_hemisphere<F>(&self, bytes: &[u8], hemispheres: &Hemispheres)
+        -> Result<(F, Option<Hemisphere>, usize)>
+        where F: FromFloatParser + ops::Neg<Output=F>
+    {
+        let parser = self.dangling_exponent(true);
+        let (value, processed) = parser.parse_partial::<F>(bytes)?;
+        match bytes.get(processed).and_then(|&c| hemispheres.hemisphere(c)) {
+            Some(hemisphere) if hemispheres.get_signed() && hemisphere.is_negative() => {
+                // The sign would be applied twice, so reject an explicit sign.
+                let has_sign = match bytes.first() {
+                    Some(&b'+') | Some(&b'-') => true,
+                    _                         => self.signs.and_then(|signs| signs.parse_sign(bytes)).is_some(),
+                };
+                match has_sign {
+                    true  => Err((ErrorCode::InvalidDigit, 0).into()),
+                    false => Ok((-value, Some(hemisphere), processed + 1)),
+                }
+            },
+            Some(hemisphere) => Ok((value, Some(hemisphere), processed + 1)),
+            None             => Ok((value, None, processed)),
+        }
+    }
+
+    /// Parse a monetary amount from string, with a currency symbol prefix.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing. The amount may
+    /// be preceded by one of the currency symbols, returning the index
+    /// of the matched symbol, so `"$-5"` is parsed as `-5.0`. If the
+    /// symbol follows the sign, `"-$5"` is parsed as `-5.0` instead.
+    /// The amount after the symbol is parsed with the configured number
+    /// format, including digit separators and the decimal point.
+    ///
+    /// * `bytes`       - Byte slice containing a numeric string.
+    /// * `currency`    - Accepted currency symbols.
+    #[inline]
+    pub fn parse_currency<F: FromFloatParser>(&self, bytes: &[u8], currency: &CurrencySymbols)
+        -> Result<(F, Option<usize>)>
+    {
+        let (value, symbol, processed) = self.parse_partial_currency(bytes, currency)?;
+        match processed == bytes.len() {
+            true  => Ok((value, symbol)),
+            false => Err((ErrorCode::InvalidDigit, processed).into()),
+        }
+    }
+
+    /// Parse a monetary amount from string, with a currency symbol prefix.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the parsed value, the index of the
+    /// currency symbol, if present, and the number of processed bytes,
+    /// including the symbol. See [`parse_currency`](#method.parse_currency).
+    ///
+    /// * `bytes`       - Byte slice containing a numeric string.
+    /// * `currency`    - Accepted currency symbols.
+    #[inline]
+    pub fn parse_partial_currency<F: FromFloatParser>(&self, bytes: &[u8], currency: &CurrencySymbols)
+        -> Result<(F, Option<usize>, usize)>
+    {
+        // Find the sign preceding the symbol, if the symbol follows the sign.
+        let sign = match bytes.first() {
+            _ if !currency.get_after_sign() => None,
+            Some(&b'+')                     => Some((false, 1)),
+            Some(&b'-')                     => Some((true, 1)),
+            _                               => self.signs.and_then(|signs| signs.parse_sign(bytes)),
+        };
+        let (negative, start) = sign.unwrap_or((false, 0));
+        let (index, length) = match currency.parse_symbol(&bytes[start..]) {
+            Some(symbol) => symbol,
+            None         => {
+                let (value, processed) = self.parse_partial::<F>(bytes)?;
+                return Ok((value, None, processed));
+            },
+        };
+
+        // Parse the amount after the symbol.
+        let offset = start + length;
+        let adjust = | e: Error | Error::from((e.code, e.index + offset));
+        let bytes = &bytes[offset..];
+        let (value, processed) = match currency.get_after_sign() {
+            true  => self.parse_partial_signed::<F>(bytes, negative),
+            false => self.parse_partial::<F>(bytes),
+        }.map_err(adjust)?;
+        Ok((value, Some(index), processed + offset))
+    }
+
+    /// Parse float from string, inferring the decimal point from the separators.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing. The comma or the
+    /// period is inferred to be the decimal point, with the other
+    /// separating groups of 3 integer digits, and the inferred separators
+    /// are returned, so the caller may check the interpretation. The
+    /// number format is replaced by the format for the inferred
+    /// separators. The heuristic is best-effort: see
+    /// [`InferredSeparators`] for the exact rules.
+    ///
+    /// * `bytes`   - Byte slice containing a numeric string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{FloatParser, InferredSeparators};
+    /// # pub fn main() {
+    /// let parser = FloatParser::new();
+    /// assert_eq!(parser.parse_ambiguous::<f64>(b"1,234.56"), Ok((1234.56, InferredSeparators::PeriodDecimalPoint)));
+    /// assert_eq!(parser.parse_ambiguous::<f64>(b"1.234,56"), Ok((1234.56, InferredSeparators::CommaDecimalPoint)));
+    /// assert_eq!(parser.parse_ambiguous::<f64>(b"1.234.567"), Ok((1234567.0, InferredSeparators::CommaDecimalPoint)));
+    ///
+    /// // A single separator is the decimal point, unless it is between groups.
+    /// assert_eq!(parser.parse_ambiguous::<f64>(b"1,5"), Ok((1.5, InferredSeparators::CommaDecimalPoint)));
+    /// assert_eq!(parser.parse_ambiguous::<f64>(b"1,234"), Ok((1234.0, InferredSeparators::PeriodDecimalPoint)));
+    /// # }
+    /// ```
+    ///
+    /// [`InferredSeparators`]: enum.InferredSeparators.html
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn parse_ambiguous<F: FromFloatParser>(&self, bytes: &[u8])
+        -> Result<(F, InferredSeparators)>
+    {
+        let separators = InferredSeparators::infer(bytes);
+        let value = self.format(separators.format()).parse::<F>(bytes)?;
+        Ok((value, separators))
+    }
+
+    /// Parse float from string, and validate it is within bounds.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing. If the parsed
+    /// value is outside the bounds, this returns an error with
+    /// `ErrorCode::OutOfRange`, with an index of 0.
+    ///
+    /// * `bytes`   - Byte slice containing a numeric string.
+    /// * `bounds`  - Range of valid values.
+    #[inline]
+    pub fn parse_bounded<F>(&self, bytes: &[u8], bounds: &FloatBounds<F>)
+        -> Result<F>
+        where F: FromFloatParser + Float
+    {
+        let value = self.parse::<F>(bytes)?;
+        match bounds.contains(value) {
+            true  => Ok(value),
+            false => Err((ErrorCode::OutOfRange, 0).into()),
+        }
+    }
+
+    /// Parse float from string, and the number of fraction digits.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing. The number of
+    /// fraction digits includes trailing zeros, so `"1.50"` has 2
+    /// fraction digits while `"1.5"` has 1, allowing decimal types
+    /// to preserve the scale of the parsed value. Digit separators
+    /// are not counted, and the exponent is not applied to the number
+    /// of fraction digits. The parsed float is identical to the
+    /// value returned by [`parse`](#method.parse).
+    ///
+    /// * `bytes`   - Byte slice containing a numeric string.
+    #[inline]
+    pub fn parse_with_scale<F: FromFloatParser>(&self, bytes: &[u8])
+        -> Result<(F, usize)>
+    {
+        let value = self.parse::<F>(bytes)?;
+        Ok((value, self.fraction_digits(bytes)))
+    }
+
+    /// Determine if the float will be parsed exactly, on the fast path.
+    ///
+    /// This method returns true if the entire string is a valid float
+    /// whose value is calculated from exactly-representable operands
+    /// with native float arithmetic, which is then correctly rounded.
+    /// The string is validated with every option of the parser, and
+    /// the digits are inspected after removing the whitespace, quotes
+    /// and signs the parser consumes. It returns false for invalid
+    /// strings and special values. Strings that return false may still
+    /// be parsed correctly, but require extended-precision or
+    /// arbitrary-precision arithmetic.
+    ///
+    /// For base 10, this returns true if the float is zero, or if the
+    /// digits, without the decimal point, leading zeros and trailing
+    /// fraction zeros, form an integer that fits in 64 bits, and:
+    ///
+    /// * the integer is exactly representable by the float: at most
+    ///   `2^24` for `f32` or `2^53` for `f64`, or a larger integer
+    ///   whose trailing binary zeros bring it within that range; and
+    /// * the scaled exponent (the exponent, minus the number of fraction
+    ///   digits) is in the range `[-10, 10]` for `f32`,
+    ///   or `[-22, 22]` for `f64`; or, for larger positive scaled
+    ///   exponents, the integer multiplied by the excess power of 10
+    ///   is still less than `2^24` for `f32` or `2^53` for `f64`.
+    ///
+    /// For example, `"1.5"`, `"0.1"`, `"1e22"` and `"9007199254740992"`
+    /// are parsed exactly as `f64`, while `"1e-23"` and
+    /// `"9007199254740993"` are not.
+    ///
+    /// For powers of two, this returns true if the float is zero, or if
+    /// no digits are truncated and the significant digits form an
+    /// integer less than `2^24` for `f32` or `2^53` for `f64`. The
+    /// exponent is not checked, so the integer is scaled exactly only
+    /// if the result is a normal float: denormal results may still be
+    /// rounded, and large exponents overflow to infinity.
+    ///
+    /// * `bytes`   - Byte slice containing a numeric string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::FloatParser;
+    /// # pub fn main() {
+    /// let parser = FloatParser::new();
+    /// assert!(parser.will_be_exact::<f64>(b"1.5"));
+    /// assert!(parser.will_be_exact::<f64>(b"-1e22"));
+    /// assert!(!parser.will_be_exact::<f64>(b"1e-23"));
+    /// assert!(!parser.will_be_exact::<f64>(b"9007199254740993"));
+    /// assert!(!parser.will_be_exact::<f64>(b"1.5x"));
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "correct")]
+    pub fn will_be_exact<F: FromFloatParser>(&self, bytes: &[u8])
+        -> bool
+    {
+        self.parse::<F>(bytes).is_ok() && F::is_fast_path_float_parser(bytes, self)
+    }
+
+    /// Parse float from string, with its neighbors and rounding error.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing. It returns the
+    /// parsed value, the previous and next representable floats
+    /// (like `nextafter` towards negative and positive infinity),
+    /// and the rounding error of the value.
+    ///
+    /// The rounding error is the difference between the exact value of
+    /// the string and the parsed value, as a fraction of the gap between
+    /// the parsed value and the neighbor in the direction of the exact
+    /// value. It is positive if the exact value is closer to `next`,
+    /// negative if it is closer to `prev`, and 0 if the value is exact.
+    /// Unless the parser is lossy, it is in the range `[-0.5, 0.5]`,
+    /// with `±0.5` for values exactly halfway between two floats. For
+    /// example, `"0.1"` is parsed to `0.1000000000000000055511151231257827`,
+    /// with a rounding error of `-0.4` as an `f64`.
+    ///
+    /// The neighbors of zero are the smallest negative and positive
+    /// denormal floats, the neighbors of NaN are NaN, and infinity
+    /// has itself as the neighbor away from zero. Values that overflow
+    /// to infinity have an infinite rounding error, and special values
+    /// are exact. The rounding error is calculated with
+    /// arbitrary-precision arithmetic, and is rounded to the nearest
+    /// `f64`, however, only the significant digits used to parse the
+    /// float are considered, which only affects strings with hundreds
+    /// of digits.
+    ///
+    /// The rounding error is calculated from the digits and sign the
+    /// parser consumes, after removing the whitespace, quotes and sign
+    /// spellings. When flushing denormals to zero, the neighbors skip
+    /// the denormal floats, so the neighbors of zero are the smallest
+    /// normal floats, and the rounding error of a value flushed to zero
+    /// is a fraction of the gap to the smallest normal float.
+    ///
+    /// * `bytes`   - Byte slice containing a numeric string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::FloatParser;
+    /// # pub fn main() {
+    /// let parser = FloatParser::new();
+    /// let (value, prev, next, error) = parser.parse_with_neighbors::<f64>(b"1.5").unwrap();
+    /// assert_eq!((value, prev, next, error), (1.5, 1.4999999999999998, 1.5000000000000002, 0.0));
+    ///
+    /// // 2^53 + 1 is exactly halfway between 2^53 and 2^53 + 2.
+    /// let (value, _, next, error) = parser.parse_with_neighbors::<f64>(b"9007199254740993").unwrap();
+    /// assert_eq!((value, next, error), (9007199254740992.0, 9007199254740994.0, 0.5));
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "correct")]
+    pub fn parse_with_neighbors<F: FromFloatParser>(&self, bytes: &[u8])
+        -> Result<(F, F, F, f64)>
+    {
+        let value = self.parse::<F>(bytes)?;
+        Ok(F::neighbors_float_parser(bytes, value, self))
+    }
+
+    /// Parse the sign and exact magnitude of a float from string.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing. It returns if
+    /// the value is negative, separately from the exact, unrounded
+    /// magnitude, for decimal and fixed-point types. Since the sign is
+    /// separate, `"-0.00"` is negative with a zero magnitude.
+    ///
+    /// If the significand does not fit in a `u64`, this returns an
+    /// error with `ErrorCode::Overflow`, with an index of 0. Special
+    /// values do not have a magnitude, and are invalid. The parser
+    /// never rounds, so the lossy option has no effect.
+    ///
+    /// * `bytes`   - Byte slice containing a numeric string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{DecimalComponents, FloatParser};
+    /// # pub fn main() {
+    /// let parser = FloatParser::new();
+    /// let (is_negative, magnitude) = parser.parse_sign_magnitude(b"-1.50e3").unwrap();
+    /// assert!(is_negative);
+    /// assert_eq!(magnitude, DecimalComponents { significand: 150, exponent: 3, scale: 2 });
+    ///
+    /// let (is_negative, magnitude) = parser.parse_sign_magnitude(b"-0.00").unwrap();
+    /// assert!(is_negative);
+    /// assert_eq!(magnitude.significand, 0);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn parse_sign_magnitude(&self, bytes: &[u8])
+        -> Result<(bool, DecimalComponents)>
+    {
+        let cb = | bytes | self.parse_trimmed(bytes, |bytes| parse_sign_magnitude(bytes, self.radix, self.format));
+        to_complete!(cb, bytes)
+    }
+
+    /// Parse floats from fixed-length fields at the given offsets in a buffer.
+    ///
+    /// For columnar or record-oriented buffers, each field of
+    /// `field_len` bytes starting at an offset in `field_offsets` is
+    /// parsed like [`parse`], and written to the value with the same
+    /// index in `values`. Fields shorter than `field_len` are padded
+    /// with whitespace, which is only accepted if [`trim_whitespace`]
+    /// is set.
+    ///
+    /// On failure, the error index is the index of the first field that
+    /// failed to parse, not a position within the buffer, and all prior
+    /// fields have been written to `values`. If there are more fields
+    /// than `values` can hold, this returns an error with
+    /// `ErrorCode::TooManyElements`, and no fields are parsed. If a
+    /// field extends past the end of the buffer, this returns an error
+    /// with `ErrorCode::OutOfRange`, with the index of the field.
+    ///
+    /// * `buffer`          - Byte slice containing the fields.
+    /// * `field_offsets`   - Offset of each field in the buffer.
+    /// * `field_len`       - Length of every field.
+    /// * `values`          - Slice to write the parsed fields to.
+    ///
+    /// [`parse`]: #method.parse
+    /// [`trim_whitespace`]: #method.trim_whitespace
+    #[inline]
+    pub fn parse_strided<F: FromFloatParser>(&self, buffer: &[u8], field_offsets: &[usize], field_len: usize, values: &mut [F])
+        -> Result<()>
+    {
+        if field_offsets.len() > values.len() {
+            return Err((ErrorCode::TooManyElements, values.len()).into());
+        }
+        for (index, (&offset, value)) in field_offsets.iter().zip(values.iter_mut()).enumerate() {
+            let field = offset.checked_add(field_len)
+                .and_then(|end| buffer.get(offset..end))
+                .ok_or_else(|| Error::from((ErrorCode::OutOfRange, index)))?;
+            *value = self.parse(field).map_err(|e| Error::from((e.code, index)))?;
+        }
+        Ok(())
+    }
+
+    // Count the fraction digits, including trailing zeros.
+    //
+    //  Preconditions:
+    //      `bytes` is a valid, complete float.
+    perftools_inline!{
+    fn fraction_digits(&self, bytes: &[u8]) -> usize {
+        let radix = self.radix;
+        let digit_separator = self.format.digit_separator();
+        let decimal_point = self.format.decimal_point();
+        let mut iter = bytes.iter().skip_while(|&&c| c != decimal_point);
+        match iter.next() {
+            Some(_) => iter
+                .take_while(|&&c| (c as char).is_digit(radix) || c == digit_separator)
+                .filter(|&&c| c != digit_separator)
+                .count(),
+            None    => 0,
+        }
+    }}
+
+    // Get the number within the whitespace and quotes, if the number is quoted.
+    //
+    // If the quotes are unbalanced and strict, the bytes are returned
+    // unchanged, so they are not a valid number.
+    perftools_inline!{
+    fn unquote<'a>(&self, bytes: &'a [u8]) -> &'a [u8] {
+        let bytes = match self.trim_whitespace {
+            true  => {
+                let start = whitespace_count(bytes.iter());
+                let end = bytes.len() - whitespace_count(bytes[start..].iter().rev());
+                &bytes[start..end]
+            },
+            false => bytes,
+        };
+        match self.quote {
+            Some(quote) if bytes.first() == Some(&quote) => {
+                let inner = &bytes[1..];
+                match inner.last() {
+                    Some(&c) if c == quote  => &inner[..inner.len() - 1],
+                    _ if self.strict_quote  => bytes,
+                    _                       => inner,
+                }
+            },
+            _ => bytes,
+        }
+    }}
+
+    // Parse value from string, consuming the whitespace around the value.
+    perftools_inline!{
+    fn parse_trimmed<T, Cb>(&self, bytes: &[u8], cb: Cb)
+        -> Result<(T, usize)>
+        where Cb: FnOnce(&[u8]) -> Result<(T, usize)>
+    {
+        if !self.trim_whitespace {
+            return self.parse_quoted(bytes, cb);
+        }
+
+        let leading = whitespace_count(bytes.iter());
+        let offset = | e: Error | Error::from((e.code, e.index + leading));
+        let (value, processed) = self.parse_quoted(&bytes[leading..], cb).map_err(offset)?;
+        let processed = leading + processed;
+        let trailing = whitespace_count(bytes[processed..].iter());
+        Ok((value, processed + trailing))
+    }}
+
+    // Parse value from string, consuming the quotes if the value is quoted.
+    perftools_inline!{
+    fn parse_quoted<T, Cb>(&self, bytes: &[u8], cb: Cb)
+        -> Result<(T, usize)>
+        where Cb: FnOnce(&[u8]) -> Result<(T, usize)>
+    {
+        match self.quote {
+            Some(quote) if bytes.first() == Some(&quote) => {
+                let offset = | e: Error | Error::from((e.code, e.index + 1));
+                let (value, processed) = cb(&bytes[1..]).map_err(offset)?;
+                match bytes.get(processed + 1) {
+                    Some(&c) if c == quote  => Ok((value, processed + 2)),
+                    _ if self.strict_quote  => Err((ErrorCode::InvalidDigit, processed + 1).into()),
+                    _                       => Ok((value, processed + 1)),
+                }
+            },
+            _ => cb(bytes),
+        }
+    }}
+
+    // Parse float from string using the resolved configuration.
+    perftools_inline!{
+    fn atof<F: StringToFloat>(&self, bytes: &[u8])
+        -> Result<(F, usize)>
+    {
+        let (value, processed) = self.parse_trimmed(bytes, |bytes| self.atof_unicode_spaces::<F>(bytes))?;
+        self.validate_result(value, processed)
+    }}
+
+    // Parse float from a magnitude with a known sign using the resolved configuration.
+    perftools_inline!{
+    fn atof_signed<F: StringToFloat>(&self, bytes: &[u8], negative: bool)
+        -> Result<(F, usize)>
+    {
+        let sign = if negative { Sign::Negative } else { Sign::Positive };
+        let special = self.special.as_ref();
+        let (value, processed) = match atof_signed::<F>(bytes, self.radix, self.lossy, self.algorithm_format(), special, sign) {
+            Ok((value, ptr)) => (value, distance(bytes.as_ptr(), ptr)),
+            Err((code, ptr)) => return Err((code, distance(bytes.as_ptr(), ptr)).into()),
+        };
+        self.validate_result(value, processed)
+    }}
+
+    // Parse float with an implied radix point using the resolved configuration.
+    perftools_inline!{
+    fn atof_implied<F: StringToFloat>(&self, bytes: &[u8], implied_digits: usize)
+        -> Result<(F, usize)>
+    {
+        let radix = self.radix;
+        let lossy = self.lossy;
+        let (value, processed) = self.parse_trimmed(bytes, |bytes| parse_implied::<F>(bytes, radix, lossy, implied_digits))?;
+        self.validate_result(value, processed)
+    }}
+
+    // Flush denormal results to zero, and reject non-finite results.
+    perftools_inline!{
+    fn validate_result<F: StringToFloat>(&self, value: F, processed: usize)
+        -> Result<(F, usize)>
+    {
+        if self.reject_non_finite && value.is_special() {
+            return Err((ErrorCode::NonFinite, 0).into());
+        } else if self.flush_to_zero && value.is_denormal() {
+            let zero = if value.is_sign_negative() { -F::ZERO } else { F::ZERO };
+            return Ok((zero, processed));
+        }
+        Ok((value, processed))
+    }}
+
+    // Parse the ASCII sign, if the sign is followed by sign whitespace.
+    //
+    // Returns if the sign is negative, and the length of the sign.
+    perftools_inline!{
+    fn parse_ascii_sign(&self, bytes: &[u8]) -> Result<Option<(bool, usize)>> {
+        let negative = match bytes.first() {
+            Some(&b'+') => false,
+            Some(&b'-') => true,
+            _           => return Ok(None),
+        };
+        if self.sign_whitespace_count(&bytes[1..]) == 0 {
+            return Ok(None);
+        }
+        let sign = if negative { Sign::Negative } else { Sign::Positive };
+        match validate_sign(bytes, &bytes[1..], sign, self.format) {
+            Ok(())           => Ok(Some((negative, 1))),
+            Err((code, ptr)) => Err((code, distance(bytes.as_ptr(), ptr)).into()),
+        }
+    }}
+
+    // Get the number of leading bytes in the sign whitespace.
+    perftools_inline!{
+    fn sign_whitespace_count(&self, bytes: &[u8]) -> usize {
+        match self.sign_whitespace {
+            Some(whitespace) => bytes.iter().take_while(|c| whitespace.contains(c)).count(),
+            None             => 0,
+        }
+    }}
+
+    // Parse float from unquoted string, consuming the Unicode spaces around the number.
+    perftools_inline!{
+    fn atof_unicode_spaces<F: StringToFloat>(&self, bytes: &[u8])
+        -> Result<(F, usize)>
+    {
+        if !self.unicode_spaces {
+            return self.atof_sign_spellings::<F>(bytes);
+        }
+
+        let leading = unicode_space_count(bytes);
+        let offset = | e: Error | Error::from((e.code, e.index + leading));
+        let (value, processed) = self.atof_sign_spellings::<F>(&bytes[leading..]).map_err(offset)?;
+        let processed = leading + processed;
+        let trailing = unicode_space_count(&bytes[processed..]);
+        Ok((value, processed + trailing))
+    }}
+
+    // Parse float from unquoted string, with a sign from the sign spellings.
+    perftools_inline!{
+    fn atof_sign_spellings<F: StringToFloat>(&self, bytes: &[u8])
+        -> Result<(F, usize)>
+    {
+        let sign = match self.signs.and_then(|signs| signs.parse_sign(bytes)) {
+            Some(sign) => Some(sign),
+            None       => self.parse_ascii_sign(bytes)?,
+        };
+        let negative = match sign {
+            Some((negative, _)) => negative,
+            None                => bytes.first() == Some(&b'-'),
+        };
+        if negative && self.require_unsigned && !self.allow_negative_zero {
+            return Err((ErrorCode::NegativeNotAllowed, 0).into());
+        }
+        let (value, processed) = match sign {
+            Some((negative, length)) => {
+                let length = length + self.sign_whitespace_count(&bytes[length..]);
+                let offset = | e: Error | Error::from((e.code, e.index + length));
+                let (value, processed) = self.atof_signed::<F>(&bytes[length..], negative).map_err(offset)?;
+                (value, processed + length)
+            },
+            None => self.atof_unquoted::<F>(bytes)?,
+        };
+        match negative && self.require_unsigned {
+            true if value.is_zero() => Ok((F::ZERO, processed)),
+            true                    => Err((ErrorCode::NegativeNotAllowed, 0).into()),
+            false                   => Ok((value, processed)),
+        }
+    }}
+
+    // Get the number format for the parsing algorithms.
+    //
+    // With Unicode spaces, Unicode thin spaces also separate groups of
+    // integer digits, if the integer digits are grouped.
+    perftools_inline!{
+    #[cfg(feature = "format")]
+    fn algorithm_format(&self) -> NumberFormat {
+        match self.unicode_spaces && self.format.integer_grouped_digit_separator() {
+            true  => self.format | NumberFormat::INTEGER_UNICODE_DIGIT_SEPARATOR,
+            false => self.format,
+        }
+    }}
+
+    // Get the number format for the parsing algorithms.
+    perftools_inline!{
+    #[cfg(not(feature = "format"))]
+    fn algorithm_format(&self) -> NumberFormat {
+        self.format
+    }}
+
+    // Parse float from unquoted string using the resolved configuration.
+    perftools_inline!{
+    fn atof_unquoted<F: StringToFloat>(&self, bytes: &[u8])
+        -> Result<(F, usize)>
+    {
+        let index = | ptr | distance(bytes.as_ptr(), ptr);
+        let result = match self.special {
+            Some(ref special) => atof_special::<F>(bytes, self.radix, self.lossy, self.algorithm_format(), special),
+            None              => atof::<F>(bytes, self.radix, self.lossy, self.algorithm_format()),
+        };
+        match result {
+            Ok((value, ptr)) => Ok((value, index(ptr))),
+            Err((ErrorCode::EmptyExponent, ptr)) if self.dangling_exponent => {
+                // Re-parse the mantissa, before the exponent marker. The
+                // error is after the exponent character, or at the sign if
+                // the sign is the exponent marker, like in `"5+"`.
+                let end = index(ptr);
+                let digit_separator = self.format.digit_separator();
+                let last = match bytes[..end].iter().rposition(|&c| c != digit_separator) {
+                    Some(last) => last,
+                    None       => return Err((ErrorCode::EmptyExponent, end).into()),
+                };
+                let c = bytes[last];
+                let is_mantissa = (c as char).is_digit(self.radix) || c == self.format.decimal_point();
+                let end = if is_mantissa { end } else { last };
+                self.atof_unquoted::<F>(&bytes[..end])
+            },
+            Err((code, ptr)) => Err((code, index(ptr)).into()),
+        }
+    }}
+
+    // Get the number the parser consumes, and if the sign consumed is negative.
+    //
+    // The whitespace, quotes, Unicode spaces, sign spellings, and ASCII
+    // signs followed by sign whitespace are removed. Other ASCII signs
+    // are kept in the number.
+    //
+    //  Preconditions:
+    //      `bytes` is a valid, complete float.
+    perftools_inline!{
+    #[cfg(feature = "correct")]
+    fn strip_number<'a>(&self, bytes: &'a [u8]) -> (&'a [u8], bool) {
+        let bytes = self.unquote(bytes);
+        let bytes = match self.unicode_spaces {
+            true  => {
+                let bytes = &bytes[unicode_space_count(bytes)..];
+                &bytes[..bytes.len() - unicode_space_count_rev(bytes)]
+            },
+            false => bytes,
+        };
+        let sign = match self.signs.and_then(|signs| signs.parse_sign(bytes)) {
+            Some(sign) => Some(sign),
+            None       => self.parse_ascii_sign(bytes).unwrap_or(None),
+        };
+        match sign {
+            Some((negative, length)) => {
+                let length = length + self.sign_whitespace_count(&bytes[length..]);
+                (&bytes[length..], negative)
+            },
+            None => (bytes, false),
+        }
+    }}
+
+    // Inspect the digits of a number, if the callback consumes the entire number.
+    //
+    // Returns None for special values, which have no digits.
+    perftools_inline!{
+    #[cfg(feature = "correct")]
+    fn inspect_number<T, Cb>(&self, bytes: &[u8], cb: Cb)
+        -> Option<T>
+        where Cb: FnOnce(&[u8]) -> ParseResult<(T, *const u8)>
+    {
+        match cb(bytes) {
+            Ok((value, ptr)) if distance(bytes.as_ptr(), ptr) == bytes.len() => Some(value),
+            _ => None,
+        }
+    }}
+
+    // Detect if the float string is parsed on the fast path.
+    perftools_inline!{
+    #[cfg(feature = "correct")]
+    fn is_fast_path<F: StringToFloat>(&self, bytes: &[u8])
+        -> bool
+    {
+        let (number, _) = self.strip_number(bytes);
+        let (radix, format) = (self.radix, self.algorithm_format());
+        self.inspect_number(number, |bytes| is_fast_path::<F>(bytes, radix, format)) == Some(true)
+    }}
+
+    // Get the neighbors and rounding error of a float parsed from the string.
+    perftools_inline!{
+    #[cfg(feature = "correct")]
+    fn neighbors<F: StringToFloat>(&self, bytes: &[u8], value: F)
+        -> (F, F, F, f64)
+    {
+        let (number, negative) = self.strip_number(bytes);
+        let (radix, format) = (self.radix, self.algorithm_format());
+        let error = self.inspect_number(number, |bytes| rounding_error::<F>(bytes, radix, format, value));
+        let error = error.unwrap_or(0.0);
+        let normal = F::from_bits(F::HIDDEN_BIT_MASK);
+        let is_flushed = self.flush_to_zero && (value.is_zero() || (value.abs() == normal && error < 0.0));
+        let error = match is_flushed {
+            true  => error * 2.0f64.powi(-F::MANTISSA_SIZE),
+            false => error,
+        };
+        let error = if negative { -error } else { error };
+        let (prev, next) = if value.is_nan() {
+            (value, value)
+        } else if value.is_zero() && self.flush_to_zero {
+            (-normal, normal)
+        } else if value.is_zero() {
+            let denormal = F::from_bits(F::Unsigned::ONE);
+            (-denormal, denormal)
+        } else if self.flush_to_zero {
+            (flush_denormal(value.prev()), flush_denormal(value.next()))
+        } else {
+            (value.prev(), value.next())
+        };
+        (value, prev, next, error)
+    }}
+}
+
+/// Unicode spaces consumed around the number: thin space and narrow no-break space.
+const UNICODE_SPACES: [&[u8]; 2] = [b"\xE2\x80\x89", b"\xE2\x80\xAF"];
+
+/// Length of each Unicode space.
+const UNICODE_SPACE_SIZE: usize = 3;
+
+// Detect if the bytes start with a Unicode space.
+perftools_inline!{
+fn is_unicode_space(bytes: &[u8]) -> bool {
+    UNICODE_SPACES.iter().any(|s| bytes.starts_with(s))
+}}
+
+// Get the number of leading bytes in Unicode spaces.
+perftools_inline!{
+fn unicode_space_count(bytes: &[u8]) -> usize {
+    let mut count = 0;
+    while is_unicode_space(&bytes[count..]) {
+        count += UNICODE_SPACE_SIZE;
+    }
+    count
+}}
+
+// Get the number of trailing bytes in Unicode spaces.
+perftools_inline!{
+#[cfg(feature = "correct")]
+fn unicode_space_count_rev(bytes: &[u8]) -> usize {
+    let mut count = 0;
+    while UNICODE_SPACES.iter().any(|s| bytes[..bytes.len() - count].ends_with(s)) {
+        count += UNICODE_SPACE_SIZE;
+    }
+    count
+}}
+
+// Flush a denormal float to zero, keeping the sign.
+perftools_inline!{
+#[cfg(feature = "correct")]
+fn flush_denormal<F: Float>(value: F) -> F {
+    match value.is_denormal() {
+        true if value.is_sign_negative()    => -F::ZERO,
+        true                                => F::ZERO,
+        false                               => value,
+    }
+}}
+
+// Get the number of leading ASCII whitespace bytes.
+perftools_inline!{
+fn whitespace_count<'a, Iter>(iter: Iter) -> usize
+    where Iter: Iterator<Item=&'a u8>
+{
+    iter.take_while(|c| c.is_ascii_whitespace()).count()
+}}
+
+impl Default for FloatParser {
+    #[inline]
+    fn default() -> FloatParser {
+        FloatParser::new()
+    }
+}
+
+// FROM FLOAT PARSER
+
+/// Trait for floating-point types that can be parsed by a `FloatParser`.
+pub trait FromFloatParser: Sized {
+    /// Checked parser for a string-to-number conversion.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed value until that point.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `parser`  - Parser holding the resolved configuration.
+    fn from_float_parser(bytes: &[u8], parser: &FloatParser) -> Result<(Self, usize)>;
+
+    /// Checked parser for a field with an implied radix point.
+    ///
+    /// * `bytes`           - Slice containing the field.
+    /// * `implied_digits`  - Number of implied fraction digits.
+    /// * `parser`          - Parser holding the resolved configuration.
+    fn implied_float_parser(bytes: &[u8], implied_digits: usize, parser: &FloatParser) -> Result<(Self, usize)>;
+
+    /// Checked parser for a magnitude with a sign parsed by the caller.
+    ///
+    /// * `bytes`       - Slice containing the magnitude.
+    /// * `negative`    - If the value is negative.
+    /// * `parser`      - Parser holding the resolved configuration.
+    fn signed_float_parser(bytes: &[u8], negative: bool, parser: &FloatParser) -> Result<(Self, usize)>;
+
+    /// Detect if the entire string is parsed on the fast path.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `parser`  - Parser holding the resolved configuration.
+    #[cfg(feature = "correct")]
+    fn is_fast_path_float_parser(bytes: &[u8], parser: &FloatParser) -> bool;
+
+    /// Get the neighbors and rounding error of a value parsed from the string.
+    ///
+    /// * `bytes`   - Slice containing a complete, valid numeric string.
+    /// * `value`   - Value parsed from the string.
+    /// * `parser`  - Parser holding the resolved configuration.
+    #[cfg(feature = "correct")]
+    fn neighbors_float_parser(bytes: &[u8], value: Self, parser: &FloatParser) -> (Self, Self, Self, f64);
+}
+
+impl FromFloatParser for f32 {
+    #[inline]
+    fn from_float_parser(bytes: &[u8], parser: &FloatParser) -> Result<(f32, usize)> {
+        parser.atof(bytes)
+    }
+
+    #[inline]
+    fn implied_float_parser(bytes: &[u8], implied_digits: usize, parser: &FloatParser) -> Result<(f32, usize)> {
+        parser.atof_implied(bytes, implied_digits)
+    }
+
+    #[inline]
+    fn signed_float_parser(bytes: &[u8], negative: bool, parser: &FloatParser) -> Result<(f32, usize)> {
+        parser.atof_signed(bytes, negative)
+    }
+
+    #[inline]
+    #[cfg(feature = "correct")]
+    fn is_fast_path_float_parser(bytes: &[u8], parser: &FloatParser) -> bool {
+        parser.is_fast_path::<f32>(bytes)
+    }
+
+    #[inline]
+    #[cfg(feature = "correct")]
+    fn neighbors_float_parser(bytes: &[u8], value: f32, parser: &FloatParser) -> (f32, f32, f32, f64) {
+        parser.neighbors(bytes, value)
+    }
+}
+
+impl FromFloatParser for f64 {
+    #[inline]
+    fn from_float_parser(bytes: &[u8], parser: &FloatParser) -> Result<(f64, usize)> {
+        parser.atof(bytes)
+    }
+
+    #[inline]
+    fn implied_float_parser(bytes: &[u8], implied_digits: usize, parser: &FloatParser) -> Result<(f64, usize)> {
+        parser.atof_implied(bytes, implied_digits)
+    }
+
+    #[inline]
+    fn signed_float_parser(bytes: &[u8], negative: bool, parser: &FloatParser) -> Result<(f64, usize)> {
+        parser.atof_signed(bytes, negative)
+    }
+
+    #[inline]
+    #[cfg(feature = "correct")]
+    fn is_fast_path_float_parser(bytes: &[u8], parser: &FloatParser) -> bool {
+        parser.is_fast_path::<f64>(bytes)
+    }
+
+    #[inline]
+    #[cfg(feature = "correct")]
+    fn neighbors_float_parser(bytes: &[u8], value: f64, parser: &FloatParser) -> (f64, f64, f64, f64) {
+        parser.neighbors(bytes, value)
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        let parser = FloatParser::new();
+        assert_eq!(Ok(1.2345), parser.parse::<f64>(b"1.2345"));
+        assert_eq!(Ok(1.2345), parser.parse::<f32>(b"1.2345"));
+        assert_eq!(Ok(-1.2345e-8), parser.parse::<f64>(b"-1.2345e-8"));
+        assert!(parser.parse::<f64>(b"NaN").unwrap().is_nan());
+        assert_eq!(Err(ErrorCode::Empty.into()), parser.parse::<f64>(b""));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), parser.parse::<f64>(b"1.2a"));
+    }
+
+    #[test]
+    fn parse_partial_test() {
+        let parser = FloatParser::new();
+        assert_eq!(Ok((1.2345, 6)), parser.parse_partial::<f64>(b"1.2345"));
+        assert_eq!(Ok((1.2, 3)), parser.parse_partial::<f64>(b"1.2a"));
+        assert_eq!(Ok((1.2, 3)), parser.parse_partial::<f32>(b"1.2a"));
+    }
+
+    #[test]
+    fn matches_stateless_test() {
+        let parser = FloatParser::new();
+        let lossy = FloatParser::new().lossy(true);
+        let values: [&[u8]; 5] = [b"0", b"1.5", b"2.2250738585072014e-308", b"1.7976931348623157e308", b"123456789.123456789e-5"];
+        for &value in values.iter() {
+            assert_eq!(f64::from_lexical(value), parser.parse::<f64>(value));
+            assert_eq!(f32::from_lexical(value), parser.parse::<f32>(value));
+            assert_eq!(f64::from_lexical_lossy(value), lossy.parse::<f64>(value));
+            assert_eq!(f32::from_lexical_lossy(value), lossy.parse::<f32>(value));
+        }
+    }
+
+    #[test]
+    fn parse_bounded_test() {
+        let parser = FloatParser::new();
+        let out_of_range: Error = (ErrorCode::OutOfRange, 0).into();
+        let bounds = FloatBounds::new(0.0f64, 1.0);
+        assert_eq!(Ok(0.0), parser.parse_bounded(b"0", &bounds));
+        assert_eq!(Ok(0.5), parser.parse_bounded(b"0.5", &bounds));
+        assert_eq!(Ok(1.0), parser.parse_bounded(b"1e0", &bounds));
+        assert_eq!(Some(out_of_range), parser.parse_bounded(b"1.5", &bounds).err());
+        assert_eq!(Some(out_of_range), parser.parse_bounded(b"-0.5", &bounds).err());
+        assert_eq!(Some(out_of_range), parser.parse_bounded(b"1e400", &bounds).err());
+        assert_eq!(Some(out_of_range), parser.parse_bounded(b"NaN", &bounds).err());
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parser.parse_bounded(b"0a", &bounds));
+
+        let bounds = bounds.exclusive_min(true).exclusive_max(true);
+        assert_eq!(Some(out_of_range), parser.parse_bounded(b"0", &bounds).err());
+        assert_eq!(Some(out_of_range), parser.parse_bounded(b"1", &bounds).err());
+        assert_eq!(Ok(1e-300), parser.parse_bounded(b"1e-300", &bounds));
+
+        let bounds = FloatBounds::new(0.0f32, f32::INFINITY).exclusive_max(true).allow_nan(true);
+        assert_eq!(Ok(1e30), parser.parse_bounded(b"1e30", &bounds));
+        assert_eq!(Some(out_of_range), parser.parse_bounded(b"inf", &bounds).err());
+        assert_eq!(Some(out_of_range), parser.parse_bounded(b"1e40", &bounds).err());
+        assert!(parser.parse_bounded(b"NaN", &bounds).unwrap().is_nan());
+    }
+
+    #[test]
+    fn reject_non_finite_test() {
+        let parser = FloatParser::new().reject_non_finite(true);
+        let non_finite: Error = (ErrorCode::NonFinite, 0).into();
+        assert_eq!(Err(non_finite), parser.parse::<f64>(b"1e400"));
+        assert_eq!(Err(non_finite), parser.parse::<f64>(b"-1e400"));
+        assert_eq!(Err(non_finite), parser.parse::<f32>(b"1e40"));
+        assert_eq!(Err(non_finite), parser.parse::<f64>(b"inf"));
+        assert_eq!(Err(non_finite), parser.parse::<f64>(b"-inf"));
+        assert_eq!(Err(non_finite), parser.parse::<f64>(b"nan"));
+        assert_eq!(Err(non_finite), parser.parse::<f32>(b"NaN"));
+        assert_eq!(Err(non_finite), parser.parse_partial::<f64>(b"inf,"));
+
+        // Finite values are unchanged.
+        assert_eq!(Ok(1.5), parser.parse::<f64>(b"1.5"));
+        assert_eq!(Ok(1.7976931348623157e308), parser.parse::<f64>(b"1.7976931348623157e308"));
+        assert_eq!(Ok(3.4028235e38), parser.parse::<f32>(b"3.4028235e38"));
+        assert_eq!(Ok(0.0), parser.parse::<f64>(b"1e-400"));
+        assert_eq!(Ok((1.5, 3)), parser.parse_partial::<f64>(b"1.5,"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parser.parse::<f64>(b"0a"));
+
+        // Non-finite values are accepted by default.
+        let parser = FloatParser::new();
+        assert!(parser.parse::<f64>(b"1e400").unwrap().is_infinite());
+        assert!(parser.parse::<f64>(b"nan").unwrap().is_nan());
+    }
+
+    #[test]
+    fn require_unsigned_test() {
+        let parser = FloatParser::new().require_unsigned(true);
+        let negative: Error = (ErrorCode::NegativeNotAllowed, 0).into();
+        assert!(parser.get_require_unsigned());
+        assert_eq!(Ok(5.0), parser.parse::<f64>(b"5"));
+        assert_eq!(Ok(5.0), parser.parse::<f64>(b"+5"));
+        assert_eq!(Ok(0.0), parser.parse::<f64>(b"0"));
+        assert_eq!(Err(negative), parser.parse::<f64>(b"-5"));
+        assert_eq!(Err(negative), parser.parse::<f32>(b"-5"));
+        assert_eq!(Err(negative), parser.parse::<f64>(b"-0"));
+        assert_eq!(Err(negative), parser.parse::<f64>(b"-inf"));
+        assert_eq!(Err(negative), parser.parse::<f64>(b"-5x"));
+        assert_eq!(Err(negative), parser.parse_partial::<f64>(b"-5,"));
+
+        // The error is at the sign, after whitespace and quotes.
+        let quoted = parser.trim_whitespace(true).quote(Some(b'"'));
+        assert_eq!(Err((ErrorCode::NegativeNotAllowed, 2).into()), quoted.parse::<f64>(b" \"-5\""));
+
+        // Negative zero is parsed as zero.
+        let parser = parser.allow_negative_zero(true);
+        assert!(parser.get_allow_negative_zero());
+        assert_eq!(Ok(false), parser.parse::<f64>(b"-0.0").map(f64::is_sign_negative));
+        assert_eq!(Ok(false), parser.parse::<f64>(b"-0e5").map(f64::is_sign_negative));
+        assert_eq!(Err(negative), parser.parse::<f64>(b"-5"));
+        assert_eq!(Ok(false), parser.parse::<f64>(b"-1e-400").map(f64::is_sign_negative));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), parser.parse::<f64>(b"-0x"));
+
+        // Negative values are accepted by default.
+        let parser = FloatParser::new().allow_negative_zero(true);
+        assert_eq!(Ok(-5.0), parser.parse::<f64>(b"-5"));
+        assert_eq!(Ok(true), parser.parse::<f64>(b"-0.0").map(f64::is_sign_negative));
+    }
+
+    #[test]
+    fn flush_to_zero_test() {
+        let parser = FloatParser::new().flush_to_zero(true);
+        assert!(parser.get_flush_to_zero());
+        assert_eq!(0, parser.parse::<f64>(b"5e-320").unwrap().to_bits());
+        assert_eq!(0x8000000000000000, parser.parse::<f64>(b"-5e-320").unwrap().to_bits());
+        assert_eq!(0, parser.parse::<f64>(b"2.2250738585072009e-308").unwrap().to_bits());
+        assert_eq!(0x80000000, parser.parse::<f32>(b"-1e-40").unwrap().to_bits());
+        assert_eq!(Ok((0.0, 6)), parser.parse_partial::<f64>(b"5e-320,"));
+
+        // Values are flushed after rounding.
+        assert_eq!(Ok(2.2250738585072014e-308), parser.parse::<f64>(b"2.2250738585072013e-308"));
+        assert_eq!(Ok(2.2250738585072014e-308), parser.parse::<f64>(b"2.2250738585072014e-308"));
+        assert_eq!(Ok(1.5), parser.parse::<f64>(b"1.5"));
+        assert_eq!(Ok(-0.0), parser.parse::<f64>(b"-0"));
+
+        // Denormal values are parsed by default.
+        let parser = FloatParser::new();
+        assert!(!parser.get_flush_to_zero());
+        assert_eq!(Ok(5e-320), parser.parse::<f64>(b"5e-320"));
+        assert_eq!(0x2788, parser.parse::<f64>(b"5e-320").unwrap().to_bits());
+    }
+
+    #[test]
+    fn dangling_exponent_test() {
+        // Strict, the default.
+        let parser = FloatParser::new();
+        assert!(!parser.get_dangling_exponent());
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), parser.parse_partial::<f64>(b"5e"));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), parser.parse::<f64>(b"5e"));
+
+        // Lenient.
+        let parser = FloatParser::new().dangling_exponent(true);
+        assert!(parser.get_dangling_exponent());
+        assert_eq!(Ok((5.0, 1)), parser.parse_partial::<f64>(b"5e"));
+        assert_eq!(Ok((5.0, 1)), parser.parse_partial::<f32>(b"5E"));
+        assert_eq!(Ok((-1.5, 4)), parser.parse_partial::<f64>(b"-1.5e+"));
+        assert_eq!(Ok((1.5, 3)), parser.parse_partial::<f64>(b"1.5e-x"));
+        assert_eq!(Ok((1.0, 2)), parser.parse_partial::<f64>(b"1.e,"));
+        assert_eq!(Ok((500.0, 3)), parser.parse_partial::<f64>(b"5e2"));
+        assert_eq!(Ok(500.0), parser.parse::<f64>(b"5e2"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parser.parse::<f64>(b"5e"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parser.parse_partial::<f64>(b"e"));
+
+        let parser = parser.quote(Some(b'"'));
+        assert_eq!(Ok((5.0, 2)), parser.parse_partial::<f64>(b"\"5e\""));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn dangling_exponent_format_test() {
+        let parser = FloatParser::new()
+            .format(NumberFormat::FORTRAN_LENIENT_STRING)
+            .dangling_exponent(true);
+        assert_eq!(Ok((5.0, 1)), parser.parse_partial::<f64>(b"5+"));
+        assert_eq!(Ok((5.5, 3)), parser.parse_partial::<f64>(b"5.5-"));
+        assert_eq!(Ok((5.0, 1)), parser.parse_partial::<f64>(b"5d+"));
+        assert_eq!(Ok((50.0, 3)), parser.parse_partial::<f64>(b"5+1"));
+    }
+
+    #[test]
+    fn parse_hemisphere_test() {
+        let parser = FloatParser::new();
+        let hemispheres = Hemispheres::new();
+        assert_eq!(Ok((12.5, Some(Hemisphere::North))), parser.parse_hemisphere::<f64>(b"12.5N", &hemispheres));
+        assert_eq!(Ok((12.5, Some(Hemisphere::South))), parser.parse_hemisphere::<f64>(b"12.5s", &hemispheres));
+        assert_eq!(Ok((12.5, Some(Hemisphere::East))), parser.parse_hemisphere::<f64>(b"12.5E", &hemispheres));
+        assert_eq!(Ok((12.5, Some(Hemisphere::West))), parser.parse_hemisphere::<f32>(b"12.5W", &hemispheres));
+        assert_eq!(Ok((12.5, Some(Hemisphere::North))), parser.parse_hemisphere::<f64>(b"12.5e0N", &hemispheres));
+        assert_eq!(Ok((125.0, None)), parser.parse_hemisphere::<f64>(b"12.5E1", &hemispheres));
+        assert_eq!(Ok((-12.5, None)), parser.parse_hemisphere::<f64>(b"-12.5", &hemispheres));
+        assert_eq!(Ok((12.5, Some(Hemisphere::North), 5)), parser.parse_partial_hemisphere::<f64>(b"12.5N, 3", &hemispheres));
+        assert_eq!(Ok((12.5, None, 4)), parser.parse_partial_hemisphere::<f64>(b"12.5X", &hemispheres));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parser.parse_hemisphere::<f64>(b"12.5X", &hemispheres));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), parser.parse_hemisphere::<f64>(b"12.5NS", &hemispheres));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parser.parse_hemisphere::<f64>(b"W", &hemispheres));
+
+        // Derive the sign from the hemisphere.
+        let hemispheres = Hemispheres::new().signed(true);
+        assert_eq!(Ok((12.5, Some(Hemisphere::North))), parser.parse_hemisphere::<f64>(b"12.5N", &hemispheres));
+        assert_eq!(Ok((-12.5, Some(Hemisphere::South))), parser.parse_hemisphere::<f64>(b"12.5S", &hemispheres));
+        assert_eq!(Ok((12.5, Some(Hemisphere::East))), parser.parse_hemisphere::<f64>(b"12.5E", &hemispheres));
+        assert_eq!(Ok((-12.5, Some(Hemisphere::West))), parser.parse_hemisphere::<f64>(b"12.5W", &hemispheres));
+        assert_eq!(Ok((12.5, None)), parser.parse_hemisphere::<f64>(b"12.5", &hemispheres));
+        assert_eq!(Ok((-12.5, None)), parser.parse_hemisphere::<f64>(b"-12.5", &hemispheres));
+        assert_eq!(Ok((-12.5, Some(Hemisphere::North))), parser.parse_hemisphere::<f64>(b"-12.5N", &hemispheres));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), parser.parse_hemisphere::<f64>(b"-12.5S", &hemispheres));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), parser.parse_hemisphere::<f64>(b"+12.5S", &hemispheres));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), parser.parse_partial_hemisphere::<f64>(b"-3W", &hemispheres));
+
+        // Custom, case-sensitive letters.
+        let hemispheres = Hemispheres::new().letters(b'N', b'S', b'O', b'W').case_sensitive(true).signed(true);
+        assert_eq!(Ok((-3.0, Some(Hemisphere::West))), parser.parse_hemisphere::<f64>(b"3W", &hemispheres));
+        assert_eq!(Ok((3.0, Some(Hemisphere::East))), parser.parse_hemisphere::<f64>(b"3O", &hemispheres));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parser.parse_hemisphere::<f64>(b"3w", &hemispheres));
+    }
+
+    #[test]
+    fn parse_currency_test() {
+        let parser = FloatParser::new();
+        let currency = CurrencySymbols::new(&[b"$", b"\xE2\x82\xAC", b"US$"]);
+        assert_eq!(Ok((5.0, Some(0))), parser.parse_currency::<f64>(b"$5", &currency));
+        assert_eq!(Ok((-5.0, Some(0))), parser.parse_currency::<f64>(b"$-5", &currency));
+        assert_eq!(Ok((1.5, Some(1))), parser.parse_currency::<f32>(b"\xE2\x82\xAC1.5", &currency));
+        assert_eq!(Ok((2.5, Some(2))), parser.parse_currency::<f64>(b"US$2.5", &currency));
+        assert_eq!(Ok((-5.0, None)), parser.parse_currency::<f64>(b"-5", &currency));
+        assert_eq!(Ok((5.0, Some(0), 2)), parser.parse_partial_currency::<f64>(b"$5, 3", &currency));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), parser.parse_currency::<f64>(b"-$5", &currency));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), parser.parse_currency::<f64>(b"$$5", &currency));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parser.parse_currency::<f64>(b"$1.5x", &currency));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), parser.parse_currency::<f64>(b"$", &currency));
+
+        // Parse the symbol after the sign.
+        let currency = currency.after_sign(true);
+        assert_eq!(Ok((-5.0, Some(0))), parser.parse_currency::<f64>(b"-$5", &currency));
+        assert_eq!(Ok((5.0, Some(0))), parser.parse_currency::<f64>(b"+$5", &currency));
+        assert_eq!(Ok((5.0, Some(0))), parser.parse_currency::<f64>(b"$5", &currency));
+        assert_eq!(Ok((-5.0, None)), parser.parse_currency::<f64>(b"-5", &currency));
+        assert_eq!(Ok((-1.5, Some(1), 7)), parser.parse_partial_currency::<f64>(b"-\xE2\x82\xAC1.5;", &currency));
+        assert!(parser.parse_currency::<f64>(b"$-5", &currency).is_err());
+        assert!(parser.parse_currency::<f64>(b"-$-5", &currency).is_err());
+
+        // Unicode signs before the symbol.
+        let parser = FloatParser::new().signs(SignSpellings::unicode());
+        assert_eq!(Ok((-5.0, Some(0))), parser.parse_currency::<f64>(b"\xE2\x88\x92$5", &currency));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn parse_currency_format_test() {
+        let currency = CurrencySymbols::new(&[b"$", b"\xE2\x82\xAC"]);
+        let parser = FloatParser::new().format(NumberFormat::grouped(b',', 3).unwrap());
+        assert_eq!(Ok((1234.56, Some(0))), parser.parse_currency::<f64>(b"$1,234.56", &currency));
+        let parser = FloatParser::new().format(NumberFormat::european(b'.').unwrap());
+        assert_eq!(Ok((1234.56, Some(1))), parser.parse_currency::<f64>(b"\xE2\x82\xAC1.234,56", &currency));
+        assert_eq!(Ok((-1234.56, Some(1))), parser.parse_currency::<f64>(b"\xE2\x82\xAC-1.234,56", &currency));
+    }
+
+    #[test]
+    fn parse_signed_test() {
+        let parsers = [FloatParser::new(), FloatParser::new().lossy(true)];
+        for parser in parsers.iter() {
+            assert_eq!(Ok(-1.5), parser.parse_signed::<f64>(b"1.5", true));
+            assert_eq!(Ok(1.5), parser.parse_signed::<f64>(b"1.5", false));
+            assert_eq!(Ok(-1.5), parser.parse_signed::<f32>(b"1.5", true));
+            assert_eq!(Ok(-1.7976931348623157e308), parser.parse_signed::<f64>(b"1.7976931348623157e308", true));
+            assert_eq!(Ok((-1.5, 3)), parser.parse_partial_signed::<f64>(b"1.5,", true));
+
+            // Negative zero, from the zero short-circuit and after underflow.
+            for &bytes in [&b"0"[..], b"0.000", b"0e400", b"1e-400"].iter() {
+                let value = parser.parse_signed::<f64>(bytes, true).unwrap();
+                assert!(value == 0.0 && value.is_sign_negative());
+                let value = parser.parse_signed::<f64>(bytes, false).unwrap();
+                assert!(value == 0.0 && value.is_sign_positive());
+            }
+            let value = parser.parse_signed::<f32>(b"0", true).unwrap();
+            assert!(value == 0.0 && value.is_sign_negative());
+
+            // Special values.
+            assert_eq!(Ok(f64::NEG_INFINITY), parser.parse_signed::<f64>(b"inf", true));
+            assert_eq!(Ok(f64::NEG_INFINITY), parser.parse_signed::<f64>(b"1e400", true));
+            let value = parser.parse_signed::<f64>(b"NaN", true).unwrap();
+            assert!(value.is_nan() && value.is_sign_negative());
+
+            // The sign was already parsed.
+            assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parser.parse_signed::<f64>(b"-1.5", false));
+            assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parser.parse_signed::<f64>(b"+1.5", true));
+            assert_eq!(Err((ErrorCode::Empty, 0).into()), parser.parse_signed::<f64>(b"", true));
+            assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), parser.parse_signed::<f64>(b"1.5a", true));
+        }
+
+        let parser = FloatParser::new().special(SpecialValues::strict());
+        assert_eq!(Ok(f64::NEG_INFINITY), parser.parse_signed::<f64>(b"inf", true));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parser.parse_signed::<f64>(b"INF", true));
+
+        let parser = FloatParser::new().reject_non_finite(true);
+        assert_eq!(Err((ErrorCode::NonFinite, 0).into()), parser.parse_signed::<f64>(b"inf", true));
+    }
+
+    #[test]
+    fn parse_implied_decimal_test() {
+        let parser = FloatParser::new();
+        assert_eq!(Ok(123.45), parser.parse_implied_decimal::<f64>(b"12345", 2));
+        assert_eq!(Ok(123.45), parser.parse_implied_decimal::<f32>(b"12345", 2));
+        assert_eq!(Ok(0.05), parser.parse_implied_decimal::<f64>(b"5", 2));
+        assert_eq!(Ok(-0.05), parser.parse_implied_decimal::<f64>(b"-5", 2));
+        assert_eq!(Ok(-123.45), parser.parse_implied_decimal::<f64>(b"-12345", 2));
+        assert_eq!(Ok(12345.0), parser.parse_implied_decimal::<f64>(b"12345", 0));
+        assert_eq!(Ok(0.0), parser.parse_implied_decimal::<f64>(b"0", 2));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parser.parse_implied_decimal::<f64>(b"1234.5", 2));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parser.parse_implied_decimal::<f64>(b"1e5", 2));
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), parser.parse_implied_decimal::<f64>(b"", 2));
+
+        let parser = FloatParser::new().quote(Some(b'"'));
+        assert_eq!(Ok(1.5), parser.parse_implied_decimal::<f64>(b"\"150\"", 2));
+
+        let digits = [b'9'; 400];
+        let parser = FloatParser::new().reject_non_finite(true);
+        assert_eq!(Err((ErrorCode::NonFinite, 0).into()), parser.parse_implied_decimal::<f64>(&digits, 0));
+        assert_eq!(Ok(1e300), parser.parse_implied_decimal::<f64>(&digits, 100));
+    }
+
+    #[test]
+    fn parse_with_scale_test() {
+        let parser = FloatParser::new();
+        assert_eq!(Ok((1.5, 2)), parser.parse_with_scale::<f64>(b"1.50"));
+        assert_eq!(Ok((1.5, 1)), parser.parse_with_scale::<f64>(b"1.5"));
+        assert_eq!(Ok((1.5, 1)), parser.parse_with_scale::<f32>(b"1.5e0"));
+        assert_eq!(Ok((15.0, 3)), parser.parse_with_scale::<f64>(b"1.500e1"));
+        assert_eq!(Ok((1.0, 0)), parser.parse_with_scale::<f64>(b"1"));
+        assert_eq!(Ok((1.0, 0)), parser.parse_with_scale::<f64>(b"1."));
+        assert_eq!(Ok((0.0, 3)), parser.parse_with_scale::<f64>(b"-.000"));
+        assert_eq!(Ok((0.0, 3)), parser.parse_with_scale::<f64>(b"0.000"));
+        assert!(parser.parse_with_scale::<f64>(b"inf").unwrap().0.is_infinite());
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parser.parse_with_scale::<f64>(b"1.50a"));
+    }
+
+    #[test]
+    fn special_test() {
+        // Spellings, and if they are accepted by the lenient, strict,
+        // json and json5 special values, respectively.
+        let cases: [(&[u8], bool, bool, bool, bool); 14] = [
+            (b"inf", true, true, false, false),
+            (b"INF", true, false, false, false),
+            (b"Inf", true, false, false, false),
+            (b"infinity", true, false, false, false),
+            (b"Infinity", true, false, false, true),
+            (b"INFINITY", true, false, false, false),
+            (b"-Infinity", true, false, false, true),
+            (b"+inf", true, true, false, false),
+            (b"nan", true, false, false, false),
+            (b"NaN", true, true, false, true),
+            (b"NAN", true, false, false, false),
+            (b"-NaN", true, true, false, true),
+            (b"infin", false, false, false, false),
+            (b"na", false, false, false, false),
+        ];
+        let lenient = FloatParser::new().special(SpecialValues::lenient());
+        let strict = FloatParser::new().special(SpecialValues::strict());
+        let json = FloatParser::new().special(SpecialValues::json());
+        let json5 = FloatParser::new().special(SpecialValues::json5());
+        for &(string, is_lenient, is_strict, is_json, is_json5) in cases.iter() {
+            assert_eq!(is_lenient, lenient.parse::<f64>(string).is_ok());
+            assert_eq!(is_strict, strict.parse::<f64>(string).is_ok());
+            assert_eq!(is_json, json.parse::<f64>(string).is_ok());
+            assert_eq!(is_json5, json5.parse::<f32>(string).is_ok());
+        }
+
+        // Check the parsed values.
+        assert_eq!(Ok(f64::NEG_INFINITY), json5.parse::<f64>(b"-Infinity"));
+        assert!(json5.parse::<f64>(b"NaN").unwrap().is_nan());
+        assert_eq!(Ok(1.5), json.parse::<f64>(b"1.5"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), strict.parse::<f64>(b"infinity"));
+
+        // Use the longest matching spelling.
+        assert_eq!(Ok((f64::INFINITY, 8)), lenient.parse_partial::<f64>(b"Infinity1"));
+        assert_eq!(Ok((f64::INFINITY, 3)), lenient.parse_partial::<f64>(b"Infinite"));
+
+        // Custom spellings.
+        const INFINITY: &[&[u8]] = &[b"oo"];
+        const NAN: &[&[u8]] = &[b"?"];
+        let parser = FloatParser::new().special(SpecialValues::new(INFINITY, NAN));
+        assert_eq!(Ok(f64::INFINITY), parser.parse::<f64>(b"OO"));
+        assert!(parser.parse::<f64>(b"-?").unwrap().is_nan());
+        assert!(parser.parse::<f64>(b"inf").is_err());
+    }
+
+    #[test]
+    fn complete_token_test() {
+        // Trailing bytes are left unparsed by default.
+        let lenient = FloatParser::new().special(SpecialValues::lenient());
+        assert_eq!(Ok((f64::INFINITY, 8)), lenient.parse_partial::<f64>(b"infinity"));
+        assert_eq!(Ok((f64::INFINITY, 8)), lenient.parse_partial::<f64>(b"infinityx"));
+        assert!(lenient.parse_partial::<f64>(b"nan123").map(|(x, n)| x.is_nan() && n == 3).unwrap());
+        assert_eq!(Err((ErrorCode::InvalidDigit, 8).into()), lenient.parse::<f64>(b"infinityx"));
+
+        // Complete tokens reject trailing token characters.
+        let strict = FloatParser::new().special(SpecialValues::lenient().complete_token(true));
+        assert_eq!(Ok(f64::INFINITY), strict.parse::<f64>(b"infinity"));
+        assert_eq!(Ok((f64::INFINITY, 8)), strict.parse_partial::<f64>(b"infinity"));
+        assert_eq!(Ok((f64::NEG_INFINITY, 4)), strict.parse_partial::<f64>(b"-inf, 1"));
+        assert!(strict.parse_partial::<f64>(b"NaN]").map(|(x, n)| x.is_nan() && n == 3).unwrap());
+        assert_eq!(Err((ErrorCode::InvalidDigit, 8).into()), strict.parse_partial::<f64>(b"infinityx"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 8).into()), strict.parse_partial::<f64>(b"infinity2"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), strict.parse_partial::<f64>(b"nan123"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), strict.parse_partial::<f64>(b"-nan_"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), strict.parse_partial::<f64>(b"infinit"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 8).into()), strict.parse::<f64>(b"infinityx"));
+
+        // Numbers are unaffected.
+        assert_eq!(Ok((1.5, 3)), strict.parse_partial::<f64>(b"1.5x"));
+    }
+
+    #[test]
+    fn localized_special_test() {
+        const INFINITY: &[&[u8]] = &[b"inf", b"infinity", b"Unendlich", b"\xE2\x88\x9E"];
+        const NAN: &[&[u8]] = &[b"nan", b"KeineZahl"];
+        let parser = FloatParser::new().special(SpecialValues::new(INFINITY, NAN));
+        assert_eq!(Ok(f64::INFINITY), parser.parse::<f64>(b"Unendlich"));
+        assert_eq!(Ok(f64::INFINITY), parser.parse::<f64>(b"UNENDLICH"));
+        assert_eq!(Ok(f64::NEG_INFINITY), parser.parse::<f64>(b"-unendlich"));
+        assert_eq!(Ok(f32::INFINITY), parser.parse::<f32>(b"Unendlich"));
+        assert_eq!(Ok(f64::NEG_INFINITY), parser.parse::<f64>(b"-\xE2\x88\x9E"));
+        assert!(parser.parse::<f64>(b"keinezahl").unwrap().is_nan());
+        assert_eq!(Err((ErrorCode::InvalidDigit, 9).into()), parser.parse::<f64>(b"Unendlichkeit"));
+
+        // The English spellings in the list, and the default parser, still work.
+        assert_eq!(Ok(f64::INFINITY), parser.parse::<f64>(b"inf"));
+        assert_eq!(Ok(f64::INFINITY), parser.parse::<f64>(b"Infinity"));
+        assert!(parser.parse::<f64>(b"NaN").unwrap().is_nan());
+        assert_eq!(Ok(1.5), parser.parse::<f64>(b"1.5"));
+        assert_eq!(Ok(f64::INFINITY), FloatParser::new().parse::<f64>(b"inf"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), FloatParser::new().parse::<f64>(b"Unendlich"));
+
+        // Case-sensitive matching.
+        let parser = FloatParser::new().special(SpecialValues::new(INFINITY, NAN).case_sensitive(true));
+        assert_eq!(Ok(f64::INFINITY), parser.parse::<f64>(b"Unendlich"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parser.parse::<f64>(b"unendlich"));
+    }
+
+    #[test]
+    fn parse_sign_magnitude_test() {
+        let components = | significand, exponent, scale | DecimalComponents { significand: significand, exponent: exponent, scale: scale };
+        let parser = FloatParser::new();
+        assert_eq!(Ok((true, components(0, 0, 2))), parser.parse_sign_magnitude(b"-0.00"));
+        assert_eq!(Ok((false, components(0, 0, 2))), parser.parse_sign_magnitude(b"0.00"));
+        assert_eq!(Ok((true, components(314, 0, 2))), parser.parse_sign_magnitude(b"-3.14"));
+        assert_eq!(Ok((false, components(314, -5, 2))), parser.parse_sign_magnitude(b"3.14e-5"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parser.parse_sign_magnitude(b"3.14x"));
+        assert_eq!(Err((ErrorCode::Overflow, 0).into()), parser.parse_sign_magnitude(b"18446744073709551616"));
+        assert!(parser.parse_sign_magnitude(b"NaN").is_err());
+
+        let parser = FloatParser::new().quote(Some(b'"'));
+        assert_eq!(Ok((true, components(314, 0, 2))), parser.parse_sign_magnitude(b"\"-3.14\""));
+    }
+
+    #[test]
+    fn quote_test() {
+        // Quotes are rejected by default.
+        let parser = FloatParser::new();
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parser.parse::<f64>(b"\"3.14\""));
+
+        // Balanced quotes.
+        let parser = FloatParser::new().quote(Some(b'"'));
+        assert_eq!(Ok(3.14), parser.parse::<f64>(b"\"3.14\""));
+        assert_eq!(Ok(3.14), parser.parse::<f64>(b"3.14"));
+        assert_eq!(Ok((3.14, 6)), parser.parse_partial::<f64>(b"\"3.14\", 1"));
+        assert_eq!(Ok((-1.5e3, 8)), parser.parse_partial::<f32>(b"\"-1.5e3\""));
+        assert!(parser.parse::<f64>(b"\"NaN\"").unwrap().is_nan());
+        assert_eq!(Ok((1.5, 2)), parser.parse_with_scale::<f64>(b"\"1.50\""));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), parser.parse::<f64>(b"\"\""));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parser.parse::<f64>(b"\"3.1x\""));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parser.parse::<f64>(b"3.14\""));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 6).into()), parser.parse::<f64>(b"\"3.14\"\""));
+
+        // Unbalanced quotes.
+        assert_eq!(Ok((3.14, 5)), parser.parse_partial::<f64>(b"\"3.14"));
+        assert_eq!(Ok((3.14, 5)), parser.parse_partial::<f64>(b"\"3.14,"));
+        let strict = parser.strict_quote(true);
+        assert_eq!(Ok(3.14), strict.parse::<f64>(b"\"3.14\""));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), strict.parse::<f64>(b"\"3.14"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), strict.parse_partial::<f64>(b"\"3.14,"));
+
+        // Custom quote character.
+        let parser = FloatParser::new().quote(Some(b'\''));
+        assert_eq!(Ok(3.14), parser.parse::<f64>(b"'3.14'"));
+        assert!(parser.parse::<f64>(b"\"3.14\"").is_err());
+    }
+
+    #[test]
+    fn trim_whitespace_test() {
+        // Whitespace is rejected by default.
+        let parser = FloatParser::new();
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parser.parse::<f64>(b"3.14 "));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parser.parse::<f64>(b"3.14xyz"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parser.parse::<f64>(b" 3.14"));
+        assert_eq!(Ok((3.14, 4)), parser.parse_partial::<f64>(b"3.14 "));
+
+        // Leading and trailing whitespace.
+        let parser = FloatParser::new().trim_whitespace(true);
+        assert!(parser.get_trim_whitespace());
+        assert_eq!(Ok(3.14), parser.parse::<f64>(b"3.14 "));
+        assert_eq!(Ok(3.14), parser.parse::<f64>(b" \t3.14\r\n"));
+        assert_eq!(Ok(-3.14), parser.parse::<f64>(b" -3.14"));
+        assert_eq!(Ok((3.14, 7)), parser.parse_partial::<f64>(b" 3.14  x"));
+        assert_eq!(Ok((1.5, 2)), parser.parse_with_scale::<f64>(b" 1.50 "));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), parser.parse::<f64>(b" 3.14xyz"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), parser.parse::<f64>(b"3.14 1"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), parser.parse::<f64>(b"- 3.14"));
+        assert_eq!(Err((ErrorCode::Empty, 2).into()), parser.parse::<f64>(b"  "));
+
+        // Whitespace around quotes.
+        let parser = parser.quote(Some(b'"'));
+        assert_eq!(Ok(3.14), parser.parse::<f64>(b" \"3.14\" "));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), parser.parse::<f64>(b"\" 3.14\""));
+    }
+
+    #[test]
+    fn sign_whitespace_test() {
+        // Whitespace after the sign is rejected by default.
+        let parser = FloatParser::new();
+        assert_eq!(None, parser.get_sign_whitespace());
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), parser.parse::<f64>(b"- 5"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), parser.parse::<f64>(b"+ 3.14"));
+
+        // Whitespace after ASCII signs.
+        let parser = FloatParser::new().sign_whitespace(Some(b" \t"));
+        assert_eq!(Some(&b" \t"[..]), parser.get_sign_whitespace());
+        assert_eq!(Ok(-5.0), parser.parse::<f64>(b"- 5"));
+        assert_eq!(Ok(3.14), parser.parse::<f64>(b"+ 3.14"));
+        assert_eq!(Ok(-5.0), parser.parse::<f64>(b"-\t 5"));
+        assert_eq!(Ok(-5.0), parser.parse::<f64>(b"-5"));
+        assert_eq!(Ok(f64::NEG_INFINITY), parser.parse::<f64>(b"- inf"));
+        assert_eq!(Ok((-5.0, 3)), parser.parse_partial::<f32>(b"- 5 x"));
+        assert_eq!(Err((ErrorCode::Empty, 2).into()), parser.parse::<f64>(b"- "));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 2).into()), parser.parse::<f64>(b"- -5"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parser.parse::<f64>(b" 5"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), parser.parse::<f64>(b"-\n5"));
+
+        // Whitespace after sign spellings, within quotes and whitespace.
+        let parser = parser.signs(SignSpellings::unicode()).quote(Some(b'"')).trim_whitespace(true);
+        assert_eq!(Ok(-5.0), parser.parse::<f64>(b"\xE2\x88\x92 5"));
+        assert_eq!(Ok((-5.0, 7)), parser.parse_partial::<f64>(b" \"- 5\" x"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn sign_whitespace_digit_test() {
+        FloatParser::new().sign_whitespace(Some(b" 0"));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn sign_whitespace_format_test() {
+        let parser = FloatParser::new().format(NumberFormat::JSON).sign_whitespace(Some(b" "));
+        assert_eq!(Ok(-5.0), parser.parse::<f64>(b"- 5"));
+        assert_eq!(Err((ErrorCode::InvalidPositiveMantissaSign, 0).into()), parser.parse::<f64>(b"+ 5"));
+        assert_eq!(Err((ErrorCode::InvalidPositiveMantissaSign, 0).into()), parser.parse::<f64>(b"+5"));
+    }
+
+    #[test]
+    fn unicode_spaces_test() {
+        // Unicode spaces are invalid digits by default.
+        let parser = FloatParser::new();
+        assert!(!parser.get_unicode_spaces());
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parser.parse::<f64>(b"\xE2\x80\x891.5"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), parser.parse::<f64>(b"1.5\xE2\x80\x89"));
+
+        // Thin space and narrow no-break space around the number.
+        let parser = parser.unicode_spaces(true);
+        assert!(parser.get_unicode_spaces());
+        assert_eq!(Ok(1.5), parser.parse::<f64>(b"1.5"));
+        assert_eq!(Ok(1.5), parser.parse::<f64>(b"\xE2\x80\x891.5"));
+        assert_eq!(Ok(-1.5), parser.parse::<f64>(b"\xE2\x80\xAF\xE2\x80\x89-1.5\xE2\x80\xAF"));
+        assert_eq!(Ok((1.5, 6)), parser.parse_partial::<f64>(b"1.5\xE2\x80\x89,2.5"));
+        assert_eq!(Ok((1.5, 3)), parser.parse_partial::<f64>(b"1.5\xE2\x80,"));
+
+        // Errors are at the index in the string.
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parser.parse::<f64>(b"\xE2\x80\x891x"));
+        assert_eq!(Err((ErrorCode::Empty, 3).into()), parser.parse::<f64>(b"\xE2\x80\x89"));
+
+        // Unicode spaces within the number end the number, unless the
+        // integer digits are grouped.
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parser.parse::<f64>(b"1\xE2\x80\x89000.5"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 6).into()), parser.parse::<f64>(b"1.5\xE2\x80\x895"));
+
+        // Long numbers are parsed directly.
+        let mut long = b"\xE2\x80\x891.".to_vec();
+        long.extend_from_slice(&[b'0'; 1000]);
+        long.extend_from_slice(b"\xE2\x80\x89");
+        assert_eq!(Ok(1.0), parser.parse::<f64>(&long));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn unicode_spaces_grouped_test() {
+        // SI notation groups digits with a thin space or an ASCII space.
+        let format = NumberFormat::grouped(b' ', 3).unwrap();
+        let parser = FloatParser::new().format(format);
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parser.parse::<f64>(b"1\xE2\x80\x89000"));
+
+        let parser = parser.unicode_spaces(true);
+        assert_eq!(format, parser.get_format());
+        assert_eq!(Ok(1000000.5), parser.parse::<f64>(b"1 000 000.5"));
+        assert_eq!(Ok(1000000.5), parser.parse::<f64>(b"1\xE2\x80\x89000\xE2\x80\x89000.5"));
+        assert_eq!(Ok(-1000000.5), parser.parse::<f64>(b"-1\xE2\x80\xAF000 000.5"));
+        assert_eq!(Ok(1000000.5), parser.parse::<f64>(b"\xE2\x80\x891 000\xE2\x80\x89000.5\xE2\x80\xAF"));
+        assert_eq!(Ok(1e6), parser.parse::<f64>(b"0\xE2\x80\x89001\xE2\x80\x89000e3"));
+        assert_eq!(Ok((1000.0, 7)), parser.parse_partial::<f64>(b"1\xE2\x80\x89000,5"));
+        assert_eq!(Ok((1000.0, 10)), parser.parse_partial::<f64>(b"1\xE2\x80\x89000\xE2\x80\x89,5"));
+
+        // Thin spaces are validated like the digit separator.
+        assert_eq!(Err((ErrorCode::InvalidDigitGrouping, 5).into()), parser.parse::<f64>(b"\xE2\x80\x891 00 000"));
+        assert_eq!(Err((ErrorCode::InvalidDigitGrouping, 4).into()), parser.parse::<f64>(b"1\xE2\x80\x8900\xE2\x80\x89000"));
+        assert_eq!(Err((ErrorCode::InvalidDigitGrouping, 0).into()), parser.parse::<f64>(b"1000\xE2\x80\x89000"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 7).into()), parser.parse::<f64>(b"1\xE2\x80\x89000x"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parser.parse::<f64>(b"1 \xE2\x80\x89000"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 6).into()), parser.parse::<f64>(b"1.5\xE2\x80\x89000"));
+
+        // European numbers group digits with a narrow no-break space.
+        let parser = parser.format(NumberFormat::european(b'.').unwrap());
+        assert_eq!(Ok(1234.5), parser.parse::<f64>(b"1\xE2\x80\xAF234,5"));
+        assert_eq!(Ok(1234567.5), parser.parse::<f64>(b"1.234\xE2\x80\xAF567,5"));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn parse_ambiguous_test() {
+        let parser = FloatParser::new();
+        let period = InferredSeparators::PeriodDecimalPoint;
+        let comma = InferredSeparators::CommaDecimalPoint;
+        assert_eq!(Ok((1234.0, InferredSeparators::Neither)), parser.parse_ambiguous::<f64>(b"1234"));
+        assert_eq!(Ok((1234.0, period)), parser.parse_ambiguous::<f64>(b"1,234"));
+        assert_eq!(Ok((1234.0, comma)), parser.parse_ambiguous::<f64>(b"1.234"));
+        assert_eq!(Ok((1.23, comma)), parser.parse_ambiguous::<f64>(b"1,23"));
+        assert_eq!(Ok((1.2345, period)), parser.parse_ambiguous::<f64>(b"1.2345"));
+        assert_eq!(Ok((1234.56, period)), parser.parse_ambiguous::<f64>(b"1,234.56"));
+        assert_eq!(Ok((1234.56, comma)), parser.parse_ambiguous::<f64>(b"1.234,56"));
+        assert_eq!(Ok((-1234567.0, period)), parser.parse_ambiguous::<f64>(b"-1,234,567"));
+        assert_eq!(Ok((1234567.5, comma)), parser.parse_ambiguous::<f64>(b"1.234.567,5"));
+        assert_eq!(Ok((1.5e3, comma)), parser.parse_ambiguous::<f32>(b"1,5e3"));
+
+        // Invalid groups and repeated decimal points.
+        assert_eq!(Err((ErrorCode::InvalidDigitGrouping, 2).into()), parser.parse_ambiguous::<f64>(b"1,23,456"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), parser.parse_ambiguous::<f64>(b"1.234,567.8"));
+    }
+
+    #[test]
+    fn sign_spellings_test() {
+        // Only ASCII signs are accepted by default.
+        let parser = FloatParser::new();
+        assert_eq!(None, parser.get_signs());
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parser.parse::<f64>(b"\xE2\x88\x921.5"));
+
+        // Unicode minus and plus signs.
+        let parser = FloatParser::new().signs(SignSpellings::unicode());
+        assert_eq!(Some(SignSpellings::unicode()), parser.get_signs());
+        assert_eq!(Ok(-1.5), parser.parse::<f64>(b"\xE2\x88\x921.5"));
+        assert_eq!(Ok((-1.5, 6)), parser.parse_partial::<f64>(b"\xE2\x88\x921.5,2"));
+        assert_eq!(Ok((-1.5e3, 8)), parser.parse_partial::<f32>(b"\xEF\xBC\x8D1.5e3"));
+        assert_eq!(Ok(2.5), parser.parse::<f64>(b"\xEF\xBC\x8B2.5"));
+        assert_eq!(Ok(-1.5), parser.parse::<f64>(b"-1.5"));
+        assert_eq!(Ok(f64::NEG_INFINITY), parser.parse::<f64>(b"\xE2\x88\x92inf"));
+        assert_eq!(Err((ErrorCode::Empty, 3).into()), parser.parse::<f64>(b"\xE2\x88\x92"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 3).into()), parser.parse::<f64>(b"\xE2\x88\x92-1.5"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 6).into()), parser.parse::<f64>(b"\xE2\x88\x921.5x"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parser.parse::<f64>(b"\xC2\xB11.5"));
+
+        // Sign spellings within quotes and whitespace.
+        let parser = parser.quote(Some(b'"')).trim_whitespace(true);
+        assert_eq!(Ok((-1.5, 9)), parser.parse_partial::<f64>(b" \"\xE2\x88\x921.5\"x"));
+    }
+
+    #[test]
+    #[cfg(feature = "correct")]
+    fn quote_correct_test() {
+        let parser = FloatParser::new().quote(Some(b'"'));
+        assert!(parser.will_be_exact::<f64>(b"\"1.5\""));
+        assert!(!parser.will_be_exact::<f64>(b"\"1e-23\""));
+        assert!(parser.will_be_exact::<f64>(b"\"1.5"));
+        assert!(!parser.strict_quote(true).will_be_exact::<f64>(b"\"1.5"));
+
+        let (_, _, _, error) = parser.parse_with_neighbors::<f64>(b"\"9007199254740993\"").unwrap();
+        assert_eq!(0.5, error);
+    }
+
+    #[test]
+    #[cfg(feature = "correct")]
+    fn will_be_exact_test() {
+        let parser = FloatParser::new();
+        assert!(parser.will_be_exact::<f64>(b"0"));
+        assert!(parser.will_be_exact::<f64>(b"-0.0e-400"));
+        assert!(parser.will_be_exact::<f64>(b"1.5"));
+        assert!(parser.will_be_exact::<f64>(b"+1.5"));
+        assert!(parser.will_be_exact::<f64>(b"0.1"));
+        assert!(parser.will_be_exact::<f64>(b"1.50"));
+        assert!(parser.will_be_exact::<f64>(b"1e22"));
+        assert!(parser.will_be_exact::<f64>(b"123e30"));
+        assert!(!parser.will_be_exact::<f64>(b"1e38"));
+        assert!(!parser.will_be_exact::<f64>(b"1e-23"));
+        assert!(parser.will_be_exact::<f64>(b"9007199254740992"));
+        assert!(!parser.will_be_exact::<f64>(b"9007199254740993"));
+        assert!(parser.will_be_exact::<f32>(b"16777216"));
+        assert!(!parser.will_be_exact::<f32>(b"16777217"));
+        assert!(parser.will_be_exact::<f32>(b"1e10"));
+        assert!(!parser.will_be_exact::<f32>(b"1e-11"));
+
+        // Invalid floats, partial floats and special values.
+        assert!(!parser.will_be_exact::<f64>(b""));
+        assert!(!parser.will_be_exact::<f64>(b"-"));
+        assert!(!parser.will_be_exact::<f64>(b"1.5x"));
+        assert!(!parser.will_be_exact::<f64>(b"inf"));
+        assert!(!parser.will_be_exact::<f64>(b"NaN"));
+
+        // Every exact float matches the parsed float.
+        let inputs: [&[u8]; 4] = [b"1.5", b"0.1", b"1e22", b"123e30"];
+        for input in inputs.iter() {
+            let expected: f64 = crate::parse(input).unwrap();
+            assert_eq!(Ok(expected), parser.parse::<f64>(input));
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "correct", feature = "radix"))]
+    fn will_be_exact_radix_test() {
+        let parser = FloatParser::new().radix(2);
+        assert!(parser.will_be_exact::<f64>(b"1.1e1010"));
+        assert!(parser.will_be_exact::<f64>(b"11111111111111111111111111111111111111111111111111111"));
+        assert!(!parser.will_be_exact::<f64>(b"111111111111111111111111111111111111111111111111111111"));
+        let parser = FloatParser::new().radix(3);
+        assert!(parser.will_be_exact::<f64>(b"1.1"));
+    }
+
+    #[cfg(feature = "correct")]
+    #[test]
+    fn parse_with_neighbors_test() {
+        let parser = FloatParser::new();
+        let error = | bytes | parser.parse_with_neighbors::<f64>(bytes).unwrap().3;
+
+        // Exact values.
+        assert_eq!(Ok((1.0, 0.9999999999999999, 1.0000000000000002, 0.0)), parser.parse_with_neighbors::<f64>(b"1.0"));
+        assert_eq!(Ok((-1.5, -1.5000000000000002, -1.4999999999999998, 0.0)), parser.parse_with_neighbors::<f64>(b"-1.5"));
+        assert_eq!(Ok((0.0, -5e-324, 5e-324, 0.0)), parser.parse_with_neighbors::<f64>(b"0.0e-400"));
+        assert_eq!(0.0, error(b"1e22"));
+        assert_eq!(0.0, error(b"0.1000000000000000055511151231257827021181583404541015625"));
+
+        // Inexact values.
+        assert_relative_eq!(-0.4, error(b"0.1"), epsilon=1e-15);
+        assert_relative_eq!(0.4, error(b"-0.1"), epsilon=1e-15);
+        assert_relative_eq!(-0.2, parser.parse_with_neighbors::<f32>(b"0.1").unwrap().3, epsilon=1e-15);
+        assert!(error(b"0.10000000000000000555111512312578270211815834045410156251") > 0.0);
+        assert!(error(b"0.10000000000000000555111512312578270211815834045410156249") < 0.0);
+
+        // Halfway values, including below a power of two, where the gap
+        // to the previous float is half the gap to the next float.
+        assert_eq!(0.5, error(b"9007199254740993"));
+        assert_eq!(-0.5, error(b"9007199254740995"));
+        assert_eq!(-0.25, error(b"9007199254740991.75"));
+        assert_eq!(0.25, error(b"9007199254740992.5"));
+
+        // Underflow and overflow.
+        assert_relative_eq!(2.024022533073106e-77, error(b"1e-400"), max_relative=1e-12);
+        assert_eq!(0.0, error(b"1e-5000"));
+        assert_eq!(f64::NEG_INFINITY, error(b"1e400"));
+        assert_eq!(f64::INFINITY, error(b"-1e400"));
+
+        // Special values.
+        assert_eq!(Ok((f64::INFINITY, f64::MAX, f64::INFINITY, 0.0)), parser.parse_with_neighbors::<f64>(b"inf"));
+        let (value, prev, next, error) = parser.parse_with_neighbors::<f64>(b"NaN").unwrap();
+        assert!(value.is_nan() && prev.is_nan() && next.is_nan());
+        assert_eq!(0.0, error);
+
+        // Invalid strings.
+        assert!(parser.parse_with_neighbors::<f64>(b"1.5x").is_err());
+        assert!(parser.parse_with_neighbors::<f64>(b"").is_err());
+    }
+
+    #[cfg(feature = "correct")]
+    #[test]
+    fn will_be_exact_options_test() {
+        // The digits are inspected after removing what the parser consumes.
+        let parser = FloatParser::new().signs(SignSpellings::unicode());
+        assert!(parser.will_be_exact::<f64>(b"\xE2\x88\x921.5"));
+        assert!(!parser.will_be_exact::<f64>(b"\xE2\x88\x921e-23"));
+        let parser = FloatParser::new().unicode_spaces(true);
+        assert!(parser.will_be_exact::<f64>(b"\xE2\x80\x891.5\xE2\x80\xAF"));
+        let parser = FloatParser::new().sign_whitespace(Some(b" "));
+        assert!(parser.will_be_exact::<f64>(b"- 1.5"));
+
+        // Strings rejected by the parser are never exact.
+        let parser = FloatParser::new().require_unsigned(true);
+        assert!(parser.will_be_exact::<f64>(b"1.5"));
+        assert!(!parser.will_be_exact::<f64>(b"-1.5"));
+        let parser = FloatParser::new().special(SpecialValues::lenient());
+        assert!(!parser.will_be_exact::<f64>(b"-Infinity"));
+        let parser = FloatParser::new().flush_to_zero(true);
+        assert!(!parser.will_be_exact::<f64>(b"1e-310"));
+    }
+
+    #[cfg(feature = "correct")]
+    #[test]
+    fn parse_with_neighbors_options_test() {
+        // The rounding error uses the digits and sign the parser consumes.
+        let parser = FloatParser::new().signs(SignSpellings::unicode());
+        assert_relative_eq!(0.4, parser.parse_with_neighbors::<f64>(b"\xE2\x88\x920.1").unwrap().3, epsilon=1e-15);
+        let parser = FloatParser::new().unicode_spaces(true);
+        assert_relative_eq!(-0.4, parser.parse_with_neighbors::<f64>(b"\xE2\x80\x890.1\xE2\x80\xAF").unwrap().3, epsilon=1e-15);
+        let parser = FloatParser::new().sign_whitespace(Some(b" "));
+        assert_relative_eq!(0.4, parser.parse_with_neighbors::<f64>(b"- 0.1").unwrap().3, epsilon=1e-15);
+
+        // Special values are exact, for every spelling.
+        let parser = FloatParser::new().special(SpecialValues::lenient()).signs(SignSpellings::unicode());
+        assert_eq!(Ok((f64::NEG_INFINITY, f64::NEG_INFINITY, f64::MIN, 0.0)), parser.parse_with_neighbors::<f64>(b"-Infinity"));
+        assert_eq!(Ok((f64::NEG_INFINITY, f64::NEG_INFINITY, f64::MIN, 0.0)), parser.parse_with_neighbors::<f64>(b"\xE2\x88\x92inf"));
+
+        // Flushed values skip the denormal floats.
+        let min = 2.2250738585072014e-308;
+        let parser = FloatParser::new().flush_to_zero(true);
+        let (value, prev, next, error) = parser.parse_with_neighbors::<f64>(b"1e-310").unwrap();
+        assert_eq!((0.0, -min, min), (value, prev, next));
+        assert_relative_eq!(1e-310 / min, error, max_relative=1e-12);
+        let (value, prev, next, error) = parser.parse_with_neighbors::<f64>(b"-1e-310").unwrap();
+        assert_eq!((0.0, -min, min), (value, prev, next));
+        assert_relative_eq!(-1e-310 / min, error, max_relative=1e-12);
+        let (value, prev, next, error) = parser.parse_with_neighbors::<f64>(b"2.2250738585072012e-308").unwrap();
+        assert_eq!((min, 0.0, 2.225073858507202e-308), (value, prev, next));
+        assert!(error < 0.0 && error > -1e-15);
+    }
+
+    #[cfg(all(feature = "correct", feature = "radix"))]
+    #[test]
+    fn parse_with_neighbors_radix_test() {
+        // 1/3 is 0.0101... in binary, so the error is 1/3 of the gap.
+        let parser = FloatParser::new().radix(3);
+        let error = parser.parse_with_neighbors::<f64>(b"0.1").unwrap().3;
+        assert_relative_eq!(1.0 / 3.0, error, epsilon=1e-15);
+
+        let parser = FloatParser::new().radix(2);
+        assert_eq!(Ok((0.5, 0.49999999999999994, 0.5000000000000001, 0.0)), parser.parse_with_neighbors::<f64>(b"0.1"));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn radix_test() {
+        let parser = FloatParser::new().radix(36);
+        assert_eq!(36, parser.get_radix());
+        assert_eq!(Ok(1234.0), parser.parse::<f64>(b"YA"));
+        assert_eq!(Ok(1234.0), parser.parse::<f32>(b"YA"));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    #[should_panic]
+    fn radix_panic_test() {
+        FloatParser::new().radix(37);
+    }
+
+    #[test]
+    fn parse_strided_test() {
+        // Fixed-width records of a 4-byte id, an 8-byte price and a
+        // 6-byte quantity, padded with trailing spaces.
+        let buffer = b"0001  12.50 3     0002 1.25e2 -0.5  0003-7      10.25 ";
+        let record_len = 18;
+        let prices = [4, 4 + record_len, 4 + 2 * record_len];
+        let quantities = [12, 12 + record_len, 12 + 2 * record_len];
+        let parser = FloatParser::new().trim_whitespace(true);
+
+        let mut values = [0.0f64; 3];
+        assert_eq!(Ok(()), parser.parse_strided(buffer, &prices, 8, &mut values));
+        assert_eq!(values, [12.5, 125.0, -7.0]);
+        assert_eq!(Ok(()), parser.parse_strided(buffer, &quantities, 6, &mut values));
+        assert_eq!(values, [3.0, -0.5, 10.25]);
+
+        // The parser is reused for other float types.
+        let mut values = [0.0f32; 3];
+        assert_eq!(Ok(()), parser.parse_strided(buffer, &prices, 8, &mut values));
+        assert_eq!(values, [12.5, 125.0, -7.0]);
+
+        // Padding is invalid without trimming whitespace.
+        let mut values = [0.0f64; 3];
+        let error = | code, index | -> Result<()> { Err((code, index).into()) };
+        assert_eq!(error(ErrorCode::EmptyMantissa, 0), FloatParser::new().parse_strided(buffer, &prices, 8, &mut values));
+
+        // The error index is the index of the field.
+        assert_eq!(error(ErrorCode::InvalidDigit, 1), parser.parse_strided(b"1.5 2.5x3.5 ", &[0, 4, 8], 4, &mut values));
+        assert_eq!(values[0], 1.5);
+        assert_eq!(error(ErrorCode::Empty, 0), parser.parse_strided(b"    ", &[0], 4, &mut values));
+        assert_eq!(error(ErrorCode::TooManyElements, 1), parser.parse_strided(b"1 2 ", &[0, 2], 2, &mut values[..1]));
+
+        // No fields.
+        assert_eq!(Ok(()), parser.parse_strided::<f64>(b"", &[], 4, &mut []));
+    }
+
+    #[test]
+    fn parse_strided_out_of_bounds_test() {
+        let mut values = [0.0f64; 2];
+        let parser = FloatParser::new();
+        let error = | index | -> Result<()> { Err((ErrorCode::OutOfRange, index).into()) };
+        assert_eq!(error(0), parser.parse_strided(b"1.5", &[0], 4, &mut values));
+        assert_eq!(error(1), parser.parse_strided(b"1.52.5", &[0, 4], 3, &mut values));
+        assert_eq!(values[0], 1.5);
+        assert_eq!(error(0), parser.parse_strided(b"1.5", &[usize::max_value()], 2, &mut values));
+        assert_eq!(error(0), parser.parse_strided(b"1.5", &[4], 0, &mut values));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn format_test() {
+        let format = NumberFormat::ignore(b'_').unwrap();
+        let parser = FloatParser::new().format(format);
+        assert_eq!(format, parser.get_format());
+        assert_eq!(Ok(1234.5), parser.parse::<f64>(b"1_2_3_4.5"));
+
+        let format = NumberFormat::ignore(b'_').unwrap();
+        let parser = FloatParser::new().format(format);
+        assert_eq!(Ok((1234.5, 3)), parser.parse_with_scale::<f64>(b"1_234.5_0_0"));
+
+        let format = NumberFormat::JSON;
+        let parser = FloatParser::new().format(format).special(SpecialValues::lenient());
+        assert!(parser.parse::<f64>(b"inf").is_err());
+
+        let format = NumberFormat::ignore(b'_').unwrap();
+        let parser = FloatParser::new().format(format).special(SpecialValues::json5());
+        assert_eq!(Ok(f64::INFINITY), parser.parse::<f64>(b"In_fin_ity"));
+        assert!(parser.parse::<f64>(b"infinity").is_err());
+    }
+}
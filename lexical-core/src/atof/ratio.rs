@@ -0,0 +1,275 @@
+//! Parse floats from ratios and mixed numbers, like `"3/4"` or `"1 1/2"`.
+
+use crate::util::*;
+
+// RATIO PARSER
+
+/// Parser for ratios and mixed numbers, like `"3/4"` or `"1 1/2"`.
+///
+/// A ratio is an optional sign, an optional whole number and separator,
+/// and a numerator and denominator separated by the slash. Each number
+/// is parsed as a `u64`, and the quotient is calculated with a single
+/// float division, so the value is correctly rounded if the numerator
+/// (including the whole number) and the denominator are exactly
+/// representable by the float. A whole number without a fraction is
+/// also accepted.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{ErrorCode, RatioParser};
+/// # pub fn main() {
+/// let parser = RatioParser::new();
+/// assert_eq!(parser.parse::<f64>(b"3/4"), Ok(0.75));
+/// assert_eq!(parser.parse::<f64>(b"-1 1/2"), Ok(-1.5));
+/// assert_eq!(parser.parse::<f64>(b"2"), Ok(2.0));
+/// assert_eq!(parser.parse::<f64>(b"1/0").unwrap_err().code, ErrorCode::DivisionByZero);
+///
+/// // Mixed numbers with a hyphen separator.
+/// let parser = RatioParser::new().separator(b'-');
+/// assert_eq!(parser.parse::<f32>(b"2-3/8"), Ok(2.375));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RatioParser {
+    /// Character separating the numerator and denominator.
+    slash: u8,
+    /// Character separating the whole number and the fraction.
+    separator: u8,
+}
+
+impl RatioParser {
+    /// Create a parser with a `/` slash and a space separator.
+    #[inline]
+    pub fn new() -> RatioParser {
+        RatioParser {
+            slash: b'/',
+            separator: b' ',
+        }
+    }
+
+    /// Set the character separating the numerator and denominator.
+    ///
+    /// * `slash`   - Character separating the numerator and denominator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slash is a digit or the separator.
+    #[inline]
+    pub fn slash(mut self, slash: u8) -> RatioParser {
+        assert!(!slash.is_ascii_digit() && slash != self.separator);
+        self.slash = slash;
+        self
+    }
+
+    /// Set the character separating the whole number and the fraction.
+    ///
+    /// * `separator`   - Character separating the whole number and the fraction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the separator is a digit or the slash.
+    #[inline]
+    pub fn separator(mut self, separator: u8) -> RatioParser {
+        assert!(!separator.is_ascii_digit() && separator != self.slash);
+        self.separator = separator;
+        self
+    }
+
+    /// Get the character separating the numerator and denominator.
+    #[inline]
+    pub fn get_slash(&self) -> u8 {
+        self.slash
+    }
+
+    /// Get the character separating the whole number and the fraction.
+    #[inline]
+    pub fn get_separator(&self) -> u8 {
+        self.separator
+    }
+
+    /// Parse float from a ratio or mixed number.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing, or
+    /// `ErrorCode::DivisionByZero` if the denominator is zero.
+    ///
+    /// * `bytes`   - Byte slice containing a ratio.
+    #[inline]
+    pub fn parse<F: Float>(&self, bytes: &[u8])
+        -> Result<F>
+    {
+        let (negative, mut index) = match bytes.first() {
+            Some(&b'+') => (false, 1),
+            Some(&b'-') => (true, 1),
+            _           => (false, 0),
+        };
+
+        // Parse the whole number, or the numerator.
+        let (first, processed) = parse_integer(bytes, index)?;
+        index += processed;
+        let (whole, numerator) = match bytes.get(index) {
+            None                            => {
+                let value: F = as_cast(first);
+                return Ok(if negative { -value } else { value });
+            },
+            Some(&c) if c == self.slash     => (0, first),
+            Some(&c) if c == self.separator => {
+                let (numerator, processed) = parse_integer(bytes, index + 1)?;
+                index += processed + 1;
+                match bytes.get(index) {
+                    Some(&c) if c == self.slash => (first, numerator),
+                    Some(_)                     => return Err((ErrorCode::InvalidDigit, index).into()),
+                    None                        => return Err((ErrorCode::Empty, index).into()),
+                }
+            },
+            Some(_)                         => return Err((ErrorCode::InvalidDigit, index).into()),
+        };
+
+        // Parse the denominator, after the slash.
+        index += 1;
+        let (denominator, processed) = parse_integer(bytes, index)?;
+        if index + processed != bytes.len() {
+            return Err((ErrorCode::InvalidDigit, index + processed).into());
+        } else if denominator == 0 {
+            return Err((ErrorCode::DivisionByZero, index).into());
+        }
+
+        // Calculate the quotient with a single division.
+        let numerator = whole as u128 * denominator as u128 + numerator as u128;
+        let value = as_cast::<F, _>(numerator) / as_cast::<F, _>(denominator);
+        Ok(if negative { -value } else { value })
+    }
+}
+
+impl Default for RatioParser {
+    #[inline]
+    fn default() -> RatioParser {
+        RatioParser::new()
+    }
+}
+
+// Parse an unsigned integer starting at the index, without a sign.
+perftools_inline!{
+fn parse_integer(bytes: &[u8], index: usize) -> Result<(u64, usize)> {
+    match bytes.get(index) {
+        Some(c) if c.is_ascii_digit() => {
+            let offset = | e: Error | Error::from((e.code, e.index + index));
+            u64::from_lexical_partial(&bytes[index..]).map_err(offset)
+        },
+        Some(_) => Err((ErrorCode::InvalidDigit, index).into()),
+        None    => Err((ErrorCode::Empty, index).into()),
+    }
+}}
+
+/// Parse `f32` from a ratio or mixed number, like `"3/4"` or `"1 1/2"`.
+///
+/// See [`RatioParser`].
+///
+/// * `bytes`   - Byte slice containing a ratio.
+///
+/// [`RatioParser`]: struct.RatioParser.html
+#[inline]
+pub fn parse_ratio_f32(bytes: &[u8])
+    -> Result<f32>
+{
+    RatioParser::new().parse(bytes)
+}
+
+/// Parse `f64` from a ratio or mixed number, like `"3/4"` or `"1 1/2"`.
+///
+/// See [`RatioParser`].
+///
+/// * `bytes`   - Byte slice containing a ratio.
+///
+/// [`RatioParser`]: struct.RatioParser.html
+#[inline]
+pub fn parse_ratio_f64(bytes: &[u8])
+    -> Result<f64>
+{
+    RatioParser::new().parse(bytes)
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ratio_f64_test() {
+        assert_eq!(Ok(0.75), parse_ratio_f64(b"3/4"));
+        assert_eq!(Ok(-0.75), parse_ratio_f64(b"-3/4"));
+        assert_eq!(Ok(0.75), parse_ratio_f64(b"+3/4"));
+        assert_eq!(Ok(1.0 / 3.0), parse_ratio_f64(b"1/3"));
+        assert_eq!(Ok(2.5), parse_ratio_f64(b"5/2"));
+        assert_eq!(Ok(0.0), parse_ratio_f64(b"0/7"));
+        assert_eq!(Ok(12.0), parse_ratio_f64(b"12"));
+        assert_eq!(Ok(-12.0), parse_ratio_f64(b"-12"));
+
+        // Mixed numbers.
+        assert_eq!(Ok(1.5), parse_ratio_f64(b"1 1/2"));
+        assert_eq!(Ok(-1.5), parse_ratio_f64(b"-1 1/2"));
+        assert_eq!(Ok(2.0 + 1.0 / 3.0), parse_ratio_f64(b"2 1/3"));
+        assert_eq!(Ok(7.0 / 3.0), parse_ratio_f64(b"2 1/3"));
+        assert_eq!(Ok(3.0), parse_ratio_f64(b"1 4/2"));
+
+        // The numerator is combined with the whole number without overflow.
+        assert_eq!(Ok(18446744073709551616.0), parse_ratio_f64(b"18446744073709551615 1/2"));
+    }
+
+    #[test]
+    fn parse_ratio_f32_test() {
+        assert_eq!(Ok(0.75), parse_ratio_f32(b"3/4"));
+        assert_eq!(Ok(-1.5), parse_ratio_f32(b"-1 1/2"));
+        assert_eq!(Ok(1.0 / 3.0), parse_ratio_f32(b"1/3"));
+    }
+
+    #[test]
+    fn parse_ratio_error_test() {
+        let err = | code, index | -> Result<f64> { Err((code, index).into()) };
+        assert_eq!(err(ErrorCode::DivisionByZero, 2), parse_ratio_f64(b"1/0"));
+        assert_eq!(err(ErrorCode::DivisionByZero, 4), parse_ratio_f64(b"1 1/00"));
+        assert_eq!(err(ErrorCode::DivisionByZero, 3), parse_ratio_f64(b"-0/0"));
+        assert_eq!(err(ErrorCode::Empty, 0), parse_ratio_f64(b""));
+        assert_eq!(err(ErrorCode::Empty, 1), parse_ratio_f64(b"-"));
+        assert_eq!(err(ErrorCode::Empty, 2), parse_ratio_f64(b"1/"));
+        assert_eq!(err(ErrorCode::Empty, 3), parse_ratio_f64(b"1 2"));
+        assert_eq!(err(ErrorCode::InvalidDigit, 0), parse_ratio_f64(b"/2"));
+        assert_eq!(err(ErrorCode::InvalidDigit, 2), parse_ratio_f64(b"1/-2"));
+        assert_eq!(err(ErrorCode::InvalidDigit, 2), parse_ratio_f64(b"1 -1/2"));
+        assert_eq!(err(ErrorCode::InvalidDigit, 3), parse_ratio_f64(b"1/2/3"));
+        assert_eq!(err(ErrorCode::InvalidDigit, 3), parse_ratio_f64(b"1 2 3/4"));
+        assert_eq!(err(ErrorCode::InvalidDigit, 1), parse_ratio_f64(b"1.5/2"));
+        assert_eq!(err(ErrorCode::InvalidDigit, 3), parse_ratio_f64(b"3/4 "));
+        assert_eq!(err(ErrorCode::Overflow, 21), parse_ratio_f64(b"1/18446744073709551616"));
+    }
+
+    #[test]
+    fn ratio_parser_test() {
+        let parser = RatioParser::new().slash(b':').separator(b'+');
+        assert_eq!(b':', parser.get_slash());
+        assert_eq!(b'+', parser.get_separator());
+        assert_eq!(Ok(0.75), parser.parse::<f64>(b"3:4"));
+        assert_eq!(Ok(1.75), parser.parse::<f64>(b"1+3:4"));
+        assert_eq!(Ok(-1.75), parser.parse::<f64>(b"-1+3:4"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parser.parse::<f64>(b"3/4"));
+
+        let parser = RatioParser::new().separator(b'-');
+        assert_eq!(Ok(-2.375), parser.parse::<f64>(b"-2-3/8"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn ratio_parser_digit_test() {
+        RatioParser::new().slash(b'1');
+    }
+
+    #[test]
+    #[should_panic]
+    fn ratio_parser_same_test() {
+        RatioParser::new().separator(b'/');
+    }
+}
@@ -0,0 +1,122 @@
+//! Parse floats from raw pointers, like memory-mapped files.
+
+use crate::lib::slice;
+use crate::util::*;
+
+// Create the byte slice from the raw parts, without dereferencing the
+// pointer if the length is 0.
+perftools_inline!{
+unsafe fn bytes_from_raw_parts<'a>(ptr: *const u8, len: usize) -> &'a [u8] {
+    match len {
+        0 => &[],
+        _ => slice::from_raw_parts(ptr, len),
+    }
+}}
+
+/// Parse `f32` from a pointer and length, like a memory-mapped file.
+///
+/// See [`parse_f64_from_raw_parts`].
+///
+/// * `ptr`     - Pointer to the first byte of the numeric string.
+/// * `len`     - Number of bytes readable from the pointer.
+///
+/// # Safety
+///
+/// See [`parse_f64_from_raw_parts`].
+///
+/// [`parse_f64_from_raw_parts`]: fn.parse_f64_from_raw_parts.html
+#[inline]
+pub unsafe fn parse_f32_from_raw_parts(ptr: *const u8, len: usize)
+    -> Result<(f32, usize)>
+{
+    f32::from_lexical_partial(bytes_from_raw_parts(ptr, len))
+}
+
+/// Parse `f64` from a pointer and length, like a memory-mapped file.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the `len` bytes), returning the parsed value and the number of
+/// processed bytes, as if by [`parse_partial`]. No bytes are read at
+/// or past `ptr + len`, so a number ending exactly at the end of a
+/// memory mapping is parsed without reading past the mapping. The
+/// number of processed bytes is at most `len`, so the next value
+/// starts at `ptr + processed`, which is at most `ptr + len`, and the
+/// remaining length is `len - processed`.
+///
+/// * `ptr`     - Pointer to the first byte of the numeric string.
+/// * `len`     - Number of bytes readable from the pointer.
+///
+/// # Safety
+///
+/// If `len` is non-zero, `ptr` must be non-null and valid for reads of
+/// `len` bytes, all within a single allocation or mapping, and the bytes
+/// must not be mutated for the duration of the call. `len` must be no
+/// larger than `isize::MAX`. If `len` is 0, the pointer is never read,
+/// and may be null or dangling. The bytes do not need to be terminated
+/// by a NUL byte, nor do they need to be valid UTF-8: a NUL byte or
+/// any other invalid digit simply ends the number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let data = b"1.5e3,2.5";
+/// let (value, processed) = unsafe {
+///     lexical_core::parse_f64_from_raw_parts(data.as_ptr(), data.len())
+/// }.unwrap();
+/// assert_eq!((value, processed), (1500.0, 5));
+///
+/// // Parse only the first 3 bytes.
+/// let result = unsafe { lexical_core::parse_f64_from_raw_parts(data.as_ptr(), 3) };
+/// assert_eq!(result, Ok((1.5, 3)));
+/// # }
+/// ```
+///
+/// [`parse_partial`]: fn.parse_partial.html
+#[inline]
+pub unsafe fn parse_f64_from_raw_parts(ptr: *const u8, len: usize)
+    -> Result<(f64, usize)>
+{
+    f64::from_lexical_partial(bytes_from_raw_parts(ptr, len))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lib::ptr;
+
+    #[test]
+    fn parse_f64_from_raw_parts_test() {
+        let data = b"1.5e3,2.5\x00";
+        unsafe {
+            assert_eq!(Ok((1500.0, 5)), parse_f64_from_raw_parts(data.as_ptr(), data.len()));
+            assert_eq!(Ok((2.5, 3)), parse_f64_from_raw_parts(data.as_ptr().add(6), 4));
+            assert_eq!(Ok((2.5, 3)), parse_f64_from_raw_parts(data.as_ptr().add(6), 3));
+
+            // The number ends at the length, not the end of the data.
+            assert_eq!(Ok((1.0, 2)), parse_f64_from_raw_parts(data.as_ptr(), 2));
+            assert_eq!(Ok((1500.0, 5)), parse_f64_from_raw_parts(data.as_ptr(), 5));
+            assert_eq!(Err((ErrorCode::EmptyExponent, 4).into()), parse_f64_from_raw_parts(data.as_ptr(), 4));
+
+            // Empty data is never read.
+            assert_eq!(Err((ErrorCode::Empty, 0).into()), parse_f64_from_raw_parts(ptr::null(), 0));
+
+            // Invalid UTF-8 ends the number.
+            let data = b"-2.5\xFF\xFE";
+            assert_eq!(Ok((-2.5, 4)), parse_f64_from_raw_parts(data.as_ptr(), data.len()));
+        }
+    }
+
+    #[test]
+    fn parse_f32_from_raw_parts_test() {
+        let data = b"0.1 0.2";
+        unsafe {
+            assert_eq!(Ok((0.1, 3)), parse_f32_from_raw_parts(data.as_ptr(), data.len()));
+            assert_eq!(Ok((0.2, 3)), parse_f32_from_raw_parts(data.as_ptr().add(4), 3));
+        }
+    }
+}
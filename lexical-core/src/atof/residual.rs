@@ -0,0 +1,234 @@
+//! Parse floats with the residual rounding error.
+
+use crate::ftoa::exact_digits;
+use crate::lib::cmp;
+use crate::util::*;
+use super::rounded::scan_decimal;
+
+// Subtract the exact decimal expansion of the parsed float from the
+// exact decimal value of the string, using fixed-point decimal digits,
+// and then parse the difference as a float. The residual is at least
+// the smallest denormal float (`2^-1074`), or rounds to zero, and all
+// halfway points between denormals have at most 1075 fraction digits,
+// so digits below `10^MIN_POWER` are truncated to a sticky digit.
+
+/// Power of 10 of the lowest decimal digit.
+const MIN_POWER: i64 = -1080;
+
+/// Power of 10 of the highest decimal digit (`f64::MAX` is below `10^309`).
+const MAX_POWER: i64 = 309;
+
+/// Number of decimal digits, from `10^MIN_POWER` to `10^MAX_POWER`.
+const DIGITS_SIZE: usize = (MAX_POWER - MIN_POWER + 1) as usize;
+
+/// Buffer size for the exact decimal expansion of the float.
+const EXACT_SIZE: usize = 1080;
+
+/// Buffer size for the residual, with the sign and exponent.
+const BUFFER_SIZE: usize = DIGITS_SIZE + 16;
+
+// Store the decimal digits in the fixed-point digits.
+//
+// The digits may include the decimal point, and `exponent` is the power
+// of 10 of the last digit. Digits below the lowest power set the lowest
+// digit, as a sticky digit.
+perftools_inline!{
+fn store_digits(digits: &[u8], exponent: i64, fixed: &mut [u8; DIGITS_SIZE]) {
+    let mut sticky = false;
+    let iter = digits.iter().rev().filter(|&&c| c != b'.');
+    for (power, &c) in (exponent..).zip(iter) {
+        if power < MIN_POWER {
+            sticky |= c != b'0';
+        } else {
+            debug_assert!(power <= MAX_POWER);
+            fixed[(power - MIN_POWER) as usize] = c - b'0';
+        }
+    }
+    if sticky {
+        fixed[0] = 1;
+    }
+}}
+
+// Parse the float, and the residual of the exact value of the string.
+perftools_inline!{
+fn parse_with_error<F: FromLexical + Float>(bytes: &[u8])
+    -> Result<(F, F)>
+{
+    let value = F::from_lexical(bytes)?;
+    if value.is_nan() || value.is_special() {
+        return Ok((value, F::ZERO));
+    }
+
+    // Store the magnitude of the string.
+    let (negative, digits, exponent) = scan_decimal(bytes);
+    let mut input = [0u8; DIGITS_SIZE];
+    store_digits(digits, exponent, &mut input);
+
+    // Store the magnitude of the float, which has no digits below `10^-1074`.
+    let mut buffer = [b'0'; EXACT_SIZE];
+    let mut length = 0;
+    for (slot, c) in buffer.iter_mut().zip(exact_digits(value.abs())) {
+        *slot = c;
+        length += 1;
+    }
+    let exact = &buffer[..length];
+    let exponent = match exact.iter().position(|&c| c == b'.') {
+        Some(index) => -((length - index - 1) as i64),
+        None        => 0,
+    };
+    let mut parsed = [0u8; DIGITS_SIZE];
+    store_digits(exact, exponent, &mut parsed);
+
+    // Subtract the smaller magnitude from the larger.
+    let (negative, larger, smaller) = match input.iter().rev().cmp(parsed.iter().rev()) {
+        cmp::Ordering::Less => (!negative, &mut parsed, &input),
+        _                   => (negative, &mut input, &parsed),
+    };
+    let mut borrow = 0;
+    for (x, &y) in larger.iter_mut().zip(smaller.iter()) {
+        let subtrahend = y + borrow;
+        borrow = (*x < subtrahend) as u8;
+        *x = *x + 10 * borrow - subtrahend;
+    }
+
+    // Write the difference without leading or trailing zeros, and parse it.
+    let (start, end) = match (larger.iter().position(|&c| c != 0), larger.iter().rposition(|&c| c != 0)) {
+        (Some(start), Some(end)) => (start, end + 1),
+        _                        => return Ok((value, if negative { -F::ZERO } else { F::ZERO })),
+    };
+    let mut buffer = [b'0'; BUFFER_SIZE];
+    let mut index = 0;
+    if negative {
+        buffer[0] = b'-';
+        index += 1;
+    }
+    for &digit in larger[start..end].iter().rev() {
+        buffer[index] = b'0' + digit;
+        index += 1;
+    }
+    buffer[index] = b'e';
+    index += 1;
+    index += (start as i64 + MIN_POWER).to_lexical(&mut buffer[index..]).len();
+    let residual = F::from_lexical(&buffer[..index])?;
+    Ok((value, residual))
+}}
+
+/// Parse `f32` from string, with the residual rounding error.
+///
+/// See [`parse_f64_with_error`].
+///
+/// * `bytes`   - Byte slice containing a decimal string.
+///
+/// [`parse_f64_with_error`]: fn.parse_f64_with_error.html
+#[inline]
+pub fn parse_f32_with_error(bytes: &[u8])
+    -> Result<(f32, f32)>
+{
+    parse_with_error::<f32>(bytes)
+}
+
+/// Parse `f64` from string, with the residual rounding error.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. Returns the parsed
+/// float, and the residual, the exact value of the string minus the
+/// parsed float, rounded to the nearest float. Since the float is
+/// rounded to nearest, the residual is at most half an ulp of the
+/// float, and is exactly half an ulp only for halfway cases. The
+/// residual may be used to correct for the rounding of the parser in
+/// compensated (Kahan) summation.
+///
+/// The residual is calculated exactly, even for long inputs, and is
+/// zero if the string is exactly representable, or for special values.
+///
+/// * `bytes`   - Byte slice containing a decimal string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let (value, residual) = lexical_core::parse_f64_with_error(b"0.1").unwrap();
+/// assert_eq!(value, 0.1);
+/// assert_eq!(residual, -5.551115123125783e-18);
+///
+/// assert_eq!(lexical_core::parse_f64_with_error(b"0.5"), Ok((0.5, 0.0)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_f64_with_error(bytes: &[u8])
+    -> Result<(f64, f64)>
+{
+    parse_with_error::<f64>(bytes)
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Get the ulp of a positive, finite float.
+    fn ulp(value: f64) -> f64 {
+        f64::from_bits(value.to_bits() + 1) - value
+    }
+
+    #[test]
+    fn parse_f64_with_error_test() {
+        assert_eq!(Ok((0.1, -5.551115123125783e-18)), parse_f64_with_error(b"0.1"));
+        assert_eq!(Ok((-0.1, 5.551115123125783e-18)), parse_f64_with_error(b"-0.1"));
+        assert_eq!(Ok((0.3, 1.1102230246251566e-17)), parse_f64_with_error(b"0.3"));
+        assert_eq!(Ok((1e23, 8.388608e6)), parse_f64_with_error(b"1e23"));
+        assert_eq!(Ok((1.5, 0.0)), parse_f64_with_error(b"1.5"));
+        assert_eq!(Ok((0.0, 0.0)), parse_f64_with_error(b"0"));
+        assert_eq!(Ok((123456.0, 0.0)), parse_f64_with_error(b"0123456.000"));
+
+        // Halfway cases are exactly half an ulp.
+        assert_eq!(Ok((9007199254740992.0, 1.0)), parse_f64_with_error(b"9007199254740993"));
+        assert_eq!(Ok((9007199254740996.0, -1.0)), parse_f64_with_error(b"9007199254740995"));
+
+        // Values below the smallest denormal.
+        assert_eq!(Ok((0.0, 0.0)), parse_f64_with_error(b"1e-400"));
+        assert_eq!(Ok((5e-324, -0.0)), parse_f64_with_error(b"4e-324"));
+
+        // Special values and errors.
+        assert_eq!(Ok((f64::INFINITY, 0.0)), parse_f64_with_error(b"1e400"));
+        assert!(parse_f64_with_error(b"NaN").unwrap().0.is_nan());
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), parse_f64_with_error(b"0.1x"));
+    }
+
+    #[test]
+    fn parse_f64_with_error_long_test() {
+        // Digits below the lowest power are sticky.
+        let halfway = b"1.00000000000000011102230246251565404236316680908203125";
+        assert_eq!(Ok((1.0, 1.1102230246251565e-16)), parse_f64_with_error(halfway));
+        let mut above = halfway.to_vec();
+        above.extend_from_slice(&[b'0'; 2000]);
+        above.push(b'1');
+        let (value, residual) = parse_f64_with_error(&above).unwrap();
+        assert_eq!(value, 1.0000000000000002);
+        assert_eq!(residual, -1.1102230246251565e-16);
+    }
+
+    #[test]
+    fn parse_f64_with_error_bound_test() {
+        let strings: &[&[u8]] = &[
+            b"0.1", b"0.2", b"0.7", b"1.1", b"3.14159265358979323846", b"2.718281828459045235360287",
+            b"1e-300", b"1.7976931348623157e308", b"2.2250738585072011e-308", b"123456789012345678901234567890",
+            b"6.02214076e23", b"1.602176634e-19", b"9.999999999999999e22", b"4.9406564584124654e-324",
+        ];
+        for &string in strings.iter() {
+            let (value, residual) = parse_f64_with_error(string).unwrap();
+            assert!(residual.abs() <= ulp(value) / 2.0);
+            assert_eq!(value + residual, value);
+        }
+    }
+
+    #[test]
+    fn parse_f32_with_error_test() {
+        assert_eq!(Ok((0.1, -1.4901161e-9)), parse_f32_with_error(b"0.1"));
+        assert_eq!(Ok((16777216.0, 1.0)), parse_f32_with_error(b"16777217"));
+        assert_eq!(Ok((-2.5, 0.0)), parse_f32_with_error(b"-2.5"));
+    }
+}
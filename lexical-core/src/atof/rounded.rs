@@ -0,0 +1,274 @@
+//! Parse floats rounded to a number of decimal places.
+
+use crate::util::*;
+
+// Round the exact decimal value of the string to the decimal places,
+// and then parse the rounded decimal as a float. The rounded decimal is
+// written with at most `MAX_DIGITS` significant digits, and a sticky
+// digit if any significant digits are truncated, which is sufficient
+// for the correct rounding of the float: halfway cases of an `f64`
+// have at most 767 significant digits.
+
+/// Maximum number of significant digits in the rounded decimal.
+const MAX_DIGITS: usize = 800;
+
+/// Buffer size for the rounded decimal, with the sign, carry, sticky
+/// digit and exponent.
+const BUFFER_SIZE: usize = MAX_DIGITS + 32;
+
+/// Maximum magnitude of the scanned exponent, to avoid overflow.
+const MAX_EXPONENT: i64 = 0x1000_0000;
+
+// Get the sign, significant digits and exponent of a decimal string.
+//
+// The string must be a valid decimal float. Returns if the value is
+// negative, the digits (which may include the decimal point), and
+// the exponent of the last digit.
+perftools_inline!{
+pub(super) fn scan_decimal(bytes: &[u8]) -> (bool, &[u8], i64) {
+    let negative = bytes.first() == Some(&b'-');
+    let bytes = match bytes.first() {
+        Some(&b'+') | Some(&b'-') => &bytes[1..],
+        _                         => bytes,
+    };
+
+    // Split the mantissa and exponent.
+    let exponent_char = get_exponent_default_char().to_ascii_lowercase();
+    let (mantissa, exponent) = match bytes.iter().position(|c| c.to_ascii_lowercase() == exponent_char) {
+        Some(index) => (&bytes[..index], &bytes[index+1..]),
+        None        => (bytes, &bytes[bytes.len()..]),
+    };
+    let (exponent_negative, exponent) = match exponent.first() {
+        Some(&b'-') => (true, &exponent[1..]),
+        Some(&b'+') => (false, &exponent[1..]),
+        _           => (false, exponent),
+    };
+    let mut exp: i64 = 0;
+    for &c in exponent {
+        exp = (exp * 10 + (c - b'0') as i64).min(MAX_EXPONENT);
+    }
+    if exponent_negative {
+        exp = -exp;
+    }
+
+    // Strip leading zeros, and account for the fraction digits.
+    let digits = match mantissa.iter().position(|&c| c != b'0' && c != b'.') {
+        Some(index) => &mantissa[index..],
+        None        => &mantissa[mantissa.len()..],
+    };
+    if let Some(index) = digits.iter().position(|&c| c == b'.') {
+        exp -= (digits.len() - index - 1) as i64;
+    } else if let Some(index) = mantissa.iter().position(|&c| c == b'.') {
+        exp -= (mantissa.len() - index - 1) as i64;
+    }
+    (negative, digits, exp)
+}}
+
+// Round the decimal to the decimal places, and parse the float.
+perftools_inline!{
+fn parse_rounded<F: FromLexical + Float>(bytes: &[u8], decimal_places: u32)
+    -> Result<F>
+{
+    let value = F::from_lexical(bytes)?;
+    if value.is_nan() || value.is_special() {
+        return Ok(value);
+    }
+
+    // Digits below the decimal places are dropped.
+    let (negative, digits, exponent) = scan_decimal(bytes);
+    let mut iter = digits.iter().filter(|&&c| c != b'.');
+    let count = iter.clone().count() as i64;
+    let dropped = -(exponent + decimal_places as i64);
+    if count == 0 || dropped <= 0 {
+        return Ok(value);
+    }
+    let kept = count - dropped;
+
+    // Round the kept digits, using round-half-to-even.
+    let round_up = if kept < 0 {
+        false
+    } else {
+        let mut rest = iter.clone().skip(kept as usize);
+        let first = *rest.next().unwrap();
+        let last_odd = kept > 0 && (iter.clone().nth(kept as usize - 1).unwrap() - b'0') % 2 == 1;
+        match first {
+            b'6'..=b'9' => true,
+            b'5'        => last_odd || rest.any(|&c| c != b'0'),
+            _           => false,
+        }
+    };
+
+    // Write the rounded digits, after slots for the sign and the carry.
+    let mut buffer = [b'0'; BUFFER_SIZE];
+    let kept = kept.max(0) as usize;
+    let length = kept.min(MAX_DIGITS);
+    for (slot, &c) in buffer[2..length+2].iter_mut().zip(iter.by_ref()) {
+        *slot = c;
+    }
+    let mut tail = iter.take(kept - length);
+    let sticky = match round_up {
+        true  => !tail.all(|&c| c == b'9'),
+        false => tail.any(|&c| c != b'0'),
+    };
+    let mut start = 2;
+    let mut end = length + 2;
+    if round_up && !sticky {
+        // Propagate the carry through the written digits.
+        let mut index = end - 1;
+        while index >= start && buffer[index] == b'9' {
+            buffer[index] = b'0';
+            index -= 1;
+        }
+        buffer[index] += 1;
+        start = start.min(index);
+    }
+    if sticky {
+        buffer[end] = b'1';
+        end += 1;
+    }
+    if start == end {
+        return Ok(if negative { -F::ZERO } else { F::ZERO });
+    }
+
+    // Write the sign and exponent, and parse the rounded decimal.
+    if negative {
+        start -= 1;
+        buffer[start] = b'-';
+    }
+    let exponent = (kept - length) as i64 - sticky as i64 - decimal_places as i64;
+    buffer[end] = b'e';
+    let written = exponent.to_lexical(&mut buffer[end+1..]).len();
+    F::from_lexical(&buffer[start..end+1+written])
+}}
+
+/// Parse `f32` from string, rounded to a number of decimal places.
+///
+/// See [`parse_f64_rounded`].
+///
+/// * `bytes`           - Byte slice containing a decimal string.
+/// * `decimal_places`  - Number of decimal places to round to.
+///
+/// [`parse_f64_rounded`]: fn.parse_f64_rounded.html
+#[inline]
+pub fn parse_f32_rounded(bytes: &[u8], decimal_places: u32)
+    -> Result<f32>
+{
+    parse_rounded::<f32>(bytes, decimal_places)
+}
+
+/// Parse `f64` from string, rounded to a number of decimal places.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. The exact decimal value
+/// of the string is rounded to the decimal places, using round-half-to-
+/// even (bankers' rounding), and the rounded decimal is then parsed as
+/// a float. Unlike scaling the parsed float by a power of 10, this is
+/// exact, so `"2.675"` rounded to 2 places is `2.68`, even though the
+/// nearest float to `2.675` is below the halfway point. Special values
+/// are returned unchanged, and values that round to zero keep their sign.
+///
+/// * `bytes`           - Byte slice containing a decimal string.
+/// * `decimal_places`  - Number of decimal places to round to.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_f64_rounded(b"2.675", 2), Ok(2.68));
+/// assert_eq!(lexical_core::parse_f64_rounded(b"2.665", 2), Ok(2.66));
+/// assert_eq!(lexical_core::parse_f64_rounded(b"-1.5", 0), Ok(-2.0));
+/// assert_eq!(lexical_core::parse_f64_rounded(b"1234.5e-3", 2), Ok(1.23));
+/// # }
+/// ```
+#[inline]
+pub fn parse_f64_rounded(bytes: &[u8], decimal_places: u32)
+    -> Result<f64>
+{
+    parse_rounded::<f64>(bytes, decimal_places)
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scan_decimal_test() {
+        assert_eq!((false, &b"2.675"[..], -3), scan_decimal(b"2.675"));
+        assert_eq!((true, &b"12"[..], 3), scan_decimal(b"-0012e3"));
+        assert_eq!((false, &b"5"[..], -3), scan_decimal(b"+0.005"));
+        assert_eq!((false, &b"1.5"[..], -6), scan_decimal(b"1.5E-5"));
+        assert_eq!((false, &b""[..], -2), scan_decimal(b"0.00"));
+        assert_eq!((false, &b"1"[..], 10), scan_decimal(b"1e10"));
+    }
+
+    #[test]
+    fn parse_f64_rounded_test() {
+        assert_eq!(Ok(2.68), parse_f64_rounded(b"2.675", 2));
+        assert_eq!(Ok(2.66), parse_f64_rounded(b"2.665", 2));
+        assert_eq!(Ok(2.67), parse_f64_rounded(b"2.6650001", 2));
+        assert_eq!(Ok(1.0), parse_f64_rounded(b"1.005", 2));
+        assert_eq!(Ok(100.0), parse_f64_rounded(b"99.995", 2));
+        assert_eq!(Ok(-100.0), parse_f64_rounded(b"-99.995", 2));
+        assert_eq!(Ok(2.5), parse_f64_rounded(b"2.5", 1));
+        assert_eq!(Ok(1234.5), parse_f64_rounded(b"1234.5", 3));
+        assert_eq!(Ok(1e300), parse_f64_rounded(b"1e300", 2));
+
+        // Round half to even.
+        assert_eq!(Ok(0.0), parse_f64_rounded(b"0.5", 0));
+        assert_eq!(Ok(2.0), parse_f64_rounded(b"1.5", 0));
+        assert_eq!(Ok(2.0), parse_f64_rounded(b"2.5", 0));
+        assert_eq!(Ok(3.0), parse_f64_rounded(b"2.50001", 0));
+        assert_eq!(Ok(1.0), parse_f64_rounded(b"0.51", 0));
+        assert_eq!(Ok(0.01), parse_f64_rounded(b"0.006", 2));
+        assert_eq!(Ok(0.0), parse_f64_rounded(b"0.005", 2));
+        assert_eq!(Ok(0.01), parse_f64_rounded(b"5.1e-3", 2));
+
+        // Values rounding to zero keep the sign.
+        assert_eq!(0x8000000000000000, parse_f64_rounded(b"-0.004", 2).unwrap().to_bits());
+        assert_eq!(0x8000000000000000, parse_f64_rounded(b"-1e-400", 2).unwrap().to_bits());
+        assert_eq!(0, parse_f64_rounded(b"0.000", 2).unwrap().to_bits());
+
+        // Special values and errors.
+        assert_eq!(Ok(f64::INFINITY), parse_f64_rounded(b"inf", 2));
+        assert!(parse_f64_rounded(b"NaN", 2).unwrap().is_nan());
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parse_f64_rounded(b"2.67x", 2));
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), parse_f64_rounded(b"", 2));
+    }
+
+    #[test]
+    fn parse_f32_rounded_test() {
+        assert_eq!(Ok(2.68), parse_f32_rounded(b"2.675", 2));
+        assert_eq!(Ok(0.124), parse_f32_rounded(b"0.1245", 3));
+        assert_eq!(Ok(0.126), parse_f32_rounded(b"0.1255", 3));
+        assert_eq!(Ok(-3.0), parse_f32_rounded(b"-2.5000001", 0));
+    }
+
+    #[test]
+    fn parse_f64_rounded_long_test() {
+        // Kept digits beyond the maximum digits are truncated to a sticky digit.
+        let mut nines = b"0.".to_vec();
+        nines.extend_from_slice(&[b'9'; 900]);
+        nines.push(b'5');
+        assert_eq!(Ok(1.0), parse_f64_rounded(&nines, 900));
+        assert_eq!(Ok(1.0), parse_f64_rounded(&nines, 899));
+
+        // Exact halfway between 1 and the next float, plus a tiny offset.
+        let halfway = b"1.00000000000000011102230246251565404236316680908203125";
+        assert_eq!(Ok(1.0), parse_f64_rounded(halfway, 1000));
+        let mut above = halfway.to_vec();
+        above.extend_from_slice(&[b'0'; 900]);
+        above.push(b'1');
+        assert_eq!(Ok(1.0000000000000002), parse_f64_rounded(&above, 1000));
+
+        // The rounded decimal has trailing zeros past the maximum digits
+        // for the slow path, which must not round like a non-zero digit.
+        assert_eq!(Ok(1.0), parse_f64_rounded(&above, 900));
+        above.pop();
+        above.push(b'6');
+        assert_eq!(Ok(1.0000000000000002), parse_f64_rounded(&above, 953));
+    }
+}
@@ -0,0 +1,189 @@
+//! Parse floats using caller-provided limbs for the slow path.
+
+use crate::util::*;
+use super::api::{atof_scratch, StringToFloat};
+
+// Parse the complete decimal string, using the scratch limbs for the slow path.
+perftools_inline!{
+fn parse_with_scratch<F: StringToFloat>(bytes: &[u8], scratch: &mut [u64])
+    -> Result<F>
+{
+    let index = | ptr | distance(bytes.as_ptr(), ptr);
+    match atof_scratch::<F>(bytes, NumberFormat::standard().unwrap(), scratch) {
+        Ok((_, ptr)) if index(ptr) != bytes.len()   => Err((ErrorCode::InvalidDigit, index(ptr)).into()),
+        Ok((Ok(value), _))                          => Ok(value),
+        Ok((Err(required), _))                      => Err((ErrorCode::ScratchTooSmall, required).into()),
+        Err((code, ptr))                            => Err((code, index(ptr)).into()),
+    }
+}}
+
+/// Parse `f32` from a decimal string, using caller-provided limbs for the slow path.
+///
+/// See [`parse_f64_with_scratch`]. At most 13 limbs are required
+/// for an `f32`.
+///
+/// * `bytes`   - Byte slice containing a decimal string.
+/// * `scratch` - Limbs for the big integers on the slow path.
+///
+/// [`parse_f64_with_scratch`]: fn.parse_f64_with_scratch.html
+#[inline]
+pub fn parse_f32_with_scratch(bytes: &[u8], scratch: &mut [u64])
+    -> Result<f32>
+{
+    parse_with_scratch::<f32>(bytes, scratch)
+}
+
+/// Parse `f64` from a decimal string, using caller-provided limbs for the slow path.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing, exactly like [`parse`]
+/// for an `f64`. Strings close to halfway between two floats require
+/// arbitrary-precision arithmetic to round correctly: rather than
+/// allocating, the big integers are stored in `scratch`, so the parser
+/// never allocates, even with the `radix` feature. The contents of
+/// `scratch` after parsing are unspecified.
+///
+/// If the slow path is required and `scratch` is too small, this
+/// returns an error with `ErrorCode::ScratchTooSmall`, and the index is
+/// the number of limbs required, rather than a position in the string.
+/// Most strings never reach the slow path, and parse with an empty
+/// scratch. At most 81 limbs are required for an `f64`, for strings
+/// with 768 significant digits near the smallest denormal float, so
+/// a `[u64; 81]` is always sufficient.
+///
+/// Only decimal strings in the standard number format use the scratch.
+/// Special values are parsed as usual.
+///
+/// * `bytes`   - Byte slice containing a decimal string.
+/// * `scratch` - Limbs for the big integers on the slow path.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ErrorCode;
+/// # pub fn main() {
+/// // Halfway between 1 and the next float, which rounds down to even.
+/// let halfway = b"1.00000000000000011102230246251565404236316680908203125";
+/// let mut scratch = [0u64; 81];
+/// assert_eq!(lexical_core::parse_f64_with_scratch(b"1.5", &mut []), Ok(1.5));
+/// assert_eq!(lexical_core::parse_f64_with_scratch(halfway, &mut scratch), Ok(1.0));
+///
+/// let error = lexical_core::parse_f64_with_scratch(halfway, &mut []).unwrap_err();
+/// assert_eq!(error.code, ErrorCode::ScratchTooSmall);
+/// assert!(error.index <= scratch.len());
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+#[inline]
+pub fn parse_f64_with_scratch(bytes: &[u8], scratch: &mut [u64])
+    -> Result<f64>
+{
+    parse_with_scratch::<f64>(bytes, scratch)
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Halfway between 0 and the smallest denormal `f64`, `2^-1075`.
+    const DENORMAL_HALFWAY: &str = "2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125";
+
+    /// Halfway between the largest `f64` and the next power of two.
+    const MAX_HALFWAY: &str = "1.79769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559699508093042880177904174497792";
+
+    fn required(bytes: &[u8]) -> usize {
+        let error = parse_f64_with_scratch(bytes, &mut []).unwrap_err();
+        assert_eq!(error.code, ErrorCode::ScratchTooSmall);
+        error.index
+    }
+
+    #[test]
+    fn parse_f64_with_scratch_test() {
+        // Never reach the slow path.
+        assert_eq!(Ok(1.5), parse_f64_with_scratch(b"1.5", &mut []));
+        assert_eq!(Ok(-0.1), parse_f64_with_scratch(b"-0.1", &mut []));
+        assert_eq!(Ok(1e300), parse_f64_with_scratch(b"1e300", &mut []));
+        assert_eq!(Ok(0.0), parse_f64_with_scratch(b"0e500", &mut []));
+        assert_eq!(Ok(f64::INFINITY), parse_f64_with_scratch(b"inf", &mut []));
+        assert_eq!(Ok(f64::INFINITY), parse_f64_with_scratch(b"1e400", &mut []));
+        assert!(parse_f64_with_scratch(b"NaN", &mut []).unwrap().is_nan());
+
+        // Errors match the generic parser.
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), parse_f64_with_scratch(b"", &mut []));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parse_f64_with_scratch(b"1x", &mut []));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), parse_f64_with_scratch(b"1e", &mut []));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parse_f64_with_scratch(b"e5", &mut []));
+    }
+
+    #[test]
+    fn parse_f64_with_scratch_slow_test() {
+        // Halfway between 1 and the next float, which rounds down to even.
+        let halfway = b"1.00000000000000011102230246251565404236316680908203125";
+        let above = b"1.000000000000000111022302462515654042363166809082031251";
+        let limbs = required(halfway);
+        let mut scratch = vec![0u64; limbs];
+        assert_eq!(Ok(1.0), parse_f64_with_scratch(halfway, &mut scratch));
+        assert_eq!(Ok(1.0000000000000002), parse_f64_with_scratch(above, &mut scratch));
+        assert_eq!(Ok(-1.0), parse_f64_with_scratch(b"-1.00000000000000011102230246251565404236316680908203125", &mut scratch));
+
+        // One limb short fails, and reports the same requirement.
+        let error = parse_f64_with_scratch(halfway, &mut scratch[1..]).unwrap_err();
+        assert_eq!((ErrorCode::ScratchTooSmall, limbs), (error.code, error.index));
+
+        // Invalid digits take precedence over the scratch size.
+        let trailing = b"1.00000000000000011102230246251565404236316680908203125x";
+        assert_eq!(Err((ErrorCode::InvalidDigit, 55).into()), parse_f64_with_scratch(trailing, &mut []));
+    }
+
+    #[test]
+    fn parse_f64_with_scratch_worst_case_test() {
+        let mut scratch = [0u64; 81];
+        let cases = [
+            (format!("{}e-324", DENORMAL_HALFWAY), 0.0),
+            (format!("{}1e-324", DENORMAL_HALFWAY), 5e-324),
+            (format!("{}e308", MAX_HALFWAY), f64::INFINITY),
+            (format!("{}e308", &MAX_HALFWAY[..MAX_HALFWAY.len()-1]), f64::MAX),
+        ];
+        for &(ref string, expected) in cases.iter() {
+            let bytes = string.as_bytes();
+            assert!(required(bytes) <= scratch.len());
+            assert_eq!(Ok(expected), parse_f64_with_scratch(bytes, &mut scratch));
+            assert_eq!(f64::from_lexical(bytes), parse_f64_with_scratch(bytes, &mut scratch));
+        }
+    }
+
+    #[test]
+    fn parse_f64_with_scratch_trailing_zeros_test() {
+        // Integer trailing zeros past the maximum digits are exact, and
+        // must not round like a non-zero digit.
+        let zeros = "0".repeat(720);
+        let cases = [
+            (format!("100000000000000011102230246251565404236316680908203125{}e-773", zeros), 1.0),
+            (format!("100000000000000011102230246251565404236316680908203126{}e-773", zeros), 1.0000000000000002),
+        ];
+        for &(ref string, expected) in cases.iter() {
+            let bytes = string.as_bytes();
+            let mut scratch = vec![0u64; required(bytes)];
+            assert_eq!(Ok(expected), parse_f64_with_scratch(bytes, &mut scratch));
+            assert_eq!(f64::from_lexical(bytes), parse_f64_with_scratch(bytes, &mut scratch));
+            assert_eq!(string.parse::<f64>().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn parse_f32_with_scratch_test() {
+        // Halfway between 1 and the next float, which rounds down to even.
+        let halfway = b"1.000000059604644775390625";
+        let error = parse_f32_with_scratch(halfway, &mut []).unwrap_err();
+        assert_eq!(ErrorCode::ScratchTooSmall, error.code);
+        assert!(error.index <= 13);
+        assert_eq!(Ok(1.0), parse_f32_with_scratch(halfway, &mut [0u64; 13]));
+        assert_eq!(Ok(1.0000001), parse_f32_with_scratch(b"1.0000000596046447753906251", &mut [0u64; 13]));
+        assert_eq!(Ok(1.5), parse_f32_with_scratch(b"1.5", &mut []));
+    }
+}
@@ -0,0 +1,167 @@
+//! Accepted spellings for the sign of a float.
+
+use crate::lib::str;
+
+// SIGN SPELLINGS
+
+/// Accepted spellings for the sign of a float, other than ASCII signs.
+///
+/// Scientific text often uses the Unicode minus sign `−` (U+2212), or
+/// other multi-byte sign characters, rather than the ASCII `-`. The
+/// sign spellings define the additional sequences recognized as a
+/// negative or positive sign at the start of the number. The ASCII
+/// signs are still accepted, as permitted by the number format. If
+/// multiple spellings match, the longest is used. At most
+/// `MAX_SPELLINGS` spellings are accepted for each sign.
+///
+/// The plus-minus sign `±` (U+00B1) does not determine a sign, so it
+/// is not accepted by default, and is an invalid digit. It may be
+/// accepted as either a positive or negative sign by adding it to the
+/// spellings.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{FloatParser, SignSpellings};
+/// # pub fn main() {
+/// let parser = FloatParser::new().signs(SignSpellings::unicode());
+/// assert_eq!(parser.parse_partial::<f64>("\u{2212}1.5".as_bytes()), Ok((-1.5, 6)));
+/// assert_eq!(parser.parse::<f64>(b"-1.5"), Ok(-1.5));
+/// assert!(parser.parse::<f64>("\u{B1}1.5".as_bytes()).is_err());
+///
+/// // Accept the plus-minus sign as a positive sign.
+/// const NEGATIVE: &[&[u8]] = &[b"\xE2\x88\x92"];
+/// const POSITIVE: &[&[u8]] = &[b"\xC2\xB1"];
+/// let parser = FloatParser::new().signs(SignSpellings::new(NEGATIVE, POSITIVE));
+/// assert_eq!(parser.parse::<f64>("\u{B1}1.5".as_bytes()), Ok(1.5));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignSpellings {
+    /// Accepted spellings for a negative sign.
+    negative: &'static [&'static [u8]],
+    /// Accepted spellings for a positive sign.
+    positive: &'static [&'static [u8]],
+}
+
+impl SignSpellings {
+    /// Maximum number of accepted spellings for each sign.
+    pub const MAX_SPELLINGS: usize = 8;
+
+    /// Create sign spellings from accepted spellings.
+    ///
+    /// * `negative`    - Accepted spellings for a negative sign.
+    /// * `positive`    - Accepted spellings for a positive sign.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any spelling is empty or is not valid UTF-8, or if
+    /// there are more than `MAX_SPELLINGS` spellings for either sign.
+    #[inline]
+    pub fn new(negative: &'static [&'static [u8]], positive: &'static [&'static [u8]])
+        -> SignSpellings
+    {
+        assert!(negative.len() <= Self::MAX_SPELLINGS && positive.len() <= Self::MAX_SPELLINGS);
+        assert!(negative.iter().chain(positive.iter()).all(|s| !s.is_empty() && str::from_utf8(s).is_ok()));
+        SignSpellings {
+            negative,
+            positive,
+        }
+    }
+
+    /// Accept the Unicode minus and plus sign variants.
+    ///
+    /// The negative signs are the minus sign `−` (U+2212), the small
+    /// hyphen-minus `﹣` (U+FE63) and the fullwidth hyphen-minus `－`
+    /// (U+FF0D). The positive signs are the small plus sign `﹢`
+    /// (U+FE62) and the fullwidth plus sign `＋` (U+FF0B).
+    #[inline]
+    pub fn unicode() -> SignSpellings {
+        SignSpellings::new(
+            &[b"\xE2\x88\x92", b"\xEF\xB9\xA3", b"\xEF\xBC\x8D"],
+            &[b"\xEF\xB9\xA2", b"\xEF\xBC\x8B"]
+        )
+    }
+
+    /// Get the accepted spellings for a negative sign.
+    #[inline]
+    pub fn get_negative(&self) -> &'static [&'static [u8]] {
+        self.negative
+    }
+
+    /// Get the accepted spellings for a positive sign.
+    #[inline]
+    pub fn get_positive(&self) -> &'static [&'static [u8]] {
+        self.positive
+    }
+
+    // Match the longest sign spelling at the start of the bytes.
+    //
+    // Returns if the sign is negative, and the length of the spelling.
+    perftools_inline!{
+    pub(super) fn parse_sign(&self, bytes: &[u8]) -> Option<(bool, usize)> {
+        let negative = self.negative.iter().map(|s| (true, s));
+        let positive = self.positive.iter().map(|s| (false, s));
+        negative.chain(positive)
+            .filter(|&(_, s)| bytes.starts_with(s))
+            .max_by_key(|&(_, s)| s.len())
+            .map(|(is_negative, s)| (is_negative, s.len()))
+    }}
+}
+
+impl Default for SignSpellings {
+    #[inline]
+    fn default() -> SignSpellings {
+        SignSpellings::unicode()
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_sign_test() {
+        let signs = SignSpellings::unicode();
+        assert_eq!(signs.parse_sign(b"\xE2\x88\x921"), Some((true, 3)));
+        assert_eq!(signs.parse_sign(b"\xEF\xBC\x8B1"), Some((false, 3)));
+        assert_eq!(signs.parse_sign(b"-1"), None);
+        assert_eq!(signs.parse_sign(b"\xC2\xB11"), None);
+        assert_eq!(signs.parse_sign(b"\xE2\x88"), None);
+        assert_eq!(signs.parse_sign(b""), None);
+
+        // Use the longest spelling.
+        let signs = SignSpellings::new(&[b"m", b"minus"], &[b"mi"]);
+        assert_eq!(signs.parse_sign(b"minus1"), Some((true, 5)));
+        assert_eq!(signs.parse_sign(b"mi1"), Some((false, 2)));
+        assert_eq!(signs.parse_sign(b"m1"), Some((true, 1)));
+
+        // Up to the maximum number of spellings.
+        const MAX: [&[u8]; SignSpellings::MAX_SPELLINGS] = [b"neg"; SignSpellings::MAX_SPELLINGS];
+        let signs = SignSpellings::new(&MAX, &[]);
+        assert_eq!(signs.parse_sign(b"neg1"), Some((true, 3)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_empty_test() {
+        SignSpellings::new(&[b""], &[]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_invalid_utf8_test() {
+        SignSpellings::new(&[b"\xE2\x88"], &[]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_too_many_test() {
+        const MANY: [&[u8]; SignSpellings::MAX_SPELLINGS + 1] = [b"pos"; SignSpellings::MAX_SPELLINGS + 1];
+        SignSpellings::new(&[], &MANY);
+    }
+}
@@ -0,0 +1,234 @@
+//! Accepted spellings for special (non-finite) float values.
+
+use crate::lib::str;
+
+// SPECIAL VALUES
+
+/// Accepted spellings for special (non-finite) float values.
+///
+/// Different formats spell infinity and NaN differently: JSON5 uses
+/// `Infinity` and `NaN`, while other formats use `inf`, `INF`, or do
+/// not allow special values at all. The special values define the
+/// spellings accepted for infinity and NaN, and if the match is
+/// case-sensitive. If multiple spellings match, the longest is used,
+/// so `"infinity"` is always fully consumed when both `inf` and
+/// `infinity` are accepted.
+///
+/// By default, the spellings `inf`, `infinity` and `nan` are accepted,
+/// case-insensitively. Any UTF-8 spelling may be used, such as the
+/// localized `Unendlich`, however, case-insensitive matching only
+/// ignores the case of ASCII letters. At most `MAX_SPELLINGS`
+/// spellings are accepted for infinity, and for NaN.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{FloatParser, SpecialValues};
+/// # pub fn main() {
+/// let parser = FloatParser::new().special(SpecialValues::json5());
+/// assert_eq!(parser.parse::<f64>(b"-Infinity"), Ok(std::f64::NEG_INFINITY));
+/// assert!(parser.parse::<f64>(b"inf").is_err());
+///
+/// const INFINITY: &[&[u8]] = &[b"INF"];
+/// const NAN: &[&[u8]] = &[b"NAN", b"NANQ"];
+/// let special = SpecialValues::new(INFINITY, NAN).case_sensitive(true);
+/// let parser = FloatParser::new().special(special);
+/// assert_eq!(parser.parse::<f64>(b"INF"), Ok(std::f64::INFINITY));
+/// assert!(parser.parse::<f64>(b"NANQ").unwrap().is_nan());
+/// assert!(parser.parse::<f64>(b"Inf").is_err());
+///
+/// // Localized spellings, which must include any English spellings
+/// // that are still accepted.
+/// const DE_INFINITY: &[&[u8]] = &[b"inf", b"infinity", b"Unendlich"];
+/// const DE_NAN: &[&[u8]] = &[b"nan", b"KeineZahl"];
+/// let parser = FloatParser::new().special(SpecialValues::new(DE_INFINITY, DE_NAN));
+/// assert_eq!(parser.parse::<f64>(b"-unendlich"), Ok(std::f64::NEG_INFINITY));
+/// assert_eq!(parser.parse::<f64>(b"inf"), Ok(std::f64::INFINITY));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpecialValues {
+    /// Accepted spellings for infinity.
+    infinity: &'static [&'static [u8]],
+    /// Accepted spellings for NaN.
+    nan: &'static [&'static [u8]],
+    /// Match the spellings case-sensitively.
+    case_sensitive: bool,
+    /// Require the special value to be a complete token.
+    complete_token: bool,
+}
+
+impl SpecialValues {
+    /// Maximum number of accepted spellings for infinity, and for NaN.
+    pub const MAX_SPELLINGS: usize = 8;
+
+    /// Create case-insensitive special values from accepted spellings.
+    ///
+    /// * `infinity`    - Accepted spellings for infinity.
+    /// * `nan`         - Accepted spellings for NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any spelling is empty or is not valid UTF-8, or if
+    /// there are more than `MAX_SPELLINGS` spellings for infinity or NaN.
+    #[inline]
+    pub fn new(infinity: &'static [&'static [u8]], nan: &'static [&'static [u8]])
+        -> SpecialValues
+    {
+        assert!(infinity.len() <= Self::MAX_SPELLINGS && nan.len() <= Self::MAX_SPELLINGS);
+        assert!(infinity.iter().chain(nan.iter()).all(|s| !s.is_empty() && str::from_utf8(s).is_ok()));
+        SpecialValues {
+            infinity,
+            nan,
+            case_sensitive: false,
+            complete_token: false,
+        }
+    }
+
+    /// Accept `inf`, `infinity` and `nan`, case-insensitively.
+    #[inline]
+    pub fn lenient() -> SpecialValues {
+        SpecialValues::new(&[b"inf", b"infinity"], &[b"nan"])
+    }
+
+    /// Accept only the spellings written by default, `inf` and `NaN`.
+    #[inline]
+    pub fn strict() -> SpecialValues {
+        SpecialValues::new(&[b"inf"], &[b"NaN"]).case_sensitive(true)
+    }
+
+    /// Accept no special values, as required by JSON.
+    #[inline]
+    pub fn json() -> SpecialValues {
+        SpecialValues::new(&[], &[])
+    }
+
+    /// Accept `Infinity` and `NaN`, case-sensitively, as required by JSON5.
+    #[inline]
+    pub fn json5() -> SpecialValues {
+        SpecialValues::new(&[b"Infinity"], &[b"NaN"]).case_sensitive(true)
+    }
+
+    /// Set whether the spellings are matched case-sensitively.
+    ///
+    /// * `case_sensitive`  - Match the spellings case-sensitively.
+    #[inline]
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> SpecialValues {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Set whether the special value must be a complete token.
+    ///
+    /// By default, the longest matching spelling is parsed, and any
+    /// trailing bytes are left unparsed, so partial parsers parse
+    /// `"infinityx"` as infinity, with 8 processed bytes, and `"nan123"`
+    /// as NaN, with 3 processed bytes. If set, a special value followed
+    /// by an ASCII letter, digit or underscore returns an error with
+    /// `ErrorCode::InvalidDigit` at the trailing byte, while any other
+    /// trailing byte, such as a delimiter, is still left unparsed. Complete
+    /// parsers reject trailing bytes either way.
+    ///
+    /// * `complete_token`  - Require the special value to be a complete token.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// # use lexical_core::{ErrorCode, FloatParser, SpecialValues};
+    /// # pub fn main() {
+    /// let parser = FloatParser::new();
+    /// assert_eq!(parser.parse_partial::<f64>(b"infinityx"), Ok((std::f64::INFINITY, 8)));
+    ///
+    /// let parser = FloatParser::new().special(SpecialValues::lenient().complete_token(true));
+    /// assert_eq!(parser.parse_partial::<f64>(b"infinity,"), Ok((std::f64::INFINITY, 8)));
+    /// assert_eq!(parser.parse_partial::<f64>(b"infinityx").unwrap_err().code, ErrorCode::InvalidDigit);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn complete_token(mut self, complete_token: bool) -> SpecialValues {
+        self.complete_token = complete_token;
+        self
+    }
+
+    /// Get the accepted spellings for infinity.
+    #[inline]
+    pub fn get_infinity(&self) -> &'static [&'static [u8]] {
+        self.infinity
+    }
+
+    /// Get the accepted spellings for NaN.
+    #[inline]
+    pub fn get_nan(&self) -> &'static [&'static [u8]] {
+        self.nan
+    }
+
+    /// Get if the spellings are matched case-sensitively.
+    #[inline]
+    pub fn get_case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+
+    /// Get if the special value must be a complete token.
+    #[inline]
+    pub fn get_complete_token(&self) -> bool {
+        self.complete_token
+    }
+}
+
+impl Default for SpecialValues {
+    #[inline]
+    fn default() -> SpecialValues {
+        SpecialValues::lenient()
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_test() {
+        assert_eq!(SpecialValues::default(), SpecialValues::lenient());
+        assert!(!SpecialValues::lenient().get_case_sensitive());
+        assert!(SpecialValues::strict().get_case_sensitive());
+        assert!(SpecialValues::json().get_infinity().is_empty());
+        assert!(SpecialValues::json().get_nan().is_empty());
+        assert_eq!(SpecialValues::json5().get_infinity(), &[b"Infinity"]);
+        assert!(!SpecialValues::lenient().get_complete_token());
+        assert!(SpecialValues::strict().complete_token(true).get_complete_token());
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_empty_test() {
+        SpecialValues::new(&[b""], &[b"nan"]);
+    }
+
+    #[test]
+    fn new_localized_test() {
+        let special = SpecialValues::new(&[b"Unendlich", b"\xE2\x88\x9E"], &[b"KeineZahl"]);
+        assert_eq!(special.get_infinity(), &[&b"Unendlich"[..], b"\xE2\x88\x9E"]);
+        assert_eq!(special.get_nan(), &[b"KeineZahl"]);
+
+        const MAX: [&[u8]; SpecialValues::MAX_SPELLINGS] = [b"inf"; SpecialValues::MAX_SPELLINGS];
+        assert_eq!(SpecialValues::new(&MAX, &MAX).get_infinity().len(), SpecialValues::MAX_SPELLINGS);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_invalid_utf8_test() {
+        SpecialValues::new(&[b"\xE2\x88"], &[b"nan"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_too_many_test() {
+        const TOO_MANY: [&[u8]; SpecialValues::MAX_SPELLINGS + 1] = [b"inf"; SpecialValues::MAX_SPELLINGS + 1];
+        SpecialValues::new(&TOO_MANY, &[b"nan"]);
+    }
+}
@@ -0,0 +1,90 @@
+//! Parse the integer portion of float strings.
+
+use crate::util::*;
+use super::algorithm::truncate::truncated_int;
+
+// Parse the integer portion of a float string, truncated toward zero.
+perftools_inline!{
+pub(crate) fn parse_truncated(bytes: &[u8], radix: u32) -> Result<(i64, usize)> {
+    let format = NumberFormat::standard().unwrap();
+    let (sign, digits) = parse_sign::<i64>(bytes, format);
+    let index = | ptr | distance(bytes.as_ptr(), ptr);
+    if digits.is_empty() {
+        return Err((ErrorCode::Empty, index(digits.as_ptr())).into());
+    }
+
+    let max = match sign {
+        Sign::Positive => i64::max_value() as u64,
+        Sign::Negative => i64::min_value().wrapping_abs() as u64,
+    };
+    match truncated_int(digits, radix, max, format) {
+        Ok((Some(value), ptr))  => match sign {
+            Sign::Positive => Ok((value as i64, index(ptr))),
+            Sign::Negative => Ok(((value as i64).wrapping_neg(), index(ptr))),
+        },
+        Ok((None, _))           => match sign {
+            Sign::Positive => Err((ErrorCode::Overflow, 0).into()),
+            Sign::Negative => Err((ErrorCode::Underflow, 0).into()),
+        },
+        Err((code, ptr))        => Err((code, index(ptr)).into()),
+    }
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_truncated_test() {
+        assert_eq!(Ok((3, 4)), parse_truncated(b"3.99", 10));
+        assert_eq!(Ok((-3, 5)), parse_truncated(b"-3.99", 10));
+        assert_eq!(Ok((3, 2)), parse_truncated(b"+3", 10));
+        assert_eq!(Ok((0, 4)), parse_truncated(b"0.99", 10));
+        assert_eq!(Ok((0, 5)), parse_truncated(b"-0.99", 10));
+        assert_eq!(Ok((0, 2)), parse_truncated(b".5", 10));
+        assert_eq!(Ok((5, 2)), parse_truncated(b"5.", 10));
+        assert_eq!(Ok((3, 4)), parse_truncated(b"3.99x", 10));
+
+        // Exponents within the digits.
+        assert_eq!(Ok((399, 6)), parse_truncated(b"3.99e2", 10));
+        assert_eq!(Ok((39, 6)), parse_truncated(b"3.99e1", 10));
+        assert_eq!(Ok((-39, 7)), parse_truncated(b"-3.99e1", 10));
+        assert_eq!(Ok((3, 6)), parse_truncated(b"399e-2", 10));
+        assert_eq!(Ok((0, 6)), parse_truncated(b"399e-3", 10));
+        assert_eq!(Ok((0, 8)), parse_truncated(b"399e-999", 10));
+
+        // Exponents larger than the fraction.
+        assert_eq!(Ok((39900, 6)), parse_truncated(b"3.99e4", 10));
+        assert_eq!(Ok((-3000, 5)), parse_truncated(b"-3e+3", 10));
+        assert_eq!(Ok((0, 8)), parse_truncated(b"0.0e9999", 10));
+        assert_eq!(Ok((0, 22)), parse_truncated(b"0e99999999999999999999", 10));
+
+        // Limits.
+        assert_eq!(Ok((i64::max_value(), 19)), parse_truncated(b"9223372036854775807", 10));
+        assert_eq!(Ok((i64::max_value(), 21)), parse_truncated(b"9223372036854775807.9", 10));
+        assert_eq!(Ok((i64::min_value(), 20)), parse_truncated(b"-9223372036854775808", 10));
+        assert_eq!(Ok((i64::min_value(), 24)), parse_truncated(b"-9.223372036854775808e18", 10));
+        assert_eq!(Err((ErrorCode::Overflow, 0).into()), parse_truncated(b"9223372036854775808", 10));
+        assert_eq!(Err((ErrorCode::Overflow, 0).into()), parse_truncated(b"1e19", 10));
+        assert_eq!(Err((ErrorCode::Overflow, 0).into()), parse_truncated(b"1e99999999999999999999", 10));
+        assert_eq!(Err((ErrorCode::Underflow, 0).into()), parse_truncated(b"-9223372036854775809", 10));
+
+        // Errors.
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), parse_truncated(b"", 10));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), parse_truncated(b"-", 10));
+        assert_eq!(Err((ErrorCode::LoneDot, 0).into()), parse_truncated(b".", 10));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), parse_truncated(b"3e", 10));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parse_truncated(b"inf", 10));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn parse_truncated_radix_test() {
+        assert_eq!(Ok((5, 5)), parse_truncated(b"101.1", 2));
+        assert_eq!(Ok((11, 7)), parse_truncated(b"101.1e1", 2));
+        assert_eq!(Ok((1234, 4)), parse_truncated(b"YA.Z", 36));
+    }
+}
@@ -1,5 +1,6 @@
 //! Fast lexical string-to-integer conversion routines.
 
+use crate::lib::num;
 use crate::util::*;
 use super::generic::*;
 
@@ -101,6 +102,59 @@ pub(crate) fn atoi_format<'a, T>(bytes: &'a [u8], radix: u32, format: NumberForm
     }
 }}
 
+// NON-ZERO
+// --------
+
+pub(crate) trait NonZero: Sized {
+    type Integer: Atoi;
+
+    // Create non-zero integer, if the value is not zero.
+    fn new(value: Self::Integer) -> Option<Self>;
+}
+
+// Implement NonZero for type.
+macro_rules! nonzero_impl {
+    ($($t:ident $i:ty ;)*) => ($(
+        impl NonZero for num::$t {
+            type Integer = $i;
+
+            perftools_inline_always!{
+            fn new(value: $i) -> Option<num::$t> {
+                num::$t::new(value)
+            }}
+        }
+    )*);
+}
+
+nonzero_impl! {
+    NonZeroU8 u8 ;
+    NonZeroU16 u16 ;
+    NonZeroU32 u32 ;
+    NonZeroU64 u64 ;
+    NonZeroUsize usize ;
+    NonZeroU128 u128 ;
+    NonZeroI8 i8 ;
+    NonZeroI16 i16 ;
+    NonZeroI32 i32 ;
+    NonZeroI64 i64 ;
+    NonZeroIsize isize ;
+    NonZeroI128 i128 ;
+}
+
+// Parse non-zero integer from string, failing with `ErrorCode::Zero`
+// at the start of the string if the value is zero.
+perftools_inline!{
+pub(crate) fn atoi_nonzero<T>(bytes: &[u8], radix: u32)
+    -> Result<(T, usize)>
+    where T: NonZero
+{
+    let (value, processed) = atoi::<T::Integer>(bytes, radix)?;
+    match T::new(value) {
+        Some(value) => Ok((value, processed)),
+        None        => Err((ErrorCode::Zero, 0).into()),
+    }
+}}
+
 // FROM LEXICAL
 // ------------
 
@@ -118,6 +172,20 @@ from_lexical!(atoi, i64);
 from_lexical!(atoi, isize);
 from_lexical!(atoi, i128);
 
+from_lexical!(FromLexicalNonZero, atoi_nonzero, num::NonZeroU8);
+from_lexical!(FromLexicalNonZero, atoi_nonzero, num::NonZeroU16);
+from_lexical!(FromLexicalNonZero, atoi_nonzero, num::NonZeroU32);
+from_lexical!(FromLexicalNonZero, atoi_nonzero, num::NonZeroU64);
+from_lexical!(FromLexicalNonZero, atoi_nonzero, num::NonZeroUsize);
+from_lexical!(FromLexicalNonZero, atoi_nonzero, num::NonZeroU128);
+
+from_lexical!(FromLexicalNonZero, atoi_nonzero, num::NonZeroI8);
+from_lexical!(FromLexicalNonZero, atoi_nonzero, num::NonZeroI16);
+from_lexical!(FromLexicalNonZero, atoi_nonzero, num::NonZeroI32);
+from_lexical!(FromLexicalNonZero, atoi_nonzero, num::NonZeroI64);
+from_lexical!(FromLexicalNonZero, atoi_nonzero, num::NonZeroIsize);
+from_lexical!(FromLexicalNonZero, atoi_nonzero, num::NonZeroI128);
+
 cfg_if!{
 if #[cfg(feature = "format")] {
     from_lexical_format!(atoi_format, u8);
@@ -217,6 +285,14 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "radix")]
+    #[test]
+    fn i8_invalid_radix_test() {
+        assert_eq!(Err(ErrorCode::InvalidRadix.into()), i8::from_lexical_radix(b"1", 0));
+        assert_eq!(Err(ErrorCode::InvalidRadix.into()), i8::from_lexical_partial_radix(b"1", 1));
+        assert_eq!(Err(ErrorCode::InvalidRadix.into()), i8::from_lexical_radix(b"1", 37));
+    }
+
     #[test]
     fn u16_decimal_test() {
         assert_eq!(Ok(0), u16::from_lexical(b"0"));
@@ -256,6 +332,29 @@ mod tests {
         assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), u32::from_lexical(b"1a"));
     }
 
+    #[test]
+    fn u32_overflow_index_test() {
+        // The index is the digit at which the value first overflowed.
+        let overflow = | index | -> Result<u32> { Err((ErrorCode::Overflow, index).into()) };
+        assert_eq!(Ok(4294967295), u32::from_lexical(b"4294967295"));
+        assert_eq!(overflow(9), u32::from_lexical(b"4294967296"));
+        assert_eq!(overflow(9), u32::from_lexical(b"4294967300"));
+        assert_eq!(Ok(429496729), u32::from_lexical(b"429496729"));
+        assert_eq!(overflow(10), u32::from_lexical(b"42949672950"));
+        assert_eq!(overflow(10), u32::from_lexical(b"+4294967296"));
+        assert_eq!(overflow(11), u32::from_lexical(b"004294967296"));
+        assert_eq!(overflow(9), u32::from_lexical(b"99999999999999"));
+        assert_eq!(overflow(9), u32::from_lexical_partial(b"4294967296,1").map(|(v, _)| v));
+
+        // Overflow takes precedence over later invalid digits.
+        assert_eq!(overflow(9), u32::from_lexical(b"4294967296x"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 9).into()), u32::from_lexical(b"429496729x0"));
+
+        // Signed integers report the index of underflow.
+        assert_eq!(Ok(-2147483648), i32::from_lexical(b"-2147483648"));
+        assert_eq!(Err((ErrorCode::Underflow, 10).into()), i32::from_lexical(b"-2147483649"));
+    }
+
     #[test]
     fn i32_decimal_test() {
         assert_eq!(Ok(0), i32::from_lexical(b"0"));
@@ -266,6 +365,31 @@ mod tests {
         assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i32::from_lexical(b"1a"));
     }
 
+    #[test]
+    fn i32_sign_sequence_test() {
+        // A single sign is valid.
+        assert_eq!(Ok(5), i32::from_lexical(b"+5"));
+        assert_eq!(Ok(-5), i32::from_lexical(b"-5"));
+        assert_eq!(Ok(5), u32::from_lexical(b"+5"));
+
+        // A second sign is an error at the second sign.
+        let err = | index | -> Result<i32> { Err((ErrorCode::InvalidSign, index).into()) };
+        assert_eq!(err(1), i32::from_lexical(b"--5"));
+        assert_eq!(err(1), i32::from_lexical(b"++5"));
+        assert_eq!(err(1), i32::from_lexical(b"+-5"));
+        assert_eq!(err(1), i32::from_lexical(b"-+5"));
+        assert_eq!(Err((ErrorCode::InvalidSign, 1).into()), i32::from_lexical_partial(b"-+5"));
+        assert_eq!(Err((ErrorCode::InvalidSign, 1).into()), u32::from_lexical(b"+-5"));
+        assert_eq!(Err((ErrorCode::InvalidSign, 1).into()), i128::from_lexical(b"--5"));
+
+        // A negative sign is an invalid digit for unsigned integers.
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), u32::from_lexical(b"-+5"));
+
+        // A lone sign has no digits.
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), i32::from_lexical(b"-"));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), i32::from_lexical(b"+"));
+    }
+
     #[test]
     fn u64_decimal_test() {
         assert_eq!(Ok(0), u64::from_lexical(b"0"));
@@ -309,6 +433,35 @@ mod tests {
         assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i128::from_lexical(b"1a"));
     }
 
+    #[test]
+    fn nonzero_decimal_test() {
+        use crate::lib::num::*;
+
+        assert_eq!(Ok(NonZeroU64::new(5).unwrap()), NonZeroU64::from_lexical(b"5"));
+        assert_eq!(Err((ErrorCode::Zero, 0).into()), NonZeroU64::from_lexical(b"0"));
+        assert_eq!(Err((ErrorCode::Zero, 0).into()), NonZeroU64::from_lexical(b"000"));
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), NonZeroU64::from_lexical(b""));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), NonZeroU64::from_lexical(b"1a"));
+        assert_eq!(Ok((NonZeroU64::new(12).unwrap(), 2)), NonZeroU64::from_lexical_partial(b"12,"));
+        assert_eq!(Err((ErrorCode::Zero, 0).into()), NonZeroU64::from_lexical_partial(b"0,"));
+
+        assert_eq!(Ok(NonZeroU8::new(255).unwrap()), NonZeroU8::from_lexical(b"255"));
+        assert_eq!(Err((ErrorCode::Overflow, 2).into()), NonZeroU8::from_lexical(b"256"));
+        assert_eq!(Ok(NonZeroI32::new(-7).unwrap()), NonZeroI32::from_lexical(b"-7"));
+        assert_eq!(Err((ErrorCode::Zero, 0).into()), NonZeroI32::from_lexical(b"-0"));
+        assert_eq!(Ok(NonZeroUsize::new(1).unwrap()), NonZeroUsize::from_lexical(b"1"));
+        assert_eq!(Ok(NonZeroI128::new(i128::min_value()).unwrap()), NonZeroI128::from_lexical(b"-170141183460469231731687303715884105728"));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn nonzero_radix_test() {
+        use crate::lib::num::*;
+
+        assert_eq!(Ok(NonZeroU32::new(37).unwrap()), NonZeroU32::from_lexical_radix(b"100101", 2));
+        assert_eq!(Err((ErrorCode::Zero, 0).into()), NonZeroU32::from_lexical_radix(b"0", 36));
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn i32_no_leading_zeros_test() {
@@ -400,7 +553,7 @@ mod tests {
             let result = u8::from_lexical(i.as_bytes());
             prop_assert!(result.is_err());
             let error = result.err().unwrap();
-            prop_assert_eq!(error.code, ErrorCode::InvalidDigit);
+            prop_assert_eq!(error.code, ErrorCode::InvalidSign);
             prop_assert!(error.index == 1);
         }
 
@@ -448,7 +601,7 @@ mod tests {
         fn i8_double_sign_proptest(i in r"[+-]{2}[0-9]{2}") {
             let result = i8::from_lexical(i.as_bytes());
             let error = result.err().unwrap();
-            prop_assert_eq!(error.code, ErrorCode::InvalidDigit);
+            prop_assert_eq!(error.code, ErrorCode::InvalidSign);
             prop_assert!(error.index == 1);
         }
 
@@ -495,7 +648,7 @@ mod tests {
             let result = u16::from_lexical(i.as_bytes());
             prop_assert!(result.is_err());
             let error = result.err().unwrap();
-            prop_assert_eq!(error.code, ErrorCode::InvalidDigit);
+            prop_assert_eq!(error.code, ErrorCode::InvalidSign);
             prop_assert!(error.index == 1);
         }
 
@@ -543,7 +696,7 @@ mod tests {
         fn i16_double_sign_proptest(i in r"[+-]{2}[0-9]{4}") {
             let result = i16::from_lexical(i.as_bytes());
             let error = result.err().unwrap();
-            prop_assert_eq!(error.code, ErrorCode::InvalidDigit);
+            prop_assert_eq!(error.code, ErrorCode::InvalidSign);
             prop_assert!(error.index == 1);
         }
 
@@ -591,7 +744,7 @@ mod tests {
             let result = u32::from_lexical(i.as_bytes());
             prop_assert!(result.is_err());
             let error = result.err().unwrap();
-            prop_assert_eq!(error.code, ErrorCode::InvalidDigit);
+            prop_assert_eq!(error.code, ErrorCode::InvalidSign);
             prop_assert!(error.index == 1);
         }
 
@@ -639,7 +792,7 @@ mod tests {
         fn i32_double_sign_proptest(i in r"[+-]{2}[0-9]{9}") {
             let result = i32::from_lexical(i.as_bytes());
             let error = result.err().unwrap();
-            prop_assert_eq!(error.code, ErrorCode::InvalidDigit);
+            prop_assert_eq!(error.code, ErrorCode::InvalidSign);
             prop_assert!(error.index == 1);
         }
 
@@ -687,7 +840,7 @@ mod tests {
             let result = u64::from_lexical(i.as_bytes());
             prop_assert!(result.is_err());
             let error = result.err().unwrap();
-            prop_assert_eq!(error.code, ErrorCode::InvalidDigit);
+            prop_assert_eq!(error.code, ErrorCode::InvalidSign);
             prop_assert!(error.index == 1);
         }
 
@@ -735,7 +888,7 @@ mod tests {
         fn i64_double_sign_proptest(i in r"[+-]{2}[0-9]{18}") {
             let result = i64::from_lexical(i.as_bytes());
             let error = result.err().unwrap();
-            prop_assert_eq!(error.code, ErrorCode::InvalidDigit);
+            prop_assert_eq!(error.code, ErrorCode::InvalidSign);
             prop_assert!(error.index == 1);
         }
 
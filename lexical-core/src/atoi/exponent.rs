@@ -50,14 +50,14 @@ macro_rules! parse_digits_exponent {
 // returns a default min or max value on overflow.
 perftools_inline!{
 pub(crate) fn standalone_exponent<'a, Iter>(mut iter: Iter, radix: u32, sign: Sign)
-    -> (i32, *const u8)
+    -> (i64, *const u8)
     where Iter: AsPtrIterator<'a, u8>
 {
     // Parse the sign bit or current data.
-    let mut value = 0;
+    let mut value: i64 = 0;
     match sign {
-        Sign::Positive => parse_digits_exponent!(value, iter, radix, checked_add, i32::max_value()),
-        Sign::Negative => parse_digits_exponent!(value, iter, radix, checked_sub, i32::min_value())
+        Sign::Positive => parse_digits_exponent!(value, iter, radix, checked_add, i64::max_value()),
+        Sign::Negative => parse_digits_exponent!(value, iter, radix, checked_sub, i64::min_value())
     }
 
     (value, iter.as_ptr())
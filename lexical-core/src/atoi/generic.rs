@@ -177,6 +177,7 @@ fn standalone_iltc<T>(bytes: &[u8], radix: u32, digit_separator: u8)
     if digits.is_empty() {
         return Err((ErrorCode::Empty, digits.as_ptr()));
     }
+    validate_sign_sequence(bytes, digits)?;
     let iter = iterate_digits_ignore_separator(digits, digit_separator);
     parse_digits(digits, iter, radix, sign)
 }}
@@ -205,6 +206,7 @@ macro_rules! standalone_atoi_separator {
             if digits.is_empty() {
                 return Err((ErrorCode::Empty, digits.as_ptr()));
             }
+            validate_sign_sequence(bytes, digits)?;
 
             // Extract the integer subslice, then parse.
             let leading = $consume(digits, radix, digit_separator).0;
@@ -499,6 +501,7 @@ fn standalone_128_iltc<W, N>(bytes: &[u8], radix: u32, digit_separator: u8)
     if digits.is_empty() {
         return Err((ErrorCode::Empty, digits.as_ptr()));
     }
+    validate_sign_sequence(bytes, digits)?;
     let iter = iterate_digits_ignore_separator(digits, digit_separator);
     parse_digits_128::<W, N, _>(digits, iter, radix, sign)
 }}
@@ -528,6 +531,7 @@ macro_rules! standalone_atoi_128_separator {
             if digits.is_empty() {
                 return Err((ErrorCode::Empty, digits.as_ptr()));
             }
+            validate_sign_sequence(bytes, digits)?;
 
             // Extract the integer subslice, then parse.
             let leading = $consume(digits, radix, digit_separator).0;
@@ -0,0 +1,71 @@
+//! Parse integers with grouped digit separators.
+
+use crate::util::*;
+
+// Parse integer from string, with digit separators between groups of
+// integer digits, validating the groups like the float parser.
+perftools_inline!{
+pub(crate) fn parse_grouped<T>(bytes: &[u8], digit_separator: u8, group_size: u8)
+    -> Result<T>
+    where T: Integer + FromLexicalFormat
+{
+    let format = NumberFormat::grouped(digit_separator, group_size)
+        .expect("Invalid digit separator or group size.");
+    let value = T::from_lexical_format(bytes, format)?;
+
+    // The digits are valid, so only the sign may precede them.
+    let digits = match bytes.first() {
+        Some(&b'+') | Some(&b'-')   => &bytes[1..],
+        _                           => bytes,
+    };
+    match format.validate_grouping(digits) {
+        Ok(())          => Ok(value),
+        Err((code, p))  => Err((code, distance(bytes.as_ptr(), p)).into()),
+    }
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_grouped_test() {
+        let grouping = | index | Error::from((ErrorCode::InvalidDigitGrouping, index));
+        assert_eq!(Ok(1234567), parse_grouped::<u64>(b"1,234,567", b',', 3));
+        assert_eq!(Ok(1234567), parse_grouped::<u64>(b"1234567", b',', 3));
+        assert_eq!(Ok(123), parse_grouped::<u64>(b"123", b',', 3));
+        assert_eq!(Ok(12345678), parse_grouped::<u64>(b"1234_5678", b'_', 4));
+        assert_eq!(Ok(u64::max_value()), parse_grouped::<u64>(b"18,446,744,073,709,551,615", b',', 3));
+
+        // Invalid group sizes.
+        assert_eq!(Err(grouping(0)), parse_grouped::<u64>(b"1234,567", b',', 3));
+        assert_eq!(Err(grouping(2)), parse_grouped::<u64>(b"1,23,456", b',', 3));
+        assert_eq!(Err(grouping(6)), parse_grouped::<u64>(b"1,234,5678", b',', 3));
+        assert_eq!(Err(grouping(6)), parse_grouped::<u64>(b"1,234,56", b',', 3));
+
+        // Leading, trailing and consecutive separators.
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), parse_grouped::<u64>(b",123", b',', 3));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), parse_grouped::<u64>(b"123,", b',', 3));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parse_grouped::<u64>(b"1,,234", b',', 3));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parse_grouped::<u64>(b"1.234", b',', 3));
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), parse_grouped::<u64>(b"", b',', 3));
+        assert_eq!(Err((ErrorCode::Overflow, 25).into()), parse_grouped::<u64>(b"18,446,744,073,709,551,616", b',', 3));
+
+        // Signs precede the first group.
+        assert_eq!(Ok(1000), parse_grouped::<u64>(b"+1,000", b',', 3));
+        assert_eq!(Ok(-1000), parse_grouped::<i64>(b"-1,000", b',', 3));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), parse_grouped::<u64>(b"-1,000", b',', 3));
+        assert_eq!(Err(grouping(1)), parse_grouped::<i64>(b"-1000,000", b',', 3));
+        assert_eq!(Err(grouping(3)), parse_grouped::<i64>(b"-1,00", b',', 3));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parse_grouped::<i64>(b"-,100", b',', 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn parse_grouped_invalid_separator_test() {
+        let _ = parse_grouped::<u64>(b"1", b'1', 3);
+    }
+}
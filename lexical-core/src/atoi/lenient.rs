@@ -0,0 +1,62 @@
+//! Parse integers, ignoring a fraction or exponent tail.
+
+use crate::util::*;
+
+// Parse the integer prefix of a string, and consume a float tail.
+//
+// The tail is only consumed if it is a valid fraction or exponent,
+// which extends the number parsed as a float. Returns the integer,
+// the number of processed bytes, and if a tail was ignored.
+perftools_inline!{
+pub(crate) fn parse_lenient(bytes: &[u8]) -> Result<(i64, usize, bool)> {
+    let (value, processed) = i64::from_lexical_partial(bytes)?;
+    let exponent_char = get_exponent_default_char().to_ascii_lowercase();
+    let has_digits = processed > 0 && bytes[processed - 1].is_ascii_digit();
+    let has_tail = match bytes.get(processed) {
+        Some(&c) => has_digits && (c == b'.' || c.to_ascii_lowercase() == exponent_char),
+        None     => false,
+    };
+    if has_tail {
+        if let Ok((_, float_processed)) = f64::from_lexical_partial(bytes) {
+            if float_processed > processed {
+                return Ok((value, float_processed, true));
+            }
+        }
+    }
+    Ok((value, processed, false))
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_lenient_test() {
+        assert_eq!(Ok((42, 2, false)), parse_lenient(b"42"));
+        assert_eq!(Ok((42, 4, true)), parse_lenient(b"42.0"));
+        assert_eq!(Ok((42, 5, true)), parse_lenient(b"42.99"));
+        assert_eq!(Ok((42, 4, true)), parse_lenient(b"42e3"));
+        assert_eq!(Ok((42, 4, true)), parse_lenient(b"42E3"));
+        assert_eq!(Ok((42, 7, true)), parse_lenient(b"42.5e-3"));
+        assert_eq!(Ok((-42, 5, true)), parse_lenient(b"-42.0"));
+        assert_eq!(Ok((42, 3, true)), parse_lenient(b"42."));
+        assert_eq!(Ok((42, 4, true)), parse_lenient(b"42.0,7"));
+
+        // Invalid tails are not consumed.
+        assert_eq!(Ok((42, 2, false)), parse_lenient(b"42e"));
+        assert_eq!(Ok((42, 2, false)), parse_lenient(b"42e+"));
+        assert_eq!(Ok((42, 2, false)), parse_lenient(b"42x"));
+        assert_eq!(Ok((42, 2, false)), parse_lenient(b"42,0"));
+
+        // A tail requires integer digits.
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), parse_lenient(b""));
+        assert_eq!(Ok((0, 0, false)), parse_lenient(b".5"));
+        assert_eq!(Ok((0, 1, false)), parse_lenient(b"-.5"));
+
+        // Errors in the integer prefix.
+        assert_eq!(Err((ErrorCode::Overflow, 18).into()), parse_lenient(b"9223372036854775808.0"));
+    }
+}
@@ -0,0 +1,242 @@
+//! Parse Rust integer literals, with a base prefix and type suffix.
+
+use crate::util::*;
+
+// BASE
+
+/// Base of an integer literal, from the literal prefix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Base {
+    /// Binary literal, with the `0b` prefix.
+    Binary,
+    /// Octal literal, with the `0o` prefix.
+    Octal,
+    /// Decimal literal, without a prefix.
+    Decimal,
+    /// Hexadecimal literal, with the `0x` prefix.
+    Hexadecimal,
+}
+
+impl Base {
+    /// Get the radix of the digits.
+    #[inline]
+    pub fn radix(self) -> u32 {
+        match self {
+            Base::Binary      => 2,
+            Base::Octal       => 8,
+            Base::Decimal     => 10,
+            Base::Hexadecimal => 16,
+        }
+    }
+
+    // Get the base and the length of the prefix.
+    perftools_inline!{
+    fn from_prefix(bytes: &[u8]) -> (Base, usize) {
+        match bytes.get(..2) {
+            Some(b"0b") => (Base::Binary, 2),
+            Some(b"0o") => (Base::Octal, 2),
+            Some(b"0x") => (Base::Hexadecimal, 2),
+            _           => (Base::Decimal, 0),
+        }
+    }}
+}
+
+// TYPE SUFFIX
+
+/// Integer type suffix of an integer literal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TypeSuffix {
+    /// The `u8` suffix.
+    U8,
+    /// The `u16` suffix.
+    U16,
+    /// The `u32` suffix.
+    U32,
+    /// The `u64` suffix.
+    U64,
+    /// The `u128` suffix.
+    U128,
+    /// The `usize` suffix.
+    Usize,
+    /// The `i8` suffix.
+    I8,
+    /// The `i16` suffix.
+    I16,
+    /// The `i32` suffix.
+    I32,
+    /// The `i64` suffix.
+    I64,
+    /// The `i128` suffix.
+    I128,
+    /// The `isize` suffix.
+    Isize,
+}
+
+impl TypeSuffix {
+    /// Get the type suffix from the suffix characters.
+    ///
+    /// * `bytes`   - Byte slice containing exactly the suffix, like `b"u8"`.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Option<TypeSuffix> {
+        match bytes {
+            b"u8"    => Some(TypeSuffix::U8),
+            b"u16"   => Some(TypeSuffix::U16),
+            b"u32"   => Some(TypeSuffix::U32),
+            b"u64"   => Some(TypeSuffix::U64),
+            b"u128"  => Some(TypeSuffix::U128),
+            b"usize" => Some(TypeSuffix::Usize),
+            b"i8"    => Some(TypeSuffix::I8),
+            b"i16"   => Some(TypeSuffix::I16),
+            b"i32"   => Some(TypeSuffix::I32),
+            b"i64"   => Some(TypeSuffix::I64),
+            b"i128"  => Some(TypeSuffix::I128),
+            b"isize" => Some(TypeSuffix::Isize),
+            _        => None,
+        }
+    }
+
+    /// Get the suffix characters, like `"u8"`.
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TypeSuffix::U8    => "u8",
+            TypeSuffix::U16   => "u16",
+            TypeSuffix::U32   => "u32",
+            TypeSuffix::U64   => "u64",
+            TypeSuffix::U128  => "u128",
+            TypeSuffix::Usize => "usize",
+            TypeSuffix::I8    => "i8",
+            TypeSuffix::I16   => "i16",
+            TypeSuffix::I32   => "i32",
+            TypeSuffix::I64   => "i64",
+            TypeSuffix::I128  => "i128",
+            TypeSuffix::Isize => "isize",
+        }
+    }
+}
+
+// PARSE
+
+/// Parse a Rust integer literal, with a base prefix and type suffix.
+///
+/// This method parses the entire literal, returning an error if any
+/// invalid digits are found during parsing. The literal has an optional
+/// lowercase `0b`, `0o` or `0x` prefix, digits in the base of the prefix,
+/// any number of `_` separators after the first digit, including before
+/// the suffix, and an optional type suffix, one of `u8`, `u16`, `u32`,
+/// `u64`, `u128`, `usize`, `i8`, `i16`, `i32`, `i64`, `i128` or `isize`.
+/// Returns the value, the base, and the type suffix, if present.
+///
+/// Unlike Rust, a `_` separator directly after the prefix, like `0x_FF`,
+/// is an invalid digit, since the first digit must follow the prefix.
+/// Literals are unsigned, so a sign is an invalid digit, and the value
+/// is not checked against the range of the suffix type, so `256u8`
+/// is `256`. Values larger than `u128::MAX` return an overflow error.
+///
+/// * `bytes`   - Byte slice containing a Rust integer literal.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{Base, TypeSuffix};
+/// # pub fn main() {
+/// let parse = lexical_core::parse_rust_int_literal;
+/// assert_eq!(parse(b"0xFF_u8"), Ok((255, Base::Hexadecimal, Some(TypeSuffix::U8))));
+/// assert_eq!(parse(b"1_000i64"), Ok((1000, Base::Decimal, Some(TypeSuffix::I64))));
+/// assert_eq!(parse(b"0b1010_1010"), Ok((170, Base::Binary, None)));
+/// assert!(parse(b"0x_FF").is_err());
+/// # }
+/// ```
+pub fn parse_rust_int_literal(bytes: &[u8])
+    -> Result<(u128, Base, Option<TypeSuffix>)>
+{
+    if bytes.is_empty() {
+        return Err((ErrorCode::Empty, 0).into());
+    }
+
+    // Parse the digits and separators, which start with a digit.
+    let (base, prefix) = Base::from_prefix(bytes);
+    let radix = base.radix();
+    let mut value: u128 = 0;
+    let mut index = prefix;
+    while let Some(&c) = bytes.get(index) {
+        if c == b'_' && index > prefix {
+            index += 1;
+            continue;
+        }
+        let digit = match to_digit!(c, radix) {
+            Some(digit) => digit,
+            None        => break,
+        };
+        value = match value.checked_mul(radix as u128).and_then(|v| v.checked_add(digit as u128)) {
+            Some(value) => value,
+            None        => return Err((ErrorCode::Overflow, index).into()),
+        };
+        index += 1;
+    }
+    if index == prefix {
+        return match bytes.get(index) {
+            Some(_) => Err((ErrorCode::InvalidDigit, index).into()),
+            None    => Err((ErrorCode::EmptyInteger, index).into()),
+        };
+    }
+
+    // Parse the type suffix, which must be the rest of the literal.
+    let suffix = &bytes[index..];
+    match (suffix.is_empty(), TypeSuffix::from_bytes(suffix)) {
+        (true, _)        => Ok((value, base, None)),
+        (false, Some(s)) => Ok((value, base, Some(s))),
+        (false, None)    => Err((ErrorCode::InvalidDigit, index).into()),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rust_int_literal_test() {
+        assert_eq!(Ok((255, Base::Hexadecimal, Some(TypeSuffix::U8))), parse_rust_int_literal(b"0xFF_u8"));
+        assert_eq!(Ok((1000, Base::Decimal, Some(TypeSuffix::I64))), parse_rust_int_literal(b"1_000i64"));
+        assert_eq!(Ok((170, Base::Binary, None)), parse_rust_int_literal(b"0b1010_1010"));
+        assert_eq!(Ok((511, Base::Octal, Some(TypeSuffix::Usize))), parse_rust_int_literal(b"0o777usize"));
+        assert_eq!(Ok((0xabcd, Base::Hexadecimal, None)), parse_rust_int_literal(b"0xab_CD"));
+        assert_eq!(Ok((0, Base::Decimal, None)), parse_rust_int_literal(b"0"));
+        assert_eq!(Ok((1, Base::Decimal, Some(TypeSuffix::I128))), parse_rust_int_literal(b"1__i128"));
+        assert_eq!(Ok((10, Base::Decimal, None)), parse_rust_int_literal(b"1_0_"));
+        assert_eq!(Ok((256, Base::Decimal, Some(TypeSuffix::U8))), parse_rust_int_literal(b"256u8"));
+        assert_eq!(Ok((u128::max_value(), Base::Decimal, None)), parse_rust_int_literal(b"340282366920938463463374607431768211455"));
+
+        // Separators must follow the first digit.
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), parse_rust_int_literal(b"0x_FF"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), parse_rust_int_literal(b"_1"));
+
+        // Invalid prefixes, digits and suffixes.
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), parse_rust_int_literal(b""));
+        assert_eq!(Err((ErrorCode::EmptyInteger, 2).into()), parse_rust_int_literal(b"0x"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), parse_rust_int_literal(b"0xu8"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parse_rust_int_literal(b"0X1"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parse_rust_int_literal(b"0b102"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parse_rust_int_literal(b"1u7"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parse_rust_int_literal(b"1e3"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), parse_rust_int_literal(b"-1"));
+        assert_eq!(Err((ErrorCode::Overflow, 38).into()), parse_rust_int_literal(b"340282366920938463463374607431768211456"));
+    }
+
+    #[test]
+    fn type_suffix_test() {
+        let suffixes = [
+            TypeSuffix::U8, TypeSuffix::U16, TypeSuffix::U32, TypeSuffix::U64, TypeSuffix::U128, TypeSuffix::Usize,
+            TypeSuffix::I8, TypeSuffix::I16, TypeSuffix::I32, TypeSuffix::I64, TypeSuffix::I128, TypeSuffix::Isize,
+        ];
+        for &suffix in suffixes.iter() {
+            assert_eq!(Some(suffix), TypeSuffix::from_bytes(suffix.as_str().as_bytes()));
+        }
+        assert_eq!(None, TypeSuffix::from_bytes(b"U8"));
+        assert_eq!(None, TypeSuffix::from_bytes(b"f64"));
+    }
+}
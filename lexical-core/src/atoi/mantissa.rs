@@ -54,6 +54,103 @@ pub(crate) fn standalone_mantissa<'a, T, Iter1, Iter2>(mut integer: Iter1, mut f
     (value, 0)
 }}
 
+// SWAR
+// ----
+
+// Parse 8 decimal digits at a time, using SWAR (SIMD within a register),
+// by loading the digits as a little-endian `u64`, and combining adjacent
+// digits, then pairs of digits, then groups of 4 digits, with a few
+// multiplies and shifts. This does not require any SIMD intrinsics.
+
+// Load 8 bytes as a little-endian `u64`.
+perftools_inline_always!{
+#[cfg(feature = "correct")]
+fn read_8digits(bytes: &[u8]) -> u64 {
+    let mut buffer = [0u8; 8];
+    buffer.copy_from_slice(&index!(bytes[..8]));
+    u64::from_le_bytes(buffer)
+}}
+
+// Determine if all 8 bytes are decimal digits.
+perftools_inline_always!{
+#[cfg(feature = "correct")]
+fn is_8digits(value: u64) -> bool {
+    let a = value.wrapping_add(0x4646464646464646);
+    let b = value.wrapping_sub(0x3030303030303030);
+    (a | b) & 0x8080808080808080 == 0
+}}
+
+// Parse 8 decimal digits into a value from 0 to 99999999.
+perftools_inline_always!{
+#[cfg(feature = "correct")]
+fn parse_8digits(value: u64) -> u64 {
+    const MASK: u64 = 0x000000FF000000FF;
+    const MUL1: u64 = 0x000F424000000064;   // 100 + (1000000 << 32)
+    const MUL2: u64 = 0x0000271000000001;   // 1 + (10000 << 32)
+    let value = value - 0x3030303030303030;
+    let value = (value * 10) + (value >> 8);
+    let lo = (value & MASK).wrapping_mul(MUL1);
+    let hi = ((value >> 16) & MASK).wrapping_mul(MUL2);
+    lo.wrapping_add(hi) >> 32
+}}
+
+// Add 8 digits at a time to the mantissa, returning the unparsed digits.
+// Stops before the first 8 digits that would overflow the mantissa,
+// so the scalar path can calculate the number of truncated digits.
+perftools_inline!{
+#[cfg(feature = "correct")]
+fn add_8digits<T>(mut value: T, mut digits: &[u8])
+    -> (T, &[u8])
+    where T: UnsignedInteger
+{
+    while digits.len() >= 8 {
+        let chunk = read_8digits(digits);
+        if !is_8digits(chunk) {
+            break;
+        }
+        let result = value
+            .checked_mul(as_cast(100000000u32))
+            .and_then(|v| v.checked_add(as_cast(parse_8digits(chunk))));
+        match result {
+            Some(v) => value = v,
+            None    => break,
+        }
+        digits = &index!(digits[8..]);
+    }
+    (value, digits)
+}}
+
+// Calculate the mantissa and the number of truncated digits from decimal
+// digits without digit separators, parsing 8 digits at a time.
+// Identical to `standalone_mantissa` with a radix of 10.
+perftools_inline!{
+#[cfg(feature = "correct")]
+pub(crate) fn standalone_mantissa_decimal<T>(integer: &[u8], fraction: &[u8])
+    -> (T, usize)
+    where T: UnsignedInteger
+{
+    // The scalar path handles the remaining digits before the decimal
+    // point, and the digits that overflow the mantissa.
+    let (mut value, integer) = add_8digits(T::ZERO, integer);
+    let mut iter = integer.iter();
+    while let Some(c) = iter.next() {
+        value = match add_digit(value, to_digit!(*c, 10).unwrap(), 10) {
+            Some(v) => v,
+            None    => return (value, 1 + iter.count() + fraction.len()),
+        };
+    }
+
+    let (mut value, fraction) = add_8digits(value, fraction);
+    let mut iter = fraction.iter();
+    while let Some(c) = iter.next() {
+        value = match add_digit(value, to_digit!(*c, 10).unwrap(), 10) {
+            Some(v) => v,
+            None    => return (value, 1 + iter.count()),
+        };
+    }
+    (value, 0)
+}}
+
 // Calculate the mantissa when it cannot have sign or other invalid digits.
 perftools_inline!{
 #[cfg(not(feature = "correct"))]
@@ -93,3 +190,61 @@ pub(crate) fn standalone_mantissa_n<'a, T, Iter>(iter: &mut Iter, radix: u32, ma
     }
     (value, index)
 }}
+
+// TESTS
+// -----
+
+#[cfg(all(test, feature = "correct"))]
+mod tests {
+    use super::*;
+
+    // Check the SWAR and scalar paths produce identical results.
+    fn check_decimal<T: UnsignedInteger>(integer: &[u8], fraction: &[u8]) {
+        let expected: (T, usize) = standalone_mantissa(integer.iter(), fraction.iter(), 10);
+        let actual: (T, usize) = standalone_mantissa_decimal(integer, fraction);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn parse_8digits_test() {
+        assert_eq!(parse_8digits(read_8digits(b"00000000")), 0);
+        assert_eq!(parse_8digits(read_8digits(b"12345678")), 12345678);
+        assert_eq!(parse_8digits(read_8digits(b"99999999")), 99999999);
+        assert_eq!(parse_8digits(read_8digits(b"10203040")), 10203040);
+        assert!(is_8digits(read_8digits(b"01234567")));
+        assert!(!is_8digits(read_8digits(b"1234567.")));
+        assert!(!is_8digits(read_8digits(b"1234:678")));
+        assert!(!is_8digits(read_8digits(b"/2345678")));
+    }
+
+    #[test]
+    fn standalone_mantissa_decimal_test() {
+        let cases: [(&[u8], &[u8]); 12] = [
+            (b"", b""),
+            (b"1", b""),
+            (b"", b"1"),
+            (b"12345678", b""),
+            (b"1234567", b"8"),
+            (b"123456789", b"123456789"),
+            (b"18446744073709551615", b""),
+            (b"18446744073709551616", b""),
+            (b"1844674407370955161", b"6"),
+            (b"9999999999999999999999", b"99"),
+            (b"1", b"23456789012345678901234567890"),
+            (b"340282366920938463463374607431768211455", b"99999999"),
+        ];
+        for &(integer, fraction) in cases.iter() {
+            check_decimal::<u64>(integer, fraction);
+            check_decimal::<u128>(integer, fraction);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    proptest! {
+        #[test]
+        fn standalone_mantissa_decimal_proptest(integer in "[0-9]{0,48}", fraction in "[0-9]{0,48}") {
+            check_decimal::<u64>(integer.as_bytes(), fraction.as_bytes());
+            check_decimal::<u128>(integer.as_bytes(), fraction.as_bytes());
+        }
+    }
+}
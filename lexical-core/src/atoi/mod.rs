@@ -7,8 +7,18 @@ mod shared;
 mod api;
 mod exponent;
 mod generic;
+#[cfg(feature = "format")]
+mod grouped;
+mod lenient;
+mod literal;
 mod mantissa;
+mod unsigned;
 
 // Re-exports
+#[cfg(feature = "format")]
+pub(crate) use self::grouped::*;
+pub(crate) use self::lenient::*;
+pub use self::literal::*;
 pub(crate) use self::mantissa::*;
 pub(crate) use self::exponent::*;
+pub(crate) use self::unsigned::*;
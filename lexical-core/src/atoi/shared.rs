@@ -31,6 +31,7 @@ macro_rules! parse_sign {
         if digits.is_empty() {
             return Err((ErrorCode::$code, digits.as_ptr()));
         }
+        validate_sign_sequence($bytes, digits)?;
 
         (sign, digits)
     });
@@ -0,0 +1,61 @@
+//! Parse integers, rejecting a negative sign.
+
+use crate::util::*;
+
+// Parse integer from string, failing with `ErrorCode::NegativeNotAllowed`
+// at the start of the string if the string has a negative sign.
+//
+// If `allow_negative_zero` is set, a negative zero is parsed as zero,
+// and the error is only returned once the value is parsed.
+perftools_inline!{
+pub(crate) fn parse_require_unsigned<T>(bytes: &[u8], allow_negative_zero: bool)
+    -> Result<T>
+    where T: Integer + FromLexical
+{
+    if bytes.first() != Some(&b'-') {
+        return T::from_lexical(bytes);
+    } else if !allow_negative_zero {
+        return Err((ErrorCode::NegativeNotAllowed, 0).into());
+    }
+
+    // Parse the magnitude, which must not have another sign.
+    let magnitude = &bytes[1..];
+    if magnitude.first() == Some(&b'+') || magnitude.first() == Some(&b'-') {
+        return Err((ErrorCode::InvalidSign, 1).into());
+    }
+    let offset = | e: Error | Error::from((e.code, e.index + 1));
+    match T::from_lexical(magnitude).map_err(offset)? {
+        value if value == T::ZERO => Ok(value),
+        _                         => Err((ErrorCode::NegativeNotAllowed, 0).into()),
+    }
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_require_unsigned_test() {
+        let negative: Error = (ErrorCode::NegativeNotAllowed, 0).into();
+        assert_eq!(Ok(5), parse_require_unsigned::<i32>(b"5", false));
+        assert_eq!(Ok(5), parse_require_unsigned::<i32>(b"+5", false));
+        assert_eq!(Ok(5), parse_require_unsigned::<u32>(b"5", false));
+        assert_eq!(Err(negative), parse_require_unsigned::<i32>(b"-5", false));
+        assert_eq!(Err(negative), parse_require_unsigned::<u32>(b"-5", false));
+        assert_eq!(Err(negative), parse_require_unsigned::<i32>(b"-0", false));
+        assert_eq!(Err(negative), parse_require_unsigned::<i32>(b"-", false));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parse_require_unsigned::<i32>(b"5x", false));
+
+        // Negative zero is parsed as zero.
+        assert_eq!(Ok(0), parse_require_unsigned::<i32>(b"-0", true));
+        assert_eq!(Ok(0), parse_require_unsigned::<u8>(b"-000", true));
+        assert_eq!(Err(negative), parse_require_unsigned::<i32>(b"-5", true));
+        assert_eq!(Err(negative), parse_require_unsigned::<u32>(b"-5", true));
+        assert_eq!(Err((ErrorCode::InvalidSign, 1).into()), parse_require_unsigned::<i32>(b"--0", true));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), parse_require_unsigned::<i32>(b"-0x", true));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), parse_require_unsigned::<i32>(b"-", true));
+    }
+}
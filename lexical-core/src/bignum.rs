@@ -0,0 +1,7 @@
+//! Arbitrary-precision arithmetic for correctly-rounded conversions.
+//!
+//! Exposes the big integer used by the slow path of the correct parser,
+//! so it may be reused to implement other correctly-rounded conversions.
+//! Only available with the `bignum` feature.
+
+pub use crate::atof::Bignum;
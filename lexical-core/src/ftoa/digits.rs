@@ -0,0 +1,287 @@
+//! Count the significant digits required to round-trip a float.
+
+use crate::atof::{Bigint, LargeOps, SharedOps, SmallOps};
+use crate::lib::cmp;
+use crate::util::*;
+
+// DIGITS
+
+// Determine if `x` is above the upper bound `y`, which is exclusive for
+// an odd mantissa (ties round to the even neighbor).
+perftools_inline!{
+fn is_above(x: &Bigint, y: &Bigint, is_even: bool) -> bool {
+    match x.compare(y) {
+        cmp::Ordering::Greater  => true,
+        cmp::Ordering::Equal    => is_even,
+        cmp::Ordering::Less     => false,
+    }
+}}
+
+// Count the significant digits of the shortest representation of a
+// finite, positive float that parses back to the same float.
+//
+// This is the free-format algorithm of Steele & White, with the fix-up
+// of Burger & Dybvig. The value is `r / s`, and the halfway points to
+// the neighboring floats are `(r - m-) / s` and `(r + m+) / s`, scaled
+// by powers of 2 and the radix so every value is an integer. Digits are
+// generated exactly until the remainder is within the halfway points,
+// so no digits or strings are written, and only the count is kept.
+// The halfway points are inclusive for an even mantissa, since ties
+// round to even.
+perftools_inline!{
+fn shortest_digits<F: Float>(value: F, radix: u32)
+    -> u32
+{
+    let radix = radix.into();
+    let mantissa = value.mantissa().as_u64();
+    let exponent = value.exponent();
+    let is_even = mantissa & 1 == 0;
+
+    // At a power of two, the lower neighbor is half as far as the upper.
+    let is_asymmetric = mantissa == F::HIDDEN_BIT_MASK.as_u64() && exponent > F::DENORMAL_EXPONENT;
+    let shift = if is_asymmetric { 2 } else { 1 };
+    let mut r = Bigint::from_u64(mantissa << shift);
+    let mut s = Bigint::from_u64(1 << shift);
+    let mut m_plus = Bigint::from_u64(1 << (shift - 1));
+    let mut m_minus = Bigint::from_u64(1);
+    if exponent >= 0 {
+        r.ishl(exponent as usize);
+        m_plus.ishl(exponent as usize);
+        m_minus.ishl(exponent as usize);
+    } else {
+        s.ishl((-exponent) as usize);
+    }
+
+    // Scale so the upper halfway point is below 1, and the first digit
+    // is non-zero.
+    while is_above(&r.add_large(&m_plus), &s, is_even) {
+        s.imul_small(radix);
+    }
+    loop {
+        let mut high = r.add_large(&m_plus);
+        high.imul_small(radix);
+        if is_above(&high, &s, is_even) {
+            break;
+        }
+        r.imul_small(radix);
+        m_plus.imul_small(radix);
+        m_minus.imul_small(radix);
+    }
+
+    // Generate digits until the remainder is within the halfway points.
+    let mut count = 0;
+    loop {
+        count += 1;
+        r.imul_small(radix);
+        m_plus.imul_small(radix);
+        m_minus.imul_small(radix);
+        while r.greater_equal(&s) {
+            r.isub_large(&s);
+        }
+        let is_low = match r.compare(&m_minus) {
+            cmp::Ordering::Less     => true,
+            cmp::Ordering::Equal    => is_even,
+            cmp::Ordering::Greater  => false,
+        };
+        if is_low || is_above(&r.add_large(&m_plus), &s, is_even) {
+            return count;
+        }
+    }
+}}
+
+// Count the significant digits required to round-trip the float.
+perftools_inline!{
+fn round_trip_digits_impl<F: Float>(value: F, radix: u32)
+    -> u32
+{
+    if value.is_nan() || value.is_special() {
+        0
+    } else if value.is_zero() {
+        1
+    } else {
+        shortest_digits(value, radix)
+    }
+}}
+
+/// Get the number of significant decimal digits required to round-trip a float.
+///
+/// This is the number of significant digits in the shortest decimal
+/// representation that parses back to the same float, so `0.1`
+/// requires 1 digit, and `1.0 / 3.0` requires 16. Any `f32` requires
+/// at most 9 digits, and any `f64` requires at most 17, including
+/// subnormal floats. The digits are counted exactly, without writing
+/// the float, and match the significant digits written by [`write`]
+/// with the `ryu` (default) feature. Other float writers may write
+/// more digits than required. Zero requires 1 digit, and NaN and
+/// infinity return 0, since they are not written with digits.
+///
+/// * `value`   - Float to write.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::round_trip_digits;
+/// # pub fn main() {
+/// assert_eq!(round_trip_digits(0.1f64), 1);
+/// assert_eq!(round_trip_digits(-123.456f64), 6);
+/// assert_eq!(round_trip_digits(1.0f64 / 3.0), 16);
+/// assert_eq!(round_trip_digits(std::f64::NAN), 0);
+/// # }
+/// ```
+///
+/// [`write`]: fn.write.html
+#[inline]
+pub fn round_trip_digits<F: Float>(value: F)
+    -> u32
+{
+    round_trip_digits_impl(value, 10)
+}
+
+/// Get the number of significant digits required to round-trip a float with a custom radix.
+///
+/// This is the number of significant digits in the shortest
+/// representation in the radix that parses back to the same float.
+/// [`write_radix`] may write more digits than required. Zero requires
+/// 1 digit, and NaN and infinity return 0. See [`round_trip_digits`].
+///
+/// * `value`   - Float to write.
+/// * `radix`   - Radix for number encoding.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+///
+/// [`write_radix`]: fn.write_radix.html
+/// [`round_trip_digits`]: fn.round_trip_digits.html
+#[inline]
+#[cfg(feature = "radix")]
+pub fn round_trip_digits_radix<F: Float>(value: F, radix: u8)
+    -> u32
+{
+    assert_radix!(radix);
+    round_trip_digits_impl(value, radix.as_u32())
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use crate::util::test::*;
+    use super::*;
+
+    // Count the significant digits in a written, unsigned float.
+    fn count_written_digits(bytes: &[u8]) -> u32 {
+        let mut count = 0;
+        let mut zeros = 0;
+        for &c in bytes.iter().filter(|&&c| c != b'.') {
+            match (c as char).to_digit(10) {
+                None                  => break,
+                Some(0) if count == 0 => (),
+                Some(0)               => zeros += 1,
+                Some(_)               => {
+                    count += zeros + 1;
+                    zeros = 0;
+                },
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn f32_round_trip_digits_test() {
+        assert_eq!(round_trip_digits(0.0f32), 1);
+        assert_eq!(round_trip_digits(-0.0f32), 1);
+        assert_eq!(round_trip_digits(1.0f32), 1);
+        assert_eq!(round_trip_digits(-12.5f32), 3);
+        assert_eq!(round_trip_digits(16777216.0f32), 8);
+        assert_eq!(round_trip_digits(f32::INFINITY), 0);
+        assert_eq!(round_trip_digits(f32::NEG_INFINITY), 0);
+        assert_eq!(round_trip_digits(f32::NAN), 0);
+    }
+
+    #[test]
+    fn f32_round_trip_digits_shortest_test() {
+        assert_eq!(round_trip_digits(0.1f32), 1);
+        assert_eq!(round_trip_digits(1e-45f32), 1);
+        for &value in [f32::MAX, f32::MIN_POSITIVE, 1.0f32 / 3.0, 0.3f32 * 3.0].iter() {
+            let digits = round_trip_digits(value);
+            assert!(digits > 0 && digits <= 9, "{} {}", value, digits);
+        }
+    }
+
+    #[test]
+    fn f64_round_trip_digits_test() {
+        assert_eq!(round_trip_digits(0.0f64), 1);
+        assert_eq!(round_trip_digits(-0.0f64), 1);
+        assert_eq!(round_trip_digits(1.0f64), 1);
+        assert_eq!(round_trip_digits(0.1f64), 1);
+        assert_eq!(round_trip_digits(-123.456f64), 6);
+        assert_eq!(round_trip_digits(f64::INFINITY), 0);
+        assert_eq!(round_trip_digits(f64::NAN), 0);
+    }
+
+    #[test]
+    fn f64_round_trip_digits_shortest_test() {
+        assert_eq!(round_trip_digits(1e23f64), 1);
+        assert_eq!(round_trip_digits(1.0f64 / 3.0), 16);
+        assert_eq!(round_trip_digits(5e-324f64), 1);
+        assert_eq!(round_trip_digits(f64::MAX), 17);
+        assert_eq!(round_trip_digits(f64::MIN_POSITIVE), 17);
+        for &value in [0.1f64 + 0.2, 2.0f64.sqrt(), 9007199254740993.0, 1.2345e-299].iter() {
+            let digits = round_trip_digits(value);
+            assert!(digits > 0 && digits <= 17, "{} {}", value, digits);
+        }
+    }
+
+    #[cfg(feature = "ryu")]
+    #[test]
+    fn round_trip_digits_write_test() {
+        // The count matches the digits written by ryu.
+        let mut buffer = new_buffer();
+        let values = [1e23f64, 5e-324, 2.2250738585072014e-308, 1.7976931348623157e308, 0.3, 123456789.0];
+        for &value in values.iter() {
+            let count = count_written_digits(value.to_lexical(&mut buffer));
+            assert_eq!(round_trip_digits(value), count, "{}", value);
+        }
+    }
+
+    #[cfg(all(feature = "ryu", feature = "std"))]
+    proptest! {
+        #[test]
+        fn f32_round_trip_digits_proptest(bits in 1u32..0x7F800000) {
+            let value = f32::from_bits(bits);
+            let mut buffer = new_buffer();
+            let count = count_written_digits(value.to_lexical(&mut buffer));
+            prop_assert_eq!(round_trip_digits(value), count);
+        }
+
+        #[test]
+        fn f64_round_trip_digits_proptest(bits in 1u64..0x7FF0000000000000) {
+            let value = f64::from_bits(bits);
+            let mut buffer = new_buffer();
+            let count = count_written_digits(value.to_lexical(&mut buffer));
+            prop_assert_eq!(round_trip_digits(value), count);
+        }
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn round_trip_digits_radix_test() {
+        assert_eq!(round_trip_digits_radix(0.0f64, 2), 1);
+        assert_eq!(round_trip_digits_radix(1.0f64, 2), 1);
+        assert_eq!(round_trip_digits_radix(0.75f64, 2), 2);
+        assert_eq!(round_trip_digits_radix(-12.5f64, 2), 5);
+        assert_eq!(round_trip_digits_radix(0.1f64, 10), 1);
+        assert_eq!(round_trip_digits_radix(255.0f64, 16), 2);
+        assert_eq!(round_trip_digits_radix(f64::NAN, 16), 0);
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    #[should_panic]
+    fn round_trip_digits_radix_invalid_test() {
+        round_trip_digits_radix(1.0f64, 37);
+    }
+}
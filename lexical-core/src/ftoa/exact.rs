@@ -0,0 +1,247 @@
+//! Lazily write the exact decimal expansion of a float.
+
+use crate::atof::{Bigint, LargeOps, SharedOps, SmallOps};
+use crate::util::*;
+use crate::lib::iter;
+
+// EXACT
+
+/// Maximum number of integer digits in a finite float (`f64::MAX` has 309).
+const INTEGER_SIZE: usize = 310;
+
+/// Iterator over the exact decimal expansion of a float.
+///
+/// Created by [`exact_digits`]. Every finite binary float has a
+/// terminating decimal expansion, so the iterator always terminates,
+/// after at most 1077 bytes for an `f64`.
+///
+/// [`exact_digits`]: fn.exact_digits.html
+pub struct ExactDigits {
+    /// Remaining bytes of the NaN or infinity string.
+    special: &'static [u8],
+    /// If the minus sign has not been written.
+    negative: bool,
+    /// Integer digits, in reverse order.
+    integer: [u8; INTEGER_SIZE],
+    /// Number of integer digits not yet written.
+    integer_len: usize,
+    /// Numerator of the fraction.
+    numerator: Bigint,
+    /// Denominator of the fraction, a power of 2.
+    denominator: Bigint,
+    /// If the decimal point has been written.
+    point: bool,
+}
+
+impl ExactDigits {
+    // Create the iterator for NaN or infinity.
+    perftools_inline!{
+    fn special(negative: bool, special: &'static [u8]) -> ExactDigits {
+        ExactDigits {
+            special,
+            negative,
+            integer: [b'0'; INTEGER_SIZE],
+            integer_len: 0,
+            numerator: Bigint::default(),
+            denominator: Bigint::default(),
+            point: false,
+        }
+    }}
+
+    // Create the iterator from the decomposed, finite float.
+    perftools_inline!{
+    fn new(negative: bool, mut mantissa: u64, mut exponent: i32) -> ExactDigits {
+        let mut digits = ExactDigits::special(negative, &[]);
+
+        // Remove trailing zero bits from the fraction.
+        while exponent < 0 && mantissa != 0 && mantissa & 1 == 0 {
+            mantissa >>= 1;
+            exponent += 1;
+        }
+
+        if exponent >= 0 {
+            // Integer float, store the digits of `mantissa * 2^exponent`.
+            let mut integer = Bigint::from_u64(mantissa);
+            integer.ishl(exponent as usize);
+            digits.push_integer(integer);
+        } else {
+            let shift = (-exponent) as usize;
+            let mut integer = Bigint::default();
+            if shift < 64 {
+                integer = Bigint::from_u64(mantissa >> shift);
+                mantissa &= (1 << shift) - 1;
+            }
+            digits.push_integer(integer);
+            digits.numerator = Bigint::from_u64(mantissa);
+            digits.denominator = Bigint::from_u64(1);
+            digits.denominator.ishl(shift);
+        }
+
+        digits
+    }}
+
+    // Store the digits of the integer part.
+    perftools_inline!{
+    fn push_integer(&mut self, mut integer: Bigint) {
+        loop {
+            let remainder = integer.idiv_small(10);
+            self.integer[self.integer_len] = b'0' + remainder as u8;
+            self.integer_len += 1;
+            if integer.is_zero() {
+                break;
+            }
+        }
+    }}
+
+    // Extract the next fraction digit, by scaling the numerator by 10.
+    perftools_inline!{
+    fn next_fraction_digit(&mut self) -> u8 {
+        self.numerator.imul_small(10);
+        let mut digit = b'0';
+        while self.numerator.greater_equal(&self.denominator) {
+            self.numerator.isub_large(&self.denominator);
+            digit += 1;
+        }
+        digit
+    }}
+}
+
+impl Iterator for ExactDigits {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        if self.negative {
+            self.negative = false;
+            Some(b'-')
+        } else if let Some((&c, rest)) = self.special.split_first() {
+            self.special = rest;
+            Some(c)
+        } else if self.integer_len != 0 {
+            self.integer_len -= 1;
+            Some(self.integer[self.integer_len])
+        } else if self.numerator.is_zero() {
+            None
+        } else if !self.point {
+            self.point = true;
+            Some(b'.')
+        } else {
+            Some(self.next_fraction_digit())
+        }
+    }
+}
+
+impl iter::FusedIterator for ExactDigits {}
+
+/// Lazily write the exact decimal expansion of a float.
+///
+/// Unlike [`write`], which writes the shortest digits that round-trip,
+/// this writes every digit of the exact value of the float, one byte
+/// at a time, without allocating. For example, `0.1f64` is exactly
+/// `0.1000000000000000055511151231257827021181583404541015625`. The
+/// expansion is written without an exponent or trailing zeros, so
+/// integral floats do not have a decimal point or fraction, and the
+/// minus sign is written for negative values, including `-0.0`. NaN
+/// and infinity are written using the NaN and infinity strings.
+///
+/// Every finite binary float has a terminating decimal expansion, so
+/// the iterator always terminates, but the expansion may be long: the
+/// smallest denormal `f64` has 1074 fraction digits.
+///
+/// * `value`   - Float to write.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::exact_digits;
+/// # pub fn main() {
+/// let digits: Vec<u8> = exact_digits(0.1f32).collect();
+/// assert_eq!(digits, b"0.100000001490116119384765625");
+///
+/// let digits: Vec<u8> = exact_digits(-2.5f64).take(4).collect();
+/// assert_eq!(digits, b"-2.5");
+/// # }
+/// ```
+///
+/// [`write`]: fn.write.html
+#[inline]
+pub fn exact_digits<F: Float>(value: F) -> ExactDigits {
+    if value.is_nan() {
+        ExactDigits::special(false, get_nan_string())
+    } else if value.is_special() {
+        ExactDigits::special(value.is_sign_negative(), get_inf_string())
+    } else {
+        // Floats are exactly representable as `f64`.
+        let bits = value.as_f64().to_bits();
+        let negative = bits & f64::SIGN_MASK != 0;
+        let biased = ((bits & f64::EXPONENT_MASK) >> f64::MANTISSA_SIZE) as i32;
+        let fraction = bits & f64::MANTISSA_MASK;
+        let (mantissa, exponent) = if biased == 0 {
+            (fraction, f64::DENORMAL_EXPONENT)
+        } else {
+            (fraction | f64::HIDDEN_BIT_MASK, biased - f64::EXPONENT_BIAS)
+        };
+        ExactDigits::new(negative, mantissa, exponent)
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exact<F: Float>(value: F) -> String {
+        exact_digits(value).map(|c| c as char).collect()
+    }
+
+    #[test]
+    fn f32_exact_digits_test() {
+        assert_eq!(exact(0.0f32), "0");
+        assert_eq!(exact(-0.0f32), "-0");
+        assert_eq!(exact(1.0f32), "1");
+        assert_eq!(exact(-12.5f32), "-12.5");
+        assert_eq!(exact(0.1f32), "0.100000001490116119384765625");
+        assert_eq!(exact(f32::MAX), "340282346638528859811704183484516925440");
+        assert_eq!(exact(1e-45f32).len(), 151);
+        assert!(exact(1e-45f32).starts_with("0.000000000000000000000000000000000000000000001401298464324817"));
+        assert_eq!(exact(f32::NAN), "NaN");
+        assert_eq!(exact(f32::INFINITY), "inf");
+        assert_eq!(exact(f32::NEG_INFINITY), "-inf");
+    }
+
+    #[test]
+    fn f64_exact_digits_test() {
+        assert_eq!(exact(0.0f64), "0");
+        assert_eq!(exact(1.0f64), "1");
+        assert_eq!(exact(0.5f64), "0.5");
+        assert_eq!(exact(9007199254740993.0f64), "9007199254740992");
+        assert_eq!(exact(0.1f64), "0.1000000000000000055511151231257827021181583404541015625");
+        assert_eq!(exact(1e23f64), "99999999999999991611392");
+        assert_eq!(exact(f64::MAX).len(), 309);
+        assert!(exact(f64::MAX).starts_with("17976931348623157081452742373170435679807056752584499659891747680315726078"));
+        assert!(exact(f64::MAX).ends_with("58368"));
+
+        // The smallest denormal is exactly 2^-1074.
+        let denormal = exact(5e-324f64);
+        assert_eq!(denormal.len(), 1076);
+        assert!(denormal.starts_with(&format!("0.{}494065645841246544", "0".repeat(323))));
+        assert!(denormal.ends_with("625"));
+
+        // The largest fraction has 1074 fraction digits and an integer digit.
+        let value = f64::from_bits(0x800F_FFFF_FFFF_FFFF);
+        assert_eq!(exact(value).len(), 1077);
+        assert!(exact(value).starts_with("-0.0000"));
+    }
+
+    #[test]
+    fn exact_digits_lazy_test() {
+        let mut digits = exact_digits(5e-324f64);
+        assert_eq!(digits.by_ref().take(3).collect::<Vec<u8>>(), b"0.0");
+        assert_eq!(digits.by_ref().count(), 1073);
+        assert_eq!(digits.next(), None);
+        assert_eq!(digits.next(), None);
+    }
+}
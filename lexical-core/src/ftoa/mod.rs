@@ -14,3 +14,13 @@ if #[cfg(feature = "grisu3")] {
 }}  // cfg_if
 
 mod api;
+#[cfg(feature = "correct")]
+mod digits;
+#[cfg(feature = "correct")]
+mod exact;
+
+// Re-exports
+#[cfg(feature = "correct")]
+pub use self::digits::*;
+#[cfg(feature = "correct")]
+pub use self::exact::*;
@@ -56,6 +56,9 @@
 //! **To String**
 //! - [`write`]
 #![cfg_attr(feature = "radix", doc = " - [`write_radix`]")]
+#![cfg_attr(feature = "correct", doc = " - [`round_trip_digits`]")]
+#![cfg_attr(all(feature = "correct", feature = "radix"), doc = " - [`round_trip_digits_radix`]")]
+#![cfg_attr(feature = "correct", doc = " - [`exact_digits`]")]
 //!
 //! **From String**
 //! - [`parse`]
@@ -63,6 +66,35 @@
 #![cfg_attr(feature = "format", doc = " - [`parse_format`]")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " - [`parse_format_radix`]")]
 //! - [`parse_partial`]
+//! - [`parse_partial_str`]
+//! - [`parse_with_kind`]
+//! - [`parse_f32_from_raw_parts`]
+//! - [`parse_f64_from_raw_parts`]
+//! - [`parse_list`]
+//! - [`parse_u64_list`]
+//! - [`parse_strided`]
+#![cfg_attr(feature = "format", doc = " - [`parse_u64_grouped`]")]
+//! - [`parse_f32_bits`]
+//! - [`parse_f64_bits`]
+//! - [`parse_f64_fast`]
+//! - [`parse_f32_rounded`]
+//! - [`parse_f64_rounded`]
+#![cfg_attr(feature = "correct", doc = " - [`parse_f32_with_error`]")]
+#![cfg_attr(feature = "correct", doc = " - [`parse_f64_with_error`]")]
+//! - [`parse_f32_classified`]
+//! - [`parse_f64_classified`]
+#![cfg_attr(feature = "correct", doc = " - [`parse_f32_with_scratch`]")]
+#![cfg_attr(feature = "correct", doc = " - [`parse_f64_with_scratch`]")]
+#![cfg_attr(feature = "correct", doc = " - [`parse_both`]")]
+#![cfg_attr(feature = "correct", doc = " - [`estimate_cost`]")]
+//! - [`parse_ratio_f32`]
+//! - [`parse_ratio_f64`]
+//! - [`extract_digits`]
+//! - [`numbers`]
+#![cfg_attr(feature = "radix", doc = " - [`numbers_radix`]")]
+//! - [`parse_rust_int_literal`]
+//! - [`parse_implied_decimal`]
+#![cfg_attr(feature = "radix", doc = " - [`parse_implied_decimal_radix`]")]
 #![cfg_attr(feature = "radix", doc = " - [`parse_partial_radix`]")]
 #![cfg_attr(feature = "format", doc = " - [`parse_partial_format`]")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " - [`parse_partial_format_radix`]")]
@@ -74,6 +106,21 @@
 #![cfg_attr(feature = "radix", doc = " - [`parse_partial_lossy_radix`]")]
 #![cfg_attr(feature = "format", doc = " - [`parse_partial_lossy_format`]")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " - [`parse_partial_lossy_format_radix`]")]
+#![cfg_attr(feature = "const_fn", doc = " - [`const_parse_f32`]")]
+#![cfg_attr(feature = "const_fn", doc = " - [`const_parse_f64`]")]
+//! - [`Lexical`]
+//! - [`max_exact_integer`]
+//! - [`finite_exponent_range`]
+//!
+//! **Reusable Parser**
+//! - [`FloatParser`]
+//! - [`FloatBounds`]
+//! - [`SpecialValues`]
+//! - [`SignSpellings`]
+//! - [`Hemispheres`]
+//! - [`CurrencySymbols`]
+//! - [`RatioParser`]
+#![cfg_attr(feature = "format", doc = " - [`InferredSeparators`]")]
 //!
 //! # Configuration Settings
 //!
@@ -95,11 +142,43 @@
 //!
 //! [`write`]: fn.write.html
 #![cfg_attr(feature = "radix", doc = " [`write_radix`]: fn.write_radix.html")]
+#![cfg_attr(feature = "correct", doc = " [`round_trip_digits`]: fn.round_trip_digits.html")]
+#![cfg_attr(all(feature = "correct", feature = "radix"), doc = " [`round_trip_digits_radix`]: fn.round_trip_digits_radix.html")]
+#![cfg_attr(feature = "correct", doc = " [`exact_digits`]: fn.exact_digits.html")]
 //! [`parse`]: fn.parse.html
 #![cfg_attr(feature = "radix", doc = " [`parse_radix`]: fn.parse_radix.html")]
 #![cfg_attr(feature = "format", doc = " [`parse_format`]: fn.parse_format.html")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " [`parse_format_radix`]: fn.parse_format_radix.html")]
 //! [`parse_partial`]: fn.parse_partial.html
+//! [`parse_partial_str`]: fn.parse_partial_str.html
+//! [`parse_with_kind`]: fn.parse_with_kind.html
+//! [`parse_f32_from_raw_parts`]: fn.parse_f32_from_raw_parts.html
+//! [`parse_f64_from_raw_parts`]: fn.parse_f64_from_raw_parts.html
+//! [`parse_list`]: fn.parse_list.html
+//! [`parse_u64_list`]: fn.parse_u64_list.html
+//! [`parse_strided`]: fn.parse_strided.html
+#![cfg_attr(feature = "format", doc = " [`parse_u64_grouped`]: fn.parse_u64_grouped.html")]
+//! [`parse_f32_bits`]: fn.parse_f32_bits.html
+//! [`parse_f64_bits`]: fn.parse_f64_bits.html
+//! [`parse_f64_fast`]: fn.parse_f64_fast.html
+//! [`parse_f32_rounded`]: fn.parse_f32_rounded.html
+//! [`parse_f64_rounded`]: fn.parse_f64_rounded.html
+#![cfg_attr(feature = "correct", doc = " [`parse_f32_with_error`]: fn.parse_f32_with_error.html")]
+#![cfg_attr(feature = "correct", doc = " [`parse_f64_with_error`]: fn.parse_f64_with_error.html")]
+//! [`parse_f32_classified`]: fn.parse_f32_classified.html
+//! [`parse_f64_classified`]: fn.parse_f64_classified.html
+#![cfg_attr(feature = "correct", doc = " [`parse_f32_with_scratch`]: fn.parse_f32_with_scratch.html")]
+#![cfg_attr(feature = "correct", doc = " [`parse_f64_with_scratch`]: fn.parse_f64_with_scratch.html")]
+#![cfg_attr(feature = "correct", doc = " [`parse_both`]: fn.parse_both.html")]
+#![cfg_attr(feature = "correct", doc = " [`estimate_cost`]: fn.estimate_cost.html")]
+//! [`parse_ratio_f32`]: fn.parse_ratio_f32.html
+//! [`parse_ratio_f64`]: fn.parse_ratio_f64.html
+//! [`extract_digits`]: fn.extract_digits.html
+//! [`numbers`]: fn.numbers.html
+#![cfg_attr(feature = "radix", doc = " [`numbers_radix`]: fn.numbers_radix.html")]
+//! [`parse_rust_int_literal`]: fn.parse_rust_int_literal.html
+//! [`parse_implied_decimal`]: fn.parse_implied_decimal.html
+#![cfg_attr(feature = "radix", doc = " [`parse_implied_decimal_radix`]: fn.parse_implied_decimal_radix.html")]
 #![cfg_attr(feature = "radix", doc = " [`parse_partial_radix`]: fn.parse_partial_radix.html")]
 #![cfg_attr(feature = "format", doc = " [`parse_partial_format`]: fn.parse_partial_format.html")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " [`parse_partial_format_radix`]: fn.parse_partial_format_radix.html")]
@@ -111,6 +190,20 @@
 #![cfg_attr(feature = "radix", doc = " [`parse_partial_lossy_radix`]: fn.parse_partial_lossy_radix.html")]
 #![cfg_attr(feature = "format", doc = " [`parse_partial_lossy_format`]: fn.parse_partial_lossy_format.html")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " [`parse_partial_lossy_format_radix`]: fn.parse_partial_lossy_format_radix.html")]
+#![cfg_attr(feature = "const_fn", doc = " [`const_parse_f32`]: fn.const_parse_f32.html")]
+#![cfg_attr(feature = "const_fn", doc = " [`const_parse_f64`]: fn.const_parse_f64.html")]
+//! [`Lexical`]: struct.Lexical.html
+//! [`max_exact_integer`]: fn.max_exact_integer.html
+//! [`finite_exponent_range`]: fn.finite_exponent_range.html
+//!
+//! [`FloatParser`]: struct.FloatParser.html
+//! [`FloatBounds`]: struct.FloatBounds.html
+//! [`SpecialValues`]: struct.SpecialValues.html
+//! [`SignSpellings`]: struct.SignSpellings.html
+//! [`Hemispheres`]: struct.Hemispheres.html
+//! [`CurrencySymbols`]: struct.CurrencySymbols.html
+//! [`RatioParser`]: struct.RatioParser.html
+#![cfg_attr(feature = "format", doc = " [`InferredSeparators`]: enum.InferredSeparators.html")]
 //!
 //! [`get_exponent_default_char`]: fn.get_exponent_default_char.html
 #![cfg_attr(feature = "radix", doc = " [`get_exponent_backup_char`]: fn.get_exponent_backup_char.html")]
@@ -208,6 +301,8 @@ mod util;
 
 mod atof;
 mod atoi;
+#[cfg(feature = "bignum")]
+pub mod bignum;
 mod float;
 mod ftoa;
 mod itoa;
@@ -215,6 +310,68 @@ mod itoa;
 // Re-export configuration and utilities globally.
 pub use util::*;
 
+// Re-export the reusable parser.
+pub use atof::{CurrencySymbols, DecimalComponents, FloatBounds, FloatParser, FromFloatParser, Hemisphere, Hemispheres, NumberKind, RatioParser, SignSpellings, SpecialValues};
+#[cfg(feature = "format")]
+pub use atof::InferredSeparators;
+#[cfg(feature = "const_fn")]
+pub use atof::{const_parse_f32, const_parse_f64};
+
+// Re-export the bit pattern parsers.
+pub use atof::{parse_f32_bits, parse_f64_bits};
+
+// Re-export the specialized fast-path parser.
+pub use atof::parse_f64_fast;
+
+// Re-export the rounded parsers.
+pub use atof::{parse_f32_rounded, parse_f64_rounded};
+
+// Re-export the parsers with the residual rounding error.
+#[cfg(feature = "correct")]
+pub use atof::{parse_f32_with_error, parse_f64_with_error};
+
+// Re-export the parsers with the IEEE category of the result.
+pub use atof::{parse_f32_classified, parse_f64_classified};
+
+// Re-export the parsers with caller-provided limbs for the slow path.
+#[cfg(feature = "correct")]
+pub use atof::{parse_f32_with_scratch, parse_f64_with_scratch};
+
+// Re-export the parser for both float types.
+#[cfg(feature = "correct")]
+pub use atof::parse_both;
+
+// Re-export the parse cost estimate.
+#[cfg(feature = "correct")]
+pub use atof::{estimate_cost, ParseCost};
+
+// Re-export the ratio parsers.
+pub use atof::{parse_ratio_f32, parse_ratio_f64};
+
+// Re-export the significant digit extraction.
+pub use atof::extract_digits;
+
+// Re-export the embedded number extraction.
+pub use atof::{numbers, Numbers};
+#[cfg(feature = "radix")]
+pub use atof::numbers_radix;
+
+// Re-export the Rust integer literal parser.
+pub use atoi::{parse_rust_int_literal, Base, TypeSuffix};
+
+// Re-export the raw pointer parsers.
+pub use atof::{parse_f32_from_raw_parts, parse_f64_from_raw_parts};
+
+// Re-export the round-trip digit counts.
+#[cfg(feature = "correct")]
+pub use ftoa::round_trip_digits;
+#[cfg(all(feature = "correct", feature = "radix"))]
+pub use ftoa::round_trip_digits_radix;
+
+// Re-export the exact decimal expansion.
+#[cfg(feature = "correct")]
+pub use ftoa::{exact_digits, ExactDigits};
+
 /// Write number to string.
 ///
 /// Returns a subslice of the input buffer containing the written bytes,
@@ -282,6 +439,11 @@ pub fn parse<N: FromLexical>(bytes: &[u8])
 /// of the string), returning the number of processed digits
 /// and the parsed value until that point.
 ///
+/// The number of processed digits is the literal length of the number
+/// in the input. A float with a leading or trailing decimal point is
+/// parsed with an implicit `0`, so `".5"` is parsed as `0.5` and `"5."`
+/// as `5.0`, but both process 2 bytes.
+///
 /// * `bytes`   - Byte slice containing a numeric string.
 #[inline]
 pub fn parse_partial<N: FromLexical>(bytes: &[u8])
@@ -290,12 +452,453 @@ pub fn parse_partial<N: FromLexical>(bytes: &[u8])
     N::from_lexical_partial(bytes)
 }
 
+/// Parse number from string slice, returning the unparsed remainder.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the parsed value until that point and
+/// the remaining, unparsed string slice.
+///
+/// The number grammar is ASCII-only: digits, signs, the decimal
+/// point, exponent characters, digit separators, and special values
+/// are all ASCII. Since every processed byte is an ASCII character,
+/// the processed prefix always ends on a UTF-8 character boundary,
+/// and the remainder is always a valid string slice.
+///
+/// * `string`  - String slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_partial_str::<f64>("1.5e3, 2"), Ok((1500.0, ", 2")));
+/// assert_eq!(lexical_core::parse_partial_str::<i32>("15\u{b0}C"), Ok((15, "\u{b0}C")));
+/// # }
+/// ```
+#[inline]
+pub fn parse_partial_str<N: FromLexical>(string: &str)
+    -> Result<(N, &str)>
+{
+    let (value, index) = N::from_lexical_partial(string.as_bytes())?;
+    debug_assert!(string.is_char_boundary(index));
+    Ok((value, &string[index..]))
+}
+
+/// Parse number from string, returning the specific kind of any error.
+///
+/// This method parses the entire string, like [`parse`], but returns
+/// a [`ParseErrorKind`] on failure, which distinguishes trailing bytes
+/// after a valid number from an invalid digit within the number.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ParseErrorKind;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_with_kind::<f64>(b"1.5"), Ok(1.5));
+/// assert_eq!(lexical_core::parse_with_kind::<f64>(b""), Err(ParseErrorKind::EmptyInput));
+/// assert_eq!(lexical_core::parse_with_kind::<f64>(b"."), Err(ParseErrorKind::LoneDot));
+/// assert_eq!(lexical_core::parse_with_kind::<f64>(b"1e"), Err(ParseErrorKind::EmptyExponent));
+/// assert_eq!(lexical_core::parse_with_kind::<f64>(b"1.5x"), Err(ParseErrorKind::TrailingBytes { index: 3 }));
+/// assert_eq!(lexical_core::parse_with_kind::<f64>(b"1x5"), Err(ParseErrorKind::InvalidDigit { index: 1 }));
+/// assert_eq!(lexical_core::parse_with_kind::<u8>(b"1x5"), Err(ParseErrorKind::InvalidDigit { index: 1 }));
+/// assert_eq!(lexical_core::parse_with_kind::<u8>(b"256"), Err(ParseErrorKind::Overflow));
+/// assert_eq!(lexical_core::parse_with_kind::<i8>(b"-129"), Err(ParseErrorKind::Underflow));
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+/// [`ParseErrorKind`]: enum.ParseErrorKind.html
+#[inline]
+pub fn parse_with_kind<N: FromLexical>(bytes: &[u8])
+    -> lib::result::Result<N, ParseErrorKind>
+{
+    util::parse_with_kind(bytes)
+}
+
+/// Parse a list of numbers from string.
+///
+/// This method parses a comma-separated list of numbers, such as
+/// `"[1.0, 2.5, 3.0]"`, writing each element to `values` and returning
+/// the number of elements parsed. The surrounding brackets are
+/// optional, and whitespace is allowed around the brackets and around
+/// each element. Each element is parsed as if by [`parse_partial`].
+///
+/// On failure, the error index is the index of the first element
+/// that failed to parse, not a position within the buffer, and all
+/// prior elements have been written to `values`. If the list has more
+/// elements than `values` can hold, this returns an error with
+/// `ErrorCode::TooManyElements`.
+///
+/// * `bytes`   - Byte slice containing a list of numeric strings.
+/// * `values`  - Slice to write the parsed elements to.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ErrorCode;
+/// # pub fn main() {
+/// let mut values = [0.0f64; 4];
+/// assert_eq!(lexical_core::parse_list(b"[1.0, 2.5, 3.0]", &mut values), Ok(3));
+/// assert_eq!(&values[..3], &[1.0, 2.5, 3.0]);
+///
+/// let error = lexical_core::parse_list(b"[1.0, 2.5x, 3.0]", &mut values).err().unwrap();
+/// assert_eq!((error.code, error.index), (ErrorCode::InvalidDigit, 1));
+/// # }
+/// ```
+///
+/// [`parse_partial`]: fn.parse_partial.html
+#[inline]
+pub fn parse_list<N: FromLexical>(bytes: &[u8], values: &mut [N])
+    -> Result<usize>
+{
+    util::parse_list(bytes, values)
+}
+
+/// Parse a delimited list of unsigned 64-bit integers from string.
+///
+/// This method is optimized for throughput on integer columns, such
+/// as `"1,23,456"`, writing each field to `values` and returning the
+/// number of fields parsed. Each field must be one or more decimal
+/// digits, without a sign or whitespace.
+///
+/// A single trailing delimiter is ignored, so `"1,2,"` parses 2
+/// fields, and an empty string parses 0 fields. Any other empty field
+/// returns an error with `ErrorCode::Empty`.
+///
+/// On failure, the error index is the index of the first field that
+/// failed to parse, not a position within the buffer, and all prior
+/// fields have been written to `values`. If the list has more fields
+/// than `values` can hold, this returns an error with
+/// `ErrorCode::TooManyElements`.
+///
+/// * `bytes`       - Byte slice containing a delimited list of integers.
+/// * `delimiter`   - Byte separating each field.
+/// * `values`      - Slice to write the parsed fields to.
+///
+/// # Panics
+///
+/// Panics if the delimiter is a decimal digit.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ErrorCode;
+/// # pub fn main() {
+/// let mut values = [0u64; 4];
+/// assert_eq!(lexical_core::parse_u64_list(b"1,23,456,", b',', &mut values), Ok(3));
+/// assert_eq!(&values[..3], &[1, 23, 456]);
+///
+/// let error = lexical_core::parse_u64_list(b"1,,456", b',', &mut values).err().unwrap();
+/// assert_eq!((error.code, error.index), (ErrorCode::Empty, 1));
+/// # }
+/// ```
+#[inline]
+pub fn parse_u64_list(bytes: &[u8], delimiter: u8, values: &mut [u64])
+    -> Result<usize>
+{
+    util::parse_u64_list(bytes, delimiter, values)
+}
+
+/// Parse `f64` from fixed-length fields at the given offsets in a buffer.
+///
+/// This method is optimized for columnar or record-oriented buffers,
+/// such as fixed-width records, parsing the field of `field_len` bytes
+/// at each offset in `field_offsets` into the value with the same
+/// index in `values`. The parser is configured once for every field,
+/// and leading and trailing whitespace is trimmed, so fields padded
+/// with spaces to `field_len` are valid. See
+/// [`FloatParser::parse_strided`] to parse with other options.
+///
+/// On failure, the error index is the index of the first field that
+/// failed to parse, not a position within the buffer, and all prior
+/// fields have been written to `values`. If there are more fields
+/// than `values` can hold, this returns an error with
+/// `ErrorCode::TooManyElements`, and no fields are parsed. If a field
+/// extends past the end of the buffer, this returns an error with
+/// `ErrorCode::OutOfRange`, with the index of the field.
+///
+/// * `radix`           - Radix for number decoding.
+/// * `buffer`          - Byte slice containing the fields.
+/// * `field_offsets`   - Offset of each field in the buffer.
+/// * `field_len`       - Length of every field.
+/// * `values`          - Slice to write the parsed fields to.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`, or is not 10
+/// without the `radix` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// // Records with a 2-byte id and a 6-byte value, padded with spaces.
+/// let buffer = b"011.5   02-2.25 03100   ";
+/// let mut values = [0.0f64; 3];
+/// lexical_core::parse_strided(10, buffer, &[2, 10, 18], 6, &mut values).unwrap();
+/// assert_eq!(values, [1.5, -2.25, 100.0]);
+/// # }
+/// ```
+///
+/// [`FloatParser::parse_strided`]: struct.FloatParser.html#method.parse_strided
+#[inline]
+pub fn parse_strided(radix: u8, buffer: &[u8], field_offsets: &[usize], field_len: usize, values: &mut [f64])
+    -> Result<()>
+{
+    #[cfg(feature = "radix")]
+    let parser = FloatParser::new().radix(radix);
+
+    #[cfg(not(feature = "radix"))]
+    let parser = {
+        assert!(radix == 10, "Numerical base must be 10.");
+        FloatParser::new()
+    };
+
+    parser.trim_whitespace(true).parse_strided(buffer, field_offsets, field_len, values)
+}
+
+/// Parse unsigned 64-bit integer from string with grouped digits.
+///
+/// This method parses the entire string, like [`parse`], but digit
+/// separators are allowed between groups of digits, such as
+/// `"1,234,567"`, for human-entered or financial fields. The digit
+/// groups are validated with the same rules as the [`grouped`] float
+/// format: the first group has from 1 to `group_size` digits, every
+/// subsequent group has exactly `group_size` digits, and leading,
+/// trailing or consecutive digit separators are invalid. Integers
+/// without any digit separators are always valid.
+///
+/// Incorrectly sized groups return an error with
+/// `ErrorCode::InvalidDigitGrouping`, with the index of the start of
+/// the invalid group. Like [`parse`], a `+` sign is allowed, and a
+/// `-` sign is an invalid digit.
+///
+/// * `bytes`           - Byte slice containing a numeric string.
+/// * `digit_separator` - Character to separate digit groups.
+/// * `group_size`      - Number of digits in each group, from 1 to 15.
+///
+/// # Panics
+///
+/// Panics if the digit separator or group size is not valid for
+/// [`grouped`].
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ErrorCode;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_u64_grouped(b"1,234,567", b',', 3), Ok(1234567));
+/// assert_eq!(lexical_core::parse_u64_grouped(b"1234567", b',', 3), Ok(1234567));
+///
+/// let error = lexical_core::parse_u64_grouped(b"1,23,456", b',', 3).err().unwrap();
+/// assert_eq!((error.code, error.index), (ErrorCode::InvalidDigitGrouping, 2));
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+/// [`grouped`]: struct.NumberFormat.html#method.grouped
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_u64_grouped(bytes: &[u8], digit_separator: u8, group_size: u8)
+    -> Result<u64>
+{
+    atoi::parse_grouped(bytes, digit_separator, group_size)
+}
+
+/// Classify a numeric string as an integer, float or special value.
+///
+/// This method scans the string once with the float grammar, without
+/// parsing the value, and classifies it by the following rules:
+///
+/// * `Special` if the string, after an optional sign, is a spelling
+///   of infinity or NaN accepted by the parser.
+/// * `Float` if the string has a decimal point or an exponent, even
+///   if the value is integral, so `"5."` and `"5e0"` are floats.
+/// * `Integer` if the string only has integer digits, and fits in
+///   a 64-bit integer: `i64` for negative values, and `u64`
+///   otherwise.
+/// * `Float` if the string only has integer digits, but is too large
+///   for a 64-bit integer.
+/// * `NotANumber` if the entire string is not a valid float.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::NumberKind;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::classify_number(b"-12"), NumberKind::Integer);
+/// assert_eq!(lexical_core::classify_number(b"5."), NumberKind::Float);
+/// assert_eq!(lexical_core::classify_number(b"5e0"), NumberKind::Float);
+/// assert_eq!(lexical_core::classify_number(b"NaN"), NumberKind::Special);
+/// assert_eq!(lexical_core::classify_number(b"5x"), NumberKind::NotANumber);
+/// # }
+/// ```
+#[inline]
+pub fn classify_number(bytes: &[u8])
+    -> NumberKind
+{
+    atof::classify(bytes, 10)
+}
+
+/// Parse the integer portion of a float from string.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits and the
+/// integer portion of the float until that point, truncated toward
+/// zero. The fraction and exponent are consumed, and the exponent
+/// shifts the decimal point before truncation, so `"3.99"` is `3`,
+/// `"-3.99"` is `-3`, `"3.99e2"` is `399` and `"3.99e4"` is `39900`.
+///
+/// If the truncated value does not fit in an `i64`, this returns an
+/// error with `ErrorCode::Overflow`, or `ErrorCode::Underflow` for
+/// negative values, with an index of 0. Special values are not
+/// integers, and are invalid.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_truncated_int(b"3.99"), Ok((3, 4)));
+/// assert_eq!(lexical_core::parse_truncated_int(b"-3.99e2"), Ok((-399, 7)));
+/// assert_eq!(lexical_core::parse_truncated_int(b"3.99e4, 5"), Ok((39900, 6)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_truncated_int(bytes: &[u8])
+    -> Result<(i64, usize)>
+{
+    atof::parse_truncated(bytes, 10)
+}
+
+/// Parse `i64` from string, ignoring a fraction or exponent tail.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), like [`parse_partial`], returning the integer
+/// prefix, the number of processed bytes, and if a fraction or
+/// exponent was ignored. This is meant for integer fields with dirty
+/// data, such as `"42.0"` where `42` is meant: if the integer is
+/// followed by a decimal point or exponent which is valid float
+/// syntax, the tail is consumed but does not change the value, so
+/// `"42.9"` is `42` and `"42e3"` is `42`, not `42000`. Use
+/// [`parse_truncated_int`] to apply the exponent instead.
+///
+/// An invalid tail, such as `"42e"`, or a tail without integer
+/// digits before it, such as `".5"`, is not consumed, and the number
+/// of processed bytes only includes the integer.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_i64_lenient(b"42.0"), Ok((42, 4, true)));
+/// assert_eq!(lexical_core::parse_i64_lenient(b"42e3"), Ok((42, 4, true)));
+/// assert_eq!(lexical_core::parse_i64_lenient(b"42,5"), Ok((42, 2, false)));
+/// # }
+/// ```
+///
+/// [`parse_partial`]: fn.parse_partial.html
+/// [`parse_truncated_int`]: fn.parse_truncated_int.html
+#[inline]
+pub fn parse_i64_lenient(bytes: &[u8])
+    -> Result<(i64, usize, bool)>
+{
+    atoi::parse_lenient(bytes)
+}
+
+/// Parse integer from string, rejecting a negative sign.
+///
+/// This method parses the entire string, returning an error if any
+/// invalid digits are found during parsing, like [`parse`]. If the
+/// string starts with a negative sign, this returns an error with
+/// `ErrorCode::NegativeNotAllowed`, with an index of 0, for signed and
+/// unsigned integers, so fields which must not be negative, like sizes
+/// or counts, fail without parsing the value. If `allow_negative_zero`
+/// is set, a negative zero, such as `"-0"`, is parsed as `0`. Use
+/// [`FloatParser::require_unsigned`] for floats.
+///
+/// * `bytes`               - Byte slice containing a numeric string.
+/// * `allow_negative_zero` - Parse a negative zero as zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ErrorCode;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_require_unsigned::<i32>(b"5", false), Ok(5));
+/// assert_eq!(lexical_core::parse_require_unsigned::<i32>(b"-5", false).map_err(|e| e.code), Err(ErrorCode::NegativeNotAllowed));
+/// assert_eq!(lexical_core::parse_require_unsigned::<i32>(b"-0", true), Ok(0));
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+/// [`FloatParser::require_unsigned`]: struct.FloatParser.html#method.require_unsigned
+#[inline]
+pub fn parse_require_unsigned<N: Integer + FromLexical>(bytes: &[u8], allow_negative_zero: bool)
+    -> Result<N>
+{
+    atoi::parse_require_unsigned(bytes, allow_negative_zero)
+}
+
+/// Parse float from a fixed-format field with an implied decimal point.
+///
+/// This method parses the entire field, returning an error if any
+/// invalid digits are found during parsing. The field contains an
+/// optional sign and integer digits, and the decimal point is shifted
+/// left by `implied_digits`, so `"12345"` with 2 implied digits is
+/// `123.45`. The scaling is exact, so the value is rounded once, as
+/// if the decimal point was present.
+///
+/// * `bytes`           - Byte slice containing the field.
+/// * `implied_digits`  - Number of implied fraction digits.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_implied_decimal::<f64>(b"12345", 2), Ok(123.45));
+/// assert_eq!(lexical_core::parse_implied_decimal::<f64>(b"5", 2), Ok(0.05));
+/// # }
+/// ```
+#[inline]
+pub fn parse_implied_decimal<F: FromFloatParser>(bytes: &[u8], implied_digits: usize)
+    -> Result<F>
+{
+    FloatParser::new().parse_implied_decimal(bytes, implied_digits)
+}
+
 /// Lossily parse number from string.
 ///
 /// This method parses the entire string, returning an error if
 /// any invalid digits are found during parsing. This parser is
 /// lossy, so numerical rounding may occur during parsing.
 ///
+/// With the `correct` feature, the lossy parser never uses the slow,
+/// arbitrary-precision algorithm, so the parsing time is bounded by the
+/// number of digits. In exchange, the parsed value may differ from the
+/// correctly-rounded value by up to 1 ULP, which only occurs for values
+/// close to halfway between two floats. Without the `correct` feature,
+/// the lossy and default parsers both use a fast, incorrect algorithm,
+/// and the error is not bounded.
+///
 /// * `bytes`   - Byte slice containing a numeric string.
 #[inline]
 pub fn parse_lossy<N: FromLexicalLossy>(bytes: &[u8])
@@ -399,6 +1002,73 @@ pub fn parse_partial_lossy_radix<N: FromLexicalLossy>(bytes: &[u8], radix: u8)
     N::from_lexical_partial_lossy_radix(bytes, radix)
 }
 
+/// Classify a numeric string with a custom radix.
+///
+/// This method classifies the string with the same rules as
+/// [`classify_number`].
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `radix`   - Radix for number decoding.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+///
+/// [`classify_number`]: fn.classify_number.html
+#[inline]
+#[cfg(feature = "radix")]
+pub fn classify_number_radix(bytes: &[u8], radix: u8)
+    -> NumberKind
+{
+    assert_radix!(radix);
+    atof::classify(bytes, radix.as_u32())
+}
+
+/// Parse the integer portion of a float from string with a custom radix.
+///
+/// This method parses the integer portion of the float with the same
+/// rules as [`parse_truncated_int`].
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `radix`   - Radix for number decoding.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+///
+/// [`parse_truncated_int`]: fn.parse_truncated_int.html
+#[inline]
+#[cfg(feature = "radix")]
+pub fn parse_truncated_int_radix(bytes: &[u8], radix: u8)
+    -> Result<(i64, usize)>
+{
+    assert_radix!(radix);
+    atof::parse_truncated(bytes, radix.as_u32())
+}
+
+/// Parse float from a fixed-format field with an implied radix point and a custom radix.
+///
+/// This method parses the field with the same rules as
+/// [`parse_implied_decimal`], and shifts the radix point left by
+/// `implied_digits` digits in the radix.
+///
+/// * `bytes`           - Byte slice containing the field.
+/// * `radix`           - Radix for number decoding.
+/// * `implied_digits`  - Number of implied fraction digits.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+///
+/// [`parse_implied_decimal`]: fn.parse_implied_decimal.html
+#[inline]
+#[cfg(feature = "radix")]
+pub fn parse_implied_decimal_radix<F: FromFloatParser>(bytes: &[u8], radix: u8, implied_digits: usize)
+    -> Result<F>
+{
+    FloatParser::new().radix(radix).parse_implied_decimal(bytes, implied_digits)
+}
+
 /// Parse number from string with a custom numerical format.
 ///
 /// This method parses the entire string, returning an error if
@@ -26,7 +26,6 @@ pub fn equal_to_slice(l: &[u8], r: &[u8])
 
 /// Check if left iter starts with right iter.
 #[inline]
-#[cfg(feature = "format")]
 pub fn starts_with_iter<'a, Iter1, Iter2>(mut l: Iter1, mut r: Iter2)
     -> (bool, Iter1)
     where Iter1: Iterator<Item=&'a u8>,
@@ -187,7 +186,6 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "format")]
     fn starts_with_test() {
         let w = b"Hello";
         let x = b"H";
@@ -20,6 +20,16 @@ macro_rules! assert_radix {
     ($radix:expr) => (assert!($radix.as_i32() >= 2 && $radix.as_i32() <= 36, "Numerical base must be from 2-36.");)
 }
 
+/// Return an `InvalidRadix` error if the radix is not in range [2, 36].
+#[cfg(feature = "radix")]
+macro_rules! check_radix {
+    ($radix:expr) => (
+        if $radix.as_i32() < 2 || $radix.as_i32() > 36 {
+            return Err($crate::util::ErrorCode::InvalidRadix.into());
+        }
+    )
+}
+
 // BUFFER
 
 /// Check the buffer has sufficient room for the output.
@@ -26,6 +26,7 @@
 //!     - `i`, consumes internal digit separators.
 //!     - `t`, consumes trailing digit separators.
 //!     - `c`, consumes consecutive digit separators.
+//!     - `u`, consumes Unicode spaces as digit separators.
 //!
 //! Consumers are named `consume_digits_x_separator`, where `x` represents
 //! the shorthand name of the consumer, in sorted order. For example,
@@ -80,6 +81,21 @@ fn is_digit_or_separator(c: u8, radix: u32, digit_separator: u8) -> bool {
     return is_digit(c, radix) || c == digit_separator
 }
 
+/// Unicode spaces separating groups of digits: no-break space, thin
+/// space and narrow no-break space.
+#[cfg(feature = "format")]
+const UNICODE_DIGIT_SEPARATORS: [&[u8]; 3] = [b"\xC2\xA0", b"\xE2\x80\x89", b"\xE2\x80\xAF"];
+
+// Get the length of the Unicode space at the start of the bytes, or 0.
+#[cfg(feature = "format")]
+#[inline(always)]
+fn unicode_separator_size(bytes: &[u8]) -> usize {
+    match UNICODE_DIGIT_SEPARATORS.iter().find(|s| bytes.starts_with(s)) {
+        Some(s) => s.len(),
+        None    => 0,
+    }
+}
+
 // Split buffer at index.
 #[inline(always)]
 fn split_at_index<'a>(digits: &'a [u8], index: usize)
@@ -148,6 +164,46 @@ pub(crate) fn consume_digits_i<'a>(digits: &'a [u8], radix: u32, digit_separator
     split_at_index(digits, index)
 }
 
+// Consume until a an invalid digit is found.
+// Consumes internal digit separators and Unicode spaces.
+#[inline]
+#[cfg(feature = "format")]
+pub(crate) fn consume_digits_iu<'a>(digits: &'a [u8], radix: u32, digit_separator: u8)
+    -> (&'a [u8], &'a [u8])
+{
+    // Consume all digits and internal digit separators, except for
+    // consecutive digit separators. Store the length of the previous
+    // digit separator, since Unicode spaces are multiple bytes.
+    let mut previous = 0;
+    let mut index = 0;
+    while index < digits.len() {
+        let c = index!(digits[index]);
+        if is_digit(c, radix) {
+            index += 1;
+            previous = 0;
+        } else if index == 0 || previous != 0 {
+            break;
+        } else if c == digit_separator {
+            index += 1;
+            previous = 1;
+        } else {
+            match unicode_separator_size(&index!(digits[index..])) {
+                0    => break,
+                size => {
+                    index += size;
+                    previous = size;
+                },
+            }
+        }
+    }
+
+    // We've gone too far if:
+    //      1). The last character was a digit separator.
+    index -= previous;
+
+    split_at_index(digits, index)
+}
+
 // Consume until a an invalid digit is found.
 // Consumes internal and consecutive digit separators.
 #[inline]
@@ -652,6 +708,15 @@ pub(crate) fn consume_integer_digits_separator<'a>(bytes: &'a [u8], radix: u32,
     }
 }
 
+// Consume digits with a digit separator or Unicode spaces in the integer component.
+#[inline]
+#[cfg(feature = "format")]
+pub(crate) fn consume_integer_digits_unicode_separator<'a>(bytes: &'a [u8], radix: u32, format: NumberFormat)
+    -> (&'a [u8], &'a [u8])
+{
+    consume_digits_iu(bytes, radix, format.digit_separator())
+}
+
 // Consume digits with a digit separator in the fraction component.
 #[inline]
 #[cfg(feature = "format")]
@@ -869,6 +934,23 @@ mod tests {
 
     }
 
+    #[cfg(feature = "format")]
+    #[test]
+    fn consume_digits_iu_test() {
+        assert_eq!(consume_digits_iu(b"123.45", 10, b' '), (&b"123"[..], &b".45"[..]));
+        assert_eq!(consume_digits_iu(b"4 5", 10, b' '), (&b"4 5"[..], &b""[..]));
+        assert_eq!(consume_digits_iu(b"4\xE2\x80\x895", 10, b' '), (&b"4\xE2\x80\x895"[..], &b""[..]));
+        assert_eq!(consume_digits_iu(b"4\xE2\x80\xAF5 6.7", 10, b' '), (&b"4\xE2\x80\xAF5 6"[..], &b".7"[..]));
+        assert_eq!(consume_digits_iu(b"\xE2\x80\x8945", 10, b' '), (&b""[..], &b"\xE2\x80\x8945"[..]));
+        assert_eq!(consume_digits_iu(b"4\xE2\x80\x89", 10, b' '), (&b"4"[..], &b"\xE2\x80\x89"[..]));
+        assert_eq!(consume_digits_iu(b"4\xE2\x80\x89.5", 10, b' '), (&b"4"[..], &b"\xE2\x80\x89.5"[..]));
+        assert_eq!(consume_digits_iu(b"4 \xE2\x80\x895", 10, b' '), (&b"4"[..], &b" \xE2\x80\x895"[..]));
+        assert_eq!(consume_digits_iu(b"4\xE2\x80\x89\xE2\x80\x895", 10, b' '), (&b"4"[..], &b"\xE2\x80\x89\xE2\x80\x895"[..]));
+        assert_eq!(consume_digits_iu(b"4\xC2\xA05 6", 10, b' '), (&b"4\xC2\xA05 6"[..], &b""[..]));
+        assert_eq!(consume_digits_iu(b"4\xE2\x80\x8A5", 10, b' '), (&b"4"[..], &b"\xE2\x80\x8A5"[..]));
+        assert_eq!(consume_digits_iu(b"4\xE2\x805", 10, b' '), (&b"4"[..], &b"\xE2\x805"[..]));
+    }
+
     #[cfg(feature = "format")]
     #[test]
     fn consume_digits_t_test() {
@@ -18,6 +18,39 @@
 /// const int32_t EMPTY_EXPONENT = -6;
 /// ```
 ///
+/// # Float Errors
+///
+/// Parsing a float returns the most specific error for each failure:
+///
+/// * `Empty`           - The input was empty, or only had a sign.
+/// * `InvalidSign`     - The sign was followed by another sign, such as
+///   `"--5"` or `"+-5"`. The index is that of the second sign.
+/// * `EmptyMantissa`   - The mantissa had no digits, such as `"e5"`.
+///   This is an error for every number format and for partial and lossy
+///   parsers: the exponent is never consumed without a mantissa, and is
+///   never applied to an implicit zero.
+/// * `LoneDot`         - The mantissa was only a decimal point, such as `"."` or `".e5"`.
+/// * `EmptyExponent`   - The exponent had no digits, such as `"1e"`.
+/// * `InvalidDigit`    - An invalid digit was found. For complete parsers,
+///   this includes trailing bytes after a valid float, and the index is
+///   that of the first trailing byte. Use `parse_with_kind` to
+///   distinguish trailing bytes from other errors.
+///
+/// Format-specific errors, such as `EmptyInteger`, take precedence when
+/// the number format enables the corresponding validation.
+///
+/// # Integer Errors
+///
+/// When parsing an integer overflows, `Overflow` (or `Underflow` for
+/// negative values) is returned with the index of the digit at which
+/// the value first overflowed, so `"4294967296"` parsed as a `u32`
+/// overflows at index 9. Overflow takes precedence over any later
+/// invalid digits.
+///
+/// Like floats, an integer with only a sign returns `Empty`, and a sign
+/// followed by another sign returns `InvalidSign` at the second sign.
+/// A `-` is not a sign for unsigned integers, so it is an `InvalidDigit`.
+///
 /// # Safety
 ///
 /// Assigning any value outside the range `[-6, -1]` to value of type
@@ -61,6 +94,32 @@ pub enum ErrorCode {
     ExponentWithoutFraction = -14,
     /// Integer had invalid leading zeros.
     InvalidLeadingZeros = -15,
+    /// Integer had digit separators between incorrectly-sized groups.
+    InvalidDigitGrouping = -16,
+    /// Parsed value was outside the valid range.
+    OutOfRange = -17,
+    /// List had more elements than the output buffer could hold.
+    TooManyElements = -18,
+    /// Parsed value was zero, but a non-zero value was required.
+    Zero = -19,
+    /// Parsed value was infinite or NaN, but a finite value was required.
+    NonFinite = -20,
+    /// Decimal point was found without any mantissa digits.
+    LoneDot = -21,
+    /// Denominator of a ratio was zero.
+    DivisionByZero = -22,
+    /// Significant digits did not fit in the output buffer.
+    TooManyDigits = -23,
+    /// Negative sign was found, but an unsigned value was required.
+    NegativeNotAllowed = -24,
+    /// Scratch storage was too small, the index is the required number of limbs.
+    ScratchTooSmall = -25,
+    /// Exponent notation is required, but was not found.
+    MissingExponent = -26,
+    /// A sign was followed by another sign.
+    InvalidSign = -27,
+    /// The radix was not in the range `[2, 36]`.
+    InvalidRadix = -28,
 
     // We may add additional variants later, so ensure that client matching
     // does not depend on exhaustive matching.
@@ -678,6 +678,8 @@
 //      ```
 
 use super::config;
+use super::error::ErrorCode;
+use super::result::ParseResult;
 
 cfg_if! {
 if #[cfg(not(feature = "format"))] {
@@ -700,6 +702,11 @@ if #[cfg(not(feature = "format"))] {
         pub fn digit_separator(&self) -> u8 {
             0
         }
+
+        #[inline]
+        pub fn decimal_point(&self) -> u8 {
+            b'.'
+        }
     }
 } else {
     // HELPERS
@@ -753,6 +760,18 @@ if #[cfg(not(feature = "format"))] {
         (flag >> 56) as u8
     }
 
+    /// Convert digit separator group size to flags.
+    #[inline]
+    const fn group_size_to_flags(size: u8) -> u64 {
+        ((size & 0xF) as u64) << 48
+    }
+
+    /// Extract digit separator group size from flags.
+    #[inline]
+    const fn group_size_from_flags(flag: u64) -> u8 {
+        ((flag >> 48) & 0xF) as u8
+    }
+
     // BITFLAGS
 
     bitflags! {
@@ -769,28 +788,29 @@ if #[cfg(not(feature = "format"))] {
         /// the parsing behavior of lexical, and the upper 8 bits set for the
         /// digit separator, allowing any valid ASCII character as a
         /// separator. The first 32-bits are reserved for non-digit separator
-        /// flags, bits 32-55 are reserved for digit separator flags, and
+        /// flags, bits 32-47 are reserved for digit separator flags, bits
+        /// 48-55 are reserved for the digit separator group size, and
         /// the last 8 bits for the digit separator.
         //
         /// ```text
         ///  0   1   2   3   4   5   6   7   8   9   0   1   2   3   4   5
         /// +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
-        /// |I/R|F/R|E/R|+/M|R/M|e/e|+/E|R/E|e/F|S/S|S/C|     RESERVED      |
+        /// |I/R|F/R|E/R|+/M|R/M|e/e|+/E|R/E|e/F|S/S|S/C|I/0|F/0|D/E|S/E|C/D|
         /// +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
         ///
         ///  16  17  18  19  20  21  22  23  24  25  26  27  28  29  30  31
         /// +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
-        /// |                           RESERVED                            |
+        /// |E/D|e/R|N/M|                     RESERVED                      |
         /// +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
         ///
         ///  32  33  34  35  36  37  38  39  40  41  42  43  44  45  46  47
         /// +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
-        /// |I/I|F/I|E/I|I/L|F/L|E/L|I/T|F/T|E/T|I/C|F/C|E/C|S/D| RESERVED  |
+        /// |I/I|F/I|E/I|I/L|F/L|E/L|I/T|F/T|E/T|I/C|F/C|E/C|S/D|I/G|RESERVED |
         /// +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
         ///
         ///  48  49  50  51  52  53  54  55  56  57  58  59  60  62  62  63
         /// +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
-        /// |           RESERVED            |        Digit Separator        |
+        /// |  Group Size   |   RESERVED    |        Digit Separator        |
         /// +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
         ///
         /// Where:
@@ -805,6 +825,14 @@ if #[cfg(not(feature = "format"))] {
         ///     e/F = No exponent without fraction.
         ///     S/S = No special (non-finite) values.
         ///     S/C = Case-sensitive special (non-finite) values.
+        ///     I/0 = No integer leading zeros.
+        ///     F/0 = No float leading zeros.
+        ///     D/E = D exponent notation.
+        ///     S/E = Exponent without notation.
+        ///     C/D = Comma decimal point.
+        ///     E/D = Exponent base 10.
+        ///     e/R = Required exponent notation.
+        ///     N/M = Normalized mantissa.
         ///     I/I = Integer internal digit separator.
         ///     F/I = Fraction internal digit separator.
         ///     E/I = Exponent internal digit separator.
@@ -818,6 +846,7 @@ if #[cfg(not(feature = "format"))] {
         ///     F/C = Fraction consecutive digit separator.
         ///     E/C = Exponent consecutive digit separator.
         ///     S/D = Special (non-finite) digit separator.
+        ///     I/G = Integer grouped digit separator.
         /// ```
         ///
         /// Note:
@@ -867,6 +896,12 @@ if #[cfg(not(feature = "format"))] {
         /// L: 'In_f'       // Special (non-finite) digit separator.
         /// M: '010'        // No integer leading zeros.
         /// N: '010.0'      // No float leading zeros.
+        /// O: '3.0d7'      // D exponent notation.
+        /// P: '3.0+7'      // Exponent without notation.
+        /// Q: '3,01'       // Comma decimal point.
+        /// R: '3.0^10'     // Exponent base 10 (in radix 15, `3.0*15^10`).
+        /// S: '3.0'        // Non-required exponent notation.
+        /// T: '30.0e7'     // Non-normalized mantissa.
         /// ```
         ///
         /// Currently Supported Programming and Data Languages:
@@ -934,11 +969,19 @@ if #[cfg(not(feature = "format"))] {
                 | Self::CASE_SENSITIVE_SPECIAL.bits
                 | Self::NO_INTEGER_LEADING_ZEROS.bits
                 | Self::NO_FLOAT_LEADING_ZEROS.bits
+                | Self::D_EXPONENT_NOTATION.bits
+                | Self::EXPONENT_WITHOUT_NOTATION.bits
+                | Self::COMMA_DECIMAL_POINT.bits
+                | Self::EXPONENT_BASE_10.bits
+                | Self::REQUIRED_EXPONENT_NOTATION.bits
+                | Self::NORMALIZED_MANTISSA.bits
                 | Self::INTERNAL_DIGIT_SEPARATOR.bits
                 | Self::LEADING_DIGIT_SEPARATOR.bits
                 | Self::TRAILING_DIGIT_SEPARATOR.bits
                 | Self::CONSECUTIVE_DIGIT_SEPARATOR.bits
                 | Self::SPECIAL_DIGIT_SEPARATOR.bits
+                | Self::INTEGER_GROUPED_DIGIT_SEPARATOR.bits
+                | Self::INTEGER_UNICODE_DIGIT_SEPARATOR.bits
             );
 
             /// Mask to extract the flag bits controlling interface parsing.
@@ -954,10 +997,17 @@ if #[cfg(not(feature = "format"))] {
                 | Self::REQUIRED_EXPONENT_SIGN.bits
                 | Self::NO_EXPONENT_WITHOUT_FRACTION.bits
                 | Self::NO_FLOAT_LEADING_ZEROS.bits
+                | Self::D_EXPONENT_NOTATION.bits
+                | Self::EXPONENT_WITHOUT_NOTATION.bits
+                | Self::COMMA_DECIMAL_POINT.bits
+                | Self::EXPONENT_BASE_10.bits
+                | Self::REQUIRED_EXPONENT_NOTATION.bits
+                | Self::NORMALIZED_MANTISSA.bits
                 | Self::INTERNAL_DIGIT_SEPARATOR.bits
                 | Self::LEADING_DIGIT_SEPARATOR.bits
                 | Self::TRAILING_DIGIT_SEPARATOR.bits
                 | Self::CONSECUTIVE_DIGIT_SEPARATOR.bits
+                | Self::INTEGER_UNICODE_DIGIT_SEPARATOR.bits
             );
 
             /// Mask to extract digit separator flags.
@@ -1004,6 +1054,10 @@ if #[cfg(not(feature = "format"))] {
                 | Self::NO_POSITIVE_EXPONENT_SIGN.bits
                 | Self::REQUIRED_EXPONENT_SIGN.bits
                 | Self::NO_EXPONENT_WITHOUT_FRACTION.bits
+                | Self::D_EXPONENT_NOTATION.bits
+                | Self::EXPONENT_WITHOUT_NOTATION.bits
+                | Self::EXPONENT_BASE_10.bits
+                | Self::REQUIRED_EXPONENT_NOTATION.bits
                 | Self::EXPONENT_INTERNAL_DIGIT_SEPARATOR.bits
                 | Self::EXPONENT_LEADING_DIGIT_SEPARATOR.bits
                 | Self::EXPONENT_TRAILING_DIGIT_SEPARATOR.bits
@@ -1104,6 +1158,58 @@ if #[cfg(not(feature = "format"))] {
             #[doc(hidden)]
             const NO_FLOAT_LEADING_ZEROS                = 0b0000000000000000000000000000000000000000000000000001000000000000;
 
+            /// The `d` and `D` characters are also allowed as exponent notation.
+            ///
+            /// This is used by FORTRAN to denote a double-precision exponent,
+            /// for example, `1.5D+03`.
+            #[doc(hidden)]
+            const D_EXPONENT_NOTATION                   = 0b0000000000000000000000000000000000000000000000000010000000000000;
+
+            /// An exponent sign without exponent notation starts the exponent.
+            ///
+            /// This is used by FORTRAN formatted output when the exponent
+            /// character is omitted, for example, `1.5+03` for `1.5e3`.
+            /// This is ambiguous with trailing data starting with a sign,
+            /// and therefore must be explicitly enabled.
+            #[doc(hidden)]
+            const EXPONENT_WITHOUT_NOTATION             = 0b0000000000000000000000000000000000000000000000000100000000000000;
+
+            /// A comma, rather than a period, separates the integer and fraction.
+            ///
+            /// This is used by most European locales, for example, `1234,5`
+            /// for `1234.5`. A period is then not a decimal point, and may
+            /// be used as a digit separator.
+            #[doc(hidden)]
+            const COMMA_DECIMAL_POINT                   = 0b0000000000000000000000000000000000000000000000001000000000000000;
+
+            /// Exponent digits are always decimal, regardless of the radix.
+            ///
+            /// By default, the exponent digits are in the same radix as the
+            /// mantissa, so `1^20` in radix 15 is `1*15^30`. With this flag,
+            /// the exponent digits are decimal, so `1^20` is `1*15^20`, for
+            /// interoperability with tools that always write decimal exponents.
+            /// The exponent is still a power of the radix. This has no effect
+            /// for decimal strings.
+            #[doc(hidden)]
+            const EXPONENT_BASE_10                      = 0b0000000000000000000000000000000000000000000000010000000000000000;
+
+            /// Exponent notation is required.
+            ///
+            /// This is the inverse of `NO_EXPONENT_NOTATION`: numbers
+            /// without an exponent, such as `1500` or `1.5`, are invalid.
+            /// Combine with `NORMALIZED_MANTISSA` to only accept normalized
+            /// scientific notation, such as `1.5e3`. Special values are
+            /// not affected.
+            #[doc(hidden)]
+            const REQUIRED_EXPONENT_NOTATION            = 0b0000000000000000000000000000000000000000000000100000000000000000;
+
+            /// Exactly one digit is required before the decimal point.
+            ///
+            /// The mantissa must be normalized, like in scientific notation,
+            /// so `1.5e3` is valid, but `15e2`, `15.0e2` and `.15e4` are not.
+            #[doc(hidden)]
+            const NORMALIZED_MANTISSA                   = 0b0000000000000000000000000000000000000000000001000000000000000000;
+
             // DIGIT SEPARATOR FLAGS & MASKS
 
             /// Digit separators are allowed between integer digits.
@@ -1190,6 +1296,27 @@ if #[cfg(not(feature = "format"))] {
             #[doc(hidden)]
             const SPECIAL_DIGIT_SEPARATOR               = 0b0000000000000000000100000000000000000000000000000000000000000000;
 
+            /// Integer digit separators must separate fixed-size groups of digits.
+            ///
+            /// The first group may have from 1 to the group size digits,
+            /// and every subsequent group must have exactly the group size
+            /// digits, for example, `1,234,567` with a group size of 3.
+            /// An integer without any digit separators is always valid.
+            #[doc(hidden)]
+            const INTEGER_GROUPED_DIGIT_SEPARATOR       = 0b0000000000000000001000000000000000000000000000000000000000000000;
+
+            /// Unicode spaces are also integer digit separators.
+            ///
+            /// The no-break space (U+00A0), thin space (U+2009) and narrow
+            /// no-break space (U+202F) separate groups of integer digits,
+            /// like the digit separator, for numbers such as `1 000 000.5`.
+            /// Only valid for floats with grouped integer digit separators,
+            /// and leading, trailing and consecutive digit separators are
+            /// never consumed. Set by the `european` format and by
+            /// `FloatParser::unicode_spaces`.
+            #[doc(hidden)]
+            const INTEGER_UNICODE_DIGIT_SEPARATOR       = 0b0000000000000000010000000000000000000000000000000000000000000000;
+
             // PRE-DEFINED
             //
             // Sample Format Shorthand:
@@ -1777,16 +1904,28 @@ if #[cfg(not(feature = "format"))] {
                 | Self::NO_SPECIAL.bits
             );
 
-            // FORTRAN LITERAL [013456MN]
+            // FORTRAN LITERAL [013456MNO]
             /// Float format for a FORTRAN literal floating-point number.
             const FORTRAN_LITERAL = (
                 Self::REQUIRED_EXPONENT_DIGITS.bits
                 | Self::NO_SPECIAL.bits
+                | Self::D_EXPONENT_NOTATION.bits
             );
 
-            // FORTRAN STRING [0134567MN]
+            // FORTRAN STRING [0134567MNO]
             /// Float format to parse a FORTRAN float from string.
-            const FORTRAN_STRING = Self::REQUIRED_EXPONENT_DIGITS.bits;
+            const FORTRAN_STRING = (
+                Self::REQUIRED_EXPONENT_DIGITS.bits
+                | Self::D_EXPONENT_NOTATION.bits
+            );
+
+            // FORTRAN LENIENT STRING [0134567MNOP]
+            /// Float format to parse a FORTRAN float from string, allowing exponents without notation.
+            const FORTRAN_LENIENT_STRING = (
+                Self::REQUIRED_EXPONENT_DIGITS.bits
+                | Self::D_EXPONENT_NOTATION.bits
+                | Self::EXPONENT_WITHOUT_NOTATION.bits
+            );
 
             // D LITERAL [0134569ABFGHIJKN-_]
             /// Float format for a D literal floating-point number.
@@ -2127,6 +2266,12 @@ if #[cfg(not(feature = "format"))] {
     check_subsequent_flags!(NO_SPECIAL, CASE_SENSITIVE_SPECIAL);
     check_subsequent_flags!(CASE_SENSITIVE_SPECIAL, NO_INTEGER_LEADING_ZEROS);
     check_subsequent_flags!(NO_INTEGER_LEADING_ZEROS, NO_FLOAT_LEADING_ZEROS);
+    check_subsequent_flags!(NO_FLOAT_LEADING_ZEROS, D_EXPONENT_NOTATION);
+    check_subsequent_flags!(D_EXPONENT_NOTATION, EXPONENT_WITHOUT_NOTATION);
+    check_subsequent_flags!(EXPONENT_WITHOUT_NOTATION, COMMA_DECIMAL_POINT);
+    check_subsequent_flags!(COMMA_DECIMAL_POINT, EXPONENT_BASE_10);
+    check_subsequent_flags!(EXPONENT_BASE_10, REQUIRED_EXPONENT_NOTATION);
+    check_subsequent_flags!(REQUIRED_EXPONENT_NOTATION, NORMALIZED_MANTISSA);
 
     // Digit separator flags.
     const_assert!(NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR.bits == 1 << 32);
@@ -2142,6 +2287,8 @@ if #[cfg(not(feature = "format"))] {
     check_subsequent_flags!(EXPONENT_LEADING_DIGIT_SEPARATOR, EXPONENT_TRAILING_DIGIT_SEPARATOR);
     check_subsequent_flags!(EXPONENT_TRAILING_DIGIT_SEPARATOR, EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR);
     check_subsequent_flags!(EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR, SPECIAL_DIGIT_SEPARATOR);
+    check_subsequent_flags!(SPECIAL_DIGIT_SEPARATOR, INTEGER_GROUPED_DIGIT_SEPARATOR);
+    check_subsequent_flags!(INTEGER_GROUPED_DIGIT_SEPARATOR, INTEGER_UNICODE_DIGIT_SEPARATOR);
 
     /// Add flag to flags
     macro_rules! add_flag {
@@ -2322,6 +2469,199 @@ if #[cfg(not(feature = "format"))] {
             Some(format)
         }
 
+        /// Compile grouped number format.
+        ///
+        /// The grouped number format is the standard number format,
+        /// except digit separators are allowed between groups of integer
+        /// digits, such as thousands separators, `1,234,567.89`. Digit
+        /// separators are not allowed in the fraction or exponent, nor
+        /// before or after the integer digits. The first group has from 1
+        /// to `group_size` digits, and every subsequent group must have
+        /// exactly `group_size` digits. Integers without any digit
+        /// separators are always valid.
+        ///
+        /// Grouped exponents are unusual, so digit separators are not
+        /// allowed in the exponent by default. To allow the same digit
+        /// separator between exponent digits, with the same validation
+        /// as the integer digits, add the `EXPONENT_INTERNAL_DIGIT_SEPARATOR`
+        /// flag: exponent digits are not grouped, however, leading,
+        /// trailing and consecutive digit separators are still invalid.
+        ///
+        /// * `digit_separator`                         - Character to separate digit groups.
+        /// * `group_size`                              - Number of digits in each group, from 1 to 15.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # extern crate lexical_core;
+        /// # use lexical_core::NumberFormat;
+        /// # pub fn main() {
+        /// let format = NumberFormat::grouped(b'_', 3).unwrap();
+        /// assert_eq!(lexical_core::parse_format::<f64>(b"1_234.5", format), Ok(1234.5));
+        /// assert!(lexical_core::parse_format::<f64>(b"1.5e1_0", format).is_err());
+        ///
+        /// let format = format | NumberFormat::EXPONENT_INTERNAL_DIGIT_SEPARATOR;
+        /// assert_eq!(lexical_core::parse_format::<f64>(b"1.5e1_0", format), Ok(1.5e10));
+        /// # }
+        /// ```
+        ///
+        /// Returns the value if it was able to compile the format,
+        /// otherwise, returns None.
+        pub fn grouped(digit_separator: u8, group_size: u8) -> Option<NumberFormat> {
+            if !is_valid_separator(digit_separator) || group_size == 0 || group_size > 0xF {
+                return None
+            }
+
+            let mut format = NumberFormat::STANDARD
+                | NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR
+                | NumberFormat::INTEGER_GROUPED_DIGIT_SEPARATOR;
+            format.bits |= digit_separator_to_flags(digit_separator);
+            format.bits |= group_size_to_flags(group_size);
+
+            Some(format)
+        }
+
+        /// Compile European number format.
+        ///
+        /// The European number format is the grouped number format,
+        /// with a comma as the decimal point and groups of 3 integer
+        /// digits, such as `1.234.567,89` or `1 234 567,89`. The exact
+        /// rules are:
+        ///
+        /// 1. A comma separates the integer and fraction, and a period is
+        ///     never a decimal point.
+        /// 2. Digit separators are only allowed between integer digits,
+        ///     and the first group has from 1 to 3 digits, while every
+        ///     subsequent group has exactly 3 digits.
+        /// 3. The digit separators are `digit_separator`, the no-break
+        ///     space (U+00A0), the thin space (U+2009) and the narrow
+        ///     no-break space (U+202F), which may be mixed.
+        /// 4. Integers without any digit separators are always valid.
+        /// 5. Otherwise, the format is identical to the standard format,
+        ///     so `1,5e3` is `1500.0`.
+        ///
+        /// The Unicode spaces are only digit separators for floats: the
+        /// integer parsers only accept `digit_separator`.
+        ///
+        /// * `digit_separator`                         - Character to separate digit groups.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # extern crate lexical_core;
+        /// # use lexical_core::NumberFormat;
+        /// # pub fn main() {
+        /// let format = NumberFormat::european(b'.').unwrap();
+        /// assert_eq!(lexical_core::parse_format::<f64>(b"1.234.567,89", format), Ok(1234567.89));
+        /// assert_eq!(lexical_core::parse_format::<f64>("1\u{A0}234\u{A0}567,89".as_bytes(), format), Ok(1234567.89));
+        /// assert!(lexical_core::parse_format::<f64>(b"1 234 567,89", format).is_err());
+        /// # }
+        /// ```
+        ///
+        /// Returns the value if it was able to compile the format,
+        /// otherwise, returns None.
+        pub fn european(digit_separator: u8) -> Option<NumberFormat> {
+            let format = NumberFormat::STANDARD
+                .with_decimal_point(b',')?
+                .with_grouping(digit_separator, 3)?;
+            Some(format | NumberFormat::INTEGER_UNICODE_DIGIT_SEPARATOR)
+        }
+
+        /// Set the character separating the integer and fraction.
+        ///
+        /// The decimal point must be a period or a comma, and must not
+        /// be the digit separator. All other flags are unchanged, so
+        /// it may be combined with [`with_grouping`] to build a format
+        /// such as `1.234.567,89`, like the [`european`] format without
+        /// the Unicode spaces. Since the digit separator cannot be the
+        /// decimal point, set the decimal point to a comma before
+        /// grouping digits with a period.
+        ///
+        /// * `decimal_point`                           - Character to separate the integer and fraction.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # extern crate lexical_core;
+        /// # use lexical_core::NumberFormat;
+        /// # pub fn main() {
+        /// let format = NumberFormat::STANDARD.with_decimal_point(b',').unwrap();
+        /// assert_eq!(lexical_core::parse_format::<f64>(b"1,5e3", format), Ok(1500.0));
+        /// assert!(lexical_core::parse_format::<f64>(b"1.5", format).is_err());
+        /// assert!(NumberFormat::STANDARD.with_decimal_point(b';').is_none());
+        /// # }
+        /// ```
+        ///
+        /// Returns the value if it was able to compile the format,
+        /// otherwise, returns None.
+        ///
+        /// [`with_grouping`]: #method.with_grouping
+        /// [`european`]: #method.european
+        pub fn with_decimal_point(self, decimal_point: u8) -> Option<NumberFormat> {
+            let is_valid = decimal_point == b'.' || decimal_point == b',';
+            if !is_valid || self.digit_separator() == decimal_point {
+                return None
+            }
+
+            let mut format = self;
+            format.set(NumberFormat::COMMA_DECIMAL_POINT, decimal_point == b',');
+
+            Some(format)
+        }
+
+        /// Set the digit separator between groups of integer digits.
+        ///
+        /// Digit separators are allowed between groups of integer digits,
+        /// with the same rules as the [`grouped`] format: the first group
+        /// has from 1 to `group_size` digits, and every subsequent group
+        /// must have exactly `group_size` digits. Any previous digit
+        /// separator and group size are replaced, and all other flags,
+        /// including the decimal point and exponent character, are
+        /// unchanged.
+        ///
+        /// The digit separator must not be the decimal point, however, a
+        /// period is a valid digit separator if the decimal point is a
+        /// comma, as set by [`with_decimal_point`].
+        ///
+        /// * `digit_separator`                         - Character to separate digit groups.
+        /// * `group_size`                              - Number of digits in each group, from 1 to 15.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # extern crate lexical_core;
+        /// # use lexical_core::NumberFormat;
+        /// # pub fn main() {
+        /// let format = NumberFormat::STANDARD
+        ///     .with_decimal_point(b',').unwrap()
+        ///     .with_grouping(b'.', 3).unwrap();
+        /// assert_eq!(lexical_core::parse_format::<f64>(b"1.234.567,89e2", format), Ok(123456789.0));
+        /// assert!(NumberFormat::STANDARD.with_grouping(b'.', 3).is_none());
+        /// # }
+        /// ```
+        ///
+        /// Returns the value if it was able to compile the format,
+        /// otherwise, returns None.
+        ///
+        /// [`grouped`]: #method.grouped
+        /// [`with_decimal_point`]: #method.with_decimal_point
+        pub fn with_grouping(self, digit_separator: u8, group_size: u8) -> Option<NumberFormat> {
+            let is_valid = is_valid_separator(digit_separator)
+                || (digit_separator == b'.' && self.comma_decimal_point());
+            if !is_valid || digit_separator == self.decimal_point() || group_size == 0 || group_size > 0xF {
+                return None
+            }
+
+            let mut format = self
+                | NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR
+                | NumberFormat::INTEGER_GROUPED_DIGIT_SEPARATOR;
+            format.bits &= !(digit_separator_to_flags(0xFF) | group_size_to_flags(0xF));
+            format.bits |= digit_separator_to_flags(digit_separator);
+            format.bits |= group_size_to_flags(group_size);
+
+            Some(format)
+        }
+
         /// Create float format directly from digit separator for unittests.
         #[cfg(test)]
         #[inline]
@@ -2431,6 +2771,51 @@ if #[cfg(not(feature = "format"))] {
             self.intersects(NumberFormat::NO_FLOAT_LEADING_ZEROS)
         }
 
+        /// Get if `d` and `D` are also allowed as exponent notation.
+        #[inline]
+        pub fn d_exponent_notation(self) -> bool {
+            self.intersects(NumberFormat::D_EXPONENT_NOTATION)
+        }
+
+        /// Get if an exponent sign without exponent notation starts the exponent.
+        #[inline]
+        pub fn exponent_without_notation(self) -> bool {
+            self.intersects(NumberFormat::EXPONENT_WITHOUT_NOTATION)
+        }
+
+        /// Get if a comma, rather than a period, separates the integer and fraction.
+        #[inline]
+        pub fn comma_decimal_point(self) -> bool {
+            self.intersects(NumberFormat::COMMA_DECIMAL_POINT)
+        }
+
+        /// Get if exponent digits are always decimal, regardless of the radix.
+        #[inline]
+        pub fn exponent_base_10(self) -> bool {
+            self.intersects(NumberFormat::EXPONENT_BASE_10)
+        }
+
+        /// Get if exponent notation is required.
+        #[inline]
+        pub fn required_exponent_notation(self) -> bool {
+            self.intersects(NumberFormat::REQUIRED_EXPONENT_NOTATION)
+        }
+
+        /// Get if exactly one digit is required before the decimal point.
+        #[inline]
+        pub fn normalized_mantissa(self) -> bool {
+            self.intersects(NumberFormat::NORMALIZED_MANTISSA)
+        }
+
+        /// Get the character separating the integer and fraction.
+        #[inline]
+        pub fn decimal_point(self) -> u8 {
+            match self.comma_decimal_point() {
+                true  => b',',
+                false => b'.',
+            }
+        }
+
         /// Get if digit separators are allowed between integer digits.
         #[inline]
         pub fn integer_internal_digit_separator(self) -> bool {
@@ -2532,6 +2917,59 @@ if #[cfg(not(feature = "format"))] {
         pub fn special_digit_separator(self) -> bool {
             self.intersects(NumberFormat::SPECIAL_DIGIT_SEPARATOR)
         }
+
+        /// Get if integer digit separators must separate fixed-size groups of digits.
+        #[inline]
+        pub fn integer_grouped_digit_separator(self) -> bool {
+            self.intersects(NumberFormat::INTEGER_GROUPED_DIGIT_SEPARATOR)
+        }
+
+        /// Get if Unicode spaces are also integer digit separators.
+        #[inline]
+        pub(crate) fn integer_unicode_digit_separator(self) -> bool {
+            self.intersects(NumberFormat::INTEGER_UNICODE_DIGIT_SEPARATOR)
+        }
+
+        /// Get the number of digits in each group separated by digit separators.
+        #[inline]
+        pub fn digit_separator_group_size(self) -> u8 {
+            group_size_from_flags(self.bits)
+        }
+
+        /// Validate the integer digits have correctly grouped digit separators.
+        ///
+        /// If any digit separators are present, the first group must have
+        /// from 1 to `group_size` digits, and every subsequent group must
+        /// have exactly `group_size` digits. Leading, trailing and consecutive
+        /// digit separators must be rejected by the caller, so every group
+        /// is non-empty. On failure, returns a pointer to the invalid group.
+        ///
+        /// If Unicode spaces are digit separators, every non-ASCII byte
+        /// is part of a Unicode space validated by the caller, so the
+        /// empty groups between the bytes of each space are skipped.
+        #[inline]
+        pub(crate) fn validate_grouping(self, integer: &[u8]) -> ParseResult<()> {
+            let digit_separator = self.digit_separator();
+            let group_size = self.digit_separator_group_size() as usize;
+            let unicode = self.integer_unicode_digit_separator();
+            let is_separator = | c: &u8 | *c == digit_separator || (unicode && !c.is_ascii());
+            if !integer.iter().any(is_separator) {
+                // No digit separators, the integer is not grouped.
+                return Ok(());
+            }
+
+            let mut groups = integer.split(is_separator)
+                .filter(|group| !unicode || !group.is_empty());
+            if groups.next().unwrap().len() > group_size {
+                return Err((ErrorCode::InvalidDigitGrouping, integer.as_ptr()));
+            }
+            for group in groups {
+                if group.len() != group_size {
+                    return Err((ErrorCode::InvalidDigitGrouping, group.as_ptr()));
+                }
+            }
+            Ok(())
+        }
     }
 
     // TESTS
@@ -2601,6 +3039,77 @@ if #[cfg(not(feature = "format"))] {
             assert_eq!(flags.special_digit_separator(), true);
         }
 
+        #[test]
+        fn test_grouped() {
+            let flags = NumberFormat::grouped(b',', 3).unwrap();
+            assert_eq!(flags.digit_separator(), b',');
+            assert_eq!(flags.digit_separator_group_size(), 3);
+            assert_eq!(flags.integer_grouped_digit_separator(), true);
+            assert_eq!(flags.integer_internal_digit_separator(), true);
+            assert_eq!(flags.fraction_internal_digit_separator(), false);
+            assert_eq!(flags.exponent_internal_digit_separator(), false);
+            assert_eq!(flags.leading_digit_separator(), false);
+            assert_eq!(flags.trailing_digit_separator(), false);
+            assert_eq!(flags.consecutive_digit_separator(), false);
+            assert_eq!(flags.flags() & NumberFormat::STANDARD, NumberFormat::STANDARD.flags());
+
+            assert_eq!(NumberFormat::grouped(b'\'', 4).unwrap().digit_separator_group_size(), 4);
+            assert!(NumberFormat::grouped(b',', 0).is_none());
+            assert!(NumberFormat::grouped(b',', 16).is_none());
+            assert!(NumberFormat::grouped(b'0', 3).is_none());
+        }
+
+        #[test]
+        fn test_european() {
+            let flags = NumberFormat::european(b'.').unwrap();
+            assert_eq!(flags.digit_separator(), b'.');
+            assert_eq!(flags.decimal_point(), b',');
+            assert_eq!(flags.comma_decimal_point(), true);
+            assert_eq!(flags.digit_separator_group_size(), 3);
+            assert_eq!(flags.integer_grouped_digit_separator(), true);
+            assert_eq!(flags.integer_unicode_digit_separator(), true);
+            assert_eq!(flags.fraction_internal_digit_separator(), false);
+
+            assert_eq!(NumberFormat::european(b' ').unwrap().digit_separator(), b' ');
+            assert_eq!(NumberFormat::standard().unwrap().decimal_point(), b'.');
+            assert!(NumberFormat::european(b',').is_none());
+            assert!(NumberFormat::european(b'0').is_none());
+            assert!(NumberFormat::european(0xA0).is_none());
+        }
+
+        #[test]
+        fn test_with_decimal_point() {
+            let flags = NumberFormat::STANDARD.with_decimal_point(b',').unwrap();
+            assert_eq!(flags.decimal_point(), b',');
+            assert_eq!(flags.flags(), NumberFormat::STANDARD | NumberFormat::COMMA_DECIMAL_POINT);
+            assert_eq!(flags.with_decimal_point(b'.').unwrap(), NumberFormat::STANDARD);
+
+            assert!(NumberFormat::STANDARD.with_decimal_point(b'_').is_none());
+            assert!(NumberFormat::grouped(b',', 3).unwrap().with_decimal_point(b',').is_none());
+            assert!(NumberFormat::european(b'.').unwrap().with_decimal_point(b'.').is_none());
+        }
+
+        #[test]
+        fn test_with_grouping() {
+            let flags = NumberFormat::STANDARD.with_grouping(b'_', 4).unwrap();
+            assert_eq!(flags, NumberFormat::grouped(b'_', 4).unwrap());
+            let flags = flags.with_grouping(b'\'', 3).unwrap();
+            assert_eq!(flags, NumberFormat::grouped(b'\'', 3).unwrap());
+
+            // The options compose to the European format, without the Unicode spaces.
+            let flags = NumberFormat::STANDARD
+                .with_decimal_point(b',').unwrap()
+                .with_grouping(b'.', 3).unwrap();
+            assert_eq!(flags | NumberFormat::INTEGER_UNICODE_DIGIT_SEPARATOR, NumberFormat::european(b'.').unwrap());
+            assert_eq!(flags.digit_separator(), b'.');
+            assert_eq!(flags.decimal_point(), b',');
+
+            assert!(NumberFormat::STANDARD.with_grouping(b'.', 3).is_none());
+            assert!(NumberFormat::european(b'.').unwrap().with_grouping(b',', 3).is_none());
+            assert!(NumberFormat::STANDARD.with_grouping(b'_', 0).is_none());
+            assert!(NumberFormat::STANDARD.with_grouping(b'_', 16).is_none());
+        }
+
         #[test]
         fn test_flags() {
             let flags = [
@@ -2617,6 +3126,12 @@ if #[cfg(not(feature = "format"))] {
                 NumberFormat::CASE_SENSITIVE_SPECIAL,
                 NumberFormat::NO_INTEGER_LEADING_ZEROS,
                 NumberFormat::NO_FLOAT_LEADING_ZEROS,
+                NumberFormat::D_EXPONENT_NOTATION,
+                NumberFormat::EXPONENT_WITHOUT_NOTATION,
+                NumberFormat::COMMA_DECIMAL_POINT,
+                NumberFormat::EXPONENT_BASE_10,
+                NumberFormat::REQUIRED_EXPONENT_NOTATION,
+                NumberFormat::NORMALIZED_MANTISSA,
                 NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR,
                 NumberFormat::FRACTION_INTERNAL_DIGIT_SEPARATOR,
                 NumberFormat::EXPONENT_INTERNAL_DIGIT_SEPARATOR,
@@ -2629,7 +3144,8 @@ if #[cfg(not(feature = "format"))] {
                 NumberFormat::INTEGER_CONSECUTIVE_DIGIT_SEPARATOR,
                 NumberFormat::FRACTION_CONSECUTIVE_DIGIT_SEPARATOR,
                 NumberFormat::EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR,
-                NumberFormat::SPECIAL_DIGIT_SEPARATOR
+                NumberFormat::SPECIAL_DIGIT_SEPARATOR,
+                NumberFormat::INTEGER_GROUPED_DIGIT_SEPARATOR
             ];
             for &flag in flags.iter() {
                 assert_eq!(flag.flags(), flag);
@@ -2719,6 +3235,7 @@ if #[cfg(not(feature = "format"))] {
                 NumberFormat::ELIXIR_STRING,
                 NumberFormat::FORTRAN_LITERAL,
                 NumberFormat::FORTRAN_STRING,
+                NumberFormat::FORTRAN_LENIENT_STRING,
                 NumberFormat::D_LITERAL,
                 NumberFormat::D_STRING,
                 NumberFormat::COFFEESCRIPT_LITERAL,
@@ -64,6 +64,19 @@ pub(crate) fn iterate_digits_ignore_separator<'a>(bytes: &'a [u8], digit_separat
     IteratorSeparator::new(bytes, digit_separator)
 }
 
+// Type for iteration with a digit separator and Unicode thin spaces.
+#[cfg(feature = "format")]
+pub(crate) type IteratorUnicodeSeparator<'a> = SkipUnicodeIterator<'a>;
+
+// Iterate while skipping digit separators and Unicode thin spaces.
+#[cfg(feature = "format")]
+#[inline(always)]
+pub(crate) fn iterate_digits_ignore_unicode_separator<'a>(bytes: &'a [u8], digit_separator: u8)
+    -> IteratorUnicodeSeparator<'a>
+{
+    IteratorUnicodeSeparator::new(bytes, digit_separator)
+}
+
 // TESTS
 // -----
 
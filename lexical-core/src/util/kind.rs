@@ -0,0 +1,173 @@
+//! Specific error kinds for diagnostics.
+
+use crate::lib::result::Result as StdResult;
+use super::error::{Error, ErrorCode};
+use super::traits::FromLexical;
+
+// PARSE ERROR KIND
+
+/// Specific kind of error for a complete parser, for diagnostics.
+///
+/// Unlike `ErrorCode`, this distinguishes trailing bytes after a valid
+/// number from an invalid digit within the number, and groups the
+/// errors for a number without digits. The kind is derived from where
+/// the partial parser stopped, so the same malformed input has the same
+/// kind for every numeric type:
+///
+/// * If the parser stopped before any digits (or special value), the
+///   kind is `NoDigits`, such as for `"x"`, `"-"` or `"-x"`.
+/// * If the parser parses digits from the bytes after the byte where
+///   it stopped, the number continues past the byte, so the kind is
+///   `InvalidDigit`, such as for `"1x5"` or `"1.2.3"`.
+/// * Otherwise, the kind is `TrailingBytes`, such as for `"1.5x"`.
+///
+/// Other errors, such as format-specific validation errors, are
+/// returned unchanged.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ParseErrorKind {
+    /// The input was empty.
+    EmptyInput,
+    /// The number had no digits, such as `"-"` or `"e5"`.
+    NoDigits,
+    /// The mantissa was only a decimal point, such as `"."` or `".e5"`.
+    LoneDot,
+    /// The exponent had no digits, such as `"1e"`.
+    EmptyExponent,
+    /// An invalid digit was found within the number, such as `"1x5"`.
+    InvalidDigit {
+        /// Index of the invalid digit.
+        index: usize
+    },
+    /// The value overflowed the numeric type, such as `"256"` for a `u8`.
+    Overflow,
+    /// The value underflowed the numeric type, such as `"-129"` for an `i8`.
+    Underflow,
+    /// A valid number was followed by other bytes, such as `"1.5x"`.
+    TrailingBytes {
+        /// Index of the first trailing byte.
+        index: usize
+    },
+    /// Any other error.
+    Other(Error),
+}
+
+// Check if a partial parser consumed any digits (or a special value).
+//
+// Partial integer parsers succeed without any digits, such as for
+// `"-x"`, stopping directly after the sign.
+perftools_inline!{
+fn has_digits(bytes: &[u8], processed: usize) -> bool {
+    let sign = match bytes[..processed].first() {
+        Some(&b'+') | Some(&b'-')   => 1,
+        _                           => 0,
+    };
+    processed > sign
+}}
+
+impl ParseErrorKind {
+    // Classify the byte where a partial parser stopped.
+    //
+    // `processed` is the number of bytes the parser consumed, so the
+    // byte at `processed` is the byte where it stopped.
+    perftools_inline!{
+    fn from_stop<N: FromLexical>(bytes: &[u8], processed: usize) -> ParseErrorKind {
+        if !has_digits(bytes, processed) {
+            return ParseErrorKind::NoDigits;
+        }
+
+        // Parse the bytes after the stopping byte, to check if the
+        // number continues past the byte.
+        let rest = &bytes[processed + 1..];
+        match N::from_lexical_partial(rest) {
+            Ok((_, count)) if has_digits(rest, count)   => ParseErrorKind::InvalidDigit { index: processed },
+            _                                           => ParseErrorKind::TrailingBytes { index: processed },
+        }
+    }}
+
+    // Classify an error from a partial parser.
+    perftools_inline!{
+    fn from_partial_error(bytes: &[u8], error: Error) -> ParseErrorKind {
+        match error.code {
+            ErrorCode::Empty if bytes.is_empty()    => ParseErrorKind::EmptyInput,
+            ErrorCode::Empty                        => ParseErrorKind::NoDigits,
+            ErrorCode::EmptyMantissa                => ParseErrorKind::NoDigits,
+            ErrorCode::LoneDot                      => ParseErrorKind::LoneDot,
+            ErrorCode::EmptyExponent                => ParseErrorKind::EmptyExponent,
+            ErrorCode::InvalidDigit                 => ParseErrorKind::InvalidDigit { index: error.index },
+            ErrorCode::Overflow                     => ParseErrorKind::Overflow,
+            ErrorCode::Underflow                    => ParseErrorKind::Underflow,
+            _                                       => ParseErrorKind::Other(error),
+        }
+    }}
+}
+
+/// Parse a number from the entire string, classifying any error.
+pub(crate) fn parse_with_kind<N: FromLexical>(bytes: &[u8])
+    -> StdResult<N, ParseErrorKind>
+{
+    match N::from_lexical_partial(bytes) {
+        Ok((value, processed)) if processed == bytes.len() => Ok(value),
+        Ok((_, processed)) => Err(ParseErrorKind::from_stop::<N>(bytes, processed)),
+        Err(error) => Err(ParseErrorKind::from_partial_error(bytes, error)),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_with_kind_test() {
+        assert_eq!(Ok(1.5), parse_with_kind::<f64>(b"1.5"));
+        assert_eq!(Err(ParseErrorKind::EmptyInput), parse_with_kind::<f64>(b""));
+        assert_eq!(Err(ParseErrorKind::NoDigits), parse_with_kind::<f64>(b"-"));
+        assert_eq!(Err(ParseErrorKind::NoDigits), parse_with_kind::<f64>(b"e5"));
+        assert_eq!(Err(ParseErrorKind::NoDigits), parse_with_kind::<f64>(b"x"));
+        assert_eq!(Err(ParseErrorKind::LoneDot), parse_with_kind::<f64>(b"."));
+        assert_eq!(Err(ParseErrorKind::LoneDot), parse_with_kind::<f64>(b"-.e5"));
+        assert_eq!(Err(ParseErrorKind::EmptyExponent), parse_with_kind::<f64>(b"1e"));
+        assert_eq!(Err(ParseErrorKind::EmptyExponent), parse_with_kind::<f64>(b"1e+"));
+        assert_eq!(Err(ParseErrorKind::InvalidDigit { index: 3 }), parse_with_kind::<f64>(b"1.2.3"));
+        assert_eq!(Err(ParseErrorKind::InvalidDigit { index: 1 }), parse_with_kind::<f64>(b"1x-5e2"));
+        assert_eq!(Err(ParseErrorKind::TrailingBytes { index: 3 }), parse_with_kind::<f64>(b"1.5x"));
+        assert_eq!(Err(ParseErrorKind::TrailingBytes { index: 3 }), parse_with_kind::<f64>(b"1.5 "));
+        assert_eq!(Err(ParseErrorKind::TrailingBytes { index: 3 }), parse_with_kind::<f64>(b"infx"));
+        assert_eq!(Err(ParseErrorKind::Other((ErrorCode::InvalidSign, 1).into())), parse_with_kind::<f64>(b"--1"));
+
+        assert_eq!(Ok(-5), parse_with_kind::<i32>(b"-5"));
+        assert_eq!(Err(ParseErrorKind::NoDigits), parse_with_kind::<i32>(b"+"));
+        assert_eq!(Err(ParseErrorKind::Overflow), parse_with_kind::<u8>(b"256"));
+        assert_eq!(Err(ParseErrorKind::Underflow), parse_with_kind::<i8>(b"-129"));
+        assert_eq!(Err(ParseErrorKind::NoDigits), parse_with_kind::<u8>(b"-1"));
+    }
+
+    #[test]
+    fn parse_with_kind_types_test() {
+        // The same malformed input has the same kind for every type.
+        macro_rules! check {
+            ($bytes:expr, $kind:expr) => {
+                assert_eq!(Err($kind), parse_with_kind::<f32>($bytes));
+                assert_eq!(Err($kind), parse_with_kind::<f64>($bytes));
+                assert_eq!(Err($kind), parse_with_kind::<u8>($bytes));
+                assert_eq!(Err($kind), parse_with_kind::<i32>($bytes));
+                assert_eq!(Err($kind), parse_with_kind::<u64>($bytes));
+            };
+        }
+
+        check!(b"", ParseErrorKind::EmptyInput);
+        check!(b"x", ParseErrorKind::NoDigits);
+        check!(b"-", ParseErrorKind::NoDigits);
+        check!(b"-x", ParseErrorKind::NoDigits);
+        check!(b"+x", ParseErrorKind::NoDigits);
+        check!(b"1x5", ParseErrorKind::InvalidDigit { index: 1 });
+        check!(b"1x+5", ParseErrorKind::InvalidDigit { index: 1 });
+        check!(b"1x+x", ParseErrorKind::TrailingBytes { index: 1 });
+        check!(b"+12,5", ParseErrorKind::InvalidDigit { index: 3 });
+        check!(b"12x", ParseErrorKind::TrailingBytes { index: 2 });
+        check!(b"12 ", ParseErrorKind::TrailingBytes { index: 2 });
+        check!(b"+1/x", ParseErrorKind::TrailingBytes { index: 2 });
+    }
+}
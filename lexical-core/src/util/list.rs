@@ -0,0 +1,204 @@
+//! Parse delimited lists of numbers.
+
+use super::error::{Error, ErrorCode};
+use super::result::Result;
+use super::traits::FromLexical;
+
+// Get the index of the first non-whitespace byte, starting from `index`.
+perftools_inline!{
+fn skip_whitespace(bytes: &[u8], index: usize) -> usize {
+    index + bytes[index..].iter().take_while(|c| c.is_ascii_whitespace()).count()
+}}
+
+/// Parse a comma-separated list of numbers, with optional brackets.
+///
+/// Parses lists such as `"[1.0, 2.5, 3.0]"` or `"1.0,2.5,3.0"`,
+/// writing each element to `values` and returning the number of
+/// elements parsed. Whitespace is allowed around the brackets and
+/// around each element.
+///
+/// On failure, the error index is the index of the first element that
+/// failed, not a position within the buffer, and all prior elements
+/// have been written to `values`.
+pub(crate) fn parse_list<N: FromLexical>(bytes: &[u8], values: &mut [N])
+    -> Result<usize>
+{
+    let error = | code, count | -> Result<usize> { Err((code, count).into()) };
+
+    let mut index = skip_whitespace(bytes, 0);
+    let bracketed = bytes.get(index) == Some(&b'[');
+    if bracketed {
+        index = skip_whitespace(bytes, index + 1);
+    }
+
+    // Parse the elements, if the list is not empty.
+    let mut count = 0;
+    let is_empty = match bracketed {
+        true  => bytes.get(index) == Some(&b']'),
+        false => index == bytes.len(),
+    };
+    if !is_empty {
+        loop {
+            if count == values.len() {
+                return error(ErrorCode::TooManyElements, count);
+            }
+            let (value, processed) = N::from_lexical_partial(&bytes[index..])
+                .map_err(|e| Error::from((e.code, count)))?;
+            values[count] = value;
+            count += 1;
+
+            // Each element must be followed by a separator or the end of the list.
+            index = skip_whitespace(bytes, index + processed);
+            match (bytes.get(index), bracketed) {
+                (Some(&b','), _)        => index = skip_whitespace(bytes, index + 1),
+                (Some(&b']'), true)     => break,
+                (None, false)           => break,
+                _                       => return error(ErrorCode::InvalidDigit, count - 1),
+            }
+        }
+    }
+
+    // Consume the closing bracket, and ensure nothing follows the list.
+    if bracketed {
+        index = skip_whitespace(bytes, index + 1);
+    }
+    match index == bytes.len() {
+        true  => Ok(count),
+        false => error(ErrorCode::InvalidDigit, count),
+    }
+}
+
+/// Parse a delimited list of unsigned, decimal 64-bit integers.
+///
+/// Each field must be one or more decimal digits, without a sign or
+/// whitespace. A single trailing delimiter is ignored, so `"1,2,"`
+/// parses 2 elements, and an empty buffer parses 0 elements. Any other
+/// empty field is an error with `ErrorCode::Empty`.
+///
+/// On failure, the error index is the index of the first field that
+/// failed, not a position within the buffer, and all prior fields
+/// have been written to `values`.
+pub(crate) fn parse_u64_list(bytes: &[u8], delimiter: u8, values: &mut [u64])
+    -> Result<usize>
+{
+    assert!(!delimiter.is_ascii_digit(), "Delimiter cannot be a digit.");
+    let error = | code, count | -> Result<usize> { Err((code, count).into()) };
+
+    let mut count = 0;
+    let mut index = 0;
+    while index < bytes.len() {
+        if count == values.len() {
+            return error(ErrorCode::TooManyElements, count);
+        }
+
+        // Accumulate the field with checked arithmetic, until the delimiter.
+        let start = index;
+        let mut value: u64 = 0;
+        while index < bytes.len() && bytes[index] != delimiter {
+            let digit = bytes[index].wrapping_sub(b'0');
+            if digit >= 10 {
+                return error(ErrorCode::InvalidDigit, count);
+            }
+            value = match value.checked_mul(10).and_then(|v| v.checked_add(digit as u64)) {
+                Some(v) => v,
+                None    => return error(ErrorCode::Overflow, count),
+            };
+            index += 1;
+        }
+        if index == start {
+            return error(ErrorCode::Empty, count);
+        }
+        values[count] = value;
+        count += 1;
+
+        // Skip the delimiter.
+        index += 1;
+    }
+
+    Ok(count)
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_list_test() {
+        let mut values = [0.0f64; 4];
+        assert_eq!(Ok(3), parse_list(b"[1.0, 2.5, 3.0]", &mut values));
+        assert_eq!(&values[..3], &[1.0, 2.5, 3.0]);
+        assert_eq!(Ok(3), parse_list(b"  [ -1.0 ,2.5,\t3e2 ]\n", &mut values));
+        assert_eq!(&values[..3], &[-1.0, 2.5, 300.0]);
+        assert_eq!(Ok(2), parse_list(b"4.5, 5.5", &mut values));
+        assert_eq!(&values[..2], &[4.5, 5.5]);
+        assert_eq!(Ok(1), parse_list(b"7", &mut values));
+        assert_eq!(values[0], 7.0);
+        assert_eq!(Ok(4), parse_list(b"[1,2,3,4]", &mut values));
+        assert_eq!(Ok(0), parse_list(b"[]", &mut values));
+        assert_eq!(Ok(0), parse_list(b" [ ] ", &mut values));
+        assert_eq!(Ok(0), parse_list(b"", &mut values));
+        assert_eq!(Ok(0), parse_list(b"  ", &mut values));
+
+        let mut values = [0i32; 2];
+        assert_eq!(Ok(2), parse_list(b"[-1, 2]", &mut values));
+        assert_eq!(values, [-1, 2]);
+    }
+
+    #[test]
+    fn parse_list_error_test() {
+        let mut values = [0.0f64; 4];
+        let err = | code, index | -> Result<usize> { Err((code, index).into()) };
+        assert_eq!(err(ErrorCode::InvalidDigit, 1), parse_list(b"[1.0, 2.5x, 3.0]", &mut values));
+        assert_eq!(values[0], 1.0);
+        assert_eq!(err(ErrorCode::EmptyMantissa, 2), parse_list(b"[1.0, 2.5, x]", &mut values));
+        assert_eq!(err(ErrorCode::EmptyMantissa, 1), parse_list(b"[1.0,, 3.0]", &mut values));
+        assert_eq!(err(ErrorCode::EmptyMantissa, 1), parse_list(b"[1.0,]", &mut values));
+        assert_eq!(err(ErrorCode::EmptyExponent, 0), parse_list(b"1e, 2", &mut values));
+        assert_eq!(err(ErrorCode::InvalidDigit, 1), parse_list(b"[1.0, 2.0", &mut values));
+        assert_eq!(err(ErrorCode::InvalidDigit, 1), parse_list(b"1.0, 2.0]", &mut values));
+        assert_eq!(err(ErrorCode::InvalidDigit, 0), parse_list(b"1.0 2.0", &mut values));
+        assert_eq!(err(ErrorCode::InvalidDigit, 2), parse_list(b"[1.0, 2.0] x", &mut values));
+        assert_eq!(err(ErrorCode::Empty, 0), parse_list(b"[", &mut values));
+        assert_eq!(err(ErrorCode::TooManyElements, 4), parse_list(b"[1, 2, 3, 4, 5]", &mut values));
+    }
+
+    #[test]
+    fn parse_u64_list_test() {
+        let mut values = [0u64; 4];
+        assert_eq!(Ok(3), parse_u64_list(b"1,23,456", b',', &mut values));
+        assert_eq!(&values[..3], &[1, 23, 456]);
+        assert_eq!(Ok(2), parse_u64_list(b"18446744073709551615|0", b'|', &mut values));
+        assert_eq!(&values[..2], &[18446744073709551615, 0]);
+        assert_eq!(Ok(2), parse_u64_list(b"7\t8\t", b'\t', &mut values));
+        assert_eq!(&values[..2], &[7, 8]);
+        assert_eq!(Ok(1), parse_u64_list(b"9", b',', &mut values));
+        assert_eq!(Ok(4), parse_u64_list(b"1,2,3,4,", b',', &mut values));
+        assert_eq!(Ok(0), parse_u64_list(b"", b',', &mut values));
+    }
+
+    #[test]
+    fn parse_u64_list_error_test() {
+        let mut values = [0u64; 4];
+        let err = | code, index | -> Result<usize> { Err((code, index).into()) };
+        assert_eq!(err(ErrorCode::InvalidDigit, 1), parse_u64_list(b"1,2x,3", b',', &mut values));
+        assert_eq!(values[0], 1);
+        assert_eq!(err(ErrorCode::InvalidDigit, 0), parse_u64_list(b"+1", b',', &mut values));
+        assert_eq!(err(ErrorCode::InvalidDigit, 1), parse_u64_list(b"1, 2", b',', &mut values));
+        assert_eq!(err(ErrorCode::Overflow, 1), parse_u64_list(b"1,18446744073709551616", b',', &mut values));
+        assert_eq!(err(ErrorCode::Empty, 0), parse_u64_list(b",", b',', &mut values));
+        assert_eq!(err(ErrorCode::Empty, 0), parse_u64_list(b",1", b',', &mut values));
+        assert_eq!(err(ErrorCode::Empty, 1), parse_u64_list(b"1,,2", b',', &mut values));
+        assert_eq!(err(ErrorCode::Empty, 1), parse_u64_list(b"1,,", b',', &mut values));
+        assert_eq!(err(ErrorCode::TooManyElements, 4), parse_u64_list(b"1,2,3,4,5", b',', &mut values));
+    }
+
+    #[test]
+    #[should_panic]
+    fn parse_u64_list_digit_delimiter_test() {
+        let mut values = [0u64; 4];
+        let _ = parse_u64_list(b"102", b'0', &mut values);
+    }
+}
@@ -28,6 +28,8 @@ mod div128;
 mod error;
 mod format;
 mod iterator;
+mod kind;
+mod list;
 mod mask;
 mod num;
 mod primitive;
@@ -36,6 +38,7 @@ mod result;
 mod rounding;
 mod sign;
 mod table;
+mod wrapper;
 
 #[cfg(feature = "format")]
 mod skip_value;
@@ -54,6 +57,7 @@ pub(crate) use self::cast::*;
 pub(crate) use self::consume::*;
 pub(crate) use self::div128::*;
 pub(crate) use self::iterator::*;
+pub(crate) use self::list::*;
 pub(crate) use self::mask::*;
 pub(crate) use self::primitive::*;
 pub(crate) use self::pow::*;
@@ -75,9 +79,12 @@ if #[cfg(feature = "correct")] {
 pub use self::config::*;
 pub use self::error::*;
 pub use self::format::*;
+pub use self::kind::*;
 pub use self::num::*;
 pub use self::result::*;
 pub use self::traits::*;
+pub use self::wrapper::*;
+pub use self::table::{finite_exponent_range, max_exact_integer};
 
 #[cfg(feature = "rounding")]
 pub use self::rounding::RoundingKind;
@@ -571,6 +571,15 @@ pub(crate) fn try_cast_or_min<U: Integer, T: TryCast<U>>(t: T) -> U {
     unwrap_or_min(TryCast::try_cast(t))
 }
 
+/// Try to convert to U, if not, return U::min_value() or U::max_value() from the sign.
+#[inline]
+pub(crate) fn try_cast_or_saturate<U: Integer, T: SignedInteger + TryCast<U>>(t: T) -> U {
+    match t < T::ZERO {
+        true  => try_cast_or_min(t),
+        false => try_cast_or_max(t),
+    }
+}
+
 // SIGNED INTEGER
 
 /// Defines a trait that supports signed integral operations.
@@ -1254,6 +1263,18 @@ mod tests {
         assert_eq!(x, u8::min_value());
     }
 
+    #[test]
+    fn try_cast_or_saturate_test() {
+        let x: i8 = try_cast_or_saturate(-5i16);
+        assert_eq!(x, -5);
+
+        let x: i8 = try_cast_or_saturate(i16::max_value());
+        assert_eq!(x, i8::max_value());
+
+        let x: i8 = try_cast_or_saturate(i16::min_value());
+        assert_eq!(x, i8::min_value());
+    }
+
     fn check_float<T: Float>(mut x: T) {
         // Copy, partialeq, partialord
         let _ = x;
@@ -217,6 +217,115 @@ mod tests {
     use crate::util::test::*;
     use super::*;
 
+    // EXACT POWERS
+    //
+    // The fast path requires `pow` and `pow2` be exact over the exponent
+    // limits, so the results are compared to the powers calculated as
+    // big integers, as little-endian 32-bit limbs. `pow` is only used
+    // for non-power-of-two radixes, and every power of a power-of-two
+    // radix is a power of 2, so `pow2` covers those radixes.
+
+    // Calculate `base^exponent` as a big integer.
+    fn big_pow(base: u32, exponent: i32) -> Vec<u32> {
+        let mut x = vec![1u32];
+        for _ in 0..exponent {
+            let mut carry = 0u64;
+            for limb in x.iter_mut() {
+                let value = (*limb as u64) * (base as u64) + carry;
+                *limb = value as u32;
+                carry = value >> 32;
+            }
+            if carry != 0 {
+                x.push(carry as u32);
+            }
+        }
+        x
+    }
+
+    // Convert a float that is an integer to a big integer.
+    // Every `f32` is exactly representable as an `f64`.
+    fn big_from_float(f: f64) -> Vec<u32> {
+        let bits = f.to_bits();
+        let biased = ((bits >> 52) & 0x7FF) as i32;
+        let mut mantissa = bits & 0xFFFFFFFFFFFFF;
+        if biased != 0 {
+            mantissa |= 1 << 52;
+        }
+        let shift = biased.max(1) - 1075;
+        if shift < 0 {
+            assert_eq!(mantissa & ((1 << -shift) - 1), 0, "{} is not an integer", f);
+            mantissa >>= -shift;
+        }
+
+        let mut x = vec![0u32; (shift.max(0) / 32) as usize];
+        let shift = shift.max(0) % 32;
+        let wide = (mantissa as u128) << shift;
+        x.extend_from_slice(&[wide as u32, (wide >> 32) as u32, (wide >> 64) as u32]);
+        while x.last() == Some(&0) {
+            x.pop();
+        }
+        x
+    }
+
+    #[test]
+    fn f32_pow_exact_test() {
+        for b in BASE_POWN.iter().cloned() {
+            let (_, max) = f32::exponent_limit(b);
+            for i in 0..max+1 {
+                let f = f32::pow(1.0, b, i);
+                assert_eq!(big_from_float(f as f64), big_pow(b, i), "{}^{}", b, i);
+                assert_eq!(f32::pow(1.0, b, -i), 1.0 / f, "{}^-{}", b, i);
+            }
+        }
+    }
+
+    #[test]
+    fn f64_pow_exact_test() {
+        for b in BASE_POWN.iter().cloned() {
+            let (_, max) = f64::exponent_limit(b);
+            for i in 0..max+1 {
+                let f = f64::pow(1.0, b, i);
+                assert_eq!(big_from_float(f), big_pow(b, i), "{}^{}", b, i);
+                assert_eq!(f64::pow(1.0, b, -i), 1.0 / f, "{}^-{}", b, i);
+            }
+        }
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn f32_pow2_exact_test() {
+        let (min, max) = f32::exponent_limit(2);
+        for i in 0..max+1 {
+            assert_eq!(big_from_float(f32::pow2(1.0, i) as f64), big_pow(2, i), "2^{}", i);
+        }
+        for i in min..0 {
+            // Negative powers of 2 are exact, so the reciprocal is a power of 2.
+            let f = f32::pow2(1.0, i);
+            let expected = match i < -126 {
+                true  => 1u32 << (i + 149),
+                false => ((i + 127) as u32) << 23,
+            };
+            assert_eq!(f.to_bits(), expected, "2^{}", i);
+        }
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn f64_pow2_exact_test() {
+        let (min, max) = f64::exponent_limit(2);
+        for i in 0..max+1 {
+            assert_eq!(big_from_float(f64::pow2(1.0, i)), big_pow(2, i), "2^{}", i);
+        }
+        for i in min..0 {
+            let f = f64::pow2(1.0, i);
+            let expected = match i < -1022 {
+                true  => 1u64 << (i + 1074),
+                false => ((i + 1023) as u64) << 52,
+            };
+            assert_eq!(f.to_bits(), expected, "2^{}", i);
+        }
+    }
+
     #[test]
     fn f32_iterative_pow_finite_test() {
         assert_relative_eq!(f32::iterative_pow_finite(1.0, 10, 38), 1e38, max_relative=1e-6);
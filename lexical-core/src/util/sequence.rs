@@ -252,6 +252,79 @@ impl<'a, T: 'a> ops::IndexMut<usize> for ReverseViewMut<'a, T> {
     }
 }
 
+// SLICE VEC
+
+/// Vector-like container over borrowed storage.
+///
+/// The elements past the length are unused, so the capacity is the
+/// length of the storage. Like `ArrayVec`, any operation that exceeds
+/// the capacity panics. The default container has no storage, and
+/// therefore has a capacity of 0.
+pub struct SliceVec<'a, T: 'a> {
+    data: &'a mut [T],
+    len: usize,
+}
+
+impl<'a, T: 'a> SliceVec<'a, T> {
+    /// Create new, empty vector using the storage.
+    #[inline]
+    pub fn from_storage(data: &'a mut [T]) -> SliceVec<'a, T> {
+        SliceVec { data, len: 0 }
+    }
+}
+
+impl<'a, T: 'a> Default for SliceVec<'a, T> {
+    #[inline]
+    fn default() -> SliceVec<'a, T> {
+        SliceVec::from_storage(Default::default())
+    }
+}
+
+impl<'a, T: 'a> ops::Deref for SliceVec<'a, T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.data[..self.len]
+    }
+}
+
+impl<'a, T: 'a> ops::DerefMut for SliceVec<'a, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.data[..self.len]
+    }
+}
+
+impl<'a, T: 'a + Copy> Extend<T> for SliceVec<'a, T> {
+    #[inline]
+    fn extend<I: iter::IntoIterator<Item=T>>(&mut self, iterable: I) {
+        for value in iterable {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a, T: 'a + Copy> iter::FromIterator<T> for SliceVec<'a, T> {
+    #[inline]
+    fn from_iter<I: iter::IntoIterator<Item=T>>(iterable: I) -> SliceVec<'a, T> {
+        let mut v = SliceVec::default();
+        v.extend(iterable);
+        v
+    }
+}
+
+impl<'a, T: 'a + Copy> iter::IntoIterator for SliceVec<'a, T> {
+    type Item = T;
+    type IntoIter = iter::Cloned<slice::Iter<'a, T>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let data: &'a [T] = self.data;
+        data[..self.len].iter().cloned()
+    }
+}
+
 // SLICELIKE
 
 /// Implied base trait for slice-like types.
@@ -310,6 +383,20 @@ impl<A: arrayvec::Array> SliceLikeImpl<A::Item> for arrayvec::ArrayVec<A> {
     }
 }
 
+impl<'a, T: 'a> SliceLikeImpl<T> for SliceVec<'a, T> {
+    // AS SLICE
+
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
 /// Collection that has a `contains()` method.
 pub trait Contains<T: PartialEq> {
     /// Check if slice contains element.
@@ -1089,6 +1176,88 @@ impl<A: arrayvec::Array> SliceLike<A::Item> for arrayvec::ArrayVec<A> {
     }
 }
 
+impl<'a, T: 'a> SliceLike<T> for SliceVec<'a, T> {
+    // GET
+
+    /// Get an immutable reference to item at index.
+    #[inline]
+    fn get<I: slice::SliceIndex<[T]>>(&self, index: I) -> Option<&I::Output> {
+        return self.as_slice().get(index);
+    }
+
+    /// Get an mutable reference to item at index.
+    #[inline]
+    fn get_mut<I: slice::SliceIndex<[T]>>(&mut self, index: I) -> Option<&mut I::Output> {
+        return self.as_mut_slice().get_mut(index);
+    }
+
+    /// Get an immutable reference to item at index.
+    #[inline]
+    unsafe fn get_unchecked<I: slice::SliceIndex<[T]>>(&self, index: I) -> &I::Output {
+        return self.as_slice().get_unchecked(index);
+    }
+
+    /// Get an mutable reference to item at index.
+    #[inline]
+    unsafe fn get_unchecked_mut<I: slice::SliceIndex<[T]>>(&mut self, index: I) -> &mut I::Output {
+        return self.as_mut_slice().get_unchecked_mut(index);
+    }
+
+    // INDEX
+
+    #[inline]
+    fn index<I: slice::SliceIndex<[T]>>(&self, index: I) -> &I::Output {
+        return self.as_slice().index(index);
+    }
+
+    #[inline]
+    fn index_mut<I: slice::SliceIndex<[T]>>(&mut self, index: I) -> &mut I::Output {
+        return self.as_mut_slice().index_mut(index);
+    }
+
+    // RGET
+
+    #[inline]
+    fn rget<I: RSliceIndex<[T]>>(&self, index: I)
+        -> Option<&I::Output>
+    {
+        index.rget(self.as_slice())
+    }
+
+    #[inline]
+    fn rget_mut<I: RSliceIndex<[T]>>(&mut self, index: I)
+        -> Option<&mut I::Output>
+    {
+        index.rget_mut(self.as_mut_slice())
+    }
+
+    #[inline]
+    unsafe fn rget_unchecked<I: RSliceIndex<[T]>>(&self, index: I)
+        -> &I::Output
+    {
+        index.rget_unchecked(self.as_slice())
+    }
+
+    #[inline]
+    unsafe fn rget_unchecked_mut<I: RSliceIndex<[T]>>(&mut self, index: I)
+        -> &mut I::Output
+    {
+        index.rget_unchecked_mut(self.as_mut_slice())
+    }
+
+    // RINDEX
+
+    #[inline]
+    fn rindex<I: RSliceIndex<[T]>>(&self, index: I) -> &I::Output {
+        index.rindex(self.as_slice())
+    }
+
+    #[inline]
+    fn rindex_mut<I: RSliceIndex<[T]>>(&mut self, index: I) -> &mut I::Output {
+        index.rindex_mut(self.as_mut_slice())
+    }
+}
+
 // VECTOR
 // ------
 
@@ -1318,11 +1487,111 @@ impl<A: arrayvec::Array> VecLike<A::Item> for arrayvec::ArrayVec<A> {
     }
 }
 
+impl<'a, T: 'a + Copy> VecLike<T> for SliceVec<'a, T> {
+    #[inline]
+    fn new() -> SliceVec<'a, T> {
+        SliceVec::default()
+    }
+
+    #[inline]
+    fn with_capacity(capacity: usize) -> SliceVec<'a, T> {
+        let mut v = SliceVec::default();
+        v.reserve(capacity);
+        v
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    #[inline]
+    fn reserve(&mut self, capacity: usize) {
+        assert!(self.len() + capacity <= self.capacity());
+    }
+
+    #[inline]
+    fn reserve_exact(&mut self, capacity: usize) {
+        assert!(self.len() + capacity <= self.capacity());
+    }
+
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        self.len = cmp::min(self.len, len);
+    }
+
+    #[inline]
+    unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity());
+        self.len = new_len;
+    }
+
+    #[inline]
+    fn swap_remove(&mut self, index: usize) -> T {
+        let value = self[index];
+        self.len -= 1;
+        self.data[index] = self.data[self.len];
+        value
+    }
+
+    #[inline]
+    fn insert(&mut self, index: usize, element: T) {
+        assert!(index <= self.len && self.len < self.capacity());
+        self.data.copy_within(index..self.len, index + 1);
+        self.data[index] = element;
+        self.len += 1;
+    }
+
+    #[inline]
+    fn remove(&mut self, index: usize) -> T {
+        let value = self[index];
+        self.data.copy_within(index + 1..self.len, index);
+        self.len -= 1;
+        value
+    }
+
+    #[inline]
+    fn push(&mut self, value: T) {
+        assert!(self.len < self.capacity());
+        self.data[self.len] = value;
+        self.len += 1;
+    }
+
+    #[inline]
+    fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            Some(self.data[self.len])
+        }
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    #[inline]
+    fn insert_many<I: iter::IntoIterator<Item=T>>(&mut self, index: usize, iterable: I) {
+        insert_many(self, index, iterable)
+    }
+
+    #[inline]
+    fn remove_many<R: ops::RangeBounds<usize>>(&mut self, range: R) {
+        remove_many(self, range)
+    }
+}
+
 // CLONEABLE VECLIKE
 
 /// Vector-like container with cloneable values.
 ///
-/// Implemented for Vec, SmallVec, and StackVec.
+/// Implemented for Vec, ArrayVec, and SliceVec.
 pub trait CloneableVecLike<T: Clone + Copy + Send>: Send + VecLike<T>
 {
     /// Extend collection from slice.
@@ -1369,6 +1638,28 @@ impl<A: arrayvec::Array> CloneableVecLike<A::Item> for arrayvec::ArrayVec<A>
     }
 }
 
+impl<'a, T: 'a> CloneableVecLike<T> for SliceVec<'a, T>
+    where T: Clone + Copy + Send
+{
+    #[inline]
+    fn extend_from_slice(&mut self, other: &[T]) {
+        let len = self.len + other.len();
+        self.data[self.len..len].copy_from_slice(other);
+        self.len = len;
+    }
+
+    #[inline]
+    fn resize(&mut self, len: usize, value: T) {
+        assert!(len <= self.capacity());
+        if len > self.len {
+            for x in self.data[self.len..len].iter_mut() {
+                *x = value;
+            }
+        }
+        self.len = len;
+    }
+}
+
 // TESTS
 // -----
 
@@ -1388,6 +1679,38 @@ mod tests {
         assert_eq!(&v[..], &[0, 5, 6, 1, 2, 3]);
     }
 
+    #[test]
+    fn slice_vec_test() {
+        let mut storage = [0u8; 8];
+        let mut v = SliceVec::from_storage(&mut storage);
+        assert_eq!(v.len(), 0);
+        assert_eq!(v.capacity(), 8);
+        v.extend_from_slice(&[0, 1, 2, 3]);
+        v.insert_many(1, [5, 6].iter().cloned());
+        assert_eq!(&v[..], &[0, 5, 6, 1, 2, 3]);
+        v.insert(0, 7);
+        assert_eq!(v.remove(1), 0);
+        assert_eq!(v.swap_remove(0), 7);
+        assert_eq!(v.pop(), Some(2));
+        assert_eq!(&v[..], &[3, 5, 6, 1]);
+        v.resize(6, 4);
+        assert_eq!(&v[..], &[3, 5, 6, 1, 4, 4]);
+        v.truncate(2);
+        assert_eq!(&v[..], &[3, 5]);
+        v.clear();
+        assert_eq!(v.pop(), None);
+        assert_eq!(SliceVec::<u8>::default().capacity(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_vec_overflow_test() {
+        let mut storage = [0u8; 2];
+        let mut v = SliceVec::from_storage(&mut storage);
+        v.extend_from_slice(&[0, 1]);
+        v.push(2);
+    }
+
     #[cfg(all(feature = "correct", feature = "radix"))]
     #[test]
     fn remove_many_test() {
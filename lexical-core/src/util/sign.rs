@@ -1,7 +1,9 @@
 //! Enumerations for the sign-bit of a number.
 
+use super::error::ErrorCode;
 use super::format::NumberFormat;
 use super::num::Number;
+use super::result::ParseResult;
 
 // ENUMERATION
 
@@ -130,12 +132,28 @@ pub(crate) fn parse_sign<'a, T>(bytes: &'a [u8], format: NumberFormat)
     return parse_sign_separator::<T>(bytes, format);
 }
 
+/// Validate the parsed sign is not followed by another sign.
+///
+/// A second sign, such as in `"--5"` or `"+-5"`, is an error at the
+/// second sign, rather than an invalid digit in the digits.
+#[inline(always)]
+pub(crate) fn validate_sign_sequence(bytes: &[u8], digits: &[u8])
+    -> ParseResult<()>
+{
+    let has_sign = bytes.as_ptr() != digits.as_ptr();
+    match digits.first() {
+        Some(&b'+') | Some(&b'-') if has_sign   => Err((ErrorCode::InvalidSign, digits.as_ptr())),
+        _                                       => Ok(())
+    }
+}
+
 // TESTS
 // -----
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::util::algorithm::distance;
     use crate::util::test::*;
 
     #[test]
@@ -154,6 +172,28 @@ mod tests {
         assert_eq!(parse_sign_no_separator::<u32>(b"-5", b'_'), (Sign::Positive, b!("-5")));
     }
 
+    #[test]
+    fn validate_sign_sequence_test() {
+        let validate = | bytes: &[u8] | {
+            let (_, digits) = parse_sign_no_separator::<i32>(bytes, b'_');
+            validate_sign_sequence(bytes, digits).map_err(|(c, p)| (c, distance(bytes.as_ptr(), p)))
+        };
+        assert_eq!(validate(b"5"), Ok(()));
+        assert_eq!(validate(b"+5"), Ok(()));
+        assert_eq!(validate(b"-5"), Ok(()));
+        assert_eq!(validate(b"-"), Ok(()));
+        assert_eq!(validate(b"--5"), Err((ErrorCode::InvalidSign, 1)));
+        assert_eq!(validate(b"++5"), Err((ErrorCode::InvalidSign, 1)));
+        assert_eq!(validate(b"+-5"), Err((ErrorCode::InvalidSign, 1)));
+        assert_eq!(validate(b"-+5"), Err((ErrorCode::InvalidSign, 1)));
+        assert_eq!(validate(b"-5-"), Ok(()));
+
+        // A negative sign is a digit for unsigned integers.
+        let bytes = b"-+5";
+        let (_, digits) = parse_sign_no_separator::<u32>(bytes, b'_');
+        assert_eq!(validate_sign_sequence(bytes, digits), Ok(()));
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn parse_sign_lc_separator_test() {
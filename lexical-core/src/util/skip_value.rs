@@ -2,6 +2,8 @@
 //!
 //! SkipValueIterator iterates over a slice, returning all values
 //! except for those matching the provided skip value.
+//! SkipUnicodeIterator also skips all non-ASCII bytes, such as the
+//! bytes of Unicode thin spaces.
 //!
 //! Example
 //! -------
@@ -77,6 +79,67 @@ impl<'a, T: 'a + PartialEq> AsPtrIterator<'a, T> for SkipValueIterator<'a, T> {
     }
 }
 
+/// Byte slice iterator that skips a given value and all non-ASCII bytes.
+pub(crate) struct SkipUnicodeIterator<'a> {
+    /// Slice iterator to wrap.
+    iter: slice::Iter<'a, u8>,
+    /// Value to skip.
+    skip: u8
+}
+
+impl<'a> SkipUnicodeIterator<'a> {
+    #[inline]
+    pub(crate) fn new(slc: &'a [u8], skip: u8) -> Self {
+        SkipUnicodeIterator {
+            iter: slc.iter(),
+            skip: skip
+        }
+    }
+}
+
+impl<'a> Clone for SkipUnicodeIterator<'a> {
+    #[inline]
+    fn clone(&self) -> Self {
+        SkipUnicodeIterator {
+            iter: self.iter.clone(),
+            skip: self.skip
+        }
+    }
+}
+
+impl<'a> Iterator for SkipUnicodeIterator<'a> {
+    type Item = &'a u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let value = self.iter.next()?;
+            if *value != self.skip && value.is_ascii() {
+                return Some(value);
+            }
+        }
+    }
+}
+
+impl<'a> ConsumedIterator for SkipUnicodeIterator<'a> {
+    // Preconditions: The iterator cannot end with skipped characters.
+    #[inline]
+    fn consumed(&self) -> bool {
+        debug_assert!(match self.iter.as_slice().last() {
+            Some(&c) => c != self.skip && c.is_ascii(),
+            None     => true,
+        });
+        self.iter.len() == 0
+    }
+}
+
+impl<'a> AsPtrIterator<'a, u8> for SkipUnicodeIterator<'a> {
+    #[inline]
+    fn as_ptr(&self) -> *const u8 {
+        self.iter.as_slice().as_ptr()
+    }
+}
+
 // TESTS
 // -----
 
@@ -96,4 +159,13 @@ mod tests {
         let iter = SkipValueIterator::new(slc, 1);
         assert!(iter.eq([2, 5, 2, 6, 7].iter()));
     }
+
+    #[test]
+    fn skip_unicode_test() {
+        let iter = SkipUnicodeIterator::new(b"1 000\xE2\x80\x89000", b' ');
+        assert!(iter.eq(b"1000000".iter()));
+
+        let iter = SkipUnicodeIterator::new(b"1_000\xE2\x80\xAF000", b' ');
+        assert!(iter.eq(b"1_000000".iter()));
+    }
 }
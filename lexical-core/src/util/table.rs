@@ -292,6 +292,116 @@ impl ExactExponent for f64 {
     }
 }
 
+// EXACT INTEGER
+
+/// Get the largest integer exactly representable as a float, for a radix.
+///
+/// Every integer from 0 to the returned value, inclusive, is exactly
+/// representable by the float, which is `2^(MANTISSA_SIZE+1)`, or
+/// `2^24` for `f32` and `2^53` for `f64`. Integers above the bound may
+/// not be exactly representable, so it may be used to decide whether to
+/// store a parsed value as an integer or as a float.
+///
+/// Since the float is binary, the bound is the same for every radix.
+/// It is distinct from the exact exponent limits of the radix used by
+/// the parsers: integral powers of the radix up to `radix^max`, where
+/// `(min, max)` is the `exponent_limit` of the radix, are exactly
+/// representable even above the bound, like `10^22` for `f64`, but the
+/// integers between them are not. The parsers instead bound the digits
+/// shifted from the exponent to the mantissa, the `mantissa_limit` of
+/// the radix, which is the largest number of digits with
+/// `radix^mantissa_limit` below the bound.
+///
+/// * `radix`   - Radix of the parsed integers.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`, or is not 10
+/// without the `radix` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::max_exact_integer;
+/// # pub fn main() {
+/// assert_eq!(max_exact_integer::<f32>(10), 16777216);
+/// assert_eq!(max_exact_integer::<f64>(10), 9007199254740992);
+/// # }
+/// ```
+#[inline]
+pub fn max_exact_integer<F: Float>(radix: u8) -> u64 {
+    #[cfg(feature = "radix")]
+    assert_radix!(radix);
+
+    #[cfg(not(feature = "radix"))]
+    assert!(radix == 10, "Numerical base must be 10.");
+
+    1 << (F::MANTISSA_SIZE + 1)
+}
+
+// FINITE EXPONENT
+
+/// Get the range of exponents for which every mantissa gives a finite, non-zero float.
+///
+/// Returns the inclusive range `(min, max)` of exponents `e` for which
+/// any value `m * radix^e`, with a mantissa `m` in `[1, radix)`, is
+/// parsed to a finite, non-zero float. Denormal floats are included,
+/// so for `f64` and a radix of 10, the range is `(-323, 307)`: `1e-323`
+/// is the smallest power of 10 above half the smallest denormal float,
+/// and `9.99...e307` is below `f64::MAX`, while `9.99...e308` is not.
+///
+/// Values outside the range may still be finite and non-zero, such as
+/// `1e308`, but not for every mantissa. The range is much wider than
+/// the exact exponent limits used by the parsers' fast path, such as
+/// `(-22, 22)` for `f64` and a radix of 10, since values in the range
+/// may be rounded.
+///
+/// * `radix`   - Radix of the mantissa and exponent.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`, or is not 10
+/// without the `radix` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::finite_exponent_range;
+/// # pub fn main() {
+/// assert_eq!(finite_exponent_range::<f32>(10), (-45, 37));
+/// assert_eq!(finite_exponent_range::<f64>(10), (-323, 307));
+/// # }
+/// ```
+#[inline]
+pub fn finite_exponent_range<F: Float>(radix: u8) -> (i32, i32) {
+    #[cfg(feature = "radix")]
+    assert_radix!(radix);
+
+    #[cfg(not(feature = "radix"))]
+    assert!(radix == 10, "Numerical base must be 10.");
+
+    // Values round to infinity at the halfway point below `2^max`, and
+    // to zero at the halfway point of the smallest denormal, `2^min`.
+    let max = F::MAX_EXPONENT + F::MANTISSA_SIZE;
+    let min = F::DENORMAL_EXPONENT - 1;
+    let radix = radix.as_u32();
+    if radix.is_power_of_two() {
+        // The powers of the radix are powers of two, so use exact
+        // integer arithmetic, since the bounds may be exact.
+        let bits = radix.trailing_zeros() as i32;
+        (1 - (bits - 1 - min) / bits, (max - 1) / bits - 1)
+    } else {
+        // The powers of the radix are never within 0.0002 bits of
+        // the bounds, so the float logarithm is accurate enough.
+        let log2 = Float::ln(radix as f64) / Float::ln(2.0f64);
+        let lower = Float::floor(min as f64 / log2) as i32 + 1;
+        let upper = Float::floor(max as f64 / log2) as i32 - 1;
+        (lower, upper)
+    }
+}
+
 // Conditionally compile the radix POWI tables.
 // These tables contain all the values that can be exactly represented
 // by a given float of a certain size.
@@ -3223,3 +3333,89 @@ impl TablePower for f64 {
 }
 
 }}   // cfg_if
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_exact_integer_test() {
+        assert_eq!(max_exact_integer::<f32>(10), 1 << 24);
+        assert_eq!(max_exact_integer::<f64>(10), 1 << 53);
+
+        // The bound is exact, and the next integer is not representable.
+        let max = max_exact_integer::<f32>(10);
+        assert_eq!(max as f32 as u64, max);
+        assert_eq!((max + 1) as f32 as u64, max);
+        let max = max_exact_integer::<f64>(10);
+        assert_eq!(max as f64 as u64, max);
+        assert_eq!((max + 1) as f64 as u64, max);
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn max_exact_integer_radix_test() {
+        for radix in 2..=36u8 {
+            let radix_u128 = radix as u128;
+            let max = max_exact_integer::<f32>(radix) as u128;
+            let limit = f32::mantissa_limit(radix) as u32;
+            assert!(radix_u128.pow(limit) < max && max <= radix_u128.pow(limit + 1));
+
+            let max = max_exact_integer::<f64>(radix) as u128;
+            let limit = f64::mantissa_limit(radix) as u32;
+            assert!(radix_u128.pow(limit) < max && max <= radix_u128.pow(limit + 1));
+        }
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    #[should_panic(expected = "Numerical base must be from 2-36.")]
+    fn max_exact_integer_radix_37_test() {
+        max_exact_integer::<f64>(37);
+    }
+
+    #[test]
+    fn finite_exponent_range_test() {
+        assert_eq!(finite_exponent_range::<f32>(10), (-45, 37));
+        assert_eq!(finite_exponent_range::<f64>(10), (-323, 307));
+    }
+
+    #[cfg(feature = "correct")]
+    #[test]
+    fn finite_exponent_range_parse_test() {
+        // The bounds are finite and non-zero for every mantissa.
+        assert_eq!(Ok(f32::MAX), f32::from_lexical(b"3.4028234e38"));
+        assert_eq!(Ok(f32::INFINITY), f32::from_lexical(b"3.5e38"));
+        assert!(f32::from_lexical(b"1e-45").unwrap() > 0.0);
+        assert_eq!(Ok(0.0), f32::from_lexical(b"6.9e-46"));
+        assert!(f64::from_lexical(b"9.999999999999999999e307").unwrap().is_finite());
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical(b"9.9e308"));
+        assert!(f64::from_lexical(b"1e-323").unwrap() > 0.0);
+        assert_eq!(Ok(0.0), f64::from_lexical(b"9.9e-325"));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn finite_exponent_range_radix_test() {
+        assert_eq!(finite_exponent_range::<f32>(2), (-149, 126));
+        assert_eq!(finite_exponent_range::<f32>(3), (-94, 79));
+        assert_eq!(finite_exponent_range::<f32>(32), (-29, 24));
+        assert_eq!(finite_exponent_range::<f32>(36), (-29, 23));
+        assert_eq!(finite_exponent_range::<f64>(2), (-1074, 1022));
+        assert_eq!(finite_exponent_range::<f64>(3), (-678, 645));
+        assert_eq!(finite_exponent_range::<f64>(8), (-358, 340));
+        assert_eq!(finite_exponent_range::<f64>(16), (-268, 254));
+        assert_eq!(finite_exponent_range::<f64>(35), (-209, 198));
+        assert_eq!(finite_exponent_range::<f64>(36), (-207, 197));
+
+        // The ranges contain the exact exponent limits.
+        for radix in 2..=36u8 {
+            let (min, max) = finite_exponent_range::<f64>(radix);
+            let (exact_min, exact_max) = f64::exponent_limit(radix);
+            assert!(min <= exact_min && exact_max <= max + 1);
+        }
+    }
+}
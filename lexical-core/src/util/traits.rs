@@ -65,9 +65,8 @@ pub trait FromLexical: Number {
     /// * `bytes`   - Slice containing a numeric string.
     /// * `radix`   - Radix for the number parsing.
     ///
-    /// # Panics
-    ///
-    /// Panics if the radix is not in the range `[2, 36]`.
+    /// Returns an `InvalidRadix` error if the radix is not in the
+    /// range `[2, 36]`.
     #[cfg(feature = "radix")]
     fn from_lexical_radix(bytes: &[u8], radix: u8) -> Result<Self>;
 
@@ -84,9 +83,8 @@ pub trait FromLexical: Number {
     /// * `bytes`   - Slice containing a numeric string.
     /// * `radix`   - Radix for the number parsing.
     ///
-    /// # Panics
-    ///
-    /// Panics if the radix is not in the range `[2, 36]`.
+    /// Returns an `InvalidRadix` error if the radix is not in the
+    /// range `[2, 36]`.
     #[cfg(feature = "radix")]
     fn from_lexical_partial_radix(bytes: &[u8], radix: u8) -> Result<(Self, usize)>;
 }
@@ -94,7 +92,10 @@ pub trait FromLexical: Number {
 // Implement FromLexical for numeric type.
 macro_rules! from_lexical {
     ($cb:expr, $t:ty) => (
-        impl FromLexical for $t {
+        from_lexical!(FromLexical, $cb, $t);
+    );
+    ($trait:ident, $cb:expr, $t:ty) => (
+        impl $trait for $t {
             #[inline]
             fn from_lexical(bytes: &[u8]) -> Result<$t>
             {
@@ -111,6 +112,7 @@ macro_rules! from_lexical {
             #[inline]
             fn from_lexical_radix(bytes: &[u8], radix: u8) -> Result<$t>
             {
+                check_radix!(radix);
                 to_complete!($cb, bytes, radix.as_u32())
             }
 
@@ -118,15 +120,80 @@ macro_rules! from_lexical {
             #[inline]
             fn from_lexical_partial_radix(bytes: &[u8], radix: u8) -> Result<($t, usize)>
             {
+                check_radix!(radix);
                 $cb(bytes, radix.as_u32())
             }
         }
     )
 }
 
+// FROM LEXICAL NON-ZERO
+
+/// Trait for non-zero integer types that can be parsed from bytes.
+///
+/// This is implemented for the `NonZero*` integers, which parse the
+/// underlying integer, and fail to parse zero with `ErrorCode::Zero`
+/// at the start of the string.
+pub trait FromLexicalNonZero: Sized {
+    /// Checked parser for a string-to-number conversion.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing, or if the value
+    /// is zero.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    fn from_lexical(bytes: &[u8]) -> Result<Self>;
+
+    /// Checked parser for a string-to-number conversion.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed value until that point, or an error if the value
+    /// is zero.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    fn from_lexical_partial(bytes: &[u8]) -> Result<(Self, usize)>;
+
+    /// Checked parser for a string-to-number conversion.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing, or if the value
+    /// is zero.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `radix`   - Radix for the number parsing.
+    ///
+    /// Returns an `InvalidRadix` error if the radix is not in the
+    /// range `[2, 36]`.
+    #[cfg(feature = "radix")]
+    fn from_lexical_radix(bytes: &[u8], radix: u8) -> Result<Self>;
+
+    /// Checked parser for a string-to-number conversion.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed value until that point, or an error if the value
+    /// is zero.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `radix`   - Radix for the number parsing.
+    ///
+    /// Returns an `InvalidRadix` error if the radix is not in the
+    /// range `[2, 36]`.
+    #[cfg(feature = "radix")]
+    fn from_lexical_partial_radix(bytes: &[u8], radix: u8) -> Result<(Self, usize)>;
+}
+
 // FROM LEXICAL LOSSY
 
 /// Trait for floating-point types that can be parsed using lossy algorithms from bytes.
+///
+/// With the `correct` feature, the lossy algorithms use the fast and
+/// moderate paths, and return the moderate path approximation rather
+/// than fall back to the slow path, so the result is within 1 ULP of
+/// the correctly-rounded value. Without the `correct` feature, the lossy
+/// algorithms are the fast, incorrect algorithms used by default, and
+/// the error is not bounded.
 pub trait FromLexicalLossy: FromLexical {
     /// Lossy, checked parser for a string-to-number conversion.
     ///
@@ -166,9 +233,8 @@ pub trait FromLexicalLossy: FromLexical {
     /// * `bytes`   - Slice containing a numeric string.
     /// * `radix`   - Radix for the number parsing.
     ///
-    /// # Panics
-    ///
-    /// Panics if the radix is not in the range `[2, 36]`.
+    /// Returns an `InvalidRadix` error if the radix is not in the
+    /// range `[2, 36]`.
     #[cfg(feature = "radix")]
     fn from_lexical_lossy_radix(bytes: &[u8], radix: u8) -> Result<Self>;
 
@@ -186,9 +252,8 @@ pub trait FromLexicalLossy: FromLexical {
     /// * `bytes`   - Slice containing a numeric string.
     /// * `radix`   - Radix for the number parsing.
     ///
-    /// # Panics
-    ///
-    /// Panics if the radix is not in the range `[2, 36]`.
+    /// Returns an `InvalidRadix` error if the radix is not in the
+    /// range `[2, 36]`.
     #[cfg(feature = "radix")]
     fn from_lexical_partial_lossy_radix(bytes: &[u8], radix: u8) -> Result<(Self, usize)>;
 }
@@ -213,6 +278,7 @@ macro_rules! from_lexical_lossy {
             #[inline]
             fn from_lexical_lossy_radix(bytes: &[u8], radix: u8) -> Result<$t>
             {
+                check_radix!(radix);
                 to_complete!($cb, bytes, radix.as_u32())
             }
 
@@ -220,6 +286,7 @@ macro_rules! from_lexical_lossy {
             #[inline]
             fn from_lexical_partial_lossy_radix(bytes: &[u8], radix: u8) -> Result<($t, usize)>
             {
+                check_radix!(radix);
                 $cb(bytes, radix.as_u32())
             }
         }
@@ -275,9 +342,8 @@ pub trait FromLexicalFormat: FromLexical {
     /// * `radix`   - Radix for the number parsing.
     /// * `format`  - Numerical format.
     ///
-    /// # Panics
-    ///
-    /// Panics if the radix is not in the range `[2, 36]`.
+    /// Returns an `InvalidRadix` error if the radix is not in the
+    /// range `[2, 36]`.
     #[cfg(feature = "radix")]
     fn from_lexical_format_radix(bytes: &[u8], radix: u8, format: NumberFormat) -> Result<Self>;
 
@@ -297,9 +363,8 @@ pub trait FromLexicalFormat: FromLexical {
     /// * `radix`   - Radix for the number parsing.
     /// * `format`  - Numerical format.
     ///
-    /// # Panics
-    ///
-    /// Panics if the radix is not in the range `[2, 36]`.
+    /// Returns an `InvalidRadix` error if the radix is not in the
+    /// range `[2, 36]`.
     #[cfg(feature = "radix")]
     fn from_lexical_partial_format_radix(bytes: &[u8], radix: u8, format: NumberFormat) -> Result<(Self, usize)>;
 }
@@ -325,6 +390,7 @@ macro_rules! from_lexical_format {
             #[inline]
             fn from_lexical_format_radix(bytes: &[u8], radix: u8, format: NumberFormat) -> Result<$t>
             {
+                check_radix!(radix);
                 to_complete!($cb, bytes, radix.as_u32(), format)
             }
 
@@ -332,6 +398,7 @@ macro_rules! from_lexical_format {
             #[inline]
             fn from_lexical_partial_format_radix(bytes: &[u8], radix: u8, format: NumberFormat) -> Result<($t, usize)>
             {
+                check_radix!(radix);
                 $cb(bytes, radix.as_u32(), format)
             }
         }
@@ -393,9 +460,8 @@ pub trait FromLexicalLossyFormat: FromLexical {
     /// * `radix`   - Radix for the number parsing.
     /// * `format`  - Numerical format.
     ///
-    /// # Panics
-    ///
-    /// Panics if the radix is not in the range `[2, 36]`.
+    /// Returns an `InvalidRadix` error if the radix is not in the
+    /// range `[2, 36]`.
     #[cfg(feature = "radix")]
     fn from_lexical_lossy_format_radix(bytes: &[u8], radix: u8, format: NumberFormat) -> Result<Self>;
 
@@ -417,9 +483,8 @@ pub trait FromLexicalLossyFormat: FromLexical {
     /// * `radix`   - Radix for the number parsing.
     /// * `format`  - Numerical format.
     ///
-    /// # Panics
-    ///
-    /// Panics if the radix is not in the range `[2, 36]`.
+    /// Returns an `InvalidRadix` error if the radix is not in the
+    /// range `[2, 36]`.
     #[cfg(feature = "radix")]
     fn from_lexical_partial_lossy_format_radix(bytes: &[u8], radix: u8, format: NumberFormat) -> Result<(Self, usize)>;
 }
@@ -448,6 +513,7 @@ macro_rules! from_lexical_lossy_format {
             fn from_lexical_lossy_format_radix(bytes: &[u8], radix: u8, format: NumberFormat)
                 -> Result<$t>
             {
+                check_radix!(radix);
                 to_complete!($cb, bytes, radix.as_u32(), format)
             }
 
@@ -456,6 +522,7 @@ macro_rules! from_lexical_lossy_format {
             fn from_lexical_partial_lossy_format_radix(bytes: &[u8], radix: u8, format: NumberFormat)
                 -> Result<($t, usize)>
             {
+                check_radix!(radix);
                 $cb(bytes, radix.as_u32(), format)
             }
         }
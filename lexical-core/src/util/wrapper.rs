@@ -0,0 +1,98 @@
+//! Wrapper to parse numbers through the `TryFrom` conversion traits.
+
+use crate::lib::convert::TryFrom;
+use super::error::Error;
+use super::traits::FromLexical;
+
+// LEXICAL
+
+/// Wrapper to parse a number with `TryFrom`.
+///
+/// Implements `TryFrom<&[u8]>` and `TryFrom<&str>` for every type
+/// implementing [`FromLexical`], including the floats and integers,
+/// so the parsers may be used by code generic over `TryFrom`. The
+/// entire string is parsed, as with [`parse`], returning an [`Error`]
+/// if any invalid digits are found during parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{ErrorCode, Lexical};
+/// # use std::convert::TryFrom;
+/// # pub fn main() {
+/// let value = Lexical::<f64>::try_from("1.5").unwrap();
+/// assert_eq!(value.into_inner(), 1.5);
+///
+/// let value = Lexical::<u8>::try_from(&b"25a"[..]);
+/// assert_eq!(value.unwrap_err().code, ErrorCode::InvalidDigit);
+/// # }
+/// ```
+///
+/// [`FromLexical`]: trait.FromLexical.html
+/// [`parse`]: fn.parse.html
+/// [`Error`]: struct.Error.html
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lexical<T>(pub T);
+
+impl<T> Lexical<T> {
+    /// Unwrap the parsed number.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<'a, T: FromLexical> TryFrom<&'a [u8]> for Lexical<T> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(bytes: &'a [u8]) -> Result<Lexical<T>, Error> {
+        T::from_lexical(bytes).map(Lexical)
+    }
+}
+
+impl<'a, T: FromLexical> TryFrom<&'a str> for Lexical<T> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(string: &'a str) -> Result<Lexical<T>, Error> {
+        Lexical::try_from(string.as_bytes())
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::error::ErrorCode;
+
+    #[test]
+    fn try_from_test() {
+        assert_eq!(Lexical::<f32>::try_from(&b"1.5"[..]), Ok(Lexical(1.5)));
+        assert_eq!(Lexical::<f64>::try_from("-1.5e3"), Ok(Lexical(-1500.0)));
+        assert_eq!(Lexical::<u8>::try_from("255"), Ok(Lexical(255)));
+        assert_eq!(Lexical::<i64>::try_from(&b"-9223372036854775808"[..]), Ok(Lexical(i64::min_value())));
+        assert_eq!(Lexical::<usize>::try_from("0").map(Lexical::into_inner), Ok(0));
+    }
+
+    #[test]
+    fn try_from_error_test() {
+        assert_eq!(Lexical::<f64>::try_from("1.5x"), Err((ErrorCode::InvalidDigit, 3).into()));
+        assert_eq!(Lexical::<f32>::try_from(""), Err((ErrorCode::Empty, 0).into()));
+        assert_eq!(Lexical::<u8>::try_from(&b"256"[..]), Err((ErrorCode::Overflow, 2).into()));
+        assert_eq!(Lexical::<u32>::try_from("-1"), Err((ErrorCode::InvalidDigit, 0).into()));
+        assert_eq!(Lexical::<i16>::try_from("12 "), Err((ErrorCode::InvalidDigit, 2).into()));
+    }
+
+    #[test]
+    fn try_from_generic_test() {
+        fn convert<'a, T: TryFrom<&'a str, Error=Error>>(string: &'a str) -> Result<T, Error> {
+            T::try_from(string)
+        }
+        assert_eq!(convert::<Lexical<f64>>("0.25"), Ok(Lexical(0.25)));
+        assert_eq!(convert::<Lexical<i32>>("0.25"), Err((ErrorCode::InvalidDigit, 1).into()));
+    }
+}
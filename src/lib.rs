@@ -27,6 +27,7 @@
 //! **To String**
 //! - [`to_string`]
 #![cfg_attr(feature = "radix", doc = " - [`to_string_radix`]")]
+//! - [`write_i64_list`]
 //!
 //! **From String**
 //! - [`parse`]
@@ -34,6 +35,10 @@
 #![cfg_attr(feature = "format", doc = " - [`parse_format`]")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " - [`parse_format_radix`]")]
 //! - [`parse_partial`]
+//! - [`parse_partial_str`]
+//! - [`parse_unicode_str`]
+//! - [`parse_list`]
+//! - [`parse_into_slice`]
 #![cfg_attr(feature = "radix", doc = " - [`parse_partial_radix`]")]
 #![cfg_attr(feature = "format", doc = " - [`parse_partial_format`]")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " - [`parse_partial_format_radix`]")]
@@ -66,11 +71,16 @@
 //!
 //! [`to_string`]: fn.to_string.html
 #![cfg_attr(feature = "radix", doc = " [`to_string_radix`]: fn.to_string_radix.html")]
+//! [`write_i64_list`]: fn.write_i64_list.html
 //! [`parse`]: fn.parse.html
 #![cfg_attr(feature = "radix", doc = " [`parse_radix`]: fn.parse_radix.html")]
 #![cfg_attr(feature = "format", doc = " [`parse_format`]: fn.parse_format.html")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " [`parse_format_radix`]: fn.parse_format_radix.html")]
 //! [`parse_partial`]: fn.parse_partial.html
+//! [`parse_partial_str`]: fn.parse_partial_str.html
+//! [`parse_unicode_str`]: fn.parse_unicode_str.html
+//! [`parse_list`]: fn.parse_list.html
+//! [`parse_into_slice`]: fn.parse_into_slice.html
 #![cfg_attr(feature = "radix", doc = " [`parse_partial_radix`]: fn.parse_partial_radix.html")]
 #![cfg_attr(feature = "format", doc = " [`parse_partial_format`]: fn.parse_partial_format.html")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " [`parse_partial_format_radix`]: fn.parse_partial_format_radix.html")]
@@ -154,11 +164,15 @@ pub use lexical_core::{get_float_rounding, set_float_rounding, RoundingKind};
 #[cfg(feature = "format")]
 pub use lexical_core::NumberFormat;
 
+// Re-export the big integer type used by the correct parser.
+#[cfg(feature = "bignum")]
+pub use lexical_core::bignum;
+
 // Re-export the Result, Error and ErrorCode globally.
 pub use lexical_core::{Error, ErrorCode, Result};
 
 // Publicly expose traits so they may be used for generic programming.
-pub use lexical_core::{FromLexical, FromLexicalLossy, ToLexical};
+pub use lexical_core::{FromLexical, FromLexicalLossy, FromLexicalNonZero, ToLexical};
 #[cfg(feature = "format")]
 pub use lexical_core::{FromLexicalFormat,FromLexicalLossyFormat};
 
@@ -231,6 +245,46 @@ pub fn to_string_radix<N: ToLexical>(n: N, radix: u8) -> lib::String {
     }
 }
 
+/// High-level conversion of a list of integers to delimited decimal strings.
+///
+/// Appends each integer to the buffer, separated by the delimiter,
+/// without a trailing delimiter, so an empty slice appends nothing,
+/// and a single integer appends only the integer. The buffer is grown
+/// once, to the maximum size of every integer and delimiter, and each
+/// integer is formatted on the stack before it is appended.
+///
+/// * `out`         - Buffer to append the list to.
+/// * `values`      - Integers to convert to strings.
+/// * `delimiter`   - Byte separating each integer.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let mut out = b"values=".to_vec();
+/// lexical::write_i64_list(&mut out, &[1, -20, 300], b',');
+/// assert_eq!(out, b"values=1,-20,300");
+///
+/// let mut out = Vec::new();
+/// lexical::write_i64_list(&mut out, &[], b',');
+/// assert_eq!(out, b"");
+/// lexical::write_i64_list(&mut out, &[i64::min_value()], b',');
+/// assert_eq!(out, b"-9223372036854775808");
+/// # }
+/// ```
+#[inline]
+pub fn write_i64_list(out: &mut lib::Vec<u8>, values: &[i64], delimiter: u8) {
+    out.reserve(values.len() * (<i64 as lexical_core::Number>::FORMATTED_SIZE_DECIMAL + 1));
+    let mut buf = [0u8; <i64 as lexical_core::Number>::FORMATTED_SIZE_DECIMAL];
+    for (index, &value) in values.iter().enumerate() {
+        if index != 0 {
+            out.push(delimiter);
+        }
+        out.extend_from_slice(lexical_core::write(value, &mut buf));
+    }
+}
+
 /// High-level conversion of decimal-encoded bytes to a number.
 ///
 /// This function only returns a value if the entire string is
@@ -314,6 +368,313 @@ pub fn parse_partial<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes)
     N::from_lexical_partial(bytes.as_ref())
 }
 
+/// High-level conversion of a string slice to a number, returning the unparsed remainder.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the parsed value until that point and
+/// the remaining, unparsed string slice. Since the number grammar is
+/// ASCII-only, the remainder is always a valid string slice.
+///
+/// * `string`  - String slice to parse.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_partial_str::<i32>("5"), Ok((5, "")));
+/// assert_eq!(lexical::parse_partial_str::<i32>("1a"), Ok((1, "a")));
+/// assert_eq!(lexical::parse_partial_str::<f64>("1.5 m\u{b2}"), Ok((1.5, " m\u{b2}")));
+/// # }
+/// ```
+#[inline]
+pub fn parse_partial_str<N: FromLexical>(string: &str)
+    -> Result<(N, &str)>
+{
+    lexical_core::parse_partial_str(string)
+}
+
+/// Decimal digits for [`parse_unicode_str`].
+///
+/// The digits are a range of 10 consecutive codepoints, from the digit
+/// 0 to the digit 9, such as the fullwidth digits `U+FF10` to `U+FF19`.
+/// ASCII digits are also accepted, unless disabled with [`with_ascii`].
+///
+/// [`parse_unicode_str`]: fn.parse_unicode_str.html
+/// [`with_ascii`]: struct.UnicodeDigits.html#method.with_ascii
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnicodeDigits {
+    zero: char,
+    ascii: bool,
+}
+
+impl UnicodeDigits {
+    /// Fullwidth digits, from `U+FF10` to `U+FF19`.
+    pub const FULLWIDTH: UnicodeDigits = UnicodeDigits { zero: '\u{ff10}', ascii: true };
+
+    /// Arabic-Indic digits, from `U+0660` to `U+0669`.
+    pub const ARABIC_INDIC: UnicodeDigits = UnicodeDigits { zero: '\u{660}', ascii: true };
+
+    /// Extended Arabic-Indic digits, from `U+06F0` to `U+06F9`.
+    pub const EXTENDED_ARABIC_INDIC: UnicodeDigits = UnicodeDigits { zero: '\u{6f0}', ascii: true };
+
+    /// Devanagari digits, from `U+0966` to `U+096F`.
+    pub const DEVANAGARI: UnicodeDigits = UnicodeDigits { zero: '\u{966}', ascii: true };
+
+    /// Create digits from the codepoint of the digit 0.
+    ///
+    /// The digits 1 to 9 are the next 9 codepoints. Returns `None` if
+    /// any of the digits is not a valid `char`.
+    #[inline]
+    pub fn new(zero: char) -> Option<UnicodeDigits> {
+        let first = zero as u32;
+        match (first..first + 10).all(|c| lib::char::from_u32(c).is_some()) {
+            true  => Some(UnicodeDigits { zero, ascii: true }),
+            false => None,
+        }
+    }
+
+    /// Set if ASCII digits are accepted, in addition to the digits.
+    #[inline]
+    pub fn with_ascii(self, ascii: bool) -> UnicodeDigits {
+        UnicodeDigits { zero: self.zero, ascii }
+    }
+
+    /// Get the codepoint of the digit 0.
+    #[inline]
+    pub fn zero(&self) -> char {
+        self.zero
+    }
+
+    /// Get if ASCII digits are accepted.
+    #[inline]
+    pub fn ascii(&self) -> bool {
+        self.ascii
+    }
+
+    // Convert the character to the equivalent ASCII byte, if any.
+    #[inline]
+    fn to_ascii(self, c: char) -> Option<u8> {
+        let offset = (c as u32).wrapping_sub(self.zero as u32);
+        if offset < 10 {
+            Some(b'0' + offset as u8)
+        } else if c.is_ascii() && (self.ascii || !c.is_ascii_digit()) {
+            Some(c as u8)
+        } else {
+            None
+        }
+    }
+}
+
+/// High-level conversion of a string with unicode digits to a number.
+///
+/// This method parses the entire string, like [`parse`], but decimal
+/// digits are decoded from the codepoints in `digits`, as well as from
+/// ASCII, unless ASCII digits are disabled. The remaining characters,
+/// such as the sign, decimal point and exponent, are ASCII. Since the
+/// string is decoded to ASCII before parsing, this is slower than
+/// [`parse`], which remains the fast path for ASCII strings.
+///
+/// On failure, the error index is the byte index of the invalid
+/// character in the string.
+///
+/// * `string`  - String slice to parse.
+/// * `digits`  - Unicode digits to decode.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::{ErrorCode, UnicodeDigits};
+/// # pub fn main() {
+/// let fullwidth = UnicodeDigits::FULLWIDTH;
+/// assert_eq!(lexical::parse_unicode_str::<f64>("\u{ff11}.\u{ff15}e\u{ff13}", fullwidth), Ok(1500.0));
+/// assert_eq!(lexical::parse_unicode_str::<i32>("-\u{ff12}\u{ff10}\u{ff12}\u{ff16}", fullwidth), Ok(-2026));
+/// assert_eq!(lexical::parse_unicode_str::<i32>("\u{ff11}2", fullwidth), Ok(12));
+/// assert_eq!(lexical::parse_unicode_str::<f64>("\u{663}.\u{661}\u{664}", UnicodeDigits::ARABIC_INDIC), Ok(3.14));
+///
+/// // ASCII digits may be rejected.
+/// let error = lexical::parse_unicode_str::<i32>("\u{ff11}2", fullwidth.with_ascii(false)).err().unwrap();
+/// assert_eq!((error.code, error.index), (ErrorCode::InvalidDigit, 3));
+///
+/// // Digits from other ranges are invalid.
+/// let error = lexical::parse_unicode_str::<i32>("\u{ff11}\u{661}", fullwidth).err().unwrap();
+/// assert_eq!((error.code, error.index), (ErrorCode::InvalidDigit, 3));
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+pub fn parse_unicode_str<N: FromLexical>(string: &str, digits: UnicodeDigits)
+    -> Result<N>
+{
+    // Decode each character to a single byte, so the index of the error
+    // is the index of the character. Characters without an ASCII
+    // equivalent are decoded to a byte that is never valid.
+    let bytes: lib::Vec<u8> = string.chars()
+        .map(|c| digits.to_ascii(c).unwrap_or(0x80))
+        .collect();
+    N::from_lexical(&bytes).map_err(|error| {
+        let index = string.char_indices().nth(error.index).map_or(string.len(), |(i, _)| i);
+        (error.code, index).into()
+    })
+}
+
+/// High-level conversion of a list of decimal-encoded numbers.
+///
+/// This method parses a comma-separated list of numbers, such as
+/// `"[1.0, 2.5, 3.0]"`. The surrounding brackets are optional, and
+/// whitespace is allowed around the brackets and around each element.
+/// On failure, the error index is the index of the first element that
+/// failed to parse, not a position within the buffer.
+///
+/// * `bytes`   - Byte slice containing a list of numeric strings.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_list::<f64, _>("[1.0, 2.5, 3.0]"), Ok(vec![1.0, 2.5, 3.0]));
+/// assert_eq!(lexical::parse_list::<i32, _>("1, 2"), Ok(vec![1, 2]));
+/// assert_eq!(lexical::parse_list::<f64, _>("[]"), Ok(vec![]));
+///
+/// let error = lexical::parse_list::<f64, _>("[1.0, 2.5x, 3.0]").err().unwrap();
+/// assert_eq!((error.code, error.index), (ErrorCode::InvalidDigit, 1));
+/// # }
+/// ```
+#[inline]
+pub fn parse_list<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Result<lib::Vec<N>>
+{
+    let bytes = bytes.as_ref();
+
+    // Every element but the last is followed by a comma, so the list
+    // cannot have more elements than this.
+    let capacity = 1 + bytes.iter().filter(|&&c| c == b',').count();
+    let zero: N = lib::iter::empty().sum();
+    let mut values = lib::Vec::with_capacity(capacity);
+    values.resize(capacity, zero);
+    let count = lexical_core::parse_list(bytes, &mut values)?;
+    values.truncate(count);
+    Ok(values)
+}
+
+/// Policy for malformed fields in [`parse_into_slice`].
+///
+/// [`parse_into_slice`]: fn.parse_into_slice.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Stop at the first malformed field, and return its error.
+    Abort,
+    /// Write `NaN` for each malformed field, and continue parsing.
+    Substitute,
+}
+
+/// Summary of the fields written by [`parse_into_slice`].
+///
+/// [`parse_into_slice`]: fn.parse_into_slice.html
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParseReport {
+    /// Number of fields written to the output slice.
+    pub filled: usize,
+    /// Indexes of the malformed fields which were substituted with `NaN`.
+    pub substituted: lib::Vec<usize>,
+}
+
+// Remove leading and trailing ASCII whitespace from a field.
+#[inline]
+fn trim_ascii_whitespace(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().take_while(|c| c.is_ascii_whitespace()).count();
+    let end = bytes.len() - bytes[start..].iter().rev().take_while(|c| c.is_ascii_whitespace()).count();
+    &bytes[start..end]
+}
+
+/// High-level conversion of a delimited list of decimal floats into a slice.
+///
+/// Each field is separated by the delimiter, and whitespace around each
+/// field is ignored. If the delimiter is an ASCII whitespace byte, any
+/// run of whitespace separates fields. A buffer with only whitespace
+/// has no fields. Each field is parsed with [`parse`], and a malformed
+/// field, including an empty field, is handled by `on_error`: either
+/// the parse aborts, or the field is written as `NaN` and its index is
+/// recorded in the report.
+///
+/// On failure, the error index is the index of the field that failed,
+/// not a position within the buffer, and all prior fields have been
+/// written to `out`. If the buffer has more fields than `out`, an
+/// error with `ErrorCode::TooManyElements` is returned, regardless
+/// of the policy.
+///
+/// * `bytes`       - Byte slice containing a delimited list of floats.
+/// * `delimiter`   - Byte separating each field.
+/// * `out`         - Slice to write the parsed floats to.
+/// * `on_error`    - Policy for malformed fields.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::{ErrorCode, ErrorPolicy};
+/// # pub fn main() {
+/// let mut out = [0.0f64; 4];
+/// let report = lexical::parse_into_slice("1.5, 2x, 3e2", b',', &mut out, ErrorPolicy::Substitute).unwrap();
+/// assert_eq!((report.filled, report.substituted), (3, vec![1]));
+/// assert_eq!((out[0], out[2]), (1.5, 300.0));
+/// assert!(out[1].is_nan());
+///
+/// let report = lexical::parse_into_slice(" 4.0\t5.0\n 6.0 ", b' ', &mut out, ErrorPolicy::Abort).unwrap();
+/// assert_eq!((report.filled, report.substituted), (3, vec![]));
+/// assert_eq!(&out[..3], &[4.0, 5.0, 6.0]);
+///
+/// let error = lexical::parse_into_slice("1.5, 2x, 3e2", b',', &mut out, ErrorPolicy::Abort).err().unwrap();
+/// assert_eq!((error.code, error.index), (ErrorCode::InvalidDigit, 1));
+/// assert_eq!(out[0], 1.5);
+///
+/// let error = lexical::parse_into_slice("1,2,3,4,5", b',', &mut out, ErrorPolicy::Substitute).err().unwrap();
+/// assert_eq!((error.code, error.index), (ErrorCode::TooManyElements, 4));
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+pub fn parse_into_slice<Bytes: AsRef<[u8]>>(bytes: Bytes, delimiter: u8, out: &mut [f64], on_error: ErrorPolicy)
+    -> Result<ParseReport>
+{
+    let bytes = bytes.as_ref();
+    let mut report = ParseReport::default();
+    if trim_ascii_whitespace(bytes).is_empty() {
+        return Ok(report);
+    }
+
+    let mut parse_field = | field: &[u8] | -> Result<()> {
+        let index = report.filled;
+        if index == out.len() {
+            return Err((ErrorCode::TooManyElements, index).into());
+        }
+        out[index] = match (f64::from_lexical(trim_ascii_whitespace(field)), on_error) {
+            (Ok(value), _)                      => value,
+            // The associated constant requires Rust 1.43.
+            #[allow(clippy::legacy_numeric_constants)]
+            (Err(_), ErrorPolicy::Substitute)   => {
+                report.substituted.push(index);
+                lib::f64::NAN
+            },
+            (Err(error), ErrorPolicy::Abort)    => return Err((error.code, index).into()),
+        };
+        report.filled += 1;
+        Ok(())
+    };
+
+    if delimiter.is_ascii_whitespace() {
+        bytes.split(|c| c.is_ascii_whitespace())
+            .filter(|f| !f.is_empty())
+            .try_for_each(&mut parse_field)?;
+    } else {
+        bytes.split(|&c| c == delimiter).try_for_each(&mut parse_field)?;
+    }
+    Ok(report)
+}
+
 /// High-level lossy conversion of decimal-encoded bytes to a number.
 ///
 /// This function uses aggressive optimizations to avoid worst-case
@@ -0,0 +1,126 @@
+//! Replay test vectors for float parsing from corpus files.
+//!
+//! Each vector file is a newline-delimited list of vectors, with the
+//! input string, the expected bits of the float in hexadecimal, and
+//! an optional base, separated by tabs. Blank lines and lines starting
+//! with `#` are ignored. Files ending in `.f32.tsv` are parsed as `f32`,
+//! and files ending in `.f64.tsv` as `f64`.
+//!
+//! The vector files are loaded from `data/test-parse-corpus`, if present,
+//! and from any additional files or directories in the `LEXICAL_CORPUS`
+//! environment variable, separated like the `PATH` environment variable.
+//! Vectors with a base other than 10 are skipped without the `radix`
+//! feature.
+
+extern crate lexical;
+
+#[cfg(feature = "correct")]
+use std::env;
+#[cfg(feature = "correct")]
+use std::fmt::Debug;
+#[cfg(feature = "correct")]
+use std::fs;
+#[cfg(feature = "correct")]
+use std::path::{Path, PathBuf};
+
+// Float with a fixed-width bit representation.
+#[cfg(feature = "correct")]
+trait Bits: lexical::FromLexical + Copy + Debug {
+    const SUFFIX: &'static str;
+
+    fn to_bits_u64(self) -> u64;
+}
+
+#[cfg(feature = "correct")]
+impl Bits for f32 {
+    const SUFFIX: &'static str = ".f32.tsv";
+
+    fn to_bits_u64(self) -> u64 {
+        self.to_bits() as u64
+    }
+}
+
+#[cfg(feature = "correct")]
+impl Bits for f64 {
+    const SUFFIX: &'static str = ".f64.tsv";
+
+    fn to_bits_u64(self) -> u64 {
+        self.to_bits()
+    }
+}
+
+// Get the vector files with the suffix, from the corpus paths.
+#[cfg(feature = "correct")]
+fn corpus_files(suffix: &str) -> Vec<PathBuf> {
+    let mut paths = vec![Path::new(env!("CARGO_MANIFEST_DIR")).join("data/test-parse-corpus")];
+    if let Some(extra) = env::var_os("LEXICAL_CORPUS") {
+        paths.extend(env::split_paths(&extra));
+    }
+
+    let mut files = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            for entry in fs::read_dir(&path).unwrap() {
+                files.push(entry.unwrap().path());
+            }
+        } else if path.is_file() {
+            files.push(path);
+        }
+    }
+    files.retain(|file| file.to_string_lossy().ends_with(suffix));
+    files.sort();
+    files
+}
+
+// Parse the vector with the base.
+#[cfg(feature = "correct")]
+fn parse<F: Bits>(input: &str, base: u8) -> Option<lexical::Result<F>> {
+    match base {
+        10 => Some(lexical::parse::<F, _>(input)),
+        #[cfg(feature = "radix")]
+        _  => Some(lexical::parse_radix::<F, _>(input, base)),
+        #[cfg(not(feature = "radix"))]
+        _  => None,
+    }
+}
+
+// Replay every vector in the files.
+#[cfg(feature = "correct")]
+fn replay<F: Bits>() {
+    let mut failures = Vec::new();
+    for file in corpus_files(F::SUFFIX) {
+        let contents = fs::read_to_string(&file).unwrap();
+        for (index, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let location = format!("{}:{}", file.display(), index + 1);
+            let columns: Vec<&str> = line.split('\t').collect();
+            let (input, bits, base) = match columns.as_slice() {
+                [input, bits]       => (*input, *bits, "10"),
+                [input, bits, base] => (*input, *bits, *base),
+                _                   => panic!("{}: invalid vector {:?}", location, line),
+            };
+            let bits = u64::from_str_radix(bits, 16).expect(&location);
+            let base = base.parse::<u8>().expect(&location);
+            match parse::<F>(input, base) {
+                Some(Ok(value)) if value.to_bits_u64() == bits => (),
+                Some(result) => failures.push(format!("{}: {:?} parsed as {:?}, expected bits {:x}", location, input, result, bits)),
+                None         => (),
+            }
+        }
+    }
+    assert!(failures.is_empty(), "corpus failures:\n{}", failures.join("\n"));
+}
+
+#[cfg(feature = "correct")]
+#[test]
+fn corpus_f32_test() {
+    replay::<f32>();
+}
+
+#[cfg(feature = "correct")]
+#[test]
+fn corpus_f64_test() {
+    replay::<f64>();
+}